@@ -0,0 +1,129 @@
+//! Minimal HTTP transport for the [`Evaluator`].
+//!
+//! Routes:
+//! - `GET /health`: returns `200` when the server is up;
+//! - `POST /evaluate`: evaluates the bincode encoded
+//!   [`EvaluationRequest`](crate::protocol::EvaluationRequest) sent as body.
+//!
+//! Request bodies are deserialized directly from the socket, without first buffering the whole
+//! body, and their operands are validated against the server key. Only then is an evaluation
+//! slot taken, so slow uploads cannot starve the evaluations, and requests arriving when all
+//! evaluation slots are busy are rejected with a `503`. A request making the evaluation panic is
+//! answered with a `500` instead of taking its worker down.
+use crate::protocol::EvaluationRequest;
+use crate::service::{ConcurrencyLimiter, Evaluator};
+use std::io::{BufReader, Read};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use tiny_http::{Method, Request, Response, Server, StatusCode};
+
+pub struct ServerConfig {
+    pub address: String,
+    pub worker_threads: usize,
+    pub max_concurrent_evaluations: usize,
+    /// Requests with a body bigger than this are rejected before being deserialized
+    pub max_request_size: u64,
+}
+
+pub fn serve(config: ServerConfig, evaluator: Evaluator) -> std::io::Result<()> {
+    let server = Arc::new(
+        Server::http(&config.address)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?,
+    );
+    let limiter = Arc::new(ConcurrencyLimiter::new(config.max_concurrent_evaluations));
+    log::info!("Listening on {}", config.address);
+
+    let workers = (0..config.worker_threads)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let limiter = Arc::clone(&limiter);
+            let evaluator = evaluator.clone();
+            let max_request_size = config.max_request_size;
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle_request(request, &evaluator, &limiter, max_request_size);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    Ok(())
+}
+
+fn respond_with_text(request: Request, status: u16, text: &str) {
+    let response = Response::from_string(text).with_status_code(StatusCode(status));
+    if let Err(err) = request.respond(response) {
+        log::warn!("Failed to send response: {err}");
+    }
+}
+
+fn handle_request(
+    mut request: Request,
+    evaluator: &Evaluator,
+    limiter: &ConcurrencyLimiter,
+    max_request_size: u64,
+) {
+    match (request.method(), request.url()) {
+        (Method::Get, "/health") => respond_with_text(request, 200, "ok"),
+        (Method::Post, "/evaluate") => {
+            if request
+                .body_length()
+                .map_or(false, |length| length as u64 > max_request_size)
+            {
+                respond_with_text(request, 413, "Request body too large");
+                return;
+            }
+
+            let reader = BufReader::new(request.as_reader().take(max_request_size));
+            let evaluation_request: EvaluationRequest = match bincode::deserialize_from(reader) {
+                Ok(evaluation_request) => evaluation_request,
+                Err(err) => {
+                    respond_with_text(request, 400, &format!("Invalid request: {err}"));
+                    return;
+                }
+            };
+            if let Err(err) = evaluator.validate(&evaluation_request) {
+                respond_with_text(request, 422, &err.to_string());
+                return;
+            }
+
+            let Some(_permit) = limiter.try_acquire() else {
+                respond_with_text(request, 503, "Too many concurrent evaluations");
+                return;
+            };
+
+            log::debug!("Evaluating {}", evaluation_request.name());
+            // The evaluator only reads the shared server key, a panic cannot leave it in an
+            // inconsistent state
+            let result =
+                match catch_unwind(AssertUnwindSafe(|| evaluator.evaluate(&evaluation_request))) {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(err)) => {
+                        respond_with_text(request, 422, &err.to_string());
+                        return;
+                    }
+                    Err(_) => {
+                        log::error!("Evaluation of {} panicked", evaluation_request.name());
+                        respond_with_text(request, 500, "Internal error during the evaluation");
+                        return;
+                    }
+                };
+            // Free the memory of the operands before serializing the result
+            drop(evaluation_request);
+
+            match bincode::serialize(&result) {
+                Ok(bytes) => {
+                    if let Err(err) = request.respond(Response::from_data(bytes)) {
+                        log::warn!("Failed to send response: {err}");
+                    }
+                }
+                Err(err) => respond_with_text(request, 500, &err.to_string()),
+            }
+        }
+        _ => respond_with_text(request, 404, "Not found"),
+    }
+}
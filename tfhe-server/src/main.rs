@@ -0,0 +1,87 @@
+//! Reference implementation of an evaluation service.
+//!
+//! The server loads a bincode serialized [`tfhe::integer::ServerKey`] once at startup and
+//! evaluates operations on the radix ciphertexts uploaded by the clients, see [`http`] for the
+//! available routes and [`protocol`] for the format of the messages.
+use clap::{Arg, Command};
+use log::LevelFilter;
+use simplelog::{ColorChoice, CombinedLogger, Config, TermLogger, TerminalMode};
+use std::fs::File;
+use std::io::BufReader;
+
+mod http;
+mod protocol;
+mod service;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let available_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+    let matches = Command::new("tfhe-server")
+        .about("Evaluate homomorphic operations on uploaded ciphertexts")
+        .arg(
+            Arg::new("server-key")
+                .long("server-key")
+                .takes_value(true)
+                .required(true)
+                .help("Path to the bincode serialized integer ServerKey"),
+        )
+        .arg(
+            Arg::new("address")
+                .long("address")
+                .takes_value(true)
+                .default_value("127.0.0.1:8080")
+                .help("Address to listen on"),
+        )
+        .arg(
+            Arg::new("max-concurrent-evaluations")
+                .long("max-concurrent-evaluations")
+                .takes_value(true)
+                .default_value("2")
+                .help("Number of evaluations running at the same time"),
+        )
+        .arg(
+            Arg::new("max-request-size")
+                .long("max-request-size")
+                .takes_value(true)
+                .default_value("268435456")
+                .help("Maximum size in bytes of a request body"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .help("Prints debug messages"),
+        )
+        .get_matches();
+
+    let verb = if matches.contains_id("verbose") {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
+    };
+    CombinedLogger::init(vec![TermLogger::new(
+        verb,
+        Config::default(),
+        TerminalMode::Mixed,
+        ColorChoice::Auto,
+    )])?;
+
+    let key_path = matches.value_of("server-key").unwrap();
+    log::info!("Loading server key from {key_path}");
+    let server_key: tfhe::integer::ServerKey =
+        bincode::deserialize_from(BufReader::new(File::open(key_path)?))?;
+
+    let max_concurrent_evaluations: usize = matches.value_of_t("max-concurrent-evaluations")?;
+
+    let config = http::ServerConfig {
+        address: matches.value_of("address").unwrap().to_string(),
+        // One more worker than evaluation slots so that busy requests can be answered
+        worker_threads: (max_concurrent_evaluations + 1).min(available_threads.max(2)),
+        max_concurrent_evaluations,
+        max_request_size: matches.value_of_t("max-request-size")?,
+    };
+
+    http::serve(config, service::Evaluator::new(server_key))?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! Messages exchanged with the evaluation service.
+//!
+//! Requests and responses are serialized with bincode. The body of a `POST /evaluate` request is
+//! an [`EvaluationRequest`], the body of a successful response is the resulting
+//! [`RadixCiphertextBig`].
+use serde::{Deserialize, Serialize};
+use tfhe::integer::RadixCiphertextBig;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum EvaluationRequest {
+    Add(RadixCiphertextBig, RadixCiphertextBig),
+    Sub(RadixCiphertextBig, RadixCiphertextBig),
+    Mul(RadixCiphertextBig, RadixCiphertextBig),
+    ScalarAdd(RadixCiphertextBig, u64),
+    ScalarMul(RadixCiphertextBig, u64),
+    /// Apply the lookup table to each block of the ciphertext, the table must have one entry per
+    /// possible value of the message of a block.
+    BlockLookupTable(RadixCiphertextBig, Vec<u64>),
+}
+
+impl EvaluationRequest {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add(..) => "add",
+            Self::Sub(..) => "sub",
+            Self::Mul(..) => "mul",
+            Self::ScalarAdd(..) => "scalar_add",
+            Self::ScalarMul(..) => "scalar_mul",
+            Self::BlockLookupTable(..) => "block_lookup_table",
+        }
+    }
+}
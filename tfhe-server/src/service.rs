@@ -0,0 +1,312 @@
+//! Transport agnostic evaluation logic.
+use crate::protocol::EvaluationRequest;
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use tfhe::integer::{IntegerCiphertext, RadixCiphertextBig, ServerKey};
+use tfhe::shortint::sanitizer::{SanitizeError, Sanitizer};
+
+#[derive(Debug)]
+pub enum EvaluationError {
+    BlockCountMismatch {
+        lhs: usize,
+        rhs: usize,
+    },
+    EmptyCiphertext,
+    /// A block of an operand does not match the server key or declares a degree the server key
+    /// cannot handle
+    InvalidBlock {
+        index: usize,
+        error: SanitizeError,
+    },
+    InvalidLookupTable {
+        expected: usize,
+        got: usize,
+    },
+}
+
+impl Display for EvaluationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BlockCountMismatch { lhs, rhs } => {
+                write!(f, "Operands have different block counts: {lhs} and {rhs}")
+            }
+            Self::EmptyCiphertext => write!(f, "Operands must have at least one block"),
+            Self::InvalidBlock { index, error } => write!(f, "Invalid block {index}: {error}"),
+            Self::InvalidLookupTable { expected, got } => {
+                write!(
+                    f,
+                    "Expected a lookup table with {expected} entries, got {got}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvaluationError {}
+
+/// Evaluates requests with a server key loaded once and shared between the worker threads.
+#[derive(Clone)]
+pub struct Evaluator {
+    server_key: Arc<ServerKey>,
+}
+
+impl Evaluator {
+    pub fn new(server_key: ServerKey) -> Self {
+        Self {
+            server_key: Arc::new(server_key),
+        }
+    }
+
+    fn shortint_key(&self) -> &tfhe::shortint::ServerKey {
+        (*self.server_key).as_ref()
+    }
+
+    fn check_ciphertext(&self, ct: &RadixCiphertextBig) -> Result<(), EvaluationError> {
+        if ct.blocks().is_empty() {
+            return Err(EvaluationError::EmptyCiphertext);
+        }
+
+        // The degrees are chosen by the client, the operations would overflow the carries
+        // without noticing if they were not checked against the server key
+        let sanitizer = Sanitizer::new(self.shortint_key());
+        for (index, block) in ct.blocks().iter().enumerate() {
+            sanitizer
+                .validate(block)
+                .map_err(|error| EvaluationError::InvalidBlock { index, error })?;
+        }
+
+        Ok(())
+    }
+
+    fn check_binary_operands(
+        &self,
+        lhs: &RadixCiphertextBig,
+        rhs: &RadixCiphertextBig,
+    ) -> Result<(), EvaluationError> {
+        self.check_ciphertext(lhs)?;
+        self.check_ciphertext(rhs)?;
+        if lhs.blocks().len() != rhs.blocks().len() {
+            return Err(EvaluationError::BlockCountMismatch {
+                lhs: lhs.blocks().len(),
+                rhs: rhs.blocks().len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_lookup_table(&self, table: &[u64]) -> Result<(), EvaluationError> {
+        let message_modulus = self.shortint_key().message_modulus.0;
+        if table.len() != message_modulus {
+            return Err(EvaluationError::InvalidLookupTable {
+                expected: message_modulus,
+                got: table.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the request can be evaluated with the server key, without evaluating it.
+    ///
+    /// This is cheap compared to the evaluation, it lets the transport reject invalid requests
+    /// before giving them an evaluation slot.
+    pub fn validate(&self, request: &EvaluationRequest) -> Result<(), EvaluationError> {
+        match request {
+            EvaluationRequest::Add(lhs, rhs)
+            | EvaluationRequest::Sub(lhs, rhs)
+            | EvaluationRequest::Mul(lhs, rhs) => self.check_binary_operands(lhs, rhs),
+            EvaluationRequest::ScalarAdd(ct, _) | EvaluationRequest::ScalarMul(ct, _) => {
+                self.check_ciphertext(ct)
+            }
+            EvaluationRequest::BlockLookupTable(ct, table) => {
+                self.check_ciphertext(ct)?;
+                self.check_lookup_table(table)
+            }
+        }
+    }
+
+    pub fn evaluate(
+        &self,
+        request: &EvaluationRequest,
+    ) -> Result<RadixCiphertextBig, EvaluationError> {
+        self.validate(request)?;
+
+        let sks = self.server_key.as_ref();
+        let result = match request {
+            EvaluationRequest::Add(lhs, rhs) => sks.add_parallelized(lhs, rhs),
+            EvaluationRequest::Sub(lhs, rhs) => sks.sub_parallelized(lhs, rhs),
+            EvaluationRequest::Mul(lhs, rhs) => sks.mul_parallelized(lhs, rhs),
+            EvaluationRequest::ScalarAdd(ct, scalar) => sks.scalar_add_parallelized(ct, *scalar),
+            EvaluationRequest::ScalarMul(ct, scalar) => sks.scalar_mul_parallelized(ct, *scalar),
+            EvaluationRequest::BlockLookupTable(ct, table) => {
+                self.apply_block_lookup_table(ct, table)
+            }
+        };
+        Ok(result)
+    }
+
+    fn apply_block_lookup_table(
+        &self,
+        ct: &RadixCiphertextBig,
+        table: &[u64],
+    ) -> RadixCiphertextBig {
+        let shortint_key = self.shortint_key();
+        let message_modulus = shortint_key.message_modulus.0;
+
+        // Carries are propagated first, so that each block only holds a message
+        let mut ct = ct.clone();
+        self.server_key.full_propagate_parallelized(&mut ct);
+
+        let acc = shortint_key.generate_accumulator(|x| table[x as usize % message_modulus]);
+        let blocks = ct
+            .blocks()
+            .par_iter()
+            .map(|block| shortint_key.apply_lookup_table(block, &acc))
+            .collect();
+
+        RadixCiphertextBig::from_blocks(blocks)
+    }
+}
+
+/// Limits the number of requests evaluated at the same time.
+///
+/// PBS heavy operations already use all the cores, accepting more requests than what can be
+/// processed only increases the latency and the memory used by ciphertexts waiting in queues.
+pub struct ConcurrencyLimiter {
+    max_concurrent: usize,
+    in_flight: Mutex<usize>,
+}
+
+/// Releases its slot in the [`ConcurrencyLimiter`] when dropped.
+pub struct ConcurrencyPermit<'a> {
+    limiter: &'a ConcurrencyLimiter,
+}
+
+impl ConcurrencyLimiter {
+    pub fn new(max_concurrent: usize) -> Self {
+        assert!(
+            max_concurrent > 0,
+            "At least one concurrent request is required"
+        );
+        Self {
+            max_concurrent,
+            in_flight: Mutex::new(0),
+        }
+    }
+
+    /// Returns a permit if a slot is available, without waiting.
+    pub fn try_acquire(&self) -> Option<ConcurrencyPermit<'_>> {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if *in_flight < self.max_concurrent {
+            *in_flight += 1;
+            Some(ConcurrencyPermit { limiter: self })
+        } else {
+            None
+        }
+    }
+
+    #[cfg(test)]
+    pub fn in_flight(&self) -> usize {
+        *self.in_flight.lock().unwrap()
+    }
+}
+
+impl Drop for ConcurrencyPermit<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.limiter.in_flight.lock().unwrap();
+        *in_flight -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tfhe::integer::keycache::KEY_CACHE;
+    use tfhe::shortint::ciphertext::Degree;
+    use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_concurrency_limiter() {
+        let limiter = ConcurrencyLimiter::new(2);
+
+        let first = limiter.try_acquire().unwrap();
+        let _second = limiter.try_acquire().unwrap();
+        assert!(limiter.try_acquire().is_none());
+        assert_eq!(limiter.in_flight(), 2);
+
+        drop(first);
+        assert_eq!(limiter.in_flight(), 1);
+        let _third = limiter.try_acquire().unwrap();
+        assert_eq!(limiter.in_flight(), 2);
+    }
+
+    #[test]
+    fn test_evaluate_requests() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+        let evaluator = Evaluator::new(sks);
+        let num_blocks = 4;
+
+        let lhs = cks.encrypt_radix(117u64, num_blocks);
+        let rhs = cks.encrypt_radix(23u64, num_blocks);
+
+        let request = EvaluationRequest::Add(lhs.clone(), rhs.clone());
+        // Requests go through bincode like they would on the wire
+        let request: EvaluationRequest =
+            bincode::deserialize(&bincode::serialize(&request).unwrap()).unwrap();
+        assert!(evaluator.validate(&request).is_ok());
+        let result = evaluator.evaluate(&request).unwrap();
+        let dec: u64 = cks.decrypt_radix(&result);
+        assert_eq!(dec, 117 + 23);
+
+        // Each block is negated in its message space
+        let table = vec![3, 2, 1, 0];
+        let result = evaluator
+            .evaluate(&EvaluationRequest::BlockLookupTable(lhs.clone(), table))
+            .unwrap();
+        let dec: u64 = cks.decrypt_radix(&result);
+        assert_eq!(dec, 255 - 117);
+
+        let error = evaluator
+            .evaluate(&EvaluationRequest::BlockLookupTable(
+                lhs.clone(),
+                vec![0; 3],
+            ))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            EvaluationError::InvalidLookupTable {
+                expected: 4,
+                got: 3
+            }
+        ));
+
+        let short_rhs = cks.encrypt_radix(1u64, num_blocks - 1);
+        let error = evaluator
+            .evaluate(&EvaluationRequest::Mul(lhs.clone(), short_rhs))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            EvaluationError::BlockCountMismatch { lhs: 4, rhs: 3 }
+        ));
+
+        let empty = RadixCiphertextBig::from_blocks(vec![]);
+        let error = evaluator
+            .evaluate(&EvaluationRequest::ScalarAdd(empty, 1))
+            .unwrap_err();
+        assert!(matches!(error, EvaluationError::EmptyCiphertext));
+
+        let mut forged = lhs;
+        let max_degree = evaluator.shortint_key().max_degree.0;
+        forged.blocks_mut()[1].degree = Degree(max_degree + 1);
+        let error = evaluator
+            .evaluate(&EvaluationRequest::Add(forged, rhs))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            EvaluationError::InvalidBlock {
+                index: 1,
+                error: SanitizeError::DegreeTooLarge { .. }
+            }
+        ));
+    }
+}
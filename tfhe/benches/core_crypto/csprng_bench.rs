@@ -0,0 +1,76 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use tfhe::core_crypto::commons::math::random::RandomGenerator;
+use tfhe::core_crypto::prelude::*;
+
+criterion_group!(csprng_group, uniform_bytes, lwe_encryption);
+criterion_main!(csprng_group);
+
+const BYTE_COUNT: usize = 1 << 20;
+
+fn uniform_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("csprng_uniform_bytes");
+    group.throughput(Throughput::Bytes(BYTE_COUNT as u64));
+
+    let mut boxed_seeder = new_seeder();
+    let seeder = boxed_seeder.as_mut();
+    let mut output = vec![0u8; BYTE_COUNT];
+
+    for backend in RandomGeneratorBackend::available() {
+        let mut generator =
+            RandomGenerator::<RuntimeRandomGenerator>::new_with_backend(seeder.seed(), backend);
+
+        group.bench_function(format!("{backend:?}"), |b| {
+            b.iter(|| {
+                generator.fill_slice_with_random_uniform(&mut output);
+                black_box(&mut output);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn lwe_encryption(c: &mut Criterion) {
+    let mut group = c.benchmark_group("csprng_lwe_encryption");
+
+    let lwe_dimension = LweDimension(742);
+    let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let mut boxed_seeder = new_seeder();
+    let seeder = boxed_seeder.as_mut();
+
+    for backend in RandomGeneratorBackend::available() {
+        let mut secret_generator =
+            SecretRandomGenerator::<RuntimeRandomGenerator>::new_with_backend(
+                seeder.seed(),
+                backend,
+            );
+        let mut encryption_generator =
+            EncryptionRandomGenerator::<RuntimeRandomGenerator>::new_with_backend(
+                seeder.seed(),
+                seeder,
+                backend,
+            );
+
+        let lwe_secret_key: LweSecretKeyOwned<u64> =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+        let mut lwe_ciphertext =
+            LweCiphertext::new(0u64, lwe_dimension.to_lwe_size(), ciphertext_modulus);
+
+        group.bench_function(format!("{backend:?}"), |b| {
+            b.iter(|| {
+                encrypt_lwe_ciphertext(
+                    &lwe_secret_key,
+                    &mut lwe_ciphertext,
+                    Plaintext(0u64),
+                    lwe_modular_std_dev,
+                    &mut encryption_generator,
+                );
+                black_box(&mut lwe_ciphertext);
+            })
+        });
+    }
+
+    group.finish();
+}
@@ -1,4 +1,6 @@
 #![allow(dead_code)]
+// The smart operations are deprecated but still benchmarked until they are removed
+#![allow(deprecated)]
 
 #[path = "../utilities.rs"]
 mod utilities;
@@ -9,6 +11,7 @@ use itertools::iproduct;
 use rand::Rng;
 use std::array::IntoIter;
 use tfhe::integer::keycache::KEY_CACHE;
+use tfhe::integer::server_key::EqualityStrategy;
 use tfhe::integer::{RadixCiphertextBig, ServerKey};
 use tfhe::shortint::keycache::NamedParam;
 
@@ -661,6 +664,17 @@ define_server_key_bench_default_fn!(method_name: le_parallelized, display_name:
 define_server_key_bench_default_fn!(method_name: gt_parallelized, display_name: greater_than);
 define_server_key_bench_default_fn!(method_name: ge_parallelized, display_name: greater_or_equal);
 
+fn eq_parallelized_comparator_tree(c: &mut Criterion) {
+    bench_server_key_binary_function_clean_inputs(
+        c,
+        "ServerKey::eq_parallelized_comparator_tree",
+        "equal",
+        |server_key, lhs, rhs| {
+            server_key.eq_parallelized_with_strategy(lhs, rhs, EqualityStrategy::ComparatorTree);
+        },
+    )
+}
+
 criterion_group!(
     smart_arithmetic_operation,
     smart_neg,
@@ -706,6 +720,7 @@ criterion_group!(
     max_parallelized,
     min_parallelized,
     eq_parallelized,
+    eq_parallelized_comparator_tree,
     lt_parallelized,
     le_parallelized,
     gt_parallelized,
@@ -783,6 +798,7 @@ criterion_group!(
     min_parallelized,
     max_parallelized,
     eq_parallelized,
+    eq_parallelized_comparator_tree,
     lt_parallelized,
     le_parallelized,
     gt_parallelized,
@@ -61,5 +61,8 @@ fn gen_c_api() {
 
 fn main() {
     #[cfg(feature = "__c_api")]
-    gen_c_api()
+    gen_c_api();
+
+    #[cfg(feature = "__node_api")]
+    napi_build::setup();
 }
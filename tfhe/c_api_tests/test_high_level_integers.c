@@ -65,6 +65,92 @@ int uint8_public_key(const ClientKey *client_key, const PublicKey *public_key) {
   return ok;
 }
 
+int uint8_compressed_public_key(const ClientKey *client_key,
+                                const CompressedPublicKey *public_key) {
+  int ok;
+  CompressedPublicKey *deserialized_public_key = NULL;
+  FheUint8 *lhs = NULL;
+  FheUint8 *rhs = NULL;
+  FheUint8 *result = NULL;
+  Buffer public_key_buffer = {.pointer = NULL, .length = 0};
+
+  uint8_t lhs_clear = 123;
+  uint8_t rhs_clear = 14;
+
+  ok = compressed_public_key_serialize(public_key, &public_key_buffer);
+  assert(ok == 0);
+
+  BufferView deser_view = {.pointer = public_key_buffer.pointer,
+                           .length = public_key_buffer.length};
+  ok = compressed_public_key_deserialize(deser_view, &deserialized_public_key);
+  assert(ok == 0);
+
+  ok = fhe_uint8_try_encrypt_with_compressed_public_key_u8(lhs_clear, public_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_try_encrypt_with_compressed_public_key_u8(rhs_clear, deserialized_public_key,
+                                                           &rhs);
+  assert(ok == 0);
+
+  ok = fhe_uint8_sub(lhs, rhs, &result);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == (lhs_clear - rhs_clear));
+
+  compressed_public_key_destroy(deserialized_public_key);
+  destroy_buffer(&public_key_buffer);
+  fhe_uint8_destroy(lhs);
+  fhe_uint8_destroy(rhs);
+  fhe_uint8_destroy(result);
+  return ok;
+}
+
+int uint8_compact_public_key(const ClientKey *client_key,
+                             const CompactPublicKey *public_key) {
+  int ok;
+  CompactFheUint8List *list = NULL;
+  FheUint8 *expanded[3] = {NULL, NULL, NULL};
+  FheUint8 *result = NULL;
+
+  uint8_t clears[3] = {123, 14, 255};
+
+  ok = compact_fhe_uint8_list_try_encrypt_with_compact_public_key_u8(clears, 3, public_key, &list);
+  assert(ok == 0);
+
+  size_t len = 0;
+  ok = compact_fhe_uint8_list_len(list, &len);
+  assert(ok == 0);
+  assert(len == 3);
+
+  ok = compact_fhe_uint8_list_expand(list, expanded, len);
+  assert(ok == 0);
+
+  ok = fhe_uint8_add(expanded[0], expanded[1], &result);
+  assert(ok == 0);
+
+  uint8_t clear;
+  ok = fhe_uint8_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == (uint8_t)(clears[0] + clears[1]));
+
+  ok = fhe_uint8_decrypt(expanded[2], client_key, &clear);
+  assert(ok == 0);
+
+  assert(clear == clears[2]);
+
+  compact_fhe_uint8_list_destroy(list);
+  for (size_t i = 0; i < len; ++i) {
+    fhe_uint8_destroy(expanded[i]);
+  }
+  fhe_uint8_destroy(result);
+  return ok;
+}
+
 int uint8_serialization(const ClientKey *client_key) {
   int ok;
   FheUint8 *lhs = NULL;
@@ -153,11 +239,17 @@ int main(void) {
     ClientKey *client_key = NULL;
     ServerKey *server_key = NULL;
     PublicKey *public_key = NULL;
+    CompactPublicKey *compact_public_key = NULL;
+    CompressedPublicKey *compressed_public_key = NULL;
 
     ok = generate_keys(config, &client_key, &server_key);
     assert(ok == 0);
     ok = public_key_new(client_key, &public_key);
     assert(ok == 0);
+    ok = compressed_public_key_new(client_key, &compressed_public_key);
+    assert(ok == 0);
+    ok = compact_public_key_new(client_key, &compact_public_key);
+    assert(ok == 0);
     ok = uint8_serialization(client_key);
     assert(ok == 0);
     ok = uint8_compressed(client_key);
@@ -170,9 +262,15 @@ int main(void) {
     assert(ok == 0);
     ok = uint8_public_key(client_key, public_key);
     assert(ok == 0);
+    ok = uint8_compressed_public_key(client_key, compressed_public_key);
+    assert(ok == 0);
+    ok = uint8_compact_public_key(client_key, compact_public_key);
+    assert(ok == 0);
 
     client_key_destroy(client_key);
     public_key_destroy(public_key);
+    compressed_public_key_destroy(compressed_public_key);
+    compact_public_key_destroy(compact_public_key);
     server_key_destroy(server_key);
   }
 
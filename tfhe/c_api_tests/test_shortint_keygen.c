@@ -210,6 +210,63 @@ void test_compressed_public_keygen(ShortintPublicKeyKind pk_kind) {
   destroy_shortint_ciphertext(ct);
 }
 
+void test_compact_public_keygen(void) {
+  ShortintClientKey *cks = NULL;
+  ShortintCompactPublicKey *pks = NULL;
+  ShortintParameters *params = NULL;
+  ShortintCompactCiphertextList *compact_list = NULL;
+  ShortintCompactCiphertextList *compact_list_deser = NULL;
+  ShortintCiphertext *cts[4] = {NULL, NULL, NULL, NULL};
+  Buffer list_ser_buff = {.pointer = NULL, .length = 0};
+
+  int get_params_ok = shortint_get_parameters(2, 2, &params);
+  assert(get_params_ok == 0);
+
+  int gen_keys_ok = shortint_gen_client_key(params, &cks);
+  assert(gen_keys_ok == 0);
+
+  int gen_pks = shortint_gen_compact_public_key(cks, &pks);
+  assert(gen_pks == 0);
+
+  uint64_t msgs[4] = {0, 1, 2, 3};
+
+  int encrypt_ok = shortint_compact_public_key_encrypt_slice(pks, msgs, 4, &compact_list);
+  assert(encrypt_ok == 0);
+
+  int list_ser = shortint_serialize_compact_ciphertext_list(compact_list, &list_ser_buff);
+  assert(list_ser == 0);
+
+  BufferView list_ser_buff_view = {.pointer = list_ser_buff.pointer,
+                                   .length = list_ser_buff.length};
+  int list_deser_ok =
+      shortint_deserialize_compact_ciphertext_list(list_ser_buff_view, &compact_list_deser);
+  assert(list_deser_ok == 0);
+
+  size_t list_len = 0;
+  int len_ok = shortint_compact_ciphertext_list_len(compact_list_deser, &list_len);
+  assert(len_ok == 0);
+  assert(list_len == 4);
+
+  int expand_ok = shortint_compact_ciphertext_list_expand(compact_list_deser, cts, list_len);
+  assert(expand_ok == 0);
+
+  for (size_t i = 0; i < 4; ++i) {
+    uint64_t result = -1;
+    int decrypt_ok = shortint_client_key_decrypt(cks, cts[i], &result);
+    assert(decrypt_ok == 0);
+
+    assert(result == msgs[i]);
+    destroy_shortint_ciphertext(cts[i]);
+  }
+
+  destroy_shortint_parameters(params);
+  destroy_shortint_client_key(cks);
+  destroy_shortint_compact_public_key(pks);
+  destroy_shortint_compact_ciphertext_list(compact_list);
+  destroy_shortint_compact_ciphertext_list(compact_list_deser);
+  destroy_buffer(&list_ser_buff);
+}
+
 int main(void) {
   test_predefined_keygen_w_serde();
   test_custom_keygen();
@@ -217,6 +274,7 @@ int main(void) {
   test_public_keygen(ShortintPublicKeySmall);
   test_compressed_public_keygen(ShortintPublicKeyBig);
   test_compressed_public_keygen(ShortintPublicKeySmall);
+  test_compact_public_keygen();
   test_server_key_trivial_encrypt();
   return EXIT_SUCCESS;
 }
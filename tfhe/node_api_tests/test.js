@@ -0,0 +1,54 @@
+const test = require('node:test');
+const assert = require('node:assert').strict;
+const { RadixClientKey, IntegerServerKey, RadixCiphertext } = require('./tfhe.node');
+
+// 4 blocks of 2 bits of message: 8 bits integers
+const cks = RadixClientKey.generate(2, 2, 4);
+const sks = IntegerServerKey.generate(cks);
+
+test('integer_encrypt_decrypt', (t) => {
+    let ct = cks.encrypt(213n);
+
+    let serialized_ct = ct.serialize();
+    let deserialized_ct = RadixCiphertext.deserialize(serialized_ct);
+
+    assert.deepStrictEqual(cks.decrypt(deserialized_ct), 213n);
+});
+
+test('integer_add_sync_and_async', async (t) => {
+    let a = cks.encrypt(27n);
+    let b = cks.encrypt(128n);
+
+    assert.deepStrictEqual(cks.decrypt(sks.add(a, b)), 155n);
+    assert.deepStrictEqual(cks.decrypt(await sks.addAsync(a, b)), 155n);
+});
+
+test('integer_parallel_ops_on_thread_pool', async (t) => {
+    let clears = [3n, 17n, 42n, 100n];
+    let cts = clears.map((clear) => cks.encrypt(clear));
+
+    let results = await Promise.all(cts.map((ct) => sks.scalarMulAsync(ct, 2n)));
+
+    let decrypted = results.map((ct) => cks.decrypt(ct));
+    assert.deepStrictEqual(decrypted, clears.map((clear) => (clear * 2n) % 256n));
+});
+
+test('integer_scalar_rejects_negative', (t) => {
+    let ct = cks.encrypt(1n);
+    assert.throws(() => sks.scalarAdd(ct, -1n));
+});
+
+test('integer_client_key_rejects_invalid_parameters', (t) => {
+    assert.throws(() => RadixClientKey.generate(2, 2, 0));
+    assert.throws(() => RadixClientKey.generate(9, 0, 4));
+    assert.throws(() => RadixClientKey.generate(64, 64, 4));
+});
+
+test('integer_ops_reject_mismatched_operands', async (t) => {
+    let a = cks.encrypt(1n);
+    // Encrypted with the same parameters but fewer blocks
+    let short = RadixClientKey.generate(2, 2, 2).encrypt(1n);
+
+    assert.throws(() => sks.add(a, short));
+    await assert.rejects(sks.addAsync(a, short));
+});
@@ -0,0 +1,53 @@
+import numpy as np
+import pytest
+
+from tfhe import ClientKey, ConfigBuilder, FheUint8, generate_keys, set_server_key
+
+
+@pytest.fixture(scope="module")
+def keys():
+    config = ConfigBuilder.all_disabled().enable_default_uint8().build()
+    client_key, server_key = generate_keys(config)
+    set_server_key(server_key)
+    return client_key, server_key
+
+
+def test_uint8_encrypt_decrypt(keys):
+    client_key, _ = keys
+    ct = FheUint8.encrypt(213, client_key)
+    assert ct.decrypt(client_key) == 213
+
+
+def test_uint8_arithmetic(keys):
+    client_key, _ = keys
+    a = FheUint8.encrypt(27, client_key)
+    b = FheUint8.encrypt(128, client_key)
+
+    assert (a + b).decrypt(client_key) == (27 + 128) % 256
+    assert (b - a).decrypt(client_key) == (128 - 27) % 256
+    assert (a * b).decrypt(client_key) == (27 * 128) % 256
+    assert a.scalar_add(3).decrypt(client_key) == 30
+
+
+def test_uint8_batch(keys):
+    client_key, _ = keys
+    clears = np.array([0, 1, 2, 200, 255], dtype=np.uint64)
+
+    cts = FheUint8.encrypt_batch(clears, client_key)
+    sums = FheUint8.add_batch(cts, cts)
+    decrypted = FheUint8.decrypt_batch(sums, client_key)
+
+    np.testing.assert_array_equal(decrypted, (clears * 2) % 256)
+
+    with pytest.raises(ValueError):
+        FheUint8.add_batch(cts, cts[:-1])
+
+
+def test_uint8_serialization(keys):
+    client_key, _ = keys
+    ct = FheUint8.encrypt(42, client_key)
+
+    deserialized_ct = FheUint8.deserialize(ct.serialize())
+    deserialized_key = ClientKey.deserialize(client_key.serialize())
+
+    assert deserialized_ct.decrypt(deserialized_key) == 42
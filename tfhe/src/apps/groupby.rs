@@ -0,0 +1,142 @@
+//! Aggregation of encrypted values grouped by a clear key.
+//!
+//! This is the encrypted counterpart of a `SELECT key, SUM(value), COUNT(*) ... GROUP BY key`
+//! query where the keys are public and the values are encrypted: the values of each group are
+//! summed with a parallel tree reduction and the groups are processed in parallel.
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+/// Aggregates of the values of one group.
+#[derive(Clone)]
+pub struct GroupAggregate<PBSOrder: PBSOrderMarker> {
+    /// Encrypted sum of the values of the group, computed on the number of blocks of the values
+    /// and wrapping around on overflow.
+    pub sum: RadixCiphertext<PBSOrder>,
+    /// Number of values in the group.
+    ///
+    /// It is not encrypted as the keys, hence the size of the groups, are public.
+    pub count: usize,
+}
+
+/// Groups the values of `pairs` by key and computes the aggregates of each group.
+///
+/// All the values must have the same number of blocks.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::apps::groupby::group_by_sum;
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+///
+/// let pairs = vec![
+///     ("apples", cks.encrypt(3u64)),
+///     ("pears", cks.encrypt(10u64)),
+///     ("apples", cks.encrypt(5u64)),
+/// ];
+///
+/// let groups = group_by_sum(&sks, &pairs);
+///
+/// let apples = &groups["apples"];
+/// let sum: u64 = cks.decrypt(&apples.sum);
+/// assert_eq!(sum, 8);
+/// assert_eq!(apples.count, 2);
+///
+/// let pears = &groups["pears"];
+/// let sum: u64 = cks.decrypt(&pears.sum);
+/// assert_eq!(sum, 10);
+/// assert_eq!(pears.count, 1);
+/// ```
+pub fn group_by_sum<K, PBSOrder>(
+    server_key: &ServerKey,
+    pairs: &[(K, RadixCiphertext<PBSOrder>)],
+) -> BTreeMap<K, GroupAggregate<PBSOrder>>
+where
+    K: Ord + Clone + Send + Sync,
+    PBSOrder: PBSOrderMarker,
+{
+    let mut groups = BTreeMap::<&K, Vec<&RadixCiphertext<PBSOrder>>>::new();
+    for (key, value) in pairs {
+        groups.entry(key).or_default().push(value);
+    }
+
+    if let Some(first) = pairs.first() {
+        let num_blocks = first.1.blocks.len();
+        assert!(
+            pairs
+                .iter()
+                .all(|(_, value)| value.blocks.len() == num_blocks),
+            "All the values must have the same number of blocks"
+        );
+    }
+
+    groups
+        .into_par_iter()
+        .map(|(key, values)| {
+            let count = values.len();
+            let mut sum = server_key
+                .default_binary_op_seq_parallelized(values, ServerKey::add_parallelized)
+                .unwrap();
+            // A group with a single value is not reduced, its value may have carries
+            if !sum.block_carries_are_empty() {
+                server_key.full_propagate_parallelized(&mut sum);
+            }
+
+            (key.clone(), GroupAggregate { sum, count })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::{gen_keys_radix, RadixCiphertextBig};
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use rand::Rng;
+
+    #[test]
+    fn test_group_by_sum() {
+        let num_blocks = 4;
+        let modulus = 1u64 << 8;
+        let num_groups = 3u8;
+
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+        let mut rng = rand::thread_rng();
+
+        let clear_pairs = (0..10)
+            .map(|_| (rng.gen::<u8>() % num_groups, rng.gen::<u64>() % modulus))
+            .collect::<Vec<_>>();
+        let pairs = clear_pairs
+            .iter()
+            .map(|(key, value)| (*key, cks.encrypt(*value)))
+            .collect::<Vec<_>>();
+
+        let groups = group_by_sum(&sks, &pairs);
+
+        let mut expected = BTreeMap::<u8, (u64, usize)>::new();
+        for (key, value) in &clear_pairs {
+            let entry = expected.entry(*key).or_default();
+            entry.0 = (entry.0 + value) % modulus;
+            entry.1 += 1;
+        }
+
+        assert_eq!(groups.len(), expected.len());
+        for (key, (expected_sum, expected_count)) in expected {
+            let group = &groups[&key];
+            assert!(group.sum.block_carries_are_empty());
+            let sum: u64 = cks.decrypt(&group.sum);
+            assert_eq!(sum, expected_sum);
+            assert_eq!(group.count, expected_count);
+        }
+
+        let no_pairs: &[(u8, RadixCiphertextBig)] = &[];
+        let groups = group_by_sum(&sks, no_pairs);
+        assert!(groups.is_empty());
+    }
+}
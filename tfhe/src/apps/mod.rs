@@ -0,0 +1,7 @@
+//! Building blocks for common applications of homomorphic encryption, written on top of the
+//! [`integer`](crate::integer) API.
+
+pub mod groupby;
+pub mod timeseries;
+#[cfg(feature = "apps-token")]
+pub mod token;
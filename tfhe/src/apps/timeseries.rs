@@ -0,0 +1,296 @@
+//! Rolling aggregates over a stream of encrypted values.
+//!
+//! A [`SlidingWindow`] keeps the last `window_size` values pushed into it and maintains their
+//! sum, minimum and maximum incrementally, so each new value costs a constant number of
+//! homomorphic operations instead of a recomputation of the whole window:
+//!
+//! - the sum is updated by adding the newest value and subtracting the evicted one,
+//! - the minimum (resp. maximum) is tracked with the encrypted counterpart of a monotonic deque:
+//!   for each value of the window, the minimum of the values pushed since then. Pushing a value
+//!   updates all these suffix minima in parallel with an encrypted select, the minimum of the
+//!   window is the suffix minimum of its oldest value.
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+
+/// Aggregates maintained by a [`SlidingWindow`].
+///
+/// Each aggregate costs homomorphic operations on every push, so only the ones that are needed
+/// should be enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowAggregates {
+    pub sum: bool,
+    pub min: bool,
+    pub max: bool,
+}
+
+impl WindowAggregates {
+    pub const ALL: Self = Self {
+        sum: true,
+        min: true,
+        max: true,
+    };
+}
+
+/// A window over the last values of a stream of encrypted integers.
+///
+/// All the values must have the same number of blocks, the sum is computed on this number of
+/// blocks and wraps around on overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::apps::timeseries::{SlidingWindow, WindowAggregates};
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+///
+/// let mut window = SlidingWindow::new(3, WindowAggregates::ALL);
+/// for value in [7u64, 2, 9, 4] {
+///     window.push(&sks, cks.encrypt(value));
+/// }
+///
+/// // The window contains 2, 9 and 4
+/// let sum: u64 = cks.decrypt(window.sum().unwrap());
+/// let min: u64 = cks.decrypt(window.min().unwrap());
+/// let max: u64 = cks.decrypt(window.max().unwrap());
+/// assert_eq!(sum, 15);
+/// assert_eq!(min, 2);
+/// assert_eq!(max, 9);
+/// ```
+#[derive(Clone)]
+pub struct SlidingWindow<PBSOrder: PBSOrderMarker> {
+    window_size: usize,
+    aggregates: WindowAggregates,
+    values: VecDeque<RadixCiphertext<PBSOrder>>,
+    sum: Option<RadixCiphertext<PBSOrder>>,
+    suffix_min: VecDeque<RadixCiphertext<PBSOrder>>,
+    suffix_max: VecDeque<RadixCiphertext<PBSOrder>>,
+}
+
+impl<PBSOrder: PBSOrderMarker> SlidingWindow<PBSOrder> {
+    /// Creates an empty window holding at most `window_size` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is 0.
+    pub fn new(window_size: usize, aggregates: WindowAggregates) -> Self {
+        assert!(window_size > 0, "The window size must be greater than 0");
+
+        Self {
+            window_size,
+            aggregates,
+            values: VecDeque::with_capacity(window_size + 1),
+            sum: None,
+            suffix_min: VecDeque::with_capacity(window_size + 1),
+            suffix_max: VecDeque::with_capacity(window_size + 1),
+        }
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    pub fn aggregates(&self) -> WindowAggregates {
+        self.aggregates
+    }
+
+    /// Number of values currently in the window.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.window_size
+    }
+
+    /// Values of the window, from the oldest to the newest.
+    pub fn values(&self) -> impl Iterator<Item = &RadixCiphertext<PBSOrder>> {
+        self.values.iter()
+    }
+
+    /// Adds `value` to the window and updates the aggregates.
+    ///
+    /// If the window was full, its oldest value is evicted and returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not have the same number of blocks as the values already in the
+    /// window.
+    pub fn push(
+        &mut self,
+        server_key: &ServerKey,
+        mut value: RadixCiphertext<PBSOrder>,
+    ) -> Option<RadixCiphertext<PBSOrder>> {
+        if let Some(newest) = self.values.back() {
+            assert_eq!(
+                newest.blocks.len(),
+                value.blocks.len(),
+                "All the values of a window must have the same number of blocks"
+            );
+        }
+
+        // Clean the value once, rather than in each of the operations it is used in
+        if !value.block_carries_are_empty() {
+            server_key.full_propagate_parallelized(&mut value);
+        }
+
+        let evicted = if self.values.len() == self.window_size {
+            self.values.pop_front()
+        } else {
+            None
+        };
+
+        let update_sum = |sum: &mut Option<RadixCiphertext<PBSOrder>>| {
+            if !self.aggregates.sum {
+                return;
+            }
+            let new_sum = match (sum.as_ref(), evicted.as_ref()) {
+                (None, _) => value.clone(),
+                (Some(sum), None) => server_key.add_parallelized(sum, &value),
+                (Some(sum), Some(evicted)) => {
+                    let difference = server_key.sub_parallelized(&value, evicted);
+                    server_key.add_parallelized(sum, &difference)
+                }
+            };
+            *sum = Some(new_sum);
+        };
+
+        let update_suffixes =
+            |suffixes: &mut VecDeque<RadixCiphertext<PBSOrder>>, enabled: bool, is_min: bool| {
+                if !enabled {
+                    return;
+                }
+                if evicted.is_some() {
+                    suffixes.pop_front();
+                }
+                suffixes.par_iter_mut().for_each(|suffix| {
+                    *suffix = if is_min {
+                        server_key.min_parallelized(suffix, &value)
+                    } else {
+                        server_key.max_parallelized(suffix, &value)
+                    };
+                });
+                suffixes.push_back(value.clone());
+            };
+
+        let (sum, suffix_min, suffix_max) =
+            (&mut self.sum, &mut self.suffix_min, &mut self.suffix_max);
+        rayon::join(
+            || update_sum(sum),
+            || {
+                rayon::join(
+                    || update_suffixes(suffix_min, self.aggregates.min, true),
+                    || update_suffixes(suffix_max, self.aggregates.max, false),
+                )
+            },
+        );
+
+        self.values.push_back(value);
+
+        evicted
+    }
+
+    /// Encrypted sum of the values of the window, `None` if the window is empty or the sum is not
+    /// tracked.
+    pub fn sum(&self) -> Option<&RadixCiphertext<PBSOrder>> {
+        self.sum.as_ref().filter(|_| !self.values.is_empty())
+    }
+
+    /// Encrypted minimum of the values of the window, `None` if the window is empty or the
+    /// minimum is not tracked.
+    pub fn min(&self) -> Option<&RadixCiphertext<PBSOrder>> {
+        self.suffix_min.front()
+    }
+
+    /// Encrypted maximum of the values of the window, `None` if the window is empty or the
+    /// maximum is not tracked.
+    pub fn max(&self) -> Option<&RadixCiphertext<PBSOrder>> {
+        self.suffix_max.front()
+    }
+
+    /// Removes all the values of the window.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.sum = None;
+        self.suffix_min.clear();
+        self.suffix_max.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::gen_keys_radix;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use rand::Rng;
+
+    #[test]
+    fn test_sliding_window_aggregates() {
+        let num_blocks = 4;
+        let modulus = 1u64 << 8;
+        let window_size = 3;
+
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+        let mut rng = rand::thread_rng();
+
+        let mut window = SlidingWindow::new(window_size, WindowAggregates::ALL);
+        let mut clear_window = VecDeque::new();
+
+        for _ in 0..6 {
+            let clear = rng.gen::<u64>() % modulus;
+            let evicted = window.push(&sks, cks.encrypt(clear));
+
+            clear_window.push_back(clear);
+            let clear_evicted = if clear_window.len() > window_size {
+                clear_window.pop_front()
+            } else {
+                None
+            };
+            assert_eq!(evicted.map(|ct| cks.decrypt::<u64, _>(&ct)), clear_evicted);
+            assert_eq!(window.len(), clear_window.len());
+
+            let sum: u64 = cks.decrypt(window.sum().unwrap());
+            let min: u64 = cks.decrypt(window.min().unwrap());
+            let max: u64 = cks.decrypt(window.max().unwrap());
+            assert_eq!(sum, clear_window.iter().sum::<u64>() % modulus);
+            assert_eq!(min, *clear_window.iter().min().unwrap());
+            assert_eq!(max, *clear_window.iter().max().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_disabled_aggregates() {
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, 2);
+
+        let aggregates = WindowAggregates {
+            sum: true,
+            min: false,
+            max: false,
+        };
+        let mut window = SlidingWindow::new(2, aggregates);
+        assert!(window.sum().is_none());
+
+        window.push(&sks, cks.encrypt(3u64));
+        window.push(&sks, cks.encrypt(5u64));
+        window.push(&sks, cks.encrypt(6u64));
+
+        assert!(window.is_full());
+        assert!(window.min().is_none());
+        assert!(window.max().is_none());
+        let sum: u64 = cks.decrypt(window.sum().unwrap());
+        assert_eq!(sum, 11);
+
+        window.clear();
+        assert!(window.is_empty());
+        assert!(window.sum().is_none());
+    }
+}
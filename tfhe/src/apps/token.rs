@@ -0,0 +1,168 @@
+//! Transfers between encrypted balances.
+//!
+//! This is the kernel of a confidential token: the balances and the amount are encrypted, and a
+//! transfer is only applied if the sender has enough funds. The server computes, without learning
+//! whether the transfer went through:
+//!
+//! ```text
+//! success = from_balance >= amount
+//! from_balance = success ? from_balance - amount : from_balance
+//! to_balance = success ? to_balance + amount : to_balance
+//! ```
+//!
+//! The comparison and the subtraction share the same carry propagation (see
+//! [`ServerKey::ge_via_sub_parallelized`]) and the receiver is credited with the amount masked by
+//! the success bit, so no full comparison nor second select is computed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::apps::token::transfer;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // We have 4 * 2 = 8 bits of message
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let alice = cks.encrypt(100u64);
+//! let bob = cks.encrypt(20u64);
+//!
+//! let result = transfer(&sks, &alice, &bob, &cks.encrypt(30u64));
+//! let alice: u64 = cks.decrypt(&result.from_balance);
+//! let bob: u64 = cks.decrypt(&result.to_balance);
+//! assert_eq!((alice, bob), (70, 50));
+//! assert!(cks.decrypt_bool(&result.success));
+//!
+//! // Alice cannot send more than she has
+//! let result = transfer(&sks, &result.from_balance, &result.to_balance, &cks.encrypt(71u64));
+//! let alice: u64 = cks.decrypt(&result.from_balance);
+//! let bob: u64 = cks.decrypt(&result.to_balance);
+//! assert_eq!((alice, bob), (70, 50));
+//! assert!(!cks.decrypt_bool(&result.success));
+//! ```
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+
+/// Balances after a transfer.
+#[derive(Clone)]
+pub struct TransferResult<PBSOrder: PBSOrderMarker> {
+    pub from_balance: RadixCiphertext<PBSOrder>,
+    pub to_balance: RadixCiphertext<PBSOrder>,
+    /// Encrypts true if the amount was transferred, false if the sender did not have enough
+    /// funds.
+    pub success: BooleanBlock<PBSOrder>,
+}
+
+/// Transfers `amount` from `from_balance` to `to_balance` if `from_balance >= amount`, see the
+/// [module documentation](self).
+///
+/// The receiver balance wraps around if it overflows, the number of blocks must be chosen so
+/// that the total supply fits in the balances.
+///
+/// The carries of the inputs are cleared if they are not empty, the carries of the output
+/// balances are always empty.
+///
+/// # Panics
+///
+/// Panics if the ciphertexts do not have the same number of blocks.
+///
+/// # Warning
+///
+/// - Multithreaded
+pub fn transfer<PBSOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    from_balance: &RadixCiphertext<PBSOrder>,
+    to_balance: &RadixCiphertext<PBSOrder>,
+    amount: &RadixCiphertext<PBSOrder>,
+) -> TransferResult<PBSOrder> {
+    let num_blocks = amount.blocks.len();
+    assert!(
+        from_balance.blocks.len() == num_blocks && to_balance.blocks.len() == num_blocks,
+        "The balances and the amount must have the same number of blocks"
+    );
+
+    let mut amount = amount.clone();
+    if !amount.block_carries_are_empty() {
+        server_key.full_propagate_parallelized(&mut amount);
+    }
+
+    let (from_balance, success) = server_key.ge_via_sub_parallelized(from_balance, &amount);
+
+    // success * (amount - 0), the amount if the transfer goes through and 0 otherwise
+    let zero = server_key.create_trivial_zero_radix(num_blocks);
+    let transferred =
+        server_key.unchecked_masked_difference_parallelized(&success.block, &amount, &zero);
+    let to_balance = server_key.add_parallelized(to_balance, &transferred);
+
+    TransferResult {
+        from_balance,
+        to_balance,
+        success,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::gen_keys_radix;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use rand::Rng;
+
+    #[test]
+    fn test_transfer() {
+        let num_blocks = 4;
+        let modulus = 1u64 << 8;
+
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..10 {
+            let from = rng.gen::<u64>() % modulus;
+            let to = rng.gen::<u64>() % (modulus - from);
+            // Half of the time, the sender has enough funds
+            let amount = if rng.gen::<bool>() {
+                rng.gen::<u64>() % (from + 1)
+            } else {
+                rng.gen::<u64>() % modulus
+            };
+
+            let result = transfer(
+                &sks,
+                &cks.encrypt(from),
+                &cks.encrypt(to),
+                &cks.encrypt(amount),
+            );
+
+            assert!(result.from_balance.block_carries_are_empty());
+            assert!(result.to_balance.block_carries_are_empty());
+            let dec_from: u64 = cks.decrypt(&result.from_balance);
+            let dec_to: u64 = cks.decrypt(&result.to_balance);
+            let success = cks.decrypt_bool(&result.success);
+
+            assert_eq!(success, from >= amount);
+            if success {
+                assert_eq!((dec_from, dec_to), (from - amount, to + amount));
+            } else {
+                assert_eq!((dec_from, dec_to), (from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transfer_with_dirty_carries() {
+        let num_blocks = 4;
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+
+        let from = sks.unchecked_add(&cks.encrypt(40u64), &cks.encrypt(23u64));
+        let to = cks.encrypt(5u64);
+        let amount = sks.unchecked_add(&cks.encrypt(20u64), &cks.encrypt(23u64));
+
+        let result = transfer(&sks, &from, &to, &amount);
+        let dec_from: u64 = cks.decrypt(&result.from_balance);
+        let dec_to: u64 = cks.decrypt(&result.to_balance);
+        assert_eq!((dec_from, dec_to), (20, 48));
+        assert!(cks.decrypt_bool(&result.success));
+    }
+}
@@ -0,0 +1,393 @@
+//! Zero-copy archives of ciphertexts and server keys, using [rkyv](https://docs.rs/rkyv).
+//!
+//! Deserializing a server key with serde and bincode copies and validates every coefficient of
+//! the key, which dominates the startup time of services loading large keys. The types of this
+//! module store the raw data of a ciphertext ([`CiphertextParts`]) or of a server key
+//! ([`ServerKeyParts`]) in the rkyv format: the bytes of an archive can be used in place, e.g.
+//! from a memory-mapped file, after a single validation pass done by [`access`].
+//!
+//! The archived types give views over the archived data ([`ArchivedCiphertextParts::lwe_view`],
+//! [`ArchivedServerKeyParts::bootstrapping_key_view`], ...) which can be used directly with the
+//! [`core_crypto`](crate::core_crypto) algorithms, and conversions back to the shortint types
+//! which copy the data once, without decoding it.
+//!
+//! Archives are stored with the endianness of the machine which created them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::archive::{access, to_bytes, CiphertextParts, ServerKeyParts};
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::CiphertextBig;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! // Store the server key once, e.g. in a file later mapped in memory by the server
+//! let sks_bytes = to_bytes(&ServerKeyParts::try_from(&sks).unwrap()).unwrap();
+//!
+//! let ct = cks.encrypt(3);
+//! let ct_bytes = to_bytes(&CiphertextParts::from(&ct)).unwrap();
+//!
+//! // Server side
+//! let sks = access::<ServerKeyParts>(&sks_bytes)
+//!     .unwrap()
+//!     .to_server_key()
+//!     .unwrap();
+//! let ct: CiphertextBig = access::<CiphertextParts>(&ct_bytes)
+//!     .unwrap()
+//!     .to_ciphertext()
+//!     .unwrap();
+//!
+//! let ct_res = sks.scalar_add(&ct, 1);
+//! assert_eq!(cks.decrypt(&ct_res), 0);
+//! ```
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::c64;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyView;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::{
+    CarryModulus, CiphertextBase, CiphertextModulus, MessageModulus, PBSOrder, PBSOrderMarker,
+    ServerKey,
+};
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{AlignedVec, Archive, CheckBytes, Deserialize, Serialize};
+
+/// Size of the scratch space used by [`to_bytes`] before allocating.
+const SCRATCH_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveError {
+    /// The value could not be serialized
+    Serialize(String),
+    /// The bytes are not a valid archive of the requested type
+    Invalid(String),
+    UnexpectedPbsOrder {
+        expected: PBSOrder,
+        got: PBSOrder,
+    },
+    /// Only [`ShortintBootstrappingKey::Classic`] keys can be archived
+    UnsupportedBootstrappingKey,
+}
+
+impl std::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serialize(err) => write!(f, "Could not serialize the archive: {err}"),
+            Self::Invalid(err) => write!(f, "Invalid archive: {err}"),
+            Self::UnexpectedPbsOrder { expected, got } => {
+                write!(f, "Expected PBSOrder: {expected:?}, got {got:?}")
+            }
+            Self::UnsupportedBootstrappingKey => write!(
+                f,
+                "Only server keys with a bootstrapping key in the Fourier domain can be archived"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+/// Serialize `value` into an archive.
+///
+/// The returned buffer is aligned so that [`access`] can be called on it directly.
+pub fn to_bytes<T>(value: &T) -> Result<AlignedVec, ArchiveError>
+where
+    T: Serialize<AllocSerializer<SCRATCH_SIZE>>,
+{
+    rkyv::to_bytes::<T, SCRATCH_SIZE>(value)
+        .map_err(|err| ArchiveError::Serialize(format!("{err:?}")))
+}
+
+/// Validate `bytes` and return the archived value they contain, without copying it.
+///
+/// `bytes` must be aligned on 16 bytes, which is the case of the buffers returned by
+/// [`to_bytes`] and of memory-mapped files.
+pub fn access<'a, T>(bytes: &'a [u8]) -> Result<&'a T::Archived, ArchiveError>
+where
+    T: Archive,
+    T::Archived: CheckBytes<DefaultValidator<'a>>,
+{
+    rkyv::check_archived_root::<T>(bytes).map_err(|err| ArchiveError::Invalid(format!("{err:?}")))
+}
+
+fn pbs_order_to_u8(pbs_order: PBSOrder) -> u8 {
+    match pbs_order {
+        PBSOrder::KeyswitchBootstrap => 0,
+        PBSOrder::BootstrapKeyswitch => 1,
+    }
+}
+
+fn pbs_order_from_u8(pbs_order: u8) -> Result<PBSOrder, ArchiveError> {
+    match pbs_order {
+        0 => Ok(PBSOrder::KeyswitchBootstrap),
+        1 => Ok(PBSOrder::BootstrapKeyswitch),
+        _ => Err(ArchiveError::Invalid(format!(
+            "Unknown PBSOrder tag {pbs_order}"
+        ))),
+    }
+}
+
+/// Rebuild a ciphertext modulus from the value returned by [`CiphertextModulus::get`], only the
+/// native modulus and powers of 2 are valid.
+fn ciphertext_modulus_from_u128(modulus: u128) -> Result<CiphertextModulus, ArchiveError> {
+    if modulus == 0 {
+        return Ok(CiphertextModulus::new_native());
+    }
+    if !modulus.is_power_of_two() {
+        return Err(ArchiveError::Invalid(format!(
+            "Unsupported ciphertext modulus {modulus}"
+        )));
+    }
+    CiphertextModulus::try_new_power_of_2(modulus.trailing_zeros() as usize)
+        .map_err(|err| ArchiveError::Invalid(err.to_string()))
+}
+
+/// The raw data of a shortint ciphertext, see the [module documentation](self).
+#[derive(Archive, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[archive(check_bytes)]
+pub struct CiphertextParts {
+    pub data: Vec<u64>,
+    pub ciphertext_modulus: u128,
+    pub degree: u64,
+    pub message_modulus: u64,
+    pub carry_modulus: u64,
+    pub pbs_order: u8,
+}
+
+impl<OpOrder: PBSOrderMarker> From<&CiphertextBase<OpOrder>> for CiphertextParts {
+    fn from(ct: &CiphertextBase<OpOrder>) -> Self {
+        Self {
+            data: ct.ct.as_ref().to_vec(),
+            ciphertext_modulus: ct.ct.ciphertext_modulus().get(),
+            degree: ct.degree.0 as u64,
+            message_modulus: ct.message_modulus.0 as u64,
+            carry_modulus: ct.carry_modulus.0 as u64,
+            pbs_order: pbs_order_to_u8(OpOrder::pbs_order()),
+        }
+    }
+}
+
+impl ArchivedCiphertextParts {
+    /// Return a view of the archived LWE ciphertext, without copying it.
+    pub fn lwe_view(&self) -> Result<LweCiphertextView<'_, u64>, ArchiveError> {
+        Ok(LweCiphertext::from_container(
+            self.data.as_slice(),
+            ciphertext_modulus_from_u128(self.ciphertext_modulus)?,
+        ))
+    }
+
+    /// Copy the archived ciphertext into a shortint ciphertext.
+    pub fn to_ciphertext<OpOrder: PBSOrderMarker>(
+        &self,
+    ) -> Result<CiphertextBase<OpOrder>, ArchiveError> {
+        let pbs_order = pbs_order_from_u8(self.pbs_order)?;
+        if pbs_order != OpOrder::pbs_order() {
+            return Err(ArchiveError::UnexpectedPbsOrder {
+                expected: OpOrder::pbs_order(),
+                got: pbs_order,
+            });
+        }
+
+        let lwe_view = self.lwe_view()?;
+        Ok(CiphertextBase {
+            ct: LweCiphertextOwned::from_container(
+                lwe_view.as_ref().to_vec(),
+                lwe_view.ciphertext_modulus(),
+            ),
+            degree: Degree(self.degree as usize),
+            message_modulus: MessageModulus(self.message_modulus as usize),
+            carry_modulus: CarryModulus(self.carry_modulus as usize),
+            _order_marker: Default::default(),
+        })
+    }
+}
+
+/// The raw data of a shortint [`ServerKey`], see the [module documentation](self).
+///
+/// The bootstrapping key is stored in the Fourier domain, the real and imaginary parts of its
+/// coefficients being interleaved.
+#[derive(Archive, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[archive(check_bytes)]
+pub struct ServerKeyParts {
+    pub key_switching_key: Vec<u64>,
+    pub ks_base_log: u64,
+    pub ks_level: u64,
+    pub ks_output_lwe_size: u64,
+    pub bootstrapping_key: Vec<f64>,
+    pub bsk_input_lwe_dimension: u64,
+    pub bsk_glwe_size: u64,
+    pub bsk_polynomial_size: u64,
+    pub bsk_base_log: u64,
+    pub bsk_level: u64,
+    pub message_modulus: u64,
+    pub carry_modulus: u64,
+    pub max_degree: u64,
+    pub ciphertext_modulus: u128,
+}
+
+impl TryFrom<&ServerKey> for ServerKeyParts {
+    type Error = ArchiveError;
+
+    fn try_from(server_key: &ServerKey) -> Result<Self, ArchiveError> {
+        let bsk = match &server_key.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(bsk) => bsk,
+            ShortintBootstrappingKey::LazySeeded(_) => {
+                return Err(ArchiveError::UnsupportedBootstrappingKey)
+            }
+        };
+        let ksk = &server_key.key_switching_key;
+
+        let bsk_data = bsk.as_view().data();
+        let bootstrapping_key = bsk_data
+            .iter()
+            .flat_map(|coefficient| [coefficient.re, coefficient.im])
+            .collect();
+
+        Ok(Self {
+            key_switching_key: ksk.as_ref().to_vec(),
+            ks_base_log: ksk.decomposition_base_log().0 as u64,
+            ks_level: ksk.decomposition_level_count().0 as u64,
+            ks_output_lwe_size: ksk.output_lwe_size().0 as u64,
+            bootstrapping_key,
+            bsk_input_lwe_dimension: bsk.input_lwe_dimension().0 as u64,
+            bsk_glwe_size: bsk.glwe_size().0 as u64,
+            bsk_polynomial_size: bsk.polynomial_size().0 as u64,
+            bsk_base_log: bsk.decomposition_base_log().0 as u64,
+            bsk_level: bsk.decomposition_level_count().0 as u64,
+            message_modulus: server_key.message_modulus.0 as u64,
+            carry_modulus: server_key.carry_modulus.0 as u64,
+            max_degree: server_key.max_degree.0 as u64,
+            ciphertext_modulus: server_key.ciphertext_modulus.get(),
+        })
+    }
+}
+
+impl ArchivedServerKeyParts {
+    /// Return a view of the archived key switching key, without copying it.
+    pub fn key_switching_key_view(&self) -> Result<LweKeyswitchKey<&[u64]>, ArchiveError> {
+        Ok(LweKeyswitchKey::from_container(
+            self.key_switching_key.as_slice(),
+            DecompositionBaseLog(self.ks_base_log as usize),
+            DecompositionLevelCount(self.ks_level as usize),
+            LweSize(self.ks_output_lwe_size as usize),
+            ciphertext_modulus_from_u128(self.ciphertext_modulus)?,
+        ))
+    }
+
+    /// Return a view of the archived bootstrapping key, without copying it.
+    pub fn bootstrapping_key_view(&self) -> FourierLweBootstrapKeyView<'_> {
+        let data = self.bootstrapping_key.as_slice();
+        assert_eq!(data.len() % 2, 0);
+        // SAFETY: c64 is a #[repr(C)] pair of f64 (real part then imaginary part), with the same
+        // alignment as f64, and the length of the slice is even.
+        let data =
+            unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<c64>(), data.len() / 2) };
+
+        FourierLweBootstrapKeyView::from_container(
+            data,
+            LweDimension(self.bsk_input_lwe_dimension as usize),
+            GlweSize(self.bsk_glwe_size as usize),
+            PolynomialSize(self.bsk_polynomial_size as usize),
+            DecompositionBaseLog(self.bsk_base_log as usize),
+            DecompositionLevelCount(self.bsk_level as usize),
+        )
+    }
+
+    /// Copy the archived key into a [`ServerKey`].
+    pub fn to_server_key(&self) -> Result<ServerKey, ArchiveError> {
+        let ksk_view = self.key_switching_key_view()?;
+        let key_switching_key = LweKeyswitchKeyOwned::from_container(
+            ksk_view.as_ref().to_vec(),
+            ksk_view.decomposition_base_log(),
+            ksk_view.decomposition_level_count(),
+            ksk_view.output_lwe_size(),
+            ksk_view.ciphertext_modulus(),
+        );
+
+        let bsk_view = self.bootstrapping_key_view();
+        let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
+            bsk_view.input_lwe_dimension(),
+            bsk_view.glwe_size(),
+            bsk_view.polynomial_size(),
+            bsk_view.decomposition_base_log(),
+            bsk_view.decomposition_level_count(),
+        );
+        bootstrapping_key
+            .as_mut_view()
+            .data()
+            .copy_from_slice(bsk_view.data());
+
+        Ok(ServerKey::from_raw_parts(
+            key_switching_key,
+            ShortintBootstrappingKey::Classic(bootstrapping_key),
+            MessageModulus(self.message_modulus as usize),
+            CarryModulus(self.carry_modulus as usize),
+            MaxDegree(self.max_degree as usize),
+            ksk_view.ciphertext_modulus(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::{CiphertextBig, CiphertextSmall};
+
+    #[test]
+    fn test_archive_server_key_and_ciphertexts() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+
+        let sks_bytes = to_bytes(&ServerKeyParts::try_from(sks).unwrap()).unwrap();
+        let archived_sks = access::<ServerKeyParts>(&sks_bytes).unwrap();
+        assert_eq!(
+            archived_sks.key_switching_key_view().unwrap().as_ref(),
+            sks.key_switching_key.as_ref()
+        );
+        let archived_sks = archived_sks.to_server_key().unwrap();
+        assert!(archived_sks == *sks);
+
+        for msg in 0..4 {
+            let ct = cks.encrypt(msg);
+            let ct_bytes = to_bytes(&CiphertextParts::from(&ct)).unwrap();
+            let archived_ct = access::<CiphertextParts>(&ct_bytes).unwrap();
+            assert_eq!(archived_ct.lwe_view().unwrap().as_ref(), ct.ct.as_ref());
+
+            // The PBS order is checked
+            let wrong_order: Result<CiphertextSmall, _> = archived_ct.to_ciphertext();
+            assert!(matches!(
+                wrong_order,
+                Err(ArchiveError::UnexpectedPbsOrder {
+                    expected: PBSOrder::BootstrapKeyswitch,
+                    got: PBSOrder::KeyswitchBootstrap,
+                })
+            ));
+
+            let ct: CiphertextBig = archived_ct.to_ciphertext().unwrap();
+            let ct_res = archived_sks.unchecked_scalar_mul(&ct, 2);
+            assert_eq!(cks.decrypt(&ct_res), (msg * 2) % 4);
+        }
+
+        // Moduli which are neither native nor a power of 2 are rejected
+        let mut parts = CiphertextParts::from(&cks.encrypt(1));
+        parts.ciphertext_modulus = 3;
+        let ct_bytes = to_bytes(&parts).unwrap();
+        let archived_ct = access::<CiphertextParts>(&ct_bytes).unwrap();
+        let invalid_modulus: Result<CiphertextBig, _> = archived_ct.to_ciphertext();
+        assert!(matches!(invalid_modulus, Err(ArchiveError::Invalid(_))));
+
+        // Truncated archives are rejected
+        let ct_bytes = to_bytes(&CiphertextParts::from(&cks.encrypt(1))).unwrap();
+        assert!(matches!(
+            access::<CiphertextParts>(&ct_bytes[..ct_bytes.len() - 8]),
+            Err(ArchiveError::Invalid(_))
+        ));
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! This module implements the ciphertext structure containing an encryption of a Boolean message.
 
+pub mod vector;
+
 use crate::core_crypto::entities::*;
+use crate::size::FheSize;
 use serde::{Deserialize, Serialize};
 
 /// A structure containing a ciphertext, meant to encrypt a Boolean message.
@@ -14,6 +17,22 @@ pub enum Ciphertext {
     Trivial(bool),
 }
 
+impl FheSize for Ciphertext {
+    fn element_count(&self) -> usize {
+        match self {
+            Self::Encrypted(ct) => ct.element_count(),
+            Self::Trivial(_) => 0,
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Encrypted(ct) => ct.heap_size(),
+            Self::Trivial(_) => 0,
+        }
+    }
+}
+
 /// A structure containing a compressed ciphertext, meant to encrypt a Boolean message.
 ///
 /// It has to be decompressed before evaluating a Boolean circuit.
@@ -22,6 +41,8 @@ pub struct CompressedCiphertext {
     pub(crate) ciphertext: SeededLweCiphertext<u32>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for CompressedCiphertext { ciphertext });
+
 impl From<CompressedCiphertext> for Ciphertext {
     fn from(value: CompressedCiphertext) -> Self {
         Self::Encrypted(value.ciphertext.decompress_into_lwe_ciphertext())
@@ -0,0 +1,80 @@
+//! Batches of boolean ciphertexts stored as a struct of arrays.
+//!
+//! A [`BooleanVector`] stores many independent boolean ciphertexts in a single
+//! [`LweCiphertextList`]. The gates on vectors (e.g.
+//! [`ServerKey::vector_and`](crate::boolean::server_key::ServerKey::vector_and) or
+//! [`ServerKey::vector_mux`](crate::boolean::server_key::ServerKey::vector_mux)) evaluate the same
+//! gate on all the elements in one call: the bootstraps of the whole batch are computed in
+//! parallel, followed by the keyswitches with the shared keyswitching key. This is meant for
+//! throughput oriented workloads, like thousands of comparators evaluated side by side, where the
+//! aggregate throughput matters more than the latency of a single gate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::boolean::prelude::*;
+//!
+//! let (cks, sks) = gen_keys();
+//!
+//! let lhs = cks.encrypt_vector(&[false, false, true, true]);
+//! let rhs = cks.encrypt_vector(&[false, true, false, true]);
+//!
+//! let res = sks.vector_xor(&lhs, &rhs);
+//! assert_eq!(cks.decrypt_vector(&res), [false, true, true, false]);
+//! ```
+use crate::boolean::ciphertext::Ciphertext;
+use crate::core_crypto::commons::parameters::LweSize;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use serde::{Deserialize, Serialize};
+
+/// A batch of encrypted booleans.
+///
+/// All the elements are encrypted under the LWE key the gates take as input, trivial ciphertexts
+/// are trivially encrypted when they are put in a vector.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BooleanVector {
+    pub(crate) cts: LweCiphertextListOwned<u32>,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for BooleanVector { cts });
+
+impl BooleanVector {
+    /// Return the number of ciphertexts in the vector.
+    pub fn len(&self) -> usize {
+        self.cts.lwe_ciphertext_count().0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn lwe_size(&self) -> LweSize {
+        self.cts.lwe_size()
+    }
+
+    /// Return the LWE ciphertexts of the vector.
+    pub fn as_lwe_ciphertext_list(&self) -> LweCiphertextListView<'_, u32> {
+        self.cts.as_view()
+    }
+
+    /// Copy the ciphertext at `index` into a [`Ciphertext`], or return `None` if it is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<Ciphertext> {
+        if index >= self.len() {
+            return None;
+        }
+        let ct = self.cts.get(index);
+        Some(Ciphertext::Encrypted(LweCiphertextOwned::from_container(
+            ct.as_ref().to_vec(),
+            ct.ciphertext_modulus(),
+        )))
+    }
+
+    /// Copy all the ciphertexts of the vector into [`Ciphertext`].
+    pub fn to_ciphertexts(&self) -> Vec<Ciphertext> {
+        (0..self.len()).map(|i| self.get(i).unwrap()).collect()
+    }
+}
@@ -3,6 +3,7 @@
 //! This module implements the generation of the client' secret keys, together with the
 //! encryption and decryption methods.
 
+use crate::boolean::ciphertext::vector::BooleanVector;
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
 use crate::boolean::parameters::BooleanParameters;
@@ -25,6 +26,8 @@ pub struct ClientKey {
     pub(crate) parameters: BooleanParameters,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for ClientKey { lwe_secret_key, glwe_secret_key });
+
 impl PartialEq for ClientKey {
     fn eq(&self, other: &Self) -> bool {
         self.parameters == other.parameters
@@ -117,6 +120,31 @@ impl ClientKey {
         BooleanEngine::with_thread_local_mut(|engine| engine.decrypt(ct, self))
     }
 
+    /// Encrypt each of the Boolean messages in a [`BooleanVector`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let msg = [true, false, true];
+    /// let vector = cks.encrypt_vector(&msg);
+    /// assert_eq!(vector.len(), msg.len());
+    ///
+    /// assert_eq!(cks.decrypt_vector(&vector), msg);
+    /// ```
+    pub fn encrypt_vector(&self, messages: &[bool]) -> BooleanVector {
+        BooleanEngine::with_thread_local_mut(|engine| engine.encrypt_vector(messages, self))
+    }
+
+    /// Decrypt each of the ciphertexts of a [`BooleanVector`].
+    pub fn decrypt_vector(&self, vector: &BooleanVector) -> Vec<bool> {
+        BooleanEngine::with_thread_local_mut(|engine| engine.decrypt_vector(vector, self))
+    }
+
     /// Allocate and generate a client key.
     ///
     /// # Example
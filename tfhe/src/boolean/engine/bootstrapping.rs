@@ -3,7 +3,9 @@ use crate::boolean::{ClientKey, PLAINTEXT_TRUE};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
+use crate::core_crypto::commons::math::random::{
+    RandomGeneratorBackend, RuntimeRandomGenerator, Seeder,
+};
 use crate::core_crypto::commons::parameters::CiphertextModulus;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
@@ -88,6 +90,8 @@ pub struct ServerKey {
     pub(crate) key_switching_key: LweKeyswitchKeyOwned<u32>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for ServerKey { bootstrapping_key, key_switching_key });
+
 impl ServerKey {
     pub fn bootstrapping_key_size_elements(&self) -> usize {
         self.bootstrapping_key.as_view().data().as_ref().len()
@@ -122,6 +126,8 @@ pub struct CompressedServerKey {
     pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u32>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for CompressedServerKey { bootstrapping_key, key_switching_key });
+
 /// Perform ciphertext bootstraps on the CPU
 pub(crate) struct Bootstrapper {
     memory: Memory,
@@ -131,18 +137,22 @@ pub(crate) struct Bootstrapper {
     /// The [`EncryptionRandomGenerator`] contains two CSPRNGs, one publicly seeded used to
     /// generate mask coefficients and one privately seeded used to generate errors during
     /// encryption.
-    pub(crate) encryption_generator: EncryptionRandomGenerator<ActivatedRandomGenerator>,
+    pub(crate) encryption_generator: EncryptionRandomGenerator<RuntimeRandomGenerator>,
     pub(crate) computation_buffers: ComputationBuffers,
-    pub(crate) seeder: DeterministicSeeder<ActivatedRandomGenerator>,
+    pub(crate) seeder: DeterministicSeeder<RuntimeRandomGenerator>,
 }
 
 impl Bootstrapper {
-    pub fn new(seeder: &mut dyn Seeder) -> Self {
+    pub fn new(seeder: &mut dyn Seeder, backend: RandomGeneratorBackend) -> Self {
         Bootstrapper {
             memory: Default::default(),
-            encryption_generator: EncryptionRandomGenerator::<_>::new(seeder.seed(), seeder),
+            encryption_generator: EncryptionRandomGenerator::new_with_backend(
+                seeder.seed(),
+                seeder,
+                backend,
+            ),
             computation_buffers: Default::default(),
-            seeder: DeterministicSeeder::<_>::new(seeder.seed()),
+            seeder: DeterministicSeeder::new_with_backend(seeder.seed(), backend),
         }
     }
 
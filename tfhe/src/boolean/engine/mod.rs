@@ -3,6 +3,7 @@
 //! Engines are required to abstract cryptographic notions and efficiently manage memory from the
 //! underlying `core_crypto` module.
 
+use crate::boolean::ciphertext::vector::BooleanVector;
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::parameters::BooleanParameters;
 use crate::boolean::{ClientKey, PublicKey, PLAINTEXT_FALSE, PLAINTEXT_TRUE};
@@ -14,7 +15,9 @@ use crate::boolean::engine::bootstrapping::{Bootstrapper, CompressedServerKey, S
 use crate::core_crypto::commons::generators::{
     DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
+use crate::core_crypto::commons::math::random::{
+    RandomGeneratorBackend, RuntimeRandomGenerator, Seeder,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::seeders::new_seeder;
 
@@ -55,14 +58,14 @@ thread_local! {
 
 pub struct BooleanEngine {
     /// A structure containing a single CSPRNG to generate secret key coefficients.
-    secret_generator: SecretRandomGenerator<ActivatedRandomGenerator>,
+    secret_generator: SecretRandomGenerator<RuntimeRandomGenerator>,
     /// A structure containing two CSPRNGs to generate material for encryption like public masks
     /// and secret errors.
     ///
     /// The [`EncryptionRandomGenerator`] contains two CSPRNGs, one publicly seeded used to
     /// generate mask coefficients and one privately seeded used to generate errors during
     /// encryption.
-    encryption_generator: EncryptionRandomGenerator<ActivatedRandomGenerator>,
+    encryption_generator: EncryptionRandomGenerator<RuntimeRandomGenerator>,
     bootstrapper: Bootstrapper,
 }
 
@@ -229,6 +232,50 @@ impl BooleanEngine {
         }
     }
 
+    pub fn encrypt_vector(&mut self, messages: &[bool], cks: &ClientKey) -> BooleanVector {
+        let mut cts = LweCiphertextList::new(
+            0u32,
+            cks.parameters.lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(messages.len()),
+            CiphertextModulus::new_native(),
+        );
+
+        let encoded = messages
+            .iter()
+            .map(|&message| {
+                if message {
+                    PLAINTEXT_TRUE
+                } else {
+                    PLAINTEXT_FALSE
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // encryption
+        encrypt_lwe_ciphertext_list(
+            &cks.lwe_secret_key,
+            &mut cts,
+            &PlaintextList::from_container(encoded),
+            cks.parameters.lwe_modular_std_dev,
+            &mut self.encryption_generator,
+        );
+
+        BooleanVector { cts }
+    }
+
+    pub fn decrypt_vector(&mut self, vector: &BooleanVector, cks: &ClientKey) -> Vec<bool> {
+        let mut decrypted = PlaintextList::new(0u32, PlaintextCount(vector.len()));
+
+        // decryption
+        decrypt_lwe_ciphertext_list(&cks.lwe_secret_key, &vector.cts, &mut decrypted);
+
+        decrypted
+            .as_ref()
+            .iter()
+            .map(|&plain| plain < (1 << 31))
+            .collect()
+    }
+
     pub fn not(&mut self, ct: &Ciphertext) -> Ciphertext {
         match ct {
             Ciphertext::Trivial(message) => Ciphertext::Trivial(!*message),
@@ -292,24 +339,45 @@ impl BooleanEngine {
     }
 
     pub fn new() -> Self {
+        Self::new_with_backend(RandomGeneratorBackend::default())
+    }
+
+    /// Create a new boolean engine whose random generators use the given backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not
+    /// [available](RandomGeneratorBackend::is_available) on this machine.
+    pub fn new_with_backend(backend: RandomGeneratorBackend) -> Self {
         let mut root_seeder = new_seeder();
 
-        Self::new_from_seeder(root_seeder.as_mut())
+        Self::new_from_seeder_with_backend(root_seeder.as_mut(), backend)
     }
 
     pub fn new_from_seeder(root_seeder: &mut dyn Seeder) -> Self {
+        Self::new_from_seeder_with_backend(root_seeder, RandomGeneratorBackend::default())
+    }
+
+    pub fn new_from_seeder_with_backend(
+        root_seeder: &mut dyn Seeder,
+        backend: RandomGeneratorBackend,
+    ) -> Self {
         let mut deterministic_seeder =
-            DeterministicSeeder::<ActivatedRandomGenerator>::new(root_seeder.seed());
+            DeterministicSeeder::new_with_backend(root_seeder.seed(), backend);
 
         // Note that the operands are evaluated from left to right for Rust Struct expressions
         // See: https://doc.rust-lang.org/stable/reference/expressions.html?highlight=left#evaluation-order-of-operands
         Self {
-            secret_generator: SecretRandomGenerator::<_>::new(deterministic_seeder.seed()),
-            encryption_generator: EncryptionRandomGenerator::<_>::new(
+            secret_generator: SecretRandomGenerator::new_with_backend(
+                deterministic_seeder.seed(),
+                backend,
+            ),
+            encryption_generator: EncryptionRandomGenerator::new_with_backend(
                 deterministic_seeder.seed(),
                 &mut deterministic_seeder,
+                backend,
             ),
-            bootstrapper: Bootstrapper::new(&mut deterministic_seeder),
+            bootstrapper: Bootstrapper::new(&mut deterministic_seeder, backend),
         }
     }
 
@@ -431,6 +499,178 @@ impl BooleanEngine {
             }
         }
     }
+
+    /// Evaluate the gate with the given truth table on `inputs`, `truth_table[i]` being the output
+    /// when the inputs are the bits of `i`, the first input being the most significant bit.
+    ///
+    /// Trivial inputs and inputs the gate does not depend on are removed first. The remaining
+    /// gate is computed with a single bootstrap if [`find_gate_combination`] finds a suitable
+    /// linear combination of the inputs, otherwise it is decomposed on its first input with a
+    /// MUX.
+    pub(crate) fn gate(
+        &mut self,
+        inputs: &[&Ciphertext],
+        truth_table: &[bool],
+        server_key: &ServerKey,
+    ) -> Ciphertext {
+        assert_eq!(truth_table.len(), 1 << inputs.len());
+
+        if truth_table.iter().all(|&output| output == truth_table[0]) {
+            return Ciphertext::Trivial(truth_table[0]);
+        }
+
+        let input_count = inputs.len();
+        for (index, input) in inputs.iter().enumerate() {
+            let bit = input_count - 1 - index;
+            let restricted = |value: bool| {
+                (0..truth_table.len())
+                    .filter(|i| ((i >> bit) & 1 == 1) == value)
+                    .map(|i| truth_table[i])
+                    .collect::<Vec<_>>()
+            };
+
+            let other_inputs = || {
+                inputs
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .map(|(_, input)| *input)
+                    .collect::<Vec<_>>()
+            };
+
+            if let Ciphertext::Trivial(value) = input {
+                return self.gate(&other_inputs(), &restricted(*value), server_key);
+            }
+
+            let (table_false, table_true) = (restricted(false), restricted(true));
+            if table_false == table_true {
+                return self.gate(&other_inputs(), &table_false, server_key);
+            }
+        }
+
+        // All the inputs are encrypted and the gate depends on all of them
+        if let [input] = inputs {
+            return if truth_table[1] {
+                (*input).clone()
+            } else {
+                self.not(input)
+            };
+        }
+
+        match find_gate_combination(truth_table) {
+            Some((coefficients, constant)) => {
+                let mut buffer_lwe_before_pbs = LweCiphertext::new(
+                    0u32,
+                    server_key
+                        .bootstrapping_key
+                        .input_lwe_dimension()
+                        .to_lwe_size(),
+                    CiphertextModulus::new_native(),
+                );
+                let mut buffer_lwe_term = buffer_lwe_before_pbs.clone();
+
+                for (input, coefficient) in inputs.iter().zip(coefficients) {
+                    if coefficient == 0 {
+                        continue;
+                    }
+                    let Ciphertext::Encrypted(input_ct) = input else {
+                        unreachable!("Trivial inputs have been removed")
+                    };
+                    lwe_ciphertext_cleartext_mul(
+                        &mut buffer_lwe_term,
+                        input_ct,
+                        Cleartext(coefficient as u32),
+                    );
+                    lwe_ciphertext_add_assign(&mut buffer_lwe_before_pbs, &buffer_lwe_term);
+                }
+                lwe_ciphertext_plaintext_add_assign(
+                    &mut buffer_lwe_before_pbs,
+                    Plaintext(PLAINTEXT_TRUE.wrapping_mul(constant)),
+                );
+
+                self.bootstrapper
+                    .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
+                    .unwrap()
+            }
+            None => {
+                let half = truth_table.len() / 2;
+                let ct_then = self.gate(&inputs[1..], &truth_table[half..], server_key);
+                let ct_else = self.gate(&inputs[1..], &truth_table[..half], server_key);
+                self.mux(inputs[0], &ct_then, &ct_else, server_key)
+            }
+        }
+    }
+}
+
+/// Find a linear combination `sum(coefficients[i] * input_i) + constant / 8` of the inputs of a
+/// gate whose sign is the output of the gate, if any.
+///
+/// Inputs are encoded as `1/8` for `true` and `-1/8` for `false` and the bootstrap outputs
+/// `true` when its input is in `]0, 1/2[`. A combination is only accepted if the ratio of its
+/// noise variance to its squared distance to `0` and `1/2` is at most the one of the built-in
+/// gates, so the result has the same failure probability.
+pub(crate) fn find_gate_combination(truth_table: &[bool]) -> Option<(Vec<i32>, u32)> {
+    const MAX_COEFFICIENT: i32 = 2;
+    const COEFFICIENT_COUNT: u32 = (2 * MAX_COEFFICIENT + 1) as u32;
+
+    let input_count = truth_table.len().trailing_zeros();
+
+    // (norm2, squared margin, coefficients, constant)
+    let mut best: Option<(i32, i32, Vec<i32>, u32)> = None;
+
+    for encoded_coefficients in 0..COEFFICIENT_COUNT.pow(input_count) {
+        let coefficients = (0..input_count)
+            .map(|i| {
+                (encoded_coefficients / COEFFICIENT_COUNT.pow(i) % COEFFICIENT_COUNT) as i32
+                    - MAX_COEFFICIENT
+            })
+            .collect::<Vec<_>>();
+        let norm2 = coefficients.iter().map(|c| c * c).sum::<i32>();
+
+        'constant: for constant in 0..8u32 {
+            let mut margin = 4;
+            for (index, &output) in truth_table.iter().enumerate() {
+                let combination = coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        let bit = (index >> (input_count as usize - 1 - i)) & 1;
+                        if bit == 1 {
+                            *c
+                        } else {
+                            -c
+                        }
+                    })
+                    .sum::<i32>()
+                    + constant as i32;
+                let value = combination.rem_euclid(8);
+
+                if value % 4 == 0 || (value < 4) != output {
+                    continue 'constant;
+                }
+                margin = margin.min((value % 4).min(4 - value % 4));
+            }
+
+            let margin2 = margin * margin;
+            // The built-in gates have a norm2 of 2 for a margin of 1 (AND) or of 8 for a margin
+            // of 2 (XOR)
+            if norm2 > 2 * margin2 {
+                continue;
+            }
+            let is_better = match &best {
+                None => true,
+                Some((best_norm2, best_margin2, _, _)) => {
+                    let (lhs, rhs) = (norm2 * best_margin2, best_norm2 * margin2);
+                    lhs < rhs || (lhs == rhs && norm2 < *best_norm2)
+                }
+            };
+            if is_better {
+                best = Some((norm2, margin2, coefficients.clone(), constant));
+            }
+        }
+    }
+
+    best.map(|(_, _, coefficients, constant)| (coefficients, constant))
 }
 
 impl BinaryGatesEngine<&Ciphertext, &Ciphertext, ServerKey> for BooleanEngine {
@@ -14,6 +14,8 @@ pub struct PublicKey {
     pub(crate) parameters: BooleanParameters,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for PublicKey { lwe_public_key });
+
 impl PublicKey {
     /// Encrypt a Boolean message using the client key.
     ///
@@ -7,6 +7,7 @@
 
 #[cfg(test)]
 mod tests;
+mod vector;
 
 use crate::boolean::ciphertext::Ciphertext;
 use crate::boolean::client_key::ClientKey;
@@ -154,6 +155,67 @@ impl ServerKey {
             engine.mux(ct_condition, ct_then, ct_else, self)
         })
     }
+
+    /// Evaluate an arbitrary 2-input gate, `truth_table[2 * a + b]` being the output for the
+    /// inputs `a` and `b`.
+    ///
+    /// Any 2-input gate is computed with a single bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_a = cks.encrypt(true);
+    /// let ct_b = cks.encrypt(false);
+    ///
+    /// // a AND NOT b
+    /// let truth_table = [false, false, true, false];
+    /// let ct_res = sks.gate(&ct_a, &ct_b, truth_table);
+    /// assert!(cks.decrypt(&ct_res));
+    /// ```
+    pub fn gate(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, truth_table: [bool; 4]) -> Ciphertext {
+        BooleanEngine::with_thread_local_mut(|engine| {
+            engine.gate(&[ct_a, ct_b], &truth_table, self)
+        })
+    }
+
+    /// Evaluate an arbitrary 3-input gate, `truth_table[4 * a + 2 * b + c]` being the output for
+    /// the inputs `a`, `b` and `c`.
+    ///
+    /// The gate is computed with a single bootstrap when this does not increase the failure
+    /// probability compared to the 2-input gates, otherwise it is decomposed in 2-input gates and
+    /// a MUX on `a`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_a = cks.encrypt(true);
+    /// let ct_b = cks.encrypt(false);
+    /// let ct_c = cks.encrypt(true);
+    ///
+    /// // majority
+    /// let truth_table = [false, false, false, true, false, true, true, true];
+    /// let ct_res = sks.gate3(&ct_a, &ct_b, &ct_c, truth_table);
+    /// assert!(cks.decrypt(&ct_res));
+    /// ```
+    pub fn gate3(
+        &self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        truth_table: [bool; 8],
+    ) -> Ciphertext {
+        BooleanEngine::with_thread_local_mut(|engine| {
+            engine.gate(&[ct_a, ct_b, ct_c], &truth_table, self)
+        })
+    }
 }
 
 impl CompressedServerKey {
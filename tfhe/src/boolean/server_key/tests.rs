@@ -57,6 +57,18 @@ mod default_parameters_tests {
     fn test_deep_circuit_default_parameters() {
         test_deep_circuit(DEFAULT_PARAMETERS);
     }
+    #[test]
+    fn test_custom_gate_default_parameters() {
+        test_custom_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_custom_gate3_default_parameters() {
+        test_custom_gate3(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_vector_gates_default_parameters() {
+        test_vector_gates(DEFAULT_PARAMETERS);
+    }
 }
 
 mod tfhe_lib_parameters_tests {
@@ -103,6 +115,18 @@ mod tfhe_lib_parameters_tests {
     fn test_deep_circuit_tfhe_lib_parameters() {
         test_deep_circuit(TFHE_LIB_PARAMETERS);
     }
+    #[test]
+    fn test_custom_gate_tfhe_lib_parameters() {
+        test_custom_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_custom_gate3_tfhe_lib_parameters() {
+        test_custom_gate3(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_vector_gates_tfhe_lib_parameters() {
+        test_vector_gates(TFHE_LIB_PARAMETERS);
+    }
 }
 
 /// test encryption and decryption with the LWE secret key
@@ -273,6 +297,149 @@ fn test_mux_gate(parameters: BooleanParameters) {
     }
 }
 
+fn test_custom_gate(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // all the 2-input gates
+    for encoded_truth_table in 0..16u8 {
+        let truth_table: [bool; 4] = std::array::from_fn(|i| (encoded_truth_table >> i) & 1 == 1);
+
+        for _ in 0..NB_TEST / 16 {
+            // generation of two random booleans
+            let b1 = random_boolean();
+            let b2 = random_boolean();
+            let expected_result = truth_table[2 * b1 as usize + b2 as usize];
+
+            // encryption of b1
+            let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+            // encryption of b2
+            let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+            // custom gate
+            let ct_res = sks.gate(&ct1, &ct2, truth_table);
+
+            // decryption
+            let dec_gate = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(
+                expected_result, dec_gate,
+                "truth table: {truth_table:?}, a: {ct1:?}, b: {ct2:?}"
+            );
+        }
+    }
+}
+
+fn test_vector_gates(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    let random_vector = || {
+        let clear = (0..NB_TEST).map(|_| random_boolean()).collect::<Vec<_>>();
+        let cts = clear
+            .iter()
+            .map(|&b| random_enum_encryption(&cks, &sks, b))
+            .collect::<Vec<_>>();
+        (clear, sks.vector_from_ciphertexts(&cts))
+    };
+
+    let (clear_1, vector_1) = random_vector();
+    let (clear_2, vector_2) = random_vector();
+    let (clear_3, vector_3) = random_vector();
+    assert_eq!(cks.decrypt_vector(&vector_1), clear_1);
+
+    // all the 2-input gates
+    for encoded_truth_table in 0..16u8 {
+        let truth_table: [bool; 4] = std::array::from_fn(|i| (encoded_truth_table >> i) & 1 == 1);
+
+        let res = sks.vector_gate(&vector_1, &vector_2, truth_table);
+        let expected = clear_1
+            .iter()
+            .zip(clear_2.iter())
+            .map(|(&b1, &b2)| truth_table[2 * b1 as usize + b2 as usize])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cks.decrypt_vector(&res),
+            expected,
+            "truth table: {truth_table:?}"
+        );
+    }
+
+    let res = sks.vector_and(&vector_1, &vector_2);
+    let expected = clear_1
+        .iter()
+        .zip(clear_2.iter())
+        .map(|(&b1, &b2)| b1 && b2)
+        .collect::<Vec<_>>();
+    assert_eq!(cks.decrypt_vector(&res), expected);
+
+    let res = sks.vector_not(&vector_1);
+    let expected = clear_1.iter().map(|&b| !b).collect::<Vec<_>>();
+    assert_eq!(cks.decrypt_vector(&res), expected);
+
+    let res = sks.vector_mux(&vector_1, &vector_2, &vector_3);
+    let expected = clear_1
+        .iter()
+        .zip(clear_2.iter().zip(clear_3.iter()))
+        .map(|(&b1, (&b2, &b3))| if b1 { b2 } else { b3 })
+        .collect::<Vec<_>>();
+    assert_eq!(cks.decrypt_vector(&res), expected);
+
+    // The elements of a vector are regular ciphertexts
+    for (ct, expected) in res.to_ciphertexts().iter().zip(expected.iter()) {
+        let ct_res = sks.and(ct, true);
+        assert_eq!(cks.decrypt(&ct_res), *expected);
+    }
+}
+
+fn test_custom_gate3(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        let encoded_truth_table = random_integer() as u8;
+        let truth_table: [bool; 8] = std::array::from_fn(|i| (encoded_truth_table >> i) & 1 == 1);
+
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = truth_table[4 * b1 as usize + 2 * b2 as usize + b3 as usize];
+
+        // encryption of b1
+        let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+        // encryption of b2
+        let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+        // encryption of b3
+        let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+        // custom gate
+        let ct_res = sks.gate3(&ct1, &ct2, &ct3, truth_table);
+
+        // decryption
+        let dec_gate = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            expected_result, dec_gate,
+            "truth table: {truth_table:?}, a: {ct1:?}, b: {ct2:?}, c: {ct3:?}"
+        );
+    }
+}
+
 fn test_nand_gate(parameters: BooleanParameters) {
     // generate the client key set
     let cks = ClientKey::new(&parameters);
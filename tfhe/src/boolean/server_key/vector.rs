@@ -0,0 +1,361 @@
+use super::ServerKey;
+use crate::boolean::ciphertext::vector::BooleanVector;
+use crate::boolean::ciphertext::Ciphertext;
+use crate::boolean::engine::find_gate_combination;
+use crate::boolean::{PLAINTEXT_FALSE, PLAINTEXT_TRUE};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Copy the given ciphertexts into a [`BooleanVector`], trivial ciphertexts are trivially
+    /// encrypted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let cts = [cks.encrypt(true), sks.trivial_encrypt(false)];
+    /// let vector = sks.vector_from_ciphertexts(&cts);
+    ///
+    /// assert_eq!(cks.decrypt_vector(&vector), [true, false]);
+    /// ```
+    pub fn vector_from_ciphertexts(&self, cts: &[Ciphertext]) -> BooleanVector {
+        let mut vector = self.new_vector(cts.len());
+
+        for (ct, mut output) in cts.iter().zip(vector.cts.iter_mut()) {
+            match ct {
+                Ciphertext::Encrypted(ct) => {
+                    assert_eq!(
+                        ct.lwe_size(),
+                        output.lwe_size(),
+                        "The ciphertexts do not match the server key"
+                    );
+                    output.as_mut().copy_from_slice(ct.as_ref());
+                }
+                Ciphertext::Trivial(message) => {
+                    *output.get_mut_body().data = encode(*message);
+                }
+            }
+        }
+
+        vector
+    }
+
+    /// Trivially encrypt each of the Boolean messages in a [`BooleanVector`].
+    pub fn trivial_encrypt_vector(&self, messages: &[bool]) -> BooleanVector {
+        let mut vector = self.new_vector(messages.len());
+
+        for (&message, mut output) in messages.iter().zip(vector.cts.iter_mut()) {
+            *output.get_mut_body().data = encode(message);
+        }
+
+        vector
+    }
+
+    /// Compute the NOT of each element of the vector, no bootstrap is needed.
+    pub fn vector_not(&self, vector: &BooleanVector) -> BooleanVector {
+        let mut result = vector.clone();
+        self.vector_not_assign(&mut result);
+        result
+    }
+
+    pub fn vector_not_assign(&self, vector: &mut BooleanVector) {
+        vector
+            .cts
+            .par_iter_mut()
+            .for_each(|mut ct| lwe_ciphertext_opposite_assign(&mut ct));
+    }
+
+    /// Evaluate an arbitrary 2-input gate element-wise, `truth_table[2 * a + b]` being the
+    /// output for the inputs `a` of `lhs` and `b` of `rhs`.
+    ///
+    /// The gate costs one bootstrap and one keyswitch per element, the ones of all the elements
+    /// are computed in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let lhs = cks.encrypt_vector(&[false, false, true, true]);
+    /// let rhs = cks.encrypt_vector(&[false, true, false, true]);
+    ///
+    /// // a AND NOT b
+    /// let truth_table = [false, false, true, false];
+    /// let res = sks.vector_gate(&lhs, &rhs, truth_table);
+    /// assert_eq!(cks.decrypt_vector(&res), [false, false, true, false]);
+    /// ```
+    pub fn vector_gate(
+        &self,
+        lhs: &BooleanVector,
+        rhs: &BooleanVector,
+        truth_table: [bool; 4],
+    ) -> BooleanVector {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "Mismatched vector lengths, lhs: {}, rhs: {}",
+            lhs.len(),
+            rhs.len()
+        );
+
+        let (coefficients, constant) = find_gate_combination(&truth_table)
+            .expect("Every 2-input gate can be computed with a single bootstrap");
+
+        let mut buffers_lwe_before_pbs = self.new_vector(lhs.len()).cts;
+
+        buffers_lwe_before_pbs
+            .par_iter_mut()
+            .zip(lhs.cts.par_iter())
+            .zip(rhs.cts.par_iter())
+            .for_each(|((mut buffer_lwe_before_pbs, ct_left), ct_right)| {
+                let mut buffer_lwe_term = LweCiphertext::new(
+                    0u32,
+                    buffer_lwe_before_pbs.lwe_size(),
+                    buffer_lwe_before_pbs.ciphertext_modulus(),
+                );
+
+                for (input, &coefficient) in [ct_left, ct_right].iter().zip(coefficients.iter()) {
+                    if coefficient == 0 {
+                        continue;
+                    }
+                    lwe_ciphertext_cleartext_mul(
+                        &mut buffer_lwe_term,
+                        input,
+                        Cleartext(coefficient as u32),
+                    );
+                    lwe_ciphertext_add_assign(&mut buffer_lwe_before_pbs, &buffer_lwe_term);
+                }
+                lwe_ciphertext_plaintext_add_assign(
+                    &mut buffer_lwe_before_pbs,
+                    Plaintext(PLAINTEXT_TRUE.wrapping_mul(constant)),
+                );
+            });
+
+        let buffers_lwe_after_pbs = self.vector_bootstrap(&buffers_lwe_before_pbs);
+
+        BooleanVector {
+            cts: self.vector_keyswitch(&buffers_lwe_after_pbs),
+        }
+    }
+
+    pub fn vector_and(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [false, false, false, true])
+    }
+
+    pub fn vector_nand(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [true, true, true, false])
+    }
+
+    pub fn vector_nor(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [true, false, false, false])
+    }
+
+    pub fn vector_or(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [false, true, true, true])
+    }
+
+    pub fn vector_xor(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [false, true, true, false])
+    }
+
+    pub fn vector_xnor(&self, lhs: &BooleanVector, rhs: &BooleanVector) -> BooleanVector {
+        self.vector_gate(lhs, rhs, [true, false, false, true])
+    }
+
+    /// Compute the MUX of each element of the vectors, selecting the element of `ct_then` where
+    /// `ct_condition` is true and the element of `ct_else` otherwise.
+    ///
+    /// As for a single MUX, each element costs two bootstraps and one keyswitch, the bootstraps
+    /// of all the elements are computed in a single parallel batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_condition = cks.encrypt_vector(&[true, false, true]);
+    /// let ct_then = cks.encrypt_vector(&[true, true, false]);
+    /// let ct_else = cks.encrypt_vector(&[false, false, true]);
+    ///
+    /// let res = sks.vector_mux(&ct_condition, &ct_then, &ct_else);
+    /// assert_eq!(cks.decrypt_vector(&res), [true, false, false]);
+    /// ```
+    pub fn vector_mux(
+        &self,
+        ct_condition: &BooleanVector,
+        ct_then: &BooleanVector,
+        ct_else: &BooleanVector,
+    ) -> BooleanVector {
+        assert!(
+            ct_condition.len() == ct_then.len() && ct_condition.len() == ct_else.len(),
+            "Mismatched vector lengths, condition: {}, then: {}, else: {}",
+            ct_condition.len(),
+            ct_then.len(),
+            ct_else.len()
+        );
+
+        let count = ct_condition.len();
+
+        // The first half holds the inputs of the first AND, the second half the inputs of the
+        // second one
+        let mut buffers_lwe_before_pbs = self.new_vector(2 * count).cts;
+        let (mut first_inputs, mut second_inputs) = buffers_lwe_before_pbs.split_at_mut(count);
+
+        first_inputs
+            .par_iter_mut()
+            .zip(second_inputs.par_iter_mut())
+            .zip(ct_condition.cts.par_iter())
+            .zip(ct_then.cts.par_iter().zip(ct_else.cts.par_iter()))
+            .for_each(
+                |(((mut first_input, mut second_input), ct_condition), (ct_then, ct_else))| {
+                    // ct_condition + ct_then + (0,...,0,-1/8)
+                    lwe_ciphertext_add(&mut first_input, &ct_condition, &ct_then);
+                    lwe_ciphertext_plaintext_add_assign(
+                        &mut first_input,
+                        Plaintext(PLAINTEXT_FALSE),
+                    );
+
+                    // - ct_condition + ct_else + (0,...,0,-1/8)
+                    lwe_ciphertext_sub(&mut second_input, &ct_else, &ct_condition);
+                    lwe_ciphertext_plaintext_add_assign(
+                        &mut second_input,
+                        Plaintext(PLAINTEXT_FALSE),
+                    );
+                },
+            );
+
+        let mut buffers_lwe_after_pbs = self.vector_bootstrap(&buffers_lwe_before_pbs);
+
+        // Add the results of the two ANDs + (0,...,0,+1/8)
+        let (mut first_outputs, second_outputs) = buffers_lwe_after_pbs.split_at_mut(count);
+        first_outputs
+            .par_iter_mut()
+            .zip(second_outputs.par_iter())
+            .for_each(|(mut first_output, second_output)| {
+                lwe_ciphertext_add_assign(&mut first_output, &second_output);
+                lwe_ciphertext_plaintext_add_assign(&mut first_output, Plaintext(PLAINTEXT_TRUE));
+            });
+
+        let mut result = self.new_vector(count);
+        result
+            .cts
+            .par_iter_mut()
+            .zip(first_outputs.par_iter())
+            .for_each(|(mut output, input)| {
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &input, &mut output);
+            });
+
+        result
+    }
+
+    /// Allocate a vector of `count` ciphertexts under the input key of the gates.
+    fn new_vector(&self, count: usize) -> BooleanVector {
+        BooleanVector {
+            cts: LweCiphertextList::new(
+                0u32,
+                self.bootstrapping_key.input_lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(count),
+                CiphertextModulus::new_native(),
+            ),
+        }
+    }
+
+    /// Bootstrap all the ciphertexts of `inputs` in parallel, with the accumulator of the gates.
+    fn vector_bootstrap(
+        &self,
+        inputs: &LweCiphertextListOwned<u32>,
+    ) -> LweCiphertextListOwned<u32> {
+        let fourier_bsk = &self.bootstrapping_key;
+
+        let mut outputs = LweCiphertextList::new(
+            0u32,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            inputs.lwe_ciphertext_count(),
+            inputs.ciphertext_modulus(),
+        );
+
+        let mut accumulator = GlweCiphertext::new(
+            0u32,
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            inputs.ciphertext_modulus(),
+        );
+        accumulator.get_mut_body().as_mut().fill(PLAINTEXT_TRUE);
+
+        let fft = Fft::new(fourier_bsk.polynomial_size());
+        let scratch_bytes = programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u32>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft.as_view(),
+        )
+        .unwrap()
+        .unaligned_bytes_required();
+
+        inputs.par_iter().zip(outputs.par_iter_mut()).for_each_init(
+            || {
+                let mut buffers = ComputationBuffers::new();
+                buffers.resize(scratch_bytes);
+                buffers
+            },
+            |buffers, (input, mut output)| {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    &input,
+                    &mut output,
+                    &accumulator,
+                    fourier_bsk,
+                    fft.as_view(),
+                    buffers.stack(),
+                );
+            },
+        );
+
+        outputs
+    }
+
+    /// Keyswitch all the ciphertexts of `inputs` in parallel back to the input key of the gates.
+    fn vector_keyswitch(
+        &self,
+        inputs: &LweCiphertextListOwned<u32>,
+    ) -> LweCiphertextListOwned<u32> {
+        let mut outputs = self.new_vector(inputs.lwe_ciphertext_count().0).cts;
+
+        inputs
+            .par_iter()
+            .zip(outputs.par_iter_mut())
+            .for_each(|(input, mut output)| {
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &input, &mut output);
+            });
+
+        outputs
+    }
+}
+
+fn encode(message: bool) -> u32 {
+    if message {
+        PLAINTEXT_TRUE
+    } else {
+        PLAINTEXT_FALSE
+    }
+}
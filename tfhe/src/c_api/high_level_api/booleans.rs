@@ -1,4 +1,4 @@
-use crate::high_level_api::prelude::*;
+use crate::prelude::*;
 
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
@@ -3,15 +3,24 @@ use std::os::raw::c_int;
 
 pub struct ClientKey(pub(crate) crate::high_level_api::ClientKey);
 pub struct PublicKey(pub(crate) crate::high_level_api::PublicKey);
+pub struct CompressedPublicKey(pub(crate) crate::high_level_api::CompressedPublicKey);
 pub struct ServerKey(pub(crate) crate::high_level_api::ServerKey);
+#[cfg(feature = "integer")]
+pub struct CompactPublicKey(pub(crate) crate::high_level_api::CompactPublicKey);
 
 impl_destroy_on_type!(ClientKey);
 impl_destroy_on_type!(PublicKey);
+impl_destroy_on_type!(CompressedPublicKey);
 impl_destroy_on_type!(ServerKey);
+#[cfg(feature = "integer")]
+impl_destroy_on_type!(CompactPublicKey);
 
 impl_serialize_deserialize_on_type!(ClientKey);
 impl_serialize_deserialize_on_type!(PublicKey);
+impl_serialize_deserialize_on_type!(CompressedPublicKey);
 impl_serialize_deserialize_on_type!(ServerKey);
+#[cfg(feature = "integer")]
+impl_serialize_deserialize_on_type!(CompactPublicKey);
 
 #[no_mangle]
 pub unsafe extern "C" fn generate_keys(
@@ -69,3 +78,30 @@ pub unsafe extern "C" fn public_key_new(
         *result_public_key = Box::into_raw(Box::new(PublicKey(inner)));
     })
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn compressed_public_key_new(
+    client_key: *const ClientKey,
+    result_public_key: *mut *mut CompressedPublicKey,
+) -> c_int {
+    catch_panic(|| {
+        let client_key = get_ref_checked(client_key).unwrap();
+        let inner = crate::high_level_api::CompressedPublicKey::new(&client_key.0);
+
+        *result_public_key = Box::into_raw(Box::new(CompressedPublicKey(inner)));
+    })
+}
+
+#[cfg(feature = "integer")]
+#[no_mangle]
+pub unsafe extern "C" fn compact_public_key_new(
+    client_key: *const ClientKey,
+    result_public_key: *mut *mut CompactPublicKey,
+) -> c_int {
+    catch_panic(|| {
+        let client_key = get_ref_checked(client_key).unwrap();
+        let inner = crate::high_level_api::CompactPublicKey::new(&client_key.0);
+
+        *result_public_key = Box::into_raw(Box::new(CompactPublicKey(inner)));
+    })
+}
@@ -186,3 +186,89 @@ pub unsafe extern "C" fn shortint_deserialize_compressed_ciphertext(
         *result = Box::into_raw(heap_allocated_ciphertext);
     })
 }
+
+pub struct ShortintCompactCiphertextList(pub(in crate::c_api) shortint::CompactCiphertextList);
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_compact_ciphertext_list_len(
+    compact_list: *const ShortintCompactCiphertextList,
+    result: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let compact_list = get_ref_checked(compact_list).unwrap();
+
+        *result = compact_list.0.len();
+    })
+}
+
+/// Expand the compact list into `output_len` ciphertexts written in `output`.
+///
+/// `output_len` must be the length of the list as returned by
+/// `shortint_compact_ciphertext_list_len`.
+#[no_mangle]
+pub unsafe extern "C" fn shortint_compact_ciphertext_list_expand(
+    compact_list: *const ShortintCompactCiphertextList,
+    output: *mut *mut ShortintCiphertext,
+    output_len: usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(output).unwrap();
+
+        let output = std::slice::from_raw_parts_mut(output, output_len);
+
+        // First fill the result with null ptrs so that if we fail and the return code is not
+        // checked, then any access to the result pointers will segfault (mimics malloc on failure)
+        output.fill(std::ptr::null_mut());
+
+        let compact_list = get_ref_checked(compact_list).unwrap();
+
+        assert_eq!(
+            compact_list.0.len(),
+            output_len,
+            "The output length must be equal to the length of the compact list"
+        );
+
+        for (result, ciphertext) in output.iter_mut().zip(compact_list.0.expand()) {
+            let heap_allocated_ciphertext = Box::new(ShortintCiphertext(ciphertext.into()));
+            *result = Box::into_raw(heap_allocated_ciphertext);
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_serialize_compact_ciphertext_list(
+    compact_list: *const ShortintCompactCiphertextList,
+    result: *mut Buffer,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let compact_list = get_ref_checked(compact_list).unwrap();
+
+        let buffer: Buffer = bincode::serialize(&compact_list.0).unwrap().into();
+
+        *result = buffer;
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_deserialize_compact_ciphertext_list(
+    buffer_view: BufferView,
+    result: *mut *mut ShortintCompactCiphertextList,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let compact_list = bincode::deserialize(buffer_view.into()).unwrap();
+
+        let heap_allocated_compact_list = Box::new(ShortintCompactCiphertextList(compact_list));
+
+        *result = Box::into_raw(heap_allocated_compact_list);
+    })
+}
@@ -4,8 +4,9 @@ use std::os::raw::c_int;
 use super::parameters::ShortintParameters;
 use super::{
     ShortintBivariatePBSLookupTable, ShortintCiphertext, ShortintClientKey,
-    ShortintCompressedCiphertext, ShortintCompressedPublicKey, ShortintCompressedServerKey,
-    ShortintPBSLookupTable, ShortintPublicKey, ShortintServerKey,
+    ShortintCompactCiphertextList, ShortintCompactPublicKey, ShortintCompressedCiphertext,
+    ShortintCompressedPublicKey, ShortintCompressedServerKey, ShortintPBSLookupTable,
+    ShortintPublicKey, ShortintServerKey,
 };
 
 #[no_mangle]
@@ -57,6 +58,17 @@ pub unsafe extern "C" fn destroy_shortint_compressed_public_key(
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn destroy_shortint_compact_public_key(
+    compact_public_key: *mut ShortintCompactPublicKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(compact_public_key).unwrap();
+
+        drop(Box::from_raw(compact_public_key));
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn destroy_shortint_parameters(
     shortint_parameters: *mut ShortintParameters,
@@ -90,6 +102,17 @@ pub unsafe extern "C" fn destroy_shortint_compressed_ciphertext(
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn destroy_shortint_compact_ciphertext_list(
+    compact_list: *mut ShortintCompactCiphertextList,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(compact_list).unwrap();
+
+        drop(Box::from_raw(compact_list));
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn destroy_shortint_pbs_accumulator(
     pbs_accumulator: *mut ShortintPBSLookupTable,
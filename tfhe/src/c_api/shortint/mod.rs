@@ -11,9 +11,12 @@ use std::os::raw::c_int;
 use crate::shortint;
 
 pub(in crate::c_api) use ciphertext::ShortintCiphertextInner;
-pub use ciphertext::{ShortintCiphertext, ShortintCiphertextKind, ShortintCompressedCiphertext};
+pub use ciphertext::{
+    ShortintCiphertext, ShortintCiphertextKind, ShortintCompactCiphertextList,
+    ShortintCompressedCiphertext,
+};
 pub use client_key::ShortintClientKey;
-pub use public_key::{ShortintCompressedPublicKey, ShortintPublicKey};
+pub use public_key::{ShortintCompactPublicKey, ShortintCompressedPublicKey, ShortintPublicKey};
 pub use server_key::pbs::{ShortintBivariatePBSLookupTable, ShortintPBSLookupTable};
 pub use server_key::{ShortintCompressedServerKey, ShortintServerKey};
 
@@ -130,6 +130,7 @@ pub unsafe extern "C" fn shortint_create_parameters(
                 polynomial_size: PolynomialSize(polynomial_size),
                 lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
                 glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+                noise_distribution: crate::shortint::parameters::NoiseDistributionKind::Gaussian,
                 pbs_base_log: DecompositionBaseLog(pbs_base_log),
                 pbs_level: DecompositionLevelCount(pbs_level),
                 ks_base_log: DecompositionBaseLog(ks_base_log),
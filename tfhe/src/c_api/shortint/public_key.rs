@@ -5,7 +5,7 @@ use std::os::raw::c_int;
 
 use crate::shortint;
 
-use super::{ShortintCiphertext, ShortintClientKey};
+use super::{ShortintCiphertext, ShortintClientKey, ShortintCompactCiphertextList};
 
 #[repr(C)]
 pub enum ShortintPublicKeyKind {
@@ -246,3 +246,117 @@ pub unsafe extern "C" fn shortint_decompress_public_key(
         *result = Box::into_raw(heap_allocated_public_key);
     })
 }
+
+pub struct ShortintCompactPublicKey(pub(in crate::c_api) shortint::CompactPublicKey);
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_gen_compact_public_key(
+    client_key: *const ShortintClientKey,
+    result: *mut *mut ShortintCompactPublicKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let client_key = get_ref_checked(client_key).unwrap();
+
+        let heap_allocated_public_key = Box::new(ShortintCompactPublicKey(
+            shortint::CompactPublicKey::new(&client_key.0),
+        ));
+
+        *result = Box::into_raw(heap_allocated_public_key);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_compact_public_key_encrypt(
+    compact_public_key: *const ShortintCompactPublicKey,
+    value_to_encrypt: u64,
+    result: *mut *mut ShortintCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let compact_public_key = get_ref_checked(compact_public_key).unwrap();
+
+        let heap_allocated_ciphertext = Box::new(ShortintCiphertext(
+            compact_public_key.0.encrypt(value_to_encrypt).into(),
+        ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_compact_public_key_encrypt_slice(
+    compact_public_key: *const ShortintCompactPublicKey,
+    values_to_encrypt: *const u64,
+    values_len: usize,
+    result: *mut *mut ShortintCompactCiphertextList,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let compact_public_key = get_ref_checked(compact_public_key).unwrap();
+
+        let values_to_encrypt: &[u64] = if values_len == 0 {
+            &[]
+        } else {
+            check_ptr_is_non_null_and_aligned(values_to_encrypt).unwrap();
+            std::slice::from_raw_parts(values_to_encrypt, values_len)
+        };
+
+        let heap_allocated_compact_list = Box::new(ShortintCompactCiphertextList(
+            compact_public_key.0.encrypt_slice(values_to_encrypt),
+        ));
+
+        *result = Box::into_raw(heap_allocated_compact_list);
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_serialize_compact_public_key(
+    compact_public_key: *const ShortintCompactPublicKey,
+    result: *mut Buffer,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let compact_public_key = get_ref_checked(compact_public_key).unwrap();
+
+        let buffer: Buffer = bincode::serialize(&compact_public_key.0).unwrap().into();
+
+        *result = buffer;
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shortint_deserialize_compact_public_key(
+    buffer_view: BufferView,
+    result: *mut *mut ShortintCompactPublicKey,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let compact_public_key = bincode::deserialize(buffer_view.into()).unwrap();
+
+        let heap_allocated_public_key = Box::new(ShortintCompactPublicKey(compact_public_key));
+
+        *result = Box::into_raw(heap_allocated_public_key);
+    })
+}
@@ -3,7 +3,7 @@
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
@@ -73,7 +73,7 @@ pub fn encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -211,7 +211,7 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -298,7 +298,7 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
     (row_index, last_row_index): (usize, usize),
     factor: Scalar,
     row_as_glwe: &mut GlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -345,7 +345,7 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -465,7 +465,7 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Nois
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -520,7 +520,7 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -641,7 +641,7 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext<Scalar, KeyCont, OutputCont,
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -691,7 +691,7 @@ fn encrypt_constant_seeded_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Ge
     (row_index, last_row_index): (usize, usize),
     factor: Scalar,
     row_as_glwe: &mut SeededGlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
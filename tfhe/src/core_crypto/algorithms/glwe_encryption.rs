@@ -5,7 +5,7 @@ use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::{
     slice_wrapping_scalar_div_assign, slice_wrapping_scalar_mul_assign,
 };
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
@@ -18,7 +18,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_mask: &mut GlweMask<MaskCont>,
     output_body: &mut GlweBody<BodyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -134,7 +134,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
 pub fn encrypt_glwe_ciphertext_assign<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -181,7 +181,7 @@ pub fn encrypt_seeded_glwe_ciphertext_assign_with_existing_generator<
 >(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -233,7 +233,7 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
     output_mask: &mut GlweMask<MaskCont>,
     output_body: &mut GlweBody<BodyCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -352,7 +352,7 @@ pub fn encrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -481,7 +481,7 @@ pub fn encrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont, Gen>
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -831,7 +831,7 @@ pub fn encrypt_seeded_glwe_ciphertext_with_exsiting_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut SeededGlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -963,7 +963,7 @@ pub fn encrypt_seeded_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont, No
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut SeededGlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1004,7 +1004,7 @@ pub fn encrypt_seeded_glwe_ciphertext_list_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1145,7 +1145,7 @@ pub fn encrypt_seeded_glwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCon
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1,12 +1,13 @@
 //! Module containing primitives pertaining to the operation usually referred to as a
 //! _sample extract_ in the literature. Allowing to extract a single
-//! [`LWE Ciphertext`](`LweCiphertext`) from a given [`GLWE ciphertext`](`GlweCiphertext`).
+//! [`LWE Ciphertext`](`LweCiphertext`) from a given [`GLWE ciphertext`](`GlweCiphertext`), or all
+//! of them at once in an [`LWE ciphertext list`](`LweCiphertextList`).
 
-use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{MonomialDegree, *};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use rayon::prelude::*;
 
 /// Extract the nth coefficient from the body of a [`GLWE Ciphertext`](`GlweCiphertext`) as an
 /// [`LWE ciphertext`](`LweCiphertext`).
@@ -112,6 +113,172 @@ pub fn extract_lwe_sample_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
         output_lwe.ciphertext_modulus()
     );
 
+    extract_sample(input_glwe, output_lwe, nth);
+}
+
+/// Extract all the coefficients from the body of a [`GLWE Ciphertext`](`GlweCiphertext`) as
+/// [`LWE ciphertexts`](`LweCiphertext`), the ith ciphertext of the output list encrypting the ith
+/// coefficient.
+///
+/// This is equivalent to calling [`extract_lwe_sample_from_glwe_ciphertext`] for each
+/// [`MonomialDegree`] of the polynomials of the input ciphertext, see
+/// [`par_extract_all_lwe_samples_from_glwe_ciphertext`] for a parallel variant.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext, each coefficient encrypting its index modulo 16
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// for (index, plaintext) in plaintext_list.iter_mut().enumerate() {
+///     *plaintext.0 = (index as u64 % 16) << 60;
+/// }
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// // Now we get the equivalent LweSecretKey from the GlweSecretKey
+/// let equivalent_lwe_sk = glwe_secret_key.clone().into_lwe_secret_key();
+///
+/// let mut extracted_samples = LweCiphertextList::new(
+///     0u64,
+///     equivalent_lwe_sk.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(polynomial_size.0),
+///     ciphertext_modulus,
+/// );
+///
+/// extract_all_lwe_samples_from_glwe_ciphertext(&glwe, &mut extracted_samples);
+///
+/// let mut decrypted_plaintexts =
+///     PlaintextList::new(0u64, PlaintextCount(extracted_samples.lwe_ciphertext_count().0));
+/// decrypt_lwe_ciphertext_list(
+///     &equivalent_lwe_sk,
+///     &extracted_samples,
+///     &mut decrypted_plaintexts,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// for (index, plaintext) in decrypted_plaintexts.iter().enumerate() {
+///     let recovered_message = decomposer.closest_representable(*plaintext.0) >> 60;
+///     assert_eq!(recovered_message, index as u64 % 16);
+/// }
+/// ```
+pub fn extract_all_lwe_samples_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
+    input_glwe: &GlweCiphertext<InputCont>,
+    output_lwe_list: &mut LweCiphertextList<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_extract_all_lwe_samples_parameters(input_glwe, output_lwe_list);
+
+    for (nth, mut output_lwe) in output_lwe_list.iter_mut().enumerate() {
+        extract_sample(input_glwe, &mut output_lwe, MonomialDegree(nth));
+    }
+}
+
+/// Parallel variant of [`extract_all_lwe_samples_from_glwe_ciphertext`].
+///
+/// See [`extract_all_lwe_samples_from_glwe_ciphertext`] for usage.
+pub fn par_extract_all_lwe_samples_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
+    input_glwe: &GlweCiphertext<InputCont>,
+    output_lwe_list: &mut LweCiphertextList<OutputCont>,
+) where
+    Scalar: UnsignedInteger + Sync + Send,
+    InputCont: Container<Element = Scalar> + Sync,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_extract_all_lwe_samples_parameters(input_glwe, output_lwe_list);
+
+    output_lwe_list
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(nth, mut output_lwe)| {
+            extract_sample(input_glwe, &mut output_lwe, MonomialDegree(nth));
+        });
+}
+
+fn check_extract_all_lwe_samples_parameters<Scalar, InputCont, OutputCont>(
+    input_glwe: &GlweCiphertext<InputCont>,
+    output_lwe_list: &LweCiphertextList<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: Container<Element = Scalar>,
+{
+    assert!(
+        input_glwe.glwe_size().to_glwe_dimension().0 * input_glwe.polynomial_size().0
+            == output_lwe_list.lwe_size().to_lwe_dimension().0,
+        "Mismatch between equivalent LweDimension of input ciphertext and output ciphertexts. \
+        Got {:?} for input and {:?} for output.",
+        LweDimension(input_glwe.glwe_size().to_glwe_dimension().0 * input_glwe.polynomial_size().0),
+        output_lwe_list.lwe_size().to_lwe_dimension(),
+    );
+
+    assert_eq!(
+        output_lwe_list.lwe_ciphertext_count().0,
+        input_glwe.polynomial_size().0,
+        "Mismatch between the output LweCiphertextCount ({:?}) and the input PolynomialSize ({:?})",
+        output_lwe_list.lwe_ciphertext_count(),
+        input_glwe.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_glwe.ciphertext_modulus(),
+        output_lwe_list.ciphertext_modulus(),
+        "Mismatched moduli between input_glwe ({:?}) and output_lwe_list ({:?})",
+        input_glwe.ciphertext_modulus(),
+        output_lwe_list.ciphertext_modulus()
+    );
+}
+
+fn extract_sample<Scalar, InputCont, OutputCont>(
+    input_glwe: &GlweCiphertext<InputCont>,
+    output_lwe: &mut LweCiphertext<OutputCont>,
+    nth: MonomialDegree,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = input_glwe.polynomial_size().0;
+
     // We retrieve the bodies and masks of the two ciphertexts.
     let (mut lwe_mask, lwe_body) = output_lwe.get_mut_mask_and_body();
     let (glwe_mask, glwe_body) = input_glwe.get_mask_and_body();
@@ -119,23 +286,22 @@ pub fn extract_lwe_sample_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
     // We copy the body
     *lwe_body.data = glwe_body.as_ref()[nth.0];
 
-    // We copy the mask (each polynomial is in the wrong order)
-    lwe_mask.as_mut().copy_from_slice(glwe_mask.as_ref());
-
-    // We compute the number of elements which must be
-    // turned into their opposite
-    let opposite_count = input_glwe.polynomial_size().0 - nth.0 - 1;
-
-    // We loop through the polynomials
-    for lwe_mask_poly in lwe_mask
+    // We loop through the polynomials, the coefficients of the mask being those of the product
+    // with X^(-nth) in reverse order, i.e. for a polynomial A of size N
+    // lwe_mask[i] = A[nth - i] for i <= nth and lwe_mask[i] = -A[N + nth - i] otherwise
+    for (lwe_mask_poly, glwe_mask_poly) in lwe_mask
         .as_mut()
-        .chunks_exact_mut(input_glwe.polynomial_size().0)
+        .chunks_exact_mut(polynomial_size)
+        .zip(glwe_mask.as_ref().chunks_exact(polynomial_size))
     {
-        // We reverse the polynomial
-        lwe_mask_poly.reverse();
-        // We compute the opposite of the proper coefficients
-        slice_wrapping_opposite_assign(&mut lwe_mask_poly[0..opposite_count]);
-        // We rotate the polynomial properly
-        lwe_mask_poly.rotate_left(opposite_count);
+        let (lwe_low, lwe_high) = lwe_mask_poly.split_at_mut(nth.0 + 1);
+        let (glwe_low, glwe_high) = glwe_mask_poly.split_at(nth.0 + 1);
+
+        for (dst, src) in lwe_low.iter_mut().zip(glwe_low.iter().rev()) {
+            *dst = *src;
+        }
+        for (dst, src) in lwe_high.iter_mut().zip(glwe_high.iter().rev()) {
+            *dst = src.wrapping_neg();
+        }
     }
 }
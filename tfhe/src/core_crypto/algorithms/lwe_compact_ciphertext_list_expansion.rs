@@ -0,0 +1,80 @@
+//! Module containing primitives pertaining to the expansion of
+//! [`LWE compact ciphertext lists`](`LweCompactCiphertextList`).
+
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Expand an [`LWE compact ciphertext list`](`LweCompactCiphertextList`) into an
+/// [`LWE ciphertext list`](`LweCiphertextList`) containing the same number of ciphertexts.
+///
+/// The $k$-th ciphertext of a group of $n$ ciphertexts sharing the mask polynomial $P$ has the
+/// mask $(p\_k, p\_{k-1}, \ldots, p\_0, -p\_{n-1}, \ldots, -p\_{k+1})$, the coefficients
+/// involved in the $k$-th coefficient of the negacyclic product $P \cdot S$.
+///
+/// See [`crate::core_crypto::algorithms::encrypt_lwe_compact_ciphertext_list_with_compact_public_key`]
+/// for usage.
+pub fn expand_lwe_compact_ciphertext_list<Scalar, InputCont, OutputCont>(
+    output_lwe_ciphertext_list: &mut LweCiphertextList<OutputCont>,
+    input_lwe_compact_ciphertext_list: &LweCompactCiphertextList<InputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        output_lwe_ciphertext_list.lwe_size() == input_lwe_compact_ciphertext_list.lwe_size(),
+        "Mismatched LweSize between input ({:?}) and output ({:?})",
+        input_lwe_compact_ciphertext_list.lwe_size(),
+        output_lwe_ciphertext_list.lwe_size()
+    );
+    assert!(
+        output_lwe_ciphertext_list.lwe_ciphertext_count()
+            == input_lwe_compact_ciphertext_list.lwe_ciphertext_count(),
+        "Mismatched LweCiphertextCount between input ({:?}) and output ({:?})",
+        input_lwe_compact_ciphertext_list.lwe_ciphertext_count(),
+        output_lwe_ciphertext_list.lwe_ciphertext_count()
+    );
+    assert_eq!(
+        output_lwe_ciphertext_list.ciphertext_modulus(),
+        input_lwe_compact_ciphertext_list.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input_lwe_compact_ciphertext_list.ciphertext_modulus(),
+        output_lwe_ciphertext_list.ciphertext_modulus()
+    );
+
+    let lwe_dimension = input_lwe_compact_ciphertext_list
+        .lwe_size()
+        .to_lwe_dimension();
+    let (input_masks, input_bodies) = input_lwe_compact_ciphertext_list.get_mask_and_body_list();
+
+    for ((mut output_lwe, &input_body), input_index) in output_lwe_ciphertext_list
+        .iter_mut()
+        .zip(input_bodies.iter())
+        .zip(0..)
+    {
+        let input_mask = input_masks.get(input_index / lwe_dimension.0);
+        let coefficient_index = input_index % lwe_dimension.0;
+
+        let (mut output_mask, output_body) = output_lwe.get_mut_mask_and_body();
+        let (output_mask_head, output_mask_tail) =
+            output_mask.as_mut().split_at_mut(coefficient_index + 1);
+        let (input_mask_head, input_mask_tail) =
+            input_mask.as_ref().split_at(coefficient_index + 1);
+
+        for (dst, &src) in output_mask_head
+            .iter_mut()
+            .zip(input_mask_head.iter().rev())
+        {
+            *dst = src;
+        }
+        // Coefficients wrapping around X^n = -1 are negated
+        for (dst, &src) in output_mask_tail
+            .iter_mut()
+            .zip(input_mask_tail.iter().rev())
+        {
+            *dst = src.wrapping_neg();
+        }
+
+        *output_body.data = input_body;
+    }
+}
@@ -0,0 +1,77 @@
+//! Module containing primitives pertaining to [`LWE compact public key
+//! generation`](`LweCompactPublicKey#lwe-compact-public-key`).
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill an [`LWE compact public key`](`LweCompactPublicKey`) with an actual public key constructed
+/// from a private [`LWE secret key`](`LweSecretKey`).
+///
+/// See [`encrypt_lwe_compact_ciphertext_list_with_compact_public_key`] for usage.
+pub fn generate_lwe_compact_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output: &mut LweCompactPublicKey<OutputKeyCont>,
+    noise_parameters: impl NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_secret_key.lwe_dimension() == output.lwe_dimension(),
+        "Mismatched LweDimension between input LweSecretKey {:?} \
+        and output LweCompactPublicKey {:?}",
+        lwe_secret_key.lwe_dimension(),
+        output.lwe_dimension(),
+    );
+
+    // The LWE secret key seen as a single polynomial is the GLWE secret key for which the compact
+    // public key is an encryption of 0
+    let glwe_secret_key = GlweSecretKey::from_container(
+        lwe_secret_key.as_ref(),
+        PolynomialSize(lwe_secret_key.lwe_dimension().0),
+    );
+
+    output.as_mut().fill(Scalar::ZERO);
+    let (mut mask, mut body) = output.get_mut_mask_and_body();
+
+    fill_glwe_mask_and_body_for_encryption_assign(
+        &glwe_secret_key,
+        &mut mask,
+        &mut body,
+        noise_parameters,
+        generator,
+    );
+}
+
+/// Allocate a new [`LWE compact public key`](`LweCompactPublicKey`) and fill it with an actual
+/// public key constructed from a private [`LWE secret key`](`LweSecretKey`).
+///
+/// See [`encrypt_lwe_compact_ciphertext_list_with_compact_public_key`] for usage.
+pub fn allocate_and_generate_new_lwe_compact_public_key<Scalar, InputKeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    noise_parameters: impl NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LweCompactPublicKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut pk = LweCompactPublicKeyOwned::new(
+        Scalar::ZERO,
+        lwe_secret_key.lwe_dimension(),
+        ciphertext_modulus,
+    );
+
+    generate_lwe_compact_public_key(lwe_secret_key, &mut pk, noise_parameters, generator);
+
+    pk
+}
@@ -3,7 +3,7 @@
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
 use crate::core_crypto::commons::parameters::*;
@@ -18,7 +18,7 @@ pub fn fill_lwe_mask_and_body_for_encryption<Scalar, KeyCont, OutputCont, Gen>(
     output_mask: &mut LweMask<OutputCont>,
     output_body: LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -117,7 +117,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -204,7 +204,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
 pub fn allocate_and_encrypt_new_lwe_ciphertext<Scalar, KeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweCiphertextOwned<Scalar>
@@ -428,6 +428,172 @@ where
     }
 }
 
+/// Decrypt an [`LWE ciphertext`](`LweCiphertext`), round the noisy plaintext to the closest
+/// multiple of `delta` and return the rounded plaintext together with the error, i.e. the
+/// difference between the noisy plaintext and the rounded one.
+///
+/// The error is returned modulo the ciphertext modulus, a value in the upper half of the modulus
+/// being a negative error. `delta` is expected to divide the ciphertext modulus.
+///
+/// This is meant to characterize the noise of ciphertexts in tests and experiments, see
+/// [`lwe_ciphertext_noise`] to get the error relative to a known plaintext.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let delta = 1u64 << 60;
+/// let plaintext = Plaintext(msg * delta);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let (rounded_plaintext, error) =
+///     decrypt_lwe_ciphertext_with_error(&lwe_secret_key, &lwe, delta);
+///
+/// assert_eq!(rounded_plaintext, plaintext);
+/// // The error is small compared to delta
+/// assert!(error < delta / 2 || error.wrapping_neg() <= delta / 2);
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe);
+/// assert_eq!(decrypted_plaintext.0, rounded_plaintext.0.wrapping_add(error));
+/// ```
+pub fn decrypt_lwe_ciphertext_with_error<Scalar, KeyCont, InputCont>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    delta: Scalar,
+) -> (Plaintext<Scalar>, Scalar)
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(delta != Scalar::ZERO, "delta must not be 0");
+
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+    let noisy = decrypt_lwe_ciphertext(lwe_secret_key, lwe_ciphertext).0;
+
+    let rounded = noisy
+        .wrapping_add(delta / Scalar::TWO)
+        .wrapping_div(delta)
+        .wrapping_mul(delta);
+    let error = noisy.wrapping_sub(rounded);
+
+    if ciphertext_modulus.is_native_modulus() {
+        (Plaintext(rounded), error)
+    } else {
+        // Plaintexts are decrypted modulo the ciphertext modulus, which is a power of 2
+        let modulus_mask = Scalar::cast_from(ciphertext_modulus.get() - 1);
+        (Plaintext(rounded & modulus_mask), error & modulus_mask)
+    }
+}
+
+/// Return the error of an [`LWE ciphertext`](`LweCiphertext`) encrypting `expected_plaintext`, as
+/// a fraction of the ciphertext modulus in \([-0.5, 0.5[\).
+///
+/// This requires the secret key and is meant to measure noise growth empirically in tests and
+/// experiments: the variance of the values returned for many ciphertexts resulting from the same
+/// computation estimates the variance of the noise of the computation.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let plaintext = Plaintext(3u64 << 60);
+///
+/// let mut lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let noise = lwe_ciphertext_noise(&lwe_secret_key, &lwe, plaintext);
+/// // Fresh noise is within 10 standard deviations
+/// assert!(noise.abs() < 10.0 * lwe_modular_std_dev.0);
+///
+/// // Multiplying by 4 multiplies the noise by 4
+/// lwe_ciphertext_cleartext_mul_assign(&mut lwe, Cleartext(4));
+/// let noise_after_mul =
+///     lwe_ciphertext_noise(&lwe_secret_key, &lwe, Plaintext(plaintext.0.wrapping_mul(4)));
+/// assert!((noise_after_mul - 4.0 * noise).abs() < 1e-12);
+/// ```
+pub fn lwe_ciphertext_noise<Scalar, KeyCont, InputCont>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    expected_plaintext: Plaintext<Scalar>,
+) -> f64
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+    let decrypted = decrypt_lwe_ciphertext(lwe_secret_key, lwe_ciphertext);
+
+    // Map the error back to the native torus so that it is a fraction of the modulus
+    let error = decrypted
+        .0
+        .wrapping_sub(expected_plaintext.0)
+        .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus());
+
+    let error: f64 = error.into_torus();
+    if error >= 0.5 {
+        error - 1.0
+    } else {
+        error
+    }
+}
+
 /// Encrypt an input plaintext list in an output [`LWE ciphertext list`](`LweCiphertextList`).
 ///
 /// See this [`formal definition`](`encrypt_lwe_ciphertext#formal-definition`) for the definition
@@ -505,7 +671,7 @@ pub fn encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -614,7 +780,7 @@ pub fn par_encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, G
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -943,6 +1109,208 @@ pub fn encrypt_lwe_ciphertext_with_seeded_public_key<Scalar, KeyCont, OutputCont
     }
 }
 
+/// Encrypt an input plaintext list in an output
+/// [`LWE compact ciphertext list`](`LweCompactCiphertextList`) using an
+/// [`LWE compact public key`](`LweCompactPublicKey`). The expanded ciphertexts can be decrypted
+/// using the [`LWE secret key`](`LweSecretKey`) that was used to generate the public key.
+///
+/// See the [`LWE compact ciphertext list formal definition`](`LweCompactCiphertextList`) for the
+/// definition of the encryption algorithm, `mask_noise_parameters` and `body_noise_parameters`
+/// being the dispersions of $E\_1$ and $E\_2$ respectively.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCompactCiphertextList creation
+/// let lwe_dimension = LweDimension(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let lwe_ciphertext_count = LweCiphertextCount(1500);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintexts
+/// let msgs: Vec<u64> = (0..lwe_ciphertext_count.0 as u64).map(|i| i % 16).collect();
+/// let plaintext_list = PlaintextList::from_container(msgs.iter().map(|m| m << 60).collect());
+///
+/// // Create a new LweCompactCiphertextList
+/// let mut lwe_compact_list = LweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+///     &lwe_compact_public_key,
+///     &mut lwe_compact_list,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     glwe_modular_std_dev,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+/// );
+///
+/// // Expand the list on the receiving side
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+/// expand_lwe_compact_ciphertext_list(&mut lwe_list, &lwe_compact_list);
+///
+/// let mut decrypted_plaintext_list =
+///     PlaintextList::new(0u64, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// decrypt_lwe_ciphertext_list(&lwe_secret_key, &lwe_list, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let cleartexts: Vec<u64> = decrypted_plaintext_list
+///     .as_ref()
+///     .iter()
+///     .map(|&plaintext| decomposer.closest_representable(plaintext) >> 60)
+///     .collect();
+///
+/// // Check we recovered the original messages
+/// assert_eq!(cleartexts, msgs);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    mask_noise_parameters: impl NoiseDistribution,
+    body_noise_parameters: impl NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert_eq!(
+        lwe_compact_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between lwe_compact_public_key ({:?}) and output ({:?})",
+        lwe_compact_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert!(
+        output.lwe_size().to_lwe_dimension() == lwe_compact_public_key.lwe_dimension(),
+        "Mismatch between LweDimension of output ciphertext list and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.lwe_size().to_lwe_dimension(),
+        lwe_compact_public_key.lwe_dimension()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
+        "Mismatch between number of output ciphertexts and input plaintexts. \
+        Got {:?} plaintexts, and {:?} ciphertext.",
+        encoded.plaintext_count(),
+        output.lwe_ciphertext_count()
+    );
+
+    let ciphertext_modulus = output.ciphertext_modulus();
+    let polynomial_size = lwe_compact_public_key.polynomial_size();
+
+    let (pk_mask, pk_body) = lwe_compact_public_key.get_mask_and_body();
+    let pk_mask = pk_mask.as_polynomial_list();
+    let pk_mask = pk_mask.get(0);
+    let pk_body = pk_body.as_polynomial();
+
+    let (mut output_masks, output_bodies) = output.get_mut_mask_and_body_list();
+
+    let mut binary_random_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+    let mut body_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    for ((mut output_mask, output_body_chunk), encoded_chunk) in output_masks
+        .iter_mut()
+        .zip(output_bodies.chunks_mut(polynomial_size.0))
+        .zip(encoded.as_ref().chunks(polynomial_size.0))
+    {
+        secret_generator.fill_slice_with_random_uniform_binary(binary_random_polynomial.as_mut());
+
+        // P = A * R + E1
+        encryption_generator.fill_slice_with_random_noise_custom_mod(
+            output_mask.as_mut(),
+            mask_noise_parameters,
+            ciphertext_modulus,
+        );
+
+        // D = B * R + E2 + M
+        body_polynomial.as_mut().fill(Scalar::ZERO);
+        encryption_generator.fill_slice_with_random_noise_custom_mod(
+            body_polynomial.as_mut(),
+            body_noise_parameters,
+            ciphertext_modulus,
+        );
+        for (body, &plaintext) in body_polynomial
+            .as_mut()
+            .iter_mut()
+            .zip(encoded_chunk.iter())
+        {
+            *body = (*body).wrapping_add(plaintext);
+        }
+
+        if !ciphertext_modulus.is_native_modulus() {
+            let torus_scaling = ciphertext_modulus.get_scaling_to_native_torus();
+            slice_wrapping_scalar_mul_assign(output_mask.as_mut(), torus_scaling);
+            slice_wrapping_scalar_mul_assign(body_polynomial.as_mut(), torus_scaling);
+        }
+
+        polynomial_algorithms::polynomial_wrapping_add_mul_assign(
+            &mut output_mask,
+            &pk_mask,
+            &binary_random_polynomial,
+        );
+        polynomial_algorithms::polynomial_wrapping_add_mul_assign(
+            &mut body_polynomial,
+            &pk_body,
+            &binary_random_polynomial,
+        );
+
+        // The last chunk may not use all the coefficients of the body polynomial
+        output_body_chunk.copy_from_slice(&body_polynomial.as_ref()[..output_body_chunk.len()]);
+    }
+}
+
 /// Convenience function to share the core logic of the seeded LWE encryption between all functions
 /// needing it.
 pub fn encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
@@ -955,7 +1323,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1078,7 +1446,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1114,7 +1482,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1238,7 +1606,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, Input
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1268,7 +1636,7 @@ pub fn encrypt_seeded_lwe_ciphertext_with_existing_generator<Scalar, KeyCont, Ge
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1357,7 +1725,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1439,7 +1807,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
 pub fn allocate_and_encrypt_new_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweCiphertext<Scalar>
@@ -3,7 +3,7 @@
 //! generation`](`SeededLweKeyswitchKey`).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
@@ -66,7 +66,7 @@ pub fn generate_lwe_keyswitch_key<Scalar, InputKeyCont, OutputKeyCont, KSKeyCont
     input_lwe_sk: &LweSecretKey<InputKeyCont>,
     output_lwe_sk: &LweSecretKey<OutputKeyCont>,
     lwe_keyswitch_key: &mut LweKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -137,7 +137,7 @@ pub fn allocate_and_generate_new_lwe_keyswitch_key<Scalar, InputKeyCont, OutputK
     output_lwe_sk: &LweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweKeyswitchKeyOwned<Scalar>
@@ -227,7 +227,7 @@ pub fn generate_seeded_lwe_keyswitch_key<
     input_lwe_sk: &LweSecretKey<InputKeyCont>,
     output_lwe_sk: &LweSecretKey<OutputKeyCont>,
     lwe_keyswitch_key: &mut SeededLweKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -308,7 +308,7 @@ pub fn allocate_and_generate_new_seeded_lwe_keyswitch_key<
     output_lwe_sk: &LweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweKeyswitchKeyOwned<Scalar>
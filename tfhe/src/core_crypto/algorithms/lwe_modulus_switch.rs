@@ -0,0 +1,139 @@
+//! Module containing primitives pertaining to the modulus switching of
+//! [`LWE ciphertexts`](`LweCiphertext`).
+
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::CiphertextModulus;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Switch the modulus of an input [`LWE ciphertext`](`LweCiphertext`) to the ciphertext modulus
+/// of the output [`LWE ciphertext`](`LweCiphertext`), rounding each coefficient to the closest
+/// value representable with the output modulus.
+///
+/// The output modulus must be a power of 2 smaller than or equal to the input modulus. The output
+/// encrypts the same plaintext with the output modulus, with the additional noise introduced by
+/// the rounding, which allows measuring that noise, e.g. the one of the modulus switch done at the
+/// beginning of a programmable bootstrap when the output modulus is `2 * polynomial_size`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Switch to the modulus 2^32
+/// let output_ciphertext_modulus = CiphertextModulus::try_new_power_of_2(32).unwrap();
+/// let mut switched_lwe =
+///     LweCiphertext::new(0u64, lwe.lwe_size(), output_ciphertext_modulus);
+///
+/// lwe_ciphertext_modulus_switch(&lwe, &mut switched_lwe);
+///
+/// // Plaintexts are now decrypted modulo 2^32
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &switched_lwe);
+///
+/// // Round and remove encoding
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0 << 32);
+/// let cleartext = rounded >> 60;
+///
+/// assert_eq!(cleartext, msg);
+///
+/// // The rounding noise can be measured with the secret key
+/// let noise = lwe_ciphertext_noise(&lwe_secret_key, &switched_lwe, Plaintext(msg << 28));
+/// assert!(noise.abs() < 1.0 / 32.0);
+/// ```
+pub fn lwe_ciphertext_modulus_switch<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size(),
+        "Mismatched LweSize between input ({:?}) and output ({:?})",
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size()
+    );
+
+    let input_ciphertext_modulus = input_lwe_ciphertext.ciphertext_modulus();
+    let output_ciphertext_modulus = output_lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        input_ciphertext_modulus.is_compatible_with_native_modulus(),
+        "The input ciphertext modulus must be the native modulus or a power of 2, \
+        got {input_ciphertext_modulus}"
+    );
+    assert!(
+        output_ciphertext_modulus.is_compatible_with_native_modulus(),
+        "The output ciphertext modulus must be the native modulus or a power of 2, \
+        got {output_ciphertext_modulus}"
+    );
+
+    let modulus_log = |modulus: CiphertextModulus<Scalar>| {
+        if modulus.is_native_modulus() {
+            Scalar::BITS
+        } else {
+            modulus.get().ilog2() as usize
+        }
+    };
+    let input_modulus_log = modulus_log(input_ciphertext_modulus);
+    let output_modulus_log = modulus_log(output_ciphertext_modulus);
+
+    assert!(
+        output_modulus_log <= input_modulus_log,
+        "The output ciphertext modulus ({output_ciphertext_modulus}) must be smaller than or equal \
+        to the input ciphertext modulus ({input_ciphertext_modulus})"
+    );
+
+    // Non native moduli are stored in the MSBs, so the coefficients can be rounded on the native
+    // torus
+    let discarded_bits = Scalar::BITS - output_modulus_log;
+
+    for (dst, &src) in output_lwe_ciphertext
+        .as_mut()
+        .iter_mut()
+        .zip(input_lwe_ciphertext.as_ref().iter())
+    {
+        *dst = if discarded_bits == 0 {
+            src
+        } else {
+            // Keep one more bit than needed to round to the closest value
+            let rounded = ((src >> (discarded_bits - 1)).wrapping_add(Scalar::ONE)) >> 1;
+            rounded.wrapping_shl(discarded_bits as u32)
+        };
+    }
+}
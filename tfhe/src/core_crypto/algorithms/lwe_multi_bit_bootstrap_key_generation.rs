@@ -2,7 +2,7 @@
 //! [`standard LWE multi_bit bootstrap keys`](`LweMultiBitBootstrapKey`).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -87,7 +87,7 @@ pub fn generate_lwe_multi_bit_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont,
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut LweMultiBitBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + CastFrom<usize>,
@@ -172,7 +172,7 @@ pub fn allocate_and_generate_new_lwe_multi_bit_bootstrap_key<
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
     grouping_factor: LweBskGroupingFactor,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweMultiBitBootstrapKeyOwned<Scalar>
@@ -298,7 +298,7 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut LweMultiBitBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + CastFrom<usize> + Sync + Send,
@@ -419,7 +419,7 @@ pub fn par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key<
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
     grouping_factor: LweBskGroupingFactor,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweMultiBitBootstrapKeyOwned<Scalar>
@@ -11,7 +11,8 @@ use crate::core_crypto::fft_impl::fft128::crypto::bootstrap::{
 };
 use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
-    bootstrap_scratch, FourierLweBootstrapKey,
+    bootstrap_scratch, bootstrap_with_seeded_bsk, bootstrap_with_seeded_bsk_scratch,
+    keyswitch_bootstrap_scratch, FourierLweBootstrapKey,
 };
 use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     add_external_product_assign as impl_add_external_product_assign,
@@ -20,6 +21,11 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::ntt_impl::ntt64::crypto::bootstrap::{
+    bootstrap_with_seeded_bsk as ntt_bootstrap_with_seeded_bsk,
+    bootstrap_with_seeded_bsk_scratch as ntt_bootstrap_with_seeded_bsk_scratch,
+};
+use crate::core_crypto::ntt_impl::ntt64::math::NttView;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
@@ -1117,6 +1123,702 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
     bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
 }
 
+/// Variant of [`programmable_bootstrap_lwe_ciphertext_mem_optimized`] only using the
+/// `pbs_level` most significant decomposition levels of the bootstrapping key.
+///
+/// Each external product of the blind rotation then costs `pbs_level` levels instead of
+/// `fourier_bsk.decomposition_level_count()`, but the coarser decomposition adds a rounding error
+/// to the output: the failure probability of the bootstrap is higher than with the full key,
+/// and is only acceptable if the key has more levels than its parameters require. Using all the
+/// levels of the key gives the same result as
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized`].
+///
+/// The memory requirement is the one of
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+///
+/// # Panics
+///
+/// Panics if `pbs_level` is zero or larger than the level count of the key.
+pub fn approximate_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    pbs_level: DecompositionLevelCount,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert!(
+        pbs_level.0 > 0 && pbs_level.0 <= fourier_bsk.decomposition_level_count().0,
+        "Invalid {pbs_level:?} for a bootstrapping key with {:?}",
+        fourier_bsk.decomposition_level_count()
+    );
+
+    fourier_bsk.as_view().bootstrap_with_level_count(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        pbs_level,
+        fft,
+        stack,
+    );
+}
+
+/// Perform a keyswitch of an input [`LWE ciphertext`](`LweCiphertext`) followed by a programmable
+/// bootstrap with a look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE
+/// bootstrap key`](`LweBootstrapKey`) in the fourier domain. The result is written in the provided
+/// output [`LWE ciphertext`](`LweCiphertext`).
+///
+/// This computes the same result as
+/// [`keyswitch_lwe_ciphertext`](crate::core_crypto::algorithms::keyswitch_lwe_ciphertext) followed by
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized`] without ever writing the keyswitched
+/// ciphertext in memory: the mask of the input is decomposed once, then the coefficients of the
+/// keyswitched ciphertext are computed by small chunks right before the CMUXes of the blind
+/// rotation consuming them.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`].
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ks_base_log = DecompositionBaseLog(3);
+/// let ks_level = DecompositionLevelCount(5);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+///     &big_lwe_sk,
+///     &small_lwe_sk,
+///     ks_base_log,
+///     ks_level,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let std_bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bsk.input_lwe_dimension(),
+///     std_bsk.glwe_size(),
+///     std_bsk.polynomial_size(),
+///     std_bsk.decomposition_base_log(),
+///     std_bsk.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bsk, &mut fourier_bsk);
+///
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // The input is encrypted under the big key, as the output of a previous bootstrap would be
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &big_lwe_sk,
+///     plaintext,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity accumulator, see the example of [`programmable_bootstrap_lwe_ciphertext`]
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|i| (i / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+/// let mut buffer = GlobalPodBuffer::new(
+///     keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         ksk.input_key_lwe_dimension(),
+///         ksk.decomposition_level_count(),
+///         fourier_bsk.glwe_size(),
+///         fourier_bsk.polynomial_size(),
+///         fft,
+///     )
+///     .unwrap(),
+/// );
+///
+/// keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &ksk,
+///     &fourier_bsk,
+///     fft,
+///     PodStack::new(&mut buffer),
+/// );
+///
+/// let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let decoded = signed_decomposer.closest_representable(decrypted.0) / delta;
+/// assert_eq!(decoded, input_message);
+/// ```
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KSKCont,
+    BskCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    lwe_keyswitch_key: &LweKeyswitchKey<KSKCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KSKCont: Container<Element = Scalar>,
+    BskCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    fourier_bsk.as_view().keyswitch_bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        lwe_keyswitch_key.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Same as [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized`] but the result is
+/// written back in the input ciphertext.
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized<
+    Scalar,
+    InOutCont,
+    AccCont,
+    KSKCont,
+    BskCont,
+>(
+    ct: &mut LweCiphertext<InOutCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    lwe_keyswitch_key: &LweKeyswitchKey<KSKCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InOutCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KSKCont: Container<Element = Scalar>,
+    BskCont: Container<Element = c64>,
+{
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        ct.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and ct ({:?})",
+        accumulator.ciphertext_modulus(),
+        ct.ciphertext_modulus()
+    );
+
+    fourier_bsk.as_view().keyswitch_bootstrap_assign(
+        ct.as_mut_view(),
+        lwe_keyswitch_key.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized`] and
+/// [`keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized`].
+pub fn keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    keyswitch_input_lwe_dimension: LweDimension,
+    keyswitch_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_bootstrap_scratch::<Scalar>(
+        keyswitch_input_lwe_dimension,
+        keyswitch_level_count,
+        glwe_size,
+        polynomial_size,
+        fft,
+    )
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and a [`seeded LWE bootstrap
+/// key`](`SeededLweBootstrapKey`). The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// Unlike [`programmable_bootstrap_lwe_ciphertext_mem_optimized`] the key is never fully converted
+/// to the Fourier domain: each GGSW of the key is decompressed and converted right before being
+/// used, so that only one GGSW is expanded in memory at any time. This keeps the memory footprint
+/// of the key to the one of the seeded key, at the cost of a slower bootstrap.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement`].
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let seeded_bsk = allocate_and_generate_new_seeded_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     seeder,
+/// );
+///
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity accumulator, see the example of [`programmable_bootstrap_lwe_ciphertext`]
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|i| (i / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+/// let mut buffer = GlobalPodBuffer::new(
+///     programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement::<u64>(
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         pbs_level,
+///         fft,
+///     )
+///     .unwrap(),
+/// );
+///
+/// programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &seeded_bsk,
+///     fft,
+///     PodStack::new(&mut buffer),
+/// );
+///
+/// let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let decoded = signed_decomposer.closest_representable(decrypted.0) / delta;
+/// assert_eq!(decoded, input_message);
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    seeded_bsk: &SeededLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between seeded_bsk ({:?}) and output ({:?})",
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    bootstrap_with_seeded_bsk(
+        seeded_bsk.as_view(),
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized`].
+pub fn programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_with_seeded_bsk_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        decomposition_level_count,
+        fft,
+    )
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and a [`seeded LWE bootstrap
+/// key`](`SeededLweBootstrapKey`), computing the polynomial products with a number theoretic
+/// transform. The result is written in the provided output [`LWE ciphertext`](`LweCiphertext`).
+///
+/// As in [`programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized`] each GGSW of the
+/// key is decompressed and transformed right before being used. The products computed with the
+/// NTT are exact, the output therefore does not carry the noise of the floating point FFT and
+/// is in general not bit for bit equal to the output of the FFT bootstraps, it decrypts to the
+/// same message.
+///
+/// The caller must provide a properly configured [`NttView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement`].
+///
+/// # Panics
+///
+/// Panics if the parameters of the key are such that the integer coefficients of the external
+/// products could exceed the range in which the NTT is exact.
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let seeded_bsk = allocate_and_generate_new_seeded_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     seeder,
+/// );
+///
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity accumulator, see the example of [`programmable_bootstrap_lwe_ciphertext`]
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|i| (i / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// let ntt = Ntt::new(polynomial_size);
+/// let ntt = ntt.as_view();
+/// let mut buffer = GlobalPodBuffer::new(
+///     programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement(
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         pbs_level,
+///     )
+///     .unwrap(),
+/// );
+///
+/// programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &seeded_bsk,
+///     ntt,
+///     PodStack::new(&mut buffer),
+/// );
+///
+/// let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let decoded = signed_decomposer.closest_representable(decrypted.0) / delta;
+/// assert_eq!(decoded, input_message);
+/// ```
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    seeded_bsk: &SeededLweBootstrapKey<KeyCont>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between seeded_bsk ({:?}) and output ({:?})",
+        seeded_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "The NTT bootstrap only supports the native modulus, got {:?}",
+        output.ciphertext_modulus()
+    );
+
+    ntt_bootstrap_with_seeded_bsk(
+        seeded_bsk.as_view(),
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        ntt,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    ntt_bootstrap_with_seeded_bsk_scratch(glwe_size, polynomial_size, decomposition_level_count)
+}
+
 /// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
 /// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain using f128. The result is written in the provided
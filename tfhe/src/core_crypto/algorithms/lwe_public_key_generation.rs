@@ -3,7 +3,7 @@
 //! generation`](`SeededLwePublicKey#lwe-public-key`).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::CompressionSeed;
 use crate::core_crypto::commons::parameters::*;
@@ -17,7 +17,7 @@ use crate::core_crypto::entities::*;
 pub fn generate_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut LwePublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -49,7 +49,7 @@ pub fn generate_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
 pub fn allocate_and_generate_new_lwe_public_key<Scalar, InputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     zero_encryption_count: LwePublicKeyZeroEncryptionCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LwePublicKeyOwned<Scalar>
@@ -75,7 +75,7 @@ where
 pub fn par_generate_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut LwePublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -103,7 +103,7 @@ pub fn par_generate_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
 pub fn par_allocate_and_generate_new_lwe_public_key<Scalar, InputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     zero_encryption_count: LwePublicKeyZeroEncryptionCount,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LwePublicKeyOwned<Scalar>
@@ -130,7 +130,7 @@ where
 pub fn generate_seeded_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut SeededLwePublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -170,7 +170,7 @@ pub fn generate_seeded_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Noise
 pub fn allocate_and_generate_new_seeded_lwe_public_key<Scalar, InputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     zero_encryption_count: LwePublicKeyZeroEncryptionCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl NoiseDistribution,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLwePublicKeyOwned<Scalar>
@@ -200,7 +200,7 @@ where
 pub fn par_generate_seeded_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut SeededLwePublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -235,7 +235,7 @@ pub fn par_generate_seeded_lwe_public_key<Scalar, InputKeyCont, OutputKeyCont, N
 pub fn par_allocate_and_generate_new_seeded_lwe_public_key<Scalar, InputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     zero_encryption_count: LwePublicKeyZeroEncryptionCount,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl NoiseDistribution + Sync,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLwePublicKeyOwned<Scalar>
@@ -9,10 +9,13 @@ pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
+pub mod lwe_compact_ciphertext_list_expansion;
+pub mod lwe_compact_public_key_generation;
 pub mod lwe_encryption;
 pub mod lwe_keyswitch;
 pub mod lwe_keyswitch_key_generation;
 pub mod lwe_linear_algebra;
+pub mod lwe_modulus_switch;
 pub mod lwe_multi_bit_bootstrap_key_conversion;
 pub mod lwe_multi_bit_bootstrap_key_generation;
 pub mod lwe_multi_bit_programmable_bootstrapping;
@@ -46,10 +49,13 @@ pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
+pub use lwe_compact_ciphertext_list_expansion::*;
+pub use lwe_compact_public_key_generation::*;
 pub use lwe_encryption::*;
 pub use lwe_keyswitch::*;
 pub use lwe_keyswitch_key_generation::*;
 pub use lwe_linear_algebra::*;
+pub use lwe_modulus_switch::*;
 pub use lwe_multi_bit_bootstrap_key_conversion::*;
 pub use lwe_multi_bit_bootstrap_key_generation::*;
 pub use lwe_multi_bit_programmable_bootstrapping::*;
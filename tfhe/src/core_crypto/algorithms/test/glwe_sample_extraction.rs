@@ -0,0 +1,93 @@
+use super::*;
+
+fn glwe_encrypt_sample_extract_decrypt_custom_mod<Scalar: UnsignedTorus + Sync + Send>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let equivalent_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+        // Each coefficient encrypts a different message to check the order of the samples
+        let mut plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+        for (index, plaintext) in plaintext_list.iter_mut().enumerate() {
+            *plaintext.0 = (Scalar::cast_from(index as u128) % msg_modulus) * delta;
+        }
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut extracted_samples = LweCiphertextList::new(
+            Scalar::ZERO,
+            equivalent_lwe_sk.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(polynomial_size.0),
+            ciphertext_modulus,
+        );
+        extract_all_lwe_samples_from_glwe_ciphertext(&glwe, &mut extracted_samples);
+
+        let mut par_extracted_samples = LweCiphertextList::new(
+            Scalar::ZERO,
+            equivalent_lwe_sk.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(polynomial_size.0),
+            ciphertext_modulus,
+        );
+        par_extract_all_lwe_samples_from_glwe_ciphertext(&glwe, &mut par_extracted_samples);
+
+        assert_eq!(extracted_samples, par_extracted_samples);
+
+        let mut single_sample = LweCiphertext::new(
+            Scalar::ZERO,
+            equivalent_lwe_sk.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        for (index, extracted_sample) in extracted_samples.iter().enumerate() {
+            extract_lwe_sample_from_glwe_ciphertext(
+                &glwe,
+                &mut single_sample,
+                MonomialDegree(index),
+            );
+            assert_eq!(single_sample.as_ref(), extracted_sample.as_ref());
+
+            assert!(check_content_respects_mod(
+                &extracted_sample,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&equivalent_lwe_sk, &extracted_sample);
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+            assert_eq!(decoded, Scalar::cast_from(index as u128) % msg_modulus);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_sample_extract_decrypt_custom_mod);
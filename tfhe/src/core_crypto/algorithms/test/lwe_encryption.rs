@@ -577,6 +577,83 @@ fn lwe_seeded_public_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: T
 
 create_parametrized_test!(lwe_seeded_public_encrypt_decrypt_custom_mod);
 
+fn lwe_compact_public_encrypt_expand_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: TestParams<Scalar>,
+) {
+    // The compact public key is meant to be used with the large LWE key, seen as a polynomial
+    let lwe_dimension = LweDimension(params.glwe_dimension.0 * params.polynomial_size.0);
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    // Use more ciphertexts than the dimension to have several masks in the list
+    let lwe_ciphertext_count = LweCiphertextCount(lwe_dimension.0 + lwe_dimension.0 / 2);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 4;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for test_index in 0..NB_TESTS {
+        let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+
+        let pk = allocate_and_generate_new_lwe_compact_public_key(
+            &lwe_sk,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let msgs: Vec<Scalar> = (0..lwe_ciphertext_count.0)
+            .map(|i| Scalar::cast_from((i + test_index) as u128) % msg_modulus)
+            .collect();
+        let plaintext_list =
+            PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+
+        let mut compact_list = LweCompactCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+            &pk,
+            &mut compact_list,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            glwe_modular_std_dev,
+            &mut rsc.secret_random_generator,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut lwe_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        expand_lwe_compact_ciphertext_list(&mut lwe_list, &compact_list);
+
+        for (ct, &msg) in lwe_list.iter().zip(msgs.iter()) {
+            assert!(check_content_respects_mod(&ct, ciphertext_modulus));
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(msg, decoded);
+        }
+    }
+}
+
+create_parametrized_test!(lwe_compact_public_encrypt_expand_decrypt_custom_mod);
+
 fn lwe_seeded_list_par_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus + Sync + Send>(
     params: TestParams<Scalar>,
 ) {
@@ -0,0 +1,81 @@
+use super::*;
+
+fn lwe_encrypt_modulus_switch_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let ciphertext_modulus_log = if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS
+    } else {
+        ciphertext_modulus.get().ilog2() as usize
+    };
+    // Keep enough bits for the message, the padding bit and some noise margin
+    let output_modulus_log = message_modulus_log.0 + 12;
+    let output_ciphertext_modulus =
+        CiphertextModulus::try_new_power_of_2(output_modulus_log).unwrap();
+    let scaling = Scalar::ONE << (ciphertext_modulus_log - output_modulus_log);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let output_delta = delta / scaling;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let plaintext = Plaintext(msg * delta);
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let (rounded, error) = decrypt_lwe_ciphertext_with_error(&lwe_sk, &ct, delta);
+            assert_eq!(rounded, plaintext);
+            let noise = lwe_ciphertext_noise(&lwe_sk, &ct, plaintext);
+            let error: f64 = error
+                .wrapping_mul(ciphertext_modulus.get_scaling_to_native_torus())
+                .into_torus();
+            let error = if error >= 0.5 { error - 1.0 } else { error };
+            assert!((error - noise).abs() < 1e-12);
+
+            let mut switched_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                output_ciphertext_modulus,
+            );
+            lwe_ciphertext_modulus_switch(&ct, &mut switched_ct);
+
+            assert!(check_content_respects_mod(
+                &switched_ct,
+                output_ciphertext_modulus
+            ));
+
+            let (rounded, _) =
+                decrypt_lwe_ciphertext_with_error(&lwe_sk, &switched_ct, output_delta);
+            assert_eq!(rounded.0 / output_delta, msg);
+
+            // The rounding adds noise, but the message is still far from the decoding bounds
+            let noise = lwe_ciphertext_noise(&lwe_sk, &switched_ct, Plaintext(msg * output_delta));
+            assert!(noise.abs() < 1.0 / (4 << message_modulus_log.0) as f64);
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_modulus_switch_decrypt_custom_mod);
@@ -128,6 +128,166 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
 
 create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod);
 
+fn lwe_encrypt_ks_pbs_single_scratch_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| {
+        x.wrapping_mul(Scalar::TWO)
+            .wrapping_sub(Scalar::ONE)
+            .wrapping_rem(msg_modulus)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let big_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &big_lwe_secret_key,
+            &small_lwe_secret_key,
+            params.ks_base_log,
+            params.ks_level,
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            params.pbs_base_log,
+            params.pbs_level,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut fbsk = FourierLweBootstrapKey::new(
+            lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            params.pbs_base_log,
+            params.pbs_level,
+        );
+
+        convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+        drop(bsk);
+
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+                ksk.input_key_lwe_dimension(),
+                ksk.decomposition_level_count(),
+                fbsk.glwe_size(),
+                fbsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &big_lwe_secret_key,
+                plaintext,
+                glwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut ks_ct =
+                LweCiphertext::new(Scalar::ZERO, ksk.output_lwe_size(), ciphertext_modulus);
+            keyswitch_lwe_ciphertext(&ksk, &lwe_ciphertext_in, &mut ks_ct);
+
+            let mut reference_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                big_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+            programmable_bootstrap_lwe_ciphertext(&ks_ct, &mut reference_ct, &accumulator, &fbsk);
+
+            let mut ks_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                big_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+            keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &lwe_ciphertext_in,
+                &mut ks_pbs_ct,
+                &accumulator,
+                &ksk,
+                &fbsk,
+                fft,
+                buffers.stack(),
+            );
+
+            assert_eq!(ks_pbs_ct, reference_ct);
+
+            let mut ks_pbs_assign_ct = lwe_ciphertext_in.clone();
+            keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized(
+                &mut ks_pbs_assign_ct,
+                &accumulator,
+                &ksk,
+                &fbsk,
+                fft,
+                buffers.stack(),
+            );
+
+            assert_eq!(ks_pbs_assign_ct, reference_ct);
+
+            let decrypted = decrypt_lwe_ciphertext(&big_lwe_secret_key, &ks_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_ks_pbs_single_scratch_decrypt_custom_mod);
+
 // DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
 pub const TEST_PARAMS_4_BITS_NATIVE_U128: TestParams<u128> = TestParams {
     lwe_dimension: LweDimension(742),
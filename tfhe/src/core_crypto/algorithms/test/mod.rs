@@ -3,11 +3,13 @@ use paste::paste;
 
 mod ggsw_encryption;
 mod glwe_encryption;
+mod glwe_sample_extraction;
 mod lwe_bootstrap_key_generation;
 mod lwe_encryption;
 mod lwe_keyswitch;
 mod lwe_keyswitch_key_generation;
 mod lwe_linear_algebra;
+mod lwe_modulus_switch;
 mod lwe_multi_bit_programmable_bootstrapping;
 mod lwe_programmable_bootstrapping;
 
@@ -12,7 +12,13 @@
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
 //! defined.
+//!
+//! The noise can also be sampled from a bounded uniform distribution, [`TUniform`]. The
+//! [`NoiseDistribution`] trait is implemented by all the distributions the encryption algorithms
+//! can sample the noise from, and [`DynamicDistribution`] selects one of them at runtime, e.g. from
+//! the parameters.
 
+use crate::core_crypto::commons::math::random::TUniform;
 use serde::{Deserialize, Serialize};
 
 /// A trait for types representing distribution parameters, for a given unsigned integer type.
@@ -194,3 +200,75 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+/// A trait for the distributions the noise of an encryption can be sampled from.
+///
+/// The [`DispersionParameter`] types describe a centered gaussian distribution.
+pub trait NoiseDistribution: Copy {
+    /// Return the distribution to sample the noise from.
+    fn to_dynamic(&self) -> DynamicDistribution;
+}
+
+impl<T: DispersionParameter> NoiseDistribution for T {
+    fn to_dynamic(&self) -> DynamicDistribution {
+        DynamicDistribution::Gaussian(StandardDev(self.get_standard_dev()))
+    }
+}
+
+impl NoiseDistribution for TUniform {
+    fn to_dynamic(&self) -> DynamicDistribution {
+        DynamicDistribution::TUniform(*self)
+    }
+}
+
+/// A noise distribution chosen at runtime.
+///
+/// # Example:
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{DynamicDistribution, StandardDev};
+/// use tfhe::core_crypto::commons::math::random::TUniform;
+/// let gaussian = DynamicDistribution::Gaussian(StandardDev(2_f64.powf(-25.)));
+/// assert_eq!(gaussian.get_variance(32), 2_f64.powf(-25.).powi(2));
+///
+/// // Values of [-2^3, 2^3] modulo 2^10
+/// let t_uniform = DynamicDistribution::TUniform(TUniform::new(3));
+/// assert_eq!(t_uniform.get_variance(10), (2. * 64. + 1.) / 6. / 2_f64.powi(20));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DynamicDistribution {
+    Gaussian(StandardDev),
+    TUniform(TUniform),
+}
+
+impl DynamicDistribution {
+    /// Return the variance of the distribution on the torus, for a ciphertext modulus of
+    /// $2^{log2\_modulus}$.
+    ///
+    /// The variance of a gaussian distribution does not depend on the modulus, the bound of a
+    /// [`TUniform`] distribution is relative to it.
+    pub fn get_variance(&self, log2_modulus: u32) -> f64 {
+        match self {
+            Self::Gaussian(std) => std.get_variance(),
+            Self::TUniform(t_uniform) => t_uniform.variance(log2_modulus),
+        }
+    }
+}
+
+impl NoiseDistribution for DynamicDistribution {
+    fn to_dynamic(&self) -> DynamicDistribution {
+        *self
+    }
+}
+
+impl From<StandardDev> for DynamicDistribution {
+    fn from(std: StandardDev) -> Self {
+        Self::Gaussian(std)
+    }
+}
+
+impl From<TUniform> for DynamicDistribution {
+    fn from(t_uniform: TUniform) -> Self {
+        Self::TUniform(t_uniform)
+    }
+}
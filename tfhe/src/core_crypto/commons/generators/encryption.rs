@@ -1,9 +1,11 @@
 //! Module containing primitives pertaining to random generation in the context of encryption.
 
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, DynamicDistribution, NoiseDistribution,
+};
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
-    Seed, Seeder, Uniform,
+    RandomGeneratorBackend, RuntimeRandomGenerator, Seed, Seeder, TUniform, Uniform,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
@@ -207,103 +209,179 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     }
 
     // Sample a noise value, using the noise generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl NoiseDistribution) -> Scalar
     where
-        Scalar: RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        <Scalar>::generate_one(
-            &mut self.noise,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-        )
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => <Scalar>::generate_one(
+                &mut self.noise,
+                Gaussian {
+                    std: std.get_standard_dev(),
+                    mean: 0.,
+                },
+            ),
+            DynamicDistribution::TUniform(t_uniform) => {
+                <Scalar>::generate_one(&mut self.noise, t_uniform)
+            }
+        }
     }
 
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
-        Scalar: UnsignedInteger + RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
+        Scalar: UnsignedTorus,
     {
-        let custom_modulus_f64: f64 = custom_modulus.get().cast_into();
-        Scalar::generate_one_custom_modulus(
-            &mut self.noise,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-            custom_modulus_f64,
-        )
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => {
+                let custom_modulus_f64: f64 = custom_modulus.get().cast_into();
+                Scalar::generate_one_custom_modulus(
+                    &mut self.noise,
+                    Gaussian {
+                        std: std.get_standard_dev(),
+                        mean: 0.,
+                    },
+                    custom_modulus_f64,
+                )
+            }
+            DynamicDistribution::TUniform(t_uniform) => {
+                self.random_t_uniform_noise_custom_mod(t_uniform, custom_modulus)
+            }
+        }
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
-        (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        self.noise
-            .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => {
+                self.noise
+                    .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev())
+            }
+            DynamicDistribution::TUniform(t_uniform) => output
+                .iter_mut()
+                .for_each(|elem| *elem = self.noise.random_t_uniform(t_uniform)),
+        }
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
-        Scalar: UnsignedInteger,
-        (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
+        Scalar: UnsignedTorus,
     {
-        self.noise.fill_slice_with_random_gaussian_custom_mod(
-            output,
-            0.,
-            std.get_standard_dev(),
-            custom_modulus,
-        );
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => {
+                self.noise.fill_slice_with_random_gaussian_custom_mod(
+                    output,
+                    0.,
+                    std.get_standard_dev(),
+                    custom_modulus,
+                );
+            }
+            DynamicDistribution::TUniform(t_uniform) => output.iter_mut().for_each(|elem| {
+                *elem = self.random_t_uniform_noise_custom_mod(t_uniform, custom_modulus);
+            }),
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
     ) where
         Scalar: UnsignedTorus,
-        (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.noise
-            .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-            );
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => self
+                .noise
+                .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
+                    output,
+                    0.,
+                    std.get_standard_dev(),
+                ),
+            DynamicDistribution::TUniform(t_uniform) => output.iter_mut().for_each(|elem| {
+                *elem = (*elem).wrapping_add(self.noise.random_t_uniform(t_uniform));
+            }),
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl NoiseDistribution,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
-        (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = f64>,
     {
-        self.noise
-            .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-                custom_modulus,
-            );
+        match noise.to_dynamic() {
+            DynamicDistribution::Gaussian(std) => self
+                .noise
+                .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
+                    output,
+                    0.,
+                    std.get_standard_dev(),
+                    custom_modulus,
+                ),
+            DynamicDistribution::TUniform(t_uniform) => output.iter_mut().for_each(|elem| {
+                let noise = self.random_t_uniform_noise_custom_mod(t_uniform, custom_modulus);
+                *elem = (*elem).wrapping_add(noise);
+            }),
+        }
+    }
+
+    // The TUniform values are integers modulo the ciphertext modulus, negative values are
+    // represented by their positive representative.
+    fn random_t_uniform_noise_custom_mod<Scalar>(
+        &mut self,
+        t_uniform: TUniform,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) -> Scalar
+    where
+        Scalar: UnsignedTorus,
+    {
+        if custom_modulus.is_native_modulus() {
+            Scalar::generate_one(&mut self.noise, t_uniform)
+        } else {
+            Scalar::generate_one_custom_modulus(
+                &mut self.noise,
+                t_uniform,
+                custom_modulus.get().cast_into(),
+            )
+        }
+    }
+}
+
+impl EncryptionRandomGenerator<RuntimeRandomGenerator> {
+    /// Create a new [`EncryptionRandomGenerator`] using the given backend for both the mask and
+    /// the noise generators, see [`EncryptionRandomGenerator::new`].
+    pub fn new_with_backend<S: Seeder + ?Sized>(
+        seed: Seed,
+        seeder: &mut S,
+        backend: RandomGeneratorBackend,
+    ) -> Self {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new_with_backend(seed, backend),
+            noise: RandomGenerator::new_with_backend(seeder.seed(), backend),
+        }
+    }
+
+    pub fn backend(&self) -> RandomGeneratorBackend {
+        self.mask.backend()
     }
 }
 
@@ -475,7 +553,7 @@ fn mask_bytes_per_gsw_level<T: UnsignedInteger>(lwe_size: LweSize) -> usize {
     lwe_size.0 * mask_bytes_per_lwe::<T>(lwe_size.to_lwe_dimension())
 }
 
-fn mask_bytes_per_ggsw<T: UnsignedInteger>(
+pub(crate) fn mask_bytes_per_ggsw<T: UnsignedInteger>(
     level: DecompositionLevelCount,
     glwe_size: GlweSize,
     poly_size: PolynomialSize,
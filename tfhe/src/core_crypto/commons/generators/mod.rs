@@ -2,6 +2,7 @@
 //! [`TFHE-rs`](`crate`).
 
 mod encryption;
+pub(crate) use encryption::mask_bytes_per_ggsw;
 pub use encryption::EncryptionRandomGenerator;
 
 mod secret;
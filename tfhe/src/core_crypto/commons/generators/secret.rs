@@ -2,7 +2,8 @@
 //! generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, RandomGeneratorBackend,
+    RuntimeRandomGenerator, Seed, UniformBinary,
 };
 
 /// A random number generator which can be used to generate secret keys.
@@ -26,3 +27,14 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
 }
+
+impl SecretRandomGenerator<RuntimeRandomGenerator> {
+    /// Create a new generator using the given backend.
+    pub fn new_with_backend(seed: Seed, backend: RandomGeneratorBackend) -> Self {
+        SecretRandomGenerator(RandomGenerator::new_with_backend(seed, backend))
+    }
+
+    pub fn backend(&self) -> RandomGeneratorBackend {
+        self.0.backend()
+    }
+}
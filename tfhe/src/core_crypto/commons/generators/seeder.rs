@@ -1,7 +1,8 @@
 //! Module containing primitives pertaining to random generation in the context of seeds generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Seeder, Uniform,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, RandomGeneratorBackend,
+    RuntimeRandomGenerator, Seed, Seeder, Uniform,
 };
 
 /// Seeder backed by a CSPRNG
@@ -44,6 +45,14 @@ impl<G: ByteRandomGenerator> DeterministicSeeder<G> {
     }
 }
 
+impl DeterministicSeeder<RuntimeRandomGenerator> {
+    pub fn new_with_backend(seed: Seed, backend: RandomGeneratorBackend) -> Self {
+        DeterministicSeeder {
+            generator: RandomGenerator::new_with_backend(seed, backend),
+        }
+    }
+}
+
 impl<G: ByteRandomGenerator> Seeder for DeterministicSeeder<G> {
     fn seed(&mut self) -> Seed {
         Seed(u128::generate_one(&mut self.generator, Uniform))
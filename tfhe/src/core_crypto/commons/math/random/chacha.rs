@@ -0,0 +1,239 @@
+//! A CSPRNG based on the ChaCha20 stream cipher, for the platforms without hardware AES.
+use super::{ByteRandomGenerator, ParallelByteRandomGenerator, Seed};
+use concrete_csprng::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use rayon::prelude::*;
+
+const BLOCK_BYTES: usize = 64;
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// A random generator outputting the ChaCha20 keystream of a key derived from the seed.
+///
+/// The 128 bits of the seed are the first half of the 256 bits key, the other half being zero.
+/// The 128 bits of counter and nonce of the cipher state are used as a single block counter, so
+/// that the stream of a generator is never exhausted in practice.
+///
+/// As for the AES based generators, forking hands out disjoint contiguous chunks of the
+/// keystream to the children, which makes the outputs independent of the parallelism.
+#[derive(Clone)]
+pub struct ChaCha20RandomGenerator {
+    key: [u32; 8],
+    // Index of the next byte of the keystream
+    position: u128,
+    // Index of the first byte the generator is not allowed to output
+    bound: u128,
+    // Keystream block containing the byte at `position`, if it was already computed
+    block: Option<(u128, [u8; BLOCK_BYTES])>,
+}
+
+impl ChaCha20RandomGenerator {
+    fn children(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Vec<Self>, ForkError> {
+        if n_children.0 == 0 {
+            return Err(ForkError::ZeroChildrenCount);
+        }
+        if n_bytes.0 == 0 {
+            return Err(ForkError::ZeroBytesPerChild);
+        }
+        let bytes_per_child = n_bytes.0 as u128;
+        let total_bytes = (n_children.0 as u128)
+            .checked_mul(bytes_per_child)
+            .filter(|total| *total <= self.bound - self.position)
+            .ok_or(ForkError::ForkTooLarge)?;
+
+        let first_position = self.position;
+        self.position += total_bytes;
+        Ok((0..n_children.0 as u128)
+            .map(|index| {
+                let position = first_position + index * bytes_per_child;
+                Self {
+                    key: self.key,
+                    position,
+                    bound: position + bytes_per_child,
+                    block: None,
+                }
+            })
+            .collect())
+    }
+}
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+/// Compute the keystream block of index `counter`, the counter taking the place of the block
+/// counter and of the nonce of RFC 8439.
+fn chacha20_block(key: &[u32; 8], counter: u128) -> [u8; BLOCK_BYTES] {
+    let mut initial = [0u32; 16];
+    initial[..4].copy_from_slice(&CONSTANTS);
+    initial[4..12].copy_from_slice(key);
+    for (i, word) in initial[12..].iter_mut().enumerate() {
+        *word = (counter >> (32 * i)) as u32;
+    }
+
+    let mut state = initial;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut block = [0u8; BLOCK_BYTES];
+    for ((bytes, word), initial_word) in block.chunks_exact_mut(4).zip(state).zip(initial) {
+        bytes.copy_from_slice(&word.wrapping_add(initial_word).to_le_bytes());
+    }
+    block
+}
+
+impl Iterator for ChaCha20RandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.position >= self.bound {
+            return None;
+        }
+        let block_index = self.position / BLOCK_BYTES as u128;
+        let block = match self.block {
+            Some((index, block)) if index == block_index => block,
+            _ => {
+                let block = chacha20_block(&self.key, block_index);
+                self.block = Some((block_index, block));
+                block
+            }
+        };
+        let byte = block[(self.position % BLOCK_BYTES as u128) as usize];
+        self.position += 1;
+        Some(byte)
+    }
+}
+
+impl ByteRandomGenerator for ChaCha20RandomGenerator {
+    type ChildrenIter = std::vec::IntoIter<Self>;
+
+    fn new(seed: Seed) -> Self {
+        let mut key = [0u32; 8];
+        for (i, word) in key[..4].iter_mut().enumerate() {
+            *word = (seed.0 >> (32 * i)) as u32;
+        }
+        Self {
+            key,
+            position: 0,
+            bound: u128::MAX,
+            block: None,
+        }
+    }
+
+    fn remaining_bytes(&self) -> ByteCount {
+        ByteCount(self.bound - self.position)
+    }
+
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.children(n_children, n_bytes).map(Vec::into_iter)
+    }
+}
+
+impl ParallelByteRandomGenerator for ChaCha20RandomGenerator {
+    type ParChildrenIter = rayon::vec::IntoIter<Self>;
+
+    fn par_try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ParChildrenIter, ForkError> {
+        self.children(n_children, n_bytes)
+            .map(IntoParallelIterator::into_par_iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chacha20_block_rfc_8439() {
+        // Test vector of RFC 8439, section 2.3.2, the 32 bits block counter being followed by the
+        // 96 bits nonce
+        let key: [u32; 8] = core::array::from_fn(|i| {
+            u32::from_le_bytes(core::array::from_fn(|j| (4 * i + j) as u8))
+        });
+        let counter = 1 | (0x0900_0000 << 32) | (0x4a00_0000 << 64);
+        let expected: [u8; BLOCK_BYTES] = [
+            0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20,
+            0x71, 0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a,
+            0xc3, 0xd4, 0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2,
+            0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9,
+            0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+        ];
+        assert_eq!(chacha20_block(&key, counter), expected);
+    }
+
+    #[test]
+    fn test_chacha20_fork_outputs_parent_stream() {
+        let seed = Seed(rand::random());
+        let stream: Vec<u8> = ChaCha20RandomGenerator::new(seed).take(1000).collect();
+
+        let mut generator = ChaCha20RandomGenerator::new(seed);
+        let mut forked = Vec::new();
+        for child in generator
+            .try_fork(ChildrenCount(7), BytesPerChild(99))
+            .unwrap()
+        {
+            assert_eq!(child.remaining_bytes().0, 99);
+            forked.extend(child);
+        }
+        for child in generator
+            .par_try_fork(ChildrenCount(3), BytesPerChild(5))
+            .unwrap()
+            .collect::<Vec<_>>()
+        {
+            forked.extend(child);
+        }
+        forked.extend(generator.take(1000 - forked.len()));
+
+        assert_eq!(forked, stream);
+    }
+
+    #[test]
+    fn test_chacha20_fork_errors() {
+        let mut generator = ChaCha20RandomGenerator::new(Seed(0));
+        let mut child = generator
+            .try_fork(ChildrenCount(1), BytesPerChild(10))
+            .unwrap()
+            .next()
+            .unwrap();
+
+        assert!(matches!(
+            child.try_fork(ChildrenCount(0), BytesPerChild(1)),
+            Err(ForkError::ZeroChildrenCount)
+        ));
+        assert!(matches!(
+            child.try_fork(ChildrenCount(1), BytesPerChild(0)),
+            Err(ForkError::ZeroBytesPerChild)
+        ));
+        assert!(matches!(
+            child.try_fork(ChildrenCount(3), BytesPerChild(4)),
+            Err(ForkError::ForkTooLarge)
+        ));
+        assert!(child.try_fork(ChildrenCount(2), BytesPerChild(5)).is_ok());
+        assert_eq!(child.next(), None);
+    }
+}
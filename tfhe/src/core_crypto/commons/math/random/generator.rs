@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    Gaussian, RandomGenerable, RandomGeneratorBackend, RuntimeRandomGenerator, TUniform, Uniform,
+    UniformBinary, UniformLsb, UniformMsb, UniformTernary, UniformWithZeros,
 };
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastFrom, CastInto, FloatingPoint};
@@ -268,6 +268,26 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_one(self, UniformTernary)
     }
 
+    /// Generate a random value following a [`TUniform`] distribution, negative values wrapping
+    /// around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let random: u64 = generator.random_t_uniform(TUniform::new(3));
+    /// assert!(random <= 8 || random.wrapping_neg() <= 8);
+    /// ```
+    pub fn random_t_uniform<Scalar: RandomGenerable<TUniform>>(
+        &mut self,
+        distribution: TUniform,
+    ) -> Scalar {
+        Scalar::generate_one(self, distribution)
+    }
+
     /// Generate an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
@@ -511,6 +531,31 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
     }
 }
 
+impl RandomGenerator<RuntimeRandomGenerator> {
+    /// Generate a new generator using the given backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{
+    ///     RandomGenerator, RandomGeneratorBackend, RuntimeRandomGenerator,
+    /// };
+    /// let mut generator = RandomGenerator::<RuntimeRandomGenerator>::new_with_backend(
+    ///     Seed(0),
+    ///     RandomGeneratorBackend::ChaCha20,
+    /// );
+    /// assert_eq!(generator.backend(), RandomGeneratorBackend::ChaCha20);
+    /// ```
+    pub fn new_with_backend(seed: Seed, backend: RandomGeneratorBackend) -> Self {
+        Self(RuntimeRandomGenerator::new_with_backend(seed, backend))
+    }
+
+    pub fn backend(&self) -> RandomGeneratorBackend {
+        self.0.backend()
+    }
+}
+
 impl<G: ParallelByteRandomGenerator> RandomGenerator<G> {
     /// Tries to fork the current generator into `n_child` generator bounded to `bytes_per_child`,
     /// as a parallel iterator.
@@ -4,9 +4,11 @@
 //! randomly according to a given distribution, for instance:
 //!
 //! + [`RandomGenerator::random_uniform`] samples a random unsigned integer with uniform
-//! probability over the set of representable values.
+//!   probability over the set of representable values.
 //! + [`RandomGenerator::random_gaussian`] samples a random float with using a gaussian
-//! distribution.
+//!   distribution.
+//! + [`RandomGenerator::random_t_uniform`] samples a random unsigned integer representing a
+//!   small signed value, with a bounded uniform distribution.
 //!
 //! The implementation relies on the [`RandomGenerable`] trait, which gives a type the ability to
 //! be randomly generated according to a given distribution. The module contains multiple
@@ -17,8 +19,11 @@ use crate::core_crypto::commons::numeric::FloatingPoint;
 
 /// Convenience alias for the most efficient CSPRNG implementation available.
 pub use activated_random_generator::ActivatedRandomGenerator;
+pub use chacha::*;
 pub use gaussian::*;
 pub use generator::*;
+pub use runtime_random_generator::*;
+pub use t_uniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
 pub use uniform_lsb::*;
@@ -30,8 +35,11 @@ pub use uniform_with_zeros::*;
 mod tests;
 
 mod activated_random_generator;
+mod chacha;
 mod gaussian;
 mod generator;
+mod runtime_random_generator;
+mod t_uniform;
 mod uniform;
 mod uniform_binary;
 mod uniform_lsb;
@@ -85,6 +93,7 @@ mod seal {
     impl Sealed for super::UniformWithZeros {}
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
+    impl Sealed for super::TUniform {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
 }
 impl Distribution for Uniform {}
@@ -93,4 +102,5 @@ impl Distribution for UniformLsb {}
 impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
+impl Distribution for TUniform {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
@@ -0,0 +1,224 @@
+//! A CSPRNG whose backend is chosen when it is created rather than when the crate is compiled.
+use super::{ByteRandomGenerator, ChaCha20RandomGenerator, ParallelByteRandomGenerator, Seed};
+#[cfg(feature = "generator_x86_64_aesni")]
+use concrete_csprng::generators::AesniRandomGenerator;
+#[cfg(feature = "generator_aarch64_aes")]
+use concrete_csprng::generators::NeonAesRandomGenerator;
+use concrete_csprng::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, SoftwareRandomGenerator,
+};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The implementations of CSPRNG a [`RuntimeRandomGenerator`] can use.
+///
+/// The AES based backends all output the same stream for a given seed, the ChaCha20 one outputs a
+/// different stream. The hardware backends are only available if the crate was compiled with the
+/// matching `generator_*` feature and if the CPU supports the instructions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RandomGeneratorBackend {
+    /// AES-CTR, implemented in software.
+    SoftwareAes,
+    /// AES-CTR using the x86_64 AES-NI instructions.
+    AesNi,
+    /// AES-CTR using the aarch64 AES instructions.
+    NeonAes,
+    /// ChaCha20, for the platforms without hardware AES.
+    ChaCha20,
+}
+
+impl RandomGeneratorBackend {
+    /// The backend of the [`ActivatedRandomGenerator`](super::ActivatedRandomGenerator), selected
+    /// at compile time.
+    pub const fn activated() -> Self {
+        if cfg!(feature = "generator_x86_64_aesni") {
+            Self::AesNi
+        } else if cfg!(feature = "generator_aarch64_aes") {
+            Self::NeonAes
+        } else {
+            Self::SoftwareAes
+        }
+    }
+
+    /// Return whether a generator using this backend can be created.
+    pub fn is_available(self) -> bool {
+        match self {
+            Self::SoftwareAes | Self::ChaCha20 => true,
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Self::AesNi => is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2"),
+            #[cfg(not(feature = "generator_x86_64_aesni"))]
+            Self::AesNi => false,
+            #[cfg(feature = "generator_aarch64_aes")]
+            Self::NeonAes => std::arch::is_aarch64_feature_detected!("aes"),
+            #[cfg(not(feature = "generator_aarch64_aes"))]
+            Self::NeonAes => false,
+        }
+    }
+
+    /// The backends available on this machine.
+    pub fn available() -> Vec<Self> {
+        [
+            Self::SoftwareAes,
+            Self::AesNi,
+            Self::NeonAes,
+            Self::ChaCha20,
+        ]
+        .into_iter()
+        .filter(|backend| backend.is_available())
+        .collect()
+    }
+}
+
+impl Default for RandomGeneratorBackend {
+    fn default() -> Self {
+        Self::activated()
+    }
+}
+
+/// A random generator dispatching to the [`RandomGeneratorBackend`] given at its creation.
+///
+/// [`ByteRandomGenerator::new`] uses the [default](RandomGeneratorBackend::activated) backend,
+/// the generators forked from a generator use the backend of their parent.
+pub enum RuntimeRandomGenerator {
+    SoftwareAes(SoftwareRandomGenerator),
+    #[cfg(feature = "generator_x86_64_aesni")]
+    AesNi(AesniRandomGenerator),
+    #[cfg(feature = "generator_aarch64_aes")]
+    NeonAes(NeonAesRandomGenerator),
+    ChaCha20(ChaCha20RandomGenerator),
+}
+
+macro_rules! dispatch {
+    ($generator:expr, $inner:ident => $body:expr) => {
+        match $generator {
+            Self::SoftwareAes($inner) => $body,
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Self::AesNi($inner) => $body,
+            #[cfg(feature = "generator_aarch64_aes")]
+            Self::NeonAes($inner) => $body,
+            Self::ChaCha20($inner) => $body,
+        }
+    };
+}
+
+impl RuntimeRandomGenerator {
+    /// Create a generator using the given backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not [available](RandomGeneratorBackend::is_available).
+    pub fn new_with_backend(seed: Seed, backend: RandomGeneratorBackend) -> Self {
+        assert!(
+            backend.is_available(),
+            "The {backend:?} random generator backend is not available on this machine"
+        );
+        match backend {
+            RandomGeneratorBackend::SoftwareAes => {
+                Self::SoftwareAes(SoftwareRandomGenerator::new(seed))
+            }
+            #[cfg(feature = "generator_x86_64_aesni")]
+            RandomGeneratorBackend::AesNi => Self::AesNi(AesniRandomGenerator::new(seed)),
+            #[cfg(feature = "generator_aarch64_aes")]
+            RandomGeneratorBackend::NeonAes => Self::NeonAes(NeonAesRandomGenerator::new(seed)),
+            RandomGeneratorBackend::ChaCha20 => Self::ChaCha20(ChaCha20RandomGenerator::new(seed)),
+            // Not available, the assert above fails
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn backend(&self) -> RandomGeneratorBackend {
+        match self {
+            Self::SoftwareAes(_) => RandomGeneratorBackend::SoftwareAes,
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Self::AesNi(_) => RandomGeneratorBackend::AesNi,
+            #[cfg(feature = "generator_aarch64_aes")]
+            Self::NeonAes(_) => RandomGeneratorBackend::NeonAes,
+            Self::ChaCha20(_) => RandomGeneratorBackend::ChaCha20,
+        }
+    }
+}
+
+impl Iterator for RuntimeRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        dispatch!(self, generator => generator.next())
+    }
+}
+
+impl ByteRandomGenerator for RuntimeRandomGenerator {
+    type ChildrenIter = std::vec::IntoIter<Self>;
+
+    fn new(seed: Seed) -> Self {
+        Self::new_with_backend(seed, RandomGeneratorBackend::default())
+    }
+
+    fn remaining_bytes(&self) -> ByteCount {
+        dispatch!(self, generator => generator.remaining_bytes())
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        dispatch!(self, generator => generator.next_byte())
+    }
+
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        let children: Vec<Self> = match self {
+            Self::SoftwareAes(generator) => generator
+                .try_fork(n_children, n_bytes)?
+                .map(Self::SoftwareAes)
+                .collect(),
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Self::AesNi(generator) => generator
+                .try_fork(n_children, n_bytes)?
+                .map(Self::AesNi)
+                .collect(),
+            #[cfg(feature = "generator_aarch64_aes")]
+            Self::NeonAes(generator) => generator
+                .try_fork(n_children, n_bytes)?
+                .map(Self::NeonAes)
+                .collect(),
+            Self::ChaCha20(generator) => generator
+                .try_fork(n_children, n_bytes)?
+                .map(Self::ChaCha20)
+                .collect(),
+        };
+        Ok(children.into_iter())
+    }
+}
+
+impl ParallelByteRandomGenerator for RuntimeRandomGenerator {
+    type ParChildrenIter = rayon::vec::IntoIter<Self>;
+
+    fn par_try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ParChildrenIter, ForkError> {
+        let children: Vec<Self> = match self {
+            Self::SoftwareAes(generator) => generator
+                .par_try_fork(n_children, n_bytes)?
+                .map(Self::SoftwareAes)
+                .collect(),
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Self::AesNi(generator) => generator
+                .par_try_fork(n_children, n_bytes)?
+                .map(Self::AesNi)
+                .collect(),
+            #[cfg(feature = "generator_aarch64_aes")]
+            Self::NeonAes(generator) => generator
+                .par_try_fork(n_children, n_bytes)?
+                .map(Self::NeonAes)
+                .collect(),
+            Self::ChaCha20(generator) => generator
+                .par_try_fork(n_children, n_bytes)?
+                .map(Self::ChaCha20)
+                .collect(),
+        };
+        Ok(children.into_par_iter())
+    }
+}
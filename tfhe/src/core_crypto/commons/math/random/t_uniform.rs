@@ -0,0 +1,116 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// A distribution type representing the bounded uniform sampling of a noise value.
+///
+/// The sampled values are the integers of $[-2^b, 2^b]$ where $b$ is `bound_log2`, the two bounds
+/// being sampled with probability $1/2^{b+2}$ and the other values with probability $1/2^{b+1}$.
+/// A value is generated from $b + 2$ random bits, without rejection.
+///
+/// The values are integers modulo the ciphertext modulus, i.e. the bound does not depend on the
+/// size of the type the values are stored in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TUniform {
+    bound_log2: u32,
+}
+
+impl TUniform {
+    /// Create a distribution of the integers of $[-2^{bound\_log2}, 2^{bound\_log2}]$.
+    pub const fn new(bound_log2: u32) -> Self {
+        Self { bound_log2 }
+    }
+
+    pub const fn bound_log2(&self) -> u32 {
+        self.bound_log2
+    }
+
+    /// Return the variance of the distribution, for a modulus of $2^{log2\_modulus}$, i.e. of
+    /// the distribution of the values divided by the modulus.
+    pub fn variance(&self, log2_modulus: u32) -> f64 {
+        let bound = 2f64.powi(self.bound_log2 as i32);
+        (2. * bound * bound + 1.) / 6. / 2f64.powi(2 * log2_modulus as i32)
+    }
+
+    /// Sample the signed value as the (magnitude, is_negative) pair.
+    fn sample<G: ByteRandomGenerator>(&self, generator: &mut RandomGenerator<G>) -> (u128, bool) {
+        let bit_count = self.bound_log2 + 2;
+        assert!(
+            bit_count <= u128::BITS,
+            "TUniform bound 2^{} is too large",
+            self.bound_log2
+        );
+        let mut bytes = [0u8; core::mem::size_of::<u128>()];
+        bytes
+            .iter_mut()
+            .take(((bit_count + 7) / 8) as usize)
+            .for_each(|byte| *byte = generator.generate_next());
+        let mut bits = u128::from_le_bytes(bytes);
+        if bit_count < u128::BITS {
+            bits &= (1 << bit_count) - 1;
+        }
+
+        // With bits = 2y + z, y + z is in [0, 2^(b + 1)] with the expected probabilities
+        let shifted = (bits >> 1) + (bits & 1);
+        let bound = 1u128 << self.bound_log2;
+        if shifted >= bound {
+            (shifted - bound, false)
+        } else {
+            (bound - shifted, true)
+        }
+    }
+}
+
+macro_rules! implement_t_uniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            type CustomModulus = $T;
+
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+            ) -> Self {
+                assert!(
+                    distribution.bound_log2 + 1 < <$T>::BITS,
+                    "TUniform bound 2^{} is too large for {}",
+                    distribution.bound_log2,
+                    stringify!($T)
+                );
+                let (magnitude, is_negative) = distribution.sample(generator);
+                let magnitude = magnitude as $T;
+                if is_negative {
+                    magnitude.wrapping_neg()
+                } else {
+                    magnitude
+                }
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+                custom_modulus: Self::CustomModulus,
+            ) -> Self {
+                assert!(
+                    (1 as $T)
+                        .checked_shl(distribution.bound_log2 + 1)
+                        .map_or(false, |double_bound| double_bound < custom_modulus),
+                    "TUniform bound 2^{} is too large for the modulus {}",
+                    distribution.bound_log2,
+                    custom_modulus
+                );
+                let (magnitude, is_negative) = distribution.sample(generator);
+                let magnitude = magnitude as $T;
+                if is_negative && magnitude != 0 {
+                    custom_modulus - magnitude
+                } else {
+                    magnitude
+                }
+            }
+        }
+    };
+}
+
+implement_t_uniform!(u8);
+implement_t_uniform!(u16);
+implement_t_uniform!(u32);
+implement_t_uniform!(u64);
+implement_t_uniform!(u128);
@@ -1,6 +1,12 @@
 use crate::core_crypto::commons::dispersion::LogStandardDev;
+use crate::core_crypto::commons::math::random::{
+    RandomGenerable, RandomGenerator, RandomGeneratorBackend, RuntimeRandomGenerator, Seed,
+    TUniform,
+};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto};
 use crate::core_crypto::commons::test_tools::*;
+use rayon::prelude::*;
 
 fn test_normal_random<T: UnsignedTorus>() {
     //! test if the normal random generation with std_dev is below 3*std_dev (99.7%)
@@ -57,6 +63,101 @@ fn test_normal_random_u64() {
     test_normal_random::<u64>();
 }
 
+fn test_t_uniform_random<T: UnsignedTorus>() {
+    //! test that the TUniform samples are in the bounds, the bounds being sampled half as often
+    //! as the other values
+    let bound_log2 = 2;
+    let bound = 1i64 << bound_log2;
+    let k = 1_000_000;
+    let mut generator = new_random_generator();
+
+    let mut counts = vec![0usize; 2 * bound as usize + 1];
+    for _ in 0..k {
+        let sample: T = generator.random_t_uniform(TUniform::new(bound_log2));
+        let value = if sample <= T::cast_from(bound as u128) {
+            let magnitude: u128 = sample.cast_into();
+            magnitude as i64
+        } else {
+            let magnitude: u128 = sample.wrapping_neg().cast_into();
+            -(magnitude as i64)
+        };
+        assert!(value.abs() <= bound, "{value} is out of the bounds");
+        counts[(value + bound) as usize] += 1;
+    }
+
+    // The values are sampled with probability 1/8 and the bounds with probability 1/16
+    let expected = k as f64 / (2 * bound) as f64;
+    for (index, &count) in counts.iter().enumerate() {
+        let expected = if index == 0 || index == counts.len() - 1 {
+            expected / 2.
+        } else {
+            expected
+        };
+        assert!(
+            (count as f64 - expected).abs() < 0.05 * expected,
+            "count {count} for {} is too far from {expected}",
+            index as i64 - bound
+        );
+    }
+}
+
+#[test]
+fn test_t_uniform_random_u32() {
+    test_t_uniform_random::<u32>();
+}
+
+#[test]
+fn test_t_uniform_random_u64() {
+    test_t_uniform_random::<u64>();
+}
+
+#[test]
+fn test_t_uniform_random_custom_modulus() {
+    let mut generator = new_random_generator();
+    let modulus = 1u64 << 20;
+
+    for _ in 0..10_000 {
+        let sample = u64::generate_one_custom_modulus(&mut generator, TUniform::new(5), modulus);
+        assert!(sample <= 32 || (sample < modulus && modulus - sample <= 32));
+    }
+}
+
+#[test]
+fn test_runtime_random_generator_backends() {
+    let seed = Seed(rand::random());
+    let stream = |backend| {
+        let mut generator =
+            RandomGenerator::<RuntimeRandomGenerator>::new_with_backend(seed, backend);
+        let mut children = generator.par_try_fork(4, 16).unwrap().collect::<Vec<_>>();
+        let mut bytes: Vec<u8> = children
+            .iter_mut()
+            .flat_map(|child| (0..16).map(|_| child.generate_next()).collect::<Vec<_>>())
+            .collect();
+        bytes.extend((0..100).map(|_| generator.generate_next()));
+        bytes
+    };
+
+    let chacha_stream = stream(RandomGeneratorBackend::ChaCha20);
+    let aes_stream = stream(RandomGeneratorBackend::SoftwareAes);
+    assert_ne!(chacha_stream, aes_stream);
+
+    for backend in RandomGeneratorBackend::available() {
+        let mut generator =
+            RandomGenerator::<RuntimeRandomGenerator>::new_with_backend(seed, backend);
+        let child = generator.try_fork(1, 8).unwrap().next().unwrap();
+        assert_eq!(child.backend(), backend);
+        assert_eq!(child.remaining_bytes(), Some(8));
+
+        // The hardware and software implementations of AES output the same stream
+        let expected = if backend == RandomGeneratorBackend::ChaCha20 {
+            &chacha_stream
+        } else {
+            &aes_stream
+        };
+        assert_eq!(&stream(backend), expected);
+    }
+}
+
 fn test_distribution<T: UnsignedTorus>() {
     //! tests gaussianity against the rand crate generation
     // settings
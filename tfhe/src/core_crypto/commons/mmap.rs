@@ -0,0 +1,311 @@
+//! Module containing containers backed by memory-mapped files.
+//!
+//! Keys like an [`LweBootstrapKey`](`crate::core_crypto::entities::LweBootstrapKey`) or an
+//! [`LweKeyswitchKey`](`crate::core_crypto::entities::LweKeyswitchKey`) can weigh hundreds of MB.
+//! A server handling many keys can store their raw data in files (see [`write_container`]) and
+//! create the entities on top of an [`MmapContainer`] instead of loading the data in anonymous
+//! memory: pages are then loaded by the OS on first access, and can be evicted under memory
+//! pressure without going to swap.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::core_crypto::commons::mmap::{write_container, MmapContainer};
+//! use tfhe::core_crypto::prelude::*;
+//!
+//! let glwe_size = GlweSize(2);
+//! let polynomial_size = PolynomialSize(256);
+//! let decomp_base_log = DecompositionBaseLog(8);
+//! let decomp_level_count = DecompositionLevelCount(2);
+//! let input_lwe_dimension = LweDimension(10);
+//! let ciphertext_modulus = CiphertextModulus::new_native();
+//!
+//! let bsk = LweBootstrapKey::new(
+//!     1u64,
+//!     glwe_size,
+//!     polynomial_size,
+//!     decomp_base_log,
+//!     decomp_level_count,
+//!     input_lwe_dimension,
+//!     ciphertext_modulus,
+//! );
+//!
+//! let path = std::env::temp_dir().join("tfhe_mmap_doctest_bsk");
+//! write_container(std::fs::File::create(&path).unwrap(), bsk.as_ref()).unwrap();
+//!
+//! let file = std::fs::File::open(&path).unwrap();
+//! // Safety: the file is not modified while it is mapped
+//! let container = unsafe { MmapContainer::<u64>::map(&file) }.unwrap();
+//!
+//! let mapped_bsk = LweBootstrapKey::from_container(
+//!     container,
+//!     glwe_size,
+//!     polynomial_size,
+//!     decomp_base_log,
+//!     decomp_level_count,
+//!     ciphertext_modulus,
+//! );
+//! assert_eq!(mapped_bsk.input_lwe_dimension(), input_lwe_dimension);
+//! assert_eq!(mapped_bsk.as_ref(), bsk.as_ref());
+//! # drop(mapped_bsk);
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+
+use crate::core_crypto::commons::traits::{Container, ContainerMut};
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use std::fs::File;
+use std::io::Write;
+use std::marker::PhantomData;
+
+fn check_layout<T>(bytes: &[u8]) -> std::io::Result<()> {
+    let elem_size = std::mem::size_of::<T>();
+    if bytes.len() % elem_size != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "Mapped size {} is not a multiple of the element size {elem_size}",
+                bytes.len()
+            ),
+        ));
+    }
+    if bytes.as_ptr() as usize % std::mem::align_of::<T>() != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Mapped data is not aligned for the element type",
+        ));
+    }
+    Ok(())
+}
+
+/// Read only container over a memory-mapped file.
+pub struct MmapContainer<T> {
+    mmap: Mmap,
+    _marker: PhantomData<T>,
+}
+
+impl<T: bytemuck::Pod> MmapContainer<T> {
+    /// Map the whole `file`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this process or another one) while it is mapped.
+    pub unsafe fn map(file: &File) -> std::io::Result<Self> {
+        Self::from_mmap(Mmap::map(file)?)
+    }
+
+    /// Map `len` elements of `file` starting at byte `offset`.
+    ///
+    /// `offset` must be a multiple of the alignment of `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this process or another one) while it is mapped.
+    pub unsafe fn map_range(file: &File, offset: u64, len: usize) -> std::io::Result<Self> {
+        let mmap = MmapOptions::new()
+            .offset(offset)
+            .len(len * std::mem::size_of::<T>())
+            .map(file)?;
+        Self::from_mmap(mmap)
+    }
+
+    pub fn from_mmap(mmap: Mmap) -> std::io::Result<Self> {
+        check_layout::<T>(&mmap)?;
+        Ok(Self {
+            mmap,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn into_mmap(self) -> Mmap {
+        self.mmap
+    }
+}
+
+impl<T: bytemuck::Pod> AsRef<[T]> for MmapContainer<T> {
+    fn as_ref(&self) -> &[T] {
+        bytemuck::cast_slice(&self.mmap)
+    }
+}
+
+impl<T: bytemuck::Pod> Container for MmapContainer<T> {
+    type Element = T;
+}
+
+/// Mutable container over a memory-mapped file, modifications are written back to the file.
+pub struct MmapContainerMut<T> {
+    mmap: MmapMut,
+    _marker: PhantomData<T>,
+}
+
+impl<T: bytemuck::Pod> MmapContainerMut<T> {
+    /// Map the whole `file`, which must be opened for reading and writing.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by another mapping or process while it is mapped.
+    pub unsafe fn map(file: &File) -> std::io::Result<Self> {
+        Self::from_mmap(MmapMut::map_mut(file)?)
+    }
+
+    /// Map `len` elements of `file` starting at byte `offset`.
+    ///
+    /// `offset` must be a multiple of the alignment of `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by another mapping or process while it is mapped.
+    pub unsafe fn map_range(file: &File, offset: u64, len: usize) -> std::io::Result<Self> {
+        let mmap = MmapOptions::new()
+            .offset(offset)
+            .len(len * std::mem::size_of::<T>())
+            .map_mut(file)?;
+        Self::from_mmap(mmap)
+    }
+
+    pub fn from_mmap(mmap: MmapMut) -> std::io::Result<Self> {
+        check_layout::<T>(&mmap)?;
+        Ok(Self {
+            mmap,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Write the modifications back to the file.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.mmap.flush()
+    }
+
+    /// Make the container read only.
+    pub fn make_read_only(self) -> std::io::Result<MmapContainer<T>> {
+        MmapContainer::from_mmap(self.mmap.make_read_only()?)
+    }
+}
+
+impl<T: bytemuck::Pod> AsRef<[T]> for MmapContainerMut<T> {
+    fn as_ref(&self) -> &[T] {
+        bytemuck::cast_slice(&self.mmap)
+    }
+}
+
+impl<T: bytemuck::Pod> AsMut<[T]> for MmapContainerMut<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        bytemuck::cast_slice_mut(&mut self.mmap)
+    }
+}
+
+impl<T: bytemuck::Pod> Container for MmapContainerMut<T> {
+    type Element = T;
+}
+
+impl<T: bytemuck::Pod> ContainerMut for MmapContainerMut<T> {}
+
+/// Write the raw data of `container` to `writer`, in the layout expected by [`MmapContainer`].
+///
+/// The data is written in the native endianness, files are therefore not portable across
+/// architectures.
+pub fn write_container<C, W>(mut writer: W, container: &C) -> std::io::Result<()>
+where
+    C: Container + ?Sized,
+    C::Element: bytemuck::Pod,
+    W: Write,
+{
+    writer.write_all(bytemuck::cast_slice(container.as_ref()))?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::*;
+    use std::path::PathBuf;
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let nonce: u64 = rand::random();
+            Self(std::env::temp_dir().join(format!("tfhe_mmap_{name}_{nonce}")))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_mmap_lwe_keyswitch_key() {
+        let decomp_base_log = DecompositionBaseLog(4);
+        let decomp_level_count = DecompositionLevelCount(3);
+        let input_lwe_dimension = LweDimension(16);
+        let output_lwe_dimension = LweDimension(8);
+        let ciphertext_modulus = CiphertextModulus::new_native();
+
+        let mut ksk = LweKeyswitchKey::new(
+            0u64,
+            decomp_base_log,
+            decomp_level_count,
+            input_lwe_dimension,
+            output_lwe_dimension,
+            ciphertext_modulus,
+        );
+        ksk.as_mut()
+            .iter_mut()
+            .for_each(|elem| *elem = rand::random());
+
+        let file = TempFile::new("ksk");
+        write_container(File::create(&file.0).unwrap(), ksk.as_ref()).unwrap();
+
+        let container =
+            unsafe { MmapContainer::<u64>::map(&File::open(&file.0).unwrap()) }.unwrap();
+        let mapped_ksk = LweKeyswitchKey::from_container(
+            container,
+            decomp_base_log,
+            decomp_level_count,
+            output_lwe_dimension.to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        assert_eq!(mapped_ksk.input_key_lwe_dimension(), input_lwe_dimension);
+        assert_eq!(mapped_ksk.as_ref(), ksk.as_ref());
+    }
+
+    #[test]
+    fn test_mmap_range_and_mut() {
+        let values = (0..64u64).collect::<Vec<_>>();
+
+        let file = TempFile::new("range");
+        write_container(File::create(&file.0).unwrap(), &values).unwrap();
+
+        let offset = 8 * std::mem::size_of::<u64>() as u64;
+        let container =
+            unsafe { MmapContainer::<u64>::map_range(&File::open(&file.0).unwrap(), offset, 16) }
+                .unwrap();
+        assert_eq!(container.as_ref(), &values[8..24]);
+
+        let rw_file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&file.0)
+            .unwrap();
+        let mut container = unsafe { MmapContainerMut::<u64>::map(&rw_file) }.unwrap();
+        container.as_mut()[0] = 42;
+        container.flush().unwrap();
+        drop(container);
+
+        let container =
+            unsafe { MmapContainer::<u64>::map(&File::open(&file.0).unwrap()) }.unwrap();
+        assert_eq!(container.as_ref()[0], 42);
+        assert_eq!(&container.as_ref()[1..], &values[1..]);
+    }
+
+    #[test]
+    fn test_mmap_invalid_size() {
+        let file = TempFile::new("invalid");
+        std::fs::write(&file.0, [0u8; 12]).unwrap();
+
+        let res = unsafe { MmapContainer::<u64>::map(&File::open(&file.0).unwrap()) };
+        assert!(res.is_err());
+    }
+}
@@ -51,6 +51,8 @@ pub mod computation_buffers;
 pub mod dispersion;
 pub mod generators;
 pub mod math;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod numeric;
 pub mod parameters;
 pub mod utils;
@@ -0,0 +1,257 @@
+//! Module with the [`DeserializeInPlace`] trait.
+//!
+//! Deserializing a ciphertext allocates its buffers, which adds up when a server decodes many
+//! ciphertexts of the same shape. Types implementing [`DeserializeInPlace`] can instead be
+//! deserialized into an existing value, reusing its allocations when the incoming value has the
+//! same shape.
+//!
+//! The format of the data is the one of the regular [`serde::Deserialize`] implementation of the
+//! type, so values serialized as usual can be deserialized in place.
+use serde::de::{DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt::Formatter;
+
+/// Deserialize into an existing value, reusing its allocations.
+pub trait DeserializeInPlace {
+    /// Deserialize a value from `deserializer` into `self`.
+    ///
+    /// If an error is returned, `self` is left in a valid but unspecified state.
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Deserialize a value serialized with [`bincode::serialize`] into `self`.
+    ///
+    /// If an error is returned, `self` is left in a valid but unspecified state.
+    #[cfg(feature = "bincode")]
+    fn deserialize_into(&mut self, bytes: &[u8]) -> bincode::Result<()> {
+        use bincode::Options;
+
+        // Same options as bincode::deserialize
+        let options = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+        self.deserialize_in_place(&mut bincode::Deserializer::from_slice(bytes, options))
+    }
+}
+
+/// A [`DeserializeSeed`] deserializing into the wrapped value, to deserialize a part of a value
+/// in place.
+pub struct InPlaceSeed<'a, T>(pub &'a mut T);
+
+impl<'a, 'de, T: DeserializeInPlace> DeserializeSeed<'de> for InPlaceSeed<'a, T> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize_in_place(deserializer)
+    }
+}
+
+macro_rules! impl_deserialize_in_place_by_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl DeserializeInPlace for $ty {
+                fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    *self = serde::Deserialize::deserialize(deserializer)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_in_place_by_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+impl<T: DeserializeInPlace + DeserializeOwned> DeserializeInPlace for Vec<T> {
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VecVisitor<'a, T>(&'a mut Vec<T>);
+
+        impl<'a, 'de, T: DeserializeInPlace + DeserializeOwned> Visitor<'de> for VecVisitor<'a, T> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let vec = self.0;
+                let mut len = 0;
+                // Existing elements are deserialized in place, missing ones are pushed
+                loop {
+                    let got_element = if len < vec.len() {
+                        seq.next_element_seed(InPlaceSeed(&mut vec[len]))?.is_some()
+                    } else {
+                        match seq.next_element::<T>()? {
+                            Some(element) => {
+                                vec.push(element);
+                                true
+                            }
+                            None => false,
+                        }
+                    };
+                    if !got_element {
+                        break;
+                    }
+                    len += 1;
+                }
+                vec.truncate(len);
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor(self))
+    }
+}
+
+/// Description of a struct deserialized in place field by field, see
+/// [`deserialize_struct_in_place`].
+pub(crate) trait StructInPlace {
+    /// Name of the struct, as serialized.
+    const NAME: &'static str;
+    /// Names of the fields, in the order in which they are serialized.
+    const FIELDS: &'static [&'static str];
+
+    /// Deserialize the field at `index` in [`Self::FIELDS`].
+    fn deserialize_field<'de, D>(&mut self, index: usize, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Deserialize a struct serialized with the derived [`serde::Serialize`] into `target`.
+///
+/// This supports formats serializing structs as sequences (like bincode) as well as self
+/// describing formats serializing them as maps.
+pub(crate) fn deserialize_struct_in_place<'de, T, D>(
+    target: &mut T,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    T: StructInPlace,
+    D: Deserializer<'de>,
+{
+    struct FieldSeed<'a, T> {
+        target: &'a mut T,
+        index: usize,
+    }
+
+    impl<'a, 'de, T: StructInPlace> DeserializeSeed<'de> for FieldSeed<'a, T> {
+        type Value = ();
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            self.target.deserialize_field(self.index, deserializer)
+        }
+    }
+
+    struct StructVisitor<'a, T>(&'a mut T);
+
+    impl<'a, 'de, T: StructInPlace> Visitor<'de> for StructVisitor<'a, T> {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            write!(formatter, "struct {}", T::NAME)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            for index in 0..T::FIELDS.len() {
+                let seed = FieldSeed {
+                    target: &mut *self.0,
+                    index,
+                };
+                if seq.next_element_seed(seed)?.is_none() {
+                    return Err(serde::de::Error::invalid_length(index, &self));
+                }
+            }
+            Ok(())
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut seen = vec![false; T::FIELDS.len()];
+            while let Some(key) = map.next_key::<String>()? {
+                let index = T::FIELDS
+                    .iter()
+                    .position(|field| *field == key)
+                    .ok_or_else(|| serde::de::Error::unknown_field(&key, T::FIELDS))?;
+                if seen[index] {
+                    return Err(serde::de::Error::duplicate_field(T::FIELDS[index]));
+                }
+                map.next_value_seed(FieldSeed {
+                    target: &mut *self.0,
+                    index,
+                })?;
+                seen[index] = true;
+            }
+            match seen.iter().position(|seen| !seen) {
+                Some(index) => Err(serde::de::Error::missing_field(T::FIELDS[index])),
+                None => Ok(()),
+            }
+        }
+    }
+
+    deserializer.deserialize_struct(T::NAME, T::FIELDS, StructVisitor(target))
+}
+
+/// Deserialize a field which cannot be deserialized in place by replacing its value.
+pub(crate) fn deserialize_field_by_value<'de, T, D>(
+    field: &mut T,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    *field = T::deserialize(deserializer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::*;
+    use bincode::Options;
+
+    fn deserialize_in_place_from_bincode<T: DeserializeInPlace>(value: &mut T, bytes: &[u8]) {
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+        value
+            .deserialize_in_place(&mut bincode::Deserializer::from_slice(bytes, options))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_vec_deserialize_in_place_reuses_allocation() {
+        let mut vec = vec![0u64; 16];
+        let ptr = vec.as_ptr();
+
+        let bytes = bincode::serialize(&(0..8u64).collect::<Vec<_>>()).unwrap();
+        deserialize_in_place_from_bincode(&mut vec, &bytes);
+        assert_eq!(vec, (0..8).collect::<Vec<_>>());
+        assert_eq!(vec.as_ptr(), ptr);
+
+        let bytes = bincode::serialize(&(0..32u64).collect::<Vec<_>>()).unwrap();
+        deserialize_in_place_from_bincode(&mut vec, &bytes);
+        assert_eq!(vec, (0..32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_lwe_ciphertext_deserialize_in_place() {
+        let ciphertext_modulus = CiphertextModulus::try_new_power_of_2(63).unwrap();
+        let ct = LweCiphertext::from_container((0..11u64).collect::<Vec<_>>(), ciphertext_modulus);
+
+        let mut output = LweCiphertext::new(0u64, LweSize(11), CiphertextModulus::new_native());
+        let ptr = output.as_ref().as_ptr();
+
+        deserialize_in_place_from_bincode(&mut output, &bincode::serialize(&ct).unwrap());
+        assert_eq!(output, ct);
+        assert_eq!(output.as_ref().as_ptr(), ptr);
+    }
+}
@@ -4,10 +4,12 @@
 pub mod container;
 pub mod contiguous_entity_container;
 pub mod create_from;
+pub mod deserialize_in_place;
 
 pub use container::*;
 pub use contiguous_entity_container::*;
 pub use create_from::*;
+pub use deserialize_in_place::{DeserializeInPlace, InPlaceSeed};
 // Convenience re-exports
 pub use super::math::random::{ByteRandomGenerator, ParallelByteRandomGenerator, Seeder};
 pub use super::math::torus::UnsignedTorus;
@@ -1,6 +1,9 @@
 //! Module containing the definition of the [`LweCiphertext`].
 
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::deserialize_in_place::{
+    deserialize_field_by_value, deserialize_struct_in_place, StructInPlace,
+};
 use crate::core_crypto::commons::traits::*;
 
 /// A convenience structure to easily manipulate the body of an [`LweCiphertext`].
@@ -417,6 +420,36 @@ impl<Scalar: UnsignedInteger> LweCiphertextOwned<Scalar> {
     }
 }
 
+impl<Scalar> StructInPlace for LweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedInteger + DeserializeInPlace + serde::de::DeserializeOwned,
+{
+    const NAME: &'static str = "LweCiphertext";
+    const FIELDS: &'static [&'static str] = &["data", "ciphertext_modulus"];
+
+    fn deserialize_field<'de, D>(&mut self, index: usize, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match index {
+            0 => self.data.deserialize_in_place(deserializer),
+            _ => deserialize_field_by_value(&mut self.ciphertext_modulus, deserializer),
+        }
+    }
+}
+
+impl<Scalar> DeserializeInPlace for LweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedInteger + DeserializeInPlace + serde::de::DeserializeOwned,
+{
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_struct_in_place(self, deserializer)
+    }
+}
+
 /// Metadata used in the [`CreateFrom`] implementation to create [`LweCiphertext`] entities.
 #[derive(Clone, Copy)]
 pub struct LweCiphertextCreationMetadata<Scalar: UnsignedInteger>(pub CiphertextModulus<Scalar>);
@@ -0,0 +1,265 @@
+//! Module containing the definition of the [`LweCompactCiphertextList`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A compact list of [`LWE ciphertexts`](`crate::core_crypto::entities::LweCiphertext`) encrypted
+/// with an [`LweCompactPublicKey`].
+///
+/// Instead of storing an $n$ elements mask per ciphertext, the list stores a single mask
+/// polynomial of size $n$ for each group of up to $n$ ciphertexts followed by the bodies of all the
+/// ciphertexts, $n$ being the [`LweDimension`] of the ciphertexts. The list can be expanded into a
+/// regular [`LweCiphertextList`] with
+/// [`crate::core_crypto::algorithms::expand_lwe_compact_ciphertext_list`].
+///
+/// # Formal Definition
+///
+/// Encrypting a list of up to $n$ plaintexts $M(X) = \sum\_{k} m\_k X^k$ with the compact public key
+/// $(A, B)$ samples a random binary polynomial $R$ and small gaussian polynomials $E\_1$ and $E\_2$
+/// to compute the mask $P = A \cdot R + E\_1$ and the bodies $D = B \cdot R + E\_2 + M$.
+///
+/// As $D - P \cdot S = M + E \cdot R + E\_2 - E\_1 \cdot S$, the $k$-th coefficient of $D$ is the
+/// body of an LWE ciphertext of $m\_k$, whose mask is obtained by reading the coefficients of $P$
+/// involved in the $k$-th coefficient of the negacyclic product $P \cdot S$.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LweCompactCiphertextList<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    lwe_size: LweSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweCompactCiphertextList<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for LweCompactCiphertextList<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of mask polynomials needed to store `lwe_ciphertext_count` ciphertexts of a
+/// given [`LweDimension`] in an [`LweCompactCiphertextList`].
+pub fn lwe_compact_ciphertext_list_mask_count(
+    lwe_dimension: LweDimension,
+    lwe_ciphertext_count: LweCiphertextCount,
+) -> usize {
+    (lwe_ciphertext_count.0 + lwe_dimension.0 - 1) / lwe_dimension.0
+}
+
+/// Return the number of elements in an [`LweCompactCiphertextList`] given an [`LweDimension`] and
+/// an [`LweCiphertextCount`].
+pub fn lwe_compact_ciphertext_list_size(
+    lwe_dimension: LweDimension,
+    lwe_ciphertext_count: LweCiphertextCount,
+) -> usize {
+    lwe_compact_ciphertext_list_mask_count(lwe_dimension, lwe_ciphertext_count) * lwe_dimension.0
+        + lwe_ciphertext_count.0
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweCompactCiphertextList<C> {
+    /// Create an [`LweCompactCiphertextList`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to encrypt data
+    /// you need to use
+    /// [`crate::core_crypto::algorithms::encrypt_lwe_compact_ciphertext_list_with_compact_public_key`]
+    /// using this list as output.
+    ///
+    /// This docstring exhibits [`LweCompactCiphertextList`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for LweCompactCiphertextList creation
+    /// let lwe_size = LweSize(1025);
+    /// let lwe_ciphertext_count = LweCiphertextCount(1500);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new LweCompactCiphertextList
+    /// let lwe_compact_list =
+    ///     LweCompactCiphertextList::new(0u64, lwe_size, lwe_ciphertext_count, ciphertext_modulus);
+    ///
+    /// assert_eq!(lwe_compact_list.lwe_size(), lwe_size);
+    /// assert_eq!(lwe_compact_list.lwe_ciphertext_count(), lwe_ciphertext_count);
+    /// assert_eq!(lwe_compact_list.ciphertext_modulus(), ciphertext_modulus);
+    /// // 1500 ciphertexts of dimension 1024 need two masks
+    /// assert_eq!(lwe_compact_list.mask_count(), 2);
+    /// assert_eq!(lwe_compact_list.as_ref().len(), 2 * 1024 + 1500);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = lwe_compact_list.into_container();
+    ///
+    /// // Recreate a list using from_container
+    /// let lwe_compact_list = LweCompactCiphertextList::from_container(
+    ///     underlying_container,
+    ///     lwe_size,
+    ///     lwe_ciphertext_count,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(lwe_compact_list.lwe_size(), lwe_size);
+    /// assert_eq!(lwe_compact_list.lwe_ciphertext_count(), lwe_ciphertext_count);
+    /// assert_eq!(lwe_compact_list.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        lwe_size: LweSize,
+        lwe_ciphertext_count: LweCiphertextCount,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> LweCompactCiphertextList<C> {
+        let expected_len =
+            lwe_compact_ciphertext_list_size(lwe_size.to_lwe_dimension(), lwe_ciphertext_count);
+        assert!(
+            container.container_len() == expected_len,
+            "The provided container length is not valid. \
+        Expected length of {expected_len} for lwe_size: {lwe_size:?} and \
+        lwe_ciphertext_count: {lwe_ciphertext_count:?}, got container length: {}.",
+            container.container_len()
+        );
+        LweCompactCiphertextList {
+            data: container,
+            lwe_size,
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`LweSize`] of the [`LweCiphertext`] stored in the list.
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Return the [`LweCiphertextCount`] of the [`LweCompactCiphertextList`].
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        self.lwe_ciphertext_count
+    }
+
+    /// Return the number of mask polynomials stored in the [`LweCompactCiphertextList`].
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn mask_count(&self) -> usize {
+        lwe_compact_ciphertext_list_mask_count(
+            self.lwe_size.to_lwe_dimension(),
+            self.lwe_ciphertext_count,
+        )
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`LweCompactCiphertextList`].
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    /// Return immutable views to the mask polynomials and to the bodies of the
+    /// [`LweCompactCiphertextList`].
+    pub fn get_mask_and_body_list(&self) -> (PolynomialListView<'_, Scalar>, &[Scalar]) {
+        let polynomial_size = PolynomialSize(self.lwe_size.to_lwe_dimension().0);
+        let (masks, bodies) = self
+            .data
+            .as_ref()
+            .split_at(self.mask_count() * polynomial_size.0);
+        (
+            PolynomialListView::from_container(masks, polynomial_size),
+            bodies,
+        )
+    }
+
+    /// Return a view of the [`LweCompactCiphertextList`]. This is useful if an algorithm takes a
+    /// view by value.
+    pub fn as_view(&self) -> LweCompactCiphertextListView<'_, Scalar> {
+        LweCompactCiphertextListView::from_container(
+            self.as_ref(),
+            self.lwe_size,
+            self.lwe_ciphertext_count,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweCompactCiphertextList<C> {
+    /// Mutable variant of [`LweCompactCiphertextList::get_mask_and_body_list`].
+    pub fn get_mut_mask_and_body_list(
+        &mut self,
+    ) -> (PolynomialListMutView<'_, Scalar>, &mut [Scalar]) {
+        let polynomial_size = PolynomialSize(self.lwe_size.to_lwe_dimension().0);
+        let mask_len = self.mask_count() * polynomial_size.0;
+        let (masks, bodies) = self.data.as_mut().split_at_mut(mask_len);
+        (
+            PolynomialListMutView::from_container(masks, polynomial_size),
+            bodies,
+        )
+    }
+
+    /// Mutable variant of [`LweCompactCiphertextList::as_view`].
+    pub fn as_mut_view(&mut self) -> LweCompactCiphertextListMutView<'_, Scalar> {
+        let lwe_size = self.lwe_size;
+        let lwe_ciphertext_count = self.lwe_ciphertext_count;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        LweCompactCiphertextListMutView::from_container(
+            self.as_mut(),
+            lwe_size,
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// An [`LweCompactCiphertextList`] owning the memory for its own storage.
+pub type LweCompactCiphertextListOwned<Scalar> = LweCompactCiphertextList<Vec<Scalar>>;
+/// An [`LweCompactCiphertextList`] immutably borrowing memory for its own storage.
+pub type LweCompactCiphertextListView<'data, Scalar> = LweCompactCiphertextList<&'data [Scalar]>;
+/// An [`LweCompactCiphertextList`] mutably borrowing memory for its own storage.
+pub type LweCompactCiphertextListMutView<'data, Scalar> =
+    LweCompactCiphertextList<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> LweCompactCiphertextListOwned<Scalar> {
+    /// Allocate memory and create a new owned [`LweCompactCiphertextList`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to encrypt data you need to use
+    /// [`crate::core_crypto::algorithms::encrypt_lwe_compact_ciphertext_list_with_compact_public_key`]
+    /// using this list as output.
+    ///
+    /// See [`LweCompactCiphertextList::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        lwe_size: LweSize,
+        lwe_ciphertext_count: LweCiphertextCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> LweCompactCiphertextListOwned<Scalar> {
+        LweCompactCiphertextListOwned::from_container(
+            vec![
+                fill_with;
+                lwe_compact_ciphertext_list_size(lwe_size.to_lwe_dimension(), lwe_ciphertext_count)
+            ],
+            lwe_size,
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        )
+    }
+}
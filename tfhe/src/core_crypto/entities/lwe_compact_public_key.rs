@@ -0,0 +1,173 @@
+//! Module containing the definition of the [`LweCompactPublicKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+// An LweCompactPublicKey is literally a GlweCiphertext with a single mask polynomial, so we wrap a
+// GlweCiphertext and use Deref to have access to all the primitives of the GlweCiphertext easily
+
+/// A [`compact public LWE key`](`LweCompactPublicKey`).
+///
+/// This is a wrapper type of [`GlweCiphertext`], [`std::ops::Deref`] and [`std::ops::DerefMut`]
+/// are implemented to dereference to the underlying [`GlweCiphertext`] for ease of use. See
+/// [`GlweCiphertext`] for additional methods.
+///
+/// # Formal Definition
+///
+/// ## LWE Compact Public Key
+///
+/// An LWE compact public key is a GLWE encryption of 0 with a GLWE dimension of 1 under the
+/// polynomial $S(X) = \sum\_{i=0}^{n-1} s\_i X^i$ built from the LWE secret key
+/// $\vec{s}\in\mathbb{Z}\_q^n$, the polynomial size being equal to the LWE dimension $n$.
+///
+/// It is made of a uniformly random polynomial $A$ and a polynomial $B = A \cdot S + E$ in
+/// $\mathbb{Z}\_q\[X\]/(X^n + 1)$, $E$ being a polynomial with small gaussian coefficients. It is
+/// $n$ times smaller than an [`LwePublicKey`] containing $n$ encryptions of 0 and allows encrypting
+/// up to $n$ messages at once in an [`LweCompactCiphertextList`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LweCompactPublicKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    glwe_ciphertext: GlweCiphertext<C>,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
+    for LweCompactPublicKey<C>
+{
+    type Target = GlweCiphertext<C>;
+
+    fn deref(&self) -> &GlweCiphertext<C> {
+        &self.glwe_ciphertext
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> std::ops::DerefMut
+    for LweCompactPublicKey<C>
+{
+    fn deref_mut(&mut self) -> &mut GlweCiphertext<C> {
+        &mut self.glwe_ciphertext
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweCompactPublicKey<C> {
+    /// Create an [`LweCompactPublicKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate an
+    /// [`LweCompactPublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_lwe_compact_public_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`LweCompactPublicKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for LweCompactPublicKey creation
+    /// let lwe_dimension = LweDimension(1024);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new LweCompactPublicKey
+    /// let lwe_compact_public_key = LweCompactPublicKey::new(0u64, lwe_dimension, ciphertext_modulus);
+    ///
+    /// // This is a method from GlweCiphertext
+    /// assert_eq!(lwe_compact_public_key.glwe_size(), GlweSize(2));
+    /// assert_eq!(
+    ///     lwe_compact_public_key.polynomial_size(),
+    ///     PolynomialSize(lwe_dimension.0)
+    /// );
+    /// // This is a method from LweCompactPublicKey
+    /// assert_eq!(lwe_compact_public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(lwe_compact_public_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = lwe_compact_public_key.into_container();
+    ///
+    /// // Recreate a public key using from_container
+    /// let lwe_compact_public_key =
+    ///     LweCompactPublicKey::from_container(underlying_container, ciphertext_modulus);
+    ///
+    /// assert_eq!(lwe_compact_public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(lwe_compact_public_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> LweCompactPublicKey<C> {
+        assert!(
+            container.container_len() > 0 && container.container_len() % 2 == 0,
+            "The provided container length is not valid. \
+            It needs to be a non zero multiple of 2. Got container length: {}.",
+            container.container_len()
+        );
+        let polynomial_size = PolynomialSize(container.container_len() / 2);
+        LweCompactPublicKey {
+            glwe_ciphertext: GlweCiphertext::from_container(
+                container,
+                polynomial_size,
+                ciphertext_modulus,
+            ),
+        }
+    }
+
+    /// Return the [`LweDimension`] of the [`LweSecretKey`] the [`LweCompactPublicKey`] was
+    /// generated with, which is also the maximum number of messages that can be encrypted in a
+    /// single [`LweCompactCiphertextList`] mask.
+    ///
+    /// See [`LweCompactPublicKey::from_container`] for usage.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.polynomial_size().0)
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`LweCompactPublicKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_ciphertext.into_container()
+    }
+
+    /// Return a view of the [`LweCompactPublicKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> LweCompactPublicKey<&'_ [Scalar]> {
+        LweCompactPublicKey::from_container(self.as_ref(), self.ciphertext_modulus())
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweCompactPublicKey<C> {
+    /// Mutable variant of [`LweCompactPublicKey::as_view`].
+    pub fn as_mut_view(&mut self) -> LweCompactPublicKey<&'_ mut [Scalar]> {
+        let ciphertext_modulus = self.ciphertext_modulus();
+        LweCompactPublicKey::from_container(self.as_mut(), ciphertext_modulus)
+    }
+}
+
+/// An [`LweCompactPublicKey`] owning the memory for its own storage.
+pub type LweCompactPublicKeyOwned<Scalar> = LweCompactPublicKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger> LweCompactPublicKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`LweCompactPublicKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate an [`LweCompactPublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_lwe_compact_public_key`] using this key as
+    /// output.
+    ///
+    /// See [`LweCompactPublicKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        lwe_dimension: LweDimension,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> LweCompactPublicKeyOwned<Scalar> {
+        LweCompactPublicKeyOwned::from_container(
+            vec![fill_with; 2 * lwe_dimension.0],
+            ciphertext_modulus,
+        )
+    }
+}
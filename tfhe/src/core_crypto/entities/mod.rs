@@ -13,6 +13,8 @@ pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
 pub mod lwe_ciphertext;
 pub mod lwe_ciphertext_list;
+pub mod lwe_compact_ciphertext_list;
+pub mod lwe_compact_public_key;
 pub mod lwe_keyswitch_key;
 pub mod lwe_multi_bit_bootstrap_key;
 pub mod lwe_private_functional_packing_keyswitch_key;
@@ -56,6 +58,8 @@ pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_list::*;
+pub use lwe_compact_ciphertext_list::*;
+pub use lwe_compact_public_key::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_multi_bit_bootstrap_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
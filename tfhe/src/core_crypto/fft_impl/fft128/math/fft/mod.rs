@@ -70,6 +70,11 @@ impl Fft128 {
             })
         };
 
+        // we optimistically hope that the plan has already been created, only taking the read lock
+        if let Some(plan) = get_plan() {
+            return Self { plan };
+        }
+
         // could not find a plan of the given size, we lock the map again and try to insert it
         let mut plans = global_plans.write().unwrap();
         if let Entry::Vacant(v) = plans.entry(n) {
@@ -82,6 +87,63 @@ impl Fft128 {
             plan: get_plan().unwrap(),
         }
     }
+
+    /// Create the plans of the given polynomial sizes in the cache shared by all the threads, so
+    /// that the later calls to [`Fft128::new`] with these sizes do not pay the planning cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// Fft128::warm_up_plan_cache([PolynomialSize(1024), PolynomialSize(2048)]);
+    ///
+    /// let cached_sizes = Fft128::cached_plan_sizes();
+    /// assert!(cached_sizes.contains(&PolynomialSize(1024)));
+    /// assert!(cached_sizes.contains(&PolynomialSize(2048)));
+    /// ```
+    pub fn warm_up_plan_cache(sizes: impl IntoIterator<Item = PolynomialSize>) {
+        for size in sizes {
+            Self::new(size);
+        }
+    }
+
+    /// Remove all the plans from the cache, releasing their memory once the [`Fft128`] created from
+    /// them are dropped.
+    ///
+    /// The plans are created again by the next calls to [`Fft128::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let fft = Fft128::new(PolynomialSize(1024));
+    /// assert_eq!(Fft128::cached_plan_sizes(), vec![PolynomialSize(1024)]);
+    ///
+    /// Fft128::clear_plan_cache();
+    /// assert!(Fft128::cached_plan_sizes().is_empty());
+    ///
+    /// // The existing Fft objects can still be used
+    /// let _fft_view = fft.as_view();
+    /// ```
+    pub fn clear_plan_cache() {
+        plans().write().unwrap().clear();
+    }
+
+    /// Return the polynomial sizes whose plan is in the cache, in increasing order.
+    pub fn cached_plan_sizes() -> Vec<PolynomialSize> {
+        let plans = plans().read().unwrap();
+        let mut sizes: Vec<_> = plans
+            .iter()
+            .filter(|(_, plan)| plan.get().is_some())
+            .map(|(&n, _)| PolynomialSize(n))
+            .collect();
+        drop(plans);
+
+        sizes.sort_unstable();
+        sizes
+    }
 }
 
 #[inline(always)]
@@ -1,12 +1,18 @@
 use super::super::math::fft::{Fft, FftView, FourierPolynomialList};
 use super::ggsw::{cmux, *};
-use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_sub_scalar_mul_assign;
+use crate::core_crypto::algorithms::{
+    decompress_seeded_ggsw_ciphertext_with_existing_generator,
+    extract_lwe_sample_from_glwe_ciphertext,
+};
+use crate::core_crypto::commons::generators::mask_bytes_per_ggsw;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension, LweSize,
     ModulusSwitchOffset, MonomialDegree, PolynomialSize,
 };
 use crate::core_crypto::commons::traits::{
@@ -213,34 +219,129 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Number of coefficients of the keyswitched mask computed together by
+/// [`FourierLweBootstrapKeyView::keyswitch_bootstrap`], a cache line of `u64`.
+const KEYSWITCH_CHUNK_SIZE: usize = 8;
+
+/// Return the required memory for [`FourierLweBootstrapKeyView::keyswitch_bootstrap`] and
+/// [`FourierLweBootstrapKeyView::keyswitch_bootstrap_assign`].
+pub fn keyswitch_bootstrap_scratch<Scalar>(
+    keyswitch_input_lwe_dimension: LweDimension,
+    keyswitch_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(
+        keyswitch_input_lwe_dimension.0 * keyswitch_level_count.0,
+        CACHELINE_ALIGN,
+    )?
+    .try_and(bootstrap_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        fft,
+    )?)
+}
+
+fn rotate_lut_by_lwe_body<Scalar: UnsignedTorus + CastInto<usize>>(
+    lut: &mut GlweCiphertextMutView<'_, Scalar>,
+    lwe_body: Scalar,
+) {
+    let monomial_degree = pbs_modulus_switch(
+        lwe_body,
+        lut.polynomial_size(),
+        ModulusSwitchOffset(0),
+        LutCountLog(0),
+    );
+
+    lut.as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign(
+                &mut poly,
+                MonomialDegree(monomial_degree),
+            )
+        });
+}
+
+/// Compute `ct0 <- cmux(ggsw, ct0, ct0 * X^{a_hat})` for a mask element `a`.
+fn rotated_cmux<Scalar: UnsignedTorus + CastInto<usize>>(
+    mut ct0: GlweCiphertextMutView<'_, Scalar>,
+    lwe_mask_element: Scalar,
+    bootstrap_key_ggsw: FourierGgswCiphertextView<'_>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) {
+    let lut_poly_size = ct0.polynomial_size();
+    let ciphertext_modulus = ct0.ciphertext_modulus();
+
+    // We copy ct_0 to ct_1
+    let (mut ct1, stack) = stack.collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
+    let mut ct1 =
+        GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+    // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+    for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
+        polynomial_wrapping_monic_monomial_mul_assign(
+            &mut poly,
+            MonomialDegree(pbs_modulus_switch(
+                lwe_mask_element,
+                lut_poly_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            )),
+        );
+    }
+
+    // ct1 is re-created each loop it can be moved, ct0 is already a view, but
+    // as_mut_view is required to keep borrow rules consistent
+    cmux(ct0.as_mut_view(), ct1, bootstrap_key_ggsw, fft, stack);
+}
+
+fn round_to_ciphertext_modulus<Scalar: UnsignedTorus>(mut ct0: GlweCiphertextMutView<'_, Scalar>) {
+    let ciphertext_modulus = ct0.ciphertext_modulus();
+    if !ciphertext_modulus.is_native_modulus() {
+        // When we convert back from the fourier domain, integer values will contain up to 53
+        // MSBs with information. In our representation of power of 2 moduli < native modulus we
+        // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
+        // round while keeping the data in the MSBs
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        ct0.as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
     // CastInto required for PBS modulus switch which returns a usize
     pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        let level_count = self.decomposition_level_count();
+        self.blind_rotate_with_level_count_assign(lut, lwe, level_count, fft, stack);
+    }
+
+    /// Blind rotation using only the `level_count` most significant levels of the key, see
+    /// [`FourierGgswCiphertextView::truncate_levels`].
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn blind_rotate_with_level_count_assign<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertextMutView<'_, Scalar>,
         lwe: &[Scalar],
+        level_count: DecompositionLevelCount,
         fft: FftView<'_>,
         mut stack: PodStack<'_>,
     ) {
         let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
 
-        let lut_poly_size = lut.polynomial_size();
-        let ciphertext_modulus = lut.ciphertext_modulus();
-        let monomial_degree = pbs_modulus_switch(
-            *lwe_body,
-            lut_poly_size,
-            ModulusSwitchOffset(0),
-            LutCountLog(0),
-        );
-
-        lut.as_mut_polynomial_list()
-            .iter_mut()
-            .for_each(|mut poly| {
-                polynomial_wrapping_monic_monomial_div_assign(
-                    &mut poly,
-                    MonomialDegree(monomial_degree),
-                )
-            });
+        rotate_lut_by_lwe_body(&mut lut, *lwe_body);
 
         // We initialize the ct_0 used for the successive cmuxes
         let mut ct0 = lut;
@@ -248,55 +349,42 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
         for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
         {
             if *lwe_mask_element != Scalar::ZERO {
-                let stack = stack.rb_mut();
-                // We copy ct_0 to ct_1
-                let (mut ct1, stack) =
-                    stack.collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
-                let mut ct1 = GlweCiphertextMutView::from_container(
-                    &mut *ct1,
-                    lut_poly_size,
-                    ciphertext_modulus,
+                rotated_cmux(
+                    ct0.as_mut_view(),
+                    *lwe_mask_element,
+                    bootstrap_key_ggsw.truncate_levels(level_count),
+                    fft,
+                    stack.rb_mut(),
                 );
-
-                // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
-                for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
-                    polynomial_wrapping_monic_monomial_mul_assign(
-                        &mut poly,
-                        MonomialDegree(pbs_modulus_switch(
-                            *lwe_mask_element,
-                            lut_poly_size,
-                            ModulusSwitchOffset(0),
-                            LutCountLog(0),
-                        )),
-                    );
-                }
-
-                // ct1 is re-created each loop it can be moved, ct0 is already a view, but
-                // as_mut_view is required to keep borrow rules consistent
-                cmux(ct0.as_mut_view(), ct1, bootstrap_key_ggsw, fft, stack);
             }
         }
 
-        if !ciphertext_modulus.is_native_modulus() {
-            // When we convert back from the fourier domain, integer values will contain up to 53
-            // MSBs with information. In our representation of power of 2 moduli < native modulus we
-            // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
-            // round while keeping the data in the MSBs
-            let signed_decomposer = SignedDecomposer::new(
-                DecompositionBaseLog(ciphertext_modulus.get().ilog2() as usize),
-                DecompositionLevelCount(1),
-            );
-            ct0.as_mut()
-                .iter_mut()
-                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
-        }
+        round_to_ciphertext_modulus(ct0);
     }
 
     pub fn bootstrap<Scalar>(
+        self,
+        lwe_out: LweCiphertextMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        let level_count = self.decomposition_level_count();
+        self.bootstrap_with_level_count(lwe_out, lwe_in, accumulator, level_count, fft, stack);
+    }
+
+    /// Bootstrap using only the `level_count` most significant levels of the key, see
+    /// [`FourierGgswCiphertextView::truncate_levels`].
+    pub fn bootstrap_with_level_count<Scalar>(
         self,
         mut lwe_out: LweCiphertextMutView<'_, Scalar>,
         lwe_in: LweCiphertextView<'_, Scalar>,
         accumulator: GlweCiphertextView<'_, Scalar>,
+        level_count: DecompositionLevelCount,
         fft: FftView<'_>,
         stack: PodStack<'_>,
     ) where
@@ -316,7 +404,13 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             accumulator.polynomial_size(),
             accumulator.ciphertext_modulus(),
         );
-        self.blind_rotate_assign(local_accumulator.as_mut_view(), lwe_in.as_ref(), fft, stack);
+        self.blind_rotate_with_level_count_assign(
+            local_accumulator.as_mut_view(),
+            lwe_in.as_ref(),
+            level_count,
+            fft,
+            stack,
+        );
 
         extract_lwe_sample_from_glwe_ciphertext(
             &local_accumulator,
@@ -324,6 +418,183 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Keyswitch `lwe_in` with `lwe_keyswitch_key` and bootstrap the result, without writing the
+    /// keyswitched ciphertext anywhere.
+    ///
+    /// The mask of `lwe_in` is decomposed once, then the body of the keyswitched ciphertext is
+    /// computed to rotate the accumulator, and the coefficients of its mask are computed by chunks
+    /// of [`KEYSWITCH_CHUNK_SIZE`] right before the CMUXes consuming them.
+    pub fn keyswitch_bootstrap<Scalar>(
+        self,
+        mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        lwe_keyswitch_key: LweKeyswitchKeyView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.keyswitch_blind_rotate_assign(
+            local_accumulator.as_mut_view(),
+            lwe_in.as_ref(),
+            lwe_keyswitch_key,
+            fft,
+            stack,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &local_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+    }
+
+    /// Same as [`FourierLweBootstrapKeyView::keyswitch_bootstrap`] but the result is written
+    /// back in `ct`.
+    pub fn keyswitch_bootstrap_assign<Scalar>(
+        self,
+        mut ct: LweCiphertextMutView<'_, Scalar>,
+        lwe_keyswitch_key: LweKeyswitchKeyView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(ct.ciphertext_modulus(), accumulator.ciphertext_modulus());
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.keyswitch_blind_rotate_assign(
+            local_accumulator.as_mut_view(),
+            ct.as_ref(),
+            lwe_keyswitch_key,
+            fft,
+            stack,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, &mut ct, MonomialDegree(0));
+    }
+
+    fn keyswitch_blind_rotate_assign<Scalar>(
+        self,
+        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lwe_in: &[Scalar],
+        lwe_keyswitch_key: LweKeyswitchKeyView<'_, Scalar>,
+        fft: FftView<'_>,
+        mut stack: PodStack<'_>,
+    ) where
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        assert_eq!(
+            lwe_keyswitch_key.input_key_lwe_dimension().to_lwe_size().0,
+            lwe_in.len(),
+            "Mismatched input LweDimension. \
+            LweKeyswitchKey input LweDimension: {:?}, input LweCiphertext LweDimension {:?}.",
+            lwe_keyswitch_key.input_key_lwe_dimension(),
+            LweSize(lwe_in.len()).to_lwe_dimension(),
+        );
+        assert_eq!(
+            lwe_keyswitch_key.output_key_lwe_dimension(),
+            self.input_lwe_dimension(),
+            "Mismatched LweDimension. \
+            LweKeyswitchKey output LweDimension: {:?}, bootstrap key input LweDimension {:?}.",
+            lwe_keyswitch_key.output_key_lwe_dimension(),
+            self.input_lwe_dimension(),
+        );
+
+        let level_count = lwe_keyswitch_key.decomposition_level_count();
+        let output_lwe_size = lwe_keyswitch_key.output_lwe_size().0;
+        let decomposer =
+            SignedDecomposer::new(lwe_keyswitch_key.decomposition_base_log(), level_count);
+
+        let (input_body, input_mask) = lwe_in.split_last().unwrap();
+
+        // The levels of the key are stored in the order of the decomposition, so that the rows of
+        // the key and the decomposed terms can simply be zipped
+        let (mut decomposed, mut stack) = stack.rb_mut().make_aligned_with(
+            input_mask.len() * level_count.0,
+            CACHELINE_ALIGN,
+            |_| Scalar::ZERO,
+        );
+        for (terms, &input_mask_element) in
+            izip!(decomposed.chunks_exact_mut(level_count.0), input_mask)
+        {
+            for (term, decomposed_term) in terms
+                .iter_mut()
+                .zip(decomposer.decompose(input_mask_element))
+            {
+                *term = decomposed_term.value();
+            }
+        }
+        let key_rows = || lwe_keyswitch_key.as_ref().chunks_exact(output_lwe_size);
+
+        let keyswitched_body = izip!(key_rows(), decomposed.iter()).fold(
+            *input_body,
+            |body, (key_row, &decomposed_term)| {
+                body.wrapping_sub(key_row[output_lwe_size - 1].wrapping_mul(decomposed_term))
+            },
+        );
+
+        rotate_lut_by_lwe_body(&mut lut, keyswitched_body);
+
+        let mut ct0 = lut;
+        let mut keyswitched_chunk = [Scalar::ZERO; KEYSWITCH_CHUNK_SIZE];
+        let mut ggsw_iter = self.into_ggsw_iter();
+
+        for chunk_start in (0..output_lwe_size - 1).step_by(KEYSWITCH_CHUNK_SIZE) {
+            let chunk_end = (chunk_start + KEYSWITCH_CHUNK_SIZE).min(output_lwe_size - 1);
+            let keyswitched_chunk = &mut keyswitched_chunk[..chunk_end - chunk_start];
+
+            keyswitched_chunk.fill(Scalar::ZERO);
+            for (key_row, &decomposed_term) in izip!(key_rows(), decomposed.iter()) {
+                slice_wrapping_sub_scalar_mul_assign(
+                    keyswitched_chunk,
+                    &key_row[chunk_start..chunk_end],
+                    decomposed_term,
+                );
+            }
+
+            // The GGSW iterator is shared between the chunks
+            for (&lwe_mask_element, bootstrap_key_ggsw) in
+                keyswitched_chunk.iter().zip(ggsw_iter.by_ref())
+            {
+                if lwe_mask_element != Scalar::ZERO {
+                    rotated_cmux(
+                        ct0.as_mut_view(),
+                        lwe_mask_element,
+                        bootstrap_key_ggsw,
+                        fft,
+                        stack.rb_mut(),
+                    );
+                }
+            }
+        }
+
+        round_to_ciphertext_modulus(ct0);
+    }
 }
 
 impl<Scalar> FourierBootstrapKey<Scalar> for FourierLweBootstrapKeyOwned
@@ -397,3 +668,185 @@ where
         )
     }
 }
+
+/// Return the required memory for [`blind_rotate_assign_with_seeded_bsk`].
+pub fn blind_rotate_with_seeded_bsk_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let standard_ggsw = StackReq::try_new_aligned::<Scalar>(
+        ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    )?;
+    let fourier_ggsw = StackReq::try_new_aligned::<c64>(
+        polynomial_size.to_fourier_polynomial_size().0
+            * glwe_size.0
+            * glwe_size.0
+            * decomposition_level_count.0,
+        CACHELINE_ALIGN,
+    )?;
+    StackReq::try_all_of([
+        standard_ggsw,
+        fourier_ggsw,
+        StackReq::try_any_of([
+            fill_with_forward_fourier_scratch(fft)?,
+            blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        ])?,
+    ])
+}
+
+/// Return the required memory for [`bootstrap_with_seeded_bsk`].
+pub fn bootstrap_with_seeded_bsk_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_with_seeded_bsk_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        decomposition_level_count,
+        fft,
+    )?
+    .try_and(StackReq::try_new_aligned::<Scalar>(
+        glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+    )?)
+}
+
+/// Blind rotation using a [`SeededLweBootstrapKey`] directly.
+///
+/// Each GGSW of the key is decompressed and converted to the Fourier domain right before being
+/// used in the cmux, so that only one GGSW is expanded in memory at any time. This trades the
+/// memory of a full [`FourierLweBootstrapKey`] for a forward FFT of each GGSW on every blind
+/// rotation.
+// CastInto required for PBS modulus switch which returns a usize
+pub fn blind_rotate_assign_with_seeded_bsk<Scalar: UnsignedTorus + CastInto<usize>>(
+    seeded_bsk: SeededLweBootstrapKey<&[Scalar]>,
+    mut lut: GlweCiphertextMutView<'_, Scalar>,
+    lwe: &[Scalar],
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) {
+    let glwe_size = seeded_bsk.glwe_size();
+    let polynomial_size = seeded_bsk.polynomial_size();
+    let decomposition_base_log = seeded_bsk.decomposition_base_log();
+    let decomposition_level_count = seeded_bsk.decomposition_level_count();
+    let ciphertext_modulus = seeded_bsk.ciphertext_modulus();
+
+    let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+    debug_assert_eq!(lwe_mask.len(), seeded_bsk.input_lwe_dimension().0);
+
+    rotate_lut_by_lwe_body(&mut lut, *lwe_body);
+
+    // Each GGSW is decompressed from its own fork of the mask generator, the forks cover the same
+    // bytes as the ones used at key generation, this allows to skip the GGSWs for which the mask
+    // element is 0 without generating their masks.
+    let mut generator =
+        RandomGenerator::<ActivatedRandomGenerator>::new(seeded_bsk.compression_seed().seed);
+    let ggsw_generators = generator
+        .try_fork(
+            seeded_bsk.input_lwe_dimension().0,
+            mask_bytes_per_ggsw::<Scalar>(decomposition_level_count, glwe_size, polynomial_size),
+        )
+        .unwrap();
+
+    let (mut standard_ggsw_data, stack) = stack.make_aligned_raw::<Scalar>(
+        ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    );
+    let (mut fourier_ggsw_data, mut stack) = stack.make_aligned_raw::<c64>(
+        polynomial_size.to_fourier_polynomial_size().0
+            * glwe_size.0
+            * glwe_size.0
+            * decomposition_level_count.0,
+        CACHELINE_ALIGN,
+    );
+
+    // We initialize the ct_0 used for the successive cmuxes
+    let mut ct0 = lut;
+
+    for ((lwe_mask_element, seeded_ggsw), mut ggsw_generator) in
+        izip!(lwe_mask.iter(), seeded_bsk.iter()).zip(ggsw_generators)
+    {
+        if *lwe_mask_element == Scalar::ZERO {
+            continue;
+        }
+
+        let mut standard_ggsw = GgswCiphertext::from_container(
+            &mut *standard_ggsw_data,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            ciphertext_modulus,
+        );
+        decompress_seeded_ggsw_ciphertext_with_existing_generator::<
+            _,
+            _,
+            _,
+            ActivatedRandomGenerator,
+        >(&mut standard_ggsw, &seeded_ggsw, &mut ggsw_generator);
+
+        let mut fourier_ggsw = FourierGgswCiphertext::from_container(
+            &mut *fourier_ggsw_data,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        );
+        fourier_ggsw.as_mut_view().fill_with_forward_fourier(
+            standard_ggsw.as_view(),
+            fft,
+            stack.rb_mut(),
+        );
+
+        rotated_cmux(
+            ct0.as_mut_view(),
+            *lwe_mask_element,
+            fourier_ggsw.as_view(),
+            fft,
+            stack.rb_mut(),
+        );
+    }
+
+    round_to_ciphertext_modulus(ct0);
+}
+
+/// Programmable bootstrap using a [`SeededLweBootstrapKey`] directly, see
+/// [`blind_rotate_assign_with_seeded_bsk`].
+pub fn bootstrap_with_seeded_bsk<Scalar>(
+    seeded_bsk: SeededLweBootstrapKey<&[Scalar]>,
+    mut lwe_out: LweCiphertextMutView<'_, Scalar>,
+    lwe_in: LweCiphertextView<'_, Scalar>,
+    accumulator: GlweCiphertextView<'_, Scalar>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+{
+    debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+    debug_assert_eq!(
+        lwe_in.ciphertext_modulus(),
+        accumulator.ciphertext_modulus()
+    );
+
+    let (mut local_accumulator_data, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+    let mut local_accumulator = GlweCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        accumulator.polynomial_size(),
+        accumulator.ciphertext_modulus(),
+    );
+    blind_rotate_assign_with_seeded_bsk(
+        seeded_bsk,
+        local_accumulator.as_mut_view(),
+        lwe_in.as_ref(),
+        fft,
+        stack,
+    );
+
+    extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, &mut lwe_out, MonomialDegree(0));
+}
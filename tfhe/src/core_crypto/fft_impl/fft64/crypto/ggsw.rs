@@ -228,6 +228,33 @@ impl<C: Container<Element = c64>> FourierGgswLevelRow<C> {
 }
 
 impl<'a> FourierGgswCiphertextView<'a> {
+    /// Return a view of the ciphertext restricted to its `level_count` most significant levels.
+    ///
+    /// The result is a GGSW ciphertext with a coarser gadget decomposition: external products
+    /// with it are cheaper, at the cost of a larger rounding error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level_count` is zero or larger than the level count of the ciphertext.
+    pub fn truncate_levels(self, level_count: DecompositionLevelCount) -> Self {
+        assert!(
+            level_count.0 > 0 && level_count.0 <= self.decomposition_level_count.0,
+            "Cannot truncate a GGSW ciphertext with {:?} to {:?}",
+            self.decomposition_level_count,
+            level_count,
+        );
+
+        // The levels are stored from the most significant one
+        let level_len = self.fourier.data.len() / self.decomposition_level_count.0;
+        Self::from_container(
+            &self.fourier.data[..level_len * level_count.0],
+            self.glwe_size,
+            self.fourier.polynomial_size,
+            self.decomposition_base_log,
+            level_count,
+        )
+    }
+
     /// Return an iterator over the level matrices.
     pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierGgswLevelMatrixView<'a>> {
         self.fourier
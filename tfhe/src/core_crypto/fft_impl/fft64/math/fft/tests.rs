@@ -165,6 +165,18 @@ fn test_roundtrip_u64() {
     test_roundtrip::<u64>();
 }
 
+#[test]
+fn test_plan_cache_shared() {
+    let size = PolynomialSize(1 << 13);
+    Fft::warm_up_plan_cache([size]);
+    assert!(Fft::cached_plan_sizes().contains(&size));
+
+    // The plan is shared between the Fft objects, from any thread
+    let fft = Fft::new(size);
+    let other_fft = std::thread::spawn(move || Fft::new(size)).join().unwrap();
+    assert!(Arc::ptr_eq(&fft.plan, &other_fft.plan));
+}
+
 #[test]
 fn f64_to_i64_bit_twiddles() {
     for x in [
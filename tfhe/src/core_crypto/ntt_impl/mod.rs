@@ -0,0 +1,8 @@
+//! Implementations of the `core_crypto` algorithms computing the polynomial products with a
+//! number theoretic transform.
+//!
+//! Unlike the Fourier transform of [`fft_impl`](crate::core_crypto::fft_impl), which computes
+//! with floating point numbers, these products are exact. A bootstrapping key cannot be converted
+//! back from the Fourier domain, the algorithms of this module therefore work from keys in the
+//! standard domain.
+pub mod ntt64;
@@ -0,0 +1,212 @@
+use super::super::math::NttView;
+use super::ggsw::{
+    assert_exact_external_product, cmux, cmux_scratch, ntt_ggsw_ciphertext_size, NttGgswCiphertext,
+};
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::{
+    decompress_seeded_ggsw_ciphertext_with_existing_generator,
+    extract_lwe_sample_from_glwe_ciphertext,
+};
+use crate::core_crypto::commons::generators::mask_bytes_per_ggsw;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
+use crate::core_crypto::commons::parameters::{
+    DecompositionLevelCount, GlweSize, LutCountLog, ModulusSwitchOffset, MonomialDegree,
+    PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    ContiguousEntityContainer, ContiguousEntityContainerMut,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::pbs_modulus_switch;
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// Return the required memory for [`blind_rotate_assign_with_seeded_bsk`].
+pub fn blind_rotate_with_seeded_bsk_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    let standard_ggsw = StackReq::try_new_aligned::<u64>(
+        ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    )?;
+    let ntt_ggsw = StackReq::try_new_aligned::<u64>(
+        ntt_ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    )?;
+    let rotated_ct =
+        StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?;
+
+    StackReq::try_all_of([
+        standard_ggsw,
+        ntt_ggsw,
+        rotated_ct,
+        cmux_scratch(glwe_size, polynomial_size)?,
+    ])
+}
+
+/// Return the required memory for [`bootstrap_with_seeded_bsk`].
+pub fn bootstrap_with_seeded_bsk_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_with_seeded_bsk_scratch(glwe_size, polynomial_size, decomposition_level_count)?
+        .try_and(StackReq::try_new_aligned::<u64>(
+            glwe_size.0 * polynomial_size.0,
+            CACHELINE_ALIGN,
+        )?)
+}
+
+/// Blind rotation using a [`SeededLweBootstrapKey`] and the NTT.
+///
+/// Each GGSW of the key is decompressed and transformed right before being used in the cmux, so
+/// that only one GGSW is expanded in memory at any time. The polynomial products being exact, so
+/// is the result: it only differs from the one of an ideal blind rotation by the noise of the
+/// key.
+pub fn blind_rotate_assign_with_seeded_bsk(
+    seeded_bsk: SeededLweBootstrapKey<&[u64]>,
+    mut lut: GlweCiphertextMutView<'_, u64>,
+    lwe: &[u64],
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) {
+    let glwe_size = seeded_bsk.glwe_size();
+    let polynomial_size = seeded_bsk.polynomial_size();
+    let decomposition_base_log = seeded_bsk.decomposition_base_log();
+    let decomposition_level_count = seeded_bsk.decomposition_level_count();
+    let ciphertext_modulus = seeded_bsk.ciphertext_modulus();
+
+    assert_exact_external_product(
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+    );
+
+    let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+    debug_assert_eq!(lwe_mask.len(), seeded_bsk.input_lwe_dimension().0);
+
+    let monomial_degree = |lwe_element: u64| {
+        MonomialDegree(pbs_modulus_switch(
+            lwe_element,
+            polynomial_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        ))
+    };
+
+    lut.as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign(&mut poly, monomial_degree(*lwe_body))
+        });
+
+    // Each GGSW is decompressed from its own fork of the mask generator, the forks cover the same
+    // bytes as the ones used at key generation, this allows to skip the GGSWs for which the mask
+    // element is 0 without generating their masks.
+    let mut generator =
+        RandomGenerator::<ActivatedRandomGenerator>::new(seeded_bsk.compression_seed().seed);
+    let ggsw_generators = generator
+        .try_fork(
+            seeded_bsk.input_lwe_dimension().0,
+            mask_bytes_per_ggsw::<u64>(decomposition_level_count, glwe_size, polynomial_size),
+        )
+        .unwrap();
+
+    let (mut standard_ggsw_data, stack) = stack.make_aligned_raw::<u64>(
+        ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    );
+    let (mut ntt_ggsw_data, mut stack) = stack.make_aligned_raw::<u64>(
+        ntt_ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+    );
+
+    // We initialize the ct_0 used for the successive cmuxes
+    let mut ct0 = lut;
+
+    for ((lwe_mask_element, seeded_ggsw), mut ggsw_generator) in
+        izip!(lwe_mask.iter(), seeded_bsk.iter()).zip(ggsw_generators)
+    {
+        if *lwe_mask_element == 0 {
+            continue;
+        }
+
+        let mut standard_ggsw = GgswCiphertext::from_container(
+            &mut *standard_ggsw_data,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            ciphertext_modulus,
+        );
+        decompress_seeded_ggsw_ciphertext_with_existing_generator::<
+            _,
+            _,
+            _,
+            ActivatedRandomGenerator,
+        >(&mut standard_ggsw, &seeded_ggsw, &mut ggsw_generator);
+
+        let mut ntt_ggsw = NttGgswCiphertext::from_container(
+            &mut *ntt_ggsw_data,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        );
+        ntt_ggsw
+            .as_mut_view()
+            .fill_with_forward_ntt(standard_ggsw.as_view(), ntt);
+
+        // We copy ct_0 to ct_1 and rotate it by performing ct_1 <- ct_1 * X^{a_hat}
+        let (mut ct1, stack) = stack
+            .rb_mut()
+            .collect_aligned(CACHELINE_ALIGN, ct0.as_ref().iter().copied());
+        let mut ct1 =
+            GlweCiphertextMutView::from_container(&mut *ct1, polynomial_size, ciphertext_modulus);
+        for mut poly in ct1.as_mut_polynomial_list().iter_mut() {
+            polynomial_wrapping_monic_monomial_mul_assign(
+                &mut poly,
+                monomial_degree(*lwe_mask_element),
+            );
+        }
+
+        cmux(ct0.as_mut_view(), ct1, ntt_ggsw.as_view(), ntt, stack);
+    }
+}
+
+/// Programmable bootstrap using a [`SeededLweBootstrapKey`] and the NTT, see
+/// [`blind_rotate_assign_with_seeded_bsk`].
+pub fn bootstrap_with_seeded_bsk(
+    seeded_bsk: SeededLweBootstrapKey<&[u64]>,
+    mut lwe_out: LweCiphertextMutView<'_, u64>,
+    lwe_in: LweCiphertextView<'_, u64>,
+    accumulator: GlweCiphertextView<'_, u64>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) {
+    debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+    debug_assert_eq!(
+        lwe_in.ciphertext_modulus(),
+        accumulator.ciphertext_modulus()
+    );
+
+    let (mut local_accumulator_data, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+    let mut local_accumulator = GlweCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        accumulator.polynomial_size(),
+        accumulator.ciphertext_modulus(),
+    );
+    blind_rotate_assign_with_seeded_bsk(
+        seeded_bsk,
+        local_accumulator.as_mut_view(),
+        lwe_in.as_ref(),
+        ntt,
+        stack,
+    );
+
+    extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, &mut lwe_out, MonomialDegree(0));
+}
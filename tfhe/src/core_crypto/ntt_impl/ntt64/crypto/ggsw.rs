@@ -0,0 +1,269 @@
+use super::super::math::{NttView, MAX_EXACT_RESULT_BITS, PRIME_COUNT};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, ContiguousEntityContainerMut,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::decomposition::TensorSignedDecompositionLendingIter;
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GGSW ciphertext in the NTT domain.
+///
+/// The polynomials are stored in the order of the standard domain GGSW, each as the
+/// [`PRIME_COUNT`] times `N` residues of its transform, see
+/// [`Ntt`](super::super::math::Ntt).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswCiphertext<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type NttGgswCiphertextView<'a> = NttGgswCiphertext<&'a [u64]>;
+pub type NttGgswCiphertextMutView<'a> = NttGgswCiphertext<&'a mut [u64]>;
+
+/// Return the number of elements of an [`NttGgswCiphertext`] with the given parameters.
+pub fn ntt_ggsw_ciphertext_size(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> usize {
+    PRIME_COUNT * polynomial_size.0 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
+}
+
+impl<C: Container<Element = u64>> NttGgswCiphertext<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            ntt_ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count)
+        );
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttGgswCiphertextView<'_> {
+        NttGgswCiphertext {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttGgswCiphertextMutView<'_>
+    where
+        C: ContainerMut,
+    {
+        NttGgswCiphertext {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> NttGgswCiphertextMutView<'a> {
+    /// Fill a GGSW ciphertext with the transform of a GGSW ciphertext in the standard domain.
+    pub fn fill_with_forward_ntt(self, coef_ggsw: GgswCiphertextView<'_, u64>, ntt: NttView<'_>) {
+        debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size);
+        debug_assert_eq!(ntt.polynomial_size(), self.polynomial_size);
+        let ntt_poly_size = PRIME_COUNT * self.polynomial_size.0;
+
+        for (ntt_poly, coef_poly) in izip!(
+            self.data.chunks_exact_mut(ntt_poly_size),
+            coef_ggsw.as_polynomial_list().iter()
+        ) {
+            ntt.forward_as_integer(ntt_poly, coef_poly);
+        }
+    }
+}
+
+/// Check that the external products with a GGSW ciphertext with the given parameters are exact.
+///
+/// The integer coefficients of an external product are sums of `glwe_size * level_count * N`
+/// products of a decomposition term, at most `2^(base_log - 1)` in magnitude, and of a GGSW
+/// coefficient, at most `2^63` in magnitude.
+///
+/// # Panics
+///
+/// Panics if these sums can exceed the range in which the transform is exact.
+pub fn assert_exact_external_product(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+) {
+    let term_count = glwe_size.0 * decomposition_level_count.0 * polynomial_size.0;
+    let term_count_bits = usize::BITS - (term_count - 1).leading_zeros();
+    let bound_bits = term_count_bits + decomposition_base_log.0 as u32 - 1 + 63;
+    assert!(
+        bound_bits <= MAX_EXACT_RESULT_BITS,
+        "The external products of GGSW ciphertexts with {glwe_size:?}, {polynomial_size:?}, \
+        {decomposition_base_log:?} and {decomposition_level_count:?} are not exact with the NTT"
+    );
+}
+
+/// Return the required memory for [`add_external_product_assign`].
+pub fn add_external_product_assign_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch =
+        StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, align)?;
+    let ntt_scratch =
+        StackReq::try_new_aligned::<u64>(glwe_size.0 * PRIME_COUNT * polynomial_size.0, align)?;
+    let ntt_scratch_single =
+        StackReq::try_new_aligned::<u64>(PRIME_COUNT * polynomial_size.0, align)?;
+
+    // output buffer, decomposition states, decomposition term and transform of one of its
+    // polynomials
+    StackReq::try_all_of([
+        ntt_scratch,
+        standard_scratch,
+        standard_scratch,
+        ntt_scratch_single,
+    ])
+}
+
+/// Perform the external product of `ggsw` and `glwe`, and add the result to `out`.
+///
+/// The caller is responsible for checking that the product is exact with
+/// [`assert_exact_external_product`].
+pub fn add_external_product_assign(
+    mut out: GlweCiphertextMutView<'_, u64>,
+    ggsw: NttGgswCiphertextView<'_>,
+    glwe: GlweCiphertextView<'_, u64>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+    debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+    debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+
+    let align = CACHELINE_ALIGN;
+    let polynomial_size = ggsw.polynomial_size().0;
+    let glwe_size = ggsw.glwe_size().0;
+    let ntt_poly_size = PRIME_COUNT * polynomial_size;
+
+    // we round the input mask and body
+    let decomposer = SignedDecomposer::<u64>::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+    );
+
+    let (mut output_ntt_buffer, mut substack0) =
+        stack.make_aligned_with::<u64, _>(ntt_poly_size * glwe_size, align, |_| 0);
+
+    let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+        glwe.as_ref()
+            .iter()
+            .map(|s| decomposer.closest_representable(*s)),
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+        substack0.rb_mut(),
+    );
+
+    // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+    for ggsw_level_matrix in ggsw
+        .data()
+        .chunks_exact(glwe_size * glwe_size * ntt_poly_size)
+        .rev()
+    {
+        let (_, _, glwe_decomp_term) = decomposition.next_term().unwrap();
+        let (glwe_decomp_term, mut substack2) =
+            substack1.rb_mut().collect_aligned(align, glwe_decomp_term);
+
+        // Each row of the level matrix is multiplied by the corresponding polynomial of the
+        // decomposition and accumulated in the output
+        for (ggsw_row, glwe_poly) in izip!(
+            ggsw_level_matrix.chunks_exact(glwe_size * ntt_poly_size),
+            glwe_decomp_term.chunks_exact(polynomial_size)
+        ) {
+            let (mut glwe_poly_ntt, _) = substack2
+                .rb_mut()
+                .make_aligned_raw::<u64>(ntt_poly_size, align);
+            ntt.forward_as_integer(&mut glwe_poly_ntt, Polynomial::from_container(glwe_poly));
+
+            for (output_poly_ntt, ggsw_poly) in izip!(
+                output_ntt_buffer.chunks_exact_mut(ntt_poly_size),
+                ggsw_row.chunks_exact(ntt_poly_size)
+            ) {
+                ntt.mul_add_assign(output_poly_ntt, ggsw_poly, &glwe_poly_ntt);
+            }
+        }
+    }
+
+    for (out_poly, output_poly_ntt) in izip!(
+        out.as_mut_polynomial_list().iter_mut(),
+        output_ntt_buffer.chunks_exact_mut(ntt_poly_size)
+    ) {
+        ntt.add_backward_as_torus(out_poly, output_poly_ntt);
+    }
+}
+
+/// Return the required memory for [`cmux`].
+pub fn cmux_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    add_external_product_assign_scratch(glwe_size, polynomial_size)
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux(
+    ct0: GlweCiphertextMutView<'_, u64>,
+    mut ct1: GlweCiphertextMutView<'_, u64>,
+    ggsw: NttGgswCiphertextView<'_>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) {
+    izip!(ct1.as_mut(), ct0.as_ref()).for_each(|(c1, c0)| {
+        *c1 = c1.wrapping_sub(*c0);
+    });
+    add_external_product_assign(ct0, ggsw, ct1.as_view(), ntt, stack);
+}
@@ -0,0 +1,130 @@
+use super::bootstrap::{bootstrap_with_seeded_bsk, bootstrap_with_seeded_bsk_scratch};
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
+    bootstrap_with_seeded_bsk as fft_bootstrap_with_seeded_bsk,
+    bootstrap_with_seeded_bsk_scratch as fft_bootstrap_with_seeded_bsk_scratch,
+};
+use crate::core_crypto::prelude::*;
+use dyn_stack::{GlobalPodBuffer, PodStack};
+
+#[test]
+fn test_bootstrap_with_seeded_bsk_decrypts_as_fft() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let small_lwe_dimension = LweDimension(742);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(2048);
+    let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let pbs_base_log = DecompositionBaseLog(23);
+    let pbs_level = DecompositionLevelCount(1);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let glwe_size = glwe_dimension.to_glwe_size();
+
+    let mut boxed_seeder = new_seeder();
+    let seeder = boxed_seeder.as_mut();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let small_lwe_sk =
+        LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+    let glwe_sk =
+        GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+    let seeded_bsk = allocate_and_generate_new_seeded_lwe_bootstrap_key(
+        &small_lwe_sk,
+        &glwe_sk,
+        pbs_base_log,
+        pbs_level,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        seeder,
+    );
+
+    let message_modulus = 1u64 << 4;
+    let delta = (1_u64 << 63) / message_modulus;
+
+    // Accumulator computing x -> 2 * x + 1
+    let box_size = polynomial_size.0 / message_modulus as usize;
+    let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+        .map(|i| ((2 * (i / box_size) as u64 + 1) % message_modulus) * delta)
+        .collect();
+    let half_box_size = box_size / 2;
+    for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+    accumulator_u64.rotate_left(half_box_size);
+    let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_size,
+        &PlaintextList::from_container(accumulator_u64),
+        ciphertext_modulus,
+    );
+
+    let ntt = Ntt::new(polynomial_size);
+    let ntt = ntt.as_view();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+    let mut buffer = GlobalPodBuffer::new(
+        bootstrap_with_seeded_bsk_scratch(glwe_size, polynomial_size, pbs_level)
+            .unwrap()
+            .or(fft_bootstrap_with_seeded_bsk_scratch::<u64>(
+                glwe_size,
+                polynomial_size,
+                pbs_level,
+                fft,
+            )
+            .unwrap()),
+    );
+
+    let signed_decomposer =
+        SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+
+    for input_message in 0..message_modulus / 2 {
+        let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+            &small_lwe_sk,
+            Plaintext(input_message * delta),
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut encryption_generator,
+        );
+
+        let mut ntt_ct = LweCiphertext::new(
+            0u64,
+            big_lwe_sk.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        bootstrap_with_seeded_bsk(
+            seeded_bsk.as_view(),
+            ntt_ct.as_mut_view(),
+            lwe_ciphertext_in.as_view(),
+            accumulator.as_view(),
+            ntt,
+            PodStack::new(&mut buffer),
+        );
+
+        let mut fft_ct = LweCiphertext::new(
+            0u64,
+            big_lwe_sk.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        fft_bootstrap_with_seeded_bsk(
+            seeded_bsk.as_view(),
+            fft_ct.as_mut_view(),
+            lwe_ciphertext_in.as_view(),
+            accumulator.as_view(),
+            fft,
+            PodStack::new(&mut buffer),
+        );
+
+        let decode = |ct: &LweCiphertextOwned<u64>| {
+            let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, ct);
+            (signed_decomposer.closest_representable(decrypted.0) / delta) % message_modulus
+        };
+
+        let expected = (2 * input_message + 1) % message_modulus;
+        assert_eq!(decode(&ntt_ct), expected);
+        assert_eq!(decode(&fft_ct), expected);
+    }
+}
@@ -0,0 +1,500 @@
+//! Negacyclic number theoretic transform computing exact products of polynomials modulo `2^64`.
+//!
+//! The native `2^64` modulus has no root of unity, the polynomials are therefore transformed
+//! modulo two primes congruent to 1 modulo `2N`, and the results are recombined with the CRT. As
+//! long as the integer result of a product fits in the range of the product of the primes, the
+//! recombined result is the exact one, which is then reduced modulo `2^64`.
+
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use once_cell::sync::OnceCell;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Primes of the residue number system. Both are smaller than `2^62` and congruent to 1 modulo
+/// `2^17`, so that they have the `2N`-th roots of unity required by a negacyclic transform of
+/// size up to [`MAX_POLYNOMIAL_SIZE`].
+pub const PRIMES: [u64; 2] = [0x3fff_ffff_ffe8_0001, 0x3fff_ffff_ffbe_0001];
+
+/// Number of primes of the residue number system, a transformed polynomial is made of that many
+/// times `N` residues.
+pub const PRIME_COUNT: usize = PRIMES.len();
+
+/// Largest polynomial size supported by [`Ntt`].
+pub const MAX_POLYNOMIAL_SIZE: usize = 1 << 16;
+
+/// Base 2 logarithm of the largest magnitude an integer result can have for its reduction modulo
+/// `2^64` to be exact, i.e. the largest `x` such that `2^x` is smaller than half the product of
+/// the [`PRIMES`].
+pub const MAX_EXACT_RESULT_BITS: u32 = 122;
+
+#[inline]
+fn add_mod(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    let sum = lhs + rhs;
+    if sum >= prime {
+        sum - prime
+    } else {
+        sum
+    }
+}
+
+#[inline]
+fn sub_mod(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs + prime - rhs
+    }
+}
+
+#[inline]
+fn mul_mod(lhs: u64, rhs: u64, prime: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % prime as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, prime: u64) -> u64 {
+    let mut result = 1;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, prime);
+        }
+        base = mul_mod(base, base, prime);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Return `floor(value * 2^64 / prime)`, used to multiply by `value` with
+/// [`mul_mod_shoup`].
+#[inline]
+fn shoup(value: u64, prime: u64) -> u64 {
+    (((value as u128) << 64) / prime as u128) as u64
+}
+
+/// Compute `lhs * rhs mod prime` with `rhs_shoup = shoup(rhs, prime)`, for `rhs < prime`.
+#[inline]
+fn mul_mod_shoup(lhs: u64, rhs: u64, rhs_shoup: u64, prime: u64) -> u64 {
+    let quotient = ((lhs as u128 * rhs_shoup as u128) >> 64) as u64;
+    let result = lhs
+        .wrapping_mul(rhs)
+        .wrapping_sub(quotient.wrapping_mul(prime));
+    if result >= prime {
+        result - prime
+    } else {
+        result
+    }
+}
+
+/// Return `floor(2^124 / prime)`, used to reduce products with [`mul_mod_barrett`].
+fn barrett(prime: u64) -> u64 {
+    ((1u128 << 124) / prime as u128) as u64
+}
+
+/// Compute `lhs * rhs mod prime` for `lhs, rhs < prime`, with `prime` between `2^61` and `2^62`
+/// and `prime_barrett = barrett(prime)`.
+#[inline]
+fn mul_mod_barrett(lhs: u64, rhs: u64, prime: u64, prime_barrett: u64) -> u64 {
+    let product = lhs as u128 * rhs as u128;
+    // The estimated quotient is at most 2 below the actual one, so that the remainder fits in a
+    // u64 and is smaller than 3 * prime
+    let quotient = (((product >> 61) as u64 as u128 * prime_barrett as u128) >> 63) as u64;
+    let mut result = (product as u64).wrapping_sub(quotient.wrapping_mul(prime));
+    if result >= prime {
+        result -= prime;
+    }
+    if result >= prime {
+        result -= prime;
+    }
+    result
+}
+
+/// Reduce a value smaller than `2^64` modulo a prime larger than `2^61`.
+#[inline]
+fn reduce(value: u64, prime: u64) -> u64 {
+    // value >> 62 is at most floor(value / prime), the remainder is then smaller than 2 * prime
+    let result = value - (value >> 62) * prime;
+    if result >= prime {
+        result - prime
+    } else {
+        result
+    }
+}
+
+/// Reduce a value interpreted as a signed integer in two's complement modulo `prime`.
+#[inline]
+fn reduce_signed(value: u64, prime: u64) -> u64 {
+    if (value as i64) < 0 {
+        let magnitude = reduce(value.wrapping_neg(), prime);
+        if magnitude == 0 {
+            0
+        } else {
+            prime - magnitude
+        }
+    } else {
+        reduce(value, prime)
+    }
+}
+
+fn bit_reverse(value: usize, log_size: u32) -> usize {
+    if log_size == 0 {
+        0
+    } else {
+        value.reverse_bits() >> (usize::BITS - log_size)
+    }
+}
+
+/// Transform of size `N` modulo a single prime.
+#[derive(Clone, Debug)]
+struct PrimePlan {
+    prime: u64,
+    prime_barrett: u64,
+    // Powers of a primitive 2N-th root of unity in bit reversed order, and their Shoup
+    // representation
+    twiddles: Vec<u64>,
+    twiddles_shoup: Vec<u64>,
+    // Same for the inverse of the root of unity
+    inv_twiddles: Vec<u64>,
+    inv_twiddles_shoup: Vec<u64>,
+    size_inv: u64,
+    size_inv_shoup: u64,
+}
+
+impl PrimePlan {
+    fn new(prime: u64, size: usize) -> Self {
+        let log_size = size.trailing_zeros();
+        let order = 2 * size as u64;
+        assert_eq!((prime - 1) % order, 0);
+
+        // A 2N-th root of unity is primitive iff its N-th power is -1, as 2N is a power of 2
+        let root = (2..)
+            .map(|generator| pow_mod(generator, (prime - 1) / order, prime))
+            .find(|&root| pow_mod(root, size as u64, prime) == prime - 1)
+            .unwrap();
+        let inv_root = pow_mod(root, prime - 2, prime);
+
+        let powers = |root: u64| -> Vec<u64> {
+            (0..size)
+                .map(|i| pow_mod(root, bit_reverse(i, log_size) as u64, prime))
+                .collect()
+        };
+        let twiddles = powers(root);
+        let inv_twiddles = powers(inv_root);
+        let twiddles_shoup = twiddles.iter().map(|&w| shoup(w, prime)).collect();
+        let inv_twiddles_shoup = inv_twiddles.iter().map(|&w| shoup(w, prime)).collect();
+        let size_inv = pow_mod(size as u64, prime - 2, prime);
+
+        Self {
+            prime,
+            prime_barrett: barrett(prime),
+            twiddles,
+            twiddles_shoup,
+            inv_twiddles,
+            inv_twiddles_shoup,
+            size_inv,
+            size_inv_shoup: shoup(size_inv, prime),
+        }
+    }
+
+    /// Negacyclic forward transform, from the natural order to the bit reversed order.
+    fn forward(&self, data: &mut [u64]) {
+        let prime = self.prime;
+        let size = data.len();
+
+        let mut half_block = size;
+        let mut block_count = 1;
+        while block_count < size {
+            half_block /= 2;
+            for (i, block) in data.chunks_exact_mut(2 * half_block).enumerate() {
+                let w = self.twiddles[block_count + i];
+                let w_shoup = self.twiddles_shoup[block_count + i];
+                let (lo, hi) = block.split_at_mut(half_block);
+                for (u, v) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let x = *u;
+                    let y = mul_mod_shoup(*v, w, w_shoup, prime);
+                    *u = add_mod(x, y, prime);
+                    *v = sub_mod(x, y, prime);
+                }
+            }
+            block_count *= 2;
+        }
+    }
+
+    /// Negacyclic backward transform, from the bit reversed order to the natural order, including
+    /// the normalization by `1/N`.
+    fn backward(&self, data: &mut [u64]) {
+        let prime = self.prime;
+        let size = data.len();
+
+        let mut half_block = 1;
+        let mut block_count = size;
+        while block_count > 1 {
+            block_count /= 2;
+            for (i, block) in data.chunks_exact_mut(2 * half_block).enumerate() {
+                let w = self.inv_twiddles[block_count + i];
+                let w_shoup = self.inv_twiddles_shoup[block_count + i];
+                let (lo, hi) = block.split_at_mut(half_block);
+                for (u, v) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let x = *u;
+                    let y = *v;
+                    *u = add_mod(x, y, prime);
+                    *v = mul_mod_shoup(sub_mod(x, y, prime), w, w_shoup, prime);
+                }
+            }
+            half_block *= 2;
+        }
+
+        for value in data.iter_mut() {
+            *value = mul_mod_shoup(*value, self.size_inv, self.size_inv_shoup, prime);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct NttPlan {
+    polynomial_size: PolynomialSize,
+    primes: [PrimePlan; PRIME_COUNT],
+    // Inverse of the first prime modulo the second one, used for the CRT
+    first_prime_inv: u64,
+    first_prime_inv_shoup: u64,
+}
+
+impl NttPlan {
+    fn new(polynomial_size: PolynomialSize) -> Self {
+        let [first_prime, second_prime] = PRIMES;
+        let first_prime_inv = pow_mod(first_prime % second_prime, second_prime - 2, second_prime);
+        Self {
+            polynomial_size,
+            primes: PRIMES.map(|prime| PrimePlan::new(prime, polynomial_size.0)),
+            first_prime_inv,
+            first_prime_inv_shoup: shoup(first_prime_inv, second_prime),
+        }
+    }
+}
+
+/// Negacyclic Number Theoretic Transform. See [`NttView`] for transform functions.
+///
+/// Transformed polynomials are slices of [`PRIME_COUNT`] times `N` residues: the residues modulo
+/// the first prime in bit reversed order, followed by the ones modulo the second prime.
+#[derive(Clone, Debug)]
+pub struct Ntt {
+    plan: Arc<NttPlan>,
+}
+
+/// View type for [`Ntt`].
+#[derive(Clone, Copy, Debug)]
+pub struct NttView<'a> {
+    plan: &'a NttPlan,
+}
+
+type PlanMap = RwLock<HashMap<usize, Arc<OnceCell<Arc<NttPlan>>>>>;
+static PLANS: OnceCell<PlanMap> = OnceCell::new();
+fn plans() -> &'static PlanMap {
+    PLANS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+impl Ntt {
+    /// Transform of polynomials of size `size`, plans are cached and shared by all the threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two or is larger than [`MAX_POLYNOMIAL_SIZE`].
+    pub fn new(size: PolynomialSize) -> Self {
+        assert!(
+            size.0.is_power_of_two() && size.0 <= MAX_POLYNOMIAL_SIZE,
+            "Unsupported polynomial size for the NTT: {size:?}"
+        );
+
+        let global_plans = plans();
+
+        let n = size.0;
+        let get_plan = || {
+            let plans = global_plans.read().unwrap();
+            let plan = plans.get(&n).cloned();
+            drop(plans);
+
+            plan.map(|p| p.get_or_init(|| Arc::new(NttPlan::new(size))).clone())
+        };
+
+        // we optimistically hope that the plan has already been created, only taking the read lock
+        if let Some(plan) = get_plan() {
+            return Self { plan };
+        }
+
+        // could not find a plan of the given size, we lock the map again and try to insert it
+        let mut plans = global_plans.write().unwrap();
+        if let Entry::Vacant(v) = plans.entry(n) {
+            v.insert(Arc::new(OnceCell::new()));
+        }
+
+        drop(plans);
+
+        Self {
+            plan: get_plan().unwrap(),
+        }
+    }
+
+    #[inline]
+    pub fn as_view(&self) -> NttView<'_> {
+        NttView { plan: &self.plan }
+    }
+}
+
+impl<'a> NttView<'a> {
+    pub fn polynomial_size(self) -> PolynomialSize {
+        self.plan.polynomial_size
+    }
+
+    /// Transform `standard`, its coefficients being interpreted as signed integers in two's
+    /// complement, and write the result in `ntt`.
+    pub fn forward_as_integer(self, ntt: &mut [u64], standard: PolynomialView<'_, u64>) {
+        let polynomial_size = self.polynomial_size().0;
+        debug_assert_eq!(standard.polynomial_size().0, polynomial_size);
+        debug_assert_eq!(ntt.len(), PRIME_COUNT * polynomial_size);
+
+        for (residues, plan) in izip!(ntt.chunks_exact_mut(polynomial_size), &self.plan.primes) {
+            for (residue, &coefficient) in izip!(residues.iter_mut(), standard.as_ref()) {
+                *residue = reduce_signed(coefficient, plan.prime);
+            }
+            plan.forward(residues);
+        }
+    }
+
+    /// Add the pointwise product of the transformed polynomials `lhs` and `rhs` to `acc`, i.e.
+    /// the transform of the negacyclic product of the polynomials.
+    pub fn mul_add_assign(self, acc: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let polynomial_size = self.polynomial_size().0;
+        debug_assert_eq!(acc.len(), PRIME_COUNT * polynomial_size);
+        debug_assert_eq!(lhs.len(), PRIME_COUNT * polynomial_size);
+        debug_assert_eq!(rhs.len(), PRIME_COUNT * polynomial_size);
+
+        for (acc, lhs, rhs, plan) in izip!(
+            acc.chunks_exact_mut(polynomial_size),
+            lhs.chunks_exact(polynomial_size),
+            rhs.chunks_exact(polynomial_size),
+            &self.plan.primes
+        ) {
+            let prime = plan.prime;
+            for (acc, &lhs, &rhs) in izip!(acc.iter_mut(), lhs, rhs) {
+                *acc = add_mod(
+                    *acc,
+                    mul_mod_barrett(lhs, rhs, prime, plan.prime_barrett),
+                    prime,
+                );
+            }
+        }
+    }
+
+    /// Compute the backward transform of `ntt` and add it to `standard` modulo `2^64`, `ntt` is
+    /// used as scratch memory.
+    ///
+    /// The result is exact if the integer polynomial whose transform is `ntt` has coefficients
+    /// smaller than `2^MAX_EXACT_RESULT_BITS` in magnitude.
+    pub fn add_backward_as_torus(self, mut standard: PolynomialMutView<'_, u64>, ntt: &mut [u64]) {
+        let polynomial_size = self.polynomial_size().0;
+        debug_assert_eq!(standard.polynomial_size().0, polynomial_size);
+        debug_assert_eq!(ntt.len(), PRIME_COUNT * polynomial_size);
+
+        for (residues, plan) in izip!(ntt.chunks_exact_mut(polynomial_size), &self.plan.primes) {
+            plan.backward(residues);
+        }
+
+        let [first_prime, second_prime] = PRIMES;
+        let product = first_prime as u128 * second_prime as u128;
+        let (first_residues, second_residues) = ntt.split_at(polynomial_size);
+
+        for (coefficient, &first_residue, &second_residue) in izip!(
+            standard.as_mut().iter_mut(),
+            first_residues,
+            second_residues
+        ) {
+            // Garner's recombination: the result is first_residue + first_prime * t, with t the
+            // residue modulo the second prime of (second_residue - first_residue) / first_prime
+            let t = mul_mod_shoup(
+                sub_mod(
+                    second_residue,
+                    reduce(first_residue, second_prime),
+                    second_prime,
+                ),
+                self.plan.first_prime_inv,
+                self.plan.first_prime_inv_shoup,
+                second_prime,
+            );
+            let mut value = first_residue as u128 + first_prime as u128 * t as u128;
+            // Values above half the product are the representatives of negative results
+            if value > product / 2 {
+                value = value.wrapping_sub(product);
+            }
+            *coefficient = coefficient.wrapping_add(value as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn ntt_barrett_reduction() {
+        let mut rng = thread_rng();
+
+        for prime in PRIMES {
+            let prime_barrett = barrett(prime);
+            for (lhs, rhs) in [(0, 0), (prime - 1, prime - 1), (1, prime - 1)]
+                .into_iter()
+                .chain((0..1000).map(|_| (rng.gen_range(0..prime), rng.gen_range(0..prime))))
+            {
+                assert_eq!(
+                    mul_mod_barrett(lhs, rhs, prime, prime_barrett),
+                    mul_mod(lhs, rhs, prime)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ntt_negacyclic_product_is_exact() {
+        let mut rng = thread_rng();
+
+        for log_size in [0, 1, 4, 10, 11] {
+            let polynomial_size = PolynomialSize(1 << log_size);
+            let ntt = Ntt::new(polynomial_size);
+            let ntt = ntt.as_view();
+
+            // Small signed operand, like a decomposed GLWE, and arbitrary 64 bits operand, like a
+            // GGSW
+            let lhs = Polynomial::from_container(
+                (0..polynomial_size.0)
+                    .map(|_| rng.gen_range(-(1i64 << 22)..(1i64 << 22)) as u64)
+                    .collect::<Vec<_>>(),
+            );
+            let rhs = Polynomial::from_container(
+                (0..polynomial_size.0)
+                    .map(|_| rng.gen::<u64>())
+                    .collect::<Vec<_>>(),
+            );
+            let init = (0..polynomial_size.0)
+                .map(|_| rng.gen::<u64>())
+                .collect::<Vec<_>>();
+
+            let mut expected = Polynomial::from_container(init.clone());
+            polynomial_wrapping_add_mul_assign(&mut expected, &lhs, &rhs);
+
+            let mut lhs_ntt = vec![0u64; PRIME_COUNT * polynomial_size.0];
+            let mut rhs_ntt = vec![0u64; PRIME_COUNT * polynomial_size.0];
+            ntt.forward_as_integer(&mut lhs_ntt, lhs.as_view());
+            ntt.forward_as_integer(&mut rhs_ntt, rhs.as_view());
+
+            let mut product_ntt = vec![0u64; PRIME_COUNT * polynomial_size.0];
+            ntt.mul_add_assign(&mut product_ntt, &lhs_ntt, &rhs_ntt);
+
+            let mut result = Polynomial::from_container(init);
+            ntt.add_backward_as_torus(result.as_mut_view(), &mut product_ntt);
+
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#![doc(hidden)]
+
+pub mod crypto;
+pub mod math;
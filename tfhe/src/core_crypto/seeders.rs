@@ -4,13 +4,14 @@
 //! obtain a seed. A random seed is useful to have compressed keys and is used as a prerequisite
 //! for cryptographically secure pseudo random number generators.
 
-pub use crate::core_crypto::commons::math::random::Seeder;
+pub use crate::core_crypto::commons::math::random::{Seed, Seeder};
 #[cfg(all(target_os = "macos", not(feature = "__wasm_api")))]
 pub use concrete_csprng::seeders::AppleSecureEnclaveSeeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use concrete_csprng::seeders::RdseedSeeder;
 #[cfg(feature = "seeder_unix")]
 pub use concrete_csprng::seeders::UnixSeeder;
+use std::sync::{Arc, Mutex};
 
 #[cfg(feature = "__wasm_api")]
 mod wasm_seeder {
@@ -37,10 +38,105 @@ mod wasm_seeder {
     }
 }
 
+type SharedSeeder = Arc<Mutex<Box<dyn Seeder + Send>>>;
+
+static REGISTERED_SEEDER: Mutex<Option<SharedSeeder>> = Mutex::new(None);
+
+/// Register a [`Seeder`] to be returned by [`new_seeder`] in place of the built-in ones.
+///
+/// This is meant for platforms without an entropy source known to this crate (e.g. enclaves or
+/// bare-metal targets). The seeder is shared by all the seeders returned by [`new_seeder`] from
+/// then on, the previously registered seeder is returned.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let mut counter = 0u128;
+/// // Do not use this in production, the callback must return seeds from a secure entropy source
+/// register_seeder(Box::new(CallbackSeeder::new(move || {
+///     counter += 1;
+///     Seed(counter)
+/// })));
+///
+/// let mut seeder = new_seeder();
+/// assert_eq!(seeder.seed(), Seed(1));
+/// assert_eq!(seeder.seed(), Seed(2));
+///
+/// unregister_seeder();
+/// ```
+pub fn register_seeder(seeder: Box<dyn Seeder + Send>) -> Option<Box<dyn Seeder + Send>> {
+    let previous = REGISTERED_SEEDER
+        .lock()
+        .unwrap()
+        .replace(Arc::new(Mutex::new(seeder)));
+    previous.and_then(into_inner_seeder)
+}
+
+/// Remove the [`Seeder`] registered with [`register_seeder`] and return it.
+///
+/// The seeders previously returned by [`new_seeder`] keep using it until they are dropped, in which
+/// case `None` is returned.
+pub fn unregister_seeder() -> Option<Box<dyn Seeder + Send>> {
+    let previous = REGISTERED_SEEDER.lock().unwrap().take();
+    previous.and_then(into_inner_seeder)
+}
+
+fn into_inner_seeder(seeder: SharedSeeder) -> Option<Box<dyn Seeder + Send>> {
+    Arc::try_unwrap(seeder)
+        .ok()
+        .map(|seeder| seeder.into_inner().unwrap())
+}
+
+struct RegisteredSeeder(SharedSeeder);
+
+impl Seeder for RegisteredSeeder {
+    fn seed(&mut self) -> Seed {
+        self.0.lock().unwrap().seed()
+    }
+
+    fn is_available() -> bool
+    where
+        Self: Sized,
+    {
+        REGISTERED_SEEDER.lock().unwrap().is_some()
+    }
+}
+
+/// A [`Seeder`] calling a user provided function, to plug an entropy source of the platform.
+///
+/// The function is responsible for the quality of the seeds, see [`register_seeder`] for an
+/// example.
+pub struct CallbackSeeder<F> {
+    callback: F,
+}
+
+impl<F: FnMut() -> Seed> CallbackSeeder<F> {
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
+}
+
+impl<F: FnMut() -> Seed> Seeder for CallbackSeeder<F> {
+    fn seed(&mut self) -> Seed {
+        (self.callback)()
+    }
+
+    fn is_available() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+}
+
 /// Return an available boxed [`Seeder`] prioritizing hardware entropy sources.
 ///
 /// # Note
 ///
+/// A seeder registered with [`register_seeder`] takes precedence over all the others.
+///
 /// With the `seeder_x86_64_rdseed` feature enabled on `x86_64` CPUs the rdseed seeder is
 /// prioritized.
 ///
@@ -69,6 +165,10 @@ mod wasm_seeder {
 /// assert_ne!(first_seed, second_seed);
 /// ```
 pub fn new_seeder() -> Box<dyn Seeder> {
+    if let Some(registered) = REGISTERED_SEEDER.lock().unwrap().as_ref() {
+        return Box::new(RegisteredSeeder(Arc::clone(registered)));
+    }
+
     let mut seeder: Option<Box<dyn Seeder>> = None;
 
     let err_msg;
@@ -101,7 +201,7 @@ pub fn new_seeder() -> Box<dyn Seeder> {
         #[cfg(not(feature = "__c_api"))]
         {
             err_msg = "Unable to instantiate a seeder, make sure to enable a seeder feature \
-    like seeder_unix for example on unix platforms.";
+    like seeder_unix for example on unix platforms, or to register one with register_seeder.";
         }
 
         #[cfg(feature = "__c_api")]
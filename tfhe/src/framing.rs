@@ -0,0 +1,601 @@
+//! Canonical wire envelope for ciphertexts.
+//!
+//! Bare bincode blobs carry no information about what they contain, a truncated or corrupted blob
+//! is only detected (if ever) while deserializing it. The functions of this module wrap the
+//! bincode serialization of a ciphertext in a frame made of:
+//!
+//! | field             | size (bytes) | content                                               |
+//! |-------------------|--------------|-------------------------------------------------------|
+//! | magic             | 4            | `b"TFHE"`                                             |
+//! | version           | 2            | [`FRAME_VERSION`], little endian                      |
+//! | type tag          | 2            | [`Framable::TYPE_TAG`], little endian                 |
+//! | flags             | 1            | bit 0 set when a MAC is present                       |
+//! | parameter digest  | 32           | [`Framable::parameters_digest`]                       |
+//! | payload length    | 8            | length of the payload, little endian                  |
+//! | payload           | variable     | bincode serialization of the value                    |
+//! | MAC (optional)    | 32           | HMAC-SHA256 of all the previous bytes of the frame    |
+//!
+//! The MAC is computed with a [`MacKey`] shared by the two ends of the channel; it is not
+//! related to the FHE keys.
+//!
+//! The parameter digest written by the sender only proves that the header matches the payload.
+//! A reader which knows the parameters it works with sets them in its [`FramingConfig`], frames
+//! produced with other parameters are then rejected before their payload is read.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::framing::{read_framed, write_framed, FramingConfig, MacKey, ParametersDigest};
+//! use tfhe::shortint::ciphertext::KeyswitchBootstrap;
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::CiphertextBig;
+//!
+//! let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let ct = cks.encrypt(3);
+//!
+//! let mac_key = MacKey::new(b"a secret shared with the server".to_vec());
+//!
+//! let mut buffer = Vec::new();
+//! write_framed(&mut buffer, &ct, Some(&mac_key)).unwrap();
+//!
+//! let config = FramingConfig::default()
+//!     .with_mac_key(mac_key)
+//!     .with_expected_parameters(ParametersDigest::shortint::<KeyswitchBootstrap>(
+//!         &PARAM_MESSAGE_2_CARRY_2,
+//!     ));
+//! let ct: CiphertextBig = read_framed(buffer.as_slice(), &config).unwrap();
+//! assert_eq!(cks.decrypt(&ct), 3);
+//!
+//! // Truncated frames are rejected
+//! let truncated = &buffer[..buffer.len() - 1];
+//! assert!(read_framed::<CiphertextBig, _>(truncated, &config).is_err());
+//! ```
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const FRAME_MAGIC: [u8; 4] = *b"TFHE";
+pub const FRAME_VERSION: u16 = 1;
+
+const MAC_FLAG: u8 = 1;
+const DIGEST_SIZE: usize = 32;
+const MAC_SIZE: usize = 32;
+const HEADER_SIZE: usize = 4 + 2 + 2 + 1 + DIGEST_SIZE + 8;
+
+/// Digest of the cryptographic parameters a ciphertext was encrypted with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParametersDigest(pub [u8; DIGEST_SIZE]);
+
+impl ParametersDigest {
+    fn from_words(domain: &[u8], words: &[u128]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(domain);
+        for word in words {
+            hasher.update(word.to_le_bytes());
+        }
+        Self(hasher.finalize().into())
+    }
+
+    /// Digest of the boolean ciphertexts encrypted with `parameters`.
+    #[cfg(feature = "boolean")]
+    pub fn boolean(parameters: &crate::boolean::parameters::BooleanParameters) -> Self {
+        let ciphertext_modulus =
+            crate::core_crypto::commons::parameters::CiphertextModulus::<u32>::new_native();
+        Self::from_words(
+            b"boolean",
+            &[
+                parameters.lwe_dimension.to_lwe_size().0 as u128,
+                ciphertext_modulus.get(),
+            ],
+        )
+    }
+
+    /// Digest of the shortint ciphertexts encrypted with `parameters` and the `OpOrder` PBS order.
+    #[cfg(feature = "shortint")]
+    pub fn shortint<OpOrder: crate::shortint::ciphertext::PBSOrderMarker>(
+        parameters: &crate::shortint::Parameters,
+    ) -> Self {
+        Self::from_words(
+            b"shortint",
+            &shortint_parameters_words::<OpOrder>(parameters),
+        )
+    }
+
+    /// Digest of the radix ciphertexts whose blocks are encrypted with `parameters` and the
+    /// `OpOrder` PBS order.
+    #[cfg(feature = "integer")]
+    pub fn integer_radix<OpOrder: crate::shortint::ciphertext::PBSOrderMarker>(
+        parameters: &crate::shortint::Parameters,
+    ) -> Self {
+        integer_words_digest(
+            b"integer-radix",
+            shortint_parameters_words::<OpOrder>(parameters),
+        )
+    }
+
+    /// Digest of the CRT ciphertexts whose blocks are encrypted with `parameters`.
+    #[cfg(feature = "integer")]
+    pub fn integer_crt(parameters: &crate::shortint::Parameters) -> Self {
+        integer_words_digest(
+            b"integer-crt",
+            shortint_parameters_words::<crate::shortint::ciphertext::KeyswitchBootstrap>(
+                parameters,
+            ),
+        )
+    }
+}
+
+/// Key used to authenticate frames.
+#[derive(Clone)]
+pub struct MacKey(Vec<u8>);
+
+impl MacKey {
+    pub fn new(key: Vec<u8>) -> Self {
+        Self(key)
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        // HMAC accepts keys of any length
+        HmacSha256::new_from_slice(&self.0).unwrap()
+    }
+}
+
+impl std::fmt::Debug for MacKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MacKey(..)")
+    }
+}
+
+#[derive(Debug)]
+pub enum FramingError {
+    Io(std::io::Error),
+    /// The input ended before the end of the frame
+    Truncated,
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    UnexpectedType {
+        expected: u16,
+        got: u16,
+    },
+    PayloadTooLarge {
+        max: u64,
+        got: u64,
+    },
+    /// A MAC key was given but the frame is not authenticated
+    MissingMac,
+    InvalidMac,
+    /// The frame was produced with other parameters than the ones of the [`FramingConfig`]
+    ParametersMismatch {
+        expected: ParametersDigest,
+        got: ParametersDigest,
+    },
+    /// The parameters digest of the header does not match the payload
+    InconsistentParameters,
+    Serialization(bincode::Error),
+}
+
+impl std::fmt::Display for FramingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Truncated => write!(f, "The frame is truncated"),
+            Self::InvalidMagic => write!(f, "The input does not start with a frame"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported frame version {version}")
+            }
+            Self::UnexpectedType { expected, got } => {
+                write!(f, "Expected a frame of type {expected}, got {got}")
+            }
+            Self::PayloadTooLarge { max, got } => {
+                write!(f, "Payload of {got} bytes exceeds the limit of {max} bytes")
+            }
+            Self::MissingMac => write!(f, "The frame is not authenticated"),
+            Self::InvalidMac => write!(f, "The frame MAC is invalid"),
+            Self::ParametersMismatch { .. } => write!(
+                f,
+                "The frame was produced with other parameters than the expected ones"
+            ),
+            Self::InconsistentParameters => write!(
+                f,
+                "The parameters digest of the frame does not match its content"
+            ),
+            Self::Serialization(err) => write!(f, "Serialization error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FramingError {}
+
+impl From<std::io::Error> for FramingError {
+    fn from(err: std::io::Error) -> Self {
+        if err.kind() == std::io::ErrorKind::UnexpectedEof {
+            Self::Truncated
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+impl From<bincode::Error> for FramingError {
+    fn from(err: bincode::Error) -> Self {
+        Self::Serialization(err)
+    }
+}
+
+/// Options used when reading frames.
+#[derive(Clone, Debug)]
+pub struct FramingConfig {
+    /// When set, frames must carry a valid MAC computed with this key
+    pub mac_key: Option<MacKey>,
+    /// Frames announcing a larger payload are rejected before reading it
+    pub max_payload_size: u64,
+    /// When set, frames must have been produced with these parameters
+    pub expected_parameters: Option<ParametersDigest>,
+}
+
+impl Default for FramingConfig {
+    fn default() -> Self {
+        Self {
+            mac_key: None,
+            max_payload_size: 1 << 30,
+            expected_parameters: None,
+        }
+    }
+}
+
+impl FramingConfig {
+    pub fn with_mac_key(mut self, mac_key: MacKey) -> Self {
+        self.mac_key = Some(mac_key);
+        self
+    }
+
+    pub fn with_max_payload_size(mut self, max_payload_size: u64) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    pub fn with_expected_parameters(mut self, expected_parameters: ParametersDigest) -> Self {
+        self.expected_parameters = Some(expected_parameters);
+        self
+    }
+}
+
+/// Types that can be written in a frame.
+pub trait Framable: Serialize + DeserializeOwned {
+    /// Tag identifying the type of the payload
+    const TYPE_TAG: u16;
+
+    fn parameters_digest(&self) -> ParametersDigest;
+}
+
+/// Write `value` in a frame, authenticated with `mac_key` if one is given.
+pub fn write_framed<T, W>(
+    mut writer: W,
+    value: &T,
+    mac_key: Option<&MacKey>,
+) -> Result<(), FramingError>
+where
+    T: Framable,
+    W: Write,
+{
+    let payload = bincode::serialize(value)?;
+
+    let mut header = Vec::with_capacity(HEADER_SIZE);
+    header.extend_from_slice(&FRAME_MAGIC);
+    header.extend_from_slice(&FRAME_VERSION.to_le_bytes());
+    header.extend_from_slice(&T::TYPE_TAG.to_le_bytes());
+    header.push(if mac_key.is_some() { MAC_FLAG } else { 0 });
+    header.extend_from_slice(&value.parameters_digest().0);
+    header.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+
+    writer.write_all(&header)?;
+    writer.write_all(&payload)?;
+
+    if let Some(mac_key) = mac_key {
+        let mut mac = mac_key.mac();
+        mac.update(&header);
+        mac.update(&payload);
+        writer.write_all(&mac.finalize().into_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Read a value written with [`write_framed`].
+///
+/// The whole frame is checked (length, MAC, type and parameters digest) before the value is
+/// returned. When the `config` has no MAC key, the MAC of authenticated frames is skipped, and
+/// when it has no expected parameters, any parameters matching the payload are accepted.
+pub fn read_framed<T, R>(mut reader: R, config: &FramingConfig) -> Result<T, FramingError>
+where
+    T: Framable,
+    R: Read,
+{
+    let mut header = [0u8; HEADER_SIZE];
+    reader.read_exact(&mut header)?;
+
+    let (magic, rest) = header.split_at(4);
+    let (version, rest) = rest.split_at(2);
+    let (type_tag, rest) = rest.split_at(2);
+    let (flags, rest) = rest.split_at(1);
+    let (digest, payload_len) = rest.split_at(DIGEST_SIZE);
+
+    if magic != FRAME_MAGIC {
+        return Err(FramingError::InvalidMagic);
+    }
+    let version = u16::from_le_bytes(version.try_into().unwrap());
+    if version != FRAME_VERSION {
+        return Err(FramingError::UnsupportedVersion(version));
+    }
+    let type_tag = u16::from_le_bytes(type_tag.try_into().unwrap());
+    if type_tag != T::TYPE_TAG {
+        return Err(FramingError::UnexpectedType {
+            expected: T::TYPE_TAG,
+            got: type_tag,
+        });
+    }
+    let digest = ParametersDigest(digest.try_into().unwrap());
+    if let Some(expected) = config.expected_parameters {
+        if digest != expected {
+            return Err(FramingError::ParametersMismatch {
+                expected,
+                got: digest,
+            });
+        }
+    }
+    let has_mac = flags[0] & MAC_FLAG != 0;
+    if config.mac_key.is_some() && !has_mac {
+        return Err(FramingError::MissingMac);
+    }
+    let payload_len = u64::from_le_bytes(payload_len.try_into().unwrap());
+    if payload_len > config.max_payload_size {
+        return Err(FramingError::PayloadTooLarge {
+            max: config.max_payload_size,
+            got: payload_len,
+        });
+    }
+
+    let mut payload = Vec::new();
+    let read = reader
+        .by_ref()
+        .take(payload_len)
+        .read_to_end(&mut payload)?;
+    if read as u64 != payload_len {
+        return Err(FramingError::Truncated);
+    }
+
+    if has_mac {
+        let mut tag = [0u8; MAC_SIZE];
+        reader.read_exact(&mut tag)?;
+        if let Some(mac_key) = &config.mac_key {
+            let mut mac = mac_key.mac();
+            mac.update(&header);
+            mac.update(&payload);
+            mac.verify_slice(&tag)
+                .map_err(|_| FramingError::InvalidMac)?;
+        }
+    }
+
+    let value: T = bincode::deserialize(&payload)?;
+    if value.parameters_digest() != digest {
+        return Err(FramingError::InconsistentParameters);
+    }
+
+    Ok(value)
+}
+
+#[cfg(feature = "boolean")]
+impl Framable for crate::boolean::ciphertext::Ciphertext {
+    const TYPE_TAG: u16 = 1;
+
+    fn parameters_digest(&self) -> ParametersDigest {
+        let words = match self {
+            Self::Encrypted(ct) => [ct.lwe_size().0 as u128, ct.ciphertext_modulus().get()],
+            // Trivial ciphertexts can be used with any parameters
+            Self::Trivial(_) => [0, 0],
+        };
+        ParametersDigest::from_words(b"boolean", &words)
+    }
+}
+
+#[cfg(feature = "shortint")]
+fn shortint_parameters_words<OpOrder: crate::shortint::ciphertext::PBSOrderMarker>(
+    parameters: &crate::shortint::Parameters,
+) -> [u128; 5] {
+    use crate::core_crypto::commons::parameters::LweDimension;
+    use crate::shortint::PBSOrder;
+
+    let lwe_dimension = match OpOrder::pbs_order() {
+        PBSOrder::KeyswitchBootstrap => {
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0)
+        }
+        PBSOrder::BootstrapKeyswitch => parameters.lwe_dimension,
+    };
+    [
+        lwe_dimension.to_lwe_size().0 as u128,
+        parameters.ciphertext_modulus.get(),
+        parameters.message_modulus.0 as u128,
+        parameters.carry_modulus.0 as u128,
+        OpOrder::pbs_order() as u128,
+    ]
+}
+
+#[cfg(feature = "shortint")]
+fn shortint_words<OpOrder: crate::shortint::ciphertext::PBSOrderMarker>(
+    ct: &crate::shortint::CiphertextBase<OpOrder>,
+) -> [u128; 5] {
+    [
+        ct.ct.lwe_size().0 as u128,
+        ct.ct.ciphertext_modulus().get(),
+        ct.message_modulus.0 as u128,
+        ct.carry_modulus.0 as u128,
+        OpOrder::pbs_order() as u128,
+    ]
+}
+
+#[cfg(feature = "shortint")]
+impl<OpOrder: crate::shortint::ciphertext::PBSOrderMarker> Framable
+    for crate::shortint::CiphertextBase<OpOrder>
+{
+    const TYPE_TAG: u16 = 2;
+
+    fn parameters_digest(&self) -> ParametersDigest {
+        ParametersDigest::from_words(b"shortint", &shortint_words(self))
+    }
+}
+
+#[cfg(feature = "integer")]
+fn integer_blocks_digest<OpOrder: crate::shortint::ciphertext::PBSOrderMarker>(
+    domain: &[u8],
+    blocks: &[crate::shortint::CiphertextBase<OpOrder>],
+) -> ParametersDigest {
+    let words = blocks.first().map_or([0; 5], shortint_words);
+    integer_words_digest(domain, words)
+}
+
+#[cfg(feature = "integer")]
+fn integer_words_digest(domain: &[u8], mut words: [u128; 5]) -> ParametersDigest {
+    // All the blocks share the same parameters, except for the message modulus of CRT blocks
+    // which is covered by the moduli of the CRT ciphertext.
+    words[2] = 0;
+    ParametersDigest::from_words(domain, &words)
+}
+
+#[cfg(feature = "integer")]
+impl<OpOrder: crate::shortint::ciphertext::PBSOrderMarker> Framable
+    for crate::integer::ciphertext::RadixCiphertext<OpOrder>
+{
+    const TYPE_TAG: u16 = 3;
+
+    fn parameters_digest(&self) -> ParametersDigest {
+        integer_blocks_digest(b"integer-radix", &self.blocks)
+    }
+}
+
+#[cfg(feature = "integer")]
+impl Framable for crate::integer::CrtCiphertext {
+    const TYPE_TAG: u16 = 4;
+
+    fn parameters_digest(&self) -> ParametersDigest {
+        integer_blocks_digest(b"integer-crt", &self.blocks)
+    }
+}
+
+#[cfg(all(test, feature = "shortint"))]
+mod test {
+    use super::*;
+    use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    use crate::shortint::CiphertextBig;
+
+    #[test]
+    fn test_framed_round_trip() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let cks = keys.client_key();
+        let ct = cks.encrypt(2);
+
+        let mac_key = MacKey::new(b"key".to_vec());
+        let config = FramingConfig::default().with_mac_key(mac_key.clone());
+
+        let mut buffer = Vec::new();
+        write_framed(&mut buffer, &ct, Some(&mac_key)).unwrap();
+        let res: CiphertextBig = read_framed(buffer.as_slice(), &config).unwrap();
+        assert_eq!(cks.decrypt(&res), 2);
+
+        // The MAC is optional for the reader without a key
+        let res: CiphertextBig = read_framed(buffer.as_slice(), &FramingConfig::default()).unwrap();
+        assert_eq!(cks.decrypt(&res), 2);
+
+        let mut unauthenticated = Vec::new();
+        write_framed(&mut unauthenticated, &ct, None).unwrap();
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(unauthenticated.as_slice(), &config),
+            Err(FramingError::MissingMac)
+        ));
+    }
+
+    #[test]
+    fn test_framed_corruptions_are_detected() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let ct = keys.client_key().encrypt(1);
+
+        let mac_key = MacKey::new(b"key".to_vec());
+        let config = FramingConfig::default().with_mac_key(mac_key.clone());
+
+        let mut buffer = Vec::new();
+        write_framed(&mut buffer, &ct, Some(&mac_key)).unwrap();
+
+        for len in [0, HEADER_SIZE - 1, HEADER_SIZE + 1, buffer.len() - 1] {
+            assert!(matches!(
+                read_framed::<CiphertextBig, _>(&buffer[..len], &config),
+                Err(FramingError::Truncated)
+            ));
+        }
+
+        let mut corrupted = buffer.clone();
+        corrupted[HEADER_SIZE + 10] ^= 1;
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(corrupted.as_slice(), &config),
+            Err(FramingError::InvalidMac)
+        ));
+
+        let other_key = FramingConfig::default().with_mac_key(MacKey::new(b"other".to_vec()));
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(buffer.as_slice(), &other_key),
+            Err(FramingError::InvalidMac)
+        ));
+
+        let small_limit = config.clone().with_max_payload_size(8);
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(buffer.as_slice(), &small_limit),
+            Err(FramingError::PayloadTooLarge { .. })
+        ));
+
+        let mut bad_magic = buffer;
+        bad_magic[0] = b'X';
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(bad_magic.as_slice(), &config),
+            Err(FramingError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn test_framed_parameters_digest() {
+        let keys_1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+        let keys_2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+
+        let ct_1 = keys_1.client_key().encrypt(1);
+        let ct_2 = keys_2.client_key().encrypt(1);
+        let ct_3 = keys_2.client_key().encrypt(3);
+
+        assert_ne!(ct_1.parameters_digest(), ct_2.parameters_digest());
+        assert_eq!(ct_2.parameters_digest(), ct_3.parameters_digest());
+
+        let digest_2 = ParametersDigest::shortint::<KeyswitchBootstrap>(&PARAM_MESSAGE_2_CARRY_2);
+        assert_eq!(ct_2.parameters_digest(), digest_2);
+        assert_eq!(
+            keys_2.client_key().encrypt_small(1).parameters_digest(),
+            ParametersDigest::shortint::<BootstrapKeyswitch>(&PARAM_MESSAGE_2_CARRY_2)
+        );
+
+        let mut buffer = Vec::new();
+        write_framed(&mut buffer, &ct_1, None).unwrap();
+
+        let config = FramingConfig::default().with_expected_parameters(digest_2);
+        assert!(matches!(
+            read_framed::<CiphertextBig, _>(buffer.as_slice(), &config),
+            Err(FramingError::ParametersMismatch { .. })
+        ));
+
+        let digest_1 = ParametersDigest::shortint::<KeyswitchBootstrap>(&PARAM_MESSAGE_1_CARRY_1);
+        let config = FramingConfig::default().with_expected_parameters(digest_1);
+        let res: CiphertextBig = read_framed(buffer.as_slice(), &config).unwrap();
+        assert_eq!(keys_1.client_key().decrypt(&res), 1);
+    }
+}
@@ -1,7 +1,9 @@
 use super::client_key::GenericBoolClientKey;
 use super::parameters::BooleanParameterSet;
 use super::types::GenericBool;
-use crate::boolean::server_key::{BinaryBooleanGates, ServerKey};
+use crate::boolean::ciphertext::Ciphertext;
+use crate::boolean::server_key::{BinaryBooleanGates, BinaryBooleanGatesAssign, ServerKey};
+use rayon::prelude::*;
 
 #[cfg_attr(all(doc, not(doctest)), cfg(feature = "boolean"))]
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
@@ -51,6 +53,30 @@ where
         GenericBool::<P>::new(ciphertext, lhs.id)
     }
 
+    pub(in crate::high_level_api::booleans) fn and_assign(
+        &self,
+        lhs: &mut GenericBool<P>,
+        rhs: &GenericBool<P>,
+    ) {
+        self.key.and_assign(&mut lhs.ciphertext, &rhs.ciphertext);
+    }
+
+    pub(in crate::high_level_api::booleans) fn or_assign(
+        &self,
+        lhs: &mut GenericBool<P>,
+        rhs: &GenericBool<P>,
+    ) {
+        self.key.or_assign(&mut lhs.ciphertext, &rhs.ciphertext);
+    }
+
+    pub(in crate::high_level_api::booleans) fn xor_assign(
+        &self,
+        lhs: &mut GenericBool<P>,
+        rhs: &GenericBool<P>,
+    ) {
+        self.key.xor_assign(&mut lhs.ciphertext, &rhs.ciphertext);
+    }
+
     pub(in crate::high_level_api::booleans) fn xnor(
         &self,
         lhs: &GenericBool<P>,
@@ -60,6 +86,15 @@ where
         GenericBool::<P>::new(ciphertext, lhs.id)
     }
 
+    pub(in crate::high_level_api::booleans) fn nor(
+        &self,
+        lhs: &GenericBool<P>,
+        rhs: &GenericBool<P>,
+    ) -> GenericBool<P> {
+        let ciphertext = self.key.nor(&lhs.ciphertext, &rhs.ciphertext);
+        GenericBool::<P>::new(ciphertext, lhs.id)
+    }
+
     pub(in crate::high_level_api::booleans) fn nand(
         &self,
         lhs: &GenericBool<P>,
@@ -74,7 +109,6 @@ where
         GenericBool::<P>::new(ciphertext, lhs.id)
     }
 
-    #[allow(dead_code)]
     pub(in crate::high_level_api::booleans) fn mux(
         &self,
         condition: &GenericBool<P>,
@@ -88,4 +122,57 @@ where
         );
         GenericBool::<P>::new(ciphertext, condition.id)
     }
+
+    pub(in crate::high_level_api::booleans) fn all(
+        &self,
+        booleans: &[&GenericBool<P>],
+        id: P::Id,
+    ) -> GenericBool<P> {
+        let ciphertext = self.reduce_tree(booleans, true, |key, lhs, rhs| key.and(lhs, rhs));
+        GenericBool::<P>::new(ciphertext, id)
+    }
+
+    pub(in crate::high_level_api::booleans) fn any(
+        &self,
+        booleans: &[&GenericBool<P>],
+        id: P::Id,
+    ) -> GenericBool<P> {
+        let ciphertext = self.reduce_tree(booleans, false, |key, lhs, rhs| key.or(lhs, rhs));
+        GenericBool::<P>::new(ciphertext, id)
+    }
+
+    // Combines the booleans with a balanced tree of gates, the gates of a level of the tree being
+    // computed in parallel
+    fn reduce_tree(
+        &self,
+        booleans: &[&GenericBool<P>],
+        empty_value: bool,
+        gate: fn(&ServerKey, &Ciphertext, &Ciphertext) -> Ciphertext,
+    ) -> Ciphertext {
+        // Only the inner key is shared with the rayon workers, the closure below must not
+        // capture anything generic over `P`
+        let key = &self.key;
+
+        if booleans.is_empty() {
+            return key.trivial_encrypt(empty_value);
+        }
+
+        let mut level = booleans
+            .iter()
+            .map(|boolean| boolean.ciphertext.clone())
+            .collect::<Vec<_>>();
+
+        while level.len() > 1 {
+            level = level
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => gate(key, lhs, rhs),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        level.pop().unwrap()
+    }
 }
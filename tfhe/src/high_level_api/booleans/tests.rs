@@ -5,11 +5,11 @@
 #![allow(clippy::bool_assert_comparison)]
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use crate::high_level_api::prelude::*;
 use crate::high_level_api::{
     generate_keys, set_server_key, ClientKey, CompressedFheBool, ConfigBuilder, FheBool,
     FheBoolParameters,
 };
+use crate::prelude::*;
 
 fn setup_static_default() -> ClientKey {
     let config = ConfigBuilder::all_disabled().enable_default_bool().build();
@@ -41,6 +41,24 @@ fn test_xor_truth_table_static_default() {
     xor_truth_table(&ttrue, &ffalse, &keys);
 }
 
+#[test]
+fn test_assign_operations_static_default() {
+    let keys = setup_static_default();
+
+    let ttrue = FheBool::encrypt(true, &keys);
+    let ffalse = FheBool::encrypt(false, &keys);
+
+    let mut result = ttrue.clone();
+    result &= &ttrue;
+    assert_eq!(result.decrypt(&keys), true);
+    result ^= &ttrue;
+    assert_eq!(result.decrypt(&keys), false);
+    result |= ttrue;
+    assert_eq!(result.decrypt(&keys), true);
+    result &= ffalse;
+    assert_eq!(result.decrypt(&keys), false);
+}
+
 #[test]
 fn test_and_truth_table_static_default() {
     let keys = setup_static_default();
@@ -201,3 +219,59 @@ fn test_trivial_bool() {
     assert_eq!(a.decrypt(&keys), true);
     assert_eq!(b.decrypt(&keys), false);
 }
+
+#[test]
+fn test_all_any_static_default() {
+    let keys = setup_static_default();
+
+    let no_booleans: [FheBool; 0] = [];
+    assert_eq!(FheBool::all(&no_booleans).decrypt(&keys), true);
+    assert_eq!(FheBool::any(&no_booleans).decrypt(&keys), false);
+
+    // Odd lengths leave the last boolean of a level of the tree alone
+    let clears = [true, true, false, true, true, false, true];
+    for len in 1..=clears.len() {
+        let clears = &clears[..len];
+        let booleans = clears
+            .iter()
+            .map(|clear| FheBool::encrypt(*clear, &keys))
+            .collect::<Vec<_>>();
+
+        let all = FheBool::all(&booleans);
+        assert_eq!(all.decrypt(&keys), clears.iter().all(|clear| *clear));
+
+        let any = FheBool::any(booleans.iter().skip(2));
+        assert_eq!(
+            any.decrypt(&keys),
+            clears.iter().skip(2).any(|clear| *clear)
+        );
+    }
+}
+
+#[test]
+fn test_gates_static_default() {
+    let keys = setup_static_default();
+
+    let clears = [false, true];
+    let booleans = clears.map(|clear| FheBool::encrypt(clear, &keys));
+
+    for (a, clear_a) in booleans.iter().zip(clears) {
+        for (b, clear_b) in booleans.iter().zip(clears) {
+            assert_eq!(a.nand(b).decrypt(&keys), !(clear_a && clear_b));
+            assert_eq!(a.nor(b).decrypt(&keys), !(clear_a || clear_b));
+            assert_eq!(a.xnor(b).decrypt(&keys), clear_a == clear_b);
+
+            for (condition, clear_condition) in booleans.iter().zip(clears) {
+                let expected = if clear_condition { clear_a } else { clear_b };
+                assert_eq!(condition.mux(a, b).decrypt(&keys), expected);
+            }
+        }
+    }
+
+    let trivial = FheBool::encrypt_trivial(true);
+    assert_eq!(trivial.nor(&booleans[0]).decrypt(&keys), false);
+    assert_eq!(
+        booleans[1].mux(&booleans[0], &trivial).decrypt(&keys),
+        false
+    );
+}
@@ -1,5 +1,5 @@
 use std::borrow::Borrow;
-use std::ops::{BitAnd, BitOr, BitXor};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
 
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use serde::{Deserialize, Serialize};
@@ -54,6 +54,8 @@ where
     pub(in crate::high_level_api::booleans) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: BooleanParameterSet] for GenericBool<P> { ciphertext });
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompressedBool<P>
 where
@@ -63,6 +65,8 @@ where
     pub(in crate::high_level_api::booleans) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: BooleanParameterSet] for CompressedBool<P> { ciphertext });
+
 impl<P> GenericBool<P>
 where
     P: BooleanParameterSet,
@@ -81,6 +85,44 @@ where
         self.id.with_unwrapped_global(|key| key.nand(self, rhs))
     }
 
+    pub fn nor(&self, rhs: &Self) -> Self {
+        self.id.with_unwrapped_global(|key| key.nor(self, rhs))
+    }
+
+    pub fn xnor(&self, rhs: &Self) -> Self {
+        self.id.with_unwrapped_global(|key| key.xnor(self, rhs))
+    }
+
+    /// Returns `then_result` if `self` encrypts `true`, `else_result` otherwise.
+    ///
+    /// The selection is computed with a single multiplexer gate, the condition is not revealed.
+    ///
+    /// Only booleans can be selected: [FheBool](crate::FheBool) and the integer types are
+    /// encrypted under different keys, so an encrypted boolean cannot select between integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let condition = FheBool::encrypt(false, &client_key);
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let result = condition.mux(&a, &b);
+    /// assert_eq!(result.decrypt(&client_key), false);
+    /// ```
+    pub fn mux(&self, then_result: &Self, else_result: &Self) -> Self {
+        self.id
+            .with_unwrapped_global(|key| key.mux(self, then_result, else_result))
+    }
+
     pub fn neq(&self, other: &Self) -> Self {
         self.id.with_unwrapped_global(|key| {
             let eq = key.xnor(self, other);
@@ -89,6 +131,80 @@ where
     }
 }
 
+impl<P> GenericBool<P>
+where
+    P: BooleanParameterSet,
+    P::Id: WithGlobalKey<Key = GenericBoolServerKey<P>> + Default,
+{
+    /// Returns an encryption of `true` if all the booleans encrypt `true`.
+    ///
+    /// The booleans are combined with a tree of AND gates, each level of the tree being computed
+    /// in parallel. An empty iterator gives a trivial encryption of `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let conditions = [true, true, false, true].map(|b| FheBool::encrypt(b, &client_key));
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let all = FheBool::all(&conditions);
+    /// assert_eq!(all.decrypt(&client_key), false);
+    /// let all = FheBool::all(&conditions[..2]);
+    /// assert_eq!(all.decrypt(&client_key), true);
+    /// ```
+    pub fn all<I, B>(booleans: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let booleans = booleans.into_iter().collect::<Vec<_>>();
+        let booleans: Vec<&Self> = booleans.iter().map(|boolean| boolean.borrow()).collect();
+        let id = P::Id::default();
+        id.with_unwrapped_global(|key| key.all(&booleans, id))
+    }
+
+    /// Returns an encryption of `true` if any of the booleans encrypts `true`.
+    ///
+    /// The booleans are combined with a tree of OR gates, each level of the tree being computed
+    /// in parallel. An empty iterator gives a trivial encryption of `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let conditions = [false, false, true, false].map(|b| FheBool::encrypt(b, &client_key));
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let any = FheBool::any(&conditions);
+    /// assert_eq!(any.decrypt(&client_key), true);
+    /// let any = FheBool::any(&conditions[..2]);
+    /// assert_eq!(any.decrypt(&client_key), false);
+    /// ```
+    pub fn any<I, B>(booleans: I) -> Self
+    where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Self>,
+    {
+        let booleans = booleans.into_iter().collect::<Vec<_>>();
+        let booleans: Vec<&Self> = booleans.iter().map(|boolean| boolean.borrow()).collect();
+        let id = P::Id::default();
+        id.with_unwrapped_global(|key| key.any(&booleans, id))
+    }
+}
+
 impl<P, B> FheEq<B> for GenericBool<P>
 where
     B: Borrow<Self>,
@@ -254,7 +370,7 @@ where
 }
 
 macro_rules! fhe_bool_impl_operation(
-    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+    ($trait_name:ident($trait_method:ident) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
         impl<P, B> $trait_name<B> for GenericBool<P>
         where B: Borrow<GenericBool<P>>,
               P: BooleanParameterSet,
@@ -262,8 +378,10 @@ macro_rules! fhe_bool_impl_operation(
         {
             type Output = GenericBool<P>;
 
-            fn $trait_method(self, rhs: B) -> Self::Output {
-                <&Self as $trait_name<B>>::$trait_method(&self, rhs)
+            // The left hand side is owned, its ciphertext is reused for the result
+            fn $trait_method(mut self, rhs: B) -> Self::Output {
+                <Self as $assign_trait<B>>::$assign_method(&mut self, rhs);
+                self
             }
         }
 
@@ -283,9 +401,30 @@ macro_rules! fhe_bool_impl_operation(
     };
 );
 
-fhe_bool_impl_operation!(BitAnd(bitand) => and);
-fhe_bool_impl_operation!(BitOr(bitor) => or);
-fhe_bool_impl_operation!(BitXor(bitxor) => xor);
+macro_rules! fhe_bool_impl_operation_assign(
+    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+        impl<P, B> $trait_name<B> for GenericBool<P>
+        where B: Borrow<GenericBool<P>>,
+              P: BooleanParameterSet,
+              P::Id: WithGlobalKey<Key=GenericBoolServerKey<P>>,
+        {
+            fn $trait_method(&mut self, rhs: B) {
+                let id = self.id;
+                id.with_unwrapped_global(|key| {
+                  key.$key_method(self, rhs.borrow())
+                })
+            }
+        }
+    };
+);
+
+fhe_bool_impl_operation!(BitAnd(bitand) => and, BitAndAssign(bitand_assign));
+fhe_bool_impl_operation!(BitOr(bitor) => or, BitOrAssign(bitor_assign));
+fhe_bool_impl_operation!(BitXor(bitxor) => xor, BitXorAssign(bitxor_assign));
+
+fhe_bool_impl_operation_assign!(BitAndAssign(bitand_assign) => and_assign);
+fhe_bool_impl_operation_assign!(BitOrAssign(bitor_assign) => or_assign);
+fhe_bool_impl_operation_assign!(BitXorAssign(bitxor_assign) => xor_assign);
 
 impl<P> ::std::ops::Not for GenericBool<P>
 where
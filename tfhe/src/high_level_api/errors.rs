@@ -129,6 +129,16 @@ pub enum Error {
     UninitializedClientKey(Type),
     UninitializedPublicKey(Type),
     UninitializedServerKey(Type),
+    /// The blocks of a ciphertext were not encrypted with the expected parameters.
+    ///
+    /// The moduli are given as `(message_modulus, carry_modulus)`.
+    MismatchedBlockParameters {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// A custom operation registered in the shortint server key failed.
+    #[cfg(feature = "shortint")]
+    CustomOp(crate::shortint::server_key::custom_op::CustomOpError),
 }
 
 impl From<OutOfRangeError> for Error {
@@ -155,6 +165,13 @@ impl From<UninitializedServerKey> for Error {
     }
 }
 
+#[cfg(feature = "shortint")]
+impl From<crate::shortint::server_key::custom_op::CustomOpError> for Error {
+    fn from(value: crate::shortint::server_key::custom_op::CustomOpError) -> Self {
+        Self::CustomOp(value)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -170,6 +187,18 @@ impl Display for Error {
             Error::UninitializedServerKey(ty) => {
                 write!(f, "{}", UninitializedServerKey(*ty))
             }
+            Error::MismatchedBlockParameters { expected, found } => {
+                write!(
+                    f,
+                    "Expected blocks with message modulus {} and carry modulus {}, \
+                     found blocks with message modulus {} and carry modulus {}",
+                    expected.0, expected.1, found.0, found.1
+                )
+            }
+            #[cfg(feature = "shortint")]
+            Error::CustomOp(error) => {
+                write!(f, "{error}")
+            }
         }
     }
 }
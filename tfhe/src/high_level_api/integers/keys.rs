@@ -11,3 +11,50 @@ define_key_structs! {
         uint256: FheUint256,
     }
 }
+
+macro_rules! define_compact_key_struct {
+    (
+        $(
+            $name:ident: $base_ty_name:ident
+        ),*
+        $(,)?
+    ) => {
+        ::paste::paste! {
+            $(
+                use super::types::static_::[<$base_ty_name CompactPublicKey>];
+            )*
+
+            #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
+            pub(crate) struct IntegerCompactPublicKey {
+                $(
+                    pub(super) [<$name _key>]: Option<[<$base_ty_name CompactPublicKey>]>,
+                )*
+            }
+
+            impl IntegerCompactPublicKey {
+                pub(crate) fn new(client_key: &IntegerClientKey) -> Self {
+                    Self {
+                        $(
+                            [<$name _key>]: client_key
+                                .[<$name _key>]
+                                .as_ref()
+                                .map(<[<$base_ty_name CompactPublicKey>]>::new),
+                        )*
+                    }
+                }
+            }
+        }
+    }
+}
+
+define_compact_key_struct! {
+    uint8: FheUint8,
+    uint10: FheUint10,
+    uint12: FheUint12,
+    uint14: FheUint14,
+    uint16: FheUint16,
+    uint32: FheUint32,
+    uint64: FheUint64,
+    uint128: FheUint128,
+    uint256: FheUint256,
+}
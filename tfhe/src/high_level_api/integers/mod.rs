@@ -1,5 +1,6 @@
 pub(crate) use keys::{
-    IntegerClientKey, IntegerCompressedPublicKey, IntegerConfig, IntegerPublicKey, IntegerServerKey,
+    IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedPublicKey, IntegerConfig,
+    IntegerPublicKey, IntegerServerKey,
 };
 pub use parameters::{CrtParameters, RadixParameters};
 pub(in crate::high_level_api) use types::static_::{
@@ -8,10 +9,15 @@ pub(in crate::high_level_api) use types::static_::{
     FheUint8Parameters,
 };
 pub use types::{
-    CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
-    CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
-    CompressedFheUint8, FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256,
-    FheUint32, FheUint64, FheUint8, GenericInteger,
+    Checked, CompactFheUint10List, CompactFheUint128List, CompactFheUint12List,
+    CompactFheUint14List, CompactFheUint16List, CompactFheUint256List, CompactFheUint32List,
+    CompactFheUint64List, CompactFheUint8List, CompressedFheUint10, CompressedFheUint12,
+    CompressedFheUint128, CompressedFheUint14, CompressedFheUint16, CompressedFheUint256,
+    CompressedFheUint32, CompressedFheUint64, CompressedFheUint8, EncryptedVec, FheExpr, FheUint10,
+    FheUint10Typed, FheUint12, FheUint128, FheUint128Typed, FheUint12Typed, FheUint14,
+    FheUint14Typed, FheUint16, FheUint16Typed, FheUint256, FheUint256Typed, FheUint32,
+    FheUint32Typed, FheUint64, FheUint64Typed, FheUint8, FheUint8Typed, GenericInteger,
+    GenericTypedInteger, Saturating,
 };
 
 mod client_key;
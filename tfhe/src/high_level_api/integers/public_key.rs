@@ -185,3 +185,35 @@ pub(in crate::high_level_api::integers) mod compressed {
         }
     }
 }
+
+pub(in crate::high_level_api::integers) mod compact {
+    use serde::{Deserialize, Serialize};
+
+    use crate::high_level_api::integers::client_key::{GenericIntegerClientKey, RadixClientKey};
+    use crate::high_level_api::integers::parameters::IntegerParameter;
+
+    #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct GenericIntegerCompactPublicKey<P>
+    where
+        P: IntegerParameter,
+    {
+        pub(in crate::high_level_api::integers) inner: crate::integer::CompactPublicKey,
+        pub(in crate::high_level_api::integers) num_blocks: usize,
+        _marker: std::marker::PhantomData<P>,
+    }
+
+    impl<P> GenericIntegerCompactPublicKey<P>
+    where
+        P: IntegerParameter<InnerClientKey = RadixClientKey>,
+    {
+        pub fn new(client_key: &GenericIntegerClientKey<P>) -> Self {
+            let key = crate::integer::CompactPublicKey::new(client_key.inner.inner.as_ref());
+            Self {
+                inner: key,
+                num_blocks: client_key.inner.inner.num_blocks(),
+                _marker: Default::default(),
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use super::client_key::{GenericIntegerClientKey, RadixClientKey};
 use super::parameters::IntegerParameter;
 
 use crate::integer::wopbs::WopbsKey;
+use crate::size::FheSize;
 
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct GenericIntegerServerKey<P: IntegerParameter> {
@@ -261,6 +262,11 @@ pub(super) trait SmartNeg<Ciphertext> {
     fn smart_neg(&self, lhs: Ciphertext) -> Self::Output;
 }
 
+pub(super) trait SmartBitNot<Ciphertext> {
+    type Output;
+    fn smart_bitnot(&self, lhs: Ciphertext) -> Self::Output;
+}
+
 macro_rules! define_smart_server_key_op {
     ($op_name:ident) => {
         paste::paste! {
@@ -294,21 +300,75 @@ define_smart_server_key_op!(
     Add, Sub, Mul, BitAnd, BitOr, BitXor, Shl, Shr, Eq, Ge, Gt, Le, Lt, Max, Min
 );
 
+pub trait SmartSaturatingAdd<Lhs, Rhs> {
+    type Output;
+    fn smart_saturating_add(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SmartSaturatingSub<Lhs, Rhs> {
+    type Output;
+    fn smart_saturating_sub(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
+/// The output is the wrapped result and an integer encrypting 1 if the operation overflowed,
+/// 0 otherwise.
+pub trait SmartOverflowingAdd<Lhs, Rhs> {
+    type Output;
+    fn smart_overflowing_add(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
+/// The output is the wrapped result and an integer encrypting 1 if the operation overflowed,
+/// 0 otherwise.
+pub trait SmartOverflowingSub<Lhs, Rhs> {
+    type Output;
+    fn smart_overflowing_sub(&self, lhs: Lhs, rhs: Rhs) -> Self::Output;
+}
+
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub enum RadixCiphertextDyn {
     Big(crate::integer::RadixCiphertextBig),
     Small(crate::integer::RadixCiphertextSmall),
 }
 
+impl FheSize for RadixCiphertextDyn {
+    fn element_count(&self) -> usize {
+        match self {
+            Self::Big(ct) => ct.element_count(),
+            Self::Small(ct) => ct.element_count(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Big(ct) => ct.heap_size(),
+            Self::Small(ct) => ct.heap_size(),
+        }
+    }
+}
+
 impl SmartNeg<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
     type Output = RadixCiphertextDyn;
     fn smart_neg(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
         match lhs {
             RadixCiphertextDyn::Big(lhs) => {
-                RadixCiphertextDyn::Big(self.smart_neg_parallelized(lhs))
+                RadixCiphertextDyn::Big(self.neg_parallelized(lhs))
+            }
+            RadixCiphertextDyn::Small(lhs) => {
+                RadixCiphertextDyn::Small(self.neg_parallelized(lhs))
+            }
+        }
+    }
+}
+
+impl SmartBitNot<&mut RadixCiphertextDyn> for crate::integer::ServerKey {
+    type Output = RadixCiphertextDyn;
+    fn smart_bitnot(&self, lhs: &mut RadixCiphertextDyn) -> Self::Output {
+        match lhs {
+            RadixCiphertextDyn::Big(lhs) => {
+                RadixCiphertextDyn::Big(self.bitnot_parallelized(lhs))
             }
             RadixCiphertextDyn::Small(lhs) => {
-                RadixCiphertextDyn::Small(self.smart_neg_parallelized(lhs))
+                RadixCiphertextDyn::Small(self.bitnot_parallelized(lhs))
             }
         }
     }
@@ -414,6 +474,85 @@ impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartLt(smart_lt) => lt_paralleli
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartMax(smart_max) => max_parallelized);
 impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartMin(smart_min) => min_parallelized);
 
+impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingAdd(smart_saturating_add) => saturating_add_parallelized);
+impl_smart_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingSub(smart_saturating_sub) => saturating_sub_parallelized);
+
+// The overflow flag is a single block, it is extended to an integer
+// with the same number of blocks as the result, like comparisons do.
+fn overflow_flag_to_radix<PBSOrder: crate::shortint::PBSOrderMarker>(
+    server_key: &crate::integer::ServerKey,
+    flag: crate::shortint::CiphertextBase<PBSOrder>,
+    num_blocks: usize,
+) -> crate::integer::ciphertext::RadixCiphertext<PBSOrder> {
+    let mut result = server_key.create_trivial_zero_radix(num_blocks);
+    result.blocks[0] = flag;
+    result
+}
+
+macro_rules! impl_smart_overflowing_op_for_tfhe_integer_server_key_dyn {
+    ($smart_trait:ident($smart_trait_fn:ident) => $method:ident, $scalar_method:ident) => {
+        impl $smart_trait<&mut RadixCiphertextDyn, &mut RadixCiphertextDyn>
+            for crate::integer::ServerKey
+        {
+            type Output = (RadixCiphertextDyn, RadixCiphertextDyn);
+
+            fn $smart_trait_fn(
+                &self,
+                lhs_enum: &mut RadixCiphertextDyn,
+                rhs_enum: &mut RadixCiphertextDyn,
+            ) -> Self::Output {
+                match (lhs_enum, rhs_enum) {
+                    (RadixCiphertextDyn::Big(lhs), RadixCiphertextDyn::Big(rhs)) => {
+                        let (result, flag) = self.$method(lhs, rhs);
+                        let flag = overflow_flag_to_radix(self, flag, result.blocks.len());
+                        (
+                            RadixCiphertextDyn::Big(result),
+                            RadixCiphertextDyn::Big(flag),
+                        )
+                    }
+                    (RadixCiphertextDyn::Small(lhs), RadixCiphertextDyn::Small(rhs)) => {
+                        let (result, flag) = self.$method(lhs, rhs);
+                        let flag = overflow_flag_to_radix(self, flag, result.blocks.len());
+                        (
+                            RadixCiphertextDyn::Small(result),
+                            RadixCiphertextDyn::Small(flag),
+                        )
+                    }
+                    (_, _) => unreachable!("internal error: mismatched big and small integer"),
+                }
+            }
+        }
+
+        impl $smart_trait<&mut RadixCiphertextDyn, u64> for crate::integer::ServerKey {
+            type Output = (RadixCiphertextDyn, RadixCiphertextDyn);
+
+            fn $smart_trait_fn(&self, lhs: &mut RadixCiphertextDyn, rhs: u64) -> Self::Output {
+                match lhs {
+                    RadixCiphertextDyn::Big(lhs) => {
+                        let (result, flag) = self.$scalar_method(lhs, rhs);
+                        let flag = overflow_flag_to_radix(self, flag, result.blocks.len());
+                        (
+                            RadixCiphertextDyn::Big(result),
+                            RadixCiphertextDyn::Big(flag),
+                        )
+                    }
+                    RadixCiphertextDyn::Small(lhs) => {
+                        let (result, flag) = self.$scalar_method(lhs, rhs);
+                        let flag = overflow_flag_to_radix(self, flag, result.blocks.len());
+                        (
+                            RadixCiphertextDyn::Small(result),
+                            RadixCiphertextDyn::Small(flag),
+                        )
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_smart_overflowing_op_for_tfhe_integer_server_key_dyn!(SmartOverflowingAdd(smart_overflowing_add) => overflowing_add_parallelized, overflowing_scalar_add_parallelized);
+impl_smart_overflowing_op_for_tfhe_integer_server_key_dyn!(SmartOverflowingSub(smart_overflowing_sub) => overflowing_sub_parallelized, overflowing_scalar_sub_parallelized);
+
 impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartAddAssign(smart_add_assign) => add_assign_parallelized);
 impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartSubAssign(smart_sub_assign) => sub_assign_parallelized);
 impl_smart_assign_op_for_tfhe_integer_server_key_dyn!(SmartMulAssign(smart_mul_assign) => mul_assign_parallelized);
@@ -427,6 +566,9 @@ impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartMul(smart_mul) => sca
 impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShl(smart_shl) => scalar_left_shift_parallelized);
 impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartShr(smart_shr) => scalar_right_shift_parallelized);
 
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingAdd(smart_saturating_add) => saturating_scalar_add_parallelized);
+impl_smart_scalar_op_for_tfhe_integer_server_key_dyn!(SmartSaturatingSub(smart_saturating_sub) => saturating_scalar_sub_parallelized);
+
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartAddAssign(smart_add_assign) => scalar_add_assign_parallelized);
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartSubAssign(smart_sub_assign) => scalar_sub_assign_parallelized);
 impl_smart_scalar_assign_op_for_tfhe_integer_server_key_dyn!(SmartMulAssign(smart_mul_assign) => scalar_mul_assign_parallelized);
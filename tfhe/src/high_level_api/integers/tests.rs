@@ -1,9 +1,10 @@
-use crate::high_level_api::prelude::*;
 use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
 use crate::integer::U256;
+use crate::prelude::*;
 use crate::{
-    CompressedFheUint16, CompressedFheUint256, CompressedPublicKey, FheUint128, FheUint16,
-    FheUint256, FheUint32, FheUint64,
+    Checked, CompactFheUint8List, CompactPublicKey, CompressedFheUint16, CompressedFheUint256,
+    CompressedPublicKey, EncryptedVec, FheExpr, FheUint128, FheUint16, FheUint256, FheUint32,
+    FheUint64, FheUint8Typed, Saturating,
 };
 
 #[test]
@@ -29,6 +30,86 @@ fn test_quickstart_uint8() {
     assert_eq!(decrypted_result, clear_result);
 }
 
+#[test]
+fn test_uint8_operand_combinations() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = &a + &b;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+
+    let result = &a * b.clone();
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(clear_b));
+
+    let result = a.clone() - &b;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_sub(clear_b));
+
+    let result = 3u8 + &a;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(3));
+
+    let result = 2u8 * a.clone();
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(2));
+
+    let mut c = a.clone();
+    c += &b;
+    c ^= b;
+    c -= 5u8;
+    let decrypted_result: u8 = c.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        (clear_a.wrapping_add(clear_b) ^ clear_b).wrapping_sub(5)
+    );
+}
+
+#[test]
+fn test_uint8_unary_operators() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 200u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = !&a;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, !clear_a);
+
+    let result = -&a;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_neg());
+
+    // The carries of the sum are not empty
+    let result = !(&a + &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, !clear_a.wrapping_add(clear_b));
+
+    let result = -&(&a + &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        clear_a.wrapping_add(clear_b).wrapping_neg()
+    );
+}
+
 #[test]
 fn test_uint8_compare() {
     let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
@@ -115,6 +196,26 @@ fn test_integer_compressed_small() {
     assert_eq!(clear_decompressed, clear);
 }
 
+#[test]
+fn test_integer_sizes() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_uint16()
+        .build();
+    let (client_key, _) = generate_keys(config);
+
+    let clear = 12_837u16;
+    let a = FheUint16::try_encrypt(clear, &client_key).unwrap();
+    let compressed = CompressedFheUint16::try_encrypt(clear, &client_key).unwrap();
+
+    assert_eq!(a.serialized_size(), bincode::serialized_size(&a).unwrap());
+    assert_eq!(
+        compressed.serialized_size(),
+        bincode::serialized_size(&compressed).unwrap()
+    );
+    assert!(compressed.element_count() < a.element_count());
+    assert!(compressed.in_memory_size() < a.in_memory_size());
+}
+
 #[test]
 fn test_uint32() {
     let config = ConfigBuilder::all_disabled()
@@ -202,6 +303,34 @@ fn test_integer_compressed_public_key() {
     assert_eq!(clear, 213u8);
 }
 
+#[test]
+fn test_integer_compact_public_key() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let clears = [213u8, 17u8, 0u8, 255u8];
+    let compact_list = CompactFheUint8List::try_encrypt(clears.as_slice(), &public_key).unwrap();
+    assert_eq!(compact_list.len(), clears.len());
+
+    let serialized = bincode::serialize(&compact_list).unwrap();
+    let compact_list: CompactFheUint8List = bincode::deserialize(&serialized).unwrap();
+
+    set_server_key(server_key);
+    let expanded = compact_list.expand();
+    assert_eq!(expanded.len(), clears.len());
+
+    for (ct, clear) in expanded.iter().zip(clears) {
+        let decrypted: u8 = ct.decrypt(&client_key);
+        assert_eq!(decrypted, clear);
+    }
+
+    let sum = &expanded[0] + &expanded[1];
+    let decrypted: u8 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, 213u8.wrapping_add(17u8));
+}
+
 #[test]
 fn test_trivial_fhe_uint8() {
     let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
@@ -237,3 +366,205 @@ fn test_trivial_fhe_uint256_small() {
     let clear: U256 = a.decrypt(&client_key);
     assert_eq!(clear, clear_a);
 }
+
+#[test]
+fn test_typed_uint8() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let a = FheUint8Typed::<2, 2>::try_encrypt(clear_a, &client_key).unwrap();
+    let b = FheUint8Typed::<2, 2>::try_encrypt(clear_b, &client_key).unwrap();
+
+    let result = &a + &b;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+
+    let result = a.lt(&b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, u8::from(clear_a < clear_b));
+
+    let serialized = bincode::serialize(&a).unwrap();
+    let deserialized: FheUint8Typed<2, 2> = bincode::deserialize(&serialized).unwrap();
+    let decrypted_result: u8 = deserialized.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a);
+
+    // The default uint8 parameters do not use 3 bits blocks
+    let deserialized = bincode::deserialize::<FheUint8Typed<3, 3>>(&serialized);
+    assert!(deserialized.is_err());
+
+    let result = FheUint8Typed::<3, 3>::try_encrypt(clear_a, &client_key);
+    assert!(matches!(
+        result,
+        Err(crate::high_level_api::Error::MismatchedBlockParameters {
+            expected: (8, 8),
+            found: (4, 4),
+        })
+    ));
+}
+
+#[test]
+fn test_uint8_overflow_semantics() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 200u8;
+    let clear_b = 100u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = Saturating(a.clone()) + Saturating(b.clone());
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.saturating_add(clear_b));
+
+    let result = Saturating(b.clone()) - &Saturating(a.clone());
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_b.saturating_sub(clear_a));
+
+    let result = Saturating(a.clone()) + 60u8;
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.saturating_add(60));
+
+    // No overflow, the flag is still computed
+    let result = Checked::new(b.clone()) + Checked::new(b.clone());
+    let (value, overflowed) = result.into_parts();
+    let decrypted_result: u8 = value.decrypt(&client_key);
+    let decrypted_flag: u8 = overflowed.unwrap().decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_b.wrapping_add(clear_b));
+    assert_eq!(decrypted_flag, 0);
+
+    // The flag is sticky
+    let mut result = Checked::new(a.clone());
+    result += &Checked::new(b);
+    result -= 150u8;
+    let (value, overflowed) = result.into_parts();
+    let decrypted_result: u8 = value.decrypt(&client_key);
+    let decrypted_flag: u8 = overflowed.unwrap().decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        clear_a.wrapping_add(clear_b).wrapping_sub(150)
+    );
+    assert_eq!(decrypted_flag, 1);
+
+    assert!(Checked::new(a).overflowed().is_none());
+}
+
+#[test]
+fn test_uint8_expression_builder() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    // The shared sum is computed once, the scalar additions are merged
+    let sum = FheExpr::encrypted(&a) + &b;
+    let expr = (sum.clone() + 3u64 + 4u64) * (sum - 5u64);
+    assert_eq!(expr.num_operations(), 4);
+    let decrypted_result: u8 = expr.eval().decrypt(&client_key);
+    let clear_sum = clear_a.wrapping_add(clear_b);
+    assert_eq!(
+        decrypted_result,
+        clear_sum
+            .wrapping_add(7)
+            .wrapping_mul(clear_sum.wrapping_sub(5))
+    );
+
+    // Clear sub-expressions are folded and identities removed
+    let expr =
+        FheExpr::encrypted(&a) * (FheExpr::clear(2) * 3u64 - 5u64) + (FheExpr::clear(10) - 10u64);
+    assert_eq!(expr.num_operations(), 0);
+    let decrypted_result: u8 = expr.eval().decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a);
+
+    // The bitwise chain with clear values is a single lookup table per block
+    let expr = ((FheExpr::encrypted(&a) & 0xF5u64) ^ 0x0Fu64) | 0x30u64;
+    assert_eq!(expr.num_operations(), 1);
+    let decrypted_result: u8 = expr.eval().decrypt(&client_key);
+    assert_eq!(decrypted_result, ((clear_a & 0xF5) ^ 0x0F) | 0x30);
+
+    let expr = (FheExpr::encrypted(&a) << 3) << 2 ^ -(FheExpr::encrypted(&b) >> 1);
+    let decrypted_result: u8 = expr.eval().decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        (clear_a << 5) ^ (clear_b >> 1).wrapping_neg()
+    );
+
+    // x - x and x ^ x are known to be zero
+    let expr = (FheExpr::encrypted(&a) - &a) + (FheExpr::encrypted(&b) ^ &b);
+    assert_eq!(expr.num_operations(), 0);
+    let decrypted_result: u8 = expr.eval().decrypt(&client_key);
+    assert_eq!(decrypted_result, 0);
+}
+
+#[test]
+fn test_uint8_encrypted_vec() {
+    let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_lhs = [27u8, 128, 3, 250, 64];
+    let clear_rhs = [13u8, 200, 3, 7, 1];
+
+    let lhs: EncryptedVec<FheUint8> = clear_lhs
+        .iter()
+        .map(|v| FheUint8::encrypt(*v, &client_key))
+        .collect();
+    let rhs: EncryptedVec<FheUint8> = clear_rhs
+        .iter()
+        .map(|v| FheUint8::encrypt(*v, &client_key))
+        .collect();
+
+    let decrypt_all = |values: &EncryptedVec<FheUint8>| -> Vec<u8> {
+        values.iter().map(|v| v.decrypt(&client_key)).collect()
+    };
+
+    let expected: Vec<u8> = clear_lhs
+        .iter()
+        .zip(clear_rhs.iter())
+        .map(|(l, r)| l.wrapping_add(*r))
+        .collect();
+    assert_eq!(decrypt_all(&lhs.zip_add(&rhs)), expected);
+
+    let expected: Vec<u8> = clear_lhs
+        .iter()
+        .zip(clear_rhs.iter())
+        .map(|(l, r)| *l.max(r))
+        .collect();
+    assert_eq!(decrypt_all(&lhs.zip_max(&rhs)), expected);
+
+    // Odd lengths leave the last element of a level of the tree alone
+    let sum: u8 = lhs.sum().unwrap().decrypt(&client_key);
+    assert_eq!(
+        sum,
+        clear_lhs.iter().fold(0u8, |acc, v| acc.wrapping_add(*v))
+    );
+    let min: u8 = lhs.min().unwrap().decrypt(&client_key);
+    assert_eq!(min, *clear_lhs.iter().min().unwrap());
+    let max: u8 = rhs.max().unwrap().decrypt(&client_key);
+    assert_eq!(max, *clear_rhs.iter().max().unwrap());
+
+    let mask = lhs.filter(|x| x >= 64);
+    let expected: Vec<u8> = clear_lhs.iter().map(|v| u8::from(*v >= 64)).collect();
+    assert_eq!(decrypt_all(&mask), expected);
+
+    let empty = EncryptedVec::<FheUint8>::new(vec![]);
+    assert!(empty.sum().is_none());
+    assert!(empty.zip_add(&empty).is_empty());
+}
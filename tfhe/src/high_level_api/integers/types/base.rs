@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
-    Neg, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
 use crate::high_level_api::global_state::WithGlobalKey;
@@ -12,8 +12,9 @@ use crate::high_level_api::integers::public_key::compressed::GenericIntegerCompr
 use crate::high_level_api::integers::public_key::GenericIntegerPublicKey;
 use crate::high_level_api::integers::server_key::{
     GenericIntegerServerKey, RadixCiphertextDyn, SmartAdd, SmartAddAssign, SmartBitAnd,
-    SmartBitAndAssign, SmartBitOr, SmartBitOrAssign, SmartBitXor, SmartBitXorAssign, SmartEq,
-    SmartGe, SmartGt, SmartLe, SmartLt, SmartMax, SmartMin, SmartMul, SmartMulAssign, SmartNeg,
+    SmartBitAndAssign, SmartBitNot, SmartBitOr, SmartBitOrAssign, SmartBitXor, SmartBitXorAssign,
+    SmartEq, SmartGe, SmartGt, SmartLe, SmartLt, SmartMax, SmartMin, SmartMul, SmartMulAssign,
+    SmartNeg, SmartOverflowingAdd, SmartOverflowingSub, SmartSaturatingAdd, SmartSaturatingSub,
     SmartShl, SmartShlAssign, SmartShr, SmartShrAssign, SmartSub, SmartSubAssign,
 };
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
@@ -26,6 +27,7 @@ use crate::high_level_api::traits::{
 };
 use crate::high_level_api::{ClientKey, PublicKey};
 use crate::integer::U256;
+use crate::size::FheSize;
 
 /// A Generic FHE unsigned integer
 ///
@@ -62,6 +64,10 @@ pub struct GenericInteger<P: IntegerParameter> {
     pub(in crate::high_level_api::integers) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: IntegerParameter] for GenericInteger<P> [where P::InnerCiphertext: FheSize] {
+    ciphertext
+});
+
 impl<P> GenericInteger<P>
 where
     P: IntegerParameter,
@@ -273,6 +279,295 @@ where
     }
 }
 
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    GenericInteger<P>: Clone,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartSaturatingAdd<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        > + for<'a> SmartSaturatingSub<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Adds `rhs`, saturating at the maximum value of the type instead of wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), tfhe::Error> {
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::try_encrypt(200u8, &keys)?;
+    /// let b = FheUint8::try_encrypt(100u8, &keys)?;
+    ///
+    /// let result = a.saturating_add(&b);
+    /// let decrypted: u8 = result.decrypt(&keys);
+    /// assert_eq!(decrypted, 200u8.saturating_add(100u8));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_saturating_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_saturating_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+
+    /// Subtracts `rhs`, saturating at zero instead of wrapping around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), tfhe::Error> {
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::try_encrypt(100u8, &keys)?;
+    /// let b = FheUint8::try_encrypt(200u8, &keys)?;
+    ///
+    /// let result = a.saturating_sub(&b);
+    /// let decrypted: u8 = result.decrypt(&keys);
+    /// assert_eq!(decrypted, 100u8.saturating_sub(200u8));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_saturating_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_saturating_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartSaturatingAdd<&'a mut P::InnerCiphertext, u64, Output = P::InnerCiphertext>
+        + for<'a> SmartSaturatingSub<&'a mut P::InnerCiphertext, u64, Output = P::InnerCiphertext>,
+{
+    /// Adds a clear value, saturating at the maximum value of the type instead of wrapping around.
+    pub fn saturating_scalar_add<T: Into<u64>>(&self, rhs: T) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_saturating_add(&mut self.ciphertext.borrow_mut(), rhs.into())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+
+    /// Subtracts a clear value, saturating at zero instead of wrapping around.
+    pub fn saturating_scalar_sub<T: Into<u64>>(&self, rhs: T) -> Self {
+        let inner_result = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_saturating_sub(&mut self.ciphertext.borrow_mut(), rhs.into())
+        });
+
+        GenericInteger::new(inner_result, self.id)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    GenericInteger<P>: Clone,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartOverflowingAdd<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = (P::InnerCiphertext, P::InnerCiphertext),
+        > + for<'a> SmartOverflowingSub<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = (P::InnerCiphertext, P::InnerCiphertext),
+        >,
+{
+    /// Adds `rhs`, wrapping around on overflow.
+    ///
+    /// Returns the result and an encryption of 1 if the addition overflowed, 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), tfhe::Error> {
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::try_encrypt(200u8, &keys)?;
+    /// let b = FheUint8::try_encrypt(100u8, &keys)?;
+    ///
+    /// let (result, overflowed) = a.overflowing_add(&b);
+    /// let decrypted: u8 = result.decrypt(&keys);
+    /// let overflowed: u8 = overflowed.decrypt(&keys);
+    /// assert_eq!((decrypted, overflowed == 1), 200u8.overflowing_add(100u8));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, Self) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_overflowing_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_overflowing_add(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        (
+            GenericInteger::new(result, self.id),
+            GenericInteger::new(overflowed, self.id),
+        )
+    }
+
+    /// Subtracts `rhs`, wrapping around on overflow.
+    ///
+    /// Returns the result and an encryption of 1 if the subtraction overflowed, 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), tfhe::Error> {
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::try_encrypt(100u8, &keys)?;
+    /// let b = FheUint8::try_encrypt(200u8, &keys)?;
+    ///
+    /// let (result, overflowed) = a.overflowing_sub(&b);
+    /// let decrypted: u8 = result.decrypt(&keys);
+    /// let overflowed: u8 = overflowed.decrypt(&keys);
+    /// assert_eq!((decrypted, overflowed == 1), 100u8.overflowing_sub(200u8));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, Self) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|server_key| {
+            if std::ptr::eq(self, rhs) {
+                let cloned = (*rhs).clone();
+                let r = server_key.inner.smart_overflowing_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut cloned.ciphertext.borrow_mut(),
+                );
+                r
+            } else {
+                server_key.inner.smart_overflowing_sub(
+                    &mut self.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            }
+        });
+
+        (
+            GenericInteger::new(result, self.id),
+            GenericInteger::new(overflowed, self.id),
+        )
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartOverflowingAdd<
+            &'a mut P::InnerCiphertext,
+            u64,
+            Output = (P::InnerCiphertext, P::InnerCiphertext),
+        > + for<'a> SmartOverflowingSub<
+            &'a mut P::InnerCiphertext,
+            u64,
+            Output = (P::InnerCiphertext, P::InnerCiphertext),
+        >,
+{
+    /// Adds a clear value, wrapping around on overflow.
+    ///
+    /// Returns the result and an encryption of 1 if the addition overflowed, 0 otherwise.
+    pub fn overflowing_scalar_add<T: Into<u64>>(&self, rhs: T) -> (Self, Self) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_overflowing_add(&mut self.ciphertext.borrow_mut(), rhs.into())
+        });
+
+        (
+            GenericInteger::new(result, self.id),
+            GenericInteger::new(overflowed, self.id),
+        )
+    }
+
+    /// Subtracts a clear value, wrapping around on overflow.
+    ///
+    /// Returns the result and an encryption of 1 if the subtraction overflowed, 0 otherwise.
+    pub fn overflowing_scalar_sub<T: Into<u64>>(&self, rhs: T) -> (Self, Self) {
+        let (result, overflowed) = self.id.with_unwrapped_global(|server_key| {
+            server_key
+                .inner
+                .smart_overflowing_sub(&mut self.ciphertext.borrow_mut(), rhs.into())
+        });
+
+        (
+            GenericInteger::new(result, self.id),
+            GenericInteger::new(overflowed, self.id),
+        )
+    }
+}
+
 impl<P, B> FheEq<B> for GenericInteger<P>
 where
     B: Borrow<GenericInteger<P>>,
@@ -472,7 +767,7 @@ where
 }
 
 macro_rules! generic_integer_impl_operation (
-    ($trait_name:ident($trait_method:ident,$op:tt, $smart_trait:ident) => $key_method:ident) => {
+    ($trait_name:ident($trait_method:ident,$op:tt, $smart_trait:ident) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
         #[doc = concat!(" Allows using the `", stringify!($op), "` operator between a")]
         #[doc = " `GenericInteger` and a `GenericInteger` or a `&GenericInteger`"]
         #[doc = " "]
@@ -530,17 +825,14 @@ macro_rules! generic_integer_impl_operation (
         where
             P: IntegerParameter,
             B: Borrow<Self>,
-            GenericInteger<P>: Clone,
-            P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
-            P::InnerServerKey: for<'a> $smart_trait<
-                                            &'a mut P::InnerCiphertext,
-                                            &'a mut P::InnerCiphertext,
-                                            Output=P::InnerCiphertext>,
+            GenericInteger<P>: $assign_trait<B>,
         {
             type Output = Self;
 
-            fn $trait_method(self, rhs: B) -> Self::Output {
-                <&Self as $trait_name<B>>::$trait_method(&self, rhs)
+            // The left hand side is owned, its ciphertext is reused for the result
+            fn $trait_method(mut self, rhs: B) -> Self::Output {
+                <Self as $assign_trait<B>>::$assign_method(&mut self, rhs);
+                self
             }
         }
 
@@ -603,21 +895,19 @@ macro_rules! generic_integer_impl_operation_assign (
 );
 
 macro_rules! generic_integer_impl_scalar_operation {
-    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*)) => {
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*), $assign_trait:ident($assign_method:ident)) => {
         $(
             impl<P> $trait_name<$scalar_type> for GenericInteger<P>
             where
                 P: IntegerParameter,
-                P::Id: WithGlobalKey<Key=GenericIntegerServerKey<P>>,
-                P::InnerServerKey: for<'a> $smart_trait<
-                                            &'a mut P::InnerCiphertext,
-                                            u64,
-                                            Output=P::InnerCiphertext>,
+                GenericInteger<P>: $assign_trait<$scalar_type>,
             {
                 type Output = GenericInteger<P>;
 
-                fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
-                    <&Self as $trait_name<$scalar_type>>::$trait_method(&self, rhs)
+                // The left hand side is owned, its ciphertext is reused for the result
+                fn $trait_method(mut self, rhs: $scalar_type) -> Self::Output {
+                    <Self as $assign_trait<$scalar_type>>::$assign_method(&mut self, rhs);
+                    self
                 }
             }
 
@@ -647,6 +937,37 @@ macro_rules! generic_integer_impl_scalar_operation {
     };
 }
 
+// Only for commutative operations, the scalar is moved to the right hand side
+macro_rules! generic_integer_impl_scalar_left_operation {
+    ($trait_name:ident($trait_method:ident)($($scalar_type:ty),*)) => {
+        $(
+            impl<P> $trait_name<GenericInteger<P>> for $scalar_type
+            where
+                P: IntegerParameter,
+                GenericInteger<P>: $trait_name<$scalar_type, Output=GenericInteger<P>>,
+            {
+                type Output = GenericInteger<P>;
+
+                fn $trait_method(self, rhs: GenericInteger<P>) -> Self::Output {
+                    <GenericInteger<P> as $trait_name<$scalar_type>>::$trait_method(rhs, self)
+                }
+            }
+
+            impl<'a, P> $trait_name<&'a GenericInteger<P>> for $scalar_type
+            where
+                P: IntegerParameter,
+                &'a GenericInteger<P>: $trait_name<$scalar_type, Output=GenericInteger<P>>,
+            {
+                type Output = GenericInteger<P>;
+
+                fn $trait_method(self, rhs: &'a GenericInteger<P>) -> Self::Output {
+                    <&'a GenericInteger<P> as $trait_name<$scalar_type>>::$trait_method(rhs, self)
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! generic_integer_impl_scalar_operation_assign {
     ($trait_name:ident($trait_method:ident,$smart_assign_trait:ident) => $key_method:ident($($scalar_type:ty),*)) => {
         $(
@@ -669,12 +990,12 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
     }
 }
 
-generic_integer_impl_operation!(Add(add,+, SmartAdd) => smart_add);
-generic_integer_impl_operation!(Sub(sub,-, SmartSub) => smart_sub);
-generic_integer_impl_operation!(Mul(mul,*, SmartMul) => smart_mul);
-generic_integer_impl_operation!(BitAnd(bitand,&, SmartBitAnd) => smart_bitand);
-generic_integer_impl_operation!(BitOr(bitor,|, SmartBitOr) => smart_bitor);
-generic_integer_impl_operation!(BitXor(bitxor,^, SmartBitXor) => smart_bitxor);
+generic_integer_impl_operation!(Add(add,+, SmartAdd) => smart_add, AddAssign(add_assign));
+generic_integer_impl_operation!(Sub(sub,-, SmartSub) => smart_sub, SubAssign(sub_assign));
+generic_integer_impl_operation!(Mul(mul,*, SmartMul) => smart_mul, MulAssign(mul_assign));
+generic_integer_impl_operation!(BitAnd(bitand,&, SmartBitAnd) => smart_bitand, BitAndAssign(bitand_assign));
+generic_integer_impl_operation!(BitOr(bitor,|, SmartBitOr) => smart_bitor, BitOrAssign(bitor_assign));
+generic_integer_impl_operation!(BitXor(bitxor,^, SmartBitXor) => smart_bitxor, BitXorAssign(bitxor_assign));
 
 generic_integer_impl_operation_assign!(AddAssign(add_assign,+=, SmartAddAssign) => smart_add_assign);
 generic_integer_impl_operation_assign!(SubAssign(sub_assign,-=, SmartSubAssign) => smart_sub_assign);
@@ -683,11 +1004,14 @@ generic_integer_impl_operation_assign!(BitAndAssign(bitand_assign,&=, SmartBitAn
 generic_integer_impl_operation_assign!(BitOrAssign(bitor_assign,|=, SmartBitOrAssign) => smart_bitor_assign);
 generic_integer_impl_operation_assign!(BitXorAssign(bitxor_assign,^=, SmartBitXorAssign) => smart_bitxor_assign);
 
-generic_integer_impl_scalar_operation!(Add(add, SmartAdd) => smart_add(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Shl(shl, SmartShl) => smart_shl(u8, u16, u32, u64));
-generic_integer_impl_scalar_operation!(Shr(shr, SmartShr) => smart_shr(u8, u16, u32, u64));
+generic_integer_impl_scalar_operation!(Add(add, SmartAdd) => smart_add(u8, u16, u32, u64), AddAssign(add_assign));
+generic_integer_impl_scalar_operation!(Sub(sub, SmartSub) => smart_sub(u8, u16, u32, u64), SubAssign(sub_assign));
+generic_integer_impl_scalar_operation!(Mul(mul, SmartMul) => smart_mul(u8, u16, u32, u64), MulAssign(mul_assign));
+generic_integer_impl_scalar_operation!(Shl(shl, SmartShl) => smart_shl(u8, u16, u32, u64), ShlAssign(shl_assign));
+generic_integer_impl_scalar_operation!(Shr(shr, SmartShr) => smart_shr(u8, u16, u32, u64), ShrAssign(shr_assign));
+
+generic_integer_impl_scalar_left_operation!(Add(add)(u8, u16, u32, u64));
+generic_integer_impl_scalar_left_operation!(Mul(mul)(u8, u16, u32, u64));
 
 generic_integer_impl_scalar_operation_assign!(AddAssign(add_assign, SmartAddAssign) => smart_add_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(SubAssign(sub_assign, SmartSubAssign) => smart_sub_assign(u8, u16, u32, u64));
@@ -695,6 +1019,36 @@ generic_integer_impl_scalar_operation_assign!(MulAssign(mul_assign, SmartMulAssi
 generic_integer_impl_scalar_operation_assign!(ShlAssign(shl_assign, SmartShlAssign) => smart_shl_assign(u8, u16, u32, u64));
 generic_integer_impl_scalar_operation_assign!(ShrAssign(shr_assign, SmartShrAssign) => smart_shr_assign(u8, u16, u32, u64));
 
+impl<P> Not for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartBitNot<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    type Output = GenericInteger<P>;
+
+    fn not(self) -> Self::Output {
+        <&Self as Not>::not(&self)
+    }
+}
+
+impl<P> Not for &GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartBitNot<&'a mut P::InnerCiphertext, Output = P::InnerCiphertext>,
+{
+    type Output = GenericInteger<P>;
+
+    fn not(self) -> Self::Output {
+        let ciphertext = self
+            .id
+            .with_unwrapped_global(|key| key.inner.smart_bitnot(&mut self.ciphertext.borrow_mut()));
+
+        GenericInteger::<P>::new(ciphertext, self.id)
+    }
+}
+
 impl<P> Neg for GenericInteger<P>
 where
     P: IntegerParameter,
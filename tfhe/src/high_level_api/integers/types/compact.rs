@@ -0,0 +1,95 @@
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::public_key::compact::GenericIntegerCompactPublicKey;
+use crate::high_level_api::integers::server_key::RadixCiphertextDyn;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::high_level_api::keys::{CompactPublicKey, RefKeyFromCompactPublicKeyChain};
+use crate::high_level_api::traits::FheTryEncrypt;
+use crate::integer::U256;
+
+/// A list of integers encrypted with a [CompactPublicKey].
+///
+/// The list is much smaller than the individual ciphertexts it contains, which makes it well
+/// suited to send many values at once. It has to be expanded (usually on the server side) to get
+/// ciphertexts on which computations can be done.
+///
+/// The expanded ciphertexts use the `KeyswitchBootstrap` PBS order.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct GenericCompactIntegerList<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::high_level_api::integers) list: crate::integer::CompactCiphertextList,
+    pub(in crate::high_level_api::integers) id: P::Id,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl[P: IntegerParameter] for GenericCompactIntegerList<P> { list });
+
+impl<P> GenericCompactIntegerList<P>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    /// Returns the number of integers in the list.
+    pub fn len(&self) -> usize {
+        self.list.ciphertext_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Expands the list into the individual ciphertexts it contains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactFheUint8List, CompactPublicKey, ConfigBuilder};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let clears = [17u8, 100u8, 255u8];
+    /// let compact_list = CompactFheUint8List::try_encrypt(clears.as_slice(), &public_key).unwrap();
+    /// assert_eq!(compact_list.len(), clears.len());
+    ///
+    /// set_server_key(server_key);
+    /// let expanded = compact_list.expand();
+    /// let sum = &expanded[0] + &expanded[1];
+    ///
+    /// let decrypted: u8 = sum.decrypt(&client_key);
+    /// assert_eq!(decrypted, 117u8);
+    /// let decrypted: u8 = expanded[2].decrypt(&client_key);
+    /// assert_eq!(decrypted, 255u8);
+    /// ```
+    pub fn expand(&self) -> Vec<GenericInteger<P>> {
+        self.list
+            .expand()
+            .into_iter()
+            .map(|ct| GenericInteger::new(RadixCiphertextDyn::Big(ct), self.id))
+            .collect()
+    }
+}
+
+impl<'a, P, T> FheTryEncrypt<&'a [T], CompactPublicKey> for GenericCompactIntegerList<P>
+where
+    T: Into<U256> + Copy,
+    P: IntegerParameter,
+    P::Id: RefKeyFromCompactPublicKeyChain<Key = GenericIntegerCompactPublicKey<P>> + Default,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(values: &'a [T], key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        let id = P::Id::default();
+        let key = id.ref_key(key)?;
+        let values = values
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect::<Vec<U256>>();
+        let list = key
+            .inner
+            .encrypt_slice_radix_compact(&values, key.num_blocks);
+        Ok(Self { list, id })
+    }
+}
@@ -7,6 +7,7 @@ use crate::high_level_api::keys::RefKeyFromKeyChain;
 use crate::high_level_api::traits::FheTryEncrypt;
 use crate::high_level_api::ClientKey;
 use crate::integer::U256;
+use crate::size::FheSize;
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub(in crate::high_level_api::integers) enum CompressedRadixCiphertextDyn {
@@ -14,6 +15,22 @@ pub(in crate::high_level_api::integers) enum CompressedRadixCiphertextDyn {
     Small(crate::integer::CompressedRadixCiphertextSmall),
 }
 
+impl FheSize for CompressedRadixCiphertextDyn {
+    fn element_count(&self) -> usize {
+        match self {
+            Self::Big(ct) => ct.element_count(),
+            Self::Small(ct) => ct.element_count(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Big(ct) => ct.heap_size(),
+            Self::Small(ct) => ct.heap_size(),
+        }
+    }
+}
+
 impl From<CompressedRadixCiphertextDyn> for RadixCiphertextDyn {
     fn from(value: CompressedRadixCiphertextDyn) -> Self {
         match value {
@@ -32,6 +49,8 @@ where
     pub(in crate::high_level_api::integers) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: IntegerParameter] for CompressedGenericInteger<P> { ciphertext });
+
 impl<P> CompressedGenericInteger<P>
 where
     P: IntegerParameter,
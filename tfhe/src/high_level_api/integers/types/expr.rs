@@ -0,0 +1,801 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Shl, Shr, Sub};
+use std::rc::Rc;
+
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::{IntegerParameter, StaticIntegerParameter};
+use crate::high_level_api::integers::server_key::{GenericIntegerServerKey, RadixCiphertextDyn};
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::high_level_api::traits::FheTrivialEncrypt;
+
+/// An expression mixing encrypted integers and clear values.
+///
+/// Contrary to the operators of [GenericInteger], which compute each operation as soon as it is
+/// written, building a `FheExpr` only records the operations. The whole expression is optimized
+/// when it is evaluated with [FheExpr::eval]:
+///
+/// - sub-expressions only made of clear values are computed in the clear, and operations which do
+///   not change their operand (e.g. `x + 0`, `x * 1`) are removed,
+/// - operations between an encrypted value and a clear one use the scalar operations of the server
+///   key, and consecutive ones are merged (e.g. `(x + 1) + 2` becomes `x + 3`),
+/// - chains of bitwise operations with clear values (e.g. `(x & 0xF0) ^ 0x0F`) are fused into a
+///   single lookup table per block,
+/// - sub-expressions appearing more than once are only computed once.
+///
+/// Clear values are wrapped modulo `2^N` for an `N` bits integer, as the operators of
+/// [GenericInteger] do.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), tfhe::Error> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheExpr, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (keys, server_key) = generate_keys(config);
+///
+/// let a = FheUint8::try_encrypt(13u8, &keys)?;
+/// let b = FheUint8::try_encrypt(7u8, &keys)?;
+///
+/// set_server_key(server_key);
+///
+/// let sum = FheExpr::encrypted(&a) + &b;
+/// // `sum` is only computed once, and the bitwise operations make a single lookup table
+/// let expr = sum.clone() * 3u64 + ((sum & 0xF0u64) ^ 0x0Fu64 | 0x01u64);
+///
+/// let result = expr.eval();
+/// let decrypted: u8 = result.decrypt(&keys);
+/// assert_eq!(decrypted, 20 * 3 + ((20 & 0xF0) ^ 0x0F | 0x01));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+pub struct FheExpr<'a, P: IntegerParameter> {
+    node: Rc<Node<'a, P>>,
+}
+
+enum Node<'a, P: IntegerParameter> {
+    Encrypted(&'a GenericInteger<P>),
+    Clear(u64),
+    Unary(UnaryOp, FheExpr<'a, P>),
+    Binary(BinaryOp, FheExpr<'a, P>, FheExpr<'a, P>),
+}
+
+#[derive(Copy, Clone)]
+enum UnaryOp {
+    Neg,
+    Shl(u64),
+    Shr(u64),
+}
+
+#[derive(Copy, Clone)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl<'a, P: IntegerParameter> Clone for FheExpr<'a, P> {
+    // Only the reference to the node is cloned, which is what makes a sub-expression shared
+    fn clone(&self) -> Self {
+        Self {
+            node: Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<'a, P: IntegerParameter> FheExpr<'a, P> {
+    /// Creates an expression made of an encrypted value.
+    pub fn encrypted(value: &'a GenericInteger<P>) -> Self {
+        Self::from_node(Node::Encrypted(value))
+    }
+
+    /// Creates an expression made of a clear value.
+    pub fn clear(value: u64) -> Self {
+        Self::from_node(Node::Clear(value))
+    }
+
+    fn from_node(node: Node<'a, P>) -> Self {
+        Self {
+            node: Rc::new(node),
+        }
+    }
+
+    fn unary(op: UnaryOp, operand: Self) -> Self {
+        Self::from_node(Node::Unary(op, operand))
+    }
+
+    fn binary(op: BinaryOp, lhs: Self, rhs: Self) -> Self {
+        Self::from_node(Node::Binary(op, lhs, rhs))
+    }
+}
+
+impl<'a, P: IntegerParameter> From<&'a GenericInteger<P>> for FheExpr<'a, P> {
+    fn from(value: &'a GenericInteger<P>) -> Self {
+        Self::encrypted(value)
+    }
+}
+
+impl<'a, P: IntegerParameter> From<u64> for FheExpr<'a, P> {
+    fn from(value: u64) -> Self {
+        Self::clear(value)
+    }
+}
+
+impl<'a, P> FheExpr<'a, P>
+where
+    P: StaticIntegerParameter<
+        InnerCiphertext = RadixCiphertextDyn,
+        InnerServerKey = crate::integer::ServerKey,
+    >,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>> + Default,
+    GenericInteger<P>: Clone,
+{
+    /// Optimizes the expression and evaluates it with the server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key was not set.
+    #[track_caller]
+    pub fn eval(&self) -> GenericInteger<P> {
+        let mut program = Program::new(P::MESSAGE_BITS);
+        let root = program.lower(self);
+        program.run(root)
+    }
+
+    /// Returns the number of operations on encrypted values computed by [FheExpr::eval], once the
+    /// expression is optimized.
+    pub fn num_operations(&self) -> usize {
+        let mut program = Program::new(P::MESSAGE_BITS);
+        let root = program.lower(self);
+        program
+            .live_instructions(root)
+            .iter()
+            .zip(program.instructions.iter())
+            .filter(|(is_live, instruction)| {
+                **is_live && !matches!(instruction, Instruction::Input(_) | Instruction::Clear(_))
+            })
+            .count()
+    }
+}
+
+macro_rules! fhe_expr_impl_operation {
+    ($trait_name:ident($trait_method:ident) => $op:ident) => {
+        impl<'a, P, B> $trait_name<B> for FheExpr<'a, P>
+        where
+            P: IntegerParameter,
+            B: Into<FheExpr<'a, P>>,
+        {
+            type Output = FheExpr<'a, P>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                FheExpr::binary(BinaryOp::$op, self, rhs.into())
+            }
+        }
+
+        impl<'a, P, B> $trait_name<B> for &FheExpr<'a, P>
+        where
+            P: IntegerParameter,
+            B: Into<FheExpr<'a, P>>,
+        {
+            type Output = FheExpr<'a, P>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                FheExpr::binary(BinaryOp::$op, self.clone(), rhs.into())
+            }
+        }
+
+        impl<'a, P> $trait_name<FheExpr<'a, P>> for u64
+        where
+            P: IntegerParameter,
+        {
+            type Output = FheExpr<'a, P>;
+
+            fn $trait_method(self, rhs: FheExpr<'a, P>) -> Self::Output {
+                FheExpr::binary(BinaryOp::$op, FheExpr::clear(self), rhs)
+            }
+        }
+    };
+}
+
+fhe_expr_impl_operation!(Add(add) => Add);
+fhe_expr_impl_operation!(Sub(sub) => Sub);
+fhe_expr_impl_operation!(Mul(mul) => Mul);
+fhe_expr_impl_operation!(BitAnd(bitand) => BitAnd);
+fhe_expr_impl_operation!(BitOr(bitor) => BitOr);
+fhe_expr_impl_operation!(BitXor(bitxor) => BitXor);
+
+macro_rules! fhe_expr_impl_shift {
+    ($trait_name:ident($trait_method:ident) => $op:ident) => {
+        impl<'a, P: IntegerParameter> $trait_name<u64> for FheExpr<'a, P> {
+            type Output = FheExpr<'a, P>;
+
+            fn $trait_method(self, rhs: u64) -> Self::Output {
+                FheExpr::unary(UnaryOp::$op(rhs), self)
+            }
+        }
+
+        impl<'a, P: IntegerParameter> $trait_name<u64> for &FheExpr<'a, P> {
+            type Output = FheExpr<'a, P>;
+
+            fn $trait_method(self, rhs: u64) -> Self::Output {
+                FheExpr::unary(UnaryOp::$op(rhs), self.clone())
+            }
+        }
+    };
+}
+
+fhe_expr_impl_shift!(Shl(shl) => Shl);
+fhe_expr_impl_shift!(Shr(shr) => Shr);
+
+impl<'a, P: IntegerParameter> Neg for FheExpr<'a, P> {
+    type Output = FheExpr<'a, P>;
+
+    fn neg(self) -> Self::Output {
+        FheExpr::unary(UnaryOp::Neg, self)
+    }
+}
+
+impl<'a, P: IntegerParameter> Neg for &FheExpr<'a, P> {
+    type Output = FheExpr<'a, P>;
+
+    fn neg(self) -> Self::Output {
+        FheExpr::unary(UnaryOp::Neg, self.clone())
+    }
+}
+
+/// Index of an instruction in a [Program], which is also the index of its result.
+type ValueId = usize;
+
+/// Mask of the bits kept by a bitwise and.
+///
+/// Clear values are `u64`, `high_bits` gives the value of the bits above the 64th for the
+/// integers having more than 64 bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct AndMask {
+    low: u64,
+    high_bits: bool,
+}
+
+impl AndMask {
+    const ALL: Self = Self {
+        low: u64::MAX,
+        high_bits: true,
+    };
+
+    /// Returns the `count` bits of the mask starting at the `shift`-th one.
+    fn bits(&self, shift: usize, count: usize) -> u64 {
+        let high = if self.high_bits { u64::MAX } else { 0 };
+        let bits = if shift >= 64 {
+            high
+        } else if shift == 0 {
+            self.low
+        } else {
+            (self.low >> shift) | (high << (64 - shift))
+        };
+        bits & low_bits_mask(count)
+    }
+}
+
+fn low_bits_mask(count: usize) -> u64 {
+    if count >= 64 {
+        u64::MAX
+    } else {
+        (1 << count) - 1
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Instruction {
+    Input(usize),
+    Clear(u64),
+    Add(ValueId, ValueId),
+    Sub(ValueId, ValueId),
+    Mul(ValueId, ValueId),
+    BitAnd(ValueId, ValueId),
+    BitOr(ValueId, ValueId),
+    BitXor(ValueId, ValueId),
+    Neg(ValueId),
+    ScalarAdd(ValueId, u64),
+    ScalarSub(ValueId, u64),
+    ScalarMul(ValueId, u64),
+    Shl(ValueId, u64),
+    Shr(ValueId, u64),
+    // (x & and_mask) ^ xor_mask, computed with one lookup table per block
+    BitMask(ValueId, AndMask, u64),
+}
+
+impl Instruction {
+    fn operands(&self) -> [Option<ValueId>; 2] {
+        match *self {
+            Self::Input(_) | Self::Clear(_) => [None, None],
+            Self::Add(lhs, rhs)
+            | Self::Sub(lhs, rhs)
+            | Self::Mul(lhs, rhs)
+            | Self::BitAnd(lhs, rhs)
+            | Self::BitOr(lhs, rhs)
+            | Self::BitXor(lhs, rhs) => [Some(lhs), Some(rhs)],
+            Self::Neg(operand)
+            | Self::ScalarAdd(operand, _)
+            | Self::ScalarSub(operand, _)
+            | Self::ScalarMul(operand, _)
+            | Self::Shl(operand, _)
+            | Self::Shr(operand, _)
+            | Self::BitMask(operand, _, _) => [Some(operand), None],
+        }
+    }
+}
+
+/// The optimized form of an expression.
+///
+/// Each instruction only refers to the results of the previous ones. Instructions are
+/// simplified as they are added, and an instruction equal to an existing one is not added again,
+/// which is how common sub-expressions are eliminated.
+struct Program<'a, P: IntegerParameter> {
+    num_bits: usize,
+    instructions: Vec<Instruction>,
+    instruction_ids: HashMap<Instruction, ValueId>,
+    inputs: Vec<&'a GenericInteger<P>>,
+    input_ids: HashMap<*const GenericInteger<P>, ValueId>,
+    // Nodes already lowered, so that shared nodes are only visited once
+    node_ids: HashMap<*const Node<'a, P>, ValueId>,
+}
+
+impl<'a, P: IntegerParameter> Program<'a, P> {
+    fn new(num_bits: usize) -> Self {
+        Self {
+            num_bits,
+            instructions: Vec::new(),
+            instruction_ids: HashMap::new(),
+            inputs: Vec::new(),
+            input_ids: HashMap::new(),
+            node_ids: HashMap::new(),
+        }
+    }
+
+    fn lower(&mut self, expr: &FheExpr<'a, P>) -> ValueId {
+        let key = Rc::as_ptr(&expr.node);
+        if let Some(&id) = self.node_ids.get(&key) {
+            return id;
+        }
+
+        let id = match &*expr.node {
+            Node::Encrypted(value) => self.input(value),
+            Node::Clear(value) => self.clear(*value),
+            Node::Unary(op, operand) => {
+                let operand = self.lower(operand);
+                match *op {
+                    UnaryOp::Neg => self.neg(operand),
+                    UnaryOp::Shl(amount) => self.shl(operand, amount),
+                    UnaryOp::Shr(amount) => self.shr(operand, amount),
+                }
+            }
+            Node::Binary(op, lhs, rhs) => {
+                let lhs = self.lower(lhs);
+                let rhs = self.lower(rhs);
+                match op {
+                    BinaryOp::Add => self.add(lhs, rhs),
+                    BinaryOp::Sub => self.sub(lhs, rhs),
+                    BinaryOp::Mul => self.mul(lhs, rhs),
+                    BinaryOp::BitAnd => self.bitand(lhs, rhs),
+                    BinaryOp::BitOr => self.bitor(lhs, rhs),
+                    BinaryOp::BitXor => self.bitxor(lhs, rhs),
+                }
+            }
+        };
+
+        self.node_ids.insert(key, id);
+        id
+    }
+
+    fn push(&mut self, instruction: Instruction) -> ValueId {
+        if let Some(&id) = self.instruction_ids.get(&instruction) {
+            return id;
+        }
+        let id = self.instructions.len();
+        self.instructions.push(instruction);
+        self.instruction_ids.insert(instruction, id);
+        id
+    }
+
+    fn input(&mut self, value: &'a GenericInteger<P>) -> ValueId {
+        let key = value as *const GenericInteger<P>;
+        if let Some(&id) = self.input_ids.get(&key) {
+            return id;
+        }
+        let index = self.inputs.len();
+        self.inputs.push(value);
+        let id = self.push(Instruction::Input(index));
+        self.input_ids.insert(key, id);
+        id
+    }
+
+    fn clear(&mut self, value: u64) -> ValueId {
+        let value = self.reduce(value);
+        self.push(Instruction::Clear(value))
+    }
+
+    fn as_clear(&self, id: ValueId) -> Option<u64> {
+        match self.instructions[id] {
+            Instruction::Clear(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    // Clear values are reduced modulo 2^num_bits when the integer has at most 64 bits, for wider
+    // integers the constant folding only happens when the result fits in a u64.
+    fn wraps(&self) -> bool {
+        self.num_bits <= 64
+    }
+
+    fn reduce(&self, value: u64) -> u64 {
+        value & low_bits_mask(self.num_bits)
+    }
+
+    fn fold_add(&self, lhs: u64, rhs: u64) -> Option<u64> {
+        if self.wraps() {
+            Some(self.reduce(lhs.wrapping_add(rhs)))
+        } else {
+            lhs.checked_add(rhs)
+        }
+    }
+
+    fn fold_sub(&self, lhs: u64, rhs: u64) -> Option<u64> {
+        if self.wraps() {
+            Some(self.reduce(lhs.wrapping_sub(rhs)))
+        } else {
+            lhs.checked_sub(rhs)
+        }
+    }
+
+    fn fold_mul(&self, lhs: u64, rhs: u64) -> Option<u64> {
+        if self.wraps() {
+            Some(self.reduce(lhs.wrapping_mul(rhs)))
+        } else {
+            lhs.checked_mul(rhs)
+        }
+    }
+
+    fn add(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => {
+                if let Some(result) = self.fold_add(lhs, rhs) {
+                    return self.clear(result);
+                }
+            }
+            (Some(lhs), None) => return self.scalar_add(rhs, lhs),
+            (None, Some(rhs)) => return self.scalar_add(lhs, rhs),
+            (None, None) => {}
+        }
+        self.push(Instruction::Add(lhs.min(rhs), lhs.max(rhs)))
+    }
+
+    fn scalar_add(&mut self, lhs: ValueId, rhs: u64) -> ValueId {
+        let rhs = self.reduce(rhs);
+        if rhs == 0 {
+            return lhs;
+        }
+        if let Instruction::ScalarAdd(operand, value) = self.instructions[lhs] {
+            if let Some(sum) = self.fold_add(value, rhs) {
+                return self.scalar_add(operand, sum);
+            }
+        }
+        self.push(Instruction::ScalarAdd(lhs, rhs))
+    }
+
+    fn sub(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        if lhs == rhs {
+            return self.clear(0);
+        }
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => {
+                if let Some(result) = self.fold_sub(lhs, rhs) {
+                    return self.clear(result);
+                }
+            }
+            (Some(0), None) => return self.neg(rhs),
+            (None, Some(rhs)) => return self.scalar_sub(lhs, rhs),
+            _ => {}
+        }
+        self.push(Instruction::Sub(lhs, rhs))
+    }
+
+    fn scalar_sub(&mut self, lhs: ValueId, rhs: u64) -> ValueId {
+        if self.wraps() {
+            let opposite = self.reduce(rhs.wrapping_neg());
+            return self.scalar_add(lhs, opposite);
+        }
+        if rhs == 0 {
+            return lhs;
+        }
+        if let Instruction::ScalarSub(operand, value) = self.instructions[lhs] {
+            if let Some(sum) = value.checked_add(rhs) {
+                return self.scalar_sub(operand, sum);
+            }
+        }
+        self.push(Instruction::ScalarSub(lhs, rhs))
+    }
+
+    fn mul(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => {
+                if let Some(result) = self.fold_mul(lhs, rhs) {
+                    return self.clear(result);
+                }
+            }
+            (Some(lhs), None) => return self.scalar_mul(rhs, lhs),
+            (None, Some(rhs)) => return self.scalar_mul(lhs, rhs),
+            (None, None) => {}
+        }
+        self.push(Instruction::Mul(lhs.min(rhs), lhs.max(rhs)))
+    }
+
+    fn scalar_mul(&mut self, lhs: ValueId, rhs: u64) -> ValueId {
+        let rhs = self.reduce(rhs);
+        match rhs {
+            0 => return self.clear(0),
+            1 => return lhs,
+            _ => {}
+        }
+        if let Instruction::ScalarMul(operand, value) = self.instructions[lhs] {
+            if let Some(product) = self.fold_mul(value, rhs) {
+                return self.scalar_mul(operand, product);
+            }
+        }
+        self.push(Instruction::ScalarMul(lhs, rhs))
+    }
+
+    fn neg(&mut self, operand: ValueId) -> ValueId {
+        match (self.as_clear(operand), self.instructions[operand]) {
+            (Some(0), _) => return operand,
+            (Some(value), _) if self.wraps() => return self.clear(value.wrapping_neg()),
+            (_, Instruction::Neg(inner)) => return inner,
+            _ => {}
+        }
+        self.push(Instruction::Neg(operand))
+    }
+
+    fn shl(&mut self, operand: ValueId, amount: u64) -> ValueId {
+        if amount >= self.num_bits as u64 {
+            return self.clear(0);
+        }
+        if amount == 0 {
+            return operand;
+        }
+        if let Some(value) = self.as_clear(operand) {
+            if self.wraps() {
+                return self.clear(value << amount);
+            }
+            if amount < 64 && u64::from(value.leading_zeros()) >= amount {
+                return self.clear(value << amount);
+            }
+        }
+        if let Instruction::Shl(inner, inner_amount) = self.instructions[operand] {
+            return self.shl(inner, inner_amount + amount);
+        }
+        self.push(Instruction::Shl(operand, amount))
+    }
+
+    fn shr(&mut self, operand: ValueId, amount: u64) -> ValueId {
+        if amount >= self.num_bits as u64 {
+            return self.clear(0);
+        }
+        if amount == 0 {
+            return operand;
+        }
+        if let Some(value) = self.as_clear(operand) {
+            return self.clear(value.checked_shr(amount as u32).unwrap_or(0));
+        }
+        if let Instruction::Shr(inner, inner_amount) = self.instructions[operand] {
+            return self.shr(inner, inner_amount + amount);
+        }
+        self.push(Instruction::Shr(operand, amount))
+    }
+
+    fn bitand(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        if lhs == rhs {
+            return lhs;
+        }
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => self.clear(lhs & rhs),
+            (Some(value), None) | (None, Some(value)) => {
+                let operand = if self.as_clear(lhs).is_some() {
+                    rhs
+                } else {
+                    lhs
+                };
+                let and_mask = AndMask {
+                    low: value,
+                    high_bits: false,
+                };
+                self.bit_mask(operand, and_mask, 0)
+            }
+            (None, None) => self.push(Instruction::BitAnd(lhs.min(rhs), lhs.max(rhs))),
+        }
+    }
+
+    fn bitor(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        if lhs == rhs {
+            return lhs;
+        }
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => self.clear(lhs | rhs),
+            (Some(value), None) | (None, Some(value)) => {
+                let operand = if self.as_clear(lhs).is_some() {
+                    rhs
+                } else {
+                    lhs
+                };
+                // x | c == (x & !c) ^ c
+                let and_mask = AndMask {
+                    low: !value,
+                    high_bits: true,
+                };
+                self.bit_mask(operand, and_mask, value)
+            }
+            (None, None) => self.push(Instruction::BitOr(lhs.min(rhs), lhs.max(rhs))),
+        }
+    }
+
+    fn bitxor(&mut self, lhs: ValueId, rhs: ValueId) -> ValueId {
+        if lhs == rhs {
+            return self.clear(0);
+        }
+        match (self.as_clear(lhs), self.as_clear(rhs)) {
+            (Some(lhs), Some(rhs)) => self.clear(lhs ^ rhs),
+            (Some(value), None) | (None, Some(value)) => {
+                let operand = if self.as_clear(lhs).is_some() {
+                    rhs
+                } else {
+                    lhs
+                };
+                self.bit_mask(operand, AndMask::ALL, value)
+            }
+            (None, None) => self.push(Instruction::BitXor(lhs.min(rhs), lhs.max(rhs))),
+        }
+    }
+
+    fn bit_mask(&mut self, operand: ValueId, and_mask: AndMask, xor_mask: u64) -> ValueId {
+        // ((x & a1) ^ x1) & a2 ^ x2 == (x & (a1 & a2)) ^ ((x1 & a2) ^ x2)
+        if let Instruction::BitMask(inner, inner_and_mask, inner_xor_mask) =
+            self.instructions[operand]
+        {
+            let and_mask = AndMask {
+                low: inner_and_mask.low & and_mask.low,
+                high_bits: inner_and_mask.high_bits && and_mask.high_bits,
+            };
+            let xor_mask = (inner_xor_mask & and_mask.low) ^ xor_mask;
+            return self.bit_mask(inner, and_mask, xor_mask);
+        }
+
+        let and_mask = if self.wraps() {
+            AndMask {
+                low: self.reduce(and_mask.low),
+                high_bits: false,
+            }
+        } else {
+            and_mask
+        };
+        let xor_mask = self.reduce(xor_mask);
+
+        if let Some(value) = self.as_clear(operand) {
+            return self.clear((value & and_mask.low) ^ xor_mask);
+        }
+
+        let keeps_all =
+            and_mask.low == self.reduce(u64::MAX) && (self.wraps() || and_mask.high_bits);
+        let keeps_none = and_mask.low == 0 && !and_mask.high_bits;
+        if keeps_none {
+            return self.clear(xor_mask);
+        }
+        if keeps_all && xor_mask == 0 {
+            return operand;
+        }
+        self.push(Instruction::BitMask(operand, and_mask, xor_mask))
+    }
+
+    /// Returns which instructions are needed to compute the result of `root`.
+    fn live_instructions(&self, root: ValueId) -> Vec<bool> {
+        let mut is_live = vec![false; self.instructions.len()];
+        is_live[root] = true;
+        for id in (0..=root).rev() {
+            if is_live[id] {
+                for operand in self.instructions[id].operands().into_iter().flatten() {
+                    is_live[operand] = true;
+                }
+            }
+        }
+        is_live
+    }
+}
+
+impl<'a, P> Program<'a, P>
+where
+    P: IntegerParameter<
+        InnerCiphertext = RadixCiphertextDyn,
+        InnerServerKey = crate::integer::ServerKey,
+    >,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>> + Default,
+    GenericInteger<P>: Clone,
+{
+    fn run(&self, root: ValueId) -> GenericInteger<P> {
+        let is_live = self.live_instructions(root);
+        let mut values: Vec<Option<Cow<'a, GenericInteger<P>>>> =
+            (0..=root).map(|_| None).collect();
+
+        for id in 0..=root {
+            if !is_live[id] {
+                continue;
+            }
+
+            let value = {
+                let get = |id: ValueId| values[id].as_deref().unwrap();
+                match self.instructions[id] {
+                    Instruction::Input(index) => Cow::Borrowed(self.inputs[index]),
+                    Instruction::Clear(value) => {
+                        Cow::Owned(GenericInteger::<P>::encrypt_trivial(value))
+                    }
+                    Instruction::Add(lhs, rhs) => Cow::Owned(get(lhs) + get(rhs)),
+                    Instruction::Sub(lhs, rhs) => Cow::Owned(get(lhs) - get(rhs)),
+                    Instruction::Mul(lhs, rhs) => Cow::Owned(get(lhs) * get(rhs)),
+                    Instruction::BitAnd(lhs, rhs) => Cow::Owned(get(lhs) & get(rhs)),
+                    Instruction::BitOr(lhs, rhs) => Cow::Owned(get(lhs) | get(rhs)),
+                    Instruction::BitXor(lhs, rhs) => Cow::Owned(get(lhs) ^ get(rhs)),
+                    Instruction::Neg(operand) => Cow::Owned(-get(operand)),
+                    Instruction::ScalarAdd(lhs, rhs) => Cow::Owned(get(lhs) + rhs),
+                    Instruction::ScalarSub(lhs, rhs) => Cow::Owned(get(lhs) - rhs),
+                    Instruction::ScalarMul(lhs, rhs) => Cow::Owned(get(lhs) * rhs),
+                    Instruction::Shl(operand, amount) => Cow::Owned(get(operand) << amount),
+                    Instruction::Shr(operand, amount) => Cow::Owned(get(operand) >> amount),
+                    Instruction::BitMask(operand, and_mask, xor_mask) => {
+                        Cow::Owned(apply_bit_mask(get(operand), and_mask, xor_mask))
+                    }
+                }
+            };
+            values[id] = Some(value);
+        }
+
+        values[root].take().unwrap().into_owned()
+    }
+}
+
+/// Computes `(value & and_mask) ^ xor_mask` with a single lookup table per block.
+fn apply_bit_mask<P>(
+    value: &GenericInteger<P>,
+    and_mask: AndMask,
+    xor_mask: u64,
+) -> GenericInteger<P>
+where
+    P: IntegerParameter<
+        InnerCiphertext = RadixCiphertextDyn,
+        InnerServerKey = crate::integer::ServerKey,
+    >,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+{
+    let ciphertext = value.id.with_unwrapped_global(|key| {
+        // The message modulus is a power of two
+        let bits_per_block = key.inner.key.message_modulus.0.trailing_zeros() as usize;
+        let block_function = |index: usize, block: u64| {
+            let shift = index * bits_per_block;
+            let and_bits = and_mask.bits(shift, bits_per_block);
+            let xor_bits = xor_mask.checked_shr(shift as u32).unwrap_or(0);
+            (block & and_bits) ^ xor_bits
+        };
+
+        match &*value.ciphertext.borrow() {
+            RadixCiphertextDyn::Big(ct) => {
+                RadixCiphertextDyn::Big(key.inner.block_map_parallelized(ct, block_function))
+            }
+            RadixCiphertextDyn::Small(ct) => {
+                RadixCiphertextDyn::Small(key.inner.block_map_parallelized(ct, block_function))
+            }
+        }
+    });
+
+    GenericInteger::<P>::new(ciphertext, value.id)
+}
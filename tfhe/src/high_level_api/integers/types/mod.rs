@@ -1,11 +1,25 @@
 pub use base::GenericInteger;
+pub use compact::GenericCompactIntegerList;
+pub use expr::FheExpr;
+pub use overflow::{Checked, Saturating};
 pub use static_::{
-    CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
-    CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
-    CompressedFheUint8, FheUint10, FheUint12, FheUint128, FheUint14, FheUint16, FheUint256,
-    FheUint32, FheUint64, FheUint8,
+    CompactFheUint10List, CompactFheUint128List, CompactFheUint12List, CompactFheUint14List,
+    CompactFheUint16List, CompactFheUint256List, CompactFheUint32List, CompactFheUint64List,
+    CompactFheUint8List, CompressedFheUint10, CompressedFheUint12, CompressedFheUint128,
+    CompressedFheUint14, CompressedFheUint16, CompressedFheUint256, CompressedFheUint32,
+    CompressedFheUint64, CompressedFheUint8, FheUint10, FheUint10Typed, FheUint12, FheUint128,
+    FheUint128Typed, FheUint12Typed, FheUint14, FheUint14Typed, FheUint16, FheUint16Typed,
+    FheUint256, FheUint256Typed, FheUint32, FheUint32Typed, FheUint64, FheUint64Typed, FheUint8,
+    FheUint8Typed,
 };
+pub use typed::GenericTypedInteger;
+pub use vec::EncryptedVec;
 
 pub(super) mod base;
+pub(super) mod compact;
 pub(super) mod compressed;
+pub(super) mod expr;
+pub(super) mod overflow;
 pub(super) mod static_;
+pub(super) mod typed;
+pub(super) mod vec;
@@ -0,0 +1,422 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::base::GenericInteger;
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::server_key::{
+    GenericIntegerServerKey, SmartBitOr, SmartOverflowingAdd, SmartOverflowingSub,
+    SmartSaturatingAdd, SmartSaturatingSub,
+};
+use crate::high_level_api::traits::FheDecrypt;
+use crate::high_level_api::ClientKey;
+
+/// Selects saturating semantics for the `+` and `-` operators of an integer.
+///
+/// The operators of [GenericInteger] wrap around on overflow, like [std::num::Wrapping]
+/// does for clear integers. Wrapping a value in `Saturating` makes the operators
+/// clamp the result to the bounds of the type instead, like [std::num::Saturating].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), tfhe::Error> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Saturating};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (keys, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let mut counter = Saturating(FheUint8::try_encrypt(250u8, &keys)?);
+/// let increment = Saturating(FheUint8::try_encrypt(3u8, &keys)?);
+///
+/// counter += &increment;
+/// counter += &increment;
+/// counter = counter - 5u8;
+///
+/// let decrypted: u8 = counter.0.decrypt(&keys);
+/// assert_eq!(decrypted, 250);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl<P, ClearType> FheDecrypt<ClearType> for Saturating<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    GenericInteger<P>: FheDecrypt<ClearType>,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        self.0.decrypt(key)
+    }
+}
+
+macro_rules! saturating_impl_operation (
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
+        impl<P, B> $trait_name<B> for &Saturating<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Saturating<GenericInteger<P>>>,
+            GenericInteger<P>: Clone,
+            P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+            P::InnerServerKey: for<'a> $smart_trait<
+                &'a mut P::InnerCiphertext,
+                &'a mut P::InnerCiphertext,
+                Output = P::InnerCiphertext,
+            >,
+        {
+            type Output = Saturating<GenericInteger<P>>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                let lhs = &self.0;
+                let rhs = &rhs.borrow().0;
+                let ciphertext = lhs.id.with_unwrapped_global(|key| {
+                    if std::ptr::eq(lhs, rhs) {
+                        let cloned = (*rhs).clone();
+                        let r = key.inner.$key_method(
+                            &mut lhs.ciphertext.borrow_mut(),
+                            &mut cloned.ciphertext.borrow_mut(),
+                        );
+                        r
+                    } else {
+                        key.inner.$key_method(
+                            &mut lhs.ciphertext.borrow_mut(),
+                            &mut rhs.ciphertext.borrow_mut(),
+                        )
+                    }
+                });
+
+                Saturating(GenericInteger::new(ciphertext, lhs.id))
+            }
+        }
+
+        impl<P, B> $trait_name<B> for Saturating<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+            for<'a> &'a Self: $trait_name<B, Output = Self>,
+        {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                $trait_name::$trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $assign_trait<B> for Saturating<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+            for<'a> &'a Self: $trait_name<B, Output = Self>,
+        {
+            fn $assign_method(&mut self, rhs: B) {
+                *self = $trait_name::$trait_method(&*self, rhs);
+            }
+        }
+    }
+);
+
+macro_rules! saturating_impl_scalar_operation (
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*), $assign_trait:ident($assign_method:ident)) => {
+        $(
+            impl<P> $trait_name<$scalar_type> for &Saturating<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+                P::InnerServerKey: for<'a> $smart_trait<
+                    &'a mut P::InnerCiphertext,
+                    u64,
+                    Output = P::InnerCiphertext,
+                >,
+            {
+                type Output = Saturating<GenericInteger<P>>;
+
+                fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    let lhs = &self.0;
+                    let ciphertext = lhs.id.with_unwrapped_global(|key| {
+                        key.inner.$key_method(&mut lhs.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    Saturating(GenericInteger::new(ciphertext, lhs.id))
+                }
+            }
+
+            impl<P> $trait_name<$scalar_type> for Saturating<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                for<'a> &'a Self: $trait_name<$scalar_type, Output = Self>,
+            {
+                type Output = Self;
+
+                fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    $trait_name::$trait_method(&self, rhs)
+                }
+            }
+
+            impl<P> $assign_trait<$scalar_type> for Saturating<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                for<'a> &'a Self: $trait_name<$scalar_type, Output = Self>,
+            {
+                fn $assign_method(&mut self, rhs: $scalar_type) {
+                    *self = $trait_name::$trait_method(&*self, rhs);
+                }
+            }
+        )*
+    }
+);
+
+saturating_impl_operation!(Add(add, SmartSaturatingAdd) => smart_saturating_add, AddAssign(add_assign));
+saturating_impl_operation!(Sub(sub, SmartSaturatingSub) => smart_saturating_sub, SubAssign(sub_assign));
+
+saturating_impl_scalar_operation!(Add(add, SmartSaturatingAdd) => smart_saturating_add(u8, u16, u32, u64), AddAssign(add_assign));
+saturating_impl_scalar_operation!(Sub(sub, SmartSaturatingSub) => smart_saturating_sub(u8, u16, u32, u64), SubAssign(sub_assign));
+
+/// Selects checked semantics for the `+` and `-` operators of an integer.
+///
+/// As the values are encrypted, an overflow cannot be reported when it happens.
+/// Instead, the operators wrap around on overflow and a `Checked` value carries an
+/// encrypted flag, which records whether any of the operations that led to it overflowed.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), tfhe::Error> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, Checked, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (keys, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = Checked::new(FheUint8::try_encrypt(200u8, &keys)?);
+/// let b = Checked::new(FheUint8::try_encrypt(100u8, &keys)?);
+/// let c = Checked::new(FheUint8::try_encrypt(50u8, &keys)?);
+///
+/// let result = (&a + &b) - &c;
+///
+/// let (value, overflowed) = result.into_parts();
+/// let value: u8 = value.decrypt(&keys);
+/// let overflowed: u8 = overflowed.unwrap().decrypt(&keys);
+/// assert_eq!(value, 200u8.wrapping_add(100).wrapping_sub(50));
+/// assert_eq!(overflowed, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Checked<T> {
+    value: T,
+    // None when the value is not the result of a checked operation,
+    // which saves the encryption of a flag known to be 0
+    overflowed: Option<T>,
+}
+
+impl<T> Checked<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            overflowed: None,
+        }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns an encryption of 1 if one of the operations that led to this value overflowed,
+    /// 0 otherwise.
+    ///
+    /// Returns `None` if the value was not computed with checked operations.
+    pub fn overflowed(&self) -> Option<&T> {
+        self.overflowed.as_ref()
+    }
+
+    /// Returns the value and its overflow flag, see [Self::overflowed].
+    pub fn into_parts(self) -> (T, Option<T>) {
+        (self.value, self.overflowed)
+    }
+}
+
+impl<P> Checked<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerServerKey: for<'a> SmartBitOr<
+        &'a mut P::InnerCiphertext,
+        &'a mut P::InnerCiphertext,
+        Output = P::InnerCiphertext,
+    >,
+{
+    fn merge_flags(
+        id: P::Id,
+        flags: impl IntoIterator<Item = Option<GenericInteger<P>>>,
+    ) -> Option<GenericInteger<P>> {
+        flags.into_iter().flatten().reduce(|lhs, rhs| {
+            let ciphertext = id.with_unwrapped_global(|key| {
+                key.inner.smart_bitor(
+                    &mut lhs.ciphertext.borrow_mut(),
+                    &mut rhs.ciphertext.borrow_mut(),
+                )
+            });
+            GenericInteger::new(ciphertext, id)
+        })
+    }
+}
+
+macro_rules! checked_impl_operation (
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
+        impl<P, B> $trait_name<B> for &Checked<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Checked<GenericInteger<P>>>,
+            GenericInteger<P>: Clone,
+            P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+            P::InnerServerKey: for<'a> $smart_trait<
+                    &'a mut P::InnerCiphertext,
+                    &'a mut P::InnerCiphertext,
+                    Output = (P::InnerCiphertext, P::InnerCiphertext),
+                > + for<'a> SmartBitOr<
+                    &'a mut P::InnerCiphertext,
+                    &'a mut P::InnerCiphertext,
+                    Output = P::InnerCiphertext,
+                >,
+        {
+            type Output = Checked<GenericInteger<P>>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                let rhs = rhs.borrow();
+                let lhs_value = &self.value;
+                let rhs_value = &rhs.value;
+                let id = lhs_value.id;
+
+                let (result, overflowed) = id.with_unwrapped_global(|key| {
+                    if std::ptr::eq(lhs_value, rhs_value) {
+                        let cloned = (*rhs_value).clone();
+                        let r = key.inner.$key_method(
+                            &mut lhs_value.ciphertext.borrow_mut(),
+                            &mut cloned.ciphertext.borrow_mut(),
+                        );
+                        r
+                    } else {
+                        key.inner.$key_method(
+                            &mut lhs_value.ciphertext.borrow_mut(),
+                            &mut rhs_value.ciphertext.borrow_mut(),
+                        )
+                    }
+                });
+
+                let overflowed = Checked::<GenericInteger<P>>::merge_flags(
+                    id,
+                    [
+                        self.overflowed.clone(),
+                        rhs.overflowed.clone(),
+                        Some(GenericInteger::new(overflowed, id)),
+                    ],
+                );
+
+                Checked {
+                    value: GenericInteger::new(result, id),
+                    overflowed,
+                }
+            }
+        }
+
+        impl<P, B> $trait_name<B> for Checked<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+            for<'a> &'a Self: $trait_name<B, Output = Self>,
+        {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                $trait_name::$trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $assign_trait<B> for Checked<GenericInteger<P>>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+            for<'a> &'a Self: $trait_name<B, Output = Self>,
+        {
+            fn $assign_method(&mut self, rhs: B) {
+                *self = $trait_name::$trait_method(&*self, rhs);
+            }
+        }
+    }
+);
+
+macro_rules! checked_impl_scalar_operation (
+    ($trait_name:ident($trait_method:ident, $smart_trait:ident) => $key_method:ident($($scalar_type:ty),*), $assign_trait:ident($assign_method:ident)) => {
+        $(
+            impl<P> $trait_name<$scalar_type> for &Checked<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                GenericInteger<P>: Clone,
+                P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+                P::InnerServerKey: for<'a> $smart_trait<
+                        &'a mut P::InnerCiphertext,
+                        u64,
+                        Output = (P::InnerCiphertext, P::InnerCiphertext),
+                    > + for<'a> SmartBitOr<
+                        &'a mut P::InnerCiphertext,
+                        &'a mut P::InnerCiphertext,
+                        Output = P::InnerCiphertext,
+                    >,
+            {
+                type Output = Checked<GenericInteger<P>>;
+
+                fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    let id = self.value.id;
+                    let (result, overflowed) = id.with_unwrapped_global(|key| {
+                        key.inner.$key_method(&mut self.value.ciphertext.borrow_mut(), u64::from(rhs))
+                    });
+
+                    let overflowed = Checked::<GenericInteger<P>>::merge_flags(
+                        id,
+                        [self.overflowed.clone(), Some(GenericInteger::new(overflowed, id))],
+                    );
+
+                    Checked {
+                        value: GenericInteger::new(result, id),
+                        overflowed,
+                    }
+                }
+            }
+
+            impl<P> $trait_name<$scalar_type> for Checked<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                for<'a> &'a Self: $trait_name<$scalar_type, Output = Self>,
+            {
+                type Output = Self;
+
+                fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    $trait_name::$trait_method(&self, rhs)
+                }
+            }
+
+            impl<P> $assign_trait<$scalar_type> for Checked<GenericInteger<P>>
+            where
+                P: IntegerParameter,
+                for<'a> &'a Self: $trait_name<$scalar_type, Output = Self>,
+            {
+                fn $assign_method(&mut self, rhs: $scalar_type) {
+                    *self = $trait_name::$trait_method(&*self, rhs);
+                }
+            }
+        )*
+    }
+);
+
+checked_impl_operation!(Add(add, SmartOverflowingAdd) => smart_overflowing_add, AddAssign(add_assign));
+checked_impl_operation!(Sub(sub, SmartOverflowingSub) => smart_overflowing_sub, SubAssign(sub_assign));
+
+checked_impl_scalar_operation!(Add(add, SmartOverflowingAdd) => smart_overflowing_add(u8, u16, u32, u64), AddAssign(add_assign));
+checked_impl_scalar_operation!(Sub(sub, SmartOverflowingSub) => smart_overflowing_sub(u8, u16, u32, u64), SubAssign(sub_assign));
@@ -5,6 +5,7 @@ use crate::high_level_api::integers::parameters::{
     EvaluationIntegerKey, IntegerParameter, RadixParameters, RadixRepresentation,
     StaticIntegerParameter, StaticRadixParameter,
 };
+use crate::high_level_api::integers::public_key::compact::GenericIntegerCompactPublicKey;
 use crate::high_level_api::integers::public_key::compressed::GenericIntegerCompressedPublicKey;
 use crate::high_level_api::integers::public_key::GenericIntegerPublicKey;
 use crate::high_level_api::integers::server_key::GenericIntegerServerKey;
@@ -14,6 +15,8 @@ use crate::high_level_api::traits::{FheDecrypt, FheEncrypt};
 use crate::high_level_api::ClientKey;
 
 use super::base::GenericInteger;
+use super::compact::GenericCompactIntegerList;
+use super::typed::GenericTypedInteger;
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey, ParameterType};
 #[cfg(feature = "internal-keycache")]
 use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
@@ -188,6 +191,9 @@ macro_rules! static_int_type {
             #[doc = concat!("CompressedPublicKey for the [", stringify!($name), "] data type.")]
             pub(in crate::high_level_api::integers) type [<$name CompressedPublicKey>] = GenericIntegerCompressedPublicKey<[<$name Parameters>]>;
 
+            #[doc = concat!("CompactPublicKey for the [", stringify!($name), "] data type.")]
+            pub(in crate::high_level_api::integers) type [<$name CompactPublicKey>] = GenericIntegerCompactPublicKey<[<$name Parameters>]>;
+
             #[doc = concat!("ServerKey for the [", stringify!($name), "] data type.")]
             pub(in crate::high_level_api::integers) type [<$name ServerKey>] = GenericIntegerServerKey<[<$name Parameters>]>;
 
@@ -199,6 +205,14 @@ macro_rules! static_int_type {
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type [<Compressed $name>] = CompressedGenericInteger<[<$name Parameters>]>;
 
+            #[doc = concat!("A compact list of [", stringify!($name), "] encrypted with a [CompactPublicKey](crate::high_level_api::CompactPublicKey)")]
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<Compact $name List>] = GenericCompactIntegerList<[<$name Parameters>]>;
+
+            #[doc = concat!("A [", stringify!($name), "] whose block parameters are checked at compile time, see [GenericTypedInteger]")]
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<$name Typed>]<const MSG_BITS: usize, const CARRY_BITS: usize> = GenericTypedInteger<[<$name Parameters>], MSG_BITS, CARRY_BITS>;
+
             impl_ref_key_from_keychain!(
                 for <[<$name Parameters>] as ParameterType>::Id {
                     key_type: [<$name ClientKey>],
@@ -223,6 +237,14 @@ macro_rules! static_int_type {
                 }
             );
 
+            impl_ref_key_from_compact_public_keychain!(
+                for <[<$name Parameters>] as ParameterType>::Id {
+                    key_type: [<$name CompactPublicKey>],
+                    keychain_member: $($member).*,
+                    type_variant: crate::high_level_api::errors::Type::$name,
+                }
+            );
+
             impl_with_global_key!(
                 for <[<$name Parameters>] as ParameterType>::Id {
                     key_type: [<$name ServerKey>],
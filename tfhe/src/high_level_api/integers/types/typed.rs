@@ -0,0 +1,307 @@
+use std::borrow::Borrow;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
+    Sub, SubAssign,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::base::GenericInteger;
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::server_key::RadixCiphertextDyn;
+use crate::high_level_api::traits::{FheDecrypt, FheEq, FheOrd, FheTryEncrypt};
+use crate::high_level_api::ClientKey;
+
+/// A [GenericInteger] whose block parameters are part of its type.
+///
+/// The `MSG_BITS` and `CARRY_BITS` const parameters are the number of bits of message and
+/// carry of each block of the ciphertext. They are checked once, when the value is created
+/// (by encryption, conversion or deserialization), so that the operators, which only
+/// accept operands of the exact same type, can never mix ciphertexts encrypted under
+/// different parameter families.
+///
+/// Only the block parameters of the ciphertexts are encoded in the type, the server key used
+/// for the computations is still the global one set with
+/// [set_server_key](crate::high_level_api::set_server_key), and it is the user's
+/// responsibility to set a key matching `MSG_BITS` and `CARRY_BITS`.
+///
+/// You will need to use one of this type specialization (e.g., [FheUint8Typed]).
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), tfhe::Error> {
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8Typed};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (keys, server_key) = generate_keys(config);
+///
+/// // The default parameters use blocks with 2 bits of message and 2 bits of carry
+/// let a = FheUint8Typed::<2, 2>::try_encrypt(27u8, &keys)?;
+/// let b = FheUint8Typed::<2, 2>::try_encrypt(100u8, &keys)?;
+///
+/// set_server_key(server_key);
+///
+/// let c = a + &b;
+/// let decrypted: u8 = c.decrypt(&keys);
+/// assert_eq!(decrypted, 127);
+///
+/// // Encrypting with a key that does not match the type parameters fails
+/// assert!(FheUint8Typed::<3, 3>::try_encrypt(27u8, &keys).is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Mixing values of different parameter families does not compile:
+///
+/// ```compile_fail
+/// use tfhe::FheUint8Typed;
+///
+/// fn mix(a: FheUint8Typed<2, 2>, b: FheUint8Typed<3, 3>) -> FheUint8Typed<2, 2> {
+///     a + b
+/// }
+/// ```
+///
+/// [FheUint8Typed]: crate::high_level_api::FheUint8Typed
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone)]
+pub struct GenericTypedInteger<P, const MSG_BITS: usize, const CARRY_BITS: usize>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    inner: GenericInteger<P>,
+}
+
+impl<P, const MSG_BITS: usize, const CARRY_BITS: usize> GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    /// Returns the untyped integer.
+    pub fn into_inner(self) -> GenericInteger<P> {
+        self.inner
+    }
+
+    fn check_block_parameters(inner: &GenericInteger<P>) -> Result<(), Error> {
+        let expected = (1usize << MSG_BITS, 1usize << CARRY_BITS);
+
+        let ciphertext = inner.ciphertext.borrow();
+        let mismatch = match &*ciphertext {
+            RadixCiphertextDyn::Big(ct) => ct
+                .blocks
+                .iter()
+                .map(|block| (block.message_modulus.0, block.carry_modulus.0))
+                .find(|moduli| *moduli != expected),
+            RadixCiphertextDyn::Small(ct) => ct
+                .blocks
+                .iter()
+                .map(|block| (block.message_modulus.0, block.carry_modulus.0))
+                .find(|moduli| *moduli != expected),
+        };
+
+        match mismatch {
+            Some(found) => Err(Error::MismatchedBlockParameters { expected, found }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<P, const MSG_BITS: usize, const CARRY_BITS: usize> TryFrom<GenericInteger<P>>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    type Error = Error;
+
+    fn try_from(inner: GenericInteger<P>) -> Result<Self, Self::Error> {
+        Self::check_block_parameters(&inner)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<P, const MSG_BITS: usize, const CARRY_BITS: usize>
+    From<GenericTypedInteger<P, MSG_BITS, CARRY_BITS>> for GenericInteger<P>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    fn from(value: GenericTypedInteger<P, MSG_BITS, CARRY_BITS>) -> Self {
+        value.inner
+    }
+}
+
+impl<P, const MSG_BITS: usize, const CARRY_BITS: usize> AsRef<GenericInteger<P>>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+{
+    fn as_ref(&self) -> &GenericInteger<P> {
+        &self.inner
+    }
+}
+
+impl<P, const MSG_BITS: usize, const CARRY_BITS: usize> Serialize
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    GenericInteger<P>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+// The block parameters of deserialized values are checked,
+// as they may come from a differently parametrized sender
+impl<'de, P, const MSG_BITS: usize, const CARRY_BITS: usize> Deserialize<'de>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    GenericInteger<P>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = GenericInteger::<P>::deserialize(deserializer)?;
+        Self::try_from(inner).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<P, T, Key, const MSG_BITS: usize, const CARRY_BITS: usize> FheTryEncrypt<T, Key>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    GenericInteger<P>: FheTryEncrypt<T, Key, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_encrypt(value: T, key: &Key) -> Result<Self, Self::Error> {
+        let inner = GenericInteger::<P>::try_encrypt(value, key)?;
+        Self::try_from(inner)
+    }
+}
+
+impl<P, ClearType, const MSG_BITS: usize, const CARRY_BITS: usize> FheDecrypt<ClearType>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    GenericInteger<P>: FheDecrypt<ClearType>,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        self.inner.decrypt(key)
+    }
+}
+
+impl<P, B, const MSG_BITS: usize, const CARRY_BITS: usize> FheEq<B>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    B: Borrow<Self>,
+    GenericInteger<P>: for<'a> FheEq<&'a GenericInteger<P>, Output = GenericInteger<P>>,
+{
+    type Output = Self;
+
+    fn eq(&self, rhs: B) -> Self::Output {
+        Self {
+            inner: self.inner.eq(&rhs.borrow().inner),
+        }
+    }
+}
+
+impl<P, B, const MSG_BITS: usize, const CARRY_BITS: usize> FheOrd<B>
+    for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+    B: Borrow<Self>,
+    GenericInteger<P>: for<'a> FheOrd<&'a GenericInteger<P>, Output = GenericInteger<P>>,
+{
+    type Output = Self;
+
+    fn lt(&self, rhs: B) -> Self::Output {
+        Self {
+            inner: self.inner.lt(&rhs.borrow().inner),
+        }
+    }
+
+    fn le(&self, rhs: B) -> Self::Output {
+        Self {
+            inner: self.inner.le(&rhs.borrow().inner),
+        }
+    }
+
+    fn gt(&self, rhs: B) -> Self::Output {
+        Self {
+            inner: self.inner.gt(&rhs.borrow().inner),
+        }
+    }
+
+    fn ge(&self, rhs: B) -> Self::Output {
+        Self {
+            inner: self.inner.ge(&rhs.borrow().inner),
+        }
+    }
+}
+
+// The operators only accept operands with the same const parameters,
+// this is what turns mixing parameter families into a compile error.
+//
+// The result of an operation between blocks of the same parameters
+// has the same parameters, so no check is needed on the outputs.
+macro_rules! generic_typed_integer_impl_operation (
+    ($trait_name:ident($trait_method:ident), $assign_trait:ident($assign_method:ident)) => {
+        impl<P, B, const MSG_BITS: usize, const CARRY_BITS: usize> $trait_name<B>
+            for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+        where
+            P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+            B: Borrow<Self>,
+            GenericInteger<P>: for<'a> $assign_trait<&'a GenericInteger<P>>,
+        {
+            type Output = Self;
+
+            fn $trait_method(mut self, rhs: B) -> Self::Output {
+                <Self as $assign_trait<B>>::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<P, B, const MSG_BITS: usize, const CARRY_BITS: usize> $trait_name<B>
+            for &GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+        where
+            P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+            B: Borrow<GenericTypedInteger<P, MSG_BITS, CARRY_BITS>>,
+            for<'a> &'a GenericInteger<P>:
+                $trait_name<&'a GenericInteger<P>, Output = GenericInteger<P>>,
+        {
+            type Output = GenericTypedInteger<P, MSG_BITS, CARRY_BITS>;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                GenericTypedInteger {
+                    inner: $trait_name::$trait_method(&self.inner, &rhs.borrow().inner),
+                }
+            }
+        }
+
+        impl<P, I, const MSG_BITS: usize, const CARRY_BITS: usize> $assign_trait<I>
+            for GenericTypedInteger<P, MSG_BITS, CARRY_BITS>
+        where
+            P: IntegerParameter<InnerCiphertext = RadixCiphertextDyn>,
+            I: Borrow<Self>,
+            GenericInteger<P>: for<'a> $assign_trait<&'a GenericInteger<P>>,
+        {
+            fn $assign_method(&mut self, rhs: I) {
+                $assign_trait::$assign_method(&mut self.inner, &rhs.borrow().inner);
+            }
+        }
+    }
+);
+
+generic_typed_integer_impl_operation!(Add(add), AddAssign(add_assign));
+generic_typed_integer_impl_operation!(Sub(sub), SubAssign(sub_assign));
+generic_typed_integer_impl_operation!(Mul(mul), MulAssign(mul_assign));
+generic_typed_integer_impl_operation!(BitAnd(bitand), BitAndAssign(bitand_assign));
+generic_typed_integer_impl_operation!(BitOr(bitor), BitOrAssign(bitor_assign));
+generic_typed_integer_impl_operation!(BitXor(bitxor), BitXorAssign(bitxor_assign));
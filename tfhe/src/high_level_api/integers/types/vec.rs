@@ -0,0 +1,485 @@
+use std::ops::Index;
+
+use rayon::prelude::*;
+
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::server_key::{
+    GenericIntegerServerKey, SmartAdd, SmartMax, SmartMin, SmartMul, SmartSub, WopbsEvaluationKey,
+};
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::integer::wopbs::WopbsKey;
+
+/// A vector of encrypted integers.
+///
+/// It provides adapters similar to the ones of [Iterator] (`map`, `filter`, `zip`, `sum`, `min`,
+/// `max`). Each adapter sends all the elements to the server key at once, the elements being
+/// processed in parallel, so code working on encrypted collections does not have to loop over the
+/// elements and call the operations one by one.
+///
+/// As encrypted values cannot be inspected, [EncryptedVec::filter] cannot remove elements, it
+/// returns an encrypted selection mask instead.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, EncryptedVec, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+/// let (keys, server_key) = generate_keys(config);
+///
+/// let prices = [12u8, 30, 7, 25];
+/// let quantities = [2u8, 1, 3, 2];
+/// let prices: EncryptedVec<FheUint8> =
+///     prices.iter().map(|p| FheUint8::encrypt(*p, &keys)).collect();
+/// let quantities: EncryptedVec<FheUint8> =
+///     quantities.iter().map(|q| FheUint8::encrypt(*q, &keys)).collect();
+///
+/// set_server_key(server_key);
+///
+/// let total = prices.zip_mul(&quantities).sum().unwrap();
+/// let decrypted: u8 = total.decrypt(&keys);
+/// assert_eq!(decrypted, 12 * 2 + 30 + 7 * 3 + 25 * 2);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct EncryptedVec<T> {
+    values: Vec<T>,
+}
+
+impl<T> EncryptedVec<T> {
+    pub fn new(values: Vec<T>) -> Self {
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<T> From<Vec<T>> for EncryptedVec<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::new(values)
+    }
+}
+
+impl<T> FromIterator<T> for EncryptedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for EncryptedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a EncryptedVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<T> Index<usize> for EncryptedVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync,
+{
+    // The ciphertexts are taken out of their `RefCell` so they can be sent to other threads
+    fn inner_ciphertexts(&self) -> Vec<P::InnerCiphertext> {
+        self.values
+            .iter()
+            .map(|value| value.ciphertext.borrow().clone())
+            .collect()
+    }
+
+    fn from_inner(ciphertexts: Vec<P::InnerCiphertext>, id: P::Id) -> Self {
+        ciphertexts
+            .into_iter()
+            .map(|ciphertext| GenericInteger::new(ciphertext, id))
+            .collect()
+    }
+
+    fn zip_map_inner<F>(&self, other: &Self, op: F) -> Self
+    where
+        F: Fn(
+                &P::InnerServerKey,
+                &mut P::InnerCiphertext,
+                &mut P::InnerCiphertext,
+            ) -> P::InnerCiphertext
+            + Sync,
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Cannot zip vectors of different lengths ({} and {})",
+            self.len(),
+            other.len()
+        );
+
+        let Some(first) = self.values.first() else {
+            return Self::new(vec![]);
+        };
+
+        let lhs = self.inner_ciphertexts();
+        let rhs = other.inner_ciphertexts();
+        let results = first.id.with_unwrapped_global(|key| {
+            let inner_key = &key.inner;
+            lhs.into_par_iter()
+                .zip(rhs.into_par_iter())
+                .map(|(mut lhs, mut rhs)| op(inner_key, &mut lhs, &mut rhs))
+                .collect::<Vec<_>>()
+        });
+
+        Self::from_inner(results, first.id)
+    }
+
+    // Combines the elements with a balanced tree of `op`, the operations of a level of the tree
+    // being computed in parallel
+    fn reduce_inner<F>(&self, op: F) -> Option<GenericInteger<P>>
+    where
+        F: Fn(
+                &P::InnerServerKey,
+                &mut P::InnerCiphertext,
+                &mut P::InnerCiphertext,
+            ) -> P::InnerCiphertext
+            + Sync,
+    {
+        let first = self.values.first()?;
+
+        let mut level = self.inner_ciphertexts();
+        let result = first.id.with_unwrapped_global(|key| {
+            let inner_key = &key.inner;
+            while level.len() > 1 {
+                level = level
+                    .par_chunks(2)
+                    .map(|pair| match pair {
+                        [lhs, rhs] => op(inner_key, &mut lhs.clone(), &mut rhs.clone()),
+                        [single] => single.clone(),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+            }
+            level.pop().unwrap()
+        });
+
+        Some(GenericInteger::new(result, first.id))
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync,
+    WopbsKey: WopbsEvaluationKey<P::InnerServerKey, P::InnerCiphertext>,
+{
+    /// Applies a function to each element.
+    ///
+    /// The function is evaluated as a lookup table, like [FheBootstrap::map] does, on all the
+    /// elements in parallel.
+    ///
+    /// [FheBootstrap::map]: crate::prelude::FheBootstrap::map
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, EncryptedVec, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    ///
+    /// let values: EncryptedVec<FheUint8> =
+    ///     [3u8, 10, 14].iter().map(|v| FheUint8::encrypt(*v, &keys)).collect();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let squares = values.map(|x| x * x);
+    /// let decrypted: Vec<u8> = squares.iter().map(|v| v.decrypt(&keys)).collect();
+    /// assert_eq!(decrypted, vec![9, 100, 196]);
+    /// ```
+    pub fn map<F>(&self, func: F) -> Self
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        let Some(first) = self.values.first() else {
+            return Self::new(vec![]);
+        };
+
+        let ciphertexts = self.inner_ciphertexts();
+        let results = first.id.with_unwrapped_global(|key| {
+            let inner_key = &key.inner;
+            let wopbs_key = &key.wopbs_key;
+            ciphertexts
+                .par_iter()
+                .map(|ct| wopbs_key.apply_wopbs(inner_key, ct, &func))
+                .collect::<Vec<_>>()
+        });
+
+        Self::from_inner(results, first.id)
+    }
+
+    /// Returns the encrypted selection mask of the elements matching the predicate.
+    ///
+    /// Each element of the mask encrypts 1 if the element at the same index matches the
+    /// predicate, 0 otherwise. The mask can then be combined with the values, e.g.
+    /// `values.zip_mul(&mask).sum()` sums the selected values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, EncryptedVec, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    ///
+    /// let values: EncryptedVec<FheUint8> =
+    ///     [3u8, 10, 14].iter().map(|v| FheUint8::encrypt(*v, &keys)).collect();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let is_even = values.filter(|x| x % 2 == 0);
+    /// let decrypted: Vec<u8> = is_even.iter().map(|v| v.decrypt(&keys)).collect();
+    /// assert_eq!(decrypted, vec![0, 1, 1]);
+    /// ```
+    pub fn filter<F>(&self, predicate: F) -> Self
+    where
+        F: Fn(u64) -> bool + Sync,
+    {
+        self.map(|x| u64::from(predicate(x)))
+    }
+
+    /// Applies a function of two values to the elements of both vectors pairwise.
+    ///
+    /// The function is evaluated as a bivariate lookup table, like
+    /// [GenericInteger::bivariate_function] does, on all the pairs in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, EncryptedVec, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint8().build();
+    /// let (keys, server_key) = generate_keys(config);
+    ///
+    /// let lhs: EncryptedVec<FheUint8> =
+    ///     [3u8, 10, 14].iter().map(|v| FheUint8::encrypt(*v, &keys)).collect();
+    /// let rhs: EncryptedVec<FheUint8> =
+    ///     [5u8, 4, 14].iter().map(|v| FheUint8::encrypt(*v, &keys)).collect();
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let distances = lhs.zip_with(&rhs, |x, y| x.abs_diff(y));
+    /// let decrypted: Vec<u8> = distances.iter().map(|v| v.decrypt(&keys)).collect();
+    /// assert_eq!(decrypted, vec![2, 6, 0]);
+    /// ```
+    pub fn zip_with<F>(&self, other: &Self, func: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64 + Sync,
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Cannot zip vectors of different lengths ({} and {})",
+            self.len(),
+            other.len()
+        );
+
+        let Some(first) = self.values.first() else {
+            return Self::new(vec![]);
+        };
+
+        let lhs = self.inner_ciphertexts();
+        let rhs = other.inner_ciphertexts();
+        let results = first.id.with_unwrapped_global(|key| {
+            let inner_key = &key.inner;
+            let wopbs_key = &key.wopbs_key;
+            lhs.par_iter()
+                .zip(rhs.par_iter())
+                .map(|(lhs, rhs)| wopbs_key.apply_bivariate_wopbs(inner_key, lhs, rhs, &func))
+                .collect::<Vec<_>>()
+        });
+
+        Self::from_inner(results, first.id)
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync
+        + for<'a> SmartAdd<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Returns the wrapping sum of the elements, or `None` if the vector is empty.
+    ///
+    /// The elements are added with a tree of additions, each level of the tree being computed in
+    /// parallel.
+    pub fn sum(&self) -> Option<GenericInteger<P>> {
+        self.reduce_inner(|key, lhs, rhs| key.smart_add(lhs, rhs))
+    }
+
+    /// Adds the elements of both vectors pairwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn zip_add(&self, other: &Self) -> Self {
+        self.zip_map_inner(other, |key, lhs, rhs| key.smart_add(lhs, rhs))
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync
+        + for<'a> SmartSub<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Subtracts the elements of `other` from the elements of `self` pairwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn zip_sub(&self, other: &Self) -> Self {
+        self.zip_map_inner(other, |key, lhs, rhs| key.smart_sub(lhs, rhs))
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync
+        + for<'a> SmartMul<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Multiplies the elements of both vectors pairwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn zip_mul(&self, other: &Self) -> Self {
+        self.zip_map_inner(other, |key, lhs, rhs| key.smart_mul(lhs, rhs))
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync
+        + for<'a> SmartMin<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Returns the minimum of the elements, or `None` if the vector is empty.
+    ///
+    /// The elements are compared with a tree of comparisons, each level of the tree being
+    /// computed in parallel.
+    pub fn min(&self) -> Option<GenericInteger<P>> {
+        self.reduce_inner(|key, lhs, rhs| key.smart_min(lhs, rhs))
+    }
+
+    /// Returns the pairwise minimum of the elements of both vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn zip_min(&self, other: &Self) -> Self {
+        self.zip_map_inner(other, |key, lhs, rhs| key.smart_min(lhs, rhs))
+    }
+}
+
+impl<P> EncryptedVec<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = GenericIntegerServerKey<P>>,
+    P::InnerCiphertext: Clone + Send + Sync,
+    P::InnerServerKey: Sync
+        + for<'a> SmartMax<
+            &'a mut P::InnerCiphertext,
+            &'a mut P::InnerCiphertext,
+            Output = P::InnerCiphertext,
+        >,
+{
+    /// Returns the maximum of the elements, or `None` if the vector is empty.
+    ///
+    /// The elements are compared with a tree of comparisons, each level of the tree being
+    /// computed in parallel.
+    pub fn max(&self) -> Option<GenericInteger<P>> {
+        self.reduce_inner(|key, lhs, rhs| key.smart_max(lhs, rhs))
+    }
+
+    /// Returns the pairwise maximum of the elements of both vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn zip_max(&self, other: &Self) -> Self {
+        self.zip_map_inner(other, |key, lhs, rhs| key.smart_max(lhs, rhs))
+    }
+}
@@ -6,6 +6,8 @@ mod server;
 
 use crate::high_level_api::config::Config;
 pub use client::{ClientKey, RefKeyFromKeyChain};
+#[cfg(feature = "integer")]
+pub use public::{CompactPublicKey, RefKeyFromCompactPublicKeyChain};
 pub use public::{
     CompressedPublicKey, PublicKey, RefKeyFromCompressedPublicKeyChain, RefKeyFromPublicKeyChain,
 };
@@ -1,12 +1,16 @@
 //! This module defines PublicKey
 //!
 //! - [PublicKey] aggregates a key that can be made public, and that allows to encrypt (only)
+//! - [CompactPublicKey] aggregates smaller public keys that encrypt lists of values in a compact
+//!   form which has to be expanded before computing on the values
 
 #[cfg(feature = "boolean")]
 use crate::high_level_api::booleans::{BooleanCompressedPublicKey, BooleanPublicKey};
 use crate::high_level_api::errors::{UninitializedPublicKey, UnwrapResultExt};
 #[cfg(feature = "integer")]
-use crate::high_level_api::integers::{IntegerCompressedPublicKey, IntegerPublicKey};
+use crate::high_level_api::integers::{
+    IntegerCompactPublicKey, IntegerCompressedPublicKey, IntegerPublicKey,
+};
 #[cfg(feature = "shortint")]
 use crate::high_level_api::shortints::{ShortIntCompressedPublicKey, ShortIntPublicKey};
 
@@ -148,3 +152,67 @@ macro_rules! impl_ref_key_from_compressed_public_keychain {
         }
     }
 }
+
+/// A public key that encrypts lists of values in a compact form.
+///
+/// Values encrypted with this key are packed in a list (e.g. [CompactFheUint8List]) which is much
+/// smaller than the corresponding individual ciphertexts, this makes it well suited for clients
+/// uploading bulk data to a server which then expands the list into individual ciphertexts.
+///
+/// [CompactFheUint8List]: crate::high_level_api::CompactFheUint8List
+#[cfg(feature = "integer")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CompactPublicKey {
+    pub(crate) integer_key: IntegerCompactPublicKey,
+}
+
+#[cfg(feature = "integer")]
+impl CompactPublicKey {
+    pub fn new(client_key: &ClientKey) -> Self {
+        Self {
+            integer_key: IntegerCompactPublicKey::new(&client_key.integer_key),
+        }
+    }
+}
+
+#[cfg(feature = "integer")]
+pub trait RefKeyFromCompactPublicKeyChain: Sized {
+    type Key;
+
+    /// The method to implement, shall return a ref to the key or an error if
+    /// the key member in the key was not initialized
+    fn ref_key(self, keys: &CompactPublicKey) -> Result<&Self::Key, UninitializedPublicKey>;
+
+    /// Returns a mutable ref to the key member of the key
+    ///
+    /// # Panic
+    ///
+    /// This will panic if the key was not initialized
+    #[track_caller]
+    fn unwrapped_ref_key(self, keys: &CompactPublicKey) -> &Self::Key {
+        self.ref_key(keys).unwrap_display()
+    }
+}
+
+#[cfg(feature = "integer")]
+macro_rules! impl_ref_key_from_compact_public_keychain {
+    (
+        for $implementor:ty {
+            key_type: $key_type:ty,
+            keychain_member: $($member:ident).*,
+            type_variant: $enum_variant:expr,
+        }
+    ) => {
+        impl crate::high_level_api::keys::RefKeyFromCompactPublicKeyChain for $implementor {
+            type Key = $key_type;
+
+            fn ref_key(self, keys: &crate::high_level_api::keys::CompactPublicKey)
+                -> Result<&Self::Key, crate::high_level_api::errors::UninitializedPublicKey>
+            {
+                keys$(.$member)*
+                    .as_ref()
+                    .ok_or(crate::high_level_api::errors::UninitializedPublicKey($enum_variant))
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 pub use config::{Config, ConfigBuilder};
 pub use errors::{Error, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
+#[cfg(feature = "integer")]
+pub use keys::CompactPublicKey;
 pub use keys::{generate_keys, ClientKey, CompressedPublicKey, PublicKey, ServerKey};
 
 #[cfg(test)]
@@ -11,10 +13,16 @@ mod tests;
 pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolParameters};
 #[cfg(feature = "integer")]
 pub use crate::high_level_api::integers::{
-    CompressedFheUint10, CompressedFheUint12, CompressedFheUint128, CompressedFheUint14,
-    CompressedFheUint16, CompressedFheUint256, CompressedFheUint32, CompressedFheUint64,
-    CompressedFheUint8, CrtParameters, FheUint10, FheUint12, FheUint128, FheUint14, FheUint16,
-    FheUint256, FheUint32, FheUint64, FheUint8, GenericInteger, RadixParameters,
+    Checked, Saturating,
+    CompactFheUint10List, CompactFheUint128List, CompactFheUint12List, CompactFheUint14List,
+    CompactFheUint16List, CompactFheUint256List, CompactFheUint32List, CompactFheUint64List,
+    CompactFheUint8List, CompressedFheUint10, CompressedFheUint12, CompressedFheUint128,
+    CompressedFheUint14, CompressedFheUint16, CompressedFheUint256, CompressedFheUint32,
+    CompressedFheUint64, CompressedFheUint8, CrtParameters, EncryptedVec, FheExpr, FheUint10,
+    FheUint10Typed, FheUint12, FheUint128, FheUint128Typed, FheUint12Typed, FheUint14,
+    FheUint14Typed, FheUint16, FheUint16Typed, FheUint256, FheUint256Typed, FheUint32,
+    FheUint32Typed, FheUint64, FheUint64Typed, FheUint8, FheUint8Typed, GenericInteger,
+    GenericTypedInteger, RadixParameters,
 };
 #[cfg(feature = "shortint")]
 pub use crate::high_level_api::shortints::{
@@ -29,15 +37,13 @@ mod global_state;
 mod keys;
 mod config;
 mod internal_traits;
-mod traits;
+pub(crate) mod traits;
 
 #[cfg(feature = "boolean")]
 mod booleans;
 pub mod errors;
 #[cfg(feature = "integer")]
 mod integers;
-/// The tfhe prelude.
-pub mod prelude;
 #[cfg(feature = "shortint")]
 mod shortints;
 
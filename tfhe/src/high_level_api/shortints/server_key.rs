@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "internal-keycache")]
 use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::server_key::custom_op::CustomOpError;
 use crate::shortint::ServerKey;
 
 use super::client_key::GenericShortIntClientKey;
@@ -171,6 +172,26 @@ where
             .scalar_sub_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
     }
 
+    pub(crate) fn scalar_div_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .scalar_div_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn scalar_mod_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .scalar_mod_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn scalar_left_shift_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .scalar_left_shift_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn scalar_right_shift_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .scalar_right_shift_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
     pub(crate) fn bitand(
         &self,
         lhs: &GenericShortInt<P>,
@@ -414,6 +435,24 @@ where
             .apply_lookup_table_assign(&mut ciphertext.ciphertext.borrow_mut(), &accumulator)
     }
 
+    pub(super) fn custom_op(
+        &self,
+        name: &str,
+        inputs: &[&GenericShortInt<P>],
+        id: P::Id,
+    ) -> Result<GenericShortInt<P>, CustomOpError> {
+        let borrowed = inputs
+            .iter()
+            .map(|input| input.ciphertext.borrow())
+            .collect::<Vec<_>>();
+        let ciphertexts = borrowed.iter().map(|ct| &**ct).collect::<Vec<_>>();
+        let ciphertext = self.key.custom_op(name, &ciphertexts)?;
+        Ok(GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id,
+        })
+    }
+
     pub(super) fn bivariate_pbs<F>(
         &self,
         lhs_ct: &GenericShortInt<P>,
@@ -1,7 +1,7 @@
-use crate::high_level_api::prelude::*;
 use crate::high_level_api::{
     generate_keys, set_server_key, CompressedFheUint2, ConfigBuilder, FheUint2,
 };
+use crate::prelude::*;
 use crate::CompressedPublicKey;
 
 #[test]
@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
-    Mul, MulAssign, Neg, Rem, Shl, Shr, Sub, SubAssign,
+    Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
 use serde::{Deserialize, Serialize};
@@ -109,6 +109,8 @@ pub struct GenericShortInt<P: ShortIntegerParameter> {
     pub(in crate::high_level_api::shortints) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: ShortIntegerParameter] for GenericShortInt<P> { ciphertext });
+
 impl<P> GenericShortInt<P>
 where
     P: ShortIntegerParameter,
@@ -338,6 +340,75 @@ where
     }
 }
 
+impl<P> GenericShortInt<P>
+where
+    P: ShortIntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = GenericShortIntServerKey<P>>,
+{
+    /// Computes the operation registered as `name` with
+    /// [register_custom_op](crate::shortint::server_key::custom_op::register_custom_op) on
+    /// `inputs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), tfhe::Error> {
+    /// use std::sync::Arc;
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::server_key::custom_op::{
+    ///     register_custom_op, CustomOp, CustomOpContext, CustomOpError,
+    /// };
+    /// use tfhe::shortint::CiphertextBig;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint2};
+    ///
+    /// struct AbsDiff;
+    ///
+    /// impl CustomOp for AbsDiff {
+    ///     fn name(&self) -> &str {
+    ///         "abs_diff"
+    ///     }
+    ///
+    ///     fn num_inputs(&self) -> usize {
+    ///         2
+    ///     }
+    ///
+    ///     fn evaluate(
+    ///         &self,
+    ///         context: &CustomOpContext<'_>,
+    ///         inputs: &[&CiphertextBig],
+    ///     ) -> Result<CiphertextBig, CustomOpError> {
+    ///         let sks = context.server_key();
+    ///         let acc = sks.generate_accumulator_bivariate(|x, y| x.abs_diff(y));
+    ///         Ok(sks.unchecked_apply_lookup_table_bivariate(inputs[0], inputs[1], &acc))
+    ///     }
+    /// }
+    ///
+    /// register_custom_op(Arc::new(AbsDiff));
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// let a = FheUint2::try_encrypt(1, &client_key)?;
+    /// let b = FheUint2::try_encrypt(3, &client_key)?;
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let c = FheUint2::custom_op("abs_diff", &[&a, &b])?;
+    /// let decrypted: u8 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn custom_op(
+        name: &str,
+        inputs: &[&Self],
+    ) -> Result<Self, crate::high_level_api::errors::Error> {
+        let id = P::Id::default();
+        let result = id.with_unwrapped_global(|key| key.custom_op(name, inputs, id))?;
+        Ok(result)
+    }
+}
+
 impl<P> FheOrd<u8> for GenericShortInt<P>
 where
     P: ShortIntegerParameter,
@@ -515,7 +586,7 @@ where
 }
 
 macro_rules! short_int_impl_operation (
-    ($trait_name:ident($trait_method:ident, $op:tt) => $key_method:ident) => {
+    ($trait_name:ident($trait_method:ident, $op:tt) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
         #[doc = concat!(" Allows using the `", stringify!($op), "` operator between a")]
         #[doc = " `GenericFheUint` and a `GenericFheUint` or a `&GenericFheUint`"]
         #[doc = " "]
@@ -576,8 +647,10 @@ macro_rules! short_int_impl_operation (
         {
             type Output = Self;
 
-            fn $trait_method(self, rhs: I) -> Self::Output {
-                <&Self as $trait_name<I>>::$trait_method(&self, rhs)
+            // The left hand side is owned, its ciphertext is reused for the result
+            fn $trait_method(mut self, rhs: I) -> Self::Output {
+                <Self as $assign_trait<I>>::$assign_method(&mut self, rhs);
+                self
             }
         }
 
@@ -730,7 +803,7 @@ macro_rules! short_int_impl_operation_assign (
 
 // Scalar operations
 macro_rules! short_int_impl_scalar_operation {
-    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+    ($trait_name:ident($trait_method:ident) => $key_method:ident, $assign_trait:ident($assign_method:ident)) => {
         impl<P> $trait_name<u8> for &GenericShortInt<P>
         where
             P: ShortIntegerParameter,
@@ -751,8 +824,10 @@ macro_rules! short_int_impl_scalar_operation {
         {
             type Output = GenericShortInt<P>;
 
-            fn $trait_method(self, rhs: u8) -> Self::Output {
-                <&Self as $trait_name<u8>>::$trait_method(&self, rhs)
+            // The left hand side is owned, its ciphertext is reused for the result
+            fn $trait_method(mut self, rhs: u8) -> Self::Output {
+                <Self as $assign_trait<u8>>::$assign_method(&mut self, rhs);
+                self
             }
         }
 
@@ -821,13 +896,13 @@ where
     }
 }
 
-short_int_impl_operation!(Add(add,+) => add);
-short_int_impl_operation!(Sub(sub,-) => sub);
-short_int_impl_operation!(Mul(mul,*) => mul);
-short_int_impl_operation!(Div(div,/) => div);
-short_int_impl_operation!(BitAnd(bitand,&) => bitand);
-short_int_impl_operation!(BitOr(bitor,|) => bitor);
-short_int_impl_operation!(BitXor(bitxor,^) => bitxor);
+short_int_impl_operation!(Add(add,+) => add, AddAssign(add_assign));
+short_int_impl_operation!(Sub(sub,-) => sub, SubAssign(sub_assign));
+short_int_impl_operation!(Mul(mul,*) => mul, MulAssign(mul_assign));
+short_int_impl_operation!(Div(div,/) => div, DivAssign(div_assign));
+short_int_impl_operation!(BitAnd(bitand,&) => bitand, BitAndAssign(bitand_assign));
+short_int_impl_operation!(BitOr(bitor,|) => bitor, BitOrAssign(bitor_assign));
+short_int_impl_operation!(BitXor(bitxor,^) => bitxor, BitXorAssign(bitxor_assign));
 
 short_int_impl_operation_assign!(AddAssign(add_assign,+=) => add_assign);
 short_int_impl_operation_assign!(SubAssign(sub_assign,-=) => sub_assign);
@@ -837,14 +912,18 @@ short_int_impl_operation_assign!(BitAndAssign(bitand_assign,&=) => bitand_assign
 short_int_impl_operation_assign!(BitOrAssign(bitor_assign,|=) => bitor_assign);
 short_int_impl_operation_assign!(BitXorAssign(bitxor_assign,^=) => bitxor_assign);
 
-short_int_impl_scalar_operation!(Add(add) => scalar_add);
-short_int_impl_scalar_operation!(Sub(sub) => scalar_sub);
-short_int_impl_scalar_operation!(Mul(mul) => scalar_mul);
-short_int_impl_scalar_operation!(Div(div) => scalar_div);
-short_int_impl_scalar_operation!(Rem(rem) => scalar_mod);
-short_int_impl_scalar_operation!(Shl(shl) => scalar_left_shift);
-short_int_impl_scalar_operation!(Shr(shr) => scalar_right_shift);
+short_int_impl_scalar_operation!(Add(add) => scalar_add, AddAssign(add_assign));
+short_int_impl_scalar_operation!(Sub(sub) => scalar_sub, SubAssign(sub_assign));
+short_int_impl_scalar_operation!(Mul(mul) => scalar_mul, MulAssign(mul_assign));
+short_int_impl_scalar_operation!(Div(div) => scalar_div, DivAssign(div_assign));
+short_int_impl_scalar_operation!(Rem(rem) => scalar_mod, RemAssign(rem_assign));
+short_int_impl_scalar_operation!(Shl(shl) => scalar_left_shift, ShlAssign(shl_assign));
+short_int_impl_scalar_operation!(Shr(shr) => scalar_right_shift, ShrAssign(shr_assign));
 
 short_int_impl_scalar_operation_assign!(AddAssign(add_assign) => scalar_add_assign);
 short_int_impl_scalar_operation_assign!(SubAssign(sub_assign) => scalar_sub_assign);
 short_int_impl_scalar_operation_assign!(MulAssign(mul_assign) => scalar_mul_assign);
+short_int_impl_scalar_operation_assign!(DivAssign(div_assign) => scalar_div_assign);
+short_int_impl_scalar_operation_assign!(RemAssign(rem_assign) => scalar_mod_assign);
+short_int_impl_scalar_operation_assign!(ShlAssign(shl_assign) => scalar_left_shift_assign);
+short_int_impl_scalar_operation_assign!(ShrAssign(shr_assign) => scalar_right_shift_assign);
@@ -14,6 +14,8 @@ where
     pub(in crate::high_level_api::shortints) id: P::Id,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[P: ShortIntegerParameter] for CompressedGenericShortint<P> { ciphertext });
+
 impl<P> CompressedGenericShortint<P>
 where
     P: ShortIntegerParameter,
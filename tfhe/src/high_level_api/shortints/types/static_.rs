@@ -3,7 +3,8 @@ use std::fmt::Formatter;
 
 use crate::shortint::parameters::{
     CarryModulus, CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
-    GlweDimension, LweDimension, MessageModulus, Parameters, PolynomialSize, StandardDev,
+    GlweDimension, LweDimension, MessageModulus, NoiseDistributionKind, Parameters, PolynomialSize,
+    StandardDev,
 };
 
 use crate::high_level_api::shortints::{CompressedGenericShortint, GenericShortInt};
@@ -31,6 +32,7 @@ pub struct ShortIntegerParameterSet<const MESSAGE_BITS: u8> {
     pub polynomial_size: PolynomialSize,
     pub lwe_modular_std_dev: StandardDev,
     pub glwe_modular_std_dev: StandardDev,
+    pub noise_distribution: NoiseDistributionKind,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -55,6 +57,7 @@ impl<const MESSAGE_BITS: u8> ShortIntegerParameterSet<MESSAGE_BITS> {
             polynomial_size: params.polynomial_size,
             lwe_modular_std_dev: params.lwe_modular_std_dev,
             glwe_modular_std_dev: params.glwe_modular_std_dev,
+            noise_distribution: params.noise_distribution,
             pbs_base_log: params.pbs_base_log,
             pbs_level: params.pbs_level,
             ks_base_log: params.ks_base_log,
@@ -78,6 +81,7 @@ impl<const MESSAGE_BITS: u8> From<ShortIntegerParameterSet<MESSAGE_BITS>> for Pa
             polynomial_size: params.polynomial_size,
             lwe_modular_std_dev: params.lwe_modular_std_dev,
             glwe_modular_std_dev: params.glwe_modular_std_dev,
+            noise_distribution: params.noise_distribution,
             pbs_base_log: params.pbs_base_log,
             pbs_level: params.pbs_level,
             ks_base_log: params.ks_base_log,
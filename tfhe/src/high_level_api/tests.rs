@@ -1,4 +1,3 @@
-use crate::high_level_api::prelude::*;
 #[cfg(feature = "boolean")]
 use crate::high_level_api::FheBool;
 #[cfg(feature = "shortint")]
@@ -9,6 +8,7 @@ use crate::high_level_api::{generate_keys, ClientKey, ConfigBuilder, PublicKey};
 use crate::high_level_api::{FheUint256, FheUint8};
 #[cfg(feature = "integer")]
 use crate::integer::U256;
+use crate::prelude::*;
 use crate::CompressedPublicKey;
 #[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
 use std::fmt::Debug;
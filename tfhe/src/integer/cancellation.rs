@@ -0,0 +1,80 @@
+//! Cooperative cancellation of long-running operations.
+//!
+//! Some operations, like large multiplications or WoP-PBS evaluations, can run for seconds.
+//! Their `_cancellable` variants take a [`CancellationToken`] and check it between their
+//! block-level steps, returning [`Cancelled`] as soon as they notice the token was cancelled.
+//!
+//! The steps already started are not interrupted, so the operation returns after at most one
+//! step once the token is cancelled.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::{CancellationToken, Cancelled};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let ct1 = cks.encrypt(13);
+//! let ct2 = cks.encrypt(7);
+//!
+//! let token = CancellationToken::new();
+//! let ct_res = sks.mul_parallelized_cancellable(&ct1, &ct2, &token).unwrap();
+//! let res: u64 = cks.decrypt(&ct_res);
+//! assert_eq!(res, 91);
+//!
+//! // The token can be cancelled from another thread, e.g. by a request timeout
+//! token.cancel();
+//! let result = sks.mul_parallelized_cancellable(&ct1, &ct2, &token);
+//! assert_eq!(result.err(), Some(Cancelled));
+//! ```
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A token used to request the cancellation of operations.
+///
+/// Clones of a token share the same state: cancelling one of them cancels all the operations
+/// that were given any of the clones.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the cancellation of the operations using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Return `Err(Cancelled)` if the token was cancelled.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Error returned by an operation whose [`CancellationToken`] was cancelled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The operation was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
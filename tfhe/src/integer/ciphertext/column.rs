@@ -0,0 +1,247 @@
+//! Columnar storage of radix ciphertexts.
+//!
+//! A [`RadixCiphertextColumn`] stores many radix ciphertexts having the same number of blocks in
+//! a shortint [`CiphertextColumn`], the blocks of each value being laid out from LSB to MSB one
+//! after the other. Each value thus takes exactly
+//! [`value_byte_width`](RadixCiphertextColumn::value_byte_width) bytes of the buffer.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::ciphertext::column::RadixCiphertextColumnOwned;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let cts = [12u64, 34, 56].map(|msg| cks.encrypt(msg));
+//! let column = RadixCiphertextColumnOwned::from_ciphertexts(&cts);
+//! assert_eq!(column.len(), 3);
+//! assert_eq!(column.as_bytes().len(), 3 * column.value_byte_width());
+//!
+//! let a = column.get(0).unwrap();
+//! let b = column.get(2).unwrap();
+//! let ct_res = sks.add_parallelized(&a, &b);
+//! let dec: u64 = cks.decrypt(&ct_res);
+//! assert_eq!(dec, 68);
+//! ```
+use super::RadixCiphertext;
+use crate::core_crypto::commons::parameters::LweSize;
+use crate::core_crypto::commons::traits::{Container, ContainerMut};
+use crate::shortint::ciphertext::column::{CiphertextColumn, CiphertextRef, ColumnError};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::PBSOrderMarker;
+use crate::size::FheSize;
+
+/// Radix ciphertexts having the same number of blocks, stored contiguously.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone)]
+pub struct RadixCiphertextColumn<C: Container<Element = u64>, OpOrder: PBSOrderMarker> {
+    blocks: CiphertextColumn<C, OpOrder>,
+    num_blocks: usize,
+}
+
+pub type RadixCiphertextColumnOwned<OpOrder> = RadixCiphertextColumn<Vec<u64>, OpOrder>;
+pub type RadixCiphertextColumnView<'a, OpOrder> = RadixCiphertextColumn<&'a [u64], OpOrder>;
+
+impl<C: Container<Element = u64>, OpOrder: PBSOrderMarker> RadixCiphertextColumn<C, OpOrder> {
+    /// Create a column of radix ciphertexts from a column of their blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is zero or does not divide the number of blocks in the column.
+    pub fn from_block_column(blocks: CiphertextColumn<C, OpOrder>, num_blocks: usize) -> Self {
+        assert_ne!(num_blocks, 0, "The number of blocks must be non-zero");
+        assert_eq!(
+            blocks.len() % num_blocks,
+            0,
+            "The number of blocks in the column {} is not a multiple of {num_blocks}",
+            blocks.len()
+        );
+        Self { blocks, num_blocks }
+    }
+
+    /// Return the number of ciphertexts in the column.
+    pub fn len(&self) -> usize {
+        self.blocks.len() / self.num_blocks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+
+    /// Return the number of bytes taken by each ciphertext in the buffer.
+    pub fn value_byte_width(&self) -> usize {
+        self.num_blocks * self.blocks.value_byte_width()
+    }
+
+    /// Return the raw buffer of the column, ciphertexts are stored one after the other.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.blocks.as_bytes()
+    }
+
+    /// Return the column of the blocks of all the ciphertexts.
+    pub fn block_column(&self) -> &CiphertextColumn<C, OpOrder> {
+        &self.blocks
+    }
+
+    /// Return references to the blocks of the ciphertext at `index`, from LSB to MSB, or `None`
+    /// if it is out of bounds.
+    pub fn get_blocks(
+        &self,
+        index: usize,
+    ) -> Option<impl Iterator<Item = CiphertextRef<'_, OpOrder>> + '_> {
+        if index >= self.len() {
+            return None;
+        }
+        let start = index * self.num_blocks;
+        Some((start..start + self.num_blocks).map(|i| self.blocks.get(i).unwrap()))
+    }
+
+    /// Copy the ciphertext at `index` into a [`RadixCiphertext`], or return `None` if it is out
+    /// of bounds.
+    pub fn get(&self, index: usize) -> Option<RadixCiphertext<OpOrder>> {
+        let blocks = self
+            .get_blocks(index)?
+            .map(|block| block.to_ciphertext())
+            .collect::<Vec<_>>();
+        Some(RadixCiphertext::from(blocks))
+    }
+
+    /// Copy all the ciphertexts of the column into [`RadixCiphertext`].
+    pub fn to_ciphertexts(&self) -> Vec<RadixCiphertext<OpOrder>> {
+        (0..self.len()).map(|i| self.get(i).unwrap()).collect()
+    }
+
+    pub fn into_block_column(self) -> CiphertextColumn<C, OpOrder> {
+        self.blocks
+    }
+}
+
+impl<C: ContainerMut<Element = u64>, OpOrder: PBSOrderMarker> RadixCiphertextColumn<C, OpOrder> {
+    /// Overwrite the ciphertext at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if the ciphertext does not have the number of blocks
+    /// or the parameters of the column.
+    pub fn set(&mut self, index: usize, ct: &RadixCiphertext<OpOrder>) {
+        assert_eq!(
+            ct.blocks.len(),
+            self.num_blocks,
+            "The ciphertext number of blocks does not match the column number of blocks"
+        );
+        assert!(index < self.len(), "Index {index} is out of bounds");
+        for (i, block) in ct.blocks.iter().enumerate() {
+            self.blocks.set(index * self.num_blocks + i, block);
+        }
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> RadixCiphertextColumnOwned<OpOrder> {
+    /// Create an empty column.
+    pub fn new(
+        num_blocks: usize,
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        Self::from_block_column(
+            CiphertextColumn::new(lwe_size, message_modulus, carry_modulus, ciphertext_modulus),
+            num_blocks,
+        )
+    }
+
+    /// Copy the given ciphertexts into a new column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty or if the ciphertexts do not all have the same number of blocks
+    /// and parameters.
+    pub fn from_ciphertexts(cts: &[RadixCiphertext<OpOrder>]) -> Self {
+        let first = cts
+            .first()
+            .expect("Cannot create a column from an empty slice of ciphertexts");
+        let first_block = first
+            .blocks
+            .first()
+            .expect("Cannot create a column from ciphertexts without blocks");
+        let mut column = Self::new(
+            first.blocks.len(),
+            first_block.ct.lwe_size(),
+            first_block.message_modulus,
+            first_block.carry_modulus,
+            first_block.ct.ciphertext_modulus(),
+        );
+        for ct in cts {
+            column.push(ct);
+        }
+        column
+    }
+
+    /// Append a copy of `ct` at the end of the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not have the number of blocks or the parameters of the
+    /// column.
+    pub fn push(&mut self, ct: &RadixCiphertext<OpOrder>) {
+        assert_eq!(
+            ct.blocks.len(),
+            self.num_blocks,
+            "The ciphertext number of blocks does not match the column number of blocks"
+        );
+        for block in &ct.blocks {
+            self.blocks.push(block);
+        }
+    }
+}
+
+impl<'a, OpOrder: PBSOrderMarker> RadixCiphertextColumnView<'a, OpOrder> {
+    /// View a raw buffer as a column without copying it.
+    ///
+    /// `degrees` holds the degrees of all the blocks, in the order in which they are stored. See
+    /// [`CiphertextColumnView::try_from_bytes`](crate::shortint::ciphertext::column::CiphertextColumnView::try_from_bytes)
+    /// for the requirements on the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is zero or does not divide the number of blocks in the buffer.
+    pub fn try_from_bytes(
+        bytes: &'a [u8],
+        degrees: Vec<Degree>,
+        num_blocks: usize,
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Result<Self, ColumnError> {
+        let blocks = CiphertextColumn::try_from_bytes(
+            bytes,
+            degrees,
+            lwe_size,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus,
+        )?;
+        Ok(Self::from_block_column(blocks, num_blocks))
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> FheSize for RadixCiphertextColumnOwned<OpOrder> {
+    fn element_count(&self) -> usize {
+        self.blocks.element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.blocks.heap_size()
+    }
+}
@@ -1,24 +1,114 @@
 //! This module implements the ciphertext structures.
+pub mod column;
+pub mod shared;
+
+use crate::core_crypto::commons::traits::deserialize_in_place::{
+    deserialize_struct_in_place, StructInPlace,
+};
+use crate::core_crypto::commons::traits::DeserializeInPlace;
+use crate::integer::server_key::CheckError;
+use crate::integer::ServerKey;
 use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
+use crate::shortint::debug::{diff_field, field_path, Mismatch, StructuralDiff};
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBig,
     CompressedCiphertextSmall, PBSOrderMarker,
 };
+use crate::size::FheSize;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// Structure containing a ciphertext in radix decomposition.
-#[derive(Serialize, Clone, Deserialize)]
+#[derive(Serialize, Clone, Deserialize, Debug)]
 pub struct BaseRadixCiphertext<Block> {
     /// The blocks are stored from LSB to MSB
     pub(crate) blocks: Vec<Block>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[Block: FheSize] for BaseRadixCiphertext<Block> { blocks });
+
+impl<Block> StructInPlace for BaseRadixCiphertext<Block>
+where
+    Block: DeserializeInPlace + DeserializeOwned,
+{
+    const NAME: &'static str = "BaseRadixCiphertext";
+    const FIELDS: &'static [&'static str] = &["blocks"];
+
+    fn deserialize_field<'de, D>(&mut self, _index: usize, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.blocks.deserialize_in_place(deserializer)
+    }
+}
+
+impl<Block> DeserializeInPlace for BaseRadixCiphertext<Block>
+where
+    Block: DeserializeInPlace + DeserializeOwned,
+{
+    /// Deserialize a ciphertext into `self`, reusing the allocations of its blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bincode::Options;
+    /// use tfhe::core_crypto::commons::traits::DeserializeInPlace;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut ct = cks.encrypt(0);
+    /// for msg in [12u64, 34, 56] {
+    ///     let bytes = bincode::serialize(&cks.encrypt(msg)).unwrap();
+    ///     ct.deserialize_in_place(&mut bincode::Deserializer::from_slice(
+    ///         &bytes,
+    ///         bincode::DefaultOptions::new().with_fixint_encoding(),
+    ///     ))
+    ///     .unwrap();
+    ///     let dec: u64 = cks.decrypt(&ct);
+    ///     assert_eq!(dec, msg);
+    /// }
+    /// ```
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_struct_in_place(self, deserializer)
+    }
+}
+
 impl<Block> From<Vec<Block>> for BaseRadixCiphertext<Block> {
     fn from(blocks: Vec<Block>) -> Self {
         Self { blocks }
     }
 }
 
+impl<Block: StructuralDiff> StructuralDiff for BaseRadixCiphertext<Block> {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        self.blocks
+            .diff_into(&other.blocks, &field_path(path, "blocks"), mismatches);
+    }
+}
+
+impl<PBSOrder: PBSOrderMarker> std::fmt::Display for BaseRadixCiphertext<CiphertextBase<PBSOrder>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "radix ciphertext of {} blocks, degrees [",
+            self.blocks.len()
+        )?;
+        for (i, block) in self.blocks.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", block.degree.0)?;
+        }
+        write!(f, "]")
+    }
+}
+
 // Type alias to save some typing in implementation parts
 pub type RadixCiphertext<PBSOder> = BaseRadixCiphertext<CiphertextBase<PBSOder>>;
 
@@ -34,6 +124,161 @@ impl<PBSOrder: PBSOrderMarker> RadixCiphertext<PBSOrder> {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(|block| block.carry_is_empty())
     }
+
+    /// Return whether all the blocks are trivial encryptions, see
+    /// [`CiphertextBase::is_trivial`].
+    pub fn is_trivial(&self) -> bool {
+        self.blocks.iter().all(|block| block.is_trivial())
+    }
+
+    /// Decode the value of a trivial ciphertext modulo the modulus of the ciphertext, or return
+    /// `None` if the ciphertext is not trivial or its value does not fit in a `u64`.
+    ///
+    /// The carries of the blocks are taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::RadixCiphertextBig;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, 4);
+    ///
+    /// let ct: RadixCiphertextBig = sks.create_trivial_radix(200, 4);
+    /// let ct_res = sks.unchecked_add(&ct, &ct);
+    /// assert_eq!(ct_res.decrypt_trivial(), Some(400 % 256));
+    ///
+    /// assert_eq!(cks.encrypt(200).decrypt_trivial(), None);
+    /// ```
+    pub fn decrypt_trivial(&self) -> Option<u64> {
+        let mut value = 0_u128;
+        let mut power = Some(1_u128);
+        for block in &self.blocks {
+            let block_value = block.decrypt_trivial()?;
+            // The message moduli being powers of two, wrapping at 2^128 does not change the
+            // value modulo the modulus of the ciphertext when the latter exceeds 2^128
+            if let Some(block_power) = power {
+                value = value.wrapping_add((block_value as u128).wrapping_mul(block_power));
+            }
+            power = power.and_then(|power| power.checked_mul(block.message_modulus.0 as u128));
+        }
+
+        let value = match power {
+            Some(modulus) => value % modulus,
+            None => value,
+        };
+        u64::try_from(value).ok()
+    }
+
+    /// Converts the ciphertext into a single shortint block encrypting its value modulo the
+    /// message modulus.
+    ///
+    /// Only the least significant block is kept, its carries are cleaned with a PBS if they are
+    /// not empty, so the returned block carries are always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext has no blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 142u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let block = ct.into_single_block(&sks);
+    /// assert_eq!(cks.decrypt_one_block(&block), msg % 4);
+    /// ```
+    pub fn into_single_block(self, sks: &ServerKey) -> CiphertextBase<PBSOrder> {
+        let mut block = self
+            .blocks
+            .into_iter()
+            .next()
+            .expect("Cannot convert a ciphertext without blocks into a single block");
+        if !block.carry_is_empty() {
+            sks.key.message_extract_assign(&mut block);
+        }
+        block
+    }
+
+    /// Converts the ciphertext into a single shortint block encrypting its whole value.
+    ///
+    /// If the degrees of the blocks guarantee that the value fits in the message and carry spaces
+    /// of a single block, the blocks are packed into one block, without any PBS. Otherwise
+    /// [CheckError::CarryFull] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext has no blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 13u64;
+    ///
+    /// // 2 * 2 = 4 bits of message fit in the 4 bits of message and carry of a block
+    /// let ct = cks.encrypt_radix(msg, 2);
+    /// let block = ct.checked_into_single_block(&sks).unwrap();
+    /// assert_eq!(block.degree.0, 15);
+    ///
+    /// // Apply a LUT on the whole value
+    /// let lut = sks.as_ref().generate_accumulator(|x| x % 5);
+    /// let res = sks.as_ref().apply_lookup_table(&block, &lut);
+    /// assert_eq!(cks.decrypt_one_block(&res), msg % 5);
+    ///
+    /// // 3 * 2 = 6 bits of message do not fit
+    /// let ct = cks.encrypt_radix(msg, 3);
+    /// assert!(ct.checked_into_single_block(&sks).is_err());
+    /// ```
+    pub fn checked_into_single_block(
+        self,
+        sks: &ServerKey,
+    ) -> Result<CiphertextBase<PBSOrder>, CheckError> {
+        let message_modulus = sks.key.message_modulus.0;
+
+        // Maximum value of the packed block, and factor of each block in it
+        let mut packed_degree = 0usize;
+        let mut factor = Some(1usize);
+        for block in &self.blocks {
+            if block.degree.0 != 0 {
+                packed_degree = factor
+                    .and_then(|factor| block.degree.0.checked_mul(factor))
+                    .and_then(|value| value.checked_add(packed_degree))
+                    .ok_or(CheckError::CarryFull)?;
+            }
+            factor = factor.and_then(|factor| factor.checked_mul(message_modulus));
+        }
+        if packed_degree > sks.key.max_degree.0 {
+            return Err(CheckError::CarryFull);
+        }
+
+        let mut blocks = self.blocks.into_iter();
+        let mut packed = blocks
+            .next()
+            .expect("Cannot convert a ciphertext without blocks into a single block");
+        let mut factor = 1;
+        for block in blocks {
+            factor *= message_modulus;
+            // Blocks known to encrypt 0 are skipped, the factor of the others fits in a block
+            if block.degree.0 != 0 {
+                let shifted = sks.key.unchecked_scalar_mul(&block, factor as u8);
+                sks.key.unchecked_add_assign(&mut packed, &shifted);
+            }
+        }
+        Ok(packed)
+    }
 }
 
 impl From<CompressedRadixCiphertextBig> for RadixCiphertextBig {
@@ -60,6 +305,95 @@ impl From<CompressedRadixCiphertextSmall> for RadixCiphertextSmall {
     }
 }
 
+/// Structure containing a single shortint block encrypting a boolean value (0 or 1).
+///
+/// Such blocks are produced by bit extraction on radix ciphertexts and can be assembled back
+/// into a radix ciphertext, see
+/// [`ServerKey::to_bits_parallelized`](crate::integer::ServerKey::to_bits_parallelized) and
+/// [`ServerKey::from_bits_parallelized`](crate::integer::ServerKey::from_bits_parallelized).
+#[derive(Serialize, Clone, Deserialize, Debug)]
+pub struct BaseBooleanBlock<Block> {
+    pub(crate) block: Block,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl[Block: FheSize] for BaseBooleanBlock<Block> { block });
+
+impl<Block: StructuralDiff> StructuralDiff for BaseBooleanBlock<Block> {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        self.block
+            .diff_into(&other.block, &field_path(path, "block"), mismatches);
+    }
+}
+
+pub type BooleanBlock<PBSOrder> = BaseBooleanBlock<CiphertextBase<PBSOrder>>;
+pub type BooleanBlockBig = BaseBooleanBlock<CiphertextBig>;
+pub type BooleanBlockSmall = BaseBooleanBlock<CiphertextSmall>;
+
+impl<PBSOrder: PBSOrderMarker> BooleanBlock<PBSOrder> {
+    /// Wraps a shortint block into a [`BooleanBlock`].
+    ///
+    /// The block is not checked, it is the responsibility of the caller to make sure it encrypts
+    /// either 0 or 1 and has a degree of at most 1.
+    pub fn new_unchecked(block: CiphertextBase<PBSOrder>) -> Self {
+        Self { block }
+    }
+
+    /// Returns a reference to the underlying shortint block.
+    pub fn as_block(&self) -> &CiphertextBase<PBSOrder> {
+        &self.block
+    }
+
+    /// Returns the underlying shortint block.
+    pub fn into_block(self) -> CiphertextBase<PBSOrder> {
+        self.block
+    }
+}
+
+/// Structure containing a single shortint block encrypting an [`Ordering`](std::cmp::Ordering):
+/// 0 for `Less`, 1 for `Equal` and 2 for `Greater`.
+///
+/// Such blocks are produced by
+/// [`ServerKey::cmp_parallelized`](crate::integer::ServerKey::cmp_parallelized), and can be used
+/// to select between three ciphertexts with
+/// [`ServerKey::select_by_ordering_parallelized`](crate::integer::ServerKey::select_by_ordering_parallelized).
+#[derive(Serialize, Clone, Deserialize, Debug)]
+pub struct BaseOrderingBlock<Block> {
+    pub(crate) block: Block,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl[Block: FheSize] for BaseOrderingBlock<Block> { block });
+
+impl<Block: StructuralDiff> StructuralDiff for BaseOrderingBlock<Block> {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        self.block
+            .diff_into(&other.block, &field_path(path, "block"), mismatches);
+    }
+}
+
+pub type OrderingBlock<PBSOrder> = BaseOrderingBlock<CiphertextBase<PBSOrder>>;
+pub type OrderingBlockBig = BaseOrderingBlock<CiphertextBig>;
+pub type OrderingBlockSmall = BaseOrderingBlock<CiphertextSmall>;
+
+impl<PBSOrder: PBSOrderMarker> OrderingBlock<PBSOrder> {
+    /// Wraps a shortint block into an [`OrderingBlock`].
+    ///
+    /// The block is not checked, it is the responsibility of the caller to make sure it encrypts
+    /// either 0, 1 or 2 and has a degree of at most 2.
+    pub fn new_unchecked(block: CiphertextBase<PBSOrder>) -> Self {
+        Self { block }
+    }
+
+    /// Returns a reference to the underlying shortint block.
+    pub fn as_block(&self) -> &CiphertextBase<PBSOrder> {
+        &self.block
+    }
+
+    /// Returns the underlying shortint block.
+    pub fn into_block(self) -> CiphertextBase<PBSOrder> {
+        self.block
+    }
+}
+
 pub trait IntegerCiphertext: Clone {
     type PBSOrder: PBSOrderMarker;
 
@@ -125,12 +459,60 @@ impl IntegerCiphertext for CrtCiphertext {
 ///
 /// For this CRT decomposition, each block is encrypted using
 /// the same parameters.
-#[derive(Serialize, Clone, Deserialize)]
+#[derive(Serialize, Clone, Deserialize, Debug)]
 pub struct BaseCrtCiphertext<Block> {
     pub(crate) blocks: Vec<Block>,
     pub(crate) moduli: Vec<u64>,
 }
 
+impl<Block: StructuralDiff> StructuralDiff for BaseCrtCiphertext<Block> {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        diff_field(path, "moduli", &self.moduli, &other.moduli, mismatches);
+        self.blocks
+            .diff_into(&other.blocks, &field_path(path, "blocks"), mismatches);
+    }
+}
+
+impl<Block: FheSize> FheSize for BaseCrtCiphertext<Block> {
+    fn element_count(&self) -> usize {
+        self.blocks.element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.blocks.heap_size() + self.moduli.len() * std::mem::size_of::<u64>()
+    }
+}
+
+impl<Block> StructInPlace for BaseCrtCiphertext<Block>
+where
+    Block: DeserializeInPlace + DeserializeOwned,
+{
+    const NAME: &'static str = "BaseCrtCiphertext";
+    const FIELDS: &'static [&'static str] = &["blocks", "moduli"];
+
+    fn deserialize_field<'de, D>(&mut self, index: usize, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match index {
+            0 => self.blocks.deserialize_in_place(deserializer),
+            _ => self.moduli.deserialize_in_place(deserializer),
+        }
+    }
+}
+
+impl<Block> DeserializeInPlace for BaseCrtCiphertext<Block>
+where
+    Block: DeserializeInPlace + DeserializeOwned,
+{
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_struct_in_place(self, deserializer)
+    }
+}
+
 /// Structure containing a ciphertext in CRT decomposition.
 pub type CrtCiphertext = BaseCrtCiphertext<CiphertextBig>;
 
@@ -154,3 +536,69 @@ impl From<CompressedCrtCiphertext> for CrtCiphertext {
         Self::from((blocks, moduli))
     }
 }
+
+/// Structure containing a list of ciphertexts in radix decomposition, all having the same number
+/// of blocks, encrypted with a [`CompactPublicKey`](crate::integer::CompactPublicKey).
+///
+/// The blocks of all the ciphertexts are stored in a single shortint
+/// [`CompactCiphertextList`](crate::shortint::CompactCiphertextList).
+#[derive(Serialize, Clone, Deserialize)]
+pub struct CompactCiphertextList {
+    pub(crate) ct_list: crate::shortint::CompactCiphertextList,
+    // Keep track of the num_blocks, as we allow
+    // storing many integer that have the same num_blocks
+    // into ct_list
+    pub(crate) num_blocks: usize,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for CompactCiphertextList { ct_list });
+
+impl CompactCiphertextList {
+    /// Expand the list into the [`RadixCiphertextBig`] it contains, in the order in which the
+    /// messages were encrypted.
+    pub fn expand(&self) -> Vec<RadixCiphertextBig> {
+        let mut all_block_iter = self.ct_list.expand().into_iter();
+        let num_ct = self.ciphertext_count();
+        let mut ciphertexts = Vec::with_capacity(num_ct);
+
+        for _ in 0..num_ct {
+            let ct_blocks = all_block_iter
+                .by_ref()
+                .take(self.num_blocks)
+                .collect::<Vec<_>>();
+            if ct_blocks.len() < self.num_blocks {
+                break;
+            }
+            ciphertexts.push(RadixCiphertextBig::from(ct_blocks));
+        }
+
+        ciphertexts
+    }
+
+    /// Expand a list containing a single ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list does not contain exactly one ciphertext.
+    pub fn expand_one(&self) -> RadixCiphertextBig {
+        assert_eq!(
+            self.ciphertext_count(),
+            1,
+            "Expected a list containing exactly one ciphertext"
+        );
+        RadixCiphertextBig::from(self.ct_list.expand())
+    }
+
+    /// Return the number of radix ciphertexts in the list.
+    pub fn ciphertext_count(&self) -> usize {
+        if self.num_blocks == 0 {
+            return 0;
+        }
+        self.ct_list.len() / self.num_blocks
+    }
+
+    /// Return the number of blocks of each radix ciphertext in the list.
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+}
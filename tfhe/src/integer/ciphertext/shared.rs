@@ -0,0 +1,118 @@
+//! Radix ciphertexts with copy-on-write blocks.
+//!
+//! The blocks of a [`SharedRadixCiphertext`] are reference counted: cloning the ciphertext only
+//! clones the references, and a block is only copied when it is modified while still being shared
+//! with another ciphertext. The operations which only modify some blocks (e.g.
+//! [`ServerKey::scalar_add_shared`](crate::integer::ServerKey::scalar_add_shared) with a scalar
+//! smaller than the integer) leave the other blocks shared with their input, which saves memory
+//! and copies for wide integers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::ciphertext::shared::SharedRadixCiphertext;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 8;
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let ct = SharedRadixCiphertext::from(cks.encrypt(1u64));
+//!
+//! // Only the first block is modified, the other ones are shared with `ct`
+//! let ct_res = sks.unchecked_scalar_add_shared(&ct, 2);
+//! assert!(!ct_res.shares_block_with(&ct, 0));
+//! assert!((1..num_blocks).all(|i| ct_res.shares_block_with(&ct, i)));
+//!
+//! let dec: u64 = cks.decrypt(&ct_res.into_radix());
+//! assert_eq!(dec, 3);
+//! ```
+use super::RadixCiphertext;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use std::sync::Arc;
+
+/// A radix ciphertext whose blocks are shared between its clones.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone, Debug)]
+pub struct SharedRadixCiphertext<OpOrder: PBSOrderMarker> {
+    /// The blocks are stored from LSB to MSB
+    blocks: Vec<Arc<CiphertextBase<OpOrder>>>,
+}
+
+impl<OpOrder: PBSOrderMarker> SharedRadixCiphertext<OpOrder> {
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the block at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn block(&self, index: usize) -> &CiphertextBase<OpOrder> {
+        &self.blocks[index]
+    }
+
+    /// Return the block at `index` for modification, copying it first if it is shared with another
+    /// ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn block_mut(&mut self, index: usize) -> &mut CiphertextBase<OpOrder> {
+        Arc::make_mut(&mut self.blocks[index])
+    }
+
+    /// Iterate over the blocks, from LSB to MSB.
+    pub fn blocks(&self) -> impl Iterator<Item = &CiphertextBase<OpOrder>> + '_ {
+        self.blocks.iter().map(|block| block.as_ref())
+    }
+
+    /// Return whether the block at `index` is the same allocation in `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds of one of the ciphertexts.
+    pub fn shares_block_with(&self, other: &Self, index: usize) -> bool {
+        Arc::ptr_eq(&self.blocks[index], &other.blocks[index])
+    }
+
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.blocks.iter().all(|block| block.carry_is_empty())
+    }
+
+    /// Return a radix ciphertext with a copy of the blocks.
+    pub fn to_radix(&self) -> RadixCiphertext<OpOrder> {
+        RadixCiphertext::from(
+            self.blocks
+                .iter()
+                .map(|block| block.as_ref().clone())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Convert into a radix ciphertext, only copying the blocks shared with another ciphertext.
+    pub fn into_radix(self) -> RadixCiphertext<OpOrder> {
+        RadixCiphertext::from(
+            self.blocks
+                .into_iter()
+                .map(|block| Arc::try_unwrap(block).unwrap_or_else(|block| block.as_ref().clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> From<RadixCiphertext<OpOrder>> for SharedRadixCiphertext<OpOrder> {
+    fn from(ct: RadixCiphertext<OpOrder>) -> Self {
+        Self {
+            blocks: ct.blocks.into_iter().map(Arc::new).collect(),
+        }
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> From<SharedRadixCiphertext<OpOrder>> for RadixCiphertext<OpOrder> {
+    fn from(ct: SharedRadixCiphertext<OpOrder>) -> Self {
+        ct.into_radix()
+    }
+}
@@ -1,5 +1,6 @@
 use super::ClientKey;
 use crate::integer::CrtCiphertext;
+use crate::size::FheSize;
 
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +33,16 @@ pub struct CrtClientKey {
     moduli: Vec<u64>,
 }
 
+impl FheSize for CrtClientKey {
+    fn element_count(&self) -> usize {
+        self.key.element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.key.heap_size() + self.moduli.len() * std::mem::size_of::<u64>()
+    }
+}
+
 impl AsRef<ClientKey> for CrtClientKey {
     fn as_ref(&self) -> &ClientKey {
         &self.key
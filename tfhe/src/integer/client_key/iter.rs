@@ -0,0 +1,225 @@
+//! Lazy encryption of streams of clear values.
+//!
+//! Encrypting a dataset of millions of values with [`ClientKey::encrypt_radix`] in a loop and
+//! collecting the result requires the whole encrypted dataset to be in memory at once. The
+//! iterators of this module encrypt values on demand instead, so that ciphertexts can be
+//! written out (or sent) as soon as they are produced.
+//!
+//! The encryptions go through the thread local shortint engine of the thread doing them, so the
+//! random generators and buffers of that engine are reused for all the values of the stream.
+use super::{ClientKey, RadixClientKey};
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::RadixCiphertextBig;
+use rayon::prelude::*;
+
+/// Iterator encrypting the values of another iterator one at a time.
+///
+/// Returned by [`ClientKey::encrypt_iter`].
+pub struct EncryptIter<'a, I> {
+    key: &'a ClientKey,
+    messages: I,
+    num_blocks: usize,
+}
+
+impl<'a, T, I> Iterator for EncryptIter<'a, I>
+where
+    T: AsLittleEndianWords,
+    I: Iterator<Item = T>,
+{
+    type Item = RadixCiphertextBig;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.messages
+            .next()
+            .map(|message| self.key.encrypt_radix(message, self.num_blocks))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.messages.size_hint()
+    }
+}
+
+/// Iterator encrypting the values of another iterator by chunks, the values of a chunk being
+/// encrypted in parallel.
+///
+/// At most `chunk_size` clear values and `chunk_size` ciphertexts are held at the same time.
+///
+/// Returned by [`ClientKey::par_encrypt_iter`].
+pub struct ParEncryptIter<'a, T, I> {
+    key: &'a ClientKey,
+    messages: I,
+    num_blocks: usize,
+    chunk_size: usize,
+    // Reused between chunks
+    clear_chunk: Vec<T>,
+    encrypted_chunk: std::vec::IntoIter<RadixCiphertextBig>,
+}
+
+impl<'a, T, I> ParEncryptIter<'a, T, I>
+where
+    T: AsLittleEndianWords + Send,
+    I: Iterator<Item = T>,
+{
+    /// Encrypt the next chunk of values, returns it as a whole.
+    ///
+    /// Values of a chunk partially consumed through [`Iterator::next`] are returned first.
+    pub fn next_batch(&mut self) -> Option<Vec<RadixCiphertextBig>> {
+        let pending = std::mem::replace(&mut self.encrypted_chunk, Vec::new().into_iter())
+            .collect::<Vec<_>>();
+        if !pending.is_empty() {
+            return Some(pending);
+        }
+
+        self.clear_chunk.clear();
+        self.clear_chunk
+            .extend(self.messages.by_ref().take(self.chunk_size));
+        if self.clear_chunk.is_empty() {
+            return None;
+        }
+
+        let key = self.key;
+        let num_blocks = self.num_blocks;
+        let mut encrypted = Vec::with_capacity(self.clear_chunk.len());
+        self.clear_chunk
+            .par_drain(..)
+            .map(|message| key.encrypt_radix(message, num_blocks))
+            .collect_into_vec(&mut encrypted);
+
+        Some(encrypted)
+    }
+}
+
+impl<'a, T, I> Iterator for ParEncryptIter<'a, T, I>
+where
+    T: AsLittleEndianWords + Send,
+    I: Iterator<Item = T>,
+{
+    type Item = RadixCiphertextBig;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ct) = self.encrypted_chunk.next() {
+            return Some(ct);
+        }
+
+        self.encrypted_chunk = self.next_batch()?.into_iter();
+        self.encrypted_chunk.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.encrypted_chunk.len();
+        let (lower, upper) = self.messages.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl ClientKey {
+    /// Lazily encrypts each value of `messages` in radix decomposition.
+    ///
+    /// Values are only encrypted when the returned iterator is advanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_blocks = 4;
+    ///
+    /// let cts = cks.encrypt_iter(0..10u64, num_blocks);
+    ///
+    /// for (expected, ct) in cts.enumerate() {
+    ///     let dec: u64 = cks.decrypt_radix(&ct);
+    ///     assert_eq!(dec, expected as u64);
+    /// }
+    /// ```
+    pub fn encrypt_iter<T, I>(&self, messages: I, num_blocks: usize) -> EncryptIter<'_, I::IntoIter>
+    where
+        T: AsLittleEndianWords,
+        I: IntoIterator<Item = T>,
+    {
+        EncryptIter {
+            key: self,
+            messages: messages.into_iter(),
+            num_blocks,
+        }
+    }
+
+    /// Lazily encrypts each value of `messages` in radix decomposition, `chunk_size` values at a
+    /// time in parallel.
+    ///
+    /// The ciphertexts are yielded in the same order as the values, either one at a time with
+    /// [`Iterator::next`] or a chunk at a time with [`ParEncryptIter::next_batch`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_blocks = 4;
+    ///
+    /// let mut cts = cks.par_encrypt_iter(0..10u64, num_blocks, 4);
+    ///
+    /// let first_chunk = cts.next_batch().unwrap();
+    /// assert_eq!(first_chunk.len(), 4);
+    ///
+    /// let dec: u64 = cks.decrypt_radix(&cts.next().unwrap());
+    /// assert_eq!(dec, 4);
+    /// assert_eq!(cts.count(), 5);
+    /// ```
+    pub fn par_encrypt_iter<T, I>(
+        &self,
+        messages: I,
+        num_blocks: usize,
+        chunk_size: usize,
+    ) -> ParEncryptIter<'_, T, I::IntoIter>
+    where
+        T: AsLittleEndianWords + Send,
+        I: IntoIterator<Item = T>,
+    {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        ParEncryptIter {
+            key: self,
+            messages: messages.into_iter(),
+            num_blocks,
+            chunk_size,
+            clear_chunk: Vec::with_capacity(chunk_size),
+            encrypted_chunk: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl RadixClientKey {
+    /// Lazily encrypts each value of `messages`, see [`ClientKey::encrypt_iter`].
+    pub fn encrypt_iter<T, I>(&self, messages: I) -> EncryptIter<'_, I::IntoIter>
+    where
+        T: AsLittleEndianWords,
+        I: IntoIterator<Item = T>,
+    {
+        self.as_ref().encrypt_iter(messages, self.num_blocks())
+    }
+
+    /// Lazily encrypts each value of `messages` by chunks of `chunk_size` values encrypted in
+    /// parallel, see [`ClientKey::par_encrypt_iter`].
+    pub fn par_encrypt_iter<T, I>(
+        &self,
+        messages: I,
+        chunk_size: usize,
+    ) -> ParEncryptIter<'_, T, I::IntoIter>
+    where
+        T: AsLittleEndianWords + Send,
+        I: IntoIterator<Item = T>,
+    {
+        self.as_ref()
+            .par_encrypt_iter(messages, self.num_blocks(), chunk_size)
+    }
+}
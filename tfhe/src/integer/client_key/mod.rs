@@ -4,15 +4,20 @@
 //! These are private and **MUST NOT** be shared.
 
 mod crt;
+mod iter;
 mod radix;
 pub(crate) mod utils;
 
+#[cfg(test)]
+mod tests;
+
 use crate::integer::ciphertext::{
     CompressedCrtCiphertext, CompressedRadixCiphertextBig, CrtCiphertext, RadixCiphertextBig,
     RadixCiphertextSmall,
 };
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl, AsLittleEndianWords};
+use crate::integer::RadixConfig;
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey as ShortintClientKey, PBSOrderMarker,
@@ -22,9 +27,10 @@ use serde::{Deserialize, Serialize};
 pub use utils::radix_decomposition;
 
 pub use crt::CrtClientKey;
+pub use iter::{EncryptIter, ParEncryptIter};
 pub use radix::RadixClientKey;
 
-use super::ciphertext::RadixCiphertext;
+use super::ciphertext::{BooleanBlock, OrderingBlock, RadixCiphertext};
 use super::CompressedRadixCiphertextSmall;
 
 /// A structure containing the client key, which must be kept secret.
@@ -39,6 +45,8 @@ pub struct ClientKey {
     pub(crate) key: ShortintClientKey,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for ClientKey { key });
+
 impl From<ShortintClientKey> for ClientKey {
     fn from(key: ShortintClientKey) -> Self {
         Self { key }
@@ -109,6 +117,44 @@ impl ClientKey {
         self.encrypt_words_radix(message, num_blocks, crate::shortint::ClientKey::encrypt)
     }
 
+    /// Encrypts an integer in radix decomposition, with the number of blocks of the
+    /// [`RadixConfig`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message is not in the range of the configuration, or if the configuration
+    /// was created for another message modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, IntegerCiphertext, RadixConfig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let config = RadixConfig::for_range(0..=255, PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt_with_range(167u64, &config);
+    /// assert_eq!(ct.blocks().len(), 4);
+    ///
+    /// let dec: u64 = cks.decrypt_radix(&ct);
+    /// assert_eq!(dec, 167);
+    /// ```
+    pub fn encrypt_with_range(&self, message: u64, config: &RadixConfig) -> RadixCiphertextBig {
+        assert!(
+            config.contains(message),
+            "The message {message} is not in the range [{}, {}]",
+            config.min_value(),
+            config.max_value()
+        );
+        assert_eq!(
+            config.parameters().message_modulus,
+            self.parameters().message_modulus,
+            "The configuration was created for another message modulus"
+        );
+        self.encrypt_radix(message, config.num_blocks())
+    }
+
     /// Encrypts an integer in radix decomposition without padding bit
     ///
     /// # Example
@@ -261,6 +307,29 @@ impl ClientKey {
         self.key.decrypt(ct)
     }
 
+    /// Decrypts a [`BooleanBlock`].
+    pub fn decrypt_bool<PBSOrder: PBSOrderMarker>(&self, ct: &BooleanBlock<PBSOrder>) -> bool {
+        self.key.decrypt(&ct.block) != 0
+    }
+
+    /// Decrypts an [`OrderingBlock`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not encrypt 0, 1 or 2.
+    pub fn decrypt_ordering<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OrderingBlock<PBSOrder>,
+    ) -> std::cmp::Ordering {
+        // The value 2 does not fit in the message of parameters with a single bit of message
+        match self.key.decrypt_message_and_carry(&ct.block) {
+            0 => std::cmp::Ordering::Less,
+            1 => std::cmp::Ordering::Equal,
+            2 => std::cmp::Ordering::Greater,
+            value => panic!("The block does not encrypt an ordering, got {value}"),
+        }
+    }
+
     /// Decrypts a ciphertext encrypting an radix integer
     ///
     /// # Example
@@ -336,6 +405,76 @@ impl ClientKey {
         res
     }
 
+    /// Decrypts a ciphertext encrypting a radix integer and returns only the `bits` most
+    /// significant bits of its value.
+    ///
+    /// Only the blocks holding these bits are decrypted, unless a lower block may have a non
+    /// empty carry (according to its degree), in which case decryption starts from the lowest
+    /// such block so that its carry is propagated to the returned bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is larger than 64 or than the number of bits of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_block = 4;
+    ///
+    /// let msg = 191_u64;
+    /// let ct = cks.encrypt_radix(msg, num_block);
+    ///
+    /// // The 3 most significant bits of the 8 bits value
+    /// let dec = cks.decrypt_radix_truncated(&ct, 3);
+    /// assert_eq!(dec, msg >> 5);
+    /// ```
+    pub fn decrypt_radix_truncated<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        bits: usize,
+    ) -> u64 {
+        let message_modulus = self.key.parameters.message_modulus.0;
+        let bits_per_block = (message_modulus as f64).log2() as usize;
+        let total_bits = bits_per_block * ctxt.blocks.len();
+        assert!(
+            bits <= 64 && bits <= total_bits,
+            "Cannot keep {bits} bits of a {total_bits} bits ciphertext"
+        );
+
+        let lowest_bit = total_bits - bits;
+        let first_block = lowest_bit / bits_per_block;
+
+        // Lower blocks only matter through their carries
+        let start_block = ctxt.blocks[..first_block]
+            .iter()
+            .position(|block| block.degree.0 >= message_modulus)
+            .unwrap_or(first_block);
+
+        let mut carry = 0u64;
+        let mut value = 0u128;
+        for (i, block) in ctxt.blocks.iter().enumerate().skip(start_block) {
+            let block_value = self.key.decrypt_message_and_carry(block) + carry;
+            carry = block_value / message_modulus as u64;
+
+            if i >= first_block {
+                let digit = (block_value % message_modulus as u64) as u128;
+                value += digit << ((i - first_block) * bits_per_block);
+            }
+        }
+
+        let value = value >> (lowest_bit - first_block * bits_per_block);
+        let mask = if bits == 64 {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        };
+        value as u64 & mask
+    }
+
     /// Decrypts a ciphertext in radix decomposition into 64bits
     ///
     /// The words are assumed to be in little endian order.
@@ -1,7 +1,7 @@
 //! Definition of the client key for radix decomposition
 
 use super::ClientKey;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{BooleanBlock, OrderingBlock, RadixCiphertext};
 use crate::integer::encryption::AsLittleEndianWords;
 use crate::integer::{RadixCiphertextBig, RadixCiphertextSmall};
 use crate::shortint::{
@@ -40,6 +40,8 @@ pub struct RadixClientKey {
     num_blocks: usize,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for RadixClientKey { key });
+
 impl AsRef<ClientKey> for RadixClientKey {
     fn as_ref(&self) -> &ClientKey {
         &self.key
@@ -86,6 +88,17 @@ impl RadixClientKey {
         self.key.decrypt_one_block(ct)
     }
 
+    pub fn decrypt_bool<PBSOrder: PBSOrderMarker>(&self, ct: &BooleanBlock<PBSOrder>) -> bool {
+        self.key.decrypt_bool(ct)
+    }
+
+    pub fn decrypt_ordering<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &OrderingBlock<PBSOrder>,
+    ) -> std::cmp::Ordering {
+        self.key.decrypt_ordering(ct)
+    }
+
     pub fn num_blocks(&self) -> usize {
         self.num_blocks
     }
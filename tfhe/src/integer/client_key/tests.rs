@@ -0,0 +1,112 @@
+use rand::Rng;
+
+use crate::integer::keycache::KEY_CACHE;
+use crate::shortint::parameters::*;
+use crate::shortint::Parameters;
+
+const NB_VALUES: usize = 30;
+const NB_BLOCKS: usize = 4;
+
+create_parametrized_test!(encrypt_iter_decrypt {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(par_encrypt_iter_decrypt {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(decrypt_radix_truncated {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2
+});
+
+fn encrypt_iter_decrypt(param: Parameters) {
+    let (cks, _) = KEY_CACHE.get_from_params(param);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (param.message_modulus.0 as u64).pow(NB_BLOCKS as u32);
+
+    let clears = (0..NB_VALUES)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let cts = cks.encrypt_iter(clears.iter().copied(), NB_BLOCKS);
+    assert_eq!(cts.size_hint(), (NB_VALUES, Some(NB_VALUES)));
+
+    let mut count = 0;
+    for (clear, ct) in clears.iter().zip(cts) {
+        assert_eq!(ct.blocks.len(), NB_BLOCKS);
+        let dec: u64 = cks.decrypt_radix(&ct);
+        assert_eq!(*clear, dec);
+        count += 1;
+    }
+    assert_eq!(count, NB_VALUES);
+}
+
+fn par_encrypt_iter_decrypt(param: Parameters) {
+    let (cks, _) = KEY_CACHE.get_from_params(param);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (param.message_modulus.0 as u64).pow(NB_BLOCKS as u32);
+
+    let clears = (0..NB_VALUES)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    // The last chunk is smaller than the others
+    let chunk_size = 7;
+    let mut cts = cks.par_encrypt_iter(clears.iter().copied(), NB_BLOCKS, chunk_size);
+
+    let first = cts.next().unwrap();
+    let dec: u64 = cks.decrypt_radix(&first);
+    assert_eq!(clears[0], dec);
+
+    // The remaining of the first chunk is returned before the next one
+    let rest_of_first_chunk = cts.next_batch().unwrap();
+    assert_eq!(rest_of_first_chunk.len(), chunk_size - 1);
+
+    let mut decrypted = vec![dec];
+    decrypted.extend(
+        rest_of_first_chunk
+            .iter()
+            .chain(cts.by_ref().collect::<Vec<_>>().iter())
+            .map(|ct| cks.decrypt_radix::<u64, _>(ct)),
+    );
+    assert_eq!(decrypted, clears);
+    assert!(cts.next_batch().is_none());
+}
+
+fn decrypt_radix_truncated(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    let mut rng = rand::thread_rng();
+    let bits_per_block = (param.message_modulus.0 as f64).log2() as usize;
+    let total_bits = bits_per_block * NB_BLOCKS;
+    let modulus = 1u64 << total_bits;
+
+    for _ in 0..NB_VALUES {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt_radix(clear_0, NB_BLOCKS);
+        // Without carry propagation, the lower blocks may hold carries
+        let ct_sum = sks.unchecked_add(&ct, &cks.encrypt_radix(clear_1, NB_BLOCKS));
+        let sum = (clear_0 + clear_1) % modulus;
+
+        for bits in 0..=total_bits {
+            let shift = total_bits - bits;
+            assert_eq!(cks.decrypt_radix_truncated(&ct, bits), clear_0 >> shift);
+            assert_eq!(cks.decrypt_radix_truncated(&ct_sum, bits), sum >> shift);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "is not in the range")]
+fn encrypt_with_range_rejects_values_out_of_range() {
+    let (cks, _) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2);
+    let config = crate::integer::RadixConfig::for_range(0..=255, PARAM_MESSAGE_2_CARRY_2);
+
+    assert_eq!(cks.encrypt_with_range(255u64, &config).blocks.len(), 4);
+    let _ = cks.encrypt_with_range(256u64, &config);
+}
@@ -135,6 +135,12 @@ impl<OpOrder: crate::shortint::PBSOrderMarker> KnowsMessageModulus
     }
 }
 
+impl KnowsMessageModulus for crate::shortint::CompactPublicKey {
+    fn message_modulus(&self) -> MessageModulus {
+        self.parameters.message_modulus
+    }
+}
+
 impl KnowsMessageModulus for crate::shortint::ServerKey {
     fn message_modulus(&self) -> MessageModulus {
         self.message_modulus
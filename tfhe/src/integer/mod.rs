@@ -52,24 +52,34 @@ extern crate core;
 mod tests;
 pub(crate) mod encryption;
 
+pub mod cancellation;
 pub mod ciphertext;
 pub mod client_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod parameters;
+pub mod permutation;
 pub mod public_key;
+pub mod radix_config;
 pub mod server_key;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 pub mod u256;
 pub mod wopbs;
 
+pub use cancellation::{CancellationToken, Cancelled};
 pub use ciphertext::{
-    CompressedRadixCiphertextBig, CompressedRadixCiphertextSmall, CrtCiphertext, IntegerCiphertext,
-    RadixCiphertextBig, RadixCiphertextSmall,
+    BooleanBlockBig, BooleanBlockSmall, CompactCiphertextList, CompressedRadixCiphertextBig,
+    CompressedRadixCiphertextSmall, CrtCiphertext, IntegerCiphertext, OrderingBlockBig,
+    OrderingBlockSmall, RadixCiphertextBig, RadixCiphertextSmall,
 };
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
+pub use permutation::{EncryptedPermutationBig, EncryptedPermutationSmall};
 pub use public_key::{
-    CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBig, PublicKeySmall,
+    CompactPublicKey, CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBig,
+    PublicKeySmall,
 };
+pub use radix_config::{RadixConfig, RadixOpProfile};
 pub use server_key::{CheckError, ServerKey};
 pub use u256::U256;
 
@@ -102,6 +112,41 @@ pub fn gen_keys(
     }
 }
 
+/// Generate `count` couples of client and server keys in parallel, e.g. to enroll many clients at
+/// once.
+///
+/// See [`crate::shortint::gen_keys_batch`] for the work shared between the generations.
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_batch;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let keys = gen_keys_batch(&PARAM_MESSAGE_2_CARRY_2, 2).unwrap();
+/// assert_eq!(keys.len(), 2);
+/// ```
+pub fn gen_keys_batch(
+    parameters_set: &crate::shortint::parameters::Parameters,
+    count: usize,
+) -> Result<Vec<(ClientKey, ServerKey)>, crate::shortint::parameters::ParametersError> {
+    use rayon::prelude::*;
+
+    parameters_set.validate()?;
+
+    // Plans are cached globally, computing it here avoids all the generations racing to build it
+    let _ =
+        crate::core_crypto::fft_impl::fft64::math::fft::Fft::new(parameters_set.polynomial_size);
+
+    Ok((0..count)
+        .into_par_iter()
+        .map(|_| {
+            let cks = ClientKey::new(*parameters_set);
+            let sks = ServerKey::new(&cks);
+
+            (cks, sks)
+        })
+        .collect())
+}
+
 /// Generate a couple of client and server keys with given parameters
 ///
 /// Contrary to [gen_keys], this returns a [RadixClientKey]
@@ -1,7 +1,7 @@
 #![allow(clippy::excessive_precision)]
 pub use crate::shortint::{CiphertextModulus, Parameters};
 
-use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::parameters::{CarryModulus, MessageModulus, NoiseDistributionKind};
 pub use crate::shortint::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
     LweDimension, PolynomialSize, StandardDev,
@@ -18,6 +18,7 @@ pub const PARAM_MESSAGE_4_CARRY_4_16_BITS: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -38,6 +39,7 @@ pub const PARAM_MESSAGE_2_CARRY_2_16_BITS: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -58,6 +60,7 @@ pub const PARAM_MESSAGE_4_CARRY_4_32_BITS: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -78,6 +81,7 @@ pub const PARAM_MESSAGE_2_CARRY_2_32_BITS: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -98,6 +102,7 @@ pub const PARAM_MESSAGE_1_CARRY_1_32_BITS: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -118,6 +123,7 @@ pub const PARAM_4_BITS_5_BLOCKS: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.0000000004168323308734758),
     glwe_modular_std_dev: StandardDev(0.00000000000000000000000000000004905643852600863),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(7),
     pbs_level: DecompositionLevelCount(6),
     ks_base_log: DecompositionBaseLog(1),
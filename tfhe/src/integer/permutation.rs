@@ -0,0 +1,321 @@
+//! Permutations of vectors of radix ciphertexts.
+//!
+//! A permutation of `n` elements is given as a slice `permutation` of the indices `0..n`, each
+//! appearing once: after being permuted, the element at index `i` is the element which was at
+//! index `permutation[i]`.
+//!
+//! [`ServerKey::permute`](crate::integer::ServerKey::permute) applies a clear permutation, which
+//! only moves the ciphertexts. To hide the permutation from the server, the client encrypts it
+//! with [`ClientKey::encrypt_permutation`] and the server applies it with
+//! [`ServerKey::oblivious_permute_parallelized`](crate::integer::ServerKey::oblivious_permute_parallelized).
+//!
+//! An encrypted permutation is a Benes network: `2 * log2(n) - 1` layers of switches, each switch
+//! exchanging two ciphertexts or not depending on an encrypted boolean. Any permutation can be
+//! routed through the network and all the permutations of `n` elements use the same switches, so
+//! the server learns nothing about the permutation. Sizes which are not a power of two are padded
+//! to the next power of two.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 2;
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+//!
+//! let clears = [3u64, 7, 11];
+//! let permutation = [2, 0, 1];
+//!
+//! let mut cts = clears.map(|clear| cks.encrypt(clear));
+//! let encrypted_permutation = cks.as_ref().encrypt_permutation(&permutation);
+//!
+//! sks.oblivious_permute_parallelized(&mut cts, &encrypted_permutation);
+//!
+//! let res: Vec<u64> = cts.iter().map(|ct| cks.decrypt(ct)).collect();
+//! assert_eq!(res, vec![11, 3, 7]);
+//! ```
+use crate::integer::ciphertext::BooleanBlock;
+use crate::integer::ClientKey;
+use crate::shortint::ciphertext::{BootstrapKeyswitch, Degree, KeyswitchBootstrap};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A permutation hidden in the encrypted switches of a Benes network, see the
+/// [module documentation](self).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EncryptedPermutation<PBSOrder: PBSOrderMarker> {
+    pub(crate) len: usize,
+    pub(crate) switches: Vec<BooleanBlock<PBSOrder>>,
+}
+
+pub type EncryptedPermutationBig = EncryptedPermutation<KeyswitchBootstrap>;
+pub type EncryptedPermutationSmall = EncryptedPermutation<BootstrapKeyswitch>;
+
+impl<PBSOrder: PBSOrderMarker> EncryptedPermutation<PBSOrder> {
+    /// Returns the number of elements the permutation applies to.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl ClientKey {
+    /// Encrypts a permutation of `permutation.len()` elements, to be applied by the server
+    /// without learning it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not a permutation of `0..permutation.len()`.
+    pub fn encrypt_permutation(&self, permutation: &[usize]) -> EncryptedPermutationBig {
+        self.encrypt_permutation_impl(permutation, |bit| self.key.encrypt(bit))
+    }
+
+    /// Encrypts a permutation of `permutation.len()` elements, to be applied by the server
+    /// without learning it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not a permutation of `0..permutation.len()`.
+    pub fn encrypt_permutation_small(&self, permutation: &[usize]) -> EncryptedPermutationSmall {
+        self.encrypt_permutation_impl(permutation, |bit| self.key.encrypt_small(bit))
+    }
+
+    fn encrypt_permutation_impl<PBSOrder, F>(
+        &self,
+        permutation: &[usize],
+        encrypt: F,
+    ) -> EncryptedPermutation<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64) -> CiphertextBase<PBSOrder> + Sync,
+    {
+        check_permutation(permutation);
+
+        let switches = benes_switches(&padded_permutation(permutation))
+            .into_par_iter()
+            .map(|crossed| {
+                let mut block = encrypt(crossed as u64);
+                block.degree = Degree(1);
+                BooleanBlock::new_unchecked(block)
+            })
+            .collect();
+
+        EncryptedPermutation {
+            len: permutation.len(),
+            switches,
+        }
+    }
+}
+
+/// Panics if `permutation` is not a permutation of `0..permutation.len()`.
+pub(crate) fn check_permutation(permutation: &[usize]) {
+    let mut seen = vec![false; permutation.len()];
+    for &index in permutation {
+        assert!(
+            index < permutation.len() && !seen[index],
+            "{permutation:?} is not a permutation of 0..{}",
+            permutation.len()
+        );
+        seen[index] = true;
+    }
+}
+
+/// Extends a permutation to the next power of two, the padding elements staying in place.
+fn padded_permutation(permutation: &[usize]) -> Vec<usize> {
+    let padded_len = permutation.len().next_power_of_two();
+    permutation
+        .iter()
+        .copied()
+        .chain(permutation.len()..padded_len)
+        .collect()
+}
+
+/// Number of switches of the Benes network of `len` elements, `len` being a power of two.
+pub(crate) fn switch_count(len: usize) -> usize {
+    match len {
+        0 | 1 => 0,
+        2 => 1,
+        // A layer of switches on each side of two networks of half the size
+        _ => len + 2 * switch_count(len / 2),
+    }
+}
+
+/// Computes whether each switch of the Benes network of `permutation` is crossed, `true` meaning
+/// that the two elements going through the switch are exchanged.
+///
+/// The switches of a network of `n` elements are listed as: the `n / 2` input switches, the
+/// switches of the top subnetwork, the switches of the bottom subnetwork and the `n / 2` output
+/// switches. The input switch `j` takes the elements `2j` and `2j + 1` and sends its first output
+/// to the input `j` of the top subnetwork and the second one to the input `j` of the bottom
+/// subnetwork; the output switch `j` takes the outputs `j` of the subnetworks.
+fn benes_switches(permutation: &[usize]) -> Vec<bool> {
+    let mut switches = Vec::with_capacity(switch_count(permutation.len()));
+    route(permutation, &mut switches);
+    switches
+}
+
+fn route(permutation: &[usize], switches: &mut Vec<bool>) {
+    let len = permutation.len();
+    if len < 2 {
+        return;
+    }
+    if len == 2 {
+        switches.push(permutation[0] == 1);
+        return;
+    }
+
+    let half = len / 2;
+    let mut inverse = vec![0; len];
+    for (output, &input) in permutation.iter().enumerate() {
+        inverse[input] = output;
+    }
+
+    // The two inputs of an input switch must go through different subnetworks, as must the two
+    // outputs of an output switch: follow the cycles of these constraints, sending the first
+    // output of the cycle through the top subnetwork
+    let mut through_bottom = vec![None; len];
+    for start in (0..len).step_by(2) {
+        let mut output = start;
+        while through_bottom[permutation[output]].is_none() {
+            let input = permutation[output];
+            through_bottom[input] = Some(false);
+            through_bottom[input ^ 1] = Some(true);
+            output = inverse[input ^ 1] ^ 1;
+        }
+    }
+    let through_bottom = through_bottom
+        .into_iter()
+        .map(Option::unwrap)
+        .collect::<Vec<_>>();
+
+    let mut top = vec![0; half];
+    let mut bottom = vec![0; half];
+    for j in 0..half {
+        let (upper, lower) = (permutation[2 * j], permutation[2 * j + 1]);
+        let (from_top, from_bottom) = if through_bottom[upper] {
+            (lower, upper)
+        } else {
+            (upper, lower)
+        };
+        top[j] = from_top / 2;
+        bottom[j] = from_bottom / 2;
+    }
+
+    switches.extend((0..half).map(|j| through_bottom[2 * j]));
+    route(&top, switches);
+    route(&bottom, switches);
+    switches.extend((0..half).map(|j| through_bottom[permutation[2 * j]]));
+}
+
+/// Applies the switches listed as in [`benes_switches`] to the elements, `swap` being called on
+/// each pair of elements going through a switch.
+///
+/// The independent switches are applied in parallel.
+pub(crate) fn apply_benes_network<T, S, F>(elements: Vec<&mut T>, switches: &[S], swap: &F)
+where
+    T: Send,
+    S: Sync,
+    F: Fn(&mut T, &mut T, &S) + Sync,
+{
+    let len = elements.len();
+    assert_eq!(switches.len(), switch_count(len));
+    if len < 2 {
+        return;
+    }
+
+    let mut elements = elements;
+
+    if len == 2 {
+        apply_switch_layer(&mut elements, switches, swap);
+        return;
+    }
+
+    let half = len / 2;
+    let sub_count = switch_count(half);
+    let (input_layer, rest) = switches.split_at(half);
+    let (top_switches, rest) = rest.split_at(sub_count);
+    let (bottom_switches, output_layer) = rest.split_at(sub_count);
+
+    apply_switch_layer(&mut elements, input_layer, swap);
+
+    {
+        let mut top = Vec::with_capacity(half);
+        let mut bottom = Vec::with_capacity(half);
+        for (index, element) in elements.iter_mut().enumerate() {
+            if index % 2 == 0 {
+                top.push(&mut **element);
+            } else {
+                bottom.push(&mut **element);
+            }
+        }
+        rayon::join(
+            || apply_benes_network(top, top_switches, swap),
+            || apply_benes_network(bottom, bottom_switches, swap),
+        );
+    }
+
+    apply_switch_layer(&mut elements, output_layer, swap);
+}
+
+/// Applies the switch `j` of the layer to the elements `2j` and `2j + 1`, in parallel.
+fn apply_switch_layer<T, S, F>(elements: &mut [&mut T], layer: &[S], swap: &F)
+where
+    T: Send,
+    S: Sync,
+    F: Fn(&mut T, &mut T, &S) + Sync,
+{
+    elements
+        .par_chunks_exact_mut(2)
+        .zip(layer.par_iter())
+        .for_each(|(pair, switch)| {
+            let (upper, lower) = pair.split_at_mut(1);
+            swap(upper[0], lower[0], switch);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    #[test]
+    fn test_benes_network_routes_all_permutations() {
+        let mut rng = rand::thread_rng();
+
+        for len in 0..=33 {
+            for _ in 0..20 {
+                let mut permutation = (0..len).collect::<Vec<usize>>();
+                permutation.shuffle(&mut rng);
+
+                let padded = padded_permutation(&permutation);
+                let switches = benes_switches(&padded);
+                assert_eq!(switches.len(), switch_count(padded.len()));
+
+                let mut values = (0..padded.len()).map(|i| i * 10).collect::<Vec<_>>();
+                apply_benes_network(
+                    values.iter_mut().collect(),
+                    &switches,
+                    &|a: &mut usize, b: &mut usize, crossed: &bool| {
+                        if *crossed {
+                            std::mem::swap(a, b);
+                        }
+                    },
+                );
+
+                let expected = padded.iter().map(|i| i * 10).collect::<Vec<_>>();
+                assert_eq!(values, expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_check_permutation_rejects_duplicates() {
+        check_permutation(&[0, 2, 2]);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::client_key::ClientKey;
+use crate::integer::encryption::{encrypt_words_radix_impl, AsLittleEndianWords};
+use crate::shortint::CompactPublicKey as ShortintCompactPublicKey;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CompactPublicKey {
+    key: ShortintCompactPublicKey,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for CompactPublicKey { key });
+
+impl CompactPublicKey {
+    pub fn new<C>(client_key: &C) -> Self
+    where
+        C: AsRef<ClientKey>,
+    {
+        Self {
+            key: ShortintCompactPublicKey::new(&client_key.as_ref().key),
+        }
+    }
+
+    pub fn parameters(&self) -> crate::shortint::Parameters {
+        self.key.parameters
+    }
+
+    /// Encrypt a message in radix decomposition in a [`CompactCiphertextList`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, CompactPublicKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let compact_list = pk.encrypt_radix_compact(200u64, num_blocks);
+    ///
+    /// let ct = compact_list.expand_one();
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 200);
+    /// ```
+    pub fn encrypt_radix_compact<T: AsLittleEndianWords + Copy>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> CompactCiphertextList {
+        self.encrypt_slice_radix_compact(std::slice::from_ref(&message), num_blocks)
+    }
+
+    /// Encrypt messages in radix decomposition in a single [`CompactCiphertextList`], each
+    /// message having `num_blocks` blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, CompactPublicKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = [12u64, 200, 255];
+    /// let compact_list = pk.encrypt_slice_radix_compact(&msgs, num_blocks);
+    /// assert_eq!(compact_list.ciphertext_count(), msgs.len());
+    ///
+    /// // The list is expanded on the server side
+    /// let cts = compact_list.expand();
+    /// let ct_res = sks.add_parallelized(&cts[0], &cts[1]);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (12 + 200) % 256);
+    /// ```
+    pub fn encrypt_slice_radix_compact<T: AsLittleEndianWords + Copy>(
+        &self,
+        messages: &[T],
+        num_blocks: usize,
+    ) -> CompactCiphertextList {
+        let mut clear_blocks = Vec::with_capacity(messages.len() * num_blocks);
+
+        for &message in messages {
+            // Decompose the message in clear blocks, the blocks are then encrypted all at once
+            let message_blocks: Vec<u64> =
+                encrypt_words_radix_impl(&self.key, message, num_blocks, |_, block| block);
+            clear_blocks.extend(message_blocks);
+        }
+
+        let ct_list = self.key.encrypt_slice(&clear_blocks);
+        CompactCiphertextList {
+            ct_list,
+            num_blocks,
+        }
+    }
+}
@@ -10,6 +10,8 @@ pub struct CompressedPublicKeyBase<OpOrder: PBSOrderMarker> {
     key: crate::shortint::CompressedPublicKeyBase<OpOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[OpOrder: PBSOrderMarker] for CompressedPublicKeyBase<OpOrder> { key });
+
 pub type CompressedPublicKeyBig = CompressedPublicKeyBase<KeyswitchBootstrap>;
 pub type CompressedPublicKeySmall = CompressedPublicKeyBase<BootstrapKeyswitch>;
 
@@ -1,8 +1,10 @@
 //! Module with the definition of the encryption PublicKey.
 
+pub mod compact;
 pub mod compressed;
 pub mod standard;
 
+pub use compact::CompactPublicKey;
 pub use compressed::{CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall};
 pub use standard::{PublicKey, PublicKeyBig, PublicKeySmall};
 
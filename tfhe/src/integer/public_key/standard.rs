@@ -10,6 +10,8 @@ pub struct PublicKey<PBSOrder: PBSOrderMarker> {
     key: PublicKeyBase<PBSOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[PBSOrder: PBSOrderMarker] for PublicKey<PBSOrder> { key });
+
 pub type PublicKeyBig = PublicKey<KeyswitchBootstrap>;
 pub type PublicKeySmall = PublicKey<BootstrapKeyswitch>;
 
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::integer::{CompressedPublicKeyBig, PublicKeyBig};
+use crate::integer::{CompactPublicKey, CompressedPublicKeyBig, PublicKeyBig};
 use crate::shortint::parameters::*;
 use crate::shortint::Parameters;
 
@@ -16,6 +16,12 @@ create_parametrized_test!(radix_encrypt_decrypt_compressed_128_bits {
     PARAM_MESSAGE_2_CARRY_2 /* PARAM_MESSAGE_3_CARRY_3, Skipped as its slow
                              * PARAM_MESSAGE_4_CARRY_4, Skipped as its slow */
 });
+create_parametrized_test!(radix_encrypt_decrypt_compact_128_bits {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4
+});
 
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
@@ -60,3 +66,30 @@ fn radix_encrypt_decrypt_compressed_128_bits(param: Parameters) {
     // assert
     assert_eq!(clear, dec);
 }
+
+fn radix_encrypt_decrypt_compact_128_bits(param: Parameters) {
+    let (cks, _) = KEY_CACHE.get_from_params(param);
+    let public_key = CompactPublicKey::new(&cks);
+
+    // RNG
+    let mut rng = rand::thread_rng();
+    let num_block = (128f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+    let clears = (0..5).map(|_| rng.gen::<u128>()).collect::<Vec<_>>();
+
+    //encryption
+    let compact_list = public_key.encrypt_slice_radix_compact(&clears, num_block);
+    assert_eq!(compact_list.ciphertext_count(), clears.len());
+
+    let cts = compact_list.expand();
+    assert_eq!(cts.len(), clears.len());
+
+    for (ct, clear) in cts.iter().zip(clears.iter()) {
+        // decryption
+        let mut dec = 0u128;
+        cks.decrypt_radix_into(ct, &mut dec);
+
+        // assert
+        assert_eq!(*clear, dec);
+    }
+}
@@ -0,0 +1,179 @@
+//! Selection of the number of blocks of radix ciphertexts from the range of the values.
+//!
+//! A radix ciphertext of `n` blocks holds the values of $[0, m^n)$ where $m$ is the message
+//! modulus, and the operations wrap around this modulus. Choosing `n` from the largest input
+//! value is not enough when a circuit computes larger intermediate values, e.g. the sum of
+//! several inputs. [`RadixConfig`] computes the number of blocks from the range of the inputs and
+//! a [`RadixOpProfile`] describing how large the values computed from them can get.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::{gen_keys_radix, RadixConfig, RadixOpProfile};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! // Each balance is at most 1000, and a circuit adds 3 of them
+//! let config = RadixConfig::for_range(0..=1000, PARAM_MESSAGE_2_CARRY_2)
+//!     .with_op_profile(RadixOpProfile::Sum { operand_count: 3 });
+//!
+//! // 3000 needs 12 bits, i.e. 6 blocks of 2 bits
+//! assert_eq!(config.num_blocks(), 6);
+//!
+//! let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, config.num_blocks());
+//! let a = cks.as_ref().encrypt_with_range(1000u64, &config);
+//! let b = cks.as_ref().encrypt_with_range(1000u64, &config);
+//! let c = cks.as_ref().encrypt_with_range(999u64, &config);
+//!
+//! let sum = sks.add_parallelized(&sks.add_parallelized(&a, &b), &c);
+//! let dec: u64 = cks.decrypt(&sum);
+//! assert_eq!(dec, 2999);
+//! ```
+use crate::shortint::Parameters;
+use std::ops::RangeInclusive;
+
+/// How large the values computed by a circuit get with respect to its inputs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RadixOpProfile {
+    /// The computed values stay in the range of the inputs, e.g. comparisons, min/max, bitwise
+    /// operations or subtractions of a smaller value.
+    #[default]
+    InRange,
+    /// At most `operand_count` values of the range are added together.
+    Sum { operand_count: u64 },
+    /// Two values of the range are multiplied together.
+    Product,
+    /// The computed values are at most `max_value`.
+    UpTo { max_value: u128 },
+}
+
+/// Radix representation of the values of a range, see the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RadixConfig {
+    min_value: u64,
+    max_value: u64,
+    parameters: Parameters,
+    op_profile: RadixOpProfile,
+}
+
+impl RadixConfig {
+    /// Create the configuration for values in `range`, the values only going through operations
+    /// which stay in the range (see [`RadixOpProfile::InRange`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or if the message modulus of the parameters is not a power
+    /// of two.
+    pub fn for_range(range: RangeInclusive<u64>, parameters: Parameters) -> Self {
+        assert!(!range.is_empty(), "The range of the values is empty");
+        assert!(
+            parameters.message_modulus.0.is_power_of_two(),
+            "The message modulus must be a power of two for the radix decomposition"
+        );
+
+        Self {
+            min_value: *range.start(),
+            max_value: *range.end(),
+            parameters,
+            op_profile: RadixOpProfile::InRange,
+        }
+    }
+
+    /// Declare the operations the values go through, adding blocks to hold the largest value
+    /// computed.
+    pub fn with_op_profile(mut self, op_profile: RadixOpProfile) -> Self {
+        self.op_profile = op_profile;
+        self
+    }
+
+    /// The smallest value of the range.
+    pub fn min_value(&self) -> u64 {
+        self.min_value
+    }
+
+    /// The largest value of the range.
+    pub fn max_value(&self) -> u64 {
+        self.max_value
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+
+    pub fn op_profile(&self) -> RadixOpProfile {
+        self.op_profile
+    }
+
+    /// The largest value the circuit computes, according to the [`RadixOpProfile`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value does not fit in a `u128`.
+    pub fn max_intermediate_value(&self) -> u128 {
+        let max_value = u128::from(self.max_value);
+        let max_intermediate_value = match self.op_profile {
+            RadixOpProfile::InRange => Some(max_value),
+            RadixOpProfile::Sum { operand_count } => {
+                max_value.checked_mul(u128::from(operand_count))
+            }
+            RadixOpProfile::Product => max_value.checked_mul(max_value),
+            RadixOpProfile::UpTo { max_value: upper } => Some(upper.max(max_value)),
+        };
+        max_intermediate_value.expect("The intermediate values do not fit in 128 bits")
+    }
+
+    /// The number of blocks of the ciphertexts, i.e. the smallest number of blocks holding
+    /// [`Self::max_intermediate_value`].
+    pub fn num_blocks(&self) -> usize {
+        let bits_per_block = self.parameters.message_modulus.0.ilog2();
+        let bit_count = u128::BITS - self.max_intermediate_value().leading_zeros();
+        // A block is still needed to encrypt 0
+        ((bit_count + bits_per_block - 1) / bits_per_block).max(1) as usize
+    }
+
+    /// Whether `value` is in the range of the configuration.
+    pub fn contains(&self, value: u64) -> bool {
+        (self.min_value..=self.max_value).contains(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+
+    #[test]
+    fn test_radix_config_num_blocks() {
+        let config = |max_value| RadixConfig::for_range(0..=max_value, PARAM_MESSAGE_2_CARRY_2);
+
+        assert_eq!(config(0).num_blocks(), 1);
+        assert_eq!(config(3).num_blocks(), 1);
+        assert_eq!(config(4).num_blocks(), 2);
+        // The top of the range needs all the bits
+        assert_eq!(config(255).num_blocks(), 4);
+        assert_eq!(config(256).num_blocks(), 5);
+        assert_eq!(config(u64::MAX).num_blocks(), 32);
+
+        let sum = |max_value, operand_count| {
+            config(max_value)
+                .with_op_profile(RadixOpProfile::Sum { operand_count })
+                .num_blocks()
+        };
+        assert_eq!(sum(255, 1), 4);
+        assert_eq!(sum(255, 2), 5);
+        assert_eq!(sum(127, 2), 4);
+        assert_eq!(sum(u64::MAX, 2), 33);
+
+        let product = config(255).with_op_profile(RadixOpProfile::Product);
+        assert_eq!(product.max_intermediate_value(), 255 * 255);
+        assert_eq!(product.num_blocks(), 8);
+
+        let up_to = config(255).with_op_profile(RadixOpProfile::UpTo { max_value: 10 });
+        assert_eq!(up_to.num_blocks(), 4);
+
+        let config = RadixConfig::for_range(10..=255, PARAM_MESSAGE_1_CARRY_1);
+        assert_eq!(config.num_blocks(), 8);
+        assert!(config.contains(255));
+        assert!(!config.contains(256));
+        assert!(!config.contains(9));
+    }
+}
@@ -1,7 +1,9 @@
 use rayon::prelude::*;
+use std::cmp::Ordering;
 
 use super::ServerKey;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{BooleanBlock, OrderingBlock, RadixCiphertext};
+use crate::shortint::ciphertext::Degree;
 use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::{CiphertextBase, PBSOrderMarker};
 
@@ -11,6 +13,24 @@ enum MinMaxSelector {
     Min,
 }
 
+/// Algorithm used to compute the equality of two radix ciphertexts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EqualityStrategy {
+    /// Each pair of blocks is tested for equality, the results are then summed in as few blocks
+    /// as possible and the sums tested for zero.
+    ///
+    /// The PBS of each step all run in parallel, which gives a depth of 2 PBS for up to
+    /// `message_modulus * carry_modulus - 1` blocks.
+    ///
+    /// The difference of two blocks needs one bit of carry, the [`Self::ComparatorTree`] is used
+    /// instead for parameters without carry.
+    #[default]
+    BlockDifferences,
+    /// The same comparison tree as the other comparisons (lt, gt, ...), its depth grows with the
+    /// logarithm of the number of blocks.
+    ComparatorTree,
+}
+
 fn has_non_zero_carries<PBSOrder: PBSOrderMarker>(ct: &RadixCiphertext<PBSOrder>) -> bool {
     ct.blocks
         .iter()
@@ -28,6 +48,7 @@ pub struct Comparator<'a> {
     mask_accumulator: LookupTableOwned,
     x_accumulator: LookupTableOwned,
     y_accumulator: LookupTableOwned,
+    block_difference_accumulator: LookupTableOwned,
 }
 
 impl<'a> Comparator<'a> {
@@ -108,6 +129,11 @@ impl<'a> Comparator<'a> {
             }
         });
 
+        // The difference of two blocks is offset by a multiple of the message modulus
+        let block_difference_accumulator = server_key
+            .key
+            .generate_accumulator(|x| u64::from(x % message_modulus != 0));
+
         Self {
             server_key,
             sign_accumulator,
@@ -115,6 +141,7 @@ impl<'a> Comparator<'a> {
             mask_accumulator,
             x_accumulator,
             y_accumulator,
+            block_difference_accumulator,
         }
     }
 
@@ -345,6 +372,65 @@ impl<'a> Comparator<'a> {
         selection
     }
 
+    /// returns a block encrypting 0 if lhs == rhs, a non zero value otherwise
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_sum_of_block_differences_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> crate::shortint::CiphertextBase<PBSOrder> {
+        assert_eq!(lhs.blocks.len(), rhs.blocks.len());
+
+        // 1 for each pair of blocks that are not equal, 0 otherwise
+        let mut differences = Vec::with_capacity(lhs.blocks.len());
+        lhs.blocks
+            .par_iter()
+            .zip(rhs.blocks.par_iter())
+            .map(|(lhs, rhs)| {
+                let mut difference = self.server_key.key.unchecked_sub(lhs, rhs);
+                self.server_key
+                    .key
+                    .apply_lookup_table_assign(&mut difference, &self.block_difference_accumulator);
+                difference
+            })
+            .collect_into_vec(&mut differences);
+
+        // As the differences are 0 or 1, we can sum up to total_modulus - 1 of them in a block
+        // before having to test the sum for zero, bringing it back to 0 or 1
+        let total_modulus =
+            self.server_key.key.message_modulus.0 * self.server_key.key.carry_modulus.0;
+        let max_sum_size = total_modulus - 1;
+
+        let sum_chunk = |chunk: &[crate::shortint::CiphertextBase<PBSOrder>]| {
+            let mut sum = chunk[0].clone();
+            for difference in &chunk[1..] {
+                self.server_key
+                    .key
+                    .unchecked_add_assign(&mut sum, difference);
+            }
+            sum
+        };
+
+        let mut sums = Vec::with_capacity(differences.len() / max_sum_size + 1);
+        while differences.len() > max_sum_size {
+            differences
+                .par_chunks(max_sum_size)
+                .map(|chunk| {
+                    let mut sum = sum_chunk(chunk);
+                    self.server_key
+                        .key
+                        .apply_lookup_table_assign(&mut sum, &self.sign_accumulator);
+                    sum
+                })
+                .collect_into_vec(&mut sums);
+
+            std::mem::swap(&mut differences, &mut sums);
+        }
+
+        sum_chunk(&differences)
+    }
+
     fn smart_compare<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -435,6 +521,22 @@ impl<'a> Comparator<'a> {
             .key
             .apply_lookup_table_assign(&mut mask, &self.mask_accumulator);
 
+        self.select_with_mask_parallelized(&mask, lhs, rhs, x_accumulator, y_accumulator)
+    }
+
+    /// Selects, block by block, `lhs` if `mask` is 0 and `rhs` if `mask` is the message modulus
+    /// when the accumulators are (x_accumulator, y_accumulator), the other way around when they
+    /// are swapped
+    ///
+    /// Expects the carry buffers to be empty
+    fn select_with_mask_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        mask: &CiphertextBase<PBSOrder>,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        x_accumulator: &LookupTableOwned,
+        y_accumulator: &LookupTableOwned,
+    ) -> RadixCiphertext<PBSOrder> {
         let blocks = lhs
             .blocks
             .par_iter()
@@ -442,14 +544,14 @@ impl<'a> Comparator<'a> {
             .map(|(lhs_block, rhs_block)| {
                 let (maybe_x, maybe_y) = rayon::join(
                     || {
-                        let mut lhs_masked = self.server_key.key.unchecked_add(lhs_block, &mask);
+                        let mut lhs_masked = self.server_key.key.unchecked_add(lhs_block, mask);
                         self.server_key
                             .key
                             .apply_lookup_table_assign(&mut lhs_masked, x_accumulator);
                         lhs_masked
                     },
                     || {
-                        let mut rhs_masked = self.server_key.key.unchecked_add(rhs_block, &mask);
+                        let mut rhs_masked = self.server_key.key.unchecked_add(rhs_block, mask);
                         self.server_key
                             .key
                             .apply_lookup_table_assign(&mut rhs_masked, y_accumulator);
@@ -457,13 +559,31 @@ impl<'a> Comparator<'a> {
                     },
                 );
 
-                self.server_key.key.unchecked_add(&maybe_x, &maybe_y)
+                let mut selected = self.server_key.key.unchecked_add(&maybe_x, &maybe_y);
+                // The result is one of the two input blocks
+                selected.degree = Degree(lhs_block.degree.0.max(rhs_block.degree.0));
+                selected
             })
             .collect::<Vec<_>>();
 
         RadixCiphertext { blocks }
     }
 
+    /// Returns the number of blocks needed to hold values up to `max_value`
+    fn num_blocks_to_hold(&self, max_value: usize) -> usize {
+        let message_modulus = self.server_key.key.message_modulus.0;
+        let mut num_blocks = 1;
+        let mut capacity = message_modulus;
+        while capacity <= max_value {
+            num_blocks += 1;
+            match capacity.checked_mul(message_modulus) {
+                Some(new_capacity) => capacity = new_capacity,
+                None => break,
+            }
+        }
+        num_blocks
+    }
+
     fn smart_min_or_max<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -661,12 +781,31 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        self.unchecked_comparison_impl(
-            Self::unchecked_compare_parallelized,
-            |x| u64::from(x == Self::IS_EQUAL),
-            lhs,
-            rhs,
-        )
+        self.unchecked_eq_parallelized_with_strategy(lhs, rhs, EqualityStrategy::default())
+    }
+
+    pub fn unchecked_eq_parallelized_with_strategy<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        strategy: EqualityStrategy,
+    ) -> RadixCiphertext<PBSOrder> {
+        let has_carry = self.server_key.key.carry_modulus.0 >= 2;
+        match strategy {
+            EqualityStrategy::BlockDifferences if has_carry => self.unchecked_comparison_impl(
+                Self::unchecked_sum_of_block_differences_parallelized,
+                |x| u64::from(x == 0),
+                lhs,
+                rhs,
+            ),
+            EqualityStrategy::BlockDifferences | EqualityStrategy::ComparatorTree => self
+                .unchecked_comparison_impl(
+                    Self::unchecked_compare_parallelized,
+                    |x| u64::from(x == Self::IS_EQUAL),
+                    lhs,
+                    rhs,
+                ),
+        }
     }
 
     pub fn unchecked_gt_parallelized<PBSOrder: PBSOrderMarker>(
@@ -826,6 +965,7 @@ impl<'a> Comparator<'a> {
     // Smart Multi-Threaded operations
     //======================================
 
+    #[deprecated(note = "the operands may be modified, use eq_parallelized")]
     pub fn smart_eq_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -839,6 +979,7 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    #[deprecated(note = "the operands may be modified, use gt_parallelized")]
     pub fn smart_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -852,6 +993,7 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    #[deprecated(note = "the operands may be modified, use ge_parallelized")]
     pub fn smart_ge_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -865,6 +1007,7 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    #[deprecated(note = "the operands may be modified, use lt_parallelized")]
     pub fn smart_lt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -878,6 +1021,7 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    #[deprecated(note = "the operands may be modified, use le_parallelized")]
     pub fn smart_le_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -891,6 +1035,7 @@ impl<'a> Comparator<'a> {
         )
     }
 
+    #[deprecated(note = "the operands may be modified, use max_parallelized")]
     pub fn smart_max_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -899,6 +1044,7 @@ impl<'a> Comparator<'a> {
         self.smart_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Max)
     }
 
+    #[deprecated(note = "the operands may be modified, use min_parallelized")]
     pub fn smart_min_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -915,32 +1061,18 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        self.eq_parallelized_with_strategy(lhs, rhs, EqualityStrategy::default())
+    }
 
-        self.unchecked_eq_parallelized(lhs, rhs)
+    pub fn eq_parallelized_with_strategy<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        strategy: EqualityStrategy,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
+
+        self.unchecked_eq_parallelized_with_strategy(&lhs, &rhs, strategy)
     }
 
     pub fn gt_parallelized<PBSOrder: PBSOrderMarker>(
@@ -948,32 +1080,9 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        self.unchecked_gt_parallelized(lhs, rhs)
+        self.unchecked_gt_parallelized(&lhs, &rhs)
     }
 
     pub fn ge_parallelized<PBSOrder: PBSOrderMarker>(
@@ -981,32 +1090,9 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        self.unchecked_ge_parallelized(lhs, rhs)
+        self.unchecked_ge_parallelized(&lhs, &rhs)
     }
 
     pub fn lt_parallelized<PBSOrder: PBSOrderMarker>(
@@ -1014,32 +1100,9 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        self.unchecked_lt_parallelized(lhs, rhs)
+        self.unchecked_lt_parallelized(&lhs, &rhs)
     }
 
     pub fn le_parallelized<PBSOrder: PBSOrderMarker>(
@@ -1047,32 +1110,9 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        self.unchecked_le_parallelized(lhs, rhs)
+        self.unchecked_le_parallelized(&lhs, &rhs)
     }
 
     pub fn max_parallelized<PBSOrder: PBSOrderMarker>(
@@ -1080,33 +1120,9 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        let mut res = self.unchecked_max_parallelized(lhs, rhs);
+        let mut res = self.unchecked_max_parallelized(&lhs, &rhs);
         res.blocks
             .par_iter_mut()
             .for_each(|block| self.server_key.key.message_extract_assign(block));
@@ -1118,38 +1134,436 @@ impl<'a> Comparator<'a> {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut tmp_lhs: RadixCiphertext<PBSOrder>;
-        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
-
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
 
-        let mut res = self.unchecked_min_parallelized(lhs, rhs);
+        let mut res = self.unchecked_min_parallelized(&lhs, &rhs);
         res.blocks
             .par_iter_mut()
             .for_each(|block| self.server_key.key.message_extract_assign(block));
         res
     }
+
+    //======================================
+    // Counting
+    //======================================
+
+    /// Returns the encrypted number of values of `cts` which are strictly greater than
+    /// `threshold`.
+    ///
+    /// The result has just enough blocks to hold `cts.len()`.
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_count_greater_than_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        threshold: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let num_blocks = self.num_blocks_to_hold(cts.len());
+
+        if cts.is_empty() {
+            return self.server_key.create_trivial_zero_radix(num_blocks);
+        }
+
+        let comparisons = cts
+            .par_iter()
+            .map(|ct| self.unchecked_compare_parallelized(ct, threshold))
+            .collect::<Vec<_>>();
+
+        // Two comparison results (each in 0..=2) are packed in one block, the lookup table
+        // directly outputs how many of them are IS_SUPERIOR, which halves the number of PBS
+        // compared to mapping each result to 0 or 1 before summing.
+        let pair_count_accumulator = self.server_key.key.generate_accumulator(|x| {
+            u64::from(x / 3 == Self::IS_SUPERIOR) + u64::from(x % 3 == Self::IS_SUPERIOR)
+        });
+        let single_count_accumulator = self
+            .server_key
+            .key
+            .generate_accumulator(|x| u64::from(x == Self::IS_SUPERIOR));
+        let counts = comparisons
+            .par_chunks(2)
+            .map(|chunk| match chunk {
+                [high, low] => {
+                    let mut packed = self.server_key.key.unchecked_scalar_mul(high, 3);
+                    self.server_key.key.unchecked_add_assign(&mut packed, low);
+                    self.server_key
+                        .key
+                        .apply_lookup_table_assign(&mut packed, &pair_count_accumulator);
+                    packed
+                }
+                [single] => {
+                    let mut single = single.clone();
+                    self.server_key
+                        .key
+                        .apply_lookup_table_assign(&mut single, &single_count_accumulator);
+                    single
+                }
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        // Partial counts are summed without any PBS as long as the degree fits in the block,
+        // the carries are then propagated and the partial sums are added in a tree.
+        let group_size = (self.server_key.key.max_degree.0 / 2).max(1);
+        let mut partial_sums = counts
+            .par_chunks(group_size)
+            .map(|group| {
+                let mut sum = group[0].clone();
+                for count in &group[1..] {
+                    self.server_key.key.unchecked_add_assign(&mut sum, count);
+                }
+
+                let mut blocks = Vec::with_capacity(num_blocks);
+                blocks.push(sum);
+                for _ in 0..num_blocks - 1 {
+                    blocks.push(self.server_key.key.create_trivial(0));
+                }
+                let mut partial_sum = RadixCiphertext { blocks };
+                self.server_key
+                    .full_propagate_parallelized(&mut partial_sum);
+                partial_sum
+            })
+            .collect::<Vec<_>>();
+
+        while partial_sums.len() > 1 {
+            partial_sums = partial_sums
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [lhs, rhs] => self.server_key.add_parallelized(lhs, rhs),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        partial_sums.pop().unwrap()
+    }
+
+    /// Returns the encrypted number of values of `cts` which are strictly greater than
+    /// `threshold`.
+    ///
+    /// The result has just enough blocks to hold `cts.len()`.
+    pub fn count_greater_than_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        threshold: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut tmp_threshold: RadixCiphertext<PBSOrder>;
+        let tmp_cts: Vec<RadixCiphertext<PBSOrder>>;
+
+        let threshold = if threshold.block_carries_are_empty() {
+            threshold
+        } else {
+            tmp_threshold = threshold.clone();
+            self.server_key
+                .full_propagate_parallelized(&mut tmp_threshold);
+            &tmp_threshold
+        };
+
+        let cts = if cts.iter().all(|ct| ct.block_carries_are_empty()) {
+            cts
+        } else {
+            tmp_cts = cts
+                .par_iter()
+                .map(|ct| {
+                    let mut ct = ct.clone();
+                    if !ct.block_carries_are_empty() {
+                        self.server_key.full_propagate_parallelized(&mut ct);
+                    }
+                    ct
+                })
+                .collect();
+            &tmp_cts
+        };
+
+        self.unchecked_count_greater_than_parallelized(cts, threshold)
+    }
+
+    //======================================
+    // Arg min / Arg max
+    //======================================
+
+    /// Expects the carry buffers to be empty
+    fn unchecked_arg_min_or_max_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        selector: MinMaxSelector,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(!cts.is_empty(), "Cannot select an index in an empty slice");
+
+        let num_index_blocks = self.num_blocks_to_hold(cts.len() - 1);
+        let mut candidates = cts
+            .iter()
+            .enumerate()
+            .map(|(index, ct)| {
+                let index = self
+                    .server_key
+                    .create_trivial_radix(index as u64, num_index_blocks);
+                (std::borrow::Cow::Borrowed(ct), index)
+            })
+            .collect::<Vec<_>>();
+
+        // Each round of the tree keeps the best of two adjacent candidates, the same mask
+        // selects both the value and its index. Candidates stay in order so that on equality
+        // the lowest index wins.
+        while candidates.len() > 1 {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [(lhs, lhs_index), (rhs, rhs_index)] => {
+                        // The mask is the message modulus when rhs must be selected, comparing
+                        // with the operands swapped for the min keeps lhs on equality
+                        let mut mask = match selector {
+                            MinMaxSelector::Max => self.unchecked_compare_parallelized(lhs, rhs),
+                            MinMaxSelector::Min => self.unchecked_compare_parallelized(rhs, lhs),
+                        };
+                        self.server_key
+                            .key
+                            .apply_lookup_table_assign(&mut mask, &self.mask_accumulator);
+
+                        let (value, index) = rayon::join(
+                            || {
+                                self.select_with_mask_parallelized(
+                                    &mask,
+                                    lhs,
+                                    rhs,
+                                    &self.x_accumulator,
+                                    &self.y_accumulator,
+                                )
+                            },
+                            || {
+                                self.select_with_mask_parallelized(
+                                    &mask,
+                                    lhs_index,
+                                    rhs_index,
+                                    &self.x_accumulator,
+                                    &self.y_accumulator,
+                                )
+                            },
+                        );
+                        (std::borrow::Cow::Owned(value), index)
+                    }
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        candidates.pop().unwrap().1
+    }
+
+    fn arg_min_or_max_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        selector: MinMaxSelector,
+    ) -> RadixCiphertext<PBSOrder> {
+        let tmp_cts: Vec<RadixCiphertext<PBSOrder>>;
+        let cts = if cts.iter().all(|ct| ct.block_carries_are_empty()) {
+            cts
+        } else {
+            tmp_cts = cts
+                .par_iter()
+                .map(|ct| {
+                    let mut ct = ct.clone();
+                    if !ct.block_carries_are_empty() {
+                        self.server_key.full_propagate_parallelized(&mut ct);
+                    }
+                    ct
+                })
+                .collect();
+            &tmp_cts
+        };
+
+        self.unchecked_arg_min_or_max_parallelized(cts, selector)
+    }
+
+    /// Returns the encrypted index of the greatest value of `cts`, the lowest index if the
+    /// maximum appears several times
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_argmax_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_arg_min_or_max_parallelized(cts, MinMaxSelector::Max)
+    }
+
+    /// Returns the encrypted index of the smallest value of `cts`, the lowest index if the
+    /// minimum appears several times
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_argmin_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        self.unchecked_arg_min_or_max_parallelized(cts, MinMaxSelector::Min)
+    }
+
+    pub fn argmax_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        self.arg_min_or_max_parallelized(cts, MinMaxSelector::Max)
+    }
+
+    pub fn argmin_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        self.arg_min_or_max_parallelized(cts, MinMaxSelector::Min)
+    }
+
+    //======================================
+    // Three-valued comparison
+    //======================================
+
+    fn ordering_value(ordering: Ordering) -> u64 {
+        match ordering {
+            Ordering::Less => Self::IS_INFERIOR,
+            Ordering::Equal => Self::IS_EQUAL,
+            Ordering::Greater => Self::IS_SUPERIOR,
+        }
+    }
+
+    /// Returns the encrypted ordering of lhs compared to rhs
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_cmp_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> OrderingBlock<PBSOrder> {
+        OrderingBlock::new_unchecked(self.unchecked_compare_parallelized(lhs, rhs))
+    }
+
+    pub fn cmp_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> OrderingBlock<PBSOrder> {
+        let (lhs, rhs) = self.server_key.propagated_operands_parallelized(lhs, rhs);
+
+        self.unchecked_cmp_parallelized(&lhs, &rhs)
+    }
+
+    /// Returns a boolean block encrypting whether `ordering` encrypts `expected`
+    pub fn ordering_is<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        expected: Ordering,
+    ) -> BooleanBlock<PBSOrder> {
+        let expected = Self::ordering_value(expected);
+        let acc = self
+            .server_key
+            .key
+            .generate_accumulator(|x| u64::from(x == expected));
+        BooleanBlock::new_unchecked(
+            self.server_key
+                .key
+                .apply_lookup_table(&ordering.block, &acc),
+        )
+    }
+
+    /// Selects, block by block, `if_less`, `if_equal` or `if_greater` according to `ordering`
+    ///
+    /// Each candidate block gets the message modulus added unless it is the selected one, so
+    /// that the x accumulator zeroes it, the three results are then summed.
+    ///
+    /// Expects the carry buffers to be empty
+    pub fn unchecked_select_by_ordering_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        if_less: &RadixCiphertext<PBSOrder>,
+        if_equal: &RadixCiphertext<PBSOrder>,
+        if_greater: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert_eq!(if_less.blocks.len(), if_equal.blocks.len());
+        assert_eq!(if_less.blocks.len(), if_greater.blocks.len());
+
+        let message_modulus = self.server_key.key.message_modulus.0 as u64;
+        let candidates = [if_less, if_equal, if_greater];
+
+        let masks = [Self::IS_INFERIOR, Self::IS_EQUAL, Self::IS_SUPERIOR]
+            .par_iter()
+            .map(|&value| {
+                let acc = self.server_key.key.generate_accumulator(|x| {
+                    if x == value {
+                        0
+                    } else {
+                        message_modulus
+                    }
+                });
+                self.server_key
+                    .key
+                    .apply_lookup_table(&ordering.block, &acc)
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = (0..if_less.blocks.len())
+            .into_par_iter()
+            .map(|i| {
+                let masked_blocks = candidates
+                    .par_iter()
+                    .zip(masks.par_iter())
+                    .map(|(candidate, mask)| {
+                        let mut masked = self
+                            .server_key
+                            .key
+                            .unchecked_add(&candidate.blocks[i], mask);
+                        self.server_key
+                            .key
+                            .apply_lookup_table_assign(&mut masked, &self.x_accumulator);
+                        masked
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut selected = masked_blocks[0].clone();
+                for masked in &masked_blocks[1..] {
+                    self.server_key
+                        .key
+                        .unchecked_add_assign(&mut selected, masked);
+                }
+                // The result is one of the candidate blocks
+                selected.degree = Degree(
+                    candidates
+                        .iter()
+                        .map(|candidate| candidate.blocks[i].degree.0)
+                        .max()
+                        .unwrap(),
+                );
+                selected
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext { blocks }
+    }
+
+    pub fn select_by_ordering_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        if_less: &RadixCiphertext<PBSOrder>,
+        if_equal: &RadixCiphertext<PBSOrder>,
+        if_greater: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let candidates = [if_less, if_equal, if_greater]
+            .par_iter()
+            .map(|&ct| {
+                if ct.block_carries_are_empty() {
+                    std::borrow::Cow::Borrowed(ct)
+                } else {
+                    let mut ct = ct.clone();
+                    self.server_key.full_propagate_parallelized(&mut ct);
+                    std::borrow::Cow::Owned(ct)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_select_by_ordering_parallelized(
+            ordering,
+            &candidates[0],
+            &candidates[1],
+            &candidates[2],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1159,6 +1573,7 @@ mod tests {
     use crate::shortint::Parameters;
     use rand;
     use rand::prelude::*;
+    use std::cmp::Ordering;
 
     /// Function to test an "unchecked" compartor function.
     ///
@@ -1666,4 +2081,235 @@ mod tests {
     fn test_max_parallelized_256_bits_param_message_4_carry_4() {
         test_max_parallelized_256_bits(crate::shortint::parameters::PARAM_MESSAGE_4_CARRY_4, 2)
     }
+
+    fn eq_parallelized_strategies(param: Parameters) {
+        use super::EqualityStrategy;
+
+        let mut rng = rand::thread_rng();
+
+        let num_block = (256f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        let words: [u64; 4] = rng.gen();
+        let mut other_words = words;
+        // Values differing by a single bit, i.e. in a single block
+        other_words[rng.gen_range(0..4)] ^= 1 << rng.gen_range(0..64);
+
+        let clear_a = U256::from((words[0], words[1], words[2], words[3]));
+        let clear_b = U256::from((
+            other_words[0],
+            other_words[1],
+            other_words[2],
+            other_words[3],
+        ));
+
+        let a = cks.encrypt_radix(clear_a, num_block);
+        let same_as_a = cks.encrypt_radix(clear_a, num_block);
+        let b = cks.encrypt_radix(clear_b, num_block);
+
+        for strategy in [
+            EqualityStrategy::BlockDifferences,
+            EqualityStrategy::ComparatorTree,
+        ] {
+            let result =
+                comparator.unchecked_eq_parallelized_with_strategy(&a, &same_as_a, strategy);
+            let mut decrypted = U256::default();
+            cks.decrypt_radix_into(&result, &mut decrypted);
+            assert_eq!(decrypted, U256::from(1u64), "{strategy:?}");
+
+            let result = comparator.eq_parallelized_with_strategy(&a, &b, strategy);
+            cks.decrypt_radix_into(&result, &mut decrypted);
+            assert_eq!(decrypted, U256::from(0u64), "{strategy:?}");
+        }
+    }
+
+    create_parametrized_test!(eq_parallelized_strategies {
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_4_CARRY_4
+    });
+
+    fn count_greater_than_parallelized(param: Parameters) {
+        // An odd number of values, so that one comparison result is not paired
+        const NUM_VALUES: usize = 9;
+
+        let mut rng = rand::thread_rng();
+
+        let num_block = (8f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        let clear_threshold = rng.gen::<u8>() as u64;
+        let clears = (0..NUM_VALUES)
+            .map(|_| rng.gen::<u8>() as u64)
+            .collect::<Vec<_>>();
+
+        let threshold = cks.encrypt_radix(clear_threshold, num_block);
+        let mut cts = clears
+            .iter()
+            .map(|clear| cks.encrypt_radix(*clear, num_block))
+            .collect::<Vec<_>>();
+        let expected = clears
+            .iter()
+            .filter(|clear| **clear > clear_threshold)
+            .count() as u64;
+
+        let result = comparator.unchecked_count_greater_than_parallelized(&cts, &threshold);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected);
+
+        // Non clean inputs go through the default version
+        let one = cks.encrypt_radix(1u64, num_block);
+        sks.unchecked_add_assign(&mut cts[0], &one);
+        let clear_0 = (clears[0] + 1) % 256;
+        let expected = expected - u64::from(clears[0] > clear_threshold)
+            + u64::from(clear_0 > clear_threshold);
+
+        let result = comparator.count_greater_than_parallelized(&cts, &threshold);
+        assert!(!super::has_non_zero_carries(&result));
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected);
+
+        let result = comparator.count_greater_than_parallelized(&[], &threshold);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, 0);
+    }
+
+    create_parametrized_test!(count_greater_than_parallelized {
+        PARAM_MESSAGE_2_CARRY_2
+    });
+
+    fn argmax_argmin_parallelized(param: Parameters) {
+        const NUM_VALUES: usize = 5;
+
+        let mut rng = rand::thread_rng();
+
+        let num_block = (8f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        // Small values so that ties happen
+        let mut clears = (0..NUM_VALUES)
+            .map(|_| rng.gen::<u64>() % 4)
+            .collect::<Vec<_>>();
+        // The first index of the extremum must be returned
+        clears[NUM_VALUES - 1] = clears[1];
+
+        let mut cts = clears
+            .iter()
+            .map(|clear| cks.encrypt_radix(*clear, num_block))
+            .collect::<Vec<_>>();
+
+        let max = *clears.iter().max().unwrap();
+        let expected_argmax = clears.iter().position(|clear| *clear == max).unwrap() as u64;
+        let min = *clears.iter().min().unwrap();
+        let expected_argmin = clears.iter().position(|clear| *clear == min).unwrap() as u64;
+
+        let result = comparator.unchecked_argmax_parallelized(&cts);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected_argmax);
+
+        let result = comparator.unchecked_argmin_parallelized(&cts);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected_argmin);
+
+        // Non clean inputs go through the default version
+        let zero = cks.encrypt_radix(0u64, num_block);
+        sks.unchecked_add_assign(&mut cts[0], &zero);
+        assert!(cts[0].blocks.iter().any(|block| !block.carry_is_empty()));
+
+        let result = comparator.argmax_parallelized(&cts);
+        assert!(!super::has_non_zero_carries(&result));
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected_argmax);
+
+        let result = comparator.argmin_parallelized(&cts);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, expected_argmin);
+
+        let result = comparator.argmax_parallelized(&cts[..1]);
+        let decrypted: u64 = cks.decrypt_radix(&result);
+        assert_eq!(decrypted, 0);
+    }
+
+    create_parametrized_test!(argmax_argmin_parallelized {
+        PARAM_MESSAGE_2_CARRY_2
+    });
+
+    fn cmp_and_select_by_ordering_parallelized(param: Parameters) {
+        let mut rng = rand::thread_rng();
+
+        let num_block = (8f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let (cks, sks) = gen_keys(&param);
+        let comparator = Comparator::new(&sks);
+
+        for _ in 0..4 {
+            // Small values so that equality happens
+            let clear_a = rng.gen::<u64>() % 4;
+            let clear_b = rng.gen::<u64>() % 4;
+            let expected = clear_a.cmp(&clear_b);
+
+            let mut a = cks.encrypt_radix(clear_a, num_block);
+            let b = cks.encrypt_radix(clear_b, num_block);
+
+            let ordering = comparator.unchecked_cmp_parallelized(&a, &b);
+            assert_eq!(cks.decrypt_ordering(&ordering), expected);
+
+            for candidate in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+                let is_candidate = comparator.ordering_is(&ordering, candidate);
+                assert_eq!(cks.decrypt_bool(&is_candidate), candidate == expected);
+            }
+
+            let clear_candidates = [rng.gen::<u8>(), rng.gen::<u8>(), rng.gen::<u8>()];
+            let candidates = clear_candidates
+                .iter()
+                .map(|clear| cks.encrypt_radix(*clear as u64, num_block))
+                .collect::<Vec<_>>();
+            let expected_selected = match expected {
+                Ordering::Less => clear_candidates[0],
+                Ordering::Equal => clear_candidates[1],
+                Ordering::Greater => clear_candidates[2],
+            } as u64;
+
+            let result = comparator.unchecked_select_by_ordering_parallelized(
+                &ordering,
+                &candidates[0],
+                &candidates[1],
+                &candidates[2],
+            );
+            assert!(!super::has_non_zero_carries(&result));
+            let decrypted: u64 = cks.decrypt_radix(&result);
+            assert_eq!(decrypted, expected_selected);
+
+            // Non clean inputs go through the default version
+            let zero = cks.encrypt_radix(0u64, num_block);
+            sks.unchecked_add_assign(&mut a, &zero);
+            assert!(a.blocks.iter().any(|block| !block.carry_is_empty()));
+
+            let ordering = comparator.cmp_parallelized(&a, &b);
+            assert_eq!(cks.decrypt_ordering(&ordering), expected);
+
+            let result = comparator.select_by_ordering_parallelized(
+                &ordering,
+                &a,
+                &candidates[1],
+                &candidates[2],
+            );
+            let decrypted: u64 = cks.decrypt_radix(&result);
+            let expected_selected = match expected {
+                Ordering::Less => clear_a,
+                Ordering::Equal => clear_candidates[1] as u64,
+                Ordering::Greater => clear_candidates[2] as u64,
+            };
+            assert_eq!(decrypted, expected_selected);
+        }
+    }
+
+    create_parametrized_test!(cmp_and_select_by_ordering_parallelized {
+        PARAM_MESSAGE_2_CARRY_2
+    });
 }
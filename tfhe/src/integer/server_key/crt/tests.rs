@@ -13,6 +13,7 @@ create_parametrized_test!(integer_smart_crt_scalar_add);
 create_parametrized_test!(integer_smart_crt_scalar_mul);
 create_parametrized_test!(integer_smart_crt_scalar_sub);
 create_parametrized_test!(integer_smart_crt_sub);
+create_parametrized_test!(integer_radix_crt_conversion);
 
 /// Number of loop iteration within randomized tests
 const NB_TEST: usize = 30;
@@ -271,3 +272,39 @@ fn integer_smart_crt_sub(param: Parameters) {
         assert_eq!(clear_0, dec_res);
     }
 }
+
+fn integer_radix_crt_conversion(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    let mut rng = rand::thread_rng();
+
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    // Enough blocks to hold any value of the CRT representation, and one more so that radix
+    // values may exceed the CRT modulus
+    let radix_modulus = param.message_modulus.0 as u64;
+    let mut num_blocks = 1;
+    while radix_modulus.pow(num_blocks as u32) < modulus {
+        num_blocks += 1;
+    }
+    num_blocks += 1;
+    let radix_total_modulus = radix_modulus.pow(num_blocks as u32);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % radix_total_modulus;
+
+        let ct = cks.encrypt_radix(clear, num_blocks);
+        let ct_crt = sks.radix_to_crt_parallelized(&ct, &basis);
+        assert_eq!(cks.decrypt_crt(&ct_crt), clear % modulus);
+
+        // Arithmetic in CRT then back to radix
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let mut ct_1 = cks.encrypt_crt(clear_1, basis.clone());
+        let ct_prod = sks.smart_crt_mul(&ct_crt, &mut ct_1);
+
+        let ct_radix = sks.crt_to_radix_parallelized(&ct_prod, num_blocks);
+        let dec: u64 = cks.decrypt_radix(&ct_radix);
+        assert_eq!(dec, (clear % modulus) * clear_1 % modulus);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertextBig};
+use crate::integer::ServerKey;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::CiphertextBig;
+use rayon::prelude::*;
+
+/// Inverse of `value` modulo `modulus`, the moduli of a CRT basis being small.
+fn inverse_mod(value: u64, modulus: u64) -> u64 {
+    (1..modulus)
+        .find(|x| (value % modulus) * x % modulus == 1)
+        .expect("The moduli of a CRT basis must be pairwise coprime")
+}
+
+impl ServerKey {
+    fn full_modulus(&self) -> u64 {
+        (self.key.message_modulus.0 * self.key.carry_modulus.0) as u64
+    }
+
+    fn assert_crt_basis_fits(&self, basis: &[u64]) {
+        let full_modulus = self.full_modulus();
+        for (i, &modulus) in basis.iter().enumerate() {
+            assert!(
+                modulus >= 2 && 2 * (modulus - 1) < full_modulus,
+                "The CRT modulus {modulus} does not fit in the blocks of the server key"
+            );
+            for &other in &basis[..i] {
+                let (mut a, mut b) = (modulus, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                assert_eq!(a, 1, "The moduli of a CRT basis must be pairwise coprime");
+            }
+        }
+    }
+
+    /// Gives a block the moduli of a CRT block of the given modulus.
+    fn set_crt_block_moduli(&self, block: &mut CiphertextBig, modulus: u64) {
+        block.message_modulus = MessageModulus(modulus as usize);
+        block.carry_modulus = CarryModulus(self.full_modulus() as usize / modulus as usize);
+    }
+
+    /// Gives a block the moduli of a radix block.
+    fn set_radix_block_moduli(&self, block: &mut CiphertextBig) {
+        block.message_modulus = self.key.message_modulus;
+        block.carry_modulus = self.key.carry_modulus;
+    }
+
+    /// Converts a radix ciphertext to the CRT representation over the given basis.
+    ///
+    /// The value of the radix ciphertext is reduced modulo the product of the basis. Each
+    /// residue is computed as the sum of the contributions of the radix blocks, each obtained with
+    /// a PBS and the sum being reduced with a PBS whenever the carry space of the block is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moduli of the basis are not pairwise coprime, or too large for the sum of
+    /// two residues to fit in a block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 217u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let basis = vec![3, 5, 7];
+    /// let ct_crt = sks.radix_to_crt_parallelized(&ct, &basis);
+    ///
+    /// let res = cks.as_ref().decrypt_crt(&ct_crt);
+    /// assert_eq!(res, msg % 105);
+    /// ```
+    pub fn radix_to_crt_parallelized(
+        &self,
+        ct: &RadixCiphertextBig,
+        basis: &[u64],
+    ) -> CrtCiphertext {
+        self.assert_crt_basis_fits(basis);
+
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let radix_modulus = self.key.message_modulus.0 as u64;
+        let full_modulus = self.full_modulus();
+
+        let blocks = basis
+            .par_iter()
+            .map(|&modulus| {
+                // Weight of each radix block modulo the CRT modulus
+                let weights = std::iter::successors(Some(1 % modulus), |weight| {
+                    Some(weight * radix_modulus % modulus)
+                })
+                .take(ct.blocks.len())
+                .collect::<Vec<_>>();
+
+                let terms = ct
+                    .blocks
+                    .par_iter()
+                    .zip(weights.par_iter())
+                    .map(|(block, &weight)| {
+                        let acc = self.key.generate_accumulator(|x| x * weight % modulus);
+                        self.key.apply_lookup_table(block, &acc)
+                    })
+                    .collect::<Vec<_>>();
+
+                let reduce = self.key.generate_accumulator(|x| x % modulus);
+                let mut terms = terms.into_iter();
+                let mut residue = terms.next().unwrap();
+                for term in terms {
+                    if residue.degree.0 + term.degree.0 >= full_modulus as usize {
+                        self.key.apply_lookup_table_assign(&mut residue, &reduce);
+                    }
+                    self.key.unchecked_add_assign(&mut residue, &term);
+                }
+                if residue.degree.0 >= modulus as usize {
+                    self.key.apply_lookup_table_assign(&mut residue, &reduce);
+                }
+
+                self.set_crt_block_moduli(&mut residue, modulus);
+                residue
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, basis.to_vec()))
+    }
+
+    /// Converts a CRT ciphertext to a radix ciphertext with `num_blocks` blocks.
+    ///
+    /// The residues are first converted to the mixed radix representation of the value
+    /// (Garner's algorithm), `value = a_1 + a_2 * m_1 + a_3 * m_1 * m_2 + ...` with `a_i < m_i`,
+    /// which only needs PBS on the CRT blocks. Each term is then expanded in radix blocks with a
+    /// PBS per block, and the terms are summed without any modular reduction since their sum is
+    /// lower than the product of the basis.
+    ///
+    /// The value is reduced modulo the radix modulus if `num_blocks` is too small to hold the
+    /// product of the basis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 97u64;
+    /// let basis = vec![3, 5, 7];
+    /// let ct = cks.encrypt_crt(msg, basis);
+    ///
+    /// let num_blocks = 4;
+    /// let ct_radix = sks.crt_to_radix_parallelized(&ct, num_blocks);
+    ///
+    /// let res: u64 = cks.decrypt_radix(&ct_radix);
+    /// assert_eq!(res, msg);
+    /// ```
+    pub fn crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertextBig {
+        let basis = &ct.moduli;
+        self.assert_crt_basis_fits(basis);
+
+        let mut digits = ct
+            .blocks
+            .par_iter()
+            .zip(basis.par_iter())
+            .map(|(block, &modulus)| {
+                if block.degree.0 < modulus as usize {
+                    block.clone()
+                } else {
+                    let acc = self.key.generate_accumulator(|x| x % modulus);
+                    self.key.apply_lookup_table(block, &acc)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Garner's algorithm: once a_i is known, it is removed from all the following residues
+        for i in 0..digits.len() {
+            let (known, rest) = digits.split_at_mut(i + 1);
+            let a_i = &known[i];
+            let m_i = basis[i];
+
+            rest.par_iter_mut()
+                .zip(basis[i + 1..].par_iter())
+                .for_each(|(x_j, &m_j)| {
+                    let neg_acc = self.key.generate_accumulator(|a| (m_j - a % m_j) % m_j);
+                    let neg_a_i = self.key.apply_lookup_table(a_i, &neg_acc);
+                    self.key.unchecked_add_assign(x_j, &neg_a_i);
+
+                    let inverse = inverse_mod(m_i, m_j);
+                    let acc = self.key.generate_accumulator(|x| (x % m_j) * inverse % m_j);
+                    self.key.apply_lookup_table_assign(x_j, &acc);
+                });
+        }
+
+        let radix_modulus = self.key.message_modulus.0 as u64;
+
+        let terms = digits
+            .par_iter()
+            .zip(basis.par_iter())
+            .enumerate()
+            .map(|(i, (a_i, &m_i))| {
+                let weight = basis[..i].iter().product::<u64>() as u128;
+
+                (0..num_blocks)
+                    .into_par_iter()
+                    .map(|k| {
+                        let max_term = (m_i as u128 - 1) * weight;
+                        // Blocks above the largest value of the term are zero, the upper digits
+                        // being dropped takes care of the reduction modulo the radix modulus
+                        let block_weight = (radix_modulus as u128)
+                            .checked_pow(k as u32)
+                            .filter(|&block_weight| block_weight <= max_term);
+
+                        let mut block = match block_weight {
+                            Some(block_weight) => {
+                                let acc = self.key.generate_accumulator(|a| {
+                                    let term = a as u128 * weight;
+                                    (term / block_weight % radix_modulus as u128) as u64
+                                });
+                                self.key.apply_lookup_table(a_i, &acc)
+                            }
+                            None => self.key.create_trivial(0),
+                        };
+                        self.set_radix_block_moduli(&mut block);
+                        block
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut terms = terms.into_iter().map(RadixCiphertextBig::from);
+        let mut result = terms.next().unwrap();
+        for term in terms {
+            self.add_assign_parallelized(&mut result, &term);
+        }
+        result
+    }
+}
@@ -1,4 +1,5 @@
 mod add_crt;
+mod conversion;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -7,8 +7,16 @@ mod crt;
 mod crt_parallel;
 mod radix;
 mod radix_parallel;
+mod scalar_cache;
+mod scheduling;
+
+pub use comparator::EqualityStrategy;
+pub use radix_parallel::RoundingMode;
 
 use crate::integer::client_key::ClientKey;
+use crate::integer::server_key::scalar_cache::ScalarMulCache;
+use crate::integer::server_key::scheduling::ParallelismBudget;
+use crate::shortint::debug::{Mismatch, StructuralDiff};
 use crate::shortint::server_key::MaxDegree;
 use serde::{Deserialize, Serialize};
 
@@ -19,9 +27,22 @@ pub use crate::shortint::CheckError;
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic integer circuits.
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
+    // Runtime tuning, not part of the key material
+    #[serde(skip)]
+    pub(crate) parallelism_budget: ParallelismBudget,
+    #[serde(skip)]
+    pub(crate) scalar_cache: ScalarMulCache,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for ServerKey { key });
+
+impl StructuralDiff for ServerKey {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        self.key.diff_into(&other.key, path, mismatches);
+    }
 }
 
 impl From<ServerKey> for crate::shortint::ServerKey {
@@ -30,6 +51,12 @@ impl From<ServerKey> for crate::shortint::ServerKey {
     }
 }
 
+impl AsRef<crate::shortint::ServerKey> for ServerKey {
+    fn as_ref(&self) -> &crate::shortint::ServerKey {
+        &self.key
+    }
+}
+
 impl ServerKey {
     /// Generates a server key.
     ///
@@ -60,7 +87,11 @@ impl ServerKey {
             MaxDegree(max),
         );
 
-        ServerKey { key: sks }
+        ServerKey {
+            key: sks,
+            parallelism_budget: ParallelismBudget::default(),
+            scalar_cache: ScalarMulCache::default(),
+        }
     }
 
     /// Creates a ServerKey from an already generated shortint::ServerKey.
@@ -88,7 +119,11 @@ impl ServerKey {
             (cks.key.parameters.message_modulus.0 - 1) * cks.key.parameters.carry_modulus.0 - 1;
 
         key.max_degree = MaxDegree(max);
-        ServerKey { key }
+        ServerKey {
+            key,
+            parallelism_budget: ParallelismBudget::default(),
+            scalar_cache: ScalarMulCache::default(),
+        }
     }
 }
 
@@ -96,6 +131,8 @@ pub struct CompressedServerKey {
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for CompressedServerKey { key });
+
 impl CompressedServerKey {
     pub fn new(client_key: &ClientKey) -> CompressedServerKey {
         let key = crate::shortint::CompressedServerKey::new(&client_key.key);
@@ -106,6 +143,10 @@ impl CompressedServerKey {
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed: CompressedServerKey) -> Self {
         let key = compressed.key.into();
-        Self { key }
+        Self {
+            key,
+            parallelism_budget: ParallelismBudget::default(),
+            scalar_cache: ScalarMulCache::default(),
+        }
     }
 }
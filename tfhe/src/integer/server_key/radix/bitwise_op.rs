@@ -1,5 +1,10 @@
+use crate::core_crypto::algorithms::{
+    lwe_ciphertext_opposite_assign, lwe_ciphertext_plaintext_add_assign,
+};
+use crate::core_crypto::entities::Plaintext;
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
 use crate::shortint::CheckError::CarryFull;
 use crate::shortint::{CheckError, PBSOrderMarker};
 
@@ -601,4 +606,60 @@ impl ServerKey {
         }
         self.unchecked_bitxor_assign(ct_left, ct_right);
     }
+
+    /// Computes homomorphically the bitwise not of a ciphertext encrypting an integer value.
+    ///
+    /// Each block `b` is replaced by `(message_modulus - 1) - b`, computed by negating the block
+    /// and adding a scalar, so no bootstrap is needed.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext: the carries of the input must be empty.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 201u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a bitwise not:
+    /// let ct_res = sks.unchecked_bitnot(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, !msg % 256);
+    /// ```
+    pub fn unchecked_bitnot<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.unchecked_bitnot_assign(&mut result);
+        result
+    }
+
+    pub fn unchecked_bitnot_assign<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        let message_modulus = self.key.message_modulus.0;
+        let delta = (1_u64 << 63) / (message_modulus * self.key.carry_modulus.0) as u64;
+        let block_max = Plaintext((message_modulus as u64 - 1) * delta);
+
+        for block in ct.blocks.iter_mut() {
+            // (0, Delta * (message_modulus - 1)) - block
+            lwe_ciphertext_opposite_assign(&mut block.ct);
+            lwe_ciphertext_plaintext_add_assign(&mut block.ct, block_max);
+            block.degree = Degree(message_modulus - 1);
+        }
+    }
 }
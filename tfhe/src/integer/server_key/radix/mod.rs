@@ -6,14 +6,16 @@ mod neg;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
+mod shared;
 mod shift;
 mod sub;
+mod truncate;
 
 use super::ServerKey;
 
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::encryption::{encrypt_words_radix_impl, AsLittleEndianWords};
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
 
 #[cfg(test)]
 mod tests;
@@ -119,14 +121,26 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         index: usize,
     ) {
-        let carry = self.key.carry_extract(&ctxt.blocks[index]);
+        let mut carry = ctxt.blocks[index].clone();
+        self.propagate_with_carry_buffer(ctxt, index, &mut carry);
+    }
 
-        ctxt.blocks[index] = self.key.message_extract(&ctxt.blocks[index]);
+    /// Propagate the carry of the block at `index`, extracting it in `carry` so that the buffer
+    /// can be reused across the blocks.
+    fn propagate_with_carry_buffer<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &mut RadixCiphertext<PBSOrder>,
+        index: usize,
+        carry: &mut CiphertextBase<PBSOrder>,
+    ) {
+        self.key.carry_extract_into(&ctxt.blocks[index], carry);
+
+        self.key.message_extract_assign(&mut ctxt.blocks[index]);
 
         //add the carry to the next block
         if index < ctxt.blocks.len() - 1 {
             self.key
-                .unchecked_add_assign(&mut ctxt.blocks[index + 1], &carry);
+                .unchecked_add_assign(&mut ctxt.blocks[index + 1], carry);
         }
     }
 
@@ -157,9 +171,14 @@ impl ServerKey {
     /// assert_eq!(msg + msg, res);
     /// ```
     pub fn full_propagate<PBSOrder: PBSOrderMarker>(&self, ctxt: &mut RadixCiphertext<PBSOrder>) {
+        let Some(first_block) = ctxt.blocks.first() else {
+            return;
+        };
+        let mut carry = first_block.clone();
+
         let len = ctxt.blocks.len();
         for i in 0..len {
-            self.propagate(ctxt, i);
+            self.propagate_with_carry_buffer(ctxt, i, &mut carry);
         }
     }
 }
@@ -0,0 +1,164 @@
+use crate::integer::ciphertext::shared::SharedRadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+
+impl ServerKey {
+    /// Computes homomorphically an addition between a scalar and a ciphertext with shared
+    /// blocks.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext. Only the blocks for which the decomposition of the scalar is non-zero are
+    /// modified, the other blocks of the result are shared with `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::shared::SharedRadixCiphertext;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 4;
+    /// let scalar = 8;
+    ///
+    /// let ct = SharedRadixCiphertext::from(cks.encrypt(msg));
+    ///
+    /// // Compute homomorphically an addition:
+    /// let ct_res = sks.unchecked_scalar_add_shared(&ct, scalar);
+    /// assert!(ct_res.shares_block_with(&ct, 0));
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res.into_radix());
+    /// assert_eq!(msg + scalar, dec);
+    /// ```
+    pub fn unchecked_scalar_add_shared<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &SharedRadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> SharedRadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_add_shared_assign(&mut result, scalar);
+        result
+    }
+
+    pub fn unchecked_scalar_add_shared_assign<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut SharedRadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) {
+        let modulus = self.key.message_modulus.0 as u64;
+
+        let mut scalar = scalar;
+        for index in 0..ct.num_blocks() {
+            if scalar == 0 {
+                break;
+            }
+
+            let decomp = scalar % modulus;
+            if decomp != 0 {
+                self.key
+                    .unchecked_scalar_add_assign(ct.block_mut(index), decomp as u8);
+            }
+            scalar /= modulus;
+        }
+    }
+
+    /// Propagate the carries of a ciphertext with shared blocks.
+    ///
+    /// Contrary to [`ServerKey::full_propagate`], the propagation stops at the first block which
+    /// has no carry according to its degree and receives no carry from the previous block: the
+    /// blocks above it are left untouched, and stay shared with the other ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::shared::SharedRadixCiphertext;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let mut ct = SharedRadixCiphertext::from(cks.encrypt(3u64));
+    /// sks.unchecked_scalar_add_shared_assign(&mut ct, 3);
+    /// sks.propagate_shared(&mut ct);
+    /// assert!(ct.block_carries_are_empty());
+    ///
+    /// let dec: u64 = cks.decrypt(&ct.into_radix());
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn propagate_shared<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut SharedRadixCiphertext<PBSOrder>,
+    ) {
+        let message_modulus = self.key.message_modulus.0;
+        let num_blocks = ct.num_blocks();
+
+        let mut carry = None;
+        for index in 0..num_blocks {
+            if carry.is_none() && ct.block(index).degree.0 < message_modulus {
+                continue;
+            }
+
+            let block = ct.block_mut(index);
+            if let Some(carry) = carry.take() {
+                self.key.unchecked_add_assign(block, &carry);
+            }
+
+            if index < num_blocks - 1 && block.degree.0 >= message_modulus {
+                carry = Some(self.key.carry_extract(block));
+            }
+            self.key.message_extract_assign(block);
+        }
+    }
+
+    /// Computes homomorphically an addition between a scalar and a ciphertext with shared
+    /// blocks.
+    ///
+    /// The carries are propagated with [`ServerKey::propagate_shared`], the blocks which are not
+    /// modified by the addition nor reached by a carry stay shared with `ct`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carries of `ct` are not empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::shared::SharedRadixCiphertext;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 201;
+    /// let scalar = 40;
+    ///
+    /// let ct = SharedRadixCiphertext::from(cks.encrypt(msg));
+    ///
+    /// let ct_res = sks.scalar_add_shared(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res.into_radix());
+    /// assert_eq!((msg + scalar) % 256, dec);
+    /// ```
+    pub fn scalar_add_shared<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &SharedRadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> SharedRadixCiphertext<PBSOrder> {
+        assert!(
+            ct.block_carries_are_empty(),
+            "The carries of the ciphertext must be empty"
+        );
+
+        let mut result = self.unchecked_scalar_add_shared(ct, scalar);
+        self.propagate_shared(&mut result);
+        result
+    }
+}
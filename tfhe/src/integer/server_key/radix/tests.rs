@@ -1,3 +1,4 @@
+use crate::integer::ciphertext::shared::SharedRadixCiphertext;
 use crate::integer::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
 use crate::shortint::Parameters;
@@ -37,6 +38,8 @@ create_parametrized_test!(integer_unchecked_small_scalar_mul);
 create_parametrized_test!(integer_smart_small_scalar_mul);
 create_parametrized_test!(integer_blockshift);
 create_parametrized_test!(integer_blockshift_right);
+create_parametrized_test!(integer_truncate_low_blocks);
+create_parametrized_test!(integer_round_low_blocks);
 create_parametrized_test!(integer_smart_scalar_mul);
 create_parametrized_test!(integer_unchecked_scalar_left_shift);
 create_parametrized_test!(integer_unchecked_scalar_right_shift);
@@ -51,6 +54,7 @@ create_parametrized_test!(integer_unchecked_mul);
 
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
+create_parametrized_test!(integer_scalar_add_shared);
 create_parametrized_test!(integer_unchecked_scalar_sub);
 create_parametrized_test!(integer_unchecked_scalar_add);
 
@@ -679,6 +683,66 @@ fn integer_blockshift_right(param: Parameters) {
     }
 }
 
+fn integer_truncate_low_blocks(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let num_blocks = rng.gen::<usize>() % NB_CTXT;
+
+        // encryption of an integer
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.truncate_low_blocks(&ct, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks);
+
+        // decryption of ct_res
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+
+        // assert
+        let divisor = param.message_modulus.0.pow(num_blocks as u32) as u64;
+        assert_eq!(clear / divisor, dec_res);
+    }
+}
+
+fn integer_round_low_blocks(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let num_blocks = rng.gen::<usize>() % NB_CTXT;
+
+        // encryption of an integer
+        let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+        let ct_res = sks.round_low_blocks(&ct, num_blocks);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks);
+
+        // decryption of ct_res
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+
+        // assert
+        let divisor = param.message_modulus.0.pow(num_blocks as u32) as u64;
+        let result_modulus = modulus / divisor;
+        let expected = (clear + divisor / 2) / divisor % result_modulus;
+        assert_eq!(expected, dec_res);
+    }
+}
+
 fn integer_smart_scalar_mul(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
@@ -1101,6 +1165,43 @@ fn integer_smart_scalar_add(param: Parameters) {
     }
 }
 
+fn integer_scalar_add_shared(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+
+        let ct = SharedRadixCiphertext::from(cks.encrypt_radix(clear, NB_CTXT));
+
+        let ct_res = sks.scalar_add_shared(&ct, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt_radix(&ct_res.to_radix());
+        assert_eq!((clear + scalar) % modulus, dec_res);
+
+        // The input is not modified by the operation on its shared blocks
+        let dec: u64 = cks.decrypt_radix(&ct.to_radix());
+        assert_eq!(clear, dec);
+
+        // Only the blocks where the scalar has a non-zero digit are copied
+        let ct_res = sks.unchecked_scalar_add_shared(&ct, scalar);
+        let mut digits = scalar;
+        for index in 0..NB_CTXT {
+            let digit = digits % param.message_modulus.0 as u64;
+            assert_eq!(ct_res.shares_block_with(&ct, index), digit == 0);
+            digits /= param.message_modulus.0 as u64;
+        }
+        let dec_res: u64 = cks.decrypt_radix(&ct_res.into_radix());
+        assert_eq!((clear + scalar) % modulus, dec_res);
+    }
+}
+
 fn integer_unchecked_scalar_sub(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
 
@@ -0,0 +1,130 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+
+impl ServerKey {
+    /// Drops the `num_blocks` least significant blocks of a ciphertext.
+    ///
+    /// The result has `num_blocks` fewer blocks than the input and encrypts
+    /// `floor(msg / message_modulus^num_blocks)`.
+    ///
+    /// As the dropped amount is a whole number of blocks, no bootstrap is needed
+    /// when the carries of the input are empty; otherwise they are propagated first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not strictly smaller than the number of blocks of `ctxt`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 201;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.truncate_low_blocks(&ct, 2);
+    /// assert_eq!(ct_res.blocks().len(), 2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg / 16, dec);
+    /// ```
+    pub fn truncate_low_blocks<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        num_blocks: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            num_blocks < ctxt.blocks.len(),
+            "Cannot drop {num_blocks} blocks of a ciphertext with {} blocks",
+            ctxt.blocks.len()
+        );
+
+        if ctxt.block_carries_are_empty() {
+            return RadixCiphertext::from(ctxt.blocks[num_blocks..].to_vec());
+        }
+
+        let mut tmp = ctxt.clone();
+        self.full_propagate(&mut tmp);
+        tmp.blocks.drain(..num_blocks);
+        tmp
+    }
+
+    /// Drops the `num_blocks` least significant blocks of a ciphertext, rounding
+    /// the result to the nearest integer.
+    ///
+    /// The result has `num_blocks` fewer blocks than the input and encrypts
+    /// `msg / message_modulus^num_blocks` rounded half up: the carry-in is taken from
+    /// the most significant dropped block, so only one bootstrap is spent on
+    /// the dropped part before the carry is propagated through the kept blocks.
+    ///
+    /// Rounding up the biggest representable value wraps around to 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not strictly smaller than the number of blocks of `ctxt`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// // 201 / 16 = 12.5625
+    /// let msg = 201;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.round_low_blocks(&ct, 2);
+    /// assert_eq!(ct_res.blocks().len(), 2);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(13, dec);
+    /// ```
+    pub fn round_low_blocks<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        num_blocks: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        assert!(
+            num_blocks < ctxt.blocks.len(),
+            "Cannot drop {num_blocks} blocks of a ciphertext with {} blocks",
+            ctxt.blocks.len()
+        );
+
+        let mut tmp = ctxt.clone();
+        if !tmp.block_carries_are_empty() {
+            self.full_propagate(&mut tmp);
+        }
+
+        if num_blocks == 0 {
+            return tmp;
+        }
+
+        // The dropped part is at least half of message_modulus^num_blocks
+        // iff its most significant block is at least half of message_modulus
+        let half = (self.key.message_modulus.0 / 2) as u64;
+        let round_acc = self.key.generate_accumulator(|x| u64::from(x >= half));
+        let round_bit = self
+            .key
+            .apply_lookup_table(&tmp.blocks[num_blocks - 1], &round_acc);
+
+        tmp.blocks.drain(..num_blocks);
+        self.key
+            .unchecked_add_assign(&mut tmp.blocks[0], &round_bit);
+        self.full_propagate(&mut tmp);
+        tmp
+    }
+}
@@ -34,6 +34,7 @@ impl ServerKey {
     /// let dec_result: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(dec_result, msg1 + msg2);
     /// ```
+    #[deprecated(note = "the operands may be modified, use add_parallelized")]
     pub fn smart_add_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -48,6 +49,7 @@ impl ServerKey {
         self.unchecked_add(ct_left, ct_right)
     }
 
+    #[deprecated(note = "the operands may be modified, use add_assign_parallelized")]
     pub fn smart_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -115,6 +117,8 @@ impl ServerKey {
         ct_left: &mut RadixCiphertext<PBSOrder>,
         ct_right: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("add");
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (
@@ -145,7 +149,34 @@ impl ServerKey {
         self.full_propagate_parallelized(lhs);
     }
 
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// choosing between the unchecked and the default operation from the degrees of the blocks.
+    ///
+    /// When the degrees allow it, the unchecked addition is used directly. Otherwise, copies of
+    /// the inputs having non-empty carries are propagated first, so the inputs are never modified.
+    ///
+    /// Unlike the default operation, the carries of the result are not cleared, which makes it
+    /// cheap to use when summing many terms whose carries are cleared once at the end.
+    pub(crate) fn degree_aware_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if self.is_add_possible(ct_left, ct_right) {
+            return self.unchecked_add(ct_left, ct_right);
+        }
+
+        let (lhs, rhs) = self.propagated_operands_parallelized(ct_left, ct_right);
+
+        self.unchecked_add(&lhs, &rhs)
+    }
+
     /// op must be associative and commutative
+    ///
+    /// The operands may be modified by `op`, prefer
+    /// [`default_binary_op_seq_parallelized`](Self::default_binary_op_seq_parallelized) which
+    /// leaves them untouched.
+    #[deprecated(note = "the operands may be modified, use default_binary_op_seq_parallelized")]
     pub fn smart_binary_op_seq_parallelized<'this, 'item, PBSOrder: PBSOrderMarker + 'item>(
         &'this self,
         ct_seq: impl IntoIterator<Item = &'item mut RadixCiphertext<PBSOrder>>,
@@ -205,13 +236,17 @@ impl ServerKey {
                     let untouched_prefix = ct_seq.len() % 2;
                     let ct_seq_slice = &mut ct_seq[untouched_prefix..];
 
-                    ct_seq_slice.par_chunks_mut(2).for_each(|chunk| {
-                        let (first, second) = chunk.split_at_mut(1);
-                        let first = &mut first[0];
-                        let second = &mut second[0];
-                        let result = op(sks, first.as_mut(), second.as_mut());
-                        results.lock().unwrap().push(result);
-                    });
+                    let min_len = sks.par_min_len(ct_seq_slice.len() / 2);
+                    ct_seq_slice
+                        .par_chunks_mut(2)
+                        .with_min_len(min_len)
+                        .for_each(|chunk| {
+                            let (first, second) = chunk.split_at_mut(1);
+                            let first = &mut first[0];
+                            let second = &mut second[0];
+                            let result = op(sks, first.as_mut(), second.as_mut());
+                            results.lock().unwrap().push(result);
+                        });
 
                     let results = results.into_inner().unwrap();
                     ct_seq.truncate(untouched_prefix);
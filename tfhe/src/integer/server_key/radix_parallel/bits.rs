@@ -0,0 +1,197 @@
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    pub(super) fn bits_per_block(&self) -> usize {
+        let message_modulus = self.key.message_modulus.0;
+        assert!(
+            message_modulus.is_power_of_two(),
+            "Bit extraction requires a power of two message modulus, got {message_modulus}"
+        );
+        message_modulus.trailing_zeros() as usize
+    }
+
+    /// Extracts the bit at position `bit_index` of a ciphertext encrypting an integer message.
+    ///
+    /// Bit 0 is the least significant bit. This costs a single PBS, as opposed to shifting and
+    /// masking the ciphertext.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_index` is not smaller than the number of bits encrypted by the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bit = sks.unchecked_extract_bit(&ct, 5);
+    /// assert!(cks.decrypt_bool(&bit));
+    /// let bit = sks.unchecked_extract_bit(&ct, 6);
+    /// assert!(!cks.decrypt_bool(&bit));
+    /// ```
+    pub fn unchecked_extract_bit<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        bit_index: usize,
+    ) -> BooleanBlock<PBSOrder> {
+        let bits_per_block = self.bits_per_block();
+        let block_index = bit_index / bits_per_block;
+        assert!(
+            block_index < ct.blocks.len(),
+            "Bit index {bit_index} is out of range for a ciphertext of {} bits",
+            ct.blocks.len() * bits_per_block
+        );
+        let shift = bit_index % bits_per_block;
+        let lut = self.key.generate_accumulator(|x| (x >> shift) & 1);
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(&ct.blocks[block_index], &lut))
+    }
+
+    /// Extracts all the bits of a ciphertext encrypting an integer message.
+    ///
+    /// The bits are returned from the least significant to the most significant, each one costs a
+    /// single PBS and all of them are computed in parallel.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_to_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> Vec<BooleanBlock<PBSOrder>> {
+        let bits_per_block = self.bits_per_block();
+        let luts = (0..bits_per_block)
+            .map(|shift| self.key.generate_accumulator(|x| (x >> shift) & 1))
+            .collect::<Vec<_>>();
+
+        (0..ct.blocks.len() * bits_per_block)
+            .into_par_iter()
+            .map(|bit_index| {
+                let block = &ct.blocks[bit_index / bits_per_block];
+                let lut = &luts[bit_index % bits_per_block];
+                BooleanBlock::new_unchecked(self.key.apply_lookup_table(block, lut))
+            })
+            .collect()
+    }
+
+    /// Extracts all the bits of a ciphertext encrypting an integer message.
+    ///
+    /// The bits are returned from the least significant to the most significant.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bits = sks.to_bits_parallelized(&ct);
+    /// assert_eq!(bits.len(), 8);
+    ///
+    /// for (i, bit) in bits.iter().enumerate() {
+    ///     assert_eq!(cks.decrypt_bool(bit), (msg >> i) & 1 == 1);
+    /// }
+    /// ```
+    pub fn to_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> Vec<BooleanBlock<PBSOrder>> {
+        if ct.block_carries_are_empty() {
+            self.unchecked_to_bits_parallelized(ct)
+        } else {
+            let mut cleaned = ct.clone();
+            self.full_propagate_parallelized(&mut cleaned);
+            self.unchecked_to_bits_parallelized(&cleaned)
+        }
+    }
+
+    /// Assembles bits into a ciphertext encrypting an integer message.
+    ///
+    /// The bits are given from the least significant to the most significant, each group of
+    /// `log2(message_modulus)` bits forms one block of the output. The output has
+    /// `bits.len() / log2(message_modulus)` blocks, rounded up, with the missing most significant
+    /// bits set to zero.
+    ///
+    /// The blocks are assembled without carries propagation, and are refreshed with a single PBS
+    /// each, which means the output block carries are empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Reverse the order of the bits
+    /// let mut bits = sks.to_bits_parallelized(&ct);
+    /// bits.reverse();
+    ///
+    /// let ct_res = sks.from_bits_parallelized(&bits);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0b0110_0101);
+    /// ```
+    pub fn from_bits_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        bits: &[BooleanBlock<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        let bits_per_block = self.bits_per_block();
+
+        let blocks = bits
+            .par_chunks(bits_per_block)
+            .map(|chunk| {
+                let mut block = chunk[0].block.clone();
+                for (shift, bit) in chunk.iter().enumerate().skip(1) {
+                    let shifted_bit = self.key.unchecked_scalar_mul(&bit.block, 1 << shift);
+                    self.key.unchecked_add_assign(&mut block, &shifted_bit);
+                }
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
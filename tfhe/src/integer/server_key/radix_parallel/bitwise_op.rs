@@ -56,6 +56,7 @@ impl ServerKey {
     /// let dec_result: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(dec_result, msg1 & msg2);
     /// ```
+    #[deprecated(note = "the operands may be modified, use bitand_parallelized")]
     pub fn smart_bitand_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -70,6 +71,7 @@ impl ServerKey {
         self.unchecked_bitand_parallelized(ct_left, ct_right)
     }
 
+    #[deprecated(note = "the operands may be modified, use bitand_assign_parallelized")]
     pub fn smart_bitand_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -136,6 +138,8 @@ impl ServerKey {
         ct_left: &mut RadixCiphertext<PBSOrder>,
         ct_right: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("bitand");
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (
@@ -217,6 +221,7 @@ impl ServerKey {
     /// let dec_result: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(dec_result, msg1 | msg2);
     /// ```
+    #[deprecated(note = "the operands may be modified, use bitor_parallelized")]
     pub fn smart_bitor_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -231,6 +236,7 @@ impl ServerKey {
         self.unchecked_bitor_parallelized(ct_left, ct_right)
     }
 
+    #[deprecated(note = "the operands may be modified, use bitor_assign_parallelized")]
     pub fn smart_bitor_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -297,6 +303,8 @@ impl ServerKey {
         ct_left: &mut RadixCiphertext<PBSOrder>,
         ct_right: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("bitor");
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (
@@ -378,6 +386,7 @@ impl ServerKey {
     /// let dec_result: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(dec_result, msg1 ^ msg2);
     /// ```
+    #[deprecated(note = "the operands may be modified, use bitxor_parallelized")]
     pub fn smart_bitxor_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -392,6 +401,7 @@ impl ServerKey {
         self.unchecked_bitxor_parallelized(ct_left, ct_right)
     }
 
+    #[deprecated(note = "the operands may be modified, use bitxor_assign_parallelized")]
     pub fn smart_bitxor_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct_left: &mut RadixCiphertext<PBSOrder>,
@@ -458,6 +468,8 @@ impl ServerKey {
         ct_left: &mut RadixCiphertext<PBSOrder>,
         ct_right: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("bitxor");
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (
@@ -486,4 +498,114 @@ impl ServerKey {
 
         self.unchecked_bitxor_assign_parallelized(lhs, rhs);
     }
+
+    /// Computes homomorphically the bitwise not of a ciphertext encrypting an integer value.
+    ///
+    /// The carries of `ct` are propagated if they are not empty, the not itself needs no
+    /// bootstrap (see [`ServerKey::unchecked_bitnot`]).
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 14;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_bitnot_parallelized(&mut ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, !msg % 256);
+    /// ```
+    #[deprecated(note = "the operand may be modified, use bitnot_parallelized")]
+    pub fn smart_bitnot_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_bitnot(ct)
+    }
+
+    #[deprecated(note = "the operand may be modified, use bitnot_assign_parallelized")]
+    pub fn smart_bitnot_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_bitnot_assign(ct);
+    }
+
+    /// Computes homomorphically the bitwise not of a ciphertext encrypting an integer value.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// Each block `b` is replaced by `(message_modulus - 1) - b`, which needs no bootstrap once
+    /// the carries are empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 97;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.bitnot_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, !msg % 256);
+    /// ```
+    pub fn bitnot_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut ct_res = ct.clone();
+        self.bitnot_assign_parallelized(&mut ct_res);
+        ct_res
+    }
+
+    pub fn bitnot_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        let _timer = crate::metrics::OperationTimer::new("bitnot");
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_bitnot_assign(ct);
+    }
 }
@@ -0,0 +1,182 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Applies a bivariate function on each pair of corresponding blocks of two ciphertexts.
+    ///
+    /// The i-th block of the result encrypts `f(lhs_i, rhs_i) % message_modulus`, where `lhs_i`
+    /// and `rhs_i` are the messages of the i-th blocks of the inputs. No carry is propagated
+    /// between blocks, each block is computed with a single bivariate PBS and all blocks are
+    /// computed in parallel.
+    ///
+    /// The input ciphertexts must have empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_block_bimap_parallelized<PBSOrder, F>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+
+        let modulus = self.key.message_modulus.0 as u64;
+        let lut = self
+            .key
+            .generate_accumulator_bivariate(|x, y| f(x, y) % modulus);
+
+        let blocks = lhs
+            .blocks
+            .par_iter()
+            .zip(rhs.blocks.par_iter())
+            .map(|(lhs_block, rhs_block)| {
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(lhs_block, rhs_block, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Applies a bivariate function on each pair of corresponding blocks of two ciphertexts.
+    ///
+    /// The i-th block of the result encrypts `f(lhs_i, rhs_i) % message_modulus`, where `lhs_i`
+    /// and `rhs_i` are the messages of the i-th blocks of the inputs, which makes it possible to
+    /// compute on each digit of a radix ciphertext independently.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message, i.e. 4 digits in base 4
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 0b11_01_10_00u64;
+    /// let msg2 = 0b10_01_11_01u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Digit-wise saturating addition
+    /// let ct_res = sks.block_bimap_parallelized(&ct1, &ct2, |x, y| (x + y).min(3));
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0b11_10_11_01);
+    /// ```
+    pub fn block_bimap_parallelized<PBSOrder, F>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(u64, u64) -> u64,
+    {
+        let (lhs, rhs) = self.propagated_operands_parallelized(lhs, rhs);
+
+        self.unchecked_block_bimap_parallelized(&lhs, &rhs, f)
+    }
+
+    /// Applies a function on each block of a ciphertext, the function being given the index of
+    /// the block.
+    ///
+    /// The i-th block of the result encrypts `f(i, ct_i) % message_modulus`, where `ct_i` is the
+    /// message of the i-th block of the input. No carry is propagated between blocks, each block
+    /// is computed with a single PBS and all blocks are computed in parallel.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message, i.e. 4 digits in base 4
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b11_01_10_00u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Flip the low bit of the even digits
+    /// let ct_res = sks.block_map_parallelized(&ct, |i, x| if i % 2 == 0 { x ^ 1 } else { x });
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0b11_00_10_01);
+    /// ```
+    pub fn block_map_parallelized<PBSOrder, F>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        f: F,
+    ) -> RadixCiphertext<PBSOrder>
+    where
+        PBSOrder: PBSOrderMarker,
+        F: Fn(usize, u64) -> u64 + Sync,
+    {
+        let mut tmp_ct: RadixCiphertext<PBSOrder>;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let modulus = self.key.message_modulus.0 as u64;
+
+        let blocks = ct
+            .blocks
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let lut = self.key.generate_accumulator(|x| f(i, x) % modulus);
+                self.key.apply_lookup_table(block, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
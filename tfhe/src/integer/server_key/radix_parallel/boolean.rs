@@ -0,0 +1,146 @@
+use crate::integer::ciphertext::BooleanBlock;
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes the logical AND of all the booleans.
+    ///
+    /// The booleans are combined with a tree: as many booleans as the block can hold are summed
+    /// and a single PBS checks if their sum is equal to their count. The levels of the tree are
+    /// computed in parallel, so the depth is logarithmic in the number of booleans.
+    ///
+    /// An empty slice gives a trivial encryption of `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters cannot hold the sum of two booleans in a block.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let bits = sks.to_bits_parallelized(&ct);
+    ///
+    /// let all = sks.all_parallelized(&bits);
+    /// assert!(!cks.decrypt_bool(&all));
+    /// let all = sks.all_parallelized(&bits[1..3]);
+    /// assert!(cks.decrypt_bool(&all));
+    /// ```
+    pub fn all_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        booleans: &[BooleanBlock<PBSOrder>],
+    ) -> BooleanBlock<PBSOrder> {
+        self.reduce_booleans_parallelized(booleans, true)
+    }
+
+    /// Computes the logical OR of all the booleans.
+    ///
+    /// The booleans are combined with a tree: as many booleans as the block can hold are summed
+    /// and a single PBS checks if their sum is not zero. The levels of the tree are computed in
+    /// parallel, so the depth is logarithmic in the number of booleans.
+    ///
+    /// An empty slice gives a trivial encryption of `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters cannot hold the sum of two booleans in a block.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let bits = sks.to_bits_parallelized(&ct);
+    ///
+    /// let any = sks.any_parallelized(&bits);
+    /// assert!(cks.decrypt_bool(&any));
+    /// let any = sks.any_parallelized(&bits[3..5]);
+    /// assert!(!cks.decrypt_bool(&any));
+    /// ```
+    pub fn any_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        booleans: &[BooleanBlock<PBSOrder>],
+    ) -> BooleanBlock<PBSOrder> {
+        self.reduce_booleans_parallelized(booleans, false)
+    }
+
+    fn reduce_booleans_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        booleans: &[BooleanBlock<PBSOrder>],
+        all: bool,
+    ) -> BooleanBlock<PBSOrder> {
+        if booleans.is_empty() {
+            return BooleanBlock::new_unchecked(self.key.create_trivial(u64::from(all)));
+        }
+
+        // The sum of as many booleans as the max degree fits in a block
+        let chunk_size = self.key.max_degree.0;
+        assert!(
+            chunk_size >= 2,
+            "The parameters cannot hold the sum of two booleans in a block"
+        );
+
+        let mut blocks = booleans
+            .iter()
+            .map(|boolean| boolean.block.clone())
+            .collect::<Vec<_>>();
+
+        while blocks.len() > 1 {
+            blocks = blocks
+                .par_chunks(chunk_size)
+                .map(|chunk| self.reduce_boolean_chunk(chunk, all))
+                .collect();
+        }
+
+        BooleanBlock::new_unchecked(blocks.pop().unwrap())
+    }
+
+    fn reduce_boolean_chunk<PBSOrder: PBSOrderMarker>(
+        &self,
+        chunk: &[CiphertextBase<PBSOrder>],
+        all: bool,
+    ) -> CiphertextBase<PBSOrder> {
+        if chunk.len() == 1 {
+            return chunk[0].clone();
+        }
+
+        let mut sum = chunk[0].clone();
+        for block in &chunk[1..] {
+            self.key.unchecked_add_assign(&mut sum, block);
+        }
+
+        let count = chunk.len() as u64;
+        let lut = if all {
+            self.key.generate_accumulator(|x| u64::from(x == count))
+        } else {
+            self.key.generate_accumulator(|x| u64::from(x != 0))
+        };
+        self.key.apply_lookup_table(&sum, &lut)
+    }
+}
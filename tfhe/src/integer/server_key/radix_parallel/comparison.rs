@@ -1,8 +1,9 @@
 use super::ServerKey;
 
-use crate::integer::ciphertext::RadixCiphertext;
-use crate::integer::server_key::comparator::Comparator;
+use crate::integer::ciphertext::{BooleanBlock, OrderingBlock, RadixCiphertext};
+use crate::integer::server_key::comparator::{Comparator, EqualityStrategy};
 use crate::shortint::PBSOrderMarker;
+use std::cmp::Ordering;
 
 impl ServerKey {
     pub fn unchecked_eq_parallelized<PBSOrder: PBSOrderMarker>(
@@ -13,6 +14,17 @@ impl ServerKey {
         Comparator::new(self).unchecked_eq_parallelized(lhs, rhs)
     }
 
+    /// Computes homomorphically the equality of two ciphertexts using the given
+    /// [`EqualityStrategy`], [`Self::unchecked_eq_parallelized`] using the default one.
+    pub fn unchecked_eq_parallelized_with_strategy<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        strategy: EqualityStrategy,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_eq_parallelized_with_strategy(lhs, rhs, strategy)
+    }
+
     pub fn unchecked_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
@@ -61,6 +73,8 @@ impl ServerKey {
         Comparator::new(self).unchecked_min_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use eq_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_eq_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -69,6 +83,8 @@ impl ServerKey {
         Comparator::new(self).smart_eq_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use gt_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -77,6 +93,8 @@ impl ServerKey {
         Comparator::new(self).smart_gt_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use ge_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_ge_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -85,6 +103,8 @@ impl ServerKey {
         Comparator::new(self).smart_ge_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use lt_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_lt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -93,6 +113,8 @@ impl ServerKey {
         Comparator::new(self).smart_lt_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use le_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_le_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -101,6 +123,8 @@ impl ServerKey {
         Comparator::new(self).smart_le_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use max_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_max_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -109,6 +133,8 @@ impl ServerKey {
         Comparator::new(self).smart_max_parallelized(lhs, rhs)
     }
 
+    #[deprecated(note = "the operands may be modified, use min_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_min_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &mut RadixCiphertext<PBSOrder>,
@@ -122,14 +148,56 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("eq");
         Comparator::new(self).eq_parallelized(lhs, rhs)
     }
 
+    /// Computes homomorphically the equality of two ciphertexts using the given
+    /// [`EqualityStrategy`], [`Self::eq_parallelized`] using the default one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::EqualityStrategy;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt(201u64);
+    /// let ct2 = cks.encrypt(201u64);
+    /// let ct3 = cks.encrypt(73u64);
+    ///
+    /// for strategy in [
+    ///     EqualityStrategy::BlockDifferences,
+    ///     EqualityStrategy::ComparatorTree,
+    /// ] {
+    ///     let ct_res = sks.eq_parallelized_with_strategy(&ct1, &ct2, strategy);
+    ///     let dec: u64 = cks.decrypt(&ct_res);
+    ///     assert_eq!(dec, 1);
+    ///
+    ///     let ct_res = sks.eq_parallelized_with_strategy(&ct1, &ct3, strategy);
+    ///     let dec: u64 = cks.decrypt(&ct_res);
+    ///     assert_eq!(dec, 0);
+    /// }
+    /// ```
+    pub fn eq_parallelized_with_strategy<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+        strategy: EqualityStrategy,
+    ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("eq");
+        Comparator::new(self).eq_parallelized_with_strategy(lhs, rhs, strategy)
+    }
+
     pub fn gt_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("gt");
         Comparator::new(self).gt_parallelized(lhs, rhs)
     }
 
@@ -138,6 +206,7 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("ge");
         Comparator::new(self).ge_parallelized(lhs, rhs)
     }
 
@@ -146,6 +215,7 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("lt");
         Comparator::new(self).lt_parallelized(lhs, rhs)
     }
 
@@ -154,6 +224,7 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("le");
         Comparator::new(self).le_parallelized(lhs, rhs)
     }
 
@@ -162,6 +233,7 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("max");
         Comparator::new(self).max_parallelized(lhs, rhs)
     }
 
@@ -170,6 +242,214 @@ impl ServerKey {
         lhs: &RadixCiphertext<PBSOrder>,
         rhs: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("min");
         Comparator::new(self).min_parallelized(lhs, rhs)
     }
+
+    pub fn unchecked_count_greater_than_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        threshold: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_count_greater_than_parallelized(cts, threshold)
+    }
+
+    /// Computes homomorphically the number of values of `cts` which are strictly greater than
+    /// `threshold`.
+    ///
+    /// The comparison results are never decrypted nor summed one by one: they are aggregated in
+    /// a tree, so the depth of the computation grows logarithmically with the number of values.
+    /// The result has just enough blocks to hold `cts.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let values = [12u64, 200, 37, 150, 99];
+    /// let cts = values.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    /// let threshold = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.count_greater_than_parallelized(&cts, &threshold);
+    ///
+    /// let count: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn count_greater_than_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+        threshold: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).count_greater_than_parallelized(cts, threshold)
+    }
+
+    pub fn unchecked_argmax_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_argmax_parallelized(cts)
+    }
+
+    pub fn unchecked_argmin_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).unchecked_argmin_parallelized(cts)
+    }
+
+    /// Computes homomorphically the index of the greatest value of `cts`.
+    ///
+    /// The values are reduced in a tree of comparisons, each comparison selecting both the
+    /// greatest value and its index. On equality the lowest index is returned. The result has
+    /// just enough blocks to hold `cts.len() - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let logits = [12u64, 200, 37, 150];
+    /// let cts = logits.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmax_parallelized(&cts);
+    ///
+    /// let index: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn argmax_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).argmax_parallelized(cts)
+    }
+
+    /// Computes homomorphically the index of the smallest value of `cts`.
+    ///
+    /// See [`Self::argmax_parallelized`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty.
+    pub fn argmin_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &[RadixCiphertext<PBSOrder>],
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self).argmin_parallelized(cts)
+    }
+
+    pub fn unchecked_cmp_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> OrderingBlock<PBSOrder> {
+        Comparator::new(self).unchecked_cmp_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the [`Ordering`] of `lhs` compared to `rhs`.
+    ///
+    /// The result is a single block encrypting 0 for `Less`, 1 for `Equal` and 2 for `Greater`,
+    /// computed with the same comparison tree as [`Self::lt_parallelized`]. A routine which needs
+    /// several of lt, eq and gt (e.g. a sort or a binary search) only pays for one comparison,
+    /// the results are then derived with [`Self::ordering_is`] or used directly with
+    /// [`Self::select_by_ordering_parallelized`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct_1 = cks.encrypt(97u64);
+    /// let ct_2 = cks.encrypt(142u64);
+    ///
+    /// let ordering = sks.cmp_parallelized(&ct_1, &ct_2);
+    /// assert_eq!(cks.decrypt_ordering(&ordering), Ordering::Less);
+    ///
+    /// let is_greater = sks.ordering_is(&ordering, Ordering::Greater);
+    /// assert!(!cks.decrypt_bool(&is_greater));
+    /// ```
+    pub fn cmp_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> OrderingBlock<PBSOrder> {
+        let _timer = crate::metrics::OperationTimer::new("cmp");
+        Comparator::new(self).cmp_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically whether `ordering` encrypts `expected`, with a single PBS.
+    pub fn ordering_is<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        expected: Ordering,
+    ) -> BooleanBlock<PBSOrder> {
+        Comparator::new(self).ordering_is(ordering, expected)
+    }
+
+    pub fn unchecked_select_by_ordering_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        if_less: &RadixCiphertext<PBSOrder>,
+        if_equal: &RadixCiphertext<PBSOrder>,
+        if_greater: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self)
+            .unchecked_select_by_ordering_parallelized(ordering, if_less, if_equal, if_greater)
+    }
+
+    /// Selects homomorphically `if_less`, `if_equal` or `if_greater` according to `ordering`.
+    ///
+    /// This is a three-way cmux: each block of the candidates is masked according to the
+    /// ordering and the masked blocks are summed, the ordering is never decrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the candidates do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let ct_1 = cks.encrypt(200u64);
+    /// let ct_2 = cks.encrypt(13u64);
+    ///
+    /// // Clamp ct_1 to ct_2, and return 1 on equality
+    /// let ordering = sks.cmp_parallelized(&ct_1, &ct_2);
+    /// let one = cks.encrypt(1u64);
+    /// let ct_res = sks.select_by_ordering_parallelized(&ordering, &ct_1, &one, &ct_2);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 13);
+    /// ```
+    pub fn select_by_ordering_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ordering: &OrderingBlock<PBSOrder>,
+        if_less: &RadixCiphertext<PBSOrder>,
+        if_equal: &RadixCiphertext<PBSOrder>,
+        if_greater: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        Comparator::new(self)
+            .select_by_ordering_parallelized(ordering, if_less, if_equal, if_greater)
+    }
 }
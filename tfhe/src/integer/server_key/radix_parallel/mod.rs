@@ -1,20 +1,29 @@
 mod add;
+mod bits;
 mod bitwise_op;
+mod block_map;
+mod boolean;
 mod comparison;
 mod mul;
 mod neg;
+mod overflow;
+mod permutation;
+mod rotate;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
 mod sub;
 
+pub use shift::RoundingMode;
+
 #[cfg(test)]
 mod tests;
 
 use super::ServerKey;
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::shortint::PBSOrderMarker;
+use std::borrow::Cow;
 
 // parallelized versions
 impl ServerKey {
@@ -48,7 +57,7 @@ impl ServerKey {
         ctxt: &mut RadixCiphertext<PBSOrder>,
         index: usize,
     ) {
-        let (carry, message) = rayon::join(
+        let (carry, message) = self.budgeted_join(
             || self.key.carry_extract(&ctxt.blocks[index]),
             || self.key.message_extract(&ctxt.blocks[index]),
         );
@@ -95,4 +104,42 @@ impl ServerKey {
             self.propagate_parallelized(ctxt, i);
         }
     }
+
+    /// Returns the operands with empty block carries, ready to be given to an unchecked
+    /// operation.
+    ///
+    /// An operand whose carries are already empty is borrowed, otherwise a copy of it is
+    /// propagated, so the inputs are never modified. When both operands need it, the two
+    /// propagations run in parallel.
+    pub(crate) fn propagated_operands_parallelized<'a, PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &'a RadixCiphertext<PBSOrder>,
+        rhs: &'a RadixCiphertext<PBSOrder>,
+    ) -> (
+        Cow<'a, RadixCiphertext<PBSOrder>>,
+        Cow<'a, RadixCiphertext<PBSOrder>>,
+    ) {
+        match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (Cow::Borrowed(lhs), Cow::Borrowed(rhs)),
+            (true, false) => {
+                let mut tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (Cow::Borrowed(lhs), Cow::Owned(tmp_rhs))
+            }
+            (false, true) => {
+                let mut tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (Cow::Owned(tmp_lhs), Cow::Borrowed(rhs))
+            }
+            (false, false) => {
+                let mut tmp_lhs = lhs.clone();
+                let mut tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (Cow::Owned(tmp_lhs), Cow::Owned(tmp_rhs))
+            }
+        }
+    }
 }
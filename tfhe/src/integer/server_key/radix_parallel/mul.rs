@@ -1,5 +1,6 @@
 use std::sync::Mutex;
 
+use crate::integer::cancellation::{CancellationToken, Cancelled};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
 use crate::shortint::PBSOrderMarker;
@@ -136,6 +137,7 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ct_res);
     /// assert_eq!((clear_1 * clear_2) % 256, res);
     /// ```
+    #[deprecated(note = "the operands may be modified, use block_mul_parallelized")]
     pub fn smart_block_mul_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct1: &mut RadixCiphertext<PBSOrder>,
@@ -152,7 +154,7 @@ impl ServerKey {
         self.unchecked_block_mul_lsb_msb_parallelized(&mut result_lsb, &mut result_msb, ct2, index);
         result_msb = self.blockshift(&result_msb, 1);
 
-        self.smart_add_parallelized(&mut result_lsb, &mut result_msb)
+        self.degree_aware_add_parallelized(&result_lsb, &result_msb)
     }
 
     /// Computes homomorphically a multiplication between a ciphertext encrypting integer value
@@ -229,7 +231,7 @@ impl ServerKey {
             }
             (false, false) => {
                 tmp_rhs = ct2.clone();
-                rayon::join(
+                self.budgeted_join(
                     || self.full_propagate_parallelized(ct1),
                     || self.key.clear_carry_assign(&mut tmp_rhs),
                 );
@@ -248,10 +250,13 @@ impl ServerKey {
         index: usize,
     ) {
         let len = result_msb.blocks.len() - 1;
-        rayon::join(
+        // Both halves share the budget
+        let min_len = self.par_min_len(2 * (len + 1 - index));
+        self.budgeted_join(
             || {
                 result_lsb.blocks[index..]
                     .par_iter_mut()
+                    .with_min_len(min_len)
                     .for_each(|res_lsb_i| {
                         self.key.unchecked_mul_lsb_assign(res_lsb_i, ct2);
                     });
@@ -259,6 +264,7 @@ impl ServerKey {
             || {
                 result_msb.blocks[index..len]
                     .par_iter_mut()
+                    .with_min_len(min_len)
                     .for_each(|res_msb_i| {
                         self.key.unchecked_mul_msb_assign(res_msb_i, ct2);
                     });
@@ -266,6 +272,8 @@ impl ServerKey {
         );
     }
 
+    #[deprecated(note = "the operands may be modified, use block_mul_assign_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_block_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct1: &mut RadixCiphertext<PBSOrder>,
@@ -300,11 +308,11 @@ impl ServerKey {
     /// let clear_2 = 143;
     ///
     /// // Encrypt two messages
-    /// let mut ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_1 = cks.encrypt(clear_1);
     /// let ctxt_2 = cks.encrypt(clear_2);
     ///
     /// // Compute homomorphically a multiplication
-    /// let ct_res = sks.unchecked_mul_parallelized(&mut ctxt_1, &ctxt_2);
+    /// let ct_res = sks.unchecked_mul_parallelized(&ctxt_1, &ctxt_2);
     ///
     /// // Decrypt
     /// let res: u64 = cks.decrypt(&ct_res);
@@ -325,30 +333,33 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// The operands used to be taken as `&mut` even though they were never modified, they are
+    /// now taken as `&`. Callers giving `&mut` references still compile, but code naming the
+    /// function type (e.g. a `fn` pointer) must be updated.
+    ///
     /// # Warning
     ///
     /// - Multithreaded
     pub fn unchecked_mul_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
-        ct1: &mut RadixCiphertext<PBSOrder>,
+        ct1: &RadixCiphertext<PBSOrder>,
         ct2: &RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        let mut result = self.create_trivial_zero_radix(ct1.blocks.len());
-
         let terms = Mutex::new(Vec::new());
 
-        ct2.blocks.par_iter().enumerate().for_each(|(i, ct2_i)| {
-            let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
-            terms.lock().unwrap().push(term);
-        });
+        ct2.blocks
+            .par_iter()
+            .with_min_len(self.par_min_len(ct2.blocks.len()))
+            .enumerate()
+            .for_each(|(i, ct2_i)| {
+                let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
+                terms.lock().unwrap().push(term);
+            });
 
-        let mut terms = terms.into_inner().unwrap();
+        let terms = terms.into_inner().unwrap();
 
-        for term in terms.iter_mut() {
-            self.smart_add_assign(&mut result, term);
-        }
-
-        result
+        self.default_binary_op_seq_parallelized(&terms, ServerKey::degree_aware_add_parallelized)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(ct1.blocks.len()))
     }
 
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -382,6 +393,8 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ct_res);
     /// assert_eq!((clear_1 * clear_2) % 256, res);
     /// ```
+    #[deprecated(note = "the operands may be modified, use mul_assign_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct1: &mut RadixCiphertext<PBSOrder>,
@@ -397,24 +410,29 @@ impl ServerKey {
     /// # Warning
     ///
     /// - Multithreaded
+    #[deprecated(note = "the operands may be modified, use mul_parallelized")]
     pub fn smart_mul_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct1: &mut RadixCiphertext<PBSOrder>,
         ct2: &mut RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
-        rayon::join(
+        self.budgeted_join(
             || self.full_propagate_parallelized(ct1),
             || self.full_propagate_parallelized(ct2),
         );
 
         let terms = Mutex::new(Vec::new());
-        ct2.blocks.par_iter().enumerate().for_each(|(i, ct2_i)| {
-            let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
-            terms.lock().unwrap().push(term);
-        });
-        let mut terms = terms.into_inner().unwrap();
+        ct2.blocks
+            .par_iter()
+            .with_min_len(self.par_min_len(ct2.blocks.len()))
+            .enumerate()
+            .for_each(|(i, ct2_i)| {
+                let term = self.unchecked_block_mul_parallelized(ct1, ct2_i, i);
+                terms.lock().unwrap().push(term);
+            });
+        let terms = terms.into_inner().unwrap();
 
-        self.smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
+        self.default_binary_op_seq_parallelized(&terms, ServerKey::degree_aware_add_parallelized)
             .unwrap_or_else(|| self.create_trivial_zero_radix(ct1.blocks.len()))
     }
 
@@ -431,6 +449,9 @@ impl ServerKey {
     /// example) has always the same performance characteristics from one call to another and
     /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
     ///
+    /// When one of the operands is a trivial ciphertext, the product is computed as a scalar
+    /// multiplication.
+    ///
     /// # Warning
     ///
     /// - Multithreaded
@@ -473,6 +494,18 @@ impl ServerKey {
         ct1: &mut RadixCiphertext<PBSOrder>,
         ct2: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("mul");
+
+        // A trivial operand is a public constant, the product is a scalar multiplication
+        if let Some(scalar) = ct2.decrypt_trivial() {
+            self.scalar_mul_assign_parallelized(ct1, scalar);
+            return;
+        }
+        if let Some(scalar) = ct1.decrypt_trivial() {
+            *ct1 = self.scalar_mul_parallelized(ct2, scalar);
+            return;
+        }
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (ct1.block_carries_are_empty(), ct2.block_carries_are_empty()) {
@@ -488,7 +521,7 @@ impl ServerKey {
             }
             (false, false) => {
                 tmp_rhs = ct2.clone();
-                rayon::join(
+                self.budgeted_join(
                     || self.full_propagate_parallelized(ct1),
                     || self.full_propagate_parallelized(&mut tmp_rhs),
                 );
@@ -501,14 +534,127 @@ impl ServerKey {
         terms
             .par_iter_mut()
             .zip(rhs.blocks.par_iter().enumerate())
+            .with_min_len(self.par_min_len(num_blocks))
             .for_each(|(term, (i, rhs_i))| {
                 *term = self.unchecked_block_mul_parallelized(lhs, rhs_i, i);
             });
 
         *lhs = self
-            .smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
+            .default_binary_op_seq_parallelized(&terms, ServerKey::degree_aware_add_parallelized)
             .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
 
         self.full_propagate_parallelized(lhs);
     }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// checking `token` between the block-level steps.
+    ///
+    /// This is the cancellable variant of [`ServerKey::mul_parallelized`], it returns
+    /// [`Cancelled`] if `token` is cancelled before the multiplication completes, see
+    /// [`crate::integer::cancellation`].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::CancellationToken;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clear_1 = 170;
+    /// let clear_2 = 6;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let token = CancellationToken::new();
+    /// let ct_res = sks
+    ///     .mul_parallelized_cancellable(&ctxt_1, &ctxt_2, &token)
+    ///     .unwrap();
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 * clear_2) % 256, res);
+    /// ```
+    pub fn mul_parallelized_cancellable<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct1: &RadixCiphertext<PBSOrder>,
+        ct2: &RadixCiphertext<PBSOrder>,
+        token: &CancellationToken,
+    ) -> Result<RadixCiphertext<PBSOrder>, Cancelled> {
+        token.check()?;
+
+        let mut lhs = ct1.clone();
+        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
+
+        let rhs = match (lhs.block_carries_are_empty(), ct2.block_carries_are_empty()) {
+            (true, true) => ct2,
+            (true, false) => {
+                tmp_rhs = ct2.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                &tmp_rhs
+            }
+            (false, true) => {
+                self.full_propagate_parallelized(&mut lhs);
+                ct2
+            }
+            (false, false) => {
+                tmp_rhs = ct2.clone();
+                self.budgeted_join(
+                    || self.full_propagate_parallelized(&mut lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                &tmp_rhs
+            }
+        };
+
+        token.check()?;
+
+        let num_blocks = lhs.blocks.len();
+        // The block multiplications not started yet are skipped once the token is cancelled
+        let mut terms = rhs
+            .blocks
+            .par_iter()
+            .enumerate()
+            .with_min_len(self.par_min_len(num_blocks))
+            .map(|(i, rhs_i)| {
+                if token.is_cancelled() {
+                    None
+                } else {
+                    Some(self.unchecked_block_mul_parallelized(&lhs, rhs_i, i))
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Cancelled)?;
+
+        // The terms are summed as a tree, the token is checked between each level
+        while terms.len() > 1 {
+            token.check()?;
+            terms = terms
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => self.degree_aware_add_parallelized(lhs, rhs),
+                    [last] => last.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        let mut result = terms
+            .pop()
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
+
+        token.check()?;
+        self.full_propagate_parallelized(&mut result);
+
+        Ok(result)
+    }
 }
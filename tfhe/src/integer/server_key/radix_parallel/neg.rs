@@ -29,10 +29,15 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(255, dec);
     /// ```
+    #[deprecated(note = "the operand may be modified, use neg_parallelized")]
     pub fn smart_neg_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
     ) -> RadixCiphertext<PBSOrder> {
+        if ctxt.block_carries_are_empty() && self.is_neg_by_bitnot_possible() {
+            return self.unchecked_neg_by_bitnot(ctxt);
+        }
+
         if !self.is_neg_possible(ctxt) {
             self.full_propagate_parallelized(ctxt);
         }
@@ -80,14 +85,40 @@ impl ServerKey {
     ) -> RadixCiphertext<PBSOrder> {
         let mut tmp_ctxt: RadixCiphertext<PBSOrder>;
 
-        let mut ctxt = if ctxt.block_carries_are_empty() {
-            self.unchecked_neg(ctxt)
+        let ctxt = if ctxt.block_carries_are_empty() {
+            ctxt
         } else {
             tmp_ctxt = ctxt.clone();
             self.full_propagate_parallelized(&mut tmp_ctxt);
-            self.unchecked_neg(&tmp_ctxt)
+            &tmp_ctxt
+        };
+
+        let mut ctxt = if self.is_neg_by_bitnot_possible() {
+            self.unchecked_neg_by_bitnot(ctxt)
+        } else {
+            self.unchecked_neg(ctxt)
         };
         self.full_propagate_parallelized(&mut ctxt);
         ctxt
     }
+
+    /// Whether a block of degree `message_modulus` fits in the blocks, which is needed to add 1 to
+    /// the bitwise not of a ciphertext.
+    fn is_neg_by_bitnot_possible(&self) -> bool {
+        self.key.max_degree.0 >= self.key.message_modulus.0
+    }
+
+    /// Computes the opposite of a ciphertext whose carries are empty as `!ctxt + 1`.
+    ///
+    /// Neither the not nor the addition of 1 needs a bootstrap, and only the first block of the
+    /// result has a non-empty carry, where [`ServerKey::unchecked_neg`] leaves correcting terms
+    /// in the carries of all the blocks.
+    fn unchecked_neg_by_bitnot<PBSOrder: PBSOrderMarker>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = self.unchecked_bitnot(ctxt);
+        self.unchecked_scalar_add_assign(&mut result, 1);
+        result
+    }
 }
@@ -0,0 +1,793 @@
+use rayon::prelude::*;
+
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::{CiphertextBase, MessageModulus, PBSOrderMarker};
+
+impl ServerKey {
+    /// Computes homomorphically an addition between two ciphertexts encrypting unsigned integer
+    /// values, and whether it overflowed.
+    ///
+    /// The result wraps around like [`ServerKey::add_parallelized`], the returned block encrypts 1
+    /// if the addition overflowed and 0 otherwise.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u64;
+    /// let msg2 = 100u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(dec_result, (msg1 + msg2) % 256);
+    /// assert_eq!(dec_overflowed, 1);
+    /// ```
+    pub fn overflowing_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut result = ct_left.clone();
+        let mut tmp_rhs: RadixCiphertext<PBSOrder>;
+
+        let rhs = match (
+            result.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => ct_right,
+            (true, false) => {
+                tmp_rhs = ct_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                &tmp_rhs
+            }
+            (false, true) => {
+                self.full_propagate_parallelized(&mut result);
+                ct_right
+            }
+            (false, false) => {
+                tmp_rhs = ct_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut result),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                &tmp_rhs
+            }
+        };
+
+        self.unchecked_add_assign(&mut result, rhs);
+        let overflowed = self.propagate_with_final_carry_parallelized(&mut result);
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically a subtraction between two ciphertexts encrypting unsigned integer
+    /// values, and whether it overflowed (i.e. `ct_left < ct_right`).
+    ///
+    /// The result wraps around like [`ServerKey::sub_parallelized`], the returned block encrypts 1
+    /// if the subtraction overflowed and 0 otherwise.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 100u64;
+    /// let msg2 = 200u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(dec_result, msg1.wrapping_sub(msg2) % 256);
+    /// assert_eq!(dec_overflowed, 1);
+    /// ```
+    pub fn overflowing_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let (result, mut is_lower) = rayon::join(
+            || self.sub_parallelized(ct_left, ct_right),
+            || self.lt_parallelized(ct_left, ct_right),
+        );
+        (result, is_lower.blocks.swap_remove(0))
+    }
+
+    /// Computes homomorphically the addition of a ciphertext encrypting an unsigned integer value
+    /// with a scalar, and whether it overflowed.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 200u64;
+    /// let scalar = 40u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_scalar_add_parallelized(&ct, scalar);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(dec_result, msg + scalar);
+    /// assert_eq!(dec_overflowed, 0);
+    /// ```
+    pub fn overflowing_scalar_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut result = ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+
+        if self.radix_max_value_is_lower_than(&result, |max_value| {
+            max_value.checked_add(u128::from(scalar))
+        }) {
+            // The degrees show the addition cannot overflow
+            self.scalar_add_assign_parallelized(&mut result, scalar);
+            return (result, self.key.create_trivial(0));
+        }
+
+        self.unchecked_scalar_add_assign(&mut result, scalar);
+        let overflowed = self.propagate_with_final_carry_parallelized(&mut result);
+
+        if self.scalar_exceeds_radix_range(scalar, ct.blocks.len()) {
+            // The scalar alone does not fit, the addition always overflows
+            return (result, self.key.create_trivial(1));
+        }
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically the subtraction of a scalar to a ciphertext encrypting an unsigned
+    /// integer value, and whether it overflowed (i.e. `ct < scalar`).
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 10u64;
+    /// let scalar = 40u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_scalar_sub_parallelized(&ct, scalar);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(dec_result, msg.wrapping_sub(scalar) % 256);
+    /// assert_eq!(dec_overflowed, 1);
+    /// ```
+    pub fn overflowing_scalar_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        if self.scalar_exceeds_radix_range(scalar, ct.blocks.len()) {
+            // The ciphertext is always lower than the scalar
+            return (
+                self.scalar_sub_parallelized(ct, scalar),
+                self.key.create_trivial(1),
+            );
+        }
+
+        let (result, no_borrow) = self.scalar_sub_with_carry_parallelized(ct, scalar);
+        let not_acc = self.key.generate_accumulator(|x| u64::from(x == 0));
+        let overflowed = self.key.apply_lookup_table(&no_borrow, &not_acc);
+        (result, overflowed)
+    }
+
+    /// Subtracts a scalar from a ciphertext encrypting an unsigned integer value if the
+    /// ciphertext is greater or equal to the scalar, leaving it unchanged otherwise.
+    ///
+    /// Returns the result and whether the ciphertext was greater or equal to the scalar. This is
+    /// the "check the balance then withdraw" operation: the comparison is read from the borrow of
+    /// the subtraction instead of being computed by its own circuit, so it costs one carry
+    /// propagation and 3 PBS per block to keep the original value when the subtraction borrows.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let balance = cks.encrypt(100u64);
+    ///
+    /// let (balance, withdrawn) = sks.scalar_ge_via_sub_parallelized(&balance, 30);
+    /// let dec_balance: u64 = cks.decrypt(&balance);
+    /// assert_eq!(dec_balance, 70);
+    /// assert!(cks.decrypt_bool(&withdrawn));
+    ///
+    /// let (balance, withdrawn) = sks.scalar_ge_via_sub_parallelized(&balance, 80);
+    /// let dec_balance: u64 = cks.decrypt(&balance);
+    /// assert_eq!(dec_balance, 70);
+    /// assert!(!cks.decrypt_bool(&withdrawn));
+    /// ```
+    pub fn scalar_ge_via_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> (RadixCiphertext<PBSOrder>, BooleanBlock<PBSOrder>) {
+        let mut original = ct.clone();
+        if !original.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut original);
+        }
+
+        if self.scalar_exceeds_radix_range(scalar, ct.blocks.len()) {
+            // The ciphertext is always lower than the scalar
+            return (
+                original,
+                BooleanBlock::new_unchecked(self.key.create_trivial(0)),
+            );
+        }
+
+        let (difference, is_ge) = self.scalar_sub_with_carry_parallelized(&original, scalar);
+        let result = self.unchecked_select_parallelized(&is_ge, &difference, &original);
+        (result, BooleanBlock::new_unchecked(is_ge))
+    }
+
+    /// Computes homomorphically the multiplication of a ciphertext encrypting an unsigned
+    /// integer value by a scalar, and whether it overflowed.
+    ///
+    /// The result wraps around like [`ServerKey::scalar_mul_parallelized`], the returned block
+    /// encrypts 1 if the multiplication overflowed and 0 otherwise.
+    ///
+    /// When the degrees of the blocks show that the multiplication cannot overflow, the returned
+    /// block is a trivial encryption of 0 and no work is spent on the detection. Otherwise the
+    /// product is computed on enough blocks to hold it entirely, the overflow being detected on
+    /// the blocks exceeding the input size.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 100u64;
+    /// let scalar = 3u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.overflowing_scalar_mul_parallelized(&ct, scalar);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// assert_eq!(dec_result, (msg * scalar) % 256);
+    /// assert_eq!(dec_overflowed, 1);
+    /// ```
+    pub fn overflowing_scalar_mul_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut result = ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+
+        if self.radix_max_value_is_lower_than(&result, |max_value| {
+            max_value.checked_mul(u128::from(scalar))
+        }) {
+            // The degrees show the multiplication cannot overflow
+            self.scalar_mul_assign_parallelized(&mut result, scalar);
+            return (result, self.key.create_trivial(0));
+        }
+
+        // Extend the ciphertext with enough blocks to hold the bits of the scalar
+        let num_blocks = result.blocks.len();
+        let bits_in_block = (self.key.message_modulus.0 as f64).log2() as u32;
+        let scalar_bits = u64::BITS - scalar.leading_zeros();
+        let extra_blocks = ((scalar_bits + bits_in_block - 1) / bits_in_block) as usize;
+        result
+            .blocks
+            .extend((0..extra_blocks).map(|_| self.key.create_trivial(0)));
+
+        self.scalar_mul_assign_parallelized(&mut result, scalar);
+
+        let high_blocks = result.blocks.split_off(num_blocks);
+        let overflowed = self.unchecked_any_block_non_zero_parallelized(high_blocks);
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically an addition between two ciphertexts encrypting unsigned integer
+    /// values, saturating at the maximum value instead of wrapping around.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 200u64;
+    /// let msg2 = 100u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 255);
+    /// ```
+    pub fn saturating_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_add_parallelized(ct_left, ct_right);
+        let max_block = self.key.message_modulus.0 as u64 - 1;
+        self.unchecked_fill_blocks_if_assign_parallelized(&mut result, &overflowed, max_block);
+        result
+    }
+
+    /// Computes homomorphically a subtraction between two ciphertexts encrypting unsigned integer
+    /// values, saturating at zero instead of wrapping around.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = 100u64;
+    /// let msg2 = 200u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn saturating_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_sub_parallelized(ct_left, ct_right);
+        self.unchecked_fill_blocks_if_assign_parallelized(&mut result, &overflowed, 0);
+        result
+    }
+
+    /// Computes homomorphically the addition of a ciphertext encrypting an unsigned integer value
+    /// with a scalar, saturating at the maximum value instead of wrapping around.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 250u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.saturating_scalar_add_parallelized(&ct, 10);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 255);
+    /// ```
+    pub fn saturating_scalar_add_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_scalar_add_parallelized(ct, scalar);
+        let max_block = self.key.message_modulus.0 as u64 - 1;
+        self.unchecked_fill_blocks_if_assign_parallelized(&mut result, &overflowed, max_block);
+        result
+    }
+
+    /// Computes homomorphically the subtraction of a scalar to a ciphertext encrypting an unsigned
+    /// integer value, saturating at zero instead of wrapping around.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 5u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.saturating_scalar_sub_parallelized(&ct, 10);
+    ///
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0);
+    /// ```
+    pub fn saturating_scalar_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let (mut result, overflowed) = self.overflowing_scalar_sub_parallelized(ct, scalar);
+        self.unchecked_fill_blocks_if_assign_parallelized(&mut result, &overflowed, 0);
+        result
+    }
+
+    /// Propagates the carries of all the blocks, returning the carry out of the last block
+    /// as a block encrypting 0 or 1 instead of discarding it.
+    ///
+    /// Expects the carry of the last block to be at most 1 once the carries of the previous
+    /// blocks are propagated, which is the case after adding two clean ciphertexts.
+    fn propagate_with_final_carry_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) -> CiphertextBase<PBSOrder> {
+        let last_index = ct.blocks.len() - 1;
+        for i in 0..last_index {
+            self.propagate_parallelized(ct, i);
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let carry_acc = self
+            .key
+            .generate_accumulator(|x| u64::from(x >= message_modulus));
+        let last_block = &ct.blocks[last_index];
+        let (carry, message) = self.budgeted_join(
+            || self.key.apply_lookup_table(last_block, &carry_acc),
+            || self.key.message_extract(last_block),
+        );
+        ct.blocks[last_index] = message;
+        carry
+    }
+
+    /// Replaces every block of `ct` by `block_value` if `condition` encrypts 1, leaving `ct`
+    /// unchanged if it encrypts 0.
+    ///
+    /// This is a cmux against a constant, done in one bivariate PBS per block.
+    ///
+    /// Expects the carries of `ct` to be empty and `condition` to have a degree of at most 1.
+    pub(crate) fn unchecked_fill_blocks_if_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        condition: &CiphertextBase<PBSOrder>,
+        block_value: u64,
+    ) {
+        debug_assert!(condition.degree.0 <= 1);
+        // The condition is at most 1, so scaling the block by 2 is enough to pack both
+        let acc = self.key.generate_accumulator_bivariate_with_factor(
+            |block, condition| if condition == 1 { block_value } else { block },
+            MessageModulus(2),
+        );
+        ct.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &acc);
+        });
+    }
+
+    /// Subtracts `ct_right` from `ct_left` if `ct_left` is greater or equal to `ct_right`, leaving
+    /// it unchanged otherwise.
+    ///
+    /// Returns the result and whether `ct_left` was greater or equal to `ct_right`, which is read
+    /// from the borrow of the subtraction like in [`ServerKey::scalar_ge_via_sub_parallelized`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let balance = cks.encrypt(100u64);
+    /// let amount = cks.encrypt(130u64);
+    ///
+    /// let (balance, withdrawn) = sks.ge_via_sub_parallelized(&balance, &amount);
+    /// let dec_balance: u64 = cks.decrypt(&balance);
+    /// assert_eq!(dec_balance, 100);
+    /// assert!(!cks.decrypt_bool(&withdrawn));
+    /// ```
+    pub fn ge_via_sub_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &RadixCiphertext<PBSOrder>,
+        ct_right: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, BooleanBlock<PBSOrder>) {
+        assert_eq!(
+            ct_left.blocks.len(),
+            ct_right.blocks.len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+
+        let mut lhs = ct_left.clone();
+        let mut rhs = ct_right.clone();
+        self.budgeted_join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut rhs);
+                }
+            },
+        );
+
+        let (difference, is_ge) = self.unchecked_sub_with_carry_parallelized(&lhs, &rhs);
+        let result = self.unchecked_select_parallelized(&is_ge, &difference, &lhs);
+        (result, BooleanBlock::new_unchecked(is_ge))
+    }
+
+    /// Returns, block by block, `condition * (lhs - rhs)` modulo the message modulus, with
+    /// `condition` encrypting 0 or 1.
+    ///
+    /// Expects the carries of the ciphertexts to be empty and `condition` to have a degree of at
+    /// most 1. Costs 2 bivariate PBS per block.
+    pub(crate) fn unchecked_masked_difference_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<PBSOrder>,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        debug_assert!(condition.degree.0 <= 1);
+        let mut difference = self.unchecked_block_bimap_parallelized(lhs, rhs, u64::wrapping_sub);
+
+        // The condition is at most 1, so scaling the block by 2 is enough to pack both
+        let acc = self.key.generate_accumulator_bivariate_with_factor(
+            |difference, condition| if condition == 1 { difference } else { 0 },
+            MessageModulus(2),
+        );
+        difference.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &acc);
+        });
+        difference
+    }
+
+    /// Returns `then` if `condition` encrypts 1 and `otherwise` if it encrypts 0.
+    ///
+    /// Expects the carries of the ciphertexts to be empty and `condition` to have a degree of at
+    /// most 1. Costs 3 bivariate PBS per block, the result carries are empty.
+    pub(crate) fn unchecked_select_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        condition: &CiphertextBase<PBSOrder>,
+        then: &RadixCiphertext<PBSOrder>,
+        otherwise: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let masked = self.unchecked_masked_difference_parallelized(condition, then, otherwise);
+        self.unchecked_block_bimap_parallelized(otherwise, &masked, u64::wrapping_add)
+    }
+
+    /// Computes `ct - scalar` and the carry out of `ct + (modulus - scalar)`, which encrypts 1
+    /// exactly when `ct >= scalar`.
+    ///
+    /// Expects `scalar` to be in the range of the ciphertext.
+    fn scalar_sub_with_carry_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: u64,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        let mut result = ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+
+        // modulus - scalar is the complement of each digit of the scalar, plus one
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let mut remaining = scalar;
+        for (i, block) in result.blocks.iter_mut().enumerate() {
+            let digit = remaining % message_modulus;
+            remaining /= message_modulus;
+            let complement = message_modulus - 1 - digit + u64::from(i == 0);
+            self.key
+                .unchecked_scalar_add_assign(block, complement as u8);
+        }
+
+        // The sum is lower than twice the modulus, the final carry is at most 1
+        let carry = self.propagate_with_final_carry_parallelized(&mut result);
+        (result, carry)
+    }
+
+    /// Computes `lhs - rhs` and the carry out of `lhs + (modulus - rhs)`, which encrypts 1
+    /// exactly when `lhs >= rhs`.
+    ///
+    /// Expects the carries of the ciphertexts to be empty.
+    pub(crate) fn unchecked_sub_with_carry_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &RadixCiphertext<PBSOrder>,
+        rhs: &RadixCiphertext<PBSOrder>,
+    ) -> (RadixCiphertext<PBSOrder>, CiphertextBase<PBSOrder>) {
+        // modulus - rhs is the complement of each digit of rhs, plus one
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let mut result = self.block_map_parallelized(rhs, |_, digit| message_modulus - 1 - digit);
+        for (block, lhs_block) in result.blocks.iter_mut().zip(lhs.blocks.iter()) {
+            self.key.unchecked_add_assign(block, lhs_block);
+        }
+        self.key
+            .unchecked_scalar_add_assign(&mut result.blocks[0], 1);
+
+        // The sum is lower than twice the modulus, the final carry is at most 1
+        let carry = self.propagate_with_final_carry_parallelized(&mut result);
+        (result, carry)
+    }
+
+    /// Returns a block encrypting 1 if one of the blocks encrypts a non zero value, 0 otherwise.
+    ///
+    /// The blocks are summed by groups whose degrees fit in a block, and the sums are turned
+    /// back into booleans until a single block is left.
+    pub(crate) fn unchecked_any_block_non_zero_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        mut blocks: Vec<CiphertextBase<PBSOrder>>,
+    ) -> CiphertextBase<PBSOrder> {
+        if blocks.is_empty() {
+            return self.key.create_trivial(0);
+        }
+
+        let max_sum = self.key.message_modulus.0 * self.key.carry_modulus.0 - 1;
+        let is_non_zero = self.key.generate_accumulator(|x| u64::from(x != 0));
+        loop {
+            let mut groups = Vec::new();
+            let mut current_group = Vec::new();
+            let mut current_sum = 0;
+            for block in blocks {
+                if !current_group.is_empty() && current_sum + block.degree.0 > max_sum {
+                    groups.push(std::mem::take(&mut current_group));
+                    current_sum = 0;
+                }
+                current_sum += block.degree.0;
+                current_group.push(block);
+            }
+            groups.push(current_group);
+
+            blocks = groups
+                .into_par_iter()
+                .map(|group| {
+                    let mut group = group.into_iter();
+                    let mut sum = group.next().unwrap();
+                    for block in group {
+                        self.key.unchecked_add_assign(&mut sum, &block);
+                    }
+                    self.key.apply_lookup_table(&sum, &is_non_zero)
+                })
+                .collect();
+
+            if blocks.len() == 1 {
+                return blocks.pop().unwrap();
+            }
+        }
+    }
+
+    /// Returns whether the largest value the degrees of the blocks allow, mapped by `f`, is
+    /// lower than the largest value the ciphertext can hold, `f` returning `None` on overflow.
+    fn radix_max_value_is_lower_than<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        f: impl Fn(u128) -> Option<u128>,
+    ) -> bool {
+        let message_modulus = self.key.message_modulus.0 as u128;
+        let mut max_value = 0u128;
+        for block in ct.blocks.iter().rev() {
+            let Some(value) = max_value
+                .checked_mul(message_modulus)
+                .and_then(|value| value.checked_add(block.degree.0 as u128))
+            else {
+                return false;
+            };
+            max_value = value;
+        }
+
+        let Some(mapped) = f(max_value) else {
+            return false;
+        };
+        message_modulus
+            .checked_pow(ct.blocks.len() as u32)
+            .map_or(true, |modulus| mapped < modulus)
+    }
+
+    /// Returns whether `scalar` is greater than the largest value `num_blocks` blocks can hold.
+    fn scalar_exceeds_radix_range(&self, scalar: u64, num_blocks: usize) -> bool {
+        let message_modulus = self.key.message_modulus.0 as u128;
+        message_modulus
+            .checked_pow(num_blocks as u32)
+            .map_or(false, |modulus| u128::from(scalar) >= modulus)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::integer::ciphertext::{BooleanBlock, RadixCiphertext};
+use crate::integer::permutation::{apply_benes_network, check_permutation, EncryptedPermutation};
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Permutes the ciphertexts by a clear permutation: after the call, `cts[i]` is the
+    /// ciphertext which was at `cts[permutation[i]]`.
+    ///
+    /// The ciphertexts are only moved, no computation is done.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not a permutation of `0..cts.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut cts = [1u64, 2, 3].map(|clear| cks.encrypt(clear));
+    ///
+    /// sks.permute(&mut cts, &[1, 2, 0]);
+    ///
+    /// let res: Vec<u64> = cts.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(res, vec![2, 3, 1]);
+    /// ```
+    pub fn permute<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &mut [RadixCiphertext<PBSOrder>],
+        permutation: &[usize],
+    ) {
+        assert_eq!(
+            cts.len(),
+            permutation.len(),
+            "The permutation must have as many elements as there are ciphertexts"
+        );
+        check_permutation(permutation);
+
+        // Follow each cycle of the permutation, pulling the next element of the cycle in place
+        let mut placed = vec![false; cts.len()];
+        for start in 0..cts.len() {
+            let mut current = start;
+            while !placed[current] {
+                placed[current] = true;
+                let next = permutation[current];
+                if next == start {
+                    break;
+                }
+                cts.swap(current, next);
+                current = next;
+            }
+        }
+    }
+
+    /// Permutes the ciphertexts by an encrypted permutation, without learning it: after the
+    /// call, `cts[i]` encrypts the value which was encrypted by `cts[permutation[i]]`, where
+    /// `permutation` is the permutation given to
+    /// [`ClientKey::encrypt_permutation`](crate::integer::ClientKey::encrypt_permutation).
+    ///
+    /// The ciphertexts go through the switches of a Benes network, each switch costing 4
+    /// bivariate PBS per block. See the [permutation module](crate::integer::permutation).
+    ///
+    /// The ciphertexts block carries are cleared if they are not empty. The output ciphertexts
+    /// block carries are always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the permutation is not a permutation of `cts.len()` elements, or if the
+    /// ciphertexts do not have the same number of blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let mut cts = [1u64, 2, 3, 4].map(|clear| cks.encrypt(clear));
+    /// let permutation = cks.as_ref().encrypt_permutation(&[3, 0, 2, 1]);
+    ///
+    /// sks.oblivious_permute_parallelized(&mut cts, &permutation);
+    ///
+    /// let res: Vec<u64> = cts.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(res, vec![4, 1, 3, 2]);
+    /// ```
+    pub fn oblivious_permute_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        cts: &mut [RadixCiphertext<PBSOrder>],
+        permutation: &EncryptedPermutation<PBSOrder>,
+    ) {
+        assert_eq!(
+            cts.len(),
+            permutation.len(),
+            "The permutation must have as many elements as there are ciphertexts"
+        );
+        let Some(num_blocks) = cts.first().map(|ct| ct.blocks.len()) else {
+            return;
+        };
+        assert!(
+            cts.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "All the ciphertexts must have the same number of blocks"
+        );
+
+        cts.par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(ct);
+            }
+        });
+
+        // The network applies to the next power of two, the padding elements are trivial and a
+        // switch only exchanging padding elements is skipped. A padding element which went
+        // through a switch with a real element may have been exchanged with it.
+        let mut padding = (cts.len()..cts.len().next_power_of_two())
+            .map(|_| self.create_trivial_zero_radix(num_blocks))
+            .collect::<Vec<RadixCiphertext<PBSOrder>>>();
+        let mut elements = cts
+            .iter_mut()
+            .map(|ct| (ct, true))
+            .chain(padding.iter_mut().map(|ct| (ct, false)))
+            .collect::<Vec<_>>();
+
+        apply_benes_network(
+            elements.iter_mut().collect(),
+            &permutation.switches,
+            &|(lhs, lhs_is_real), (rhs, rhs_is_real), condition| {
+                if *lhs_is_real || *rhs_is_real {
+                    self.unchecked_conditional_swap_parallelized(lhs, rhs, condition);
+                    *lhs_is_real = true;
+                    *rhs_is_real = true;
+                }
+            },
+        );
+    }
+
+    /// Exchanges the values of `lhs` and `rhs` if `condition` encrypts 1, leaves them unchanged
+    /// if it encrypts 0.
+    ///
+    /// With `t = condition * (lhs - rhs)`, the results are `lhs - t` and `rhs + t`, each block
+    /// being computed modulo the message modulus: 4 bivariate PBS per pair of blocks.
+    ///
+    /// Expects the carries of the ciphertexts to be empty, the carries of the results are empty.
+    pub(crate) fn unchecked_conditional_swap_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut RadixCiphertext<PBSOrder>,
+        rhs: &mut RadixCiphertext<PBSOrder>,
+        condition: &BooleanBlock<PBSOrder>,
+    ) {
+        let masked = self.unchecked_masked_difference_parallelized(&condition.block, lhs, rhs);
+
+        let (new_lhs, new_rhs) = rayon::join(
+            || self.unchecked_block_bimap_parallelized(lhs, &masked, u64::wrapping_sub),
+            || self.unchecked_block_bimap_parallelized(rhs, &masked, u64::wrapping_add),
+        );
+        *lhs = new_lhs;
+        *rhs = new_rhs;
+    }
+}
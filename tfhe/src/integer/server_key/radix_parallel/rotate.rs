@@ -0,0 +1,312 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::PBSOrderMarker;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically a left rotation by a clear amount.
+    ///
+    /// The bits leaving on the most significant side come back on the least significant side.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// The result is returned as a new ciphertext.
+    pub fn unchecked_rotate_left_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.unchecked_rotate_left_assign_parallelized(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a left rotation by a clear amount.
+    ///
+    /// Rotating by a multiple of the number of bits per block only moves blocks around, other
+    /// amounts cost one bivariate PBS per block.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_rotate_left_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) {
+        let bits_per_block = self.bits_per_block();
+        let num_blocks = ct.blocks.len();
+        let total_bits = num_blocks * bits_per_block;
+        if total_bits == 0 {
+            return;
+        }
+
+        let n = n % total_bits;
+        let block_shift = n / bits_per_block;
+        let bit_shift = n % bits_per_block;
+
+        // Blocks are stored from LSB to MSB
+        ct.blocks.rotate_right(block_shift);
+        if bit_shift == 0 {
+            return;
+        }
+
+        let modulus = self.key.message_modulus.0 as u64;
+        let lut = self
+            .key
+            .generate_accumulator_bivariate(|current, previous| {
+                ((current << bit_shift) | (previous >> (bits_per_block - bit_shift))) % modulus
+            });
+
+        ct.blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|i| {
+                let previous = &ct.blocks[(i + num_blocks - 1) % num_blocks];
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[i], previous, &lut)
+            })
+            .collect();
+    }
+
+    /// Computes homomorphically a left rotation by a clear amount.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0b1100_0101u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a left rotation:
+    /// let ct_res = sks.rotate_left_parallelized(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_left(n as u32) as u64, dec);
+    /// ```
+    pub fn rotate_left_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.rotate_left_assign_parallelized(&mut result, n);
+        result
+    }
+
+    pub fn rotate_left_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_rotate_left_assign_parallelized(ct, n);
+    }
+
+    /// Computes homomorphically a right rotation by a clear amount.
+    ///
+    /// The bits leaving on the least significant side come back on the most significant side.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// The result is returned as a new ciphertext.
+    pub fn unchecked_rotate_right_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.unchecked_rotate_right_assign_parallelized(&mut result, n);
+        result
+    }
+
+    /// Computes homomorphically a right rotation by a clear amount.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_rotate_right_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) {
+        let total_bits = ct.blocks.len() * self.bits_per_block();
+        if total_bits == 0 {
+            return;
+        }
+        // Rotating right by n is rotating left by total_bits - n
+        let n = total_bits - (n % total_bits);
+        self.unchecked_rotate_left_assign_parallelized(ct, n);
+    }
+
+    /// Computes homomorphically a right rotation by a clear amount.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0b1100_0101u8;
+    /// let n = 3;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a right rotation:
+    /// let ct_res = sks.rotate_right_parallelized(&ct, n);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(n as u32) as u64, dec);
+    /// ```
+    pub fn rotate_right_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.rotate_right_assign_parallelized(&mut result, n);
+        result
+    }
+
+    pub fn rotate_right_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        n: usize,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_rotate_right_assign_parallelized(ct, n);
+    }
+
+    /// Reverses the order of the bytes of a ciphertext encrypting an integer message.
+    ///
+    /// This only moves blocks around and does not cost any PBS.
+    ///
+    /// The input ciphertext must have empty carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits per block does not divide 8, or if the ciphertext does not
+    /// encrypt a whole number of bytes.
+    pub fn unchecked_swap_bytes_assign<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        let bits_per_block = self.bits_per_block();
+        assert_eq!(
+            u8::BITS as usize % bits_per_block,
+            0,
+            "Swapping bytes requires the number of bits per block to divide 8, got {bits_per_block}"
+        );
+        let blocks_per_byte = u8::BITS as usize / bits_per_block;
+        assert_eq!(
+            ct.blocks.len() % blocks_per_byte,
+            0,
+            "Swapping bytes requires a whole number of bytes, got {} blocks of {bits_per_block} bits",
+            ct.blocks.len()
+        );
+
+        // Reversing the blocks then the blocks within each byte reverses the bytes
+        ct.blocks.reverse();
+        for byte_blocks in ct.blocks.chunks_mut(blocks_per_byte) {
+            byte_blocks.reverse();
+        }
+    }
+
+    /// Reverses the order of the bytes of a ciphertext encrypting an integer message.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits per block does not divide 8, or if the ciphertext does not
+    /// encrypt a whole number of bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 0x12_34u16;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// // Compute homomorphically a byte swap:
+    /// let ct_res = sks.swap_bytes_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.swap_bytes() as u64, dec);
+    /// ```
+    pub fn swap_bytes_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.swap_bytes_assign_parallelized(&mut result);
+        result
+    }
+
+    pub fn swap_bytes_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_swap_bytes_assign(ct);
+    }
+}
@@ -29,6 +29,7 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg + scalar, dec);
     /// ```
+    #[deprecated(note = "the operand may be modified, use scalar_add_parallelized")]
     pub fn smart_scalar_add_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
@@ -66,6 +67,7 @@ impl ServerKey {
     /// let dec: u64 = cks.decrypt(&ct);
     /// assert_eq!(msg + scalar, dec);
     /// ```
+    #[deprecated(note = "the operand may be modified, use scalar_add_assign_parallelized")]
     pub fn smart_scalar_add_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
@@ -168,4 +170,50 @@ impl ServerKey {
         self.unchecked_scalar_add_assign(ct, scalar);
         self.full_propagate_parallelized(ct);
     }
+
+    /// Computes homomorphically the addition of ciphertext with a signed scalar.
+    ///
+    /// The addition is computed modulo the modulus of the ciphertext, adding a negative scalar
+    /// subtracts its magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 129;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_add_signed_parallelized(&ct, -40);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg - 40, dec);
+    /// ```
+    pub fn scalar_add_signed_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: i64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut ct_res = ct.clone();
+        self.scalar_add_signed_assign_parallelized(&mut ct_res, scalar);
+        ct_res
+    }
+
+    pub fn scalar_add_signed_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: i64,
+    ) {
+        if scalar >= 0 {
+            self.scalar_add_assign_parallelized(ct, scalar as u64);
+        } else {
+            self.scalar_sub_assign_parallelized(ct, scalar.unsigned_abs());
+        }
+    }
 }
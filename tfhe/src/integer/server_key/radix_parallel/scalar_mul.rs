@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::server_key::scalar_cache::PrecomputedScalar;
 use crate::integer::server_key::CheckError;
 use crate::integer::server_key::CheckError::CarryFull;
 use crate::integer::ServerKey;
@@ -172,6 +173,7 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
+    #[deprecated(note = "the operand may be modified, use small_scalar_mul_parallelized")]
     pub fn smart_small_scalar_mul_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
@@ -214,6 +216,7 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
+    #[deprecated(note = "the operand may be modified, use small_scalar_mul_assign_parallelized")]
     pub fn smart_small_scalar_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
@@ -353,6 +356,7 @@ impl ServerKey {
     /// let clear: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg * scalar % modulus, clear);
     /// ```
+    #[deprecated(note = "the operand may be modified, use scalar_mul_parallelized")]
     pub fn smart_scalar_mul_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ct: &mut RadixCiphertext<PBSOrder>,
@@ -407,11 +411,13 @@ impl ServerKey {
                 terms.lock().unwrap().push(term);
             });
         });
-        let mut terms = terms.into_inner().unwrap();
-        self.smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
+        let terms = terms.into_inner().unwrap();
+        self.default_binary_op_seq_parallelized(&terms, ServerKey::degree_aware_add_parallelized)
             .unwrap_or(zero)
     }
 
+    #[deprecated(note = "the operand may be modified, use scalar_mul_assign_parallelized")]
+    #[allow(deprecated)]
     pub fn smart_scalar_mul_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt: &mut RadixCiphertext<PBSOrder>,
@@ -481,46 +487,161 @@ impl ServerKey {
         //Propagate the carries before doing the multiplications
         self.full_propagate_parallelized(ct);
 
-        // key is the small scalar we multiply by
-        // value is the vector of blockshifts
-        let mut task_map = HashMap::<u64, Vec<usize>>::new();
+        let terms = match self.scalar_cache.get(scalar) {
+            Some(precomputed) => self.precomputed_scalar_mul_terms(ct, precomputed),
+            None => {
+                // key is the small scalar we multiply by
+                // value is the vector of blockshifts
+                let mut task_map = HashMap::<u64, Vec<usize>>::new();
 
-        let mut scalar_i = scalar;
-        for i in 0..n {
-            let u_i = scalar_i % b;
-            task_map.entry(u_i).or_insert_with(Vec::new).push(i);
-            scalar_i /= b;
-            if scalar_i == 0 {
-                break;
+                let mut scalar_i = scalar;
+                for i in 0..n {
+                    let u_i = scalar_i % b;
+                    task_map.entry(u_i).or_default().push(i);
+                    scalar_i /= b;
+                    if scalar_i == 0 {
+                        break;
+                    }
+                }
+
+                let terms = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::new());
+                task_map.par_iter().for_each(|(&u_i, blockshifts)| {
+                    if u_i == 0 {
+                        return;
+                    }
+
+                    let blockshifts = &**blockshifts;
+                    let min_blockshift = *blockshifts.iter().min().unwrap();
+
+                    let mut tmp = ct.clone();
+                    if u_i != 1 {
+                        tmp.blocks[0..n - min_blockshift]
+                            .par_iter_mut()
+                            .for_each(|ct_i| self.key.unchecked_scalar_mul_assign(ct_i, u_i as u8));
+                    }
+
+                    let tmp = &tmp;
+                    blockshifts.par_iter().for_each(|&shift| {
+                        let term = self.blockshift(tmp, shift);
+                        terms.lock().unwrap().push(term);
+                    });
+                });
+                terms.into_inner().unwrap()
+            }
+        };
+        *ct = self
+            .default_binary_op_seq_parallelized(&terms, ServerKey::degree_aware_add_parallelized)
+            .unwrap_or(zero);
+        self.full_propagate_parallelized(ct);
+    }
+
+    /// Terms of the multiplication of a ciphertext with empty carries by a scalar registered
+    /// with [`ServerKey::precompute_scalar`].
+    ///
+    /// The product of each block by a digit is split in a low and a high block with the
+    /// precomputed tables, so the terms have empty carries.
+    fn precomputed_scalar_mul_terms<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        precomputed: &PrecomputedScalar,
+    ) -> Vec<RadixCiphertext<PBSOrder>> {
+        let n = ct.blocks.len();
+
+        let mut task_map = HashMap::<u64, Vec<usize>>::new();
+        for (i, &digit) in precomputed.digits.iter().take(n).enumerate() {
+            if digit != 0 {
+                task_map.entry(digit).or_default().push(i);
             }
         }
 
         let terms = Mutex::new(Vec::<RadixCiphertext<PBSOrder>>::new());
-        task_map.par_iter().for_each(|(&u_i, blockshifts)| {
-            if u_i == 0 {
+        task_map.par_iter().for_each(|(&digit, blockshifts)| {
+            let blockshifts = &**blockshifts;
+
+            if digit == 1 {
+                blockshifts.par_iter().for_each(|&shift| {
+                    let term = self.blockshift(ct, shift);
+                    terms.lock().unwrap().push(term);
+                });
                 return;
             }
 
-            let blockshifts = &**blockshifts;
+            let (low_table, high_table) = &precomputed.tables[&digit];
             let min_blockshift = *blockshifts.iter().min().unwrap();
 
-            let mut tmp = ct.clone();
-            if u_i != 1 {
-                tmp.blocks[0..n - min_blockshift]
-                    .par_iter_mut()
-                    .for_each(|ct_i| self.key.unchecked_scalar_mul_assign(ct_i, u_i as u8));
-            }
+            // Blocks shifted out of the result are not computed
+            let (low, high) = self.budgeted_join(
+                || {
+                    let mut low = ct.clone();
+                    low.blocks[0..n - min_blockshift]
+                        .par_iter_mut()
+                        .for_each(|block| self.key.apply_lookup_table_assign(block, low_table));
+                    low
+                },
+                || {
+                    let mut high = ct.clone();
+                    high.blocks[0..n - min_blockshift - 1]
+                        .par_iter_mut()
+                        .for_each(|block| self.key.apply_lookup_table_assign(block, high_table));
+                    high
+                },
+            );
 
-            let tmp = &tmp;
             blockshifts.par_iter().for_each(|&shift| {
-                let term = self.blockshift(tmp, shift);
-                terms.lock().unwrap().push(term);
+                let low_term = self.blockshift(&low, shift);
+                terms.lock().unwrap().push(low_term);
+                if shift + 1 < n {
+                    let high_term = self.blockshift(&high, shift + 1);
+                    terms.lock().unwrap().push(high_term);
+                }
             });
         });
-        let terms = terms.into_inner().unwrap();
-        *ct = self
-            .default_binary_op_seq_parallelized(&terms, ServerKey::add_parallelized)
-            .unwrap_or(zero);
-        self.full_propagate_parallelized(ct);
+        terms.into_inner().unwrap()
+    }
+
+    /// Computes homomorphically the multiplication of a ciphertext by a signed scalar.
+    ///
+    /// The product is computed modulo the modulus of the ciphertext, multiplying by a negative
+    /// scalar negates the product by its magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let modulus = 1 << 8;
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 23;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_mul_signed_parallelized(&ct, -3);
+    ///
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((modulus - msg * 3) % modulus, clear);
+    /// ```
+    pub fn scalar_mul_signed_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        scalar: i64,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut ct_res = ct.clone();
+        self.scalar_mul_signed_assign_parallelized(&mut ct_res, scalar);
+        ct_res
+    }
+
+    pub fn scalar_mul_signed_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        scalar: i64,
+    ) {
+        self.scalar_mul_assign_parallelized(ct, scalar.unsigned_abs());
+        if scalar < 0 {
+            *ct = self.neg_parallelized(ct);
+        }
     }
 }
@@ -1,6 +1,18 @@
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
-use crate::shortint::PBSOrderMarker;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+use rayon::prelude::*;
+
+/// Rounding applied to the bits shifted out by a right shift.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds toward zero, the shifted out bits are discarded.
+    Floor,
+    /// Rounds toward the next integer as soon as one of the shifted out bits is set.
+    Ceil,
+    /// Rounds to the nearest integer, ties being rounded to the even one.
+    HalfEven,
+}
 
 impl ServerKey {
     /// Computes homomorphically a right shift.
@@ -191,6 +203,197 @@ impl ServerKey {
         self.full_propagate_parallelized(ct);
     }
 
+    /// Computes homomorphically a right shift, rounding the result with the given mode.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// Rounding up the biggest representable value wraps around to 0, as for an addition.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::RoundingMode;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// // 46 / 2^2 = 11.5
+    /// let msg = 46;
+    /// let shift = 2;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, shift, RoundingMode::Floor);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 11);
+    ///
+    /// let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, shift, RoundingMode::Ceil);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 12);
+    ///
+    /// let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, shift, RoundingMode::HalfEven);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 12);
+    /// ```
+    pub fn scalar_right_shift_rounded_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        shift: usize,
+        rounding: RoundingMode,
+    ) -> RadixCiphertext<PBSOrder> {
+        let mut result = ct.clone();
+        self.scalar_right_shift_rounded_assign_parallelized(&mut result, shift, rounding);
+        result
+    }
+
+    /// Computes homomorphically a right shift, rounding the result with the given mode.
+    ///
+    /// The result is assigned to the input ciphertext.
+    ///
+    /// See [Self::scalar_right_shift_rounded_parallelized].
+    pub fn scalar_right_shift_rounded_assign_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut RadixCiphertext<PBSOrder>,
+        shift: usize,
+        rounding: RoundingMode,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        if shift == 0 {
+            return;
+        }
+
+        let num_bits_in_block = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_bits = num_bits_in_block * ct.blocks.len();
+
+        // The rounding bit only depends on the bits of the input,
+        // it is computed while the shift is done
+        let (shifted, round_bit) = self.budgeted_join(
+            || {
+                if shift >= num_bits {
+                    self.create_trivial_zero_radix(ct.blocks.len())
+                } else {
+                    let mut shifted = self.unchecked_scalar_right_shift_parallelized(ct, shift);
+                    self.full_propagate_parallelized(&mut shifted);
+                    shifted
+                }
+            },
+            || match rounding {
+                RoundingMode::Floor => None,
+                RoundingMode::Ceil => Some(self.any_low_bit_set_parallelized(ct, shift)),
+                RoundingMode::HalfEven => Some(self.half_even_round_bit_parallelized(ct, shift)),
+            },
+        );
+        *ct = shifted;
+
+        if let Some(round_bit) = round_bit {
+            // The first block has an empty carry, adding a bit to it cannot overflow
+            self.key.unchecked_add_assign(&mut ct.blocks[0], &round_bit);
+            self.full_propagate_parallelized(ct);
+        }
+    }
+
+    /// Returns a block encrypting the bit at `index` of the radix ciphertext,
+    /// or `None` if the index is past the last bit.
+    fn extract_bit_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        index: usize,
+    ) -> Option<CiphertextBase<PBSOrder>> {
+        let num_bits_in_block = (self.key.message_modulus.0 as f64).log2() as usize;
+        let block = ct.blocks.get(index / num_bits_in_block)?;
+        let bit_in_block = index % num_bits_in_block;
+
+        let acc = self.key.generate_accumulator(|x| (x >> bit_in_block) & 1);
+        Some(self.key.apply_lookup_table(block, &acc))
+    }
+
+    /// Returns a block encrypting 1 if one of the `num_bits` least significant bits of the radix
+    /// ciphertext is set, 0 otherwise.
+    ///
+    /// The carries of the blocks must be empty.
+    fn any_low_bit_set_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        num_bits: usize,
+    ) -> CiphertextBase<PBSOrder> {
+        let num_bits_in_block = (self.key.message_modulus.0 as f64).log2() as usize;
+        let num_bits = num_bits.min(num_bits_in_block * ct.blocks.len());
+        if num_bits == 0 {
+            return self.key.create_trivial(0);
+        }
+
+        let num_blocks = (num_bits + num_bits_in_block - 1) / num_bits_in_block;
+        // The blocks below num_bits are kept as is, the last one is masked
+        let low_blocks = ct.blocks[..num_blocks]
+            .par_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let bits_in_this_block = (num_bits - i * num_bits_in_block).min(num_bits_in_block);
+                if bits_in_this_block == num_bits_in_block {
+                    return block.clone();
+                }
+                let mask = (1u64 << bits_in_this_block) - 1;
+                let acc = self.key.generate_accumulator(|x| x & mask);
+                self.key.apply_lookup_table(block, &acc)
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_any_block_non_zero_parallelized(low_blocks)
+    }
+
+    /// Returns a block encrypting 1 if a right shift by `shift` bits has to be rounded up to
+    /// round half to even, 0 otherwise.
+    ///
+    /// The carries of the blocks must be empty.
+    fn half_even_round_bit_parallelized<PBSOrder: PBSOrderMarker>(
+        &self,
+        ct: &RadixCiphertext<PBSOrder>,
+        shift: usize,
+    ) -> CiphertextBase<PBSOrder> {
+        // The result is rounded up if the most significant shifted out bit is set,
+        // and either one of the other shifted out bits or the lsb of the result is set
+        let ((half_bit, sticky_bit), lsb) = self.budgeted_join(
+            || {
+                self.budgeted_join(
+                    || self.extract_bit_parallelized(ct, shift - 1),
+                    || self.any_low_bit_set_parallelized(ct, shift - 1),
+                )
+            },
+            || self.extract_bit_parallelized(ct, shift),
+        );
+
+        let Some(half_bit) = half_bit else {
+            return self.key.create_trivial(0);
+        };
+
+        let mut tie_breaker = sticky_bit;
+        if let Some(lsb) = lsb {
+            self.key.unchecked_add_assign(&mut tie_breaker, &lsb);
+            let is_non_zero = self.key.generate_accumulator(|x| u64::from(x != 0));
+            self.key
+                .apply_lookup_table_assign(&mut tie_breaker, &is_non_zero);
+        }
+
+        // half_bit * 2 + tie_breaker <= 3 fits in the smallest block
+        let mut round_bit = self.key.unchecked_scalar_mul(&half_bit, 2);
+        self.key.unchecked_add_assign(&mut round_bit, &tie_breaker);
+        let both_set = self.key.generate_accumulator(|x| u64::from(x == 3));
+        self.key
+            .apply_lookup_table_assign(&mut round_bit, &both_set);
+        round_bit
+    }
+
     /// Propagates all carries except the last one.
     /// For development purpose only.
     fn partial_propagate_parallelized<PBSOrder: PBSOrderMarker>(
@@ -271,7 +474,7 @@ impl ServerKey {
         shift: usize,
     ) {
         let tmp = 1_u64 << shift;
-        self.smart_scalar_mul_assign_parallelized(ct, tmp);
+        self.scalar_mul_assign_parallelized(ct, tmp);
     }
 
     /// Computes homomorphically a left shift by a scalar.
@@ -29,6 +29,7 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg_1.wrapping_sub(msg_2) as u64, res);
     /// ```
+    #[deprecated(note = "the operands may be modified, use sub_parallelized")]
     pub fn smart_sub_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt_left: &mut RadixCiphertext<PBSOrder>,
@@ -79,6 +80,7 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ctxt_1);
     /// assert_eq!(msg_1.wrapping_sub(msg_2) as u64, res);
     /// ```
+    #[deprecated(note = "the operands may be modified, use sub_assign_parallelized")]
     pub fn smart_sub_assign_parallelized<PBSOrder: PBSOrderMarker>(
         &self,
         ctxt_left: &mut RadixCiphertext<PBSOrder>,
@@ -185,6 +187,8 @@ impl ServerKey {
         ctxt_left: &mut RadixCiphertext<PBSOrder>,
         ctxt_right: &RadixCiphertext<PBSOrder>,
     ) {
+        let _timer = crate::metrics::OperationTimer::new("sub");
+
         let mut tmp_rhs: RadixCiphertext<PBSOrder>;
 
         let (lhs, rhs) = match (
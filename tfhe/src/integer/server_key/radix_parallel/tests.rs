@@ -1,8 +1,13 @@
+// The smart operations are deprecated but still tested until they are removed
+#![allow(deprecated)]
+
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{RadixClientKey, ServerKey};
+use crate::integer::server_key::RoundingMode;
+use crate::integer::{BooleanBlockBig, RadixCiphertextBig, RadixClientKey, ServerKey};
 use crate::shortint::parameters::*;
 use crate::shortint::Parameters;
 use paste::paste;
+use rand::seq::SliceRandom;
 use rand::Rng;
 
 /// Number of loop iteration within randomized tests
@@ -51,6 +56,7 @@ create_parametrized_test!(integer_smart_bitxor);
 create_parametrized_test!(integer_default_bitand);
 create_parametrized_test!(integer_default_bitor);
 create_parametrized_test!(integer_default_bitxor);
+create_parametrized_test!(integer_default_bitnot);
 create_parametrized_test!(integer_unchecked_small_scalar_mul);
 create_parametrized_test!(integer_smart_small_scalar_mul);
 create_parametrized_test!(integer_default_small_scalar_mul);
@@ -62,10 +68,12 @@ create_parametrized_test!(integer_default_scalar_mul_u128_fix_non_reg_test {
 });
 create_parametrized_test!(integer_smart_scalar_mul);
 create_parametrized_test!(integer_default_scalar_mul);
+create_parametrized_test!(integer_default_precomputed_scalar_mul);
 create_parametrized_test!(integer_unchecked_scalar_left_shift);
 create_parametrized_test!(integer_default_scalar_left_shift);
 create_parametrized_test!(integer_unchecked_scalar_right_shift);
 create_parametrized_test!(integer_default_scalar_right_shift);
+create_parametrized_test!(integer_default_scalar_right_shift_rounded);
 create_parametrized_test!(integer_smart_neg);
 create_parametrized_test!(integer_default_neg);
 create_parametrized_test!(integer_smart_sub);
@@ -75,10 +83,35 @@ create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
+create_parametrized_test!(integer_mul_cancellable {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(integer_default_mul_ops_in_flight {
+    PARAM_MESSAGE_2_CARRY_2
+});
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
 create_parametrized_test!(integer_default_scalar_add);
+create_parametrized_test!(integer_default_signed_scalar_ops);
+create_parametrized_test!(integer_default_mul_trivial_operand);
+create_parametrized_test!(integer_default_to_bits_from_bits);
+create_parametrized_test!(integer_default_all_any);
+create_parametrized_test!(integer_oblivious_permute);
+create_parametrized_test!(integer_default_rotate_left);
+create_parametrized_test!(integer_default_rotate_right);
+create_parametrized_test!(integer_into_single_block);
+create_parametrized_test!(integer_default_block_bimap);
+create_parametrized_test!(integer_degree_aware_add);
+create_parametrized_test!(integer_default_overflowing_add_sub);
+create_parametrized_test!(integer_default_scalar_ge_via_sub);
+create_parametrized_test!(integer_default_overflowing_scalar_mul);
+create_parametrized_test!(integer_default_saturating_add_sub);
+create_parametrized_test!(integer_default_swap_bytes {
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_4_CARRY_4
+});
 
 fn integer_smart_add(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
@@ -124,6 +157,7 @@ fn integer_smart_add(param: Parameters) {
     }
 }
 
+#[allow(deprecated)]
 fn integer_smart_add_sequence_multi_thread(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -159,6 +193,7 @@ fn integer_smart_add_sequence_multi_thread(param: Parameters) {
     }
 }
 
+#[allow(deprecated)]
 fn integer_smart_add_sequence_single_thread(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -774,6 +809,51 @@ fn integer_default_scalar_mul(param: Parameters) {
     }
 }
 
+fn integer_default_precomputed_scalar_mul(param: Parameters) {
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // Scalars larger than the modulus only use their lower digits
+    let scalars = [
+        rng.gen::<u64>() % modulus,
+        rng.gen::<u64>() % modulus,
+        rng.gen::<u64>(),
+        modulus - 1,
+    ];
+    for scalar in scalars {
+        sks.precompute_scalar(scalar);
+    }
+    let mut registered = scalars.to_vec();
+    registered.sort_unstable();
+    registered.dedup();
+    assert_eq!(sks.precomputed_scalars(), registered);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        for scalar in scalars {
+            let ct = cks.encrypt(clear);
+
+            let ct_res = sks.scalar_mul_parallelized(&ct, scalar);
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(clear.wrapping_mul(scalar) % modulus, dec_res);
+        }
+    }
+
+    assert!(sks.forget_precomputed_scalar(scalars[0]));
+    assert!(!sks.is_scalar_precomputed(scalars[0]));
+    sks.clear_precomputed_scalars();
+    assert!(sks.precomputed_scalars().is_empty());
+}
+
 fn integer_smart_scalar_mul_u128_fix_non_reg_test(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let nb_ct = (128f64 / (param.message_modulus.0 as f64).log2().ceil()).ceil() as usize;
@@ -958,6 +1038,93 @@ fn integer_default_scalar_right_shift(param: Parameters) {
     }
 }
 
+fn integer_default_scalar_right_shift_rounded(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    //Nb of bits to shift
+    let tmp_f64 = param.message_modulus.0 as f64;
+    let nb_bits = tmp_f64.log2().floor() as usize * NB_CTXT;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Shifting by all the bits is allowed, the result is then 0 or 1
+        let scalar = rng.gen::<usize>() % (nb_bits + 1);
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear);
+
+        let quotient = clear >> scalar;
+        let remainder = clear - (quotient << scalar);
+        let half = (1u64 << scalar) >> 1;
+
+        for (rounding, round_up) in [
+            (RoundingMode::Floor, false),
+            (RoundingMode::Ceil, remainder != 0),
+            (
+                RoundingMode::HalfEven,
+                scalar != 0 && (remainder > half || (remainder == half && quotient % 2 == 1)),
+            ),
+        ] {
+            let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, scalar, rounding);
+            assert!(ct_res.block_carries_are_empty());
+
+            // decryption of ct_res
+            let dec_res: u64 = cks.decrypt(&ct_res);
+
+            // assert
+            let expected = (quotient + u64::from(round_up)) % modulus;
+            assert_eq!(
+                expected, dec_res,
+                "invalid result for {clear} >> {scalar} with {rounding:?}"
+            );
+        }
+    }
+}
+
+fn integer_default_bitnot(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.bitnot_parallelized(&ctxt_0);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, !clear_0 % modulus);
+
+        // the carries of the input are propagated first
+        let mut ctxt_sum = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let ct_res = sks.smart_bitnot_parallelized(&mut ctxt_sum);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, !(clear_0 + clear_1) % modulus);
+
+        // the negation computed from the not leaves room in the carries
+        let ct_neg = sks.smart_neg_parallelized(&mut cks.encrypt(clear_0));
+        let ct_res = sks.unchecked_add(&ct_neg, &ctxt_1);
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, clear_1.wrapping_sub(clear_0) % modulus);
+    }
+}
+
 fn integer_smart_neg(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -1240,6 +1407,75 @@ fn integer_smart_mul(param: Parameters) {
     }
 }
 
+fn integer_mul_cancellable(param: Parameters) {
+    use crate::integer::{CancellationToken, Cancelled};
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let clear1 = rng.gen::<u64>() % modulus;
+    let clear2 = rng.gen::<u64>() % modulus;
+
+    let ctxt_1 = cks.encrypt(clear1);
+    let ctxt_2 = cks.encrypt(clear2);
+
+    let token = CancellationToken::new();
+    let res = sks
+        .mul_parallelized_cancellable(&ctxt_1, &ctxt_2, &token)
+        .unwrap();
+    let dec: u64 = cks.decrypt(&res);
+    assert_eq!((clear1 * clear2) % modulus, dec);
+
+    // Cancelling a clone cancels the original token
+    token.clone().cancel();
+    assert!(token.is_cancelled());
+    let res = sks.mul_parallelized_cancellable(&ctxt_1, &ctxt_2, &token);
+    assert_eq!(res.err(), Some(Cancelled));
+}
+
+fn integer_default_mul_ops_in_flight(param: Parameters) {
+    use rayon::prelude::*;
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let clears = (0..4)
+        .map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+    let ctxts = clears
+        .iter()
+        .map(|&(clear1, clear2)| (cks.encrypt(clear1), cks.encrypt(clear2)))
+        .collect::<Vec<_>>();
+
+    for ops_in_flight in [1, ctxts.len(), rayon::current_num_threads()] {
+        let mut sks = sks.clone();
+        sks.set_par_ops_in_flight(ops_in_flight);
+        assert!(sks.intra_op_parallelism() >= 1);
+
+        let results = ctxts
+            .par_iter()
+            .map(|(ctxt_1, ctxt_2)| sks.mul_parallelized(ctxt_1, ctxt_2))
+            .collect::<Vec<_>>();
+
+        for (&(clear1, clear2), res) in clears.iter().zip(results.iter()) {
+            let dec: u64 = cks.decrypt(res);
+            assert_eq!((clear1 * clear2) % modulus, dec);
+        }
+    }
+}
+
 fn integer_default_mul(param: Parameters) {
     let (cks, sks) = KEY_CACHE.get_from_params(param);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -1451,3 +1687,589 @@ fn integer_default_scalar_sub(param: Parameters) {
         }
     }
 }
+
+fn integer_default_mul_trivial_operand(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let trivial_1: RadixCiphertextBig = sks.create_trivial_radix(clear_1, NB_CTXT);
+        assert_eq!(trivial_1.decrypt_trivial(), Some(clear_1));
+
+        let expected = (clear_0 * clear_1) % modulus;
+
+        let ct_res = sks.mul_parallelized(&ctxt_0, &trivial_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.mul_parallelized(&trivial_1, &ctxt_0);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(expected, dec_res);
+
+        // Operations on trivial ciphertexts only give trivial ciphertexts
+        let trivial_0: RadixCiphertextBig = sks.create_trivial_radix(clear_0, NB_CTXT);
+        let ct_res = sks.mul_parallelized(&trivial_0, &trivial_1);
+        assert_eq!(ct_res.decrypt_trivial(), Some(expected));
+    }
+}
+
+fn integer_default_signed_scalar_ops(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as i128;
+
+    // RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus as u64;
+        let scalar = rng.gen::<i64>();
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.scalar_add_signed_parallelized(&ctxt, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (clear as i128 + scalar as i128).rem_euclid(modulus) as u64;
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.scalar_sub_signed_parallelized(&ctxt, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (clear as i128 - scalar as i128).rem_euclid(modulus) as u64;
+        assert_eq!(expected, dec_res);
+
+        let ct_res = sks.scalar_mul_signed_parallelized(&ctxt, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (clear as i128 * scalar as i128).rem_euclid(modulus) as u64;
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_default_to_bits_from_bits(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let nb_bits = modulus.trailing_zeros() as usize;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // Produce a ciphertext with non empty carries
+        let ctxt = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let bits = sks.to_bits_parallelized(&ctxt);
+        assert_eq!(bits.len(), nb_bits);
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(cks.decrypt_bool(bit), (clear >> i) & 1 == 1);
+        }
+
+        let bit_index = rng.gen::<usize>() % nb_bits;
+        let bit = sks.unchecked_extract_bit(&ctxt_0, bit_index);
+        assert_eq!(cks.decrypt_bool(&bit), (clear_0 >> bit_index) & 1 == 1);
+
+        // Reverse the bits to check the assembly order
+        let reversed_bits = bits.into_iter().rev().collect::<Vec<_>>();
+        let ct_res = sks.from_bits_parallelized(&reversed_bits);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (0..nb_bits)
+            .map(|i| ((clear >> i) & 1) << (nb_bits - 1 - i))
+            .sum::<u64>();
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_all_any(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    let no_booleans: &[BooleanBlockBig] = &[];
+    assert!(cks.decrypt_bool(&sks.all_parallelized(no_booleans)));
+    assert!(!cks.decrypt_bool(&sks.any_parallelized(no_booleans)));
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Enough booleans to need several levels of the tree, with all of them being true, all
+        // of them being false or a mix
+        let clears = match rng.gen::<u64>() % 3 {
+            0 => vec![modulus - 1; 3],
+            1 => vec![0; 3],
+            _ => (0..3).map(|_| rng.gen::<u64>() % modulus).collect(),
+        };
+
+        let bits = clears
+            .iter()
+            .flat_map(|clear| sks.to_bits_parallelized(&cks.encrypt(*clear)))
+            .collect::<Vec<_>>();
+
+        let all = sks.all_parallelized(&bits);
+        assert_eq!(
+            cks.decrypt_bool(&all),
+            clears.iter().all(|clear| *clear == modulus - 1)
+        );
+
+        let any = sks.any_parallelized(&bits);
+        assert_eq!(
+            cks.decrypt_bool(&any),
+            clears.iter().any(|clear| *clear != 0)
+        );
+    }
+}
+
+fn integer_oblivious_permute(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    // A power of two and sizes which need padding
+    for len in [1, 3, 4, 5] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut permutation = (0..len).collect::<Vec<usize>>();
+        permutation.shuffle(&mut rng);
+
+        let mut cts = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+        // Dirty carries are cleared before going through the network
+        sks.unchecked_scalar_add_assign(&mut cts[0], 1);
+        let mut clears = clears;
+        clears[0] = (clears[0] + 1) % modulus;
+
+        let expected = permutation
+            .iter()
+            .map(|index| clears[*index])
+            .collect::<Vec<_>>();
+
+        let mut clear_permuted = cts.clone();
+        sks.full_propagate_parallelized(&mut clear_permuted[0]);
+        sks.permute(&mut clear_permuted, &permutation);
+
+        let encrypted_permutation = cks.as_ref().encrypt_permutation(&permutation);
+        sks.oblivious_permute_parallelized(&mut cts, &encrypted_permutation);
+
+        for ((ct, clear_permuted), expected) in cts.iter().zip(clear_permuted.iter()).zip(expected)
+        {
+            assert!(ct.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(ct);
+            assert_eq!(dec, expected);
+            let dec: u64 = cks.decrypt(clear_permuted);
+            assert_eq!(dec, expected);
+        }
+    }
+}
+
+fn clear_rotate_left(clear: u64, n: usize, nb_bits: usize) -> u64 {
+    let n = n % nb_bits;
+    let mask = (1u64 << nb_bits) - 1;
+    if n == 0 {
+        clear
+    } else {
+        ((clear << n) | (clear >> (nb_bits - n))) & mask
+    }
+}
+
+fn integer_default_rotate_left(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let nb_bits = modulus.trailing_zeros() as usize;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let n = rng.gen::<usize>() % (2 * nb_bits);
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.rotate_left_parallelized(&ctxt, n);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(clear_rotate_left(clear, n, nb_bits), dec_res);
+    }
+}
+
+fn integer_default_rotate_right(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+    let nb_bits = modulus.trailing_zeros() as usize;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let n = rng.gen::<usize>() % (2 * nb_bits);
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.rotate_right_parallelized(&ctxt, n);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = clear_rotate_left(clear, nb_bits - (n % nb_bits), nb_bits);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_default_swap_bytes(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    // Encrypt 16 bits
+    let bits_per_block = param.message_modulus.0.trailing_zeros() as usize;
+    let num_blocks = u16::BITS as usize / bits_per_block;
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u16>();
+
+        let ctxt = cks.encrypt(clear as u64);
+
+        let ct_res = sks.swap_bytes_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(clear.swap_bytes() as u64, dec_res);
+    }
+}
+
+fn integer_into_single_block(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = param.message_modulus.0 as u64;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // The sum has non empty carries
+        let ct_sum = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let block = ct_sum.into_single_block(&sks);
+        assert!(block.carry_is_empty());
+        assert_eq!(
+            cks.decrypt_one_block(&block),
+            (clear_0 + clear_1) % message_modulus
+        );
+
+        // Two fresh blocks always fit in the message and carry spaces of one block
+        let clear = clear_0 % (message_modulus * message_modulus);
+        let ctxt = cks.as_ref().encrypt_radix(clear, 2);
+        let block = ctxt.checked_into_single_block(&sks).unwrap();
+        assert_eq!(cks.as_ref().key.decrypt_message_and_carry(&block), clear);
+
+        assert!(ctxt_0.checked_into_single_block(&sks).is_err());
+    }
+}
+
+fn integer_default_block_bimap(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = param.message_modulus.0 as u64;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    // Digit-wise saturating subtraction
+    let f = |x: u64, y: u64| x.saturating_sub(y);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_2 = cks.encrypt(clear_2);
+
+        // Non empty carries are propagated first
+        let ctxt_sum = sks.unchecked_add(&ctxt_0, &ctxt_2);
+        let clear_sum = (clear_0 + clear_2) % modulus;
+
+        let ct_res = sks.block_bimap_parallelized(&ctxt_sum, &ctxt_1, f);
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = (0..NB_CTXT as u32)
+            .map(|i| {
+                let digit_0 = (clear_sum / message_modulus.pow(i)) % message_modulus;
+                let digit_1 = (clear_1 / message_modulus.pow(i)) % message_modulus;
+                f(digit_0, digit_1) * message_modulus.pow(i)
+            })
+            .sum::<u64>();
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(expected, dec_res);
+    }
+}
+
+fn integer_degree_aware_add(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let mut ct_res = sks.degree_aware_add_parallelized(&ctxt_0, &ctxt_1);
+        let mut clear = (clear_0 + clear_1) % modulus;
+
+        //add multiple times to raise the degree
+        for _ in 0..NB_TEST_SMALLER {
+            let ct_lhs = ct_res.clone();
+            ct_res = sks.degree_aware_add_parallelized(&ct_lhs, &ct_res);
+            clear = (clear * 2) % modulus;
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
+fn integer_default_overflowing_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+        assert_eq!(
+            cks.decrypt_one_block(&overflowed),
+            u64::from(clear_0 + clear_1 >= modulus)
+        );
+
+        let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.wrapping_sub(clear_1) % modulus);
+        assert_eq!(
+            cks.decrypt_one_block(&overflowed),
+            u64::from(clear_0 < clear_1)
+        );
+
+        let (ct_res, overflowed) = sks.overflowing_scalar_add_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + scalar) % modulus);
+        assert_eq!(
+            cks.decrypt_one_block(&overflowed),
+            u64::from(clear_0 + scalar >= modulus)
+        );
+
+        let (ct_res, overflowed) = sks.overflowing_scalar_sub_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.wrapping_sub(scalar) % modulus);
+        assert_eq!(
+            cks.decrypt_one_block(&overflowed),
+            u64::from(clear_0 < scalar)
+        );
+    }
+
+    // A scalar that does not fit in the ciphertext always overflows
+    let ctxt_0 = cks.encrypt(0u64);
+    let (_, overflowed) = sks.overflowing_scalar_add_parallelized(&ctxt_0, modulus);
+    assert_eq!(cks.decrypt_one_block(&overflowed), 1);
+    let (_, overflowed) = sks.overflowing_scalar_sub_parallelized(&ctxt_0, modulus);
+    assert_eq!(cks.decrypt_one_block(&overflowed), 1);
+}
+
+fn integer_default_scalar_ge_via_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        // Half of the time, the scalar is lower than the clear
+        let scalar = if rng.gen::<bool>() {
+            rng.gen::<u64>() % (clear + 1)
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ctxt = cks.encrypt(clear);
+
+        let (ct_res, is_ge) = sks.scalar_ge_via_sub_parallelized(&ctxt, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        if clear >= scalar {
+            assert_eq!(dec_res, clear - scalar);
+        } else {
+            assert_eq!(dec_res, clear);
+        }
+        assert_eq!(cks.decrypt_bool(&is_ge), clear >= scalar);
+
+        let ctxt_scalar = cks.encrypt(scalar);
+        let (ct_res, is_ge) = sks.ge_via_sub_parallelized(&ctxt, &ctxt_scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        if clear >= scalar {
+            assert_eq!(dec_res, clear - scalar);
+        } else {
+            assert_eq!(dec_res, clear);
+        }
+        assert_eq!(cks.decrypt_bool(&is_ge), clear >= scalar);
+    }
+
+    // A scalar that does not fit in the ciphertext is never subtracted
+    let ctxt = cks.encrypt(modulus - 1);
+    let (ct_res, is_ge) = sks.scalar_ge_via_sub_parallelized(&ctxt, modulus);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, modulus - 1);
+    assert!(!cks.decrypt_bool(&is_ge));
+}
+
+fn integer_default_saturating_add_sub(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+
+        let ct_res = sks.saturating_scalar_add_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + scalar).min(modulus - 1));
+
+        let ct_res = sks.saturating_scalar_sub_parallelized(&ctxt_0, scalar);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(scalar));
+    }
+}
+
+fn integer_default_overflowing_scalar_mul(param: Parameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        // Scalars bigger than the modulus are also tested
+        let scalar = rng.gen::<u64>() % (modulus * 4);
+
+        let ctxt = cks.encrypt(clear);
+
+        let (ct_res, overflowed) = sks.overflowing_scalar_mul_parallelized(&ctxt, scalar);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear * scalar) % modulus);
+        assert_eq!(
+            cks.decrypt_one_block(&overflowed),
+            u64::from(clear * scalar >= modulus)
+        );
+    }
+
+    // The degrees of a small trivial ciphertext show that the product cannot overflow
+    let ctxt: RadixCiphertextBig = sks.create_trivial_radix(1u64, NB_CTXT);
+    let (ct_res, overflowed) = sks.overflowing_scalar_mul_parallelized(&ctxt, 2);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, 2);
+    assert_eq!(cks.decrypt_one_block(&overflowed), 0);
+}
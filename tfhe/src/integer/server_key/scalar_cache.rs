@@ -0,0 +1,133 @@
+//! Lookup tables precomputed for the scalars an application multiplies by repeatedly.
+//!
+//! Applications such as the evaluation of a model multiply ciphertexts by the same scalars (the
+//! weights of the model) millions of times. Registering these scalars with
+//! [`ServerKey::precompute_scalar`] decomposes them and generates, once, the lookup tables
+//! computing the product of a block by each of their digits. The scalar multiplications by a
+//! registered scalar then use these tables, which produce blocks whose carries are already
+//! empty, instead of multiplying the blocks in place and propagating the resulting carries.
+//!
+//! The tables are runtime state: they are not serialized with the key, but are copied when the
+//! key is cloned.
+use super::ServerKey;
+use crate::shortint::server_key::LookupTableOwned;
+use std::collections::HashMap;
+
+/// Digits of a scalar and lookup tables of the product of a block by each of them.
+#[derive(Clone)]
+pub(crate) struct PrecomputedScalar {
+    /// Digits of the scalar in the radix basis, least significant first
+    pub(crate) digits: Vec<u64>,
+    /// For each distinct digit greater than 1, the tables computing the low and the high block of
+    /// the product of a block by the digit
+    pub(crate) tables: HashMap<u64, (LookupTableOwned, LookupTableOwned)>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ScalarMulCache {
+    scalars: HashMap<u64, PrecomputedScalar>,
+}
+
+impl ScalarMulCache {
+    pub(crate) fn get(&self, scalar: u64) -> Option<&PrecomputedScalar> {
+        self.scalars.get(&scalar)
+    }
+}
+
+impl std::fmt::Debug for ScalarMulCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut scalars = self.scalars.keys().collect::<Vec<_>>();
+        scalars.sort_unstable();
+        f.debug_struct("ScalarMulCache")
+            .field("scalars", &scalars)
+            .finish()
+    }
+}
+
+impl ServerKey {
+    /// Precompute the lookup tables used by the scalar multiplications by `scalar`.
+    ///
+    /// The tables are used by [`ServerKey::scalar_mul_parallelized`] and
+    /// [`ServerKey::scalar_mul_assign_parallelized`]. Registering a scalar twice does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let size = 4;
+    /// let (cks, mut sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let weight = 187;
+    /// sks.precompute_scalar(weight);
+    /// assert!(sks.is_scalar_precomputed(weight));
+    ///
+    /// let msg = 58;
+    /// let ct = cks.encrypt(msg);
+    /// let ct_res = sks.scalar_mul_parallelized(&ct, weight);
+    ///
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(clear, msg * weight % 256);
+    ///
+    /// sks.clear_precomputed_scalars();
+    /// assert!(!sks.is_scalar_precomputed(weight));
+    /// ```
+    pub fn precompute_scalar(&mut self, scalar: u64) {
+        if self.scalar_cache.scalars.contains_key(&scalar) {
+            return;
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+
+        let mut digits = Vec::new();
+        let mut scalar_i = scalar;
+        while scalar_i != 0 {
+            digits.push(scalar_i % message_modulus);
+            scalar_i /= message_modulus;
+        }
+
+        let mut tables = HashMap::new();
+        for &digit in &digits {
+            if digit > 1 && !tables.contains_key(&digit) {
+                let low = self
+                    .key
+                    .generate_accumulator(|x| (x * digit) % message_modulus);
+                let high = self
+                    .key
+                    .generate_accumulator(|x| (x * digit) / message_modulus);
+                tables.insert(digit, (low, high));
+            }
+        }
+
+        self.scalar_cache
+            .scalars
+            .insert(scalar, PrecomputedScalar { digits, tables });
+    }
+
+    pub fn is_scalar_precomputed(&self, scalar: u64) -> bool {
+        self.scalar_cache.scalars.contains_key(&scalar)
+    }
+
+    /// Return the registered scalars, in increasing order.
+    pub fn precomputed_scalars(&self) -> Vec<u64> {
+        let mut scalars = self
+            .scalar_cache
+            .scalars
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        scalars.sort_unstable();
+        scalars
+    }
+
+    /// Drop the lookup tables of `scalar`, returning whether it was registered.
+    pub fn forget_precomputed_scalar(&mut self, scalar: u64) -> bool {
+        self.scalar_cache.scalars.remove(&scalar).is_some()
+    }
+
+    /// Drop the lookup tables of all the registered scalars.
+    pub fn clear_precomputed_scalars(&mut self) {
+        self.scalar_cache.scalars.clear();
+    }
+}
@@ -0,0 +1,109 @@
+//! Control of the parallelism used inside a single operation.
+//!
+//! The `_parallelized` operations split their work in as many rayon tasks as they can. This is
+//! the best choice when a single operation runs at a time, but when an application runs many
+//! operations concurrently (e.g. one multiplication per element of a vector, each on its own
+//! rayon task) the tasks of all the operations compete for the same threads: each operation gets
+//! split in small pieces that cannot run in parallel anyway, adding scheduling overhead and
+//! memory pressure for no gain.
+//!
+//! Declaring the number of operations expected to run concurrently with
+//! [`ServerKey::set_par_ops_in_flight`] makes the operations split their work in at most
+//! `threads / ops_in_flight` tasks, `threads` being the number of threads of the rayon pool the
+//! operation runs in. Running operations in a rayon pool built for a given NUMA node or set of
+//! cores therefore also limits their parallelism to that pool.
+use super::ServerKey;
+
+/// Parallelism budget of the operations of a [`ServerKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ParallelismBudget {
+    ops_in_flight: usize,
+}
+
+impl Default for ParallelismBudget {
+    fn default() -> Self {
+        Self { ops_in_flight: 1 }
+    }
+}
+
+impl ServerKey {
+    /// Return the number of operations expected to run concurrently, see
+    /// [`ServerKey::set_par_ops_in_flight`].
+    pub fn par_ops_in_flight(&self) -> usize {
+        self.parallelism_budget.ops_in_flight
+    }
+
+    /// Set the number of operations expected to run concurrently with this key.
+    ///
+    /// The parallelized operations then split their work in at most
+    /// [`ServerKey::intra_op_parallelism`] tasks. The default is 1, i.e. a single operation uses
+    /// all the threads available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ops_in_flight` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rayon::prelude::*;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, mut sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let clears = [(3u64, 5u64), (7, 11), (13, 17), (19, 23)];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&(a, b)| (cks.encrypt(a), cks.encrypt(b)))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // The multiplications run concurrently, limit their inner parallelism accordingly
+    /// sks.set_par_ops_in_flight(cts.len());
+    ///
+    /// let products = cts
+    ///     .par_iter()
+    ///     .map(|(a, b)| sks.mul_parallelized(a, b))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// for (&(a, b), ct) in clears.iter().zip(products.iter()) {
+    ///     let res: u64 = cks.decrypt(ct);
+    ///     assert_eq!(res, (a * b) % 256);
+    /// }
+    /// ```
+    pub fn set_par_ops_in_flight(&mut self, ops_in_flight: usize) {
+        assert!(ops_in_flight > 0, "ops_in_flight must be greater than 0");
+        self.parallelism_budget.ops_in_flight = ops_in_flight;
+    }
+
+    /// Return the maximum number of tasks a parallelized operation splits its work in when
+    /// called from the current rayon pool.
+    pub fn intra_op_parallelism(&self) -> usize {
+        (rayon::current_num_threads() / self.parallelism_budget.ops_in_flight).max(1)
+    }
+
+    /// Minimum number of items to process per task for a parallel iteration over `len` items to
+    /// respect the parallelism budget, to be used with
+    /// [`rayon::iter::IndexedParallelIterator::with_min_len`].
+    pub(crate) fn par_min_len(&self, len: usize) -> usize {
+        let tasks = self.intra_op_parallelism();
+        ((len + tasks - 1) / tasks).max(1)
+    }
+
+    /// Run `oper_a` and `oper_b` with [`rayon::join`], or one after the other if the parallelism
+    /// budget only allows for one task.
+    pub(crate) fn budgeted_join<A, B, RA, RB>(&self, oper_a: A, oper_b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        if self.intra_op_parallelism() > 1 {
+            rayon::join(oper_a, oper_b)
+        } else {
+            (oper_a(), oper_b())
+        }
+    }
+}
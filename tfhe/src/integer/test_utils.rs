@@ -0,0 +1,234 @@
+//! Module with helpers to write property based tests on top of integer.
+//!
+//! This module is only available when the `test-utils` feature is enabled. It complements
+//! [`shortint::test_utils`](crate::shortint::test_utils) with strategies for radix
+//! decompositions, and with helpers to decrypt and check large batches of results in parallel.
+//!
+//! # Example
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::test_utils::radix_clear_value_strategy;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let num_blocks = 4;
+//! let param = PARAM_MESSAGE_2_CARRY_2;
+//! let (cks, sks) = gen_keys_radix(&param, num_blocks);
+//! let modulus = (param.message_modulus.0 as u64).pow(num_blocks as u32);
+//!
+//! proptest!(ProptestConfig::with_cases(2), |(
+//!     clear_0 in radix_clear_value_strategy(param, num_blocks),
+//!     clear_1 in radix_clear_value_strategy(param, num_blocks),
+//! )| {
+//!     let ct_0 = cks.encrypt(clear_0);
+//!     let ct_1 = cks.encrypt(clear_1);
+//!
+//!     let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+//!
+//!     let dec: u64 = cks.decrypt(&ct_res);
+//!     prop_assert_eq!(dec, (clear_0 + clear_1) % modulus);
+//! });
+//! ```
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::encryption::AsLittleEndianWords;
+use crate::integer::ClientKey;
+use crate::shortint::parameters::{
+    Parameters, PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_4,
+};
+use crate::shortint::PBSOrderMarker;
+use proptest::prelude::*;
+use proptest::sample::select;
+use rayon::prelude::*;
+use std::fmt::Debug;
+
+pub use crate::shortint::test_utils::{
+    clear_value_and_degree_strategy, clear_value_strategy, clear_value_with_carry_strategy,
+    degree_strategy,
+};
+
+/// Strategy yielding one of the parameter sets used by the integer test suite, i.e. parameter
+/// sets where the message and carry spaces have the same size.
+pub fn radix_parameters_strategy() -> impl Strategy<Value = Parameters> {
+    select(vec![
+        PARAM_MESSAGE_1_CARRY_1,
+        PARAM_MESSAGE_2_CARRY_2,
+        PARAM_MESSAGE_3_CARRY_3,
+        PARAM_MESSAGE_4_CARRY_4,
+    ])
+}
+
+/// Strategy yielding a clear value fitting in `num_blocks` blocks of the given parameters.
+///
+/// If the radix modulus does not fit in a u64, any u64 can be returned.
+pub fn radix_clear_value_strategy(
+    parameters: Parameters,
+    num_blocks: usize,
+) -> impl Strategy<Value = u64> {
+    let modulus = (parameters.message_modulus.0 as u64).checked_pow(num_blocks as u32);
+    any::<u64>().prop_map(move |value| match modulus {
+        Some(modulus) => value % modulus,
+        None => value,
+    })
+}
+
+/// Strategy yielding a number of blocks needed to represent at most `max_bits` bits of message
+/// with the given parameters, and at least one block.
+pub fn num_blocks_strategy(
+    parameters: Parameters,
+    max_bits: usize,
+) -> impl Strategy<Value = usize> {
+    let bits_in_block = parameters.message_modulus.0.ilog2() as usize;
+    let max_num_blocks = (max_bits / bits_in_block).max(1);
+    1..=max_num_blocks
+}
+
+impl ClientKey {
+    /// Decrypts the radix ciphertexts in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_blocks = 4;
+    ///
+    /// let msgs = [3u64, 42, 191];
+    /// let cts = msgs
+    ///     .iter()
+    ///     .map(|msg| cks.encrypt_radix(*msg, num_blocks))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let decrypted: Vec<u64> = cks.par_decrypt_radix(&cts);
+    /// assert_eq!(decrypted, msgs);
+    /// ```
+    pub fn par_decrypt_radix<T, PBSOrder>(&self, ctxts: &[RadixCiphertext<PBSOrder>]) -> Vec<T>
+    where
+        T: AsLittleEndianWords + Default + Send,
+        PBSOrder: PBSOrderMarker,
+    {
+        ctxts.par_iter().map(|ct| self.decrypt_radix(ct)).collect()
+    }
+
+    /// Decrypts the radix ciphertext and panics if it does not decrypt to `expected`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt_radix(191u64, 4);
+    /// cks.decrypt_and_check_radix(&ct, 191u64);
+    /// ```
+    #[track_caller]
+    pub fn decrypt_and_check_radix<T, PBSOrder>(
+        &self,
+        ctxt: &RadixCiphertext<PBSOrder>,
+        expected: T,
+    ) where
+        T: AsLittleEndianWords + Default + PartialEq + Debug,
+        PBSOrder: PBSOrderMarker,
+    {
+        let decrypted: T = self.decrypt_radix(ctxt);
+        assert_eq!(decrypted, expected, "invalid decryption result");
+    }
+
+    /// Decrypts the radix ciphertexts in parallel and panics if one of them does not decrypt to
+    /// the value at the same index in `expected`.
+    ///
+    /// All the ciphertexts are decrypted before panicking, the message reports the number of
+    /// mismatches and the first of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctxts` and `expected` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let num_blocks = 4;
+    ///
+    /// let msgs = [3u64, 42, 191];
+    /// let cts = msgs
+    ///     .iter()
+    ///     .map(|msg| cks.encrypt_radix(*msg, num_blocks))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// cks.par_decrypt_and_check_radix(&cts, &msgs);
+    /// ```
+    #[track_caller]
+    pub fn par_decrypt_and_check_radix<T, PBSOrder>(
+        &self,
+        ctxts: &[RadixCiphertext<PBSOrder>],
+        expected: &[T],
+    ) where
+        T: AsLittleEndianWords + Default + PartialEq + Debug + Send + Sync,
+        PBSOrder: PBSOrderMarker,
+    {
+        assert_eq!(
+            ctxts.len(),
+            expected.len(),
+            "the number of ciphertexts and of expected values differ"
+        );
+
+        let decrypted: Vec<T> = self.par_decrypt_radix(ctxts);
+        let mut mismatches = decrypted
+            .iter()
+            .zip(expected.iter())
+            .enumerate()
+            .filter(|(_, (decrypted, expected))| decrypted != expected);
+
+        if let Some((index, (decrypted, expected))) = mismatches.next() {
+            panic!(
+                "{} invalid decryption result(s) out of {}, first at index {index}: \
+                decrypted {decrypted:?}, expected {expected:?}",
+                mismatches.count() + 1,
+                ctxts.len(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_radix_clear_value_fits_in_blocks(
+            (param, num_blocks, clear) in radix_parameters_strategy()
+                .prop_flat_map(|param| (Just(param), num_blocks_strategy(param, 64)))
+                .prop_flat_map(|(param, num_blocks)| {
+                    (Just(param), Just(num_blocks), radix_clear_value_strategy(param, num_blocks))
+                })
+        ) {
+            let bits_in_block = param.message_modulus.0.ilog2() as usize;
+            let total_bits = bits_in_block * num_blocks;
+            if total_bits < 64 {
+                prop_assert!(clear < (1u64 << total_bits));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "1 invalid decryption result(s) out of 3, first at index 1")]
+    fn test_par_decrypt_and_check_radix_reports_mismatch() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+        let cts = [1u64, 2, 3]
+            .iter()
+            .map(|msg| cks.encrypt_radix(*msg, 2))
+            .collect::<Vec<_>>();
+
+        cks.par_decrypt_and_check_radix(&cts, &[1u64, 4, 3]);
+    }
+}
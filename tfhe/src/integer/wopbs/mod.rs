@@ -7,6 +7,7 @@
 mod test;
 
 use crate::core_crypto::prelude::*;
+use crate::integer::cancellation::{CancellationToken, Cancelled};
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::{ClientKey, CrtCiphertext, IntegerCiphertext, ServerKey};
 use crate::shortint::ciphertext::Degree;
@@ -22,6 +23,8 @@ pub struct WopbsKey {
     wopbs_key: crate::shortint::wopbs::WopbsKey,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for WopbsKey { wopbs_key });
+
 /// ```rust
 /// use tfhe::integer::wopbs::{decode_radix, encode_radix};
 ///
@@ -204,6 +207,52 @@ impl WopbsKey {
     /// assert_eq!(res, clear);
     /// ```
     pub fn wopbs<T>(&self, ct_in: &T, lut: &[Vec<u64>]) -> T
+    where
+        T: IntegerCiphertext,
+    {
+        // A fresh token is never cancelled
+        self.wopbs_cancellable(ct_in, lut, &CancellationToken::new())
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Computes the WoP-PBS given the luts, checking `token` between the block-level steps.
+    ///
+    /// This is the cancellable variant of [`WopbsKey::wopbs`], it returns [`Cancelled`] if
+    /// `token` is cancelled before the evaluation completes, see
+    /// [`crate::integer::cancellation`].
+    ///
+    /// The token is checked before the bits of each block are extracted and before the circuit
+    /// bootstrapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::integer::wopbs::*;
+    /// use tfhe::integer::{CancellationToken, Cancelled};
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let nb_block = 3;
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(&PARAM_MESSAGE_2_CARRY_2);
+    /// let wopbs_key = WopbsKey::new_wopbs_key(&cks, &sks, &WOPBS_PARAM_MESSAGE_2_CARRY_2);
+    /// let clear = 42 % 64;
+    /// let ct = cks.encrypt_radix(clear as u64, nb_block);
+    /// let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+    /// let lut = wopbs_key.generate_lut_radix(&ct, |x| x);
+    ///
+    /// let token = CancellationToken::new();
+    /// token.cancel();
+    /// let result = wopbs_key.wopbs_cancellable(&ct, &lut, &token);
+    /// assert_eq!(result.err(), Some(Cancelled));
+    /// ```
+    pub fn wopbs_cancellable<T>(
+        &self,
+        ct_in: &T,
+        lut: &[Vec<u64>],
+        token: &CancellationToken,
+    ) -> Result<T, Cancelled>
     where
         T: IntegerCiphertext,
     {
@@ -229,6 +278,8 @@ impl WopbsKey {
 
         // Extraction of each bit for each block
         for block in ct_in.blocks().iter().rev() {
+            token.check()?;
+
             let delta = (1_usize << 63)
                 / (self.wopbs_key.param.message_modulus.0 * self.wopbs_key.param.carry_modulus.0);
             let delta_log = DeltaLog(f64::log2(delta as f64) as usize);
@@ -249,6 +300,8 @@ impl WopbsKey {
             );
         }
 
+        token.check()?;
+
         let vec_ct_out = self
             .wopbs_key
             .circuit_bootstrapping_vertical_packing(lut, &extracted_bits_blocks);
@@ -263,7 +316,7 @@ impl WopbsKey {
                 _order_marker: Default::default(),
             });
         }
-        T::from_blocks(ct_vec_out)
+        Ok(T::from_blocks(ct_vec_out))
     }
 
     /// # Example
@@ -0,0 +1,591 @@
+//! Raw binary key and ciphertext format shared with other TFHE implementations.
+//!
+//! Other TFHE toolchains (TFHE-lib, concrete, tfhe-go, ...) store the same LWE/GLWE entities as
+//! this crate, but not with bincode: their keys and ciphertexts are plain arrays of torus
+//! elements, 32 bits wide for TFHE-lib and 64 bits wide for the others. The functions of this
+//! module read and write such arrays, preceded by a small header describing their dimensions:
+//!
+//! | field        | size (bytes)  | content                                               |
+//! |--------------|---------------|-------------------------------------------------------|
+//! | magic        | 4             | `b"TFRW"`                                             |
+//! | version      | 2             | [`RAW_FORMAT_VERSION`], little endian                 |
+//! | kind         | 1             | [`RawFormat::KIND`]                                   |
+//! | torus width  | 1             | 32 or 64, see [`TorusWidth`]                          |
+//! | dimensions   | 8 each        | [`RawFormat::dimensions`], little endian              |
+//! | coefficients | 4 or 8 each   | data of the entity, little endian                     |
+//!
+//! The coefficients are stored in the order of the containers of the corresponding
+//! [`core_crypto`](crate::core_crypto) entities: mask then body for ciphertexts, one key element
+//! after the other for keyswitching keys and one GGSW ciphertext per input key element for
+//! bootstrapping keys. Data exported by another implementation storing its entities in this order
+//! only needs to be prefixed by the header to be imported.
+//!
+//! Ciphertexts and keys using a 32 bits torus are moved to the native 64 bits torus of this crate
+//! when they are read, and rounded to their 32 most significant bits when written with
+//! [`TorusWidth::U32`]. Secret keys are binary and are not scaled.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::core_crypto::prelude::*;
+//! use tfhe::interop::{RawFormat, TorusWidth};
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::ClientKey;
+//!
+//! let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let mut ct = cks.encrypt(3);
+//!
+//! // Export the secret keys and a ciphertext as another implementation would store them
+//! let (glwe_secret_key, small_lwe_secret_key, parameters) = cks.into_raw_parts();
+//! let mut glwe_buffer = Vec::new();
+//! glwe_secret_key
+//!     .write_raw(&mut glwe_buffer, TorusWidth::U64)
+//!     .unwrap();
+//! let mut lwe_buffer = Vec::new();
+//! small_lwe_secret_key
+//!     .write_raw(&mut lwe_buffer, TorusWidth::U64)
+//!     .unwrap();
+//! let mut ct_buffer = Vec::new();
+//! ct.ct.write_raw(&mut ct_buffer, TorusWidth::U64).unwrap();
+//!
+//! // And import them back
+//! let glwe_secret_key = GlweSecretKeyOwned::read_raw(glwe_buffer.as_slice()).unwrap();
+//! let small_lwe_secret_key = LweSecretKeyOwned::read_raw(lwe_buffer.as_slice()).unwrap();
+//! let cks =
+//!     ClientKey::try_from_raw_parts(glwe_secret_key, small_lwe_secret_key, parameters).unwrap();
+//!
+//! ct.ct = LweCiphertextOwned::read_raw(ct_buffer.as_slice()).unwrap();
+//! assert_eq!(cks.decrypt(&ct), 3);
+//! ```
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use std::io::{Read, Write};
+
+pub const RAW_FORMAT_MAGIC: [u8; 4] = *b"TFRW";
+pub const RAW_FORMAT_VERSION: u16 = 1;
+
+const HEADER_SIZE: usize = 4 + 2 + 1 + 1;
+
+/// Width of the torus elements in the raw format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TorusWidth {
+    /// 32 bits torus, as used by TFHE-lib.
+    U32,
+    /// 64 bits torus, the native torus of this crate.
+    U64,
+}
+
+impl TorusWidth {
+    pub fn bits(self) -> usize {
+        match self {
+            Self::U32 => 32,
+            Self::U64 => 64,
+        }
+    }
+
+    fn bytes(self) -> usize {
+        self.bits() / 8
+    }
+
+    fn from_bits(bits: u8) -> Result<Self, InteropError> {
+        match bits {
+            32 => Ok(Self::U32),
+            64 => Ok(Self::U64),
+            _ => Err(InteropError::UnsupportedTorusWidth(bits)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InteropError {
+    Io(std::io::Error),
+    /// The input ended before the end of the entity
+    Truncated,
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    UnexpectedKind {
+        expected: u8,
+        got: u8,
+    },
+    UnsupportedTorusWidth(u8),
+    /// The dimensions of the header are zero or too large
+    InvalidDimensions,
+    /// Only the native ciphertext modulus can be exported
+    UnsupportedCiphertextModulus,
+    /// The decomposition of the key uses more bits than the torus it is written with
+    DecompositionTooLarge {
+        bits: usize,
+        torus_bits: usize,
+    },
+}
+
+impl std::fmt::Display for InteropError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Truncated => write!(f, "The input is truncated"),
+            Self::InvalidMagic => write!(f, "The input does not start with a raw entity header"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported raw format version {version}")
+            }
+            Self::UnexpectedKind { expected, got } => {
+                write!(f, "Expected an entity of kind {expected}, got {got}")
+            }
+            Self::UnsupportedTorusWidth(bits) => write!(f, "Unsupported torus width {bits}"),
+            Self::InvalidDimensions => write!(f, "Invalid dimensions in the header"),
+            Self::UnsupportedCiphertextModulus => {
+                write!(f, "Only the native ciphertext modulus is supported")
+            }
+            Self::DecompositionTooLarge { bits, torus_bits } => write!(
+                f,
+                "The decomposition uses {bits} bits, more than the {torus_bits} bits of the torus"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InteropError {}
+
+impl From<std::io::Error> for InteropError {
+    fn from(err: std::io::Error) -> Self {
+        if err.kind() == std::io::ErrorKind::UnexpectedEof {
+            Self::Truncated
+        } else {
+            Self::Io(err)
+        }
+    }
+}
+
+/// Entities that can be stored in the raw format.
+pub trait RawFormat: Sized {
+    /// Tag identifying the kind of entity
+    const KIND: u8;
+    /// Whether the coefficients are torus elements, scaled when changing the torus width
+    const IS_TORUS: bool;
+
+    /// Dimensions written in the header, enough to rebuild the entity from its coefficients.
+    fn dimensions(&self) -> Vec<u64>;
+
+    /// Number of dimensions of the header.
+    fn dimension_count() -> usize;
+
+    /// Number of coefficients of an entity with the given dimensions, `None` if the dimensions
+    /// are invalid.
+    fn element_count(dimensions: &[u64]) -> Option<usize>;
+
+    fn coefficients(&self) -> &[u64];
+
+    fn from_parts(dimensions: &[u64], coefficients: Vec<u64>) -> Self;
+
+    /// Check that the entity can be written with a torus of the given width.
+    fn check_width(&self, _width: TorusWidth) -> Result<(), InteropError> {
+        Ok(())
+    }
+
+    fn write_raw<W: Write>(&self, mut writer: W, width: TorusWidth) -> Result<(), InteropError> {
+        self.check_width(width)?;
+
+        let mut header = Vec::with_capacity(HEADER_SIZE + 8 * Self::dimension_count());
+        header.extend_from_slice(&RAW_FORMAT_MAGIC);
+        header.extend_from_slice(&RAW_FORMAT_VERSION.to_le_bytes());
+        header.push(Self::KIND);
+        header.push(width.bits() as u8);
+        for dimension in self.dimensions() {
+            header.extend_from_slice(&dimension.to_le_bytes());
+        }
+        writer.write_all(&header)?;
+
+        let coefficients = self.coefficients();
+        let mut data = Vec::with_capacity(coefficients.len() * width.bytes());
+        match width {
+            TorusWidth::U64 => {
+                for &coefficient in coefficients {
+                    data.extend_from_slice(&coefficient.to_le_bytes());
+                }
+            }
+            TorusWidth::U32 => {
+                for &coefficient in coefficients {
+                    let coefficient = if Self::IS_TORUS {
+                        // Round to the closest element of the 32 bits torus
+                        (coefficient.wrapping_add(1 << 31) >> 32) as u32
+                    } else {
+                        coefficient as u32
+                    };
+                    data.extend_from_slice(&coefficient.to_le_bytes());
+                }
+            }
+        }
+        writer.write_all(&data)?;
+
+        Ok(())
+    }
+
+    fn read_raw<R: Read>(mut reader: R) -> Result<Self, InteropError> {
+        let mut header = [0u8; HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+
+        let (magic, rest) = header.split_at(4);
+        let (version, rest) = rest.split_at(2);
+
+        if magic != RAW_FORMAT_MAGIC {
+            return Err(InteropError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes(version.try_into().unwrap());
+        if version != RAW_FORMAT_VERSION {
+            return Err(InteropError::UnsupportedVersion(version));
+        }
+        if rest[0] != Self::KIND {
+            return Err(InteropError::UnexpectedKind {
+                expected: Self::KIND,
+                got: rest[0],
+            });
+        }
+        let width = TorusWidth::from_bits(rest[1])?;
+
+        let mut dimensions = vec![0u64; Self::dimension_count()];
+        for dimension in dimensions.iter_mut() {
+            let mut bytes = [0u8; 8];
+            reader.read_exact(&mut bytes)?;
+            *dimension = u64::from_le_bytes(bytes);
+        }
+
+        let data_len = Self::element_count(&dimensions)
+            .filter(|&count| count > 0)
+            .and_then(|count| count.checked_mul(width.bytes()))
+            .ok_or(InteropError::InvalidDimensions)?;
+
+        // The buffer grows with the data actually read, a forged header cannot make us allocate
+        // more memory than the size of the input
+        let mut data = Vec::new();
+        let read = reader
+            .by_ref()
+            .take(data_len as u64)
+            .read_to_end(&mut data)?;
+        if read != data_len {
+            return Err(InteropError::Truncated);
+        }
+
+        let coefficients = match width {
+            TorusWidth::U64 => data
+                .chunks_exact(8)
+                .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                .collect(),
+            TorusWidth::U32 => data
+                .chunks_exact(4)
+                .map(|bytes| {
+                    let coefficient = u32::from_le_bytes(bytes.try_into().unwrap()) as u64;
+                    if Self::IS_TORUS {
+                        coefficient << 32
+                    } else {
+                        coefficient
+                    }
+                })
+                .collect(),
+        };
+
+        Ok(Self::from_parts(&dimensions, coefficients))
+    }
+}
+
+fn checked_product(factors: &[u64]) -> Option<usize> {
+    factors.iter().try_fold(1usize, |acc, &factor| {
+        let factor = usize::try_from(factor).ok().filter(|&factor| factor > 0)?;
+        acc.checked_mul(factor)
+    })
+}
+
+fn check_native_modulus(ciphertext_modulus: CiphertextModulus<u64>) -> Result<(), InteropError> {
+    if ciphertext_modulus.is_native_modulus() {
+        Ok(())
+    } else {
+        Err(InteropError::UnsupportedCiphertextModulus)
+    }
+}
+
+fn check_decomposition(
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+    width: TorusWidth,
+) -> Result<(), InteropError> {
+    let bits = base_log.0 * level_count.0;
+    if bits > width.bits() {
+        Err(InteropError::DecompositionTooLarge {
+            bits,
+            torus_bits: width.bits(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+impl RawFormat for LweSecretKeyOwned<u64> {
+    const KIND: u8 = 1;
+    const IS_TORUS: bool = false;
+
+    fn dimensions(&self) -> Vec<u64> {
+        vec![self.lwe_dimension().0 as u64]
+    }
+
+    fn dimension_count() -> usize {
+        1
+    }
+
+    fn element_count(dimensions: &[u64]) -> Option<usize> {
+        checked_product(dimensions)
+    }
+
+    fn coefficients(&self) -> &[u64] {
+        self.as_ref()
+    }
+
+    fn from_parts(_dimensions: &[u64], coefficients: Vec<u64>) -> Self {
+        Self::from_container(coefficients)
+    }
+}
+
+impl RawFormat for GlweSecretKeyOwned<u64> {
+    const KIND: u8 = 2;
+    const IS_TORUS: bool = false;
+
+    /// GLWE dimension and polynomial size
+    fn dimensions(&self) -> Vec<u64> {
+        vec![
+            self.glwe_dimension().0 as u64,
+            self.polynomial_size().0 as u64,
+        ]
+    }
+
+    fn dimension_count() -> usize {
+        2
+    }
+
+    fn element_count(dimensions: &[u64]) -> Option<usize> {
+        checked_product(dimensions)
+    }
+
+    fn coefficients(&self) -> &[u64] {
+        self.as_ref()
+    }
+
+    fn from_parts(dimensions: &[u64], coefficients: Vec<u64>) -> Self {
+        Self::from_container(coefficients, PolynomialSize(dimensions[1] as usize))
+    }
+}
+
+impl RawFormat for LweCiphertextOwned<u64> {
+    const KIND: u8 = 3;
+    const IS_TORUS: bool = true;
+
+    /// LWE size, the LWE dimension plus one for the body
+    fn dimensions(&self) -> Vec<u64> {
+        vec![self.lwe_size().0 as u64]
+    }
+
+    fn dimension_count() -> usize {
+        1
+    }
+
+    fn element_count(dimensions: &[u64]) -> Option<usize> {
+        checked_product(dimensions)
+    }
+
+    fn coefficients(&self) -> &[u64] {
+        self.as_ref()
+    }
+
+    fn check_width(&self, _width: TorusWidth) -> Result<(), InteropError> {
+        check_native_modulus(self.ciphertext_modulus())
+    }
+
+    fn from_parts(_dimensions: &[u64], coefficients: Vec<u64>) -> Self {
+        Self::from_container(coefficients, CiphertextModulus::new_native())
+    }
+}
+
+impl RawFormat for LweKeyswitchKeyOwned<u64> {
+    const KIND: u8 = 4;
+    const IS_TORUS: bool = true;
+
+    /// Input LWE dimension, output LWE size, decomposition base log and level count
+    fn dimensions(&self) -> Vec<u64> {
+        vec![
+            self.input_key_lwe_dimension().0 as u64,
+            self.output_lwe_size().0 as u64,
+            self.decomposition_base_log().0 as u64,
+            self.decomposition_level_count().0 as u64,
+        ]
+    }
+
+    fn dimension_count() -> usize {
+        4
+    }
+
+    fn element_count(dimensions: &[u64]) -> Option<usize> {
+        // input_lwe_dimension * level_count * output_lwe_size, the base log only has to be non
+        // zero
+        checked_product(&[dimensions[2]])?;
+        checked_product(&[dimensions[0], dimensions[3], dimensions[1]])
+    }
+
+    fn coefficients(&self) -> &[u64] {
+        self.as_ref()
+    }
+
+    fn check_width(&self, width: TorusWidth) -> Result<(), InteropError> {
+        check_native_modulus(self.ciphertext_modulus())?;
+        check_decomposition(
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+            width,
+        )
+    }
+
+    fn from_parts(dimensions: &[u64], coefficients: Vec<u64>) -> Self {
+        Self::from_container(
+            coefficients,
+            DecompositionBaseLog(dimensions[2] as usize),
+            DecompositionLevelCount(dimensions[3] as usize),
+            LweSize(dimensions[1] as usize),
+            CiphertextModulus::new_native(),
+        )
+    }
+}
+
+impl RawFormat for LweBootstrapKeyOwned<u64> {
+    const KIND: u8 = 5;
+    const IS_TORUS: bool = true;
+
+    /// Input LWE dimension, GLWE size, polynomial size, decomposition base log and level count
+    fn dimensions(&self) -> Vec<u64> {
+        vec![
+            self.input_lwe_dimension().0 as u64,
+            self.glwe_size().0 as u64,
+            self.polynomial_size().0 as u64,
+            self.decomposition_base_log().0 as u64,
+            self.decomposition_level_count().0 as u64,
+        ]
+    }
+
+    fn dimension_count() -> usize {
+        5
+    }
+
+    fn element_count(dimensions: &[u64]) -> Option<usize> {
+        let glwe_size = dimensions[1];
+        // input_lwe_dimension * level_count * glwe_size * glwe_size * polynomial_size, the base
+        // log only has to be non zero
+        checked_product(&[dimensions[3]])?;
+        checked_product(&[
+            dimensions[0],
+            dimensions[4],
+            glwe_size,
+            glwe_size,
+            dimensions[2],
+        ])
+    }
+
+    fn coefficients(&self) -> &[u64] {
+        self.as_ref()
+    }
+
+    fn check_width(&self, width: TorusWidth) -> Result<(), InteropError> {
+        check_native_modulus(self.ciphertext_modulus())?;
+        check_decomposition(
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+            width,
+        )
+    }
+
+    fn from_parts(dimensions: &[u64], coefficients: Vec<u64>) -> Self {
+        Self::from_container(
+            coefficients,
+            GlweSize(dimensions[1] as usize),
+            PolynomialSize(dimensions[2] as usize),
+            DecompositionBaseLog(dimensions[3] as usize),
+            DecompositionLevelCount(dimensions[4] as usize),
+            CiphertextModulus::new_native(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::*;
+
+    #[test]
+    fn test_raw_format_32_bits_torus() {
+        let lwe_dimension = LweDimension(742);
+        let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+        let ciphertext_modulus = CiphertextModulus::new_native();
+
+        let mut seeder = new_seeder();
+        let seeder = seeder.as_mut();
+        let mut secret_generator =
+            SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+        let mut encryption_generator =
+            EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+        let lwe_secret_key =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+
+        let delta = 1u64 << 60;
+        for msg in 0..16u64 {
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_secret_key,
+                Plaintext(msg * delta),
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut encryption_generator,
+            );
+
+            let mut buffer = Vec::new();
+            ct.write_raw(&mut buffer, TorusWidth::U32).unwrap();
+            assert_eq!(buffer.len(), HEADER_SIZE + 8 + 4 * ct.lwe_size().0);
+
+            // The rounding to 32 bits only adds a negligible error
+            let ct = LweCiphertextOwned::read_raw(buffer.as_slice()).unwrap();
+            let decrypted = decrypt_lwe_ciphertext(&lwe_secret_key, &ct);
+            let decoded = (decrypted.0.wrapping_add(delta / 2) / delta) % 16;
+            assert_eq!(decoded, msg);
+
+            // Truncated inputs and inputs of another kind are rejected
+            let truncated = &buffer[..buffer.len() - 1];
+            assert!(matches!(
+                LweCiphertextOwned::read_raw(truncated),
+                Err(InteropError::Truncated)
+            ));
+            assert!(matches!(
+                LweSecretKeyOwned::read_raw(buffer.as_slice()),
+                Err(InteropError::UnexpectedKind { .. })
+            ));
+        }
+
+        let lwe_secret_key_2 =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &lwe_secret_key,
+            &lwe_secret_key_2,
+            DecompositionBaseLog(12),
+            DecompositionLevelCount(3),
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut encryption_generator,
+        );
+
+        // 36 bits of decomposition do not fit in a 32 bits torus
+        assert!(matches!(
+            ksk.write_raw(Vec::new(), TorusWidth::U32),
+            Err(InteropError::DecompositionTooLarge { .. })
+        ));
+
+        let mut buffer = Vec::new();
+        ksk.write_raw(&mut buffer, TorusWidth::U64).unwrap();
+        assert_eq!(
+            LweKeyswitchKeyOwned::read_raw(buffer.as_slice()).unwrap(),
+            ksk
+        );
+    }
+}
@@ -8,6 +8,13 @@ pub mod boolean;
 #[cfg(feature = "boolean-client-js-wasm-api")]
 pub use boolean::*;
 
+/// Initialize the pool of web workers used by the parallel operations.
+///
+/// It has to be called (and awaited) once from JS before running operations, otherwise they
+/// run sequentially on the calling thread.
+#[cfg(feature = "parallel-wasm-api")]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
 pub(self) mod js_wasm_seeder {
     use crate::core_crypto::commons::math::random::{Seed, Seeder};
 
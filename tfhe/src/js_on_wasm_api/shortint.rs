@@ -160,6 +160,7 @@ impl Shortint {
             polynomial_size: PolynomialSize(polynomial_size),
             lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
             glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+            noise_distribution: crate::shortint::parameters::NoiseDistributionKind::Gaussian,
             pbs_base_log: DecompositionBaseLog(pbs_base_log),
             pbs_level: DecompositionLevelCount(pbs_level),
             ks_base_log: DecompositionBaseLog(ks_base_log),
@@ -31,6 +31,13 @@ pub mod core_crypto;
 /// cbindgen:ignore
 pub mod integer;
 
+#[cfg(feature = "integer")]
+/// Application level helpers built on top of the [`integer`](`crate::integer`) module.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod apps;
+
 #[cfg(feature = "shortint")]
 /// Welcome to the TFHE-rs [`shortint`](`crate::shortint`) module documentation!
 ///
@@ -38,12 +45,76 @@ pub mod integer;
 /// cbindgen:ignore
 pub mod shortint;
 
+#[cfg(feature = "framing")]
+/// cbindgen:ignore
+pub mod framing;
+
+#[cfg(feature = "interop")]
+/// cbindgen:ignore
+pub mod interop;
+
+#[cfg(feature = "rkyv")]
+/// cbindgen:ignore
+pub mod archive;
+
+#[cfg(feature = "migration")]
+/// Migration of keys serialized with previous versions of the library.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod migration;
+
+/// The tfhe prelude.
+pub mod prelude;
+
+/// Size introspection for ciphertexts and keys.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod size;
+
+#[cfg(feature = "shortint")]
+/// Metrics reported by the shortint and integer operations, see the `metrics` feature.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod metrics;
+
+#[cfg(feature = "shortint")]
+/// Simulation of shortint and integer circuits on clear values.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod simulator;
+
+#[cfg(feature = "shortint")]
+/// Empirical measurement of the noise and error rate of ciphertexts.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod stats;
+
+#[cfg(feature = "timing-audit")]
+/// Measurement of the data-dependent timing of the shortint and integer operations.
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod timing_audit;
+
 #[cfg(feature = "__wasm_api")]
 /// cbindgen:ignore
 pub mod js_on_wasm_api;
 #[cfg(feature = "__wasm_api")]
 pub use js_on_wasm_api::*;
 
+#[cfg(feature = "__node_api")]
+/// cbindgen:ignore
+pub mod node_api;
+
+#[cfg(feature = "__python_api")]
+/// cbindgen:ignore
+pub mod python_api;
+
 #[cfg(all(
     doctest,
     feature = "shortint",
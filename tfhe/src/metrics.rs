@@ -0,0 +1,96 @@
+//! Counters and histograms reported through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! When the `metrics` feature is enabled, the shortint engine and the integer operations report
+//! the following metrics to the recorder installed by the application (e.g. a Prometheus
+//! exporter):
+//!
+//! | name                                | kind      | description                                          |
+//! |-------------------------------------|-----------|------------------------------------------------------|
+//! | [`PBS_COUNT`]                       | counter   | number of programmable bootstraps computed           |
+//! | [`KEYSWITCH_COUNT`]                 | counter   | number of LWE keyswitches computed                   |
+//! | [`ENGINE_BUFFER_BYTES`]             | gauge     | bytes held by the ciphertext buffers of the engines  |
+//! | [`INTEGER_OPERATION_DURATION`]      | histogram | duration in seconds of integer operations            |
+//!
+//! The operation durations are labelled with the name of the operation under the `op` label.
+//!
+//! When the feature is disabled, the recording functions are no-ops and are optimized away.
+
+/// Name of the counter of programmable bootstraps.
+pub const PBS_COUNT: &str = "tfhe_pbs_total";
+
+/// Name of the counter of LWE keyswitches.
+pub const KEYSWITCH_COUNT: &str = "tfhe_keyswitch_total";
+
+/// Name of the gauge of the bytes held by the ciphertext buffers of the shortint engines.
+pub const ENGINE_BUFFER_BYTES: &str = "tfhe_engine_buffer_bytes";
+
+/// Name of the histogram of the integer operations durations, in seconds.
+pub const INTEGER_OPERATION_DURATION: &str = "tfhe_integer_operation_duration_seconds";
+
+#[inline(always)]
+pub(crate) fn record_pbs(count: usize) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(PBS_COUNT, count as u64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+#[inline(always)]
+pub(crate) fn record_keyswitch(count: usize) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(KEYSWITCH_COUNT, count as u64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+#[inline(always)]
+pub(crate) fn record_engine_buffer_allocation(bytes: usize) {
+    #[cfg(feature = "metrics")]
+    ::metrics::increment_gauge!(ENGINE_BUFFER_BYTES, bytes as f64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = bytes;
+}
+
+#[inline(always)]
+pub(crate) fn record_engine_buffer_release(bytes: usize) {
+    #[cfg(feature = "metrics")]
+    ::metrics::decrement_gauge!(ENGINE_BUFFER_BYTES, bytes as f64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = bytes;
+}
+
+/// Records the duration of an integer operation in [`INTEGER_OPERATION_DURATION`] when dropped.
+// Unused when the integer feature is disabled
+#[allow(dead_code)]
+pub(crate) struct OperationTimer {
+    #[cfg(feature = "metrics")]
+    op: &'static str,
+    #[cfg(feature = "metrics")]
+    start: std::time::Instant,
+}
+
+impl OperationTimer {
+    #[inline(always)]
+    #[allow(dead_code)]
+    pub(crate) fn new(op: &'static str) -> Self {
+        #[cfg(not(feature = "metrics"))]
+        let _ = op;
+        Self {
+            #[cfg(feature = "metrics")]
+            op,
+            #[cfg(feature = "metrics")]
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        ::metrics::histogram!(
+            INTEGER_OPERATION_DURATION,
+            self.start.elapsed(),
+            "op" => self.op
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! Migration of serialized keys to the current layout.
+//!
+//! The serialized form of a key mirrors its structure, so a change of structure makes the keys
+//! stored with a previous version of the library impossible to deserialize, together with the
+//! data encrypted for them. The functions of this module read a key serialized (with bincode)
+//! under any of the layouts listed below and rebuild the current structure from it.
+//!
+//! | layout    | bootstrapping key of the shortint [`ServerKey`]                           |
+//! |-----------|---------------------------------------------------------------------------|
+//! | `V0_2`    | a Fourier key, read as a [`ShortintBootstrappingKey::Classic`] key        |
+//! | `Current` | a [`ShortintBootstrappingKey`]                                            |
+//!
+//! The layout of the ciphertexts and of the client and public keys did not change since `V0_2`,
+//! they deserialize as is.
+//!
+//! A migrated key is checked as a key generated by the library would be: the dimensions of its
+//! keyswitching and bootstrapping keys must match, otherwise a [`MigrationError`] says which ones
+//! do not.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::migration::{migrate_shortint_server_key, rewrite_shortint_server_key, Layout};
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let stored = bincode::serialize(&sks).unwrap();
+//!
+//! // Keys already in the current layout are read as is
+//! let (sks, layout) = migrate_shortint_server_key(&stored).unwrap();
+//! assert_eq!(layout, Layout::Current);
+//!
+//! let ct = cks.encrypt(1);
+//! assert_eq!(cks.decrypt(&sks.scalar_add(&ct, 1)), 2);
+//!
+//! // Rewrite a stored key to the current layout
+//! let rewritten = rewrite_shortint_server_key(&stored).unwrap();
+//! assert_eq!(rewritten, stored);
+//! ```
+use crate::core_crypto::entities::LweKeyswitchKeyOwned;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::ServerKey;
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Serialization layouts of the keys, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layout {
+    V0_2,
+    Current,
+}
+
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The input deserializes under none of the known layouts, the error of each layout is kept
+    UnknownLayout {
+        current: bincode::Error,
+        legacy: Vec<(Layout, bincode::Error)>,
+    },
+    /// The input deserializes but its keys are not compatible with each other
+    DimensionMismatch {
+        what: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    Serialization(bincode::Error),
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownLayout { current, legacy } => {
+                write!(f, "Unknown layout, as {:?}: {current}", Layout::Current)?;
+                for (layout, err) in legacy {
+                    write!(f, ", as {layout:?}: {err}")?;
+                }
+                Ok(())
+            }
+            Self::DimensionMismatch {
+                what,
+                expected,
+                got,
+            } => write!(f, "Mismatch of the {what}: expected {expected}, got {got}"),
+            Self::Serialization(err) => write!(f, "Serialization error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+/// Layout of the shortint [`ServerKey`] up to tfhe 0.2.
+#[derive(Deserialize)]
+struct ServerKeyV0_2 {
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: FourierLweBootstrapKeyOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+}
+
+impl ServerKeyV0_2 {
+    fn migrate(self) -> Result<ServerKey, MigrationError> {
+        let bootstrapping_key = ShortintBootstrappingKey::from(self.bootstrapping_key);
+        check_server_key_dimensions(&self.key_switching_key, &bootstrapping_key)?;
+
+        Ok(ServerKey::from_raw_parts(
+            self.key_switching_key,
+            bootstrapping_key,
+            self.message_modulus,
+            self.carry_modulus,
+            self.max_degree,
+            self.ciphertext_modulus,
+        ))
+    }
+}
+
+/// Layout of the integer [`ServerKey`](crate::integer::ServerKey) up to tfhe 0.2.
+#[cfg(feature = "integer")]
+#[derive(Deserialize)]
+struct IntegerServerKeyV0_2 {
+    key: ServerKeyV0_2,
+}
+
+fn check_server_key_dimensions(
+    key_switching_key: &LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &ShortintBootstrappingKey,
+) -> Result<(), MigrationError> {
+    let checks = [
+        (
+            "keyswitching key input and bootstrapping key output LWE dimensions",
+            bootstrapping_key.output_lwe_dimension().0,
+            key_switching_key.input_key_lwe_dimension().0,
+        ),
+        (
+            "keyswitching key output and bootstrapping key input LWE dimensions",
+            bootstrapping_key.input_lwe_dimension().0,
+            key_switching_key.output_key_lwe_dimension().0,
+        ),
+    ];
+
+    for (what, expected, got) in checks {
+        if expected != got {
+            return Err(MigrationError::DimensionMismatch {
+                what,
+                expected,
+                got,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Deserialize the whole input, trailing bytes mean the input was not written with this layout.
+fn deserialize_exact<T: DeserializeOwned>(serialized: &[u8]) -> bincode::Result<T> {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .deserialize(serialized)
+}
+
+fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, MigrationError> {
+    bincode::serialize(value).map_err(MigrationError::Serialization)
+}
+
+/// Deserialize a shortint [`ServerKey`] serialized under any known layout.
+///
+/// The layout the key was serialized with is returned with the key.
+pub fn migrate_shortint_server_key(
+    serialized: &[u8],
+) -> Result<(ServerKey, Layout), MigrationError> {
+    let current = match deserialize_exact::<ServerKey>(serialized) {
+        Ok(key) => {
+            check_server_key_dimensions(&key.key_switching_key, &key.bootstrapping_key)?;
+            return Ok((key, Layout::Current));
+        }
+        Err(err) => err,
+    };
+
+    match deserialize_exact::<ServerKeyV0_2>(serialized) {
+        Ok(key) => Ok((key.migrate()?, Layout::V0_2)),
+        Err(legacy) => Err(MigrationError::UnknownLayout {
+            current,
+            legacy: vec![(Layout::V0_2, legacy)],
+        }),
+    }
+}
+
+/// Rewrite a shortint [`ServerKey`] serialized under any known layout to the current layout.
+pub fn rewrite_shortint_server_key(serialized: &[u8]) -> Result<Vec<u8>, MigrationError> {
+    let (key, _) = migrate_shortint_server_key(serialized)?;
+    serialize(&key)
+}
+
+/// Deserialize an integer [`ServerKey`](crate::integer::ServerKey) serialized under any known
+/// layout.
+///
+/// The layout the key was serialized with is returned with the key.
+#[cfg(feature = "integer")]
+pub fn migrate_integer_server_key(
+    serialized: &[u8],
+) -> Result<(crate::integer::ServerKey, Layout), MigrationError> {
+    let current = match deserialize_exact::<crate::integer::ServerKey>(serialized) {
+        Ok(key) => {
+            check_server_key_dimensions(&key.key.key_switching_key, &key.key.bootstrapping_key)?;
+            return Ok((key, Layout::Current));
+        }
+        Err(err) => err,
+    };
+
+    match deserialize_exact::<IntegerServerKeyV0_2>(serialized) {
+        Ok(key) => {
+            let key = crate::integer::ServerKey {
+                key: key.key.migrate()?,
+                parallelism_budget: Default::default(),
+                scalar_cache: Default::default(),
+            };
+            Ok((key, Layout::V0_2))
+        }
+        Err(legacy) => Err(MigrationError::UnknownLayout {
+            current,
+            legacy: vec![(Layout::V0_2, legacy)],
+        }),
+    }
+}
+
+/// Rewrite an integer [`ServerKey`](crate::integer::ServerKey) serialized under any known layout
+/// to the current layout.
+#[cfg(feature = "integer")]
+pub fn rewrite_integer_server_key(serialized: &[u8]) -> Result<Vec<u8>, MigrationError> {
+    let (key, _) = migrate_integer_server_key(serialized)?;
+    serialize(&key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+
+    /// Serialize a key as tfhe 0.2 did, the fields were serialized in the same order
+    #[derive(Serialize)]
+    struct ServerKeyV0_2Ref<'a> {
+        key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: &'a FourierLweBootstrapKeyOwned,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        max_degree: MaxDegree,
+        ciphertext_modulus: CiphertextModulus,
+    }
+
+    fn serialize_v0_2(sks: &ServerKey, bootstrapping_key: &FourierLweBootstrapKeyOwned) -> Vec<u8> {
+        bincode::serialize(&ServerKeyV0_2Ref {
+            key_switching_key: &sks.key_switching_key,
+            bootstrapping_key,
+            message_modulus: sks.message_modulus,
+            carry_modulus: sks.carry_modulus,
+            max_degree: sks.max_degree,
+            ciphertext_modulus: sks.ciphertext_modulus,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_migrate_shortint_server_key_v0_2() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+
+        let legacy = serialize_v0_2(sks, sks.bootstrapping_key.as_fourier().unwrap());
+
+        let (migrated, layout) = migrate_shortint_server_key(&legacy).unwrap();
+        assert_eq!(layout, Layout::V0_2);
+        assert_eq!(&migrated, sks);
+
+        let ct = cks.encrypt(2);
+        assert_eq!(cks.decrypt(&migrated.scalar_add(&ct, 1)), 3);
+
+        let rewritten = rewrite_shortint_server_key(&legacy).unwrap();
+        assert_eq!(rewritten, bincode::serialize(sks).unwrap());
+        let (_, layout) = migrate_shortint_server_key(&rewritten).unwrap();
+        assert_eq!(layout, Layout::Current);
+    }
+
+    #[test]
+    fn test_migrate_shortint_server_key_errors() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let sks = keys.server_key();
+        let other_keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+
+        let legacy = serialize_v0_2(sks, sks.bootstrapping_key.as_fourier().unwrap());
+        assert!(matches!(
+            migrate_shortint_server_key(&legacy[..legacy.len() - 1]),
+            Err(MigrationError::UnknownLayout { .. })
+        ));
+
+        // The bootstrapping key of other parameters does not fit the keyswitching key
+        let mismatched = serialize_v0_2(
+            sks,
+            other_keys
+                .server_key()
+                .bootstrapping_key
+                .as_fourier()
+                .unwrap(),
+        );
+        assert!(matches!(
+            migrate_shortint_server_key(&mismatched),
+            Err(MigrationError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "integer")]
+    #[test]
+    fn test_migrate_integer_server_key_v0_2() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let cks = crate::integer::ClientKey::from(keys.client_key().clone());
+        let sks = crate::integer::ServerKey::new(&cks);
+
+        // The integer key was a struct wrapping the shortint key
+        let legacy = serialize_v0_2(&sks.key, sks.key.bootstrapping_key.as_fourier().unwrap());
+
+        let (migrated, layout) = migrate_integer_server_key(&legacy).unwrap();
+        assert_eq!(layout, Layout::V0_2);
+        assert_eq!(migrated.key, sks.key);
+
+        let ct = cks.encrypt_radix(200u64, 4);
+        let res = migrated.scalar_add_parallelized(&ct, 50u64);
+        let dec: u64 = cks.decrypt_radix(&res);
+        assert_eq!(dec, 250);
+
+        let rewritten = rewrite_integer_server_key(&legacy).unwrap();
+        assert_eq!(rewritten, bincode::serialize(&sks).unwrap());
+    }
+}
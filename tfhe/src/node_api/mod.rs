@@ -0,0 +1,412 @@
+//! Native Node.js bindings for the integer API, built with [napi-rs](https://napi.rs/).
+//!
+//! Contrary to the [`js_on_wasm_api`](crate::js_on_wasm_api), which is restricted to the client
+//! side and to a single thread, these bindings expose the server key operations. Every operation
+//! has an `Async` variant returning a `Promise` which is computed on the libuv thread pool, the
+//! operations themselves use the `_parallelized` integer algorithms.
+//!
+//! The bindings are built with the `integer-node-api` feature:
+//!
+//! ```shell
+//! cd tfhe/node_api_tests && napi build --cargo-cwd .. --release --features=integer-node-api
+//! ```
+use crate::integer::IntegerCiphertext;
+use crate::shortint::sanitizer::Sanitizer;
+use napi::bindgen_prelude::*;
+use napi::Task;
+use napi_derive::napi;
+use std::sync::Arc;
+
+fn into_napi_err<E: std::fmt::Display>(error: E) -> napi::Error {
+    napi::Error::new(Status::InvalidArg, error.to_string())
+}
+
+fn bigint_to_u64(value: BigInt) -> napi::Result<u64> {
+    let (signed, value, lossless) = value.get_u64();
+    if signed || !lossless {
+        return Err(napi::Error::new(
+            Status::InvalidArg,
+            "Expected a BigInt in the range of a u64".to_string(),
+        ));
+    }
+    Ok(value)
+}
+
+/// Check that a ciphertext coming from JS can be used with the server key, the integer
+/// operations panic on mismatched parameters, which would abort the Node process.
+fn check_operand(
+    server_key: &crate::integer::ServerKey,
+    ct: &crate::integer::RadixCiphertextBig,
+) -> napi::Result<()> {
+    if ct.blocks().is_empty() {
+        return Err(napi::Error::new(
+            Status::InvalidArg,
+            "A radix ciphertext needs at least one block".to_string(),
+        ));
+    }
+
+    let sanitizer = Sanitizer::new(server_key.as_ref());
+    for (index, block) in ct.blocks().iter().enumerate() {
+        sanitizer.validate(block).map_err(|err| {
+            napi::Error::new(Status::InvalidArg, format!("Invalid block {index}: {err}"))
+        })?;
+    }
+    Ok(())
+}
+
+fn check_binary_operands(
+    server_key: &crate::integer::ServerKey,
+    lhs: &crate::integer::RadixCiphertextBig,
+    rhs: &crate::integer::RadixCiphertextBig,
+) -> napi::Result<()> {
+    check_operand(server_key, lhs)?;
+    check_operand(server_key, rhs)?;
+    if lhs.blocks().len() != rhs.blocks().len() {
+        return Err(napi::Error::new(
+            Status::InvalidArg,
+            format!(
+                "Operands have different block counts: {} and {}",
+                lhs.blocks().len(),
+                rhs.blocks().len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[napi]
+pub struct RadixCiphertext {
+    pub(crate) inner: crate::integer::RadixCiphertextBig,
+}
+
+#[napi]
+impl RadixCiphertext {
+    #[napi]
+    pub fn serialize(&self) -> napi::Result<Buffer> {
+        bincode::serialize(&self.inner)
+            .map(Buffer::from)
+            .map_err(into_napi_err)
+    }
+
+    #[napi(factory)]
+    pub fn deserialize(buffer: Buffer) -> napi::Result<Self> {
+        bincode::deserialize(&buffer)
+            .map(|inner| Self { inner })
+            .map_err(into_napi_err)
+    }
+}
+
+#[napi]
+pub struct RadixClientKey {
+    pub(crate) inner: crate::integer::RadixClientKey,
+}
+
+#[napi]
+impl RadixClientKey {
+    /// Generate a client key for radix integers made of `num_blocks` blocks, each block
+    /// having `message_bits` bits of message and `carry_bits` bits of carry.
+    ///
+    /// Throws if there are no parameters for this number of message and carry bits, or if
+    /// `num_blocks` is 0.
+    #[napi(factory)]
+    pub fn generate(message_bits: u32, carry_bits: u32, num_blocks: u32) -> napi::Result<Self> {
+        if num_blocks == 0 {
+            return Err(napi::Error::new(
+                Status::InvalidArg,
+                "A radix integer needs at least one block".to_string(),
+            ));
+        }
+
+        // The moduli of the parameters are powers of two, comparing their number of trailing
+        // zeros avoids overflowing a shift for large bit counts
+        let parameters = crate::shortint::parameters::ALL_PARAMETER_VEC
+            .into_iter()
+            .find(|parameters| {
+                parameters.message_modulus.0.trailing_zeros() == message_bits
+                    && parameters.carry_modulus.0.trailing_zeros() == carry_bits
+            })
+            .ok_or_else(|| {
+                napi::Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "No parameters for {message_bits} bits of message and {carry_bits} bits \
+                        of carry"
+                    ),
+                )
+            })?;
+
+        Ok(Self {
+            inner: crate::integer::RadixClientKey::new(parameters, num_blocks as usize),
+        })
+    }
+
+    #[napi]
+    pub fn encrypt(&self, value: BigInt) -> napi::Result<RadixCiphertext> {
+        let value = bigint_to_u64(value)?;
+        Ok(RadixCiphertext {
+            inner: self.inner.encrypt(value),
+        })
+    }
+
+    #[napi]
+    pub fn decrypt(&self, ciphertext: &RadixCiphertext) -> BigInt {
+        let value: u64 = self.inner.decrypt(&ciphertext.inner);
+        BigInt::from(value)
+    }
+
+    #[napi]
+    pub fn serialize(&self) -> napi::Result<Buffer> {
+        bincode::serialize(&self.inner)
+            .map(Buffer::from)
+            .map_err(into_napi_err)
+    }
+
+    #[napi(factory)]
+    pub fn deserialize(buffer: Buffer) -> napi::Result<Self> {
+        bincode::deserialize(&buffer)
+            .map(|inner| Self { inner })
+            .map_err(into_napi_err)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Min,
+    Max,
+}
+
+impl BinaryOp {
+    fn execute(
+        self,
+        server_key: &crate::integer::ServerKey,
+        lhs: &crate::integer::RadixCiphertextBig,
+        rhs: &crate::integer::RadixCiphertextBig,
+    ) -> napi::Result<crate::integer::RadixCiphertextBig> {
+        check_binary_operands(server_key, lhs, rhs)?;
+
+        let result = match self {
+            Self::Add => server_key.add_parallelized(lhs, rhs),
+            Self::Sub => server_key.sub_parallelized(lhs, rhs),
+            Self::Mul => server_key.mul_parallelized(lhs, rhs),
+            Self::BitAnd => server_key.bitand_parallelized(lhs, rhs),
+            Self::BitOr => server_key.bitor_parallelized(lhs, rhs),
+            Self::BitXor => server_key.bitxor_parallelized(lhs, rhs),
+            Self::Eq => server_key.eq_parallelized(lhs, rhs),
+            Self::Gt => server_key.gt_parallelized(lhs, rhs),
+            Self::Ge => server_key.ge_parallelized(lhs, rhs),
+            Self::Lt => server_key.lt_parallelized(lhs, rhs),
+            Self::Le => server_key.le_parallelized(lhs, rhs),
+            Self::Min => server_key.min_parallelized(lhs, rhs),
+            Self::Max => server_key.max_parallelized(lhs, rhs),
+        };
+        Ok(result)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum ScalarOp {
+    Add,
+    Sub,
+    Mul,
+    LeftShift,
+    RightShift,
+}
+
+impl ScalarOp {
+    fn execute(
+        self,
+        server_key: &crate::integer::ServerKey,
+        ct: &crate::integer::RadixCiphertextBig,
+        scalar: u64,
+    ) -> napi::Result<crate::integer::RadixCiphertextBig> {
+        check_operand(server_key, ct)?;
+
+        let result = match self {
+            Self::Add => server_key.scalar_add_parallelized(ct, scalar),
+            Self::Sub => server_key.scalar_sub_parallelized(ct, scalar),
+            Self::Mul => server_key.scalar_mul_parallelized(ct, scalar),
+            Self::LeftShift => server_key.scalar_left_shift_parallelized(ct, scalar as usize),
+            Self::RightShift => server_key.scalar_right_shift_parallelized(ct, scalar as usize),
+        };
+        Ok(result)
+    }
+}
+
+/// Binary operation executed on the libuv thread pool.
+pub struct BinaryOpTask {
+    server_key: Arc<crate::integer::ServerKey>,
+    lhs: crate::integer::RadixCiphertextBig,
+    rhs: crate::integer::RadixCiphertextBig,
+    op: BinaryOp,
+}
+
+impl Task for BinaryOpTask {
+    type Output = crate::integer::RadixCiphertextBig;
+    type JsValue = RadixCiphertext;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        self.op.execute(&self.server_key, &self.lhs, &self.rhs)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(RadixCiphertext { inner: output })
+    }
+}
+
+/// Scalar operation executed on the libuv thread pool.
+pub struct ScalarOpTask {
+    server_key: Arc<crate::integer::ServerKey>,
+    ct: crate::integer::RadixCiphertextBig,
+    scalar: u64,
+    op: ScalarOp,
+}
+
+impl Task for ScalarOpTask {
+    type Output = crate::integer::RadixCiphertextBig;
+    type JsValue = RadixCiphertext;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        self.op.execute(&self.server_key, &self.ct, self.scalar)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(RadixCiphertext { inner: output })
+    }
+}
+
+#[napi]
+pub struct IntegerServerKey {
+    // Shared with the tasks running on the thread pool
+    pub(crate) inner: Arc<crate::integer::ServerKey>,
+}
+
+macro_rules! impl_node_binary_ops {
+    ($($sync_name:ident, $async_name:ident => $op:ident),* $(,)?) => {
+        #[napi]
+        impl IntegerServerKey {
+            $(
+                #[napi]
+                pub fn $sync_name(
+                    &self,
+                    lhs: &RadixCiphertext,
+                    rhs: &RadixCiphertext,
+                ) -> napi::Result<RadixCiphertext> {
+                    Ok(RadixCiphertext {
+                        inner: BinaryOp::$op.execute(&self.inner, &lhs.inner, &rhs.inner)?,
+                    })
+                }
+
+                #[napi(ts_return_type = "Promise<RadixCiphertext>")]
+                pub fn $async_name(
+                    &self,
+                    lhs: &RadixCiphertext,
+                    rhs: &RadixCiphertext,
+                ) -> AsyncTask<BinaryOpTask> {
+                    AsyncTask::new(BinaryOpTask {
+                        server_key: Arc::clone(&self.inner),
+                        lhs: lhs.inner.clone(),
+                        rhs: rhs.inner.clone(),
+                        op: BinaryOp::$op,
+                    })
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! impl_node_scalar_ops {
+    ($($sync_name:ident, $async_name:ident => $op:ident),* $(,)?) => {
+        #[napi]
+        impl IntegerServerKey {
+            $(
+                #[napi]
+                pub fn $sync_name(
+                    &self,
+                    ct: &RadixCiphertext,
+                    scalar: BigInt,
+                ) -> napi::Result<RadixCiphertext> {
+                    let scalar = bigint_to_u64(scalar)?;
+                    Ok(RadixCiphertext {
+                        inner: ScalarOp::$op.execute(&self.inner, &ct.inner, scalar)?,
+                    })
+                }
+
+                #[napi(ts_return_type = "Promise<RadixCiphertext>")]
+                pub fn $async_name(
+                    &self,
+                    ct: &RadixCiphertext,
+                    scalar: BigInt,
+                ) -> napi::Result<AsyncTask<ScalarOpTask>> {
+                    let scalar = bigint_to_u64(scalar)?;
+                    Ok(AsyncTask::new(ScalarOpTask {
+                        server_key: Arc::clone(&self.inner),
+                        ct: ct.inner.clone(),
+                        scalar,
+                        op: ScalarOp::$op,
+                    }))
+                }
+            )*
+        }
+    };
+}
+
+#[napi]
+impl IntegerServerKey {
+    #[napi(factory)]
+    pub fn generate(client_key: &RadixClientKey) -> Self {
+        Self {
+            inner: Arc::new(crate::integer::ServerKey::new(&client_key.inner)),
+        }
+    }
+
+    #[napi]
+    pub fn serialize(&self) -> napi::Result<Buffer> {
+        bincode::serialize(self.inner.as_ref())
+            .map(Buffer::from)
+            .map_err(into_napi_err)
+    }
+
+    #[napi(factory)]
+    pub fn deserialize(buffer: Buffer) -> napi::Result<Self> {
+        bincode::deserialize(&buffer)
+            .map(|key| Self {
+                inner: Arc::new(key),
+            })
+            .map_err(into_napi_err)
+    }
+}
+
+impl_node_binary_ops!(
+    add, add_async => Add,
+    sub, sub_async => Sub,
+    mul, mul_async => Mul,
+    bitand, bitand_async => BitAnd,
+    bitor, bitor_async => BitOr,
+    bitxor, bitxor_async => BitXor,
+    eq, eq_async => Eq,
+    gt, gt_async => Gt,
+    ge, ge_async => Ge,
+    lt, lt_async => Lt,
+    le, le_async => Le,
+    min, min_async => Min,
+    max, max_async => Max,
+);
+
+impl_node_scalar_ops!(
+    scalar_add, scalar_add_async => Add,
+    scalar_sub, scalar_sub_async => Sub,
+    scalar_mul, scalar_mul_async => Mul,
+    scalar_left_shift, scalar_left_shift_async => LeftShift,
+    scalar_right_shift, scalar_right_shift_async => RightShift,
+);
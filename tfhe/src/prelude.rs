@@ -0,0 +1,34 @@
+//! The purpose of this module is to make it easier to have the most commonly needed
+//! traits of this crate.
+//!
+//! It is meant to be glob imported:
+//! ```
+//! use tfhe::prelude::*;
+//! ```
+//!
+//! The traits of a module are only re-exported when its feature is enabled, so the same import
+//! works under any combination of features:
+//! - the encryption, decryption and comparison traits of the high level API with any of the
+//!   `boolean`, `shortint` or `integer` features,
+//! - `BinaryBooleanGates` and `BinaryBooleanGatesAssign` with the `boolean` feature,
+//! - `PBSOrderMarker` with the `shortint` feature,
+//! - `IntegerCiphertext` with the `integer` feature,
+//! - [`FheSize`] in all cases.
+//!
+//! The types (keys, ciphertexts, parameters) are not re-exported, they are at the root of the
+//! crate for the high level API and in the preludes of the `boolean` and `shortint` modules.
+#[cfg(any(feature = "boolean", feature = "shortint", feature = "integer"))]
+pub use crate::high_level_api::traits::{
+    DynamicFheEncryptor, DynamicFheTrivialEncryptor, DynamicFheTryEncryptor, FheBootstrap,
+    FheDecrypt, FheEncrypt, FheEq, FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
+    FheTryTrivialEncrypt,
+};
+
+#[cfg(feature = "boolean")]
+pub use crate::boolean::server_key::{BinaryBooleanGates, BinaryBooleanGatesAssign};
+#[cfg(feature = "integer")]
+pub use crate::integer::IntegerCiphertext;
+#[cfg(feature = "shortint")]
+pub use crate::shortint::PBSOrderMarker;
+
+pub use crate::size::FheSize;
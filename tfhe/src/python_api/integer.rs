@@ -0,0 +1,205 @@
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use super::{into_py_err, ClientKey};
+use crate::prelude::*;
+
+macro_rules! create_python_fhe_uint_type {
+    ($name:ident) => {
+        #[pyclass]
+        #[derive(Clone)]
+        pub struct $name(pub(crate) crate::high_level_api::$name);
+
+        #[pymethods]
+        impl $name {
+            #[staticmethod]
+            pub fn encrypt(value: u64, client_key: &ClientKey) -> PyResult<Self> {
+                crate::high_level_api::$name::try_encrypt(value, &client_key.0)
+                    .map(Self)
+                    .map_err(into_py_err)
+            }
+
+            #[staticmethod]
+            pub fn encrypt_trivial(value: u64) -> PyResult<Self> {
+                crate::high_level_api::$name::try_encrypt_trivial(value)
+                    .map(Self)
+                    .map_err(into_py_err)
+            }
+
+            pub fn decrypt(&self, client_key: &ClientKey) -> u64 {
+                self.0.decrypt(&client_key.0)
+            }
+
+            /// Encrypt each value of a 1D numpy array.
+            #[staticmethod]
+            pub fn encrypt_batch(
+                values: PyReadonlyArray1<'_, u64>,
+                client_key: &ClientKey,
+            ) -> PyResult<Vec<Self>> {
+                values
+                    .as_array()
+                    .iter()
+                    .map(|value| Self::encrypt(*value, client_key))
+                    .collect()
+            }
+
+            /// Decrypt each ciphertext into a 1D numpy array.
+            #[staticmethod]
+            pub fn decrypt_batch<'py>(
+                py: Python<'py>,
+                ciphertexts: Vec<PyRef<'_, Self>>,
+                client_key: &ClientKey,
+            ) -> &'py PyArray1<u64> {
+                let clears = ciphertexts
+                    .iter()
+                    .map(|ct| ct.decrypt(client_key))
+                    .collect::<Vec<_>>();
+                PyArray1::from_vec(py, clears)
+            }
+
+            /// Element-wise addition of two lists of ciphertexts of the same length.
+            #[staticmethod]
+            pub fn add_batch(
+                lhs: Vec<PyRef<'_, Self>>,
+                rhs: Vec<PyRef<'_, Self>>,
+            ) -> PyResult<Vec<Self>> {
+                check_same_length(lhs.len(), rhs.len())?;
+                Ok(lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .map(|(l, r)| Self(&l.0 + &r.0))
+                    .collect())
+            }
+
+            /// Element-wise subtraction of two lists of ciphertexts of the same length.
+            #[staticmethod]
+            pub fn sub_batch(
+                lhs: Vec<PyRef<'_, Self>>,
+                rhs: Vec<PyRef<'_, Self>>,
+            ) -> PyResult<Vec<Self>> {
+                check_same_length(lhs.len(), rhs.len())?;
+                Ok(lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .map(|(l, r)| Self(&l.0 - &r.0))
+                    .collect())
+            }
+
+            /// Element-wise multiplication of two lists of ciphertexts of the same length.
+            #[staticmethod]
+            pub fn mul_batch(
+                lhs: Vec<PyRef<'_, Self>>,
+                rhs: Vec<PyRef<'_, Self>>,
+            ) -> PyResult<Vec<Self>> {
+                check_same_length(lhs.len(), rhs.len())?;
+                Ok(lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .map(|(l, r)| Self(&l.0 * &r.0))
+                    .collect())
+            }
+
+            /// Add the same clear scalar to every ciphertext.
+            #[staticmethod]
+            pub fn scalar_add_batch(ciphertexts: Vec<PyRef<'_, Self>>, scalar: u64) -> Vec<Self> {
+                ciphertexts.iter().map(|ct| Self(&ct.0 + scalar)).collect()
+            }
+
+            /// Multiply every ciphertext by the same clear scalar.
+            #[staticmethod]
+            pub fn scalar_mul_batch(ciphertexts: Vec<PyRef<'_, Self>>, scalar: u64) -> Vec<Self> {
+                ciphertexts.iter().map(|ct| Self(&ct.0 * scalar)).collect()
+            }
+
+            pub fn __add__(&self, other: &Self) -> Self {
+                Self(&self.0 + &other.0)
+            }
+
+            pub fn __sub__(&self, other: &Self) -> Self {
+                Self(&self.0 - &other.0)
+            }
+
+            pub fn __mul__(&self, other: &Self) -> Self {
+                Self(&self.0 * &other.0)
+            }
+
+            pub fn __and__(&self, other: &Self) -> Self {
+                Self(&self.0 & &other.0)
+            }
+
+            pub fn __or__(&self, other: &Self) -> Self {
+                Self(&self.0 | &other.0)
+            }
+
+            pub fn __xor__(&self, other: &Self) -> Self {
+                Self(&self.0 ^ &other.0)
+            }
+
+            pub fn __neg__(&self) -> Self {
+                Self(-&self.0)
+            }
+
+            pub fn __lshift__(&self, shift: u64) -> Self {
+                Self(&self.0 << shift)
+            }
+
+            pub fn __rshift__(&self, shift: u64) -> Self {
+                Self(&self.0 >> shift)
+            }
+
+            pub fn scalar_add(&self, scalar: u64) -> Self {
+                Self(&self.0 + scalar)
+            }
+
+            pub fn scalar_sub(&self, scalar: u64) -> Self {
+                Self(&self.0 - scalar)
+            }
+
+            pub fn scalar_mul(&self, scalar: u64) -> Self {
+                Self(&self.0 * scalar)
+            }
+
+            pub fn min(&self, other: &Self) -> Self {
+                Self(self.0.min(&other.0))
+            }
+
+            pub fn max(&self, other: &Self) -> Self {
+                Self(self.0.max(&other.0))
+            }
+
+            pub fn serialize<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+                let bytes = bincode::serialize(&self.0).map_err(into_py_err)?;
+                Ok(PyBytes::new(py, &bytes))
+            }
+
+            #[staticmethod]
+            pub fn deserialize(buffer: &[u8]) -> PyResult<Self> {
+                bincode::deserialize(buffer).map(Self).map_err(into_py_err)
+            }
+        }
+    };
+}
+
+fn check_same_length(lhs_len: usize, rhs_len: usize) -> PyResult<()> {
+    if lhs_len != rhs_len {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Batches must have the same length, got {lhs_len} and {rhs_len}"
+        )));
+    }
+    Ok(())
+}
+
+create_python_fhe_uint_type!(FheUint8);
+create_python_fhe_uint_type!(FheUint16);
+create_python_fhe_uint_type!(FheUint32);
+create_python_fhe_uint_type!(FheUint64);
+
+pub(super) fn register(m: &PyModule) -> PyResult<()> {
+    m.add_class::<FheUint8>()?;
+    m.add_class::<FheUint16>()?;
+    m.add_class::<FheUint32>()?;
+    m.add_class::<FheUint64>()?;
+
+    Ok(())
+}
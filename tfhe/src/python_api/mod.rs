@@ -0,0 +1,151 @@
+//! Python bindings for the high level API.
+//!
+//! The bindings are built with [maturin](https://www.maturin.rs/) using the `tfhe/pyproject.toml`
+//! manifest, which enables the `integer-python-api` feature:
+//!
+//! ```shell
+//! cd tfhe && maturin develop --release
+//! ```
+//!
+//! The python module mirrors the rust high level API:
+//!
+//! ```python
+//! import numpy as np
+//! from tfhe import ConfigBuilder, FheUint8, generate_keys, set_server_key
+//!
+//! config = ConfigBuilder.all_disabled().enable_default_uint8().build()
+//! client_key, server_key = generate_keys(config)
+//! set_server_key(server_key)
+//!
+//! a = FheUint8.encrypt(27, client_key)
+//! b = FheUint8.encrypt(128, client_key)
+//! assert (a + b).decrypt(client_key) == 155
+//!
+//! cts = FheUint8.encrypt_batch(np.arange(10, dtype=np.uint64), client_key)
+//! clears = FheUint8.decrypt_batch(FheUint8.add_batch(cts, cts), client_key)
+//! ```
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+mod integer;
+
+fn into_py_err<E: std::fmt::Display>(error: E) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Config(pub(crate) crate::high_level_api::Config);
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ConfigBuilder(pub(crate) crate::high_level_api::ConfigBuilder);
+
+#[pymethods]
+impl ConfigBuilder {
+    #[staticmethod]
+    pub fn all_disabled() -> Self {
+        Self(crate::high_level_api::ConfigBuilder::all_disabled())
+    }
+
+    #[staticmethod]
+    pub fn all_enabled() -> Self {
+        Self(crate::high_level_api::ConfigBuilder::all_enabled())
+    }
+
+    pub fn enable_default_uint8(&self) -> Self {
+        Self(self.0.clone().enable_default_uint8())
+    }
+
+    pub fn enable_default_uint16(&self) -> Self {
+        Self(self.0.clone().enable_default_uint16())
+    }
+
+    pub fn enable_default_uint32(&self) -> Self {
+        Self(self.0.clone().enable_default_uint32())
+    }
+
+    pub fn enable_default_uint64(&self) -> Self {
+        Self(self.0.clone().enable_default_uint64())
+    }
+
+    pub fn build(&self) -> Config {
+        Config(self.0.clone().build())
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ClientKey(pub(crate) crate::high_level_api::ClientKey);
+
+#[pymethods]
+impl ClientKey {
+    #[staticmethod]
+    pub fn generate(config: &Config) -> Self {
+        Self(crate::high_level_api::ClientKey::generate(config.0.clone()))
+    }
+
+    pub fn generate_server_key(&self) -> ServerKey {
+        ServerKey(self.0.generate_server_key())
+    }
+
+    pub fn serialize<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let bytes = bincode::serialize(&self.0).map_err(into_py_err)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    #[staticmethod]
+    pub fn deserialize(buffer: &[u8]) -> PyResult<Self> {
+        bincode::deserialize(buffer).map(Self).map_err(into_py_err)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ServerKey(pub(crate) crate::high_level_api::ServerKey);
+
+#[pymethods]
+impl ServerKey {
+    pub fn serialize<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let bytes = bincode::serialize(&self.0).map_err(into_py_err)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    #[staticmethod]
+    pub fn deserialize(buffer: &[u8]) -> PyResult<Self> {
+        bincode::deserialize(buffer).map(Self).map_err(into_py_err)
+    }
+}
+
+#[pyfunction]
+pub fn generate_keys(config: &Config) -> (ClientKey, ServerKey) {
+    let (client_key, server_key) = crate::high_level_api::generate_keys(config.0.clone());
+    (ClientKey(client_key), ServerKey(server_key))
+}
+
+/// Set the server key used by the operations of the calling python thread.
+#[pyfunction]
+pub fn set_server_key(server_key: &ServerKey) {
+    crate::high_level_api::set_server_key(server_key.0.clone());
+}
+
+#[pyfunction]
+pub fn unset_server_key() {
+    let _ = crate::high_level_api::unset_server_key();
+}
+
+#[pymodule]
+fn tfhe(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Config>()?;
+    m.add_class::<ConfigBuilder>()?;
+    m.add_class::<ClientKey>()?;
+    m.add_class::<ServerKey>()?;
+    m.add_function(wrap_pyfunction!(generate_keys, m)?)?;
+    m.add_function(wrap_pyfunction!(set_server_key, m)?)?;
+    m.add_function(wrap_pyfunction!(unset_server_key, m)?)?;
+
+    integer::register(m)?;
+
+    Ok(())
+}
@@ -0,0 +1,457 @@
+//! Columnar storage of shortint ciphertexts.
+//!
+//! A [`CiphertextColumn`] lays many ciphertexts sharing the same parameters one after the other
+//! in a single contiguous buffer of `u64`, each value taking exactly
+//! [`value_byte_width`](CiphertextColumn::value_byte_width) bytes, in the same way an Apache
+//! Arrow `FixedSizeBinary` column does. The degrees, which are metadata only known to the server,
+//! are kept in a separate small vector.
+//!
+//! The column is generic over its container: a column can own its data, or be a view over a
+//! buffer managed by someone else (e.g. an analytics engine) without copying it, see
+//! [`CiphertextColumnView::try_from_bytes`]. Individual values are accessed through
+//! [`CiphertextRef`], a borrowed counterpart of [`CiphertextBase`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::ciphertext::column::{CiphertextColumnOwned, CiphertextColumnView};
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let cts = (0..4).map(|msg| cks.encrypt(msg)).collect::<Vec<_>>();
+//! let column = CiphertextColumnOwned::from_ciphertexts(&cts);
+//! assert_eq!(column.len(), 4);
+//!
+//! // The raw bytes can be handed to a columnar engine, and viewed back without copies
+//! let bytes = column.as_bytes();
+//! assert_eq!(bytes.len(), column.len() * column.value_byte_width());
+//! let view = CiphertextColumnView::try_from_bytes(
+//!     bytes,
+//!     column.degrees().to_vec(),
+//!     column.lwe_size(),
+//!     column.message_modulus(),
+//!     column.carry_modulus(),
+//!     column.ciphertext_modulus(),
+//! )
+//! .unwrap();
+//!
+//! let ct = view.get(1).unwrap().to_ciphertext();
+//! let ct_res = sks.unchecked_add(&ct, &cts[2]);
+//! assert_eq!(cks.decrypt(&ct_res), 3);
+//! ```
+use super::{CiphertextBase, Degree, PBSOrderMarker};
+use crate::core_crypto::commons::parameters::LweSize;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::size::FheSize;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// Errors returned when creating a [`CiphertextColumnView`] from raw bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnError {
+    /// The buffer is not aligned for `u64`.
+    Misaligned,
+    /// The buffer length is not a multiple of the size of one value.
+    InvalidLength {
+        byte_len: usize,
+        value_byte_width: usize,
+    },
+    /// The number of degrees does not match the number of values in the buffer.
+    DegreeCountMismatch { values: usize, degrees: usize },
+}
+
+impl Display for ColumnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Misaligned => write!(f, "The column buffer is not aligned for u64"),
+            Self::InvalidLength {
+                byte_len,
+                value_byte_width,
+            } => write!(
+                f,
+                "The column buffer length {byte_len} is not a multiple of the value size \
+                {value_byte_width}"
+            ),
+            Self::DegreeCountMismatch { values, degrees } => write!(
+                f,
+                "The column buffer holds {values} values but {degrees} degrees were given"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+/// A borrowed shortint ciphertext, pointing into a [`CiphertextColumn`].
+#[derive(Clone)]
+pub struct CiphertextRef<'a, OpOrder: PBSOrderMarker> {
+    pub ct: LweCiphertextView<'a, u64>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub _order_marker: PhantomData<OpOrder>,
+}
+
+impl<'a, OpOrder: PBSOrderMarker> CiphertextRef<'a, OpOrder> {
+    /// Copy the referenced ciphertext into a [`CiphertextBase`] usable with the server key.
+    pub fn to_ciphertext(&self) -> CiphertextBase<OpOrder> {
+        CiphertextBase {
+            ct: LweCiphertextOwned::from_container(
+                self.ct.as_ref().to_vec(),
+                self.ct.ciphertext_modulus(),
+            ),
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            _order_marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, OpOrder: PBSOrderMarker> From<CiphertextRef<'a, OpOrder>> for CiphertextBase<OpOrder> {
+    fn from(value: CiphertextRef<'a, OpOrder>) -> Self {
+        value.to_ciphertext()
+    }
+}
+
+/// Shortint ciphertexts sharing the same parameters, stored contiguously.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone)]
+pub struct CiphertextColumn<C: Container<Element = u64>, OpOrder: PBSOrderMarker> {
+    data: C,
+    degrees: Vec<Degree>,
+    lwe_size: LweSize,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    ciphertext_modulus: CiphertextModulus,
+    _order_marker: PhantomData<OpOrder>,
+}
+
+pub type CiphertextColumnOwned<OpOrder> = CiphertextColumn<Vec<u64>, OpOrder>;
+pub type CiphertextColumnView<'a, OpOrder> = CiphertextColumn<&'a [u64], OpOrder>;
+
+impl<C: Container<Element = u64>, OpOrder: PBSOrderMarker> CiphertextColumn<C, OpOrder> {
+    /// Create a column on top of an existing container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container length is not a multiple of `lwe_size`, or if the number of
+    /// degrees does not match the number of values in the container.
+    pub fn from_container(
+        container: C,
+        degrees: Vec<Degree>,
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        assert_eq!(
+            container.container_len() % lwe_size.0,
+            0,
+            "The container length {} is not a multiple of the lwe_size {lwe_size:?}",
+            container.container_len()
+        );
+        assert_eq!(
+            container.container_len() / lwe_size.0,
+            degrees.len(),
+            "The number of degrees does not match the number of values in the container"
+        );
+        Self {
+            data: container,
+            degrees,
+            lwe_size,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus,
+            _order_marker: PhantomData,
+        }
+    }
+
+    /// Return the number of ciphertexts in the column.
+    pub fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.degrees.is_empty()
+    }
+
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Return the number of bytes taken by each ciphertext in the buffer.
+    pub fn value_byte_width(&self) -> usize {
+        self.lwe_size.0 * std::mem::size_of::<u64>()
+    }
+
+    pub fn degrees(&self) -> &[Degree] {
+        &self.degrees
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    /// Return the raw buffer of the column, ciphertexts are stored one after the other.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.data.as_ref())
+    }
+
+    /// Return a reference to the ciphertext at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<CiphertextRef<'_, OpOrder>> {
+        let degree = *self.degrees.get(index)?;
+        let start = index * self.lwe_size.0;
+        let ct = LweCiphertextView::from_container(
+            &self.data.as_ref()[start..start + self.lwe_size.0],
+            self.ciphertext_modulus,
+        );
+        Some(CiphertextRef {
+            ct,
+            degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            _order_marker: PhantomData,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = CiphertextRef<'_, OpOrder>> + '_ {
+        (0..self.len()).map(|index| self.get(index).unwrap())
+    }
+
+    /// Copy all the ciphertexts of the column into [`CiphertextBase`].
+    pub fn to_ciphertexts(&self) -> Vec<CiphertextBase<OpOrder>> {
+        self.iter().map(|ct| ct.to_ciphertext()).collect()
+    }
+
+    pub fn as_view(&self) -> CiphertextColumnView<'_, OpOrder> {
+        CiphertextColumn {
+            data: self.data.as_ref(),
+            degrees: self.degrees.clone(),
+            lwe_size: self.lwe_size,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+            _order_marker: PhantomData,
+        }
+    }
+
+    /// Consume the column and return its underlying container and degrees.
+    pub fn into_container(self) -> (C, Vec<Degree>) {
+        (self.data, self.degrees)
+    }
+
+    fn check_compatible(&self, ct: &CiphertextBase<OpOrder>) {
+        assert_eq!(
+            ct.ct.lwe_size(),
+            self.lwe_size,
+            "The ciphertext lwe_size does not match the column lwe_size"
+        );
+        assert_eq!(
+            (ct.message_modulus, ct.carry_modulus),
+            (self.message_modulus, self.carry_modulus),
+            "The ciphertext moduli do not match the column moduli"
+        );
+    }
+}
+
+impl<C: ContainerMut<Element = u64>, OpOrder: PBSOrderMarker> CiphertextColumn<C, OpOrder> {
+    /// Overwrite the ciphertext at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if the ciphertext does not have the parameters of
+    /// the column.
+    pub fn set(&mut self, index: usize, ct: &CiphertextBase<OpOrder>) {
+        self.check_compatible(ct);
+        let start = index * self.lwe_size.0;
+        self.data.as_mut()[start..start + self.lwe_size.0].copy_from_slice(ct.ct.as_ref());
+        self.degrees[index] = ct.degree;
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> CiphertextColumnOwned<OpOrder> {
+    /// Create an empty column.
+    pub fn new(
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        Self::from_container(
+            vec![],
+            vec![],
+            lwe_size,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus,
+        )
+    }
+
+    /// Copy the given ciphertexts into a new column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty or if the ciphertexts do not all have the same parameters.
+    pub fn from_ciphertexts(cts: &[CiphertextBase<OpOrder>]) -> Self {
+        let first = cts
+            .first()
+            .expect("Cannot create a column from an empty slice of ciphertexts");
+        let mut column = Self::new(
+            first.ct.lwe_size(),
+            first.message_modulus,
+            first.carry_modulus,
+            first.ct.ciphertext_modulus(),
+        );
+        column.data.reserve(cts.len() * column.lwe_size.0);
+        column.degrees.reserve(cts.len());
+        for ct in cts {
+            column.push(ct);
+        }
+        column
+    }
+
+    /// Append a copy of `ct` at the end of the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not have the parameters of the column.
+    pub fn push(&mut self, ct: &CiphertextBase<OpOrder>) {
+        self.check_compatible(ct);
+        self.data.extend_from_slice(ct.ct.as_ref());
+        self.degrees.push(ct.degree);
+    }
+}
+
+impl<'a, OpOrder: PBSOrderMarker> CiphertextColumnView<'a, OpOrder> {
+    /// View a raw buffer, e.g. the values buffer of an Arrow `FixedSizeBinary` array, as a column
+    /// without copying it.
+    ///
+    /// The buffer must be aligned for `u64`, which Arrow buffers always are.
+    pub fn try_from_bytes(
+        bytes: &'a [u8],
+        degrees: Vec<Degree>,
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Result<Self, ColumnError> {
+        let value_byte_width = lwe_size.0 * std::mem::size_of::<u64>();
+        if bytes.len() % value_byte_width != 0 {
+            return Err(ColumnError::InvalidLength {
+                byte_len: bytes.len(),
+                value_byte_width,
+            });
+        }
+        let values = bytes.len() / value_byte_width;
+        if values != degrees.len() {
+            return Err(ColumnError::DegreeCountMismatch {
+                values,
+                degrees: degrees.len(),
+            });
+        }
+        let data: &[u64] = bytemuck::try_cast_slice(bytes).map_err(|_| ColumnError::Misaligned)?;
+
+        Ok(Self::from_container(
+            data,
+            degrees,
+            lwe_size,
+            message_modulus,
+            carry_modulus,
+            ciphertext_modulus,
+        ))
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> FheSize for CiphertextColumnOwned<OpOrder> {
+    fn element_count(&self) -> usize {
+        self.data.len()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.data.len() * std::mem::size_of::<u64>()
+            + self.degrees.len() * std::mem::size_of::<Degree>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    use crate::shortint::CiphertextSmall;
+
+    #[test]
+    fn test_column_round_trip() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        let cts = (0..4).map(|msg| cks.encrypt(msg)).collect::<Vec<_>>();
+        let mut column = CiphertextColumnOwned::from_ciphertexts(&cts);
+
+        let ct_res = sks.unchecked_add(&cts[1], &cts[2]);
+        column.set(0, &ct_res);
+        assert_eq!(column.degrees()[0], ct_res.degree);
+
+        let decrypted = column
+            .iter()
+            .map(|ct| cks.decrypt(&ct.to_ciphertext()))
+            .collect::<Vec<_>>();
+        assert_eq!(decrypted, vec![3, 1, 2, 3]);
+        assert!(column.get(4).is_none());
+    }
+
+    #[test]
+    fn test_column_small_from_bytes() {
+        let (cks, _) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+
+        let cts = (0..4)
+            .map(|msg| cks.encrypt_small(msg))
+            .collect::<Vec<CiphertextSmall>>();
+        let column = CiphertextColumnOwned::from_ciphertexts(&cts);
+        let bytes = column.as_bytes();
+
+        let view = |bytes, degrees| {
+            CiphertextColumnView::<crate::shortint::ciphertext::BootstrapKeyswitch>::try_from_bytes(
+                bytes,
+                degrees,
+                column.lwe_size(),
+                column.message_modulus(),
+                column.carry_modulus(),
+                column.ciphertext_modulus(),
+            )
+        };
+
+        let column_view = view(bytes, column.degrees().to_vec()).unwrap();
+        for (ct, msg) in column_view.iter().zip(0..) {
+            assert_eq!(cks.decrypt(&ct.to_ciphertext()), msg);
+        }
+
+        assert_eq!(
+            view(&bytes[..bytes.len() - 8], column.degrees().to_vec()).err(),
+            Some(ColumnError::InvalidLength {
+                byte_len: bytes.len() - 8,
+                value_byte_width: column.value_byte_width(),
+            })
+        );
+        assert_eq!(
+            view(bytes, column.degrees()[1..].to_vec()).err(),
+            Some(ColumnError::DegreeCountMismatch {
+                values: 4,
+                degrees: 3,
+            })
+        );
+    }
+}
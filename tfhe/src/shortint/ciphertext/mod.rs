@@ -1,5 +1,17 @@
 //! Module with the definition of the Ciphertext.
+pub mod column;
+pub mod vector;
+
+use crate::core_crypto::algorithms::{
+    decompress_seeded_lwe_ciphertext_list, expand_lwe_compact_ciphertext_list,
+};
+use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
+use crate::core_crypto::commons::traits::deserialize_in_place::{
+    deserialize_field_by_value, deserialize_struct_in_place, StructInPlace,
+};
+use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use crate::shortint::debug::{diff_field, DataHash, Mismatch, StructuralDiff};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use serde::{Deserialize, Serialize};
 use std::cmp;
@@ -118,7 +130,6 @@ impl Degree {
     }
 }
 
-#[derive(Clone)]
 #[must_use]
 pub struct CiphertextBase<OpOrder: PBSOrderMarker> {
     pub ct: LweCiphertextOwned<u64>,
@@ -128,6 +139,36 @@ pub struct CiphertextBase<OpOrder: PBSOrderMarker> {
     pub _order_marker: PhantomData<OpOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[OpOrder: PBSOrderMarker] for CiphertextBase<OpOrder> { ct });
+
+impl<OpOrder: PBSOrderMarker> Clone for CiphertextBase<OpOrder> {
+    fn clone(&self) -> Self {
+        Self {
+            ct: self.ct.clone(),
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            _order_marker: PhantomData,
+        }
+    }
+
+    /// Copy `source` in `self`, reusing the allocation of `self` if both ciphertexts have the
+    /// same size. This is what the `*_into` operations of the server key use to write their
+    /// output.
+    fn clone_from(&mut self, source: &Self) {
+        if self.ct.lwe_size() == source.ct.lwe_size()
+            && self.ct.ciphertext_modulus() == source.ct.ciphertext_modulus()
+        {
+            self.ct.as_mut().copy_from_slice(source.ct.as_ref());
+        } else {
+            self.ct = source.ct.clone();
+        }
+        self.degree = source.degree;
+        self.message_modulus = source.message_modulus;
+        self.carry_modulus = source.carry_modulus;
+    }
+}
+
 pub type CiphertextBig = CiphertextBase<KeyswitchBootstrap>;
 pub type CiphertextSmall = CiphertextBase<BootstrapKeyswitch>;
 
@@ -135,6 +176,200 @@ impl<OpOrder: PBSOrderMarker> CiphertextBase<OpOrder> {
     pub fn carry_is_empty(&self) -> bool {
         self.degree.0 < self.message_modulus.0
     }
+
+    /// Raise the degree of the ciphertext to `degree`.
+    ///
+    /// A degree is an upper bound of the encrypted value, so raising it is always correct: the
+    /// following operations only become more conservative. This is useful to give blocks coming
+    /// from different pipelines the same degree, e.g. before packing them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is smaller than the current degree, use
+    /// [`assume_degree`](Self::assume_degree) to lower it, or if it does not fit in the message
+    /// and carry spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(1);
+    /// ct.set_degree(Degree(6));
+    /// assert_eq!(ct.degree, Degree(6));
+    /// assert_eq!(cks.decrypt(&ct), 1);
+    /// ```
+    pub fn set_degree(&mut self, degree: Degree) {
+        assert!(
+            degree.0 >= self.degree.0,
+            "The degree can only be raised, current degree: {}, new degree: {}, \
+             use assume_degree to lower it",
+            self.degree.0,
+            degree.0
+        );
+        assert!(
+            degree.0 < self.message_modulus.0 * self.carry_modulus.0,
+            "The degree {} does not fit in the message and carry spaces ({})",
+            degree.0,
+            self.message_modulus.0 * self.carry_modulus.0
+        );
+        self.degree = degree;
+    }
+
+    /// Set the degree of the ciphertext to `degree`, which may be lower than the current one.
+    ///
+    /// The degree is not checked against the encrypted value: the caller guarantees that the
+    /// value (message and carry) is at most `degree`, e.g. because it knows the lookup table or
+    /// the clear inputs of an unchecked pipeline. An underestimated degree makes the checked
+    /// operations accept inputs which overflow into the padding bit, and their results are then
+    /// wrong. In tests, [`ClientKey::audit_degree`] checks the assumption.
+    ///
+    /// [`ClientKey::audit_degree`]: crate::shortint::ClientKey::audit_degree
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // The sum of two booleans is at most 2, but the degree of the result is 6
+    /// let ct_1 = cks.encrypt(1);
+    /// let ct_2 = cks.encrypt(1);
+    /// let mut ct_res = sks.unchecked_add(&ct_1, &ct_2);
+    /// assert_eq!(ct_res.degree, Degree(6));
+    ///
+    /// ct_res.assume_degree(Degree(2));
+    /// assert_eq!(cks.audit_degree(&ct_res), Ok(()));
+    /// ```
+    pub fn assume_degree(&mut self, degree: Degree) {
+        self.degree = degree;
+    }
+
+    /// Return whether the ciphertext is a trivial encryption, i.e. its mask is zero and its value
+    /// is public.
+    ///
+    /// Trivial ciphertexts are created with [`ServerKey::create_trivial`], and stay trivial
+    /// through the operations only involving trivial ciphertexts. The server key evaluates lookup
+    /// tables on them in the clear instead of computing a PBS.
+    ///
+    /// [`ServerKey::create_trivial`]: crate::shortint::ServerKey::create_trivial
+    pub fn is_trivial(&self) -> bool {
+        self.ct.get_mask().as_ref().iter().all(|&x| x == 0)
+    }
+
+    /// Decode the value (message and carry) of a trivial ciphertext, or return `None` if the
+    /// ciphertext is not trivial.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = sks.create_trivial(3);
+    /// let ct_res = sks.unchecked_add(&ct, &ct);
+    /// assert_eq!(ct_res.decrypt_trivial(), Some(6));
+    ///
+    /// assert_eq!(cks.encrypt(3).decrypt_trivial(), None);
+    /// ```
+    pub fn decrypt_trivial(&self) -> Option<u64> {
+        let full_modulus = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        self.trivial_encoded_value()
+            .map(|value| value % full_modulus)
+    }
+
+    /// Decode the value of a trivial ciphertext including its padding bit, i.e. in
+    /// `[0, 2 * message_modulus * carry_modulus)`.
+    pub(crate) fn trivial_encoded_value(&self) -> Option<u64> {
+        if !self.is_trivial() {
+            return None;
+        }
+
+        let full_modulus = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let delta = (1_u64 << 63) / full_modulus;
+        let body = *self.ct.get_body().data;
+
+        // Round to the closest encoding
+        Some(body.wrapping_add(delta / 2) / delta % (2 * full_modulus))
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> Debug for CiphertextBase<OpOrder> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CiphertextBase")
+            .field("pbs_order", &OpOrder::pbs_order())
+            .field("lwe_dimension", &self.ct.lwe_size().to_lwe_dimension())
+            .field("ciphertext_modulus", &self.ct.ciphertext_modulus())
+            .field("message_modulus", &self.message_modulus)
+            .field("carry_modulus", &self.carry_modulus)
+            .field("degree", &self.degree)
+            .field("data", &DataHash::of(self.ct.as_ref()))
+            .finish()
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> std::fmt::Display for CiphertextBase<OpOrder> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} ciphertext of dimension {}, message modulus {}, carry modulus {}, degree {}",
+            OpOrder::pbs_order(),
+            self.ct.lwe_size().to_lwe_dimension().0,
+            self.message_modulus.0,
+            self.carry_modulus.0,
+            self.degree.0,
+        )
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> StructuralDiff for CiphertextBase<OpOrder> {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        diff_field(
+            path,
+            "lwe_dimension",
+            &self.ct.lwe_size().to_lwe_dimension(),
+            &other.ct.lwe_size().to_lwe_dimension(),
+            mismatches,
+        );
+        diff_field(
+            path,
+            "ciphertext_modulus",
+            &self.ct.ciphertext_modulus(),
+            &other.ct.ciphertext_modulus(),
+            mismatches,
+        );
+        diff_field(
+            path,
+            "message_modulus",
+            &self.message_modulus,
+            &other.message_modulus,
+            mismatches,
+        );
+        diff_field(
+            path,
+            "carry_modulus",
+            &self.carry_modulus,
+            &other.carry_modulus,
+            mismatches,
+        );
+        diff_field(path, "degree", &self.degree, &other.degree, mismatches);
+        diff_field(
+            path,
+            "data",
+            &DataHash::of(self.ct.as_ref()),
+            &DataHash::of(other.ct.as_ref()),
+            mismatches,
+        );
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -163,6 +398,22 @@ impl<OpOrder: PBSOrderMarker> Serialize for CiphertextBase<OpOrder> {
     }
 }
 
+fn check_ciphertext_op_order<OpOrder: PBSOrderMarker, E: serde::de::Error>(
+    op_order: PBSOrder,
+) -> Result<(), E> {
+    if op_order != OpOrder::pbs_order() {
+        return Err(E::custom(format!(
+            "Expected PBSOrder: {:?}, got {:?}, \
+            did you mix CiphertextBig ({:?}) and CiphertextSmall ({:?})?",
+            OpOrder::pbs_order(),
+            op_order,
+            PBSOrder::KeyswitchBootstrap,
+            PBSOrder::BootstrapKeyswitch
+        )));
+    }
+    Ok(())
+}
+
 // Manual impl to be able to check the OpOrder information
 impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -170,16 +421,7 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder>
         D: serde::Deserializer<'de>,
     {
         let intermediate = SerialiazableCiphertextBase::deserialize(deserializer)?;
-        if intermediate.op_order != OpOrder::pbs_order() {
-            return Err(serde::de::Error::custom(format!(
-                "Expected PBSOrder: {:?}, got {:?}, \
-                did you mix CiphertextBig ({:?}) and CiphertextSmall ({:?})?",
-                OpOrder::pbs_order(),
-                intermediate.op_order,
-                PBSOrder::KeyswitchBootstrap,
-                PBSOrder::BootstrapKeyswitch
-            )));
-        }
+        check_ciphertext_op_order::<OpOrder, D::Error>(intermediate.op_order)?;
 
         Ok(CiphertextBase {
             ct: intermediate.ct,
@@ -191,6 +433,65 @@ impl<'de, OpOrder: PBSOrderMarker> Deserialize<'de> for CiphertextBase<OpOrder>
     }
 }
 
+// Deserializes the same format as the Deserialize impl, reusing the ciphertext allocation
+impl<OpOrder: PBSOrderMarker> StructInPlace for CiphertextBase<OpOrder> {
+    const NAME: &'static str = "SerialiazableCiphertextBase";
+    const FIELDS: &'static [&'static str] = &[
+        "ct",
+        "degree",
+        "message_modulus",
+        "carry_modulus",
+        "op_order",
+    ];
+
+    fn deserialize_field<'de, D>(&mut self, index: usize, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match index {
+            0 => self.ct.deserialize_in_place(deserializer),
+            1 => deserialize_field_by_value(&mut self.degree, deserializer),
+            2 => deserialize_field_by_value(&mut self.message_modulus, deserializer),
+            3 => deserialize_field_by_value(&mut self.carry_modulus, deserializer),
+            _ => {
+                check_ciphertext_op_order::<OpOrder, D::Error>(PBSOrder::deserialize(deserializer)?)
+            }
+        }
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> DeserializeInPlace for CiphertextBase<OpOrder> {
+    /// Deserialize a ciphertext into `self`, reusing its allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bincode::Options;
+    /// use tfhe::core_crypto::commons::traits::DeserializeInPlace;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, _) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(0);
+    /// for msg in 0..4 {
+    ///     let bytes = bincode::serialize(&cks.encrypt(msg)).unwrap();
+    ///     ct.deserialize_in_place(&mut bincode::Deserializer::from_slice(
+    ///         &bytes,
+    ///         bincode::DefaultOptions::new().with_fixint_encoding(),
+    ///     ))
+    ///     .unwrap();
+    ///     assert_eq!(cks.decrypt(&ct), msg);
+    /// }
+    /// ```
+    fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_struct_in_place(self, deserializer)
+    }
+}
+
 /// A structure representing a compressed shortint ciphertext.
 /// It is used to homomorphically evaluate a shortint circuits.
 /// Internally, it uses a LWE ciphertext.
@@ -203,6 +504,8 @@ pub struct CompressedCiphertextBase<OpOrder: PBSOrderMarker> {
     pub _order_marker: PhantomData<OpOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[OpOrder: PBSOrderMarker] for CompressedCiphertextBase<OpOrder> { ct });
+
 pub type CompressedCiphertextBig = CompressedCiphertextBase<KeyswitchBootstrap>;
 pub type CompressedCiphertextSmall = CompressedCiphertextBase<BootstrapKeyswitch>;
 
@@ -285,3 +588,150 @@ impl<OpOrder: PBSOrderMarker> From<CompressedCiphertextBase<OpOrder>> for Cipher
         value.decompress()
     }
 }
+
+/// A structure representing a list of shortint ciphertexts encrypted with a
+/// [`CompactPublicKey`](crate::shortint::CompactPublicKey).
+///
+/// The ciphertexts of the list share their masks, making the list much smaller than the same
+/// number of [`CiphertextBig`] to send to a server, which expands it back into individual
+/// ciphertexts with [`CompactCiphertextList::expand`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactCiphertextList {
+    pub ct_list: LweCompactCiphertextListOwned<u64>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for CompactCiphertextList { ct_list });
+
+impl CompactCiphertextList {
+    /// Expand the list into the [`CiphertextBig`] it contains, in the order in which the messages
+    /// were encrypted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{gen_keys, CompactPublicKey};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = [0, 1, 2, 3];
+    /// let compact_list = pk.encrypt_slice(&msgs);
+    /// assert_eq!(compact_list.len(), msgs.len());
+    ///
+    /// let cts = compact_list.expand();
+    /// let ct_res = sks.unchecked_add(&cts[1], &cts[2]);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn expand(&self) -> Vec<CiphertextBig> {
+        let mut lwe_list = LweCiphertextListOwned::new(
+            0u64,
+            self.ct_list.lwe_size(),
+            self.ct_list.lwe_ciphertext_count(),
+            self.ct_list.ciphertext_modulus(),
+        );
+
+        expand_lwe_compact_ciphertext_list(&mut lwe_list, &self.ct_list);
+
+        lwe_list
+            .iter()
+            .map(|lwe_view| CiphertextBig {
+                ct: LweCiphertextOwned::from_container(
+                    lwe_view.as_ref().to_vec(),
+                    lwe_view.ciphertext_modulus(),
+                ),
+                degree: self.degree,
+                message_modulus: self.message_modulus,
+                carry_modulus: self.carry_modulus,
+                _order_marker: Default::default(),
+            })
+            .collect()
+    }
+
+    /// Return the number of ciphertexts in the list.
+    pub fn len(&self) -> usize {
+        self.ct_list.lwe_ciphertext_count().0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A structure representing a batch of compressed shortint ciphertexts encrypted with a
+/// [`ClientKey`](crate::shortint::ClientKey).
+///
+/// Contrary to a list of [`CompressedCiphertextBig`], which each store their own seed, all the
+/// ciphertexts of the batch have their masks generated from a single compression seed, so the
+/// batch only stores their bodies and one seed. The server expands the batch back into individual
+/// ciphertexts with [`CompressedCiphertextBatch::expand`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedCiphertextBatch {
+    pub ct_list: SeededLweCiphertextListOwned<u64>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for CompressedCiphertextBatch { ct_list });
+
+impl CompressedCiphertextBatch {
+    /// Expand the batch into the [`CiphertextBig`] it contains, in the order in which the
+    /// messages were encrypted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs = [0, 1, 2, 3];
+    /// let batch = cks.encrypt_compressed_batch(&msgs);
+    /// assert_eq!(batch.len(), msgs.len());
+    ///
+    /// let cts = batch.expand();
+    /// let ct_res = sks.unchecked_add(&cts[1], &cts[2]);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn expand(&self) -> Vec<CiphertextBig> {
+        let mut lwe_list = LweCiphertextListOwned::new(
+            0u64,
+            self.ct_list.lwe_size(),
+            self.ct_list.lwe_ciphertext_count(),
+            self.ct_list.ciphertext_modulus(),
+        );
+
+        decompress_seeded_lwe_ciphertext_list::<_, _, _, ActivatedRandomGenerator>(
+            &mut lwe_list,
+            &self.ct_list,
+        );
+
+        lwe_list
+            .iter()
+            .map(|lwe_view| CiphertextBig {
+                ct: LweCiphertextOwned::from_container(
+                    lwe_view.as_ref().to_vec(),
+                    lwe_view.ciphertext_modulus(),
+                ),
+                degree: self.degree,
+                message_modulus: self.message_modulus,
+                carry_modulus: self.carry_modulus,
+                _order_marker: Default::default(),
+            })
+            .collect()
+    }
+
+    /// Return the number of ciphertexts in the batch.
+    pub fn len(&self) -> usize {
+        self.ct_list.lwe_ciphertext_count().0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,197 @@
+//! Batches of shortint ciphertexts sharing their metadata.
+//!
+//! A [`ShortintVector`] stores many ciphertexts in a single [`LweCiphertextList`] with one
+//! degree and one set of moduli for the whole batch, instead of one per ciphertext. The server
+//! key operations on vectors (e.g.
+//! [`ServerKey::vector_add`](crate::shortint::ServerKey::vector_add) or
+//! [`ServerKey::vector_apply_lookup_table`](crate::shortint::ServerKey::vector_apply_lookup_table))
+//! process the whole batch in one call, in parallel and with batched bootstraps, which avoids the
+//! per ciphertext dispatch overhead dominating when working on large batches of tiny values.
+//!
+//! The degree of a vector is an upper bound of the degrees of all its ciphertexts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::ciphertext::vector::ShortintVector;
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let lhs = ShortintVector::from_ciphertexts(&[0, 1, 2, 3].map(|msg| cks.encrypt(msg)));
+//! let rhs = ShortintVector::from_ciphertexts(&[3, 3, 1, 2].map(|msg| cks.encrypt(msg)));
+//!
+//! let res = sks.vector_add(&lhs, &rhs);
+//!
+//! let dec = res.to_ciphertexts().iter().map(|ct| cks.decrypt(ct)).collect::<Vec<_>>();
+//! assert_eq!(dec, [3, 0, 3, 1]);
+//! ```
+use super::{CiphertextBase, Degree, PBSOrderMarker};
+use crate::core_crypto::commons::parameters::{LweCiphertextCount, LweSize};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::size::FheSize;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A batch of shortint ciphertexts sharing the same degree and parameters.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShortintVector<OpOrder: PBSOrderMarker> {
+    pub(crate) cts: LweCiphertextListOwned<u64>,
+    pub(crate) degree: Degree,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) _order_marker: PhantomData<OpOrder>,
+}
+
+impl<OpOrder: PBSOrderMarker> ShortintVector<OpOrder> {
+    /// Create a vector of `count` trivial encryptions of zero.
+    pub fn new(
+        count: usize,
+        lwe_size: LweSize,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        Self {
+            cts: LweCiphertextList::new(
+                0u64,
+                lwe_size,
+                LweCiphertextCount(count),
+                ciphertext_modulus,
+            ),
+            degree: Degree(0),
+            message_modulus,
+            carry_modulus,
+            _order_marker: PhantomData,
+        }
+    }
+
+    /// Copy the given ciphertexts into a new vector, whose degree is the maximum of their
+    /// degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty or if the ciphertexts do not all have the same parameters.
+    pub fn from_ciphertexts(cts: &[CiphertextBase<OpOrder>]) -> Self {
+        let first = cts
+            .first()
+            .expect("Cannot create a vector from an empty slice of ciphertexts");
+        let lwe_size = first.ct.lwe_size();
+        let ciphertext_modulus = first.ct.ciphertext_modulus();
+        let mut vector = Self::new(
+            cts.len(),
+            lwe_size,
+            first.message_modulus,
+            first.carry_modulus,
+            ciphertext_modulus,
+        );
+
+        for (ct, mut output) in cts.iter().zip(vector.cts.iter_mut()) {
+            assert!(
+                ct.message_modulus == first.message_modulus
+                    && ct.carry_modulus == first.carry_modulus
+                    && ct.ct.lwe_size() == lwe_size
+                    && ct.ct.ciphertext_modulus() == ciphertext_modulus,
+                "All the ciphertexts of a vector must have the same parameters"
+            );
+            output.as_mut().copy_from_slice(ct.ct.as_ref());
+            vector.degree = Degree(vector.degree.0.max(ct.degree.0));
+        }
+
+        vector
+    }
+
+    /// Return the number of ciphertexts in the vector.
+    pub fn len(&self) -> usize {
+        self.cts.lwe_ciphertext_count().0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the degree shared by all the ciphertexts of the vector.
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    pub fn lwe_size(&self) -> LweSize {
+        self.cts.lwe_size()
+    }
+
+    /// Return the LWE ciphertexts of the vector.
+    pub fn as_lwe_ciphertext_list(&self) -> LweCiphertextListView<'_, u64> {
+        self.cts.as_view()
+    }
+
+    pub fn carry_is_empty(&self) -> bool {
+        self.degree.0 < self.message_modulus.0
+    }
+
+    /// Copy the ciphertext at `index` into a [`CiphertextBase`], or return `None` if it is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<CiphertextBase<OpOrder>> {
+        if index >= self.len() {
+            return None;
+        }
+        let ct = self.cts.get(index);
+        Some(CiphertextBase {
+            ct: LweCiphertextOwned::from_container(ct.as_ref().to_vec(), ct.ciphertext_modulus()),
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            _order_marker: PhantomData,
+        })
+    }
+
+    /// Copy all the ciphertexts of the vector into [`CiphertextBase`].
+    pub fn to_ciphertexts(&self) -> Vec<CiphertextBase<OpOrder>> {
+        (0..self.len()).map(|i| self.get(i).unwrap()).collect()
+    }
+
+    /// Overwrite the ciphertext at `index`, the degree of the vector is raised to the one of
+    /// `ct` if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds or if the ciphertext does not have the parameters of
+    /// the vector.
+    pub fn set(&mut self, index: usize, ct: &CiphertextBase<OpOrder>) {
+        assert!(index < self.len(), "Index {index} is out of bounds");
+        assert!(
+            ct.message_modulus == self.message_modulus
+                && ct.carry_modulus == self.carry_modulus
+                && ct.ct.lwe_size() == self.cts.lwe_size()
+                && ct.ct.ciphertext_modulus() == self.cts.ciphertext_modulus(),
+            "The ciphertext parameters do not match the vector parameters"
+        );
+        self.cts
+            .get_mut(index)
+            .as_mut()
+            .copy_from_slice(ct.ct.as_ref());
+        self.degree = Degree(self.degree.0.max(ct.degree.0));
+    }
+}
+
+impl<OpOrder: PBSOrderMarker> FheSize for ShortintVector<OpOrder> {
+    fn element_count(&self) -> usize {
+        self.cts.element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.cts.heap_size()
+    }
+}
@@ -1,14 +1,15 @@
 //! Module with the definition of the ClientKey.
 
+use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBig,
-    CompressedCiphertextSmall, PBSOrderMarker,
+    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBatch,
+    CompressedCiphertextBig, CompressedCiphertextSmall, Degree, PBSOrderMarker,
 };
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::{MessageModulus, Parameters};
+use crate::shortint::parameters::{MessageModulus, Parameters, ParametersError};
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 
 /// A structure containing the client key, which must be kept secret.
 ///
@@ -28,6 +29,34 @@ pub struct ClientKey {
     pub parameters: Parameters,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for ClientKey {
+    large_lwe_secret_key,
+    glwe_secret_key,
+    small_lwe_secret_key,
+});
+
+/// Error returned by [`ClientKey::audit_degree`] when the degree of a ciphertext is smaller than
+/// the value it encrypts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DegreeAuditError {
+    /// The degree of the ciphertext.
+    pub degree: Degree,
+    /// The decrypted value, message and carry.
+    pub value: u64,
+}
+
+impl Display for DegreeAuditError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The ciphertext encrypts {} which is greater than its degree {}",
+            self.value, self.degree.0
+        )
+    }
+}
+
+impl std::error::Error for DegreeAuditError {}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -44,6 +73,68 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.new_client_key(parameters).unwrap())
     }
 
+    /// Build a client key from secret keys generated outside of this library.
+    ///
+    /// `glwe_secret_key` is the key under which the bootstrapping key encrypts and
+    /// `small_lwe_secret_key` the key under which the keyswitching key encrypts, the large LWE
+    /// secret key being derived from the GLWE one. Their dimensions are checked against
+    /// `parameters`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let (glwe_secret_key, small_lwe_secret_key, parameters) = cks.into_raw_parts();
+    /// let cks =
+    ///     ClientKey::try_from_raw_parts(glwe_secret_key, small_lwe_secret_key, parameters).unwrap();
+    ///
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn try_from_raw_parts(
+        glwe_secret_key: GlweSecretKeyOwned<u64>,
+        small_lwe_secret_key: LweSecretKeyOwned<u64>,
+        parameters: Parameters,
+    ) -> Result<Self, ParametersError> {
+        ParametersError::check_key(
+            "GlweSecretKey GlweDimension",
+            parameters.glwe_dimension,
+            glwe_secret_key.glwe_dimension(),
+        )?;
+        ParametersError::check_key(
+            "GlweSecretKey PolynomialSize",
+            parameters.polynomial_size,
+            glwe_secret_key.polynomial_size(),
+        )?;
+        ParametersError::check_key(
+            "LweSecretKey LweDimension",
+            parameters.lwe_dimension,
+            small_lwe_secret_key.lwe_dimension(),
+        )?;
+
+        let large_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+        Ok(Self {
+            large_lwe_secret_key,
+            glwe_secret_key,
+            small_lwe_secret_key,
+            parameters,
+        })
+    }
+
+    /// Deconstruct a [`ClientKey`] into its constituents, see [`ClientKey::try_from_raw_parts`].
+    pub fn into_raw_parts(self) -> (GlweSecretKeyOwned<u64>, LweSecretKeyOwned<u64>, Parameters) {
+        (
+            self.glwe_secret_key,
+            self.small_lwe_secret_key,
+            self.parameters,
+        )
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -180,6 +271,37 @@ impl ClientKey {
         })
     }
 
+    /// Encrypt a batch of small integer messages using the client key returning a
+    /// [`CompressedCiphertextBatch`].
+    ///
+    /// All the ciphertexts of the batch share one compression seed, making the batch smaller than
+    /// the same number of [`CompressedCiphertextBig`]. The input messages are reduced to the
+    /// encrypted message space modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs = [3, 1, 5];
+    /// let batch = cks.encrypt_compressed_batch(&msgs);
+    ///
+    /// let cts = batch.expand();
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// for (ct, msg) in cts.iter().zip(msgs) {
+    ///     assert_eq!(cks.decrypt(ct), msg % modulus);
+    /// }
+    /// ```
+    pub fn encrypt_compressed_batch(&self, messages: &[u64]) -> CompressedCiphertextBatch {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_compressed_batch(self, messages).unwrap()
+        })
+    }
+
     /// Encrypt a small integer message using the client key with a specific message modulus
     ///
     /// # Example
@@ -406,6 +528,45 @@ impl ClientKey {
         })
     }
 
+    /// Check that the degree of a ciphertext is an upper bound of the value (message and carry)
+    /// it encrypts.
+    ///
+    /// This is meant for tests of pipelines built with the unchecked operations and
+    /// [`CiphertextBase::assume_degree`], where an underestimated degree silently breaks the
+    /// checked operations computed afterwards. A value which already overflowed into the padding
+    /// bit decrypts modulo the message and carry spaces and cannot be detected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = sks.unchecked_add(&cks.encrypt(3), &cks.encrypt(2));
+    /// assert_eq!(cks.audit_degree(&ct), Ok(()));
+    ///
+    /// ct.assume_degree(Degree(3));
+    /// let err = cks.audit_degree(&ct).unwrap_err();
+    /// assert_eq!(err.value, 5);
+    /// ```
+    pub fn audit_degree<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+    ) -> Result<(), DegreeAuditError> {
+        let value = self.decrypt_message_and_carry(ct);
+        if value > ct.degree.0 as u64 {
+            Err(DegreeAuditError {
+                degree: ct.degree,
+                value,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     /// Decrypt a ciphertext encrypting a message using the client key.
     ///
     /// # Example
@@ -439,6 +600,77 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.decrypt(self, ct).unwrap())
     }
 
+    /// Decrypt a ciphertext and return only the `bits` most significant bits of its message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is larger than the number of bits of the message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(2);
+    /// assert_eq!(cks.decrypt_truncated(&ct, 1), 1);
+    /// assert_eq!(cks.decrypt_truncated(&ct, 2), 2);
+    /// ```
+    pub fn decrypt_truncated<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        bits: u32,
+    ) -> u64 {
+        let message_bits = (ct.message_modulus.0 as f64).log2() as u32;
+        assert!(
+            bits <= message_bits,
+            "Cannot keep {bits} bits of a {message_bits} bits message"
+        );
+
+        self.decrypt(ct) >> (message_bits - bits)
+    }
+
+    /// Decrypt a ciphertext after flooding a copy of it with fresh Gaussian noise of standard
+    /// deviation `std_dev`.
+    ///
+    /// The result is the one the client would get by decrypting the ciphertext flooded by the
+    /// server with [`ServerKey::add_flooding_noise`](crate::shortint::ServerKey::add_flooding_noise),
+    /// see its documentation for how to choose `std_dev`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt_with_flooding(&ct, StandardDev(2.0f64.powi(-20)));
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn decrypt_with_flooding<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        std_dev: impl DispersionParameter,
+    ) -> u64 {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let mut flooded = ct.clone();
+            engine
+                .add_flooding_noise_assign(&mut flooded, std_dev)
+                .unwrap();
+            engine.decrypt(self, &flooded).unwrap()
+        })
+    }
+
     /// Encrypt a small integer message using the client key without padding bit.
     ///
     /// The input message is reduced to the encrypted message space modulus
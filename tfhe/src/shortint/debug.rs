@@ -0,0 +1,114 @@
+//! Structured summaries and structural diffs of ciphertexts and keys.
+//!
+//! The [`Debug`] output of the ciphertexts and of the server keys does not dump their raw data,
+//! which is megabytes of `u64` for a key, but their dimensions, moduli and degree, and a
+//! [`DataHash`] of their data which is enough to tell whether two values are the same.
+//!
+//! The [`diff`] function lists the fields in which two values differ, so that a test failure
+//! points directly at what is wrong (e.g. a degree which was not updated).
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::debug::diff;
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let ct_1 = cks.encrypt(1);
+//! let ct_2 = sks.unchecked_add(&ct_1, &ct_1);
+//!
+//! println!("{ct_1:?}");
+//!
+//! let mismatches = diff(&ct_1, &ct_2);
+//! let paths: Vec<_> = mismatches.iter().map(|m| m.path.as_str()).collect();
+//! assert_eq!(paths, ["degree", "data"]);
+//! ```
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A field in which two values differ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Path of the field from the compared values, e.g. `blocks[2].degree`.
+    pub path: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} != {}", self.path, self.left, self.right)
+    }
+}
+
+/// Types whose values can be compared field by field.
+pub trait StructuralDiff {
+    /// Appends the fields in which `self` and `other` differ to `mismatches`, their paths
+    /// being prefixed by `path`.
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>);
+}
+
+/// Returns the fields in which `left` and `right` differ, an empty result meaning that the
+/// values are identical.
+pub fn diff<T: StructuralDiff + ?Sized>(left: &T, right: &T) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    left.diff_into(right, "", &mut mismatches);
+    mismatches
+}
+
+/// Hash of the data of a ciphertext or key, printed in place of the data itself.
+///
+/// The hash is not stable across versions of the crate or of the compiler, it is only meant to
+/// compare values in a single run.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct DataHash(pub u64);
+
+impl DataHash {
+    pub fn of<T: Hash + ?Sized>(data: &T) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+impl Debug for DataHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:016x}", self.0)
+    }
+}
+
+pub(crate) fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+pub(crate) fn diff_field<T: PartialEq + Debug>(
+    path: &str,
+    field: &str,
+    left: &T,
+    right: &T,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    if left != right {
+        mismatches.push(Mismatch {
+            path: field_path(path, field),
+            left: format!("{left:?}"),
+            right: format!("{right:?}"),
+        });
+    }
+}
+
+impl<T: StructuralDiff> StructuralDiff for [T] {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        diff_field(path, "len", &self.len(), &other.len(), mismatches);
+        for (i, (left, right)) in self.iter().zip(other.iter()).enumerate() {
+            left.diff_into(right, &format!("{path}[{i}]"), mismatches);
+        }
+    }
+}
@@ -1,15 +1,28 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
 use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
+use crate::core_crypto::commons::math::random::Seeder;
+use crate::core_crypto::commons::parameters::LweCiphertextCount;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
+use crate::shortint::key_rotation::KeyRotationKey;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
-    CiphertextBase, ClientKey, CompressedCiphertextBase, PBSOrder, PBSOrderMarker, Parameters,
+    CiphertextBase, ClientKey, CompressedCiphertextBase, CompressedCiphertextBatch, PBSOrder,
+    PBSOrderMarker, Parameters,
 };
 
 impl ShortintEngine {
+    /// Sample a fresh noise value, used to hide the secret dependent part of partial decryptions.
+    #[cfg(feature = "experimental-multi-key")]
+    pub(crate) fn sample_noise(
+        &mut self,
+        noise_parameter: impl NoiseDistribution,
+    ) -> EngineResult<u64> {
+        Ok(self.encryption_generator.random_noise(noise_parameter))
+    }
+
     pub fn new_client_key(&mut self, parameters: Parameters) -> EngineResult<ClientKey> {
         // generate the lwe secret key
         let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
@@ -35,6 +48,45 @@ impl ShortintEngine {
         })
     }
 
+    pub fn new_key_rotation_key(
+        &mut self,
+        old_key: &ClientKey,
+        new_key: &ClientKey,
+    ) -> EngineResult<KeyRotationKey> {
+        let parameters = &new_key.parameters;
+
+        // Each keyswitching key encrypts under the noise of the ciphertexts of its output key. The
+        // large key uses the finer decomposition of the bootstrapping key, which keeps the noise
+        // added by the keyswitch on large ciphertexts small.
+        let large_key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            &old_key.large_lwe_secret_key,
+            &new_key.large_lwe_secret_key,
+            parameters.pbs_base_log,
+            parameters.pbs_level,
+            parameters.glwe_noise_distribution(),
+            parameters.ciphertext_modulus,
+            &mut self.encryption_generator,
+        );
+
+        let small_key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            &old_key.small_lwe_secret_key,
+            &new_key.small_lwe_secret_key,
+            parameters.ks_base_log,
+            parameters.ks_level,
+            parameters.lwe_noise_distribution(),
+            parameters.ciphertext_modulus,
+            &mut self.encryption_generator,
+        );
+
+        Ok(KeyRotationKey {
+            large_key_switching_key,
+            small_key_switching_key,
+            message_modulus: parameters.message_modulus,
+            carry_modulus: parameters.carry_modulus,
+            ciphertext_modulus: parameters.ciphertext_modulus,
+        })
+    }
+
     pub fn encrypt<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
@@ -63,7 +115,7 @@ impl ShortintEngine {
         &mut self,
         client_key_parameters: &Parameters,
         client_lwe_sk: &LweSecretKeyOwned<u64>,
-        noise_parameter: impl DispersionParameter,
+        noise_parameter: impl NoiseDistribution,
         message: u64,
         message_modulus: MessageModulus,
     ) -> LweCiphertextOwned<u64> {
@@ -97,11 +149,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -155,11 +207,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -180,6 +232,53 @@ impl ShortintEngine {
         })
     }
 
+    pub fn encrypt_compressed_batch(
+        &mut self,
+        client_key: &ClientKey,
+        messages: &[u64],
+    ) -> EngineResult<CompressedCiphertextBatch> {
+        let message_modulus = client_key.parameters.message_modulus;
+        let carry_modulus = client_key.parameters.carry_modulus;
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        //The inputs are reduced modulus the message_modulus
+        let encoded = PlaintextList::from_container(
+            messages
+                .iter()
+                .map(|message| (message % message_modulus.0 as u64) * delta)
+                .collect::<Vec<_>>(),
+        );
+
+        // All the masks are derived from this single seed
+        let mut ct_list = SeededLweCiphertextListOwned::new(
+            0u64,
+            client_key
+                .large_lwe_secret_key
+                .lwe_dimension()
+                .to_lwe_size(),
+            LweCiphertextCount(messages.len()),
+            self.seeder.seed().into(),
+            client_key.parameters.ciphertext_modulus,
+        );
+
+        encrypt_seeded_lwe_ciphertext_list(
+            &client_key.large_lwe_secret_key,
+            &mut ct_list,
+            &encoded,
+            client_key.parameters.glwe_noise_distribution(),
+            &mut self.seeder,
+        );
+
+        Ok(CompressedCiphertextBatch {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus,
+        })
+    }
+
     pub(crate) fn unchecked_encrypt<OpOrder: PBSOrderMarker>(
         &mut self,
         client_key: &ClientKey,
@@ -188,11 +287,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -277,11 +376,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -320,11 +419,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -398,11 +497,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -438,11 +537,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match OpOrder::pbs_order() {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev,
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
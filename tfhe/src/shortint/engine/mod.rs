@@ -7,11 +7,14 @@ use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{
     DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
+use crate::core_crypto::commons::math::random::{
+    RandomGeneratorBackend, RuntimeRandomGenerator, Seed, Seeder,
+};
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::ContainerMut;
 use crate::core_crypto::seeders::new_seeder;
 use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::backend::fft_programmable_bootstrap_scratch_bytes;
 use crate::shortint::server_key::{
     BivariateLookupTableOwned, LookupTableMutView, LookupTableOwned,
 };
@@ -19,10 +22,12 @@ use crate::shortint::ServerKey;
 use std::cell::RefCell;
 use std::fmt::Debug;
 
-use super::parameters::MessageModulus;
+use super::parameters::{CarryModulus, MessageModulus};
 use super::server_key::BivariateLookupTable;
 
 mod client_side;
+#[cfg(not(feature = "__wasm_api"))]
+pub(crate) mod pipeline;
 mod public_side;
 mod server_side;
 #[cfg(not(feature = "__wasm_api"))]
@@ -40,31 +45,112 @@ pub struct BuffersRef<'a> {
     pub(crate) buffer_lwe_after_pbs: LweCiphertextMutView<'a, u64>,
 }
 
-#[derive(Default)]
+/// Sizes of the ciphertext buffers needed by a [`ServerKey`], used to find the buffers of a key
+/// in the [`Memory`] cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct BuffersFingerprint {
+    num_elem_in_accumulator: usize,
+    num_elem_in_lwe_after_ks: usize,
+    num_elem_in_lwe_after_pbs: usize,
+}
+
+impl BuffersFingerprint {
+    fn new(server_key: &ServerKey) -> Self {
+        Self {
+            num_elem_in_accumulator: server_key.bootstrapping_key.glwe_size().0
+                * server_key.bootstrapping_key.polynomial_size().0,
+            num_elem_in_lwe_after_ks: server_key.key_switching_key.output_lwe_size().0,
+            num_elem_in_lwe_after_pbs: server_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size()
+                .0,
+        }
+    }
+
+    fn total_elem_needed(&self) -> usize {
+        self.num_elem_in_accumulator
+            + self.num_elem_in_lwe_after_ks
+            + self.num_elem_in_lwe_after_pbs
+    }
+}
+
+/// Default number of keys for which an engine keeps ciphertext buffers.
+pub const DEFAULT_BUFFERS_CACHE_CAPACITY: usize = 4;
+
+/// Cache of the ciphertext buffers of the last keys used with an engine, the most recently used
+/// key coming last.
 struct Memory {
-    buffer: Vec<u64>,
+    buffers: Vec<(BuffersFingerprint, Vec<u64>)>,
+    capacity: usize,
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self {
+            buffers: Vec::new(),
+            capacity: DEFAULT_BUFFERS_CACHE_CAPACITY,
+        }
+    }
+}
+
+impl Drop for Memory {
+    fn drop(&mut self) {
+        for (_, buffer) in &self.buffers {
+            crate::metrics::record_engine_buffer_release(std::mem::size_of_val(buffer.as_slice()));
+        }
+    }
 }
 
 impl Memory {
+    fn set_capacity(&mut self, capacity: usize) {
+        assert!(
+            capacity > 0,
+            "The buffers cache capacity must be greater than 0"
+        );
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// Drop the least recently used buffers in excess of the capacity.
+    fn evict(&mut self) {
+        let excess = self.buffers.len().saturating_sub(self.capacity);
+        for (_, buffer) in self.buffers.drain(..excess) {
+            crate::metrics::record_engine_buffer_release(std::mem::size_of_val(buffer.as_slice()));
+        }
+    }
+
+    /// Return the buffer for the given fingerprint, moved to the most recently used position.
+    fn buffer_for(&mut self, fingerprint: BuffersFingerprint) -> &mut [u64] {
+        match self
+            .buffers
+            .iter()
+            .position(|(cached, _)| *cached == fingerprint)
+        {
+            Some(index) => {
+                let entry = self.buffers.remove(index);
+                self.buffers.push(entry);
+            }
+            None => {
+                let buffer = vec![0u64; fingerprint.total_elem_needed()];
+                crate::metrics::record_engine_buffer_allocation(std::mem::size_of_val(
+                    buffer.as_slice(),
+                ));
+                self.buffers.push((fingerprint, buffer));
+                self.evict();
+            }
+        }
+
+        // The vec cannot be empty, we just pushed in it
+        self.buffers.last_mut().unwrap().1.as_mut_slice()
+    }
+
     fn as_buffers(&mut self, server_key: &ServerKey) -> BuffersRef<'_> {
-        let num_elem_in_accumulator = server_key.bootstrapping_key.glwe_size().0
-            * server_key.bootstrapping_key.polynomial_size().0;
-        let num_elem_in_lwe_after_ks = server_key.key_switching_key.output_lwe_size().0;
-        let num_elem_in_lwe_after_pbs = server_key
-            .bootstrapping_key
-            .output_lwe_dimension()
-            .to_lwe_size()
-            .0;
-
-        let total_elem_needed =
-            num_elem_in_accumulator + num_elem_in_lwe_after_ks + num_elem_in_lwe_after_pbs;
-
-        let all_elements = if self.buffer.len() < total_elem_needed {
-            self.buffer.resize(total_elem_needed, 0u64);
-            self.buffer.as_mut_slice()
-        } else {
-            &mut self.buffer[..total_elem_needed]
-        };
+        let fingerprint = BuffersFingerprint::new(server_key);
+        let num_elem_in_accumulator = fingerprint.num_elem_in_accumulator;
+        let num_elem_in_lwe_after_ks = fingerprint.num_elem_in_lwe_after_ks;
+
+        let all_elements = self.buffer_for(fingerprint);
 
         let (accumulator_elements, other_elements) =
             all_elements.split_at_mut(num_elem_in_accumulator);
@@ -111,18 +197,39 @@ where
         server_key.bootstrapping_key.glwe_size()
     );
 
+    fill_accumulator_with_moduli(
+        accumulator,
+        server_key.message_modulus,
+        server_key.carry_modulus,
+        f,
+    )
+}
+
+/// Fill `accumulator` with the lookup table of `f` for ciphertexts with the given moduli, the
+/// polynomial size being the one of the accumulator.
+pub(crate) fn fill_accumulator_with_moduli<F, C>(
+    accumulator: &mut GlweCiphertext<C>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    f: F,
+) -> u64
+where
+    C: ContainerMut<Element = u64>,
+    F: Fn(u64) -> u64,
+{
+    let polynomial_size = accumulator.polynomial_size();
     let mut accumulator_view = accumulator.as_mut_view();
 
     accumulator_view.get_mut_mask().as_mut().fill(0);
 
     // Modulus of the msg contained in the msg bits and operations buffer
-    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
 
     // N/(p/2) = size of each block
-    let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+    let box_size = polynomial_size.0 / modulus_sup;
 
     // Value of the shift we multiply our messages by
-    let delta = (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+    let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
 
     let mut body = accumulator_view.get_mut_body();
     let accumulator_u64 = body.as_mut();
@@ -176,6 +283,24 @@ where
 
 pub(crate) type EngineResult<T> = Result<T, EngineError>;
 
+/// Snapshot of the state of the random generators of a [`ShortintEngine`].
+///
+/// See [`ShortintEngine::snapshot_rng_state`], the snapshot must be kept secret. It records the
+/// backend of the generators, which must be available where the snapshot is restored.
+#[derive(Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EngineRngState {
+    seed: u128,
+    #[serde(default)]
+    backend: RandomGeneratorBackend,
+}
+
+// The seed is not printed, as it gives access to the noise of the encryptions
+impl Debug for EngineRngState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EngineRngState").finish_non_exhaustive()
+    }
+}
+
 /// ShortintEngine
 ///
 /// This 'engine' holds the necessary engines from [`core_crypto`](crate::core_crypto)
@@ -184,17 +309,17 @@ pub(crate) type EngineResult<T> = Result<T, EngineError>;
 /// This structs actually implements the logics into its methods.
 pub struct ShortintEngine {
     /// A structure containing a single CSPRNG to generate secret key coefficients.
-    secret_generator: SecretRandomGenerator<ActivatedRandomGenerator>,
+    secret_generator: SecretRandomGenerator<RuntimeRandomGenerator>,
     /// A structure containing two CSPRNGs to generate material for encryption like public masks
     /// and secret errors.
     ///
     /// The [`EncryptionRandomGenerator`] contains two CSPRNGs, one publicly seeded used to
     /// generate mask coefficients and one privately seeded used to generate errors during
     /// encryption.
-    encryption_generator: EncryptionRandomGenerator<ActivatedRandomGenerator>,
+    encryption_generator: EncryptionRandomGenerator<RuntimeRandomGenerator>,
     /// A seeder that can be called to generate 128 bits seeds, useful to create new
     /// [`EncryptionRandomGenerator`] to encrypt seeded types.
-    seeder: DeterministicSeeder<ActivatedRandomGenerator>,
+    seeder: DeterministicSeeder<RuntimeRandomGenerator>,
     computation_buffers: ComputationBuffers,
     ciphertext_buffers: Memory,
 }
@@ -221,29 +346,169 @@ impl ShortintEngine {
     ///
     /// This will panic if the `CoreEngine` failed to create.
     pub fn new() -> Self {
+        Self::new_with_backend(RandomGeneratorBackend::default())
+    }
+
+    /// Create a new shortint engine whose random generators use the given backend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend is not
+    /// [available](RandomGeneratorBackend::is_available) on this machine.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::RandomGeneratorBackend;
+    /// use tfhe::shortint::engine::ShortintEngine;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Use ChaCha20 for the keys and the encryptions of this thread
+    /// ShortintEngine::with_thread_local_mut(|engine| {
+    ///     *engine = ShortintEngine::new_with_backend(RandomGeneratorBackend::ChaCha20)
+    /// });
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn new_with_backend(backend: RandomGeneratorBackend) -> Self {
         let mut root_seeder = new_seeder();
 
-        Self::new_from_seeder(root_seeder.as_mut())
+        Self::new_from_seeder_with_backend(root_seeder.as_mut(), backend)
     }
 
     pub fn new_from_seeder(root_seeder: &mut dyn Seeder) -> Self {
-        let mut deterministic_seeder =
-            DeterministicSeeder::<ActivatedRandomGenerator>::new(root_seeder.seed());
+        Self::new_from_seeder_with_backend(root_seeder, RandomGeneratorBackend::default())
+    }
+
+    pub fn new_from_seeder_with_backend(
+        root_seeder: &mut dyn Seeder,
+        backend: RandomGeneratorBackend,
+    ) -> Self {
+        let (secret_generator, encryption_generator, seeder) =
+            Self::generators_from_seed(root_seeder.seed(), backend);
 
-        // Note that the operands are evaluated from left to right for Rust Struct expressions
-        // See: https://doc.rust-lang.org/stable/reference/expressions.html?highlight=left#evaluation-order-of-operands
         Self {
-            secret_generator: SecretRandomGenerator::new(deterministic_seeder.seed()),
-            encryption_generator: EncryptionRandomGenerator::new(
-                deterministic_seeder.seed(),
-                &mut deterministic_seeder,
-            ),
-            seeder: deterministic_seeder,
+            secret_generator,
+            encryption_generator,
+            seeder,
             computation_buffers: Default::default(),
             ciphertext_buffers: Default::default(),
         }
     }
 
+    /// Create a new shortint engine whose random generators are in the given state.
+    ///
+    /// See [`ShortintEngine::snapshot_rng_state`].
+    ///
+    /// # Warning
+    ///
+    /// The new engine draws the same masks and noises as the engine the snapshot was taken from.
+    /// Encrypting a message different from the one encrypted after the snapshot reuses both the
+    /// mask and the noise: anyone holding the two ciphertexts can subtract them and recover
+    /// `m1 - m2`. Only use it to replay the exact same encryptions.
+    pub fn new_from_rng_state(state: &EngineRngState) -> Self {
+        let (secret_generator, encryption_generator, seeder) =
+            Self::generators_from_seed(Seed(state.seed), state.backend);
+
+        Self {
+            secret_generator,
+            encryption_generator,
+            seeder,
+            computation_buffers: Default::default(),
+            ciphertext_buffers: Default::default(),
+        }
+    }
+
+    /// The backend used by the random generators of the engine.
+    pub fn rng_backend(&self) -> RandomGeneratorBackend {
+        self.secret_generator.backend()
+    }
+
+    fn generators_from_seed(
+        root_seed: Seed,
+        backend: RandomGeneratorBackend,
+    ) -> (
+        SecretRandomGenerator<RuntimeRandomGenerator>,
+        EncryptionRandomGenerator<RuntimeRandomGenerator>,
+        DeterministicSeeder<RuntimeRandomGenerator>,
+    ) {
+        let mut deterministic_seeder = DeterministicSeeder::new_with_backend(root_seed, backend);
+
+        // Note that the operands are evaluated from left to right for Rust tuple expressions
+        // See: https://doc.rust-lang.org/stable/reference/expressions.html?highlight=left#evaluation-order-of-operands
+        (
+            SecretRandomGenerator::new_with_backend(deterministic_seeder.seed(), backend),
+            EncryptionRandomGenerator::new_with_backend(
+                deterministic_seeder.seed(),
+                &mut deterministic_seeder,
+                backend,
+            ),
+            deterministic_seeder,
+        )
+    }
+
+    /// Take a snapshot of the state of the random generators of the engine.
+    ///
+    /// The generators of the engine are reseeded from a seed drawn from the engine itself, and
+    /// this seed is returned as the snapshot: restoring the snapshot with
+    /// [`ShortintEngine::restore_rng_state`] (or creating a new engine with
+    /// [`ShortintEngine::new_from_rng_state`]), in this process or another one, makes the engine
+    /// generate the exact same sequence of keys and encryptions as the one generated after the
+    /// snapshot was taken.
+    ///
+    /// # Warning
+    ///
+    /// The snapshot determines the noise of all the subsequent encryptions and the subsequently
+    /// generated secret keys, it must be protected like a secret key and is only meant for
+    /// audits and debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::engine::ShortintEngine;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let state = ShortintEngine::with_thread_local_mut(|engine| engine.snapshot_rng_state());
+    /// let ct_1 = cks.encrypt(3);
+    ///
+    /// // Replay the encryption
+    /// ShortintEngine::with_thread_local_mut(|engine| engine.restore_rng_state(&state));
+    /// let ct_2 = cks.encrypt(3);
+    ///
+    /// assert_eq!(ct_1.ct, ct_2.ct);
+    /// ```
+    pub fn snapshot_rng_state(&mut self) -> EngineRngState {
+        let state = EngineRngState {
+            seed: self.seeder.seed().0,
+            backend: self.rng_backend(),
+        };
+        self.restore_rng_state(&state);
+        state
+    }
+
+    /// Restore the state of the random generators of the engine from a snapshot taken with
+    /// [`ShortintEngine::snapshot_rng_state`].
+    ///
+    /// # Warning
+    ///
+    /// After the restoration the engine draws the same masks and noises as after the snapshot
+    /// was taken. Encrypting a message different from the one encrypted after the snapshot
+    /// reuses both the mask and the noise: anyone holding the two ciphertexts can subtract them
+    /// and recover `m1 - m2`. Only use it to replay the exact same encryptions.
+    pub fn restore_rng_state(&mut self, state: &EngineRngState) {
+        let (secret_generator, encryption_generator, seeder) =
+            Self::generators_from_seed(Seed(state.seed), state.backend);
+        self.secret_generator = secret_generator;
+        self.encryption_generator = encryption_generator;
+        self.seeder = seeder;
+    }
+
     fn generate_accumulator_with_engine<F>(
         server_key: &ServerKey,
         f: F,
@@ -293,6 +558,52 @@ impl ShortintEngine {
         })
     }
 
+    /// Allocate the buffers needed to compute with `server_key` ahead of time, so that the first
+    /// operations done with the key do not pay for the allocations.
+    ///
+    /// The buffers of the last [`ShortintEngine::set_buffers_cache_capacity`] keys used with the
+    /// engine are kept, so alternating between keys of different sizes does not reallocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::engine::ShortintEngine;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// ShortintEngine::with_thread_local_mut(|engine| engine.preallocate_for(&sks));
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.message_extract(&ct);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn preallocate_for(&mut self, server_key: &ServerKey) {
+        let _ = self.ciphertext_buffers.as_buffers(server_key);
+
+        self.computation_buffers
+            .resize(fft_programmable_bootstrap_scratch_bytes(
+                &server_key.bootstrapping_key,
+            ));
+    }
+
+    /// Return the scratch memory of the engine, to be resized by its user.
+    pub(crate) fn computation_buffers(&mut self) -> &mut ComputationBuffers {
+        &mut self.computation_buffers
+    }
+
+    /// Set the number of keys for which the engine keeps ciphertext buffers, the buffers of the
+    /// least recently used keys being dropped first. The default is
+    /// [`DEFAULT_BUFFERS_CACHE_CAPACITY`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn set_buffers_cache_capacity(&mut self, capacity: usize) {
+        self.ciphertext_buffers.set_capacity(capacity);
+    }
+
     /// Return the [`BuffersRef`] and [`ComputationBuffers`] for the given `ServerKey`
     pub fn get_carry_clearing_accumulator_and_buffers(
         &mut self,
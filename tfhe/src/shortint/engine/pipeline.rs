@@ -0,0 +1,127 @@
+//! A two stage pipeline executor.
+//!
+//! The items of a batch go through a first stage then a second stage, each stage being run by its
+//! own set of threads. The stages are connected by a bounded channel, so the second stage of an
+//! item is computed while the first stage of the following items is, instead of all the items
+//! waiting for the slowest stage of the batch to finish.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::sync_channel;
+use std::sync::Mutex;
+
+/// Number of threads running each stage of a pipeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PipelineWorkers {
+    pub(crate) first_stage: usize,
+    pub(crate) second_stage: usize,
+}
+
+/// Compute `second_stage(first_stage(input))` for each input, returning the outputs in the order
+/// of the inputs.
+///
+/// `first_stage` and `second_stage` are called with the index of the item. At most `depth` items
+/// wait between the two stages. Each thread creates its own state with `first_state` or
+/// `second_state`, e.g. its computation buffers.
+pub(crate) fn run_pipeline<I, M, O, S1, S2>(
+    inputs: &[I],
+    workers: PipelineWorkers,
+    depth: usize,
+    first_state: impl Fn() -> S1 + Sync,
+    first_stage: impl Fn(&mut S1, usize, &I) -> M + Sync,
+    second_state: impl Fn() -> S2 + Sync,
+    second_stage: impl Fn(&mut S2, usize, M) -> O + Sync,
+) -> Vec<O>
+where
+    I: Sync,
+    M: Send,
+    O: Send,
+{
+    assert!(workers.first_stage > 0 && workers.second_stage > 0);
+
+    let next_input = AtomicUsize::new(0);
+    let (sender, receiver) = sync_channel::<(usize, M)>(depth.max(1));
+    let receiver = Mutex::new(receiver);
+    let outputs = Mutex::new((0..inputs.len()).map(|_| None).collect::<Vec<Option<O>>>());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers.first_stage {
+            let sender = sender.clone();
+            let (next_input, first_state, first_stage) = (&next_input, &first_state, &first_stage);
+            scope.spawn(move || {
+                let mut state = first_state();
+                loop {
+                    let index = next_input.fetch_add(1, Ordering::Relaxed);
+                    let input = match inputs.get(index) {
+                        Some(input) => input,
+                        None => break,
+                    };
+                    let intermediate = first_stage(&mut state, index, input);
+                    // The receiving side only hangs up if a second stage thread panicked
+                    if sender.send((index, intermediate)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        // The channel is closed once all the first stage threads are done
+        drop(sender);
+
+        for _ in 0..workers.second_stage {
+            let (receiver, outputs, second_state, second_stage) =
+                (&receiver, &outputs, &second_state, &second_stage);
+            scope.spawn(move || {
+                let mut state = second_state();
+                loop {
+                    // The lock is released before computing the stage
+                    let received = receiver.lock().unwrap().recv();
+                    let (index, intermediate) = match received {
+                        Ok(received) => received,
+                        Err(_) => break,
+                    };
+                    let output = second_stage(&mut state, index, intermediate);
+                    outputs.lock().unwrap()[index] = Some(output);
+                }
+            });
+        }
+    });
+
+    outputs
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|output| output.unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_pipeline_keeps_order() {
+        let inputs = (0..100u64).collect::<Vec<_>>();
+
+        for (first_stage, second_stage) in [(1, 1), (1, 4), (3, 2)] {
+            let outputs = run_pipeline(
+                &inputs,
+                PipelineWorkers {
+                    first_stage,
+                    second_stage,
+                },
+                2,
+                || (),
+                |_, index, x| {
+                    assert_eq!(index as u64, *x);
+                    x * 3
+                },
+                || 0u64,
+                |count, _, x| {
+                    *count += 1;
+                    x + 1
+                },
+            );
+
+            let expected = inputs.iter().map(|x| x * 3 + 1).collect::<Vec<_>>();
+            assert_eq!(outputs, expected);
+        }
+    }
+}
@@ -3,10 +3,11 @@ use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{CompactCiphertextList, Degree};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
-    CiphertextBase, ClientKey, CompressedPublicKeyBase, PBSOrderMarker, PublicKeyBase,
+    CiphertextBase, ClientKey, CompactPublicKey, CompressedPublicKeyBase, PBSOrderMarker,
+    PublicKeyBase,
 };
 
 // We have q = 2^64 so log2q = 64
@@ -31,11 +32,11 @@ impl ShortintEngine {
         let (secret_encryption_key, encryption_noise) = match OpOrder::pbs_order() {
             crate::shortint::PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_parameters.glwe_modular_std_dev,
+                client_parameters.glwe_noise_distribution(),
             ),
             crate::shortint::PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_parameters.lwe_modular_std_dev,
+                client_parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -77,11 +78,11 @@ impl ShortintEngine {
         let (secret_encryption_key, encryption_noise) = match OpOrder::pbs_order() {
             crate::shortint::PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_parameters.glwe_modular_std_dev,
+                client_parameters.glwe_noise_distribution(),
             ),
             crate::shortint::PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_parameters.lwe_modular_std_dev,
+                client_parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -114,6 +115,27 @@ impl ShortintEngine {
         })
     }
 
+    pub(crate) fn new_compact_public_key(
+        &mut self,
+        client_key: &ClientKey,
+    ) -> EngineResult<CompactPublicKey> {
+        let client_parameters = client_key.parameters;
+
+        // The compact public key is generated for the large key so that its lwe dimension, k * N,
+        // is large enough to pack many messages
+        let key = allocate_and_generate_new_lwe_compact_public_key(
+            &client_key.large_lwe_secret_key,
+            client_parameters.glwe_noise_distribution(),
+            client_parameters.ciphertext_modulus,
+            &mut self.encryption_generator,
+        );
+
+        Ok(CompactPublicKey {
+            key,
+            parameters: client_parameters,
+        })
+    }
+
     pub(crate) fn encrypt_with_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
@@ -142,6 +164,52 @@ impl ShortintEngine {
         Ok(ciphertext)
     }
 
+    pub(crate) fn encrypt_slice_with_compact_public_key(
+        &mut self,
+        public_key: &CompactPublicKey,
+        messages: &[u64],
+    ) -> EngineResult<CompactCiphertextList> {
+        let message_modulus = public_key.parameters.message_modulus;
+        let carry_modulus = public_key.parameters.carry_modulus;
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        //The inputs are reduced modulus the message_modulus
+        let plaintext_list = PlaintextList::from_container(
+            messages
+                .iter()
+                .map(|message| (message % message_modulus.0 as u64) * delta)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut ct_list = LweCompactCiphertextListOwned::new(
+            0u64,
+            public_key.key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(messages.len()),
+            public_key.key.ciphertext_modulus(),
+        );
+
+        let encryption_noise = public_key.parameters.glwe_noise_distribution();
+
+        encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+            &public_key.key,
+            &mut ct_list,
+            &plaintext_list,
+            encryption_noise,
+            encryption_noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+
+        Ok(CompactCiphertextList {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus,
+        })
+    }
+
     pub(crate) fn encrypt_with_message_modulus_and_public_key<OpOrder: PBSOrderMarker>(
         &mut self,
         public_key: &PublicKeyBase<OpOrder>,
@@ -14,6 +14,16 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn unchecked_add_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct_left);
+        self.unchecked_add_assign(ct_out, ct_right)
+    }
+
     pub(crate) fn unchecked_add_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         ct_left: &mut CiphertextBase<OpOrder>,
@@ -13,6 +13,17 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn unchecked_bitand_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct_left);
+        self.unchecked_bitand_assign(server_key, ct_out, ct_right)
+    }
+
     pub(crate) fn unchecked_bitand_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -65,6 +76,17 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn unchecked_bitxor_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct_left);
+        self.unchecked_bitxor_assign(server_key, ct_out, ct_right)
+    }
+
     pub(crate) fn unchecked_bitxor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -117,6 +139,17 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn unchecked_bitor_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct_left);
+        self.unchecked_bitor_assign(server_key, ct_out, ct_right)
+    }
+
     pub(crate) fn unchecked_bitor_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
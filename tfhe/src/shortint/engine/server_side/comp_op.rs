@@ -1,33 +1,82 @@
 use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::server_key::{BivariateLookupTableOwned, ComparisonOp};
 use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
 
 impl ShortintEngine {
-    pub(crate) fn unchecked_greater<OpOrder: PBSOrderMarker>(
+    pub(crate) fn unchecked_comparison<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
+        op: ComparisonOp,
     ) -> EngineResult<CiphertextBase<OpOrder>> {
         let mut result = ct_left.clone();
-        self.unchecked_greater_assign(server_key, &mut result, ct_right)?;
+        self.unchecked_comparison_assign(server_key, &mut result, ct_right, op)?;
         Ok(result)
     }
 
-    fn unchecked_greater_assign<OpOrder: PBSOrderMarker>(
+    /// Evaluates the comparison in a single bivariate PBS.
+    ///
+    /// The lhs is scaled by the degree of the rhs rather than by the message modulus, so small
+    /// inputs use less of the plaintext space and need fewer carry extractions.
+    fn unchecked_comparison_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
+        op: ComparisonOp,
     ) -> EngineResult<()> {
         self.unchecked_evaluate_bivariate_function_assign(
             server_key,
             ct_left,
             ct_right,
-            |lhs, rhs| u64::from(lhs > rhs),
+            |lhs, rhs| op.evaluate(lhs, rhs),
         )?;
         Ok(())
     }
 
+    pub(crate) fn smart_comparison<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+        op: ComparisonOp,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
+            self.message_extract_assign(server_key, ct_left)?;
+            self.message_extract_assign(server_key, ct_right)?;
+        }
+        self.unchecked_comparison(server_key, ct_left, ct_right, op)
+    }
+
+    pub(crate) fn generate_comparison_accumulator(
+        &mut self,
+        server_key: &ServerKey,
+        op: ComparisonOp,
+    ) -> EngineResult<BivariateLookupTableOwned> {
+        self.generate_accumulator_bivariate(server_key, |lhs, rhs| op.evaluate(lhs, rhs))
+    }
+
+    pub(crate) fn unchecked_greater<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct_left.clone();
+        self.unchecked_greater_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    fn unchecked_greater_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        self.unchecked_comparison_assign(server_key, ct_left, ct_right, ComparisonOp::Greater)
+    }
+
     pub(crate) fn smart_greater<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -71,13 +120,12 @@ impl ShortintEngine {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
-        self.unchecked_evaluate_bivariate_function_assign(
+        self.unchecked_comparison_assign(
             server_key,
             ct_left,
             ct_right,
-            |lhs, rhs| u64::from(lhs >= rhs),
-        )?;
-        Ok(())
+            ComparisonOp::GreaterOrEqual,
+        )
     }
 
     pub(crate) fn smart_greater_or_equal<OpOrder: PBSOrderMarker>(
@@ -122,13 +170,7 @@ impl ShortintEngine {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
-        self.unchecked_evaluate_bivariate_function_assign(
-            server_key,
-            ct_left,
-            ct_right,
-            |lhs, rhs| u64::from(lhs < rhs),
-        )?;
-        Ok(())
+        self.unchecked_comparison_assign(server_key, ct_left, ct_right, ComparisonOp::Less)
     }
 
     pub(crate) fn smart_less<OpOrder: PBSOrderMarker>(
@@ -173,13 +215,7 @@ impl ShortintEngine {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
-        self.unchecked_evaluate_bivariate_function_assign(
-            server_key,
-            ct_left,
-            ct_right,
-            |lhs, rhs| u64::from(lhs <= rhs),
-        )?;
-        Ok(())
+        self.unchecked_comparison_assign(server_key, ct_left, ct_right, ComparisonOp::LessOrEqual)
     }
 
     pub(crate) fn smart_less_or_equal<OpOrder: PBSOrderMarker>(
@@ -224,13 +260,7 @@ impl ShortintEngine {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
-        self.unchecked_evaluate_bivariate_function_assign(
-            server_key,
-            ct_left,
-            ct_right,
-            |lhs, rhs| u64::from(lhs == rhs),
-        )?;
-        Ok(())
+        self.unchecked_comparison_assign(server_key, ct_left, ct_right, ComparisonOp::Equal)
     }
 
     pub(crate) fn smart_equal<OpOrder: PBSOrderMarker>(
@@ -300,13 +330,7 @@ impl ShortintEngine {
         ct_left: &mut CiphertextBase<OpOrder>,
         ct_right: &CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
-        self.unchecked_evaluate_bivariate_function_assign(
-            server_key,
-            ct_left,
-            ct_right,
-            |lhs, rhs| u64::from(lhs != rhs),
-        )?;
-        Ok(())
+        self.unchecked_comparison_assign(server_key, ct_left, ct_right, ComparisonOp::NotEqual)
     }
 
     pub(crate) fn smart_not_equal<OpOrder: PBSOrderMarker>(
@@ -11,6 +11,16 @@ pub(crate) fn safe_division(x: u64, y: u64) -> u64 {
     }
 }
 
+// Specific modulo function returning x in case of a modulo by 0, so that
+// x == (x / y) * y + x % y also holds for y == 0 with the division above
+pub(crate) fn safe_modulo(x: u64, y: u64) -> u64 {
+    if y == 0 {
+        x
+    } else {
+        x % y
+    }
+}
+
 impl ShortintEngine {
     pub(crate) fn unchecked_div<OpOrder: PBSOrderMarker>(
         &mut self,
@@ -70,6 +80,64 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn unchecked_mod<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct_left.clone();
+        self.unchecked_mod_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_mod_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        self.unchecked_evaluate_bivariate_function_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            safe_modulo,
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn smart_mod<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<CiphertextBase<OpOrder>> {
+        let mut result = ct_left.clone();
+        self.smart_mod_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_mod_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
+            if ct_left.message_modulus.0 + ct_right.degree.0 <= server_key.max_degree.0 {
+                self.message_extract_assign(server_key, ct_left)?;
+            } else if ct_right.message_modulus.0 + (ct_left.degree.0 + 1) <= server_key.max_degree.0
+            {
+                self.message_extract_assign(server_key, ct_right)?;
+            } else {
+                self.message_extract_assign(server_key, ct_left)?;
+                self.message_extract_assign(server_key, ct_right)?;
+            }
+        }
+        self.unchecked_mod_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
     /// # Panics
     ///
     /// This function will panic if `scalar == 0`
@@ -0,0 +1,28 @@
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+impl ShortintEngine {
+    pub(crate) fn add_flooding_noise_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        ct: &mut CiphertextBase<OpOrder>,
+        std_dev: impl DispersionParameter,
+    ) -> EngineResult<()> {
+        let ciphertext_modulus = ct.ct.ciphertext_modulus();
+        let body = ct.ct.get_mut_body();
+        let body = std::slice::from_mut(body.data);
+
+        if ciphertext_modulus.is_native_modulus() {
+            self.encryption_generator
+                .unsigned_torus_slice_wrapping_add_random_noise_assign(body, std_dev);
+        } else {
+            self.encryption_generator
+                .unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign(
+                    body,
+                    std_dev,
+                    ciphertext_modulus,
+                );
+        }
+        Ok(())
+    }
+}
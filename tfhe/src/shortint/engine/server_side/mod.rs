@@ -7,7 +7,10 @@ use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::engine::EngineResult;
 use crate::shortint::parameters::MessageModulus;
-use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned, MaxDegree};
+use crate::shortint::server_key::backend::ServerKeyBackend;
+use crate::shortint::server_key::{
+    BivariateLookupTableOwned, LookupTableOwned, MaxDegree, PbsMode, ShortintBootstrappingKey,
+};
 use crate::shortint::{
     CiphertextBase, CiphertextBig, CiphertextSmall, ClientKey, CompressedServerKey, PBSOrder,
     PBSOrderMarker, ServerKey,
@@ -17,6 +20,7 @@ mod add;
 mod bitwise_op;
 mod comp_op;
 mod div_mod;
+mod flooding;
 mod mul;
 mod neg;
 mod scalar_add;
@@ -46,7 +50,7 @@ impl ShortintEngine {
                 &cks.glwe_secret_key,
                 cks.parameters.pbs_base_log,
                 cks.parameters.pbs_level,
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution(),
                 cks.parameters.ciphertext_modulus,
                 &mut self.encryption_generator,
             );
@@ -83,7 +87,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -91,11 +95,12 @@ impl ShortintEngine {
         // Pack the keys in the server key set:
         Ok(ServerKey {
             key_switching_key,
-            bootstrapping_key: fourier_bsk,
+            bootstrapping_key: ShortintBootstrappingKey::Classic(fourier_bsk),
             message_modulus: cks.parameters.message_modulus,
             carry_modulus: cks.parameters.carry_modulus,
             max_degree,
             ciphertext_modulus: cks.parameters.ciphertext_modulus,
+            backend: ServerKeyBackend::default(),
         })
     }
 
@@ -122,7 +127,7 @@ impl ShortintEngine {
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution(),
             cks.parameters.ciphertext_modulus,
             &mut self.seeder,
         );
@@ -133,7 +138,7 @@ impl ShortintEngine {
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution(),
             cks.parameters.ciphertext_modulus,
             &mut self.seeder,
         );
@@ -144,7 +149,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus,
             &mut self.seeder,
         );
@@ -180,36 +185,12 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        // Compute a keyswitch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ct.ct,
-            &mut ciphertext_buffers.buffer_lwe_after_ks,
-        );
-
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
-        // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &ciphertext_buffers.buffer_lwe_after_ks,
+        // Compute a keyswitch followed by the bootstrap
+        server_key.keyswitch_programmable_bootstrap_assign(
             &mut ct.ct,
+            &mut ciphertext_buffers.buffer_lwe_after_ks,
             &ciphertext_buffers.accumulator.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         ct.degree = ciphertext_buffers.accumulator.degree;
@@ -251,40 +232,15 @@ impl ShortintEngine {
         ct: &mut CiphertextBig,
         acc: &LookupTableOwned,
     ) -> EngineResult<()> {
-        // Compute the programmable bootstrapping with fixed test polynomial
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        // Compute a key switch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ct.ct,
-            &mut ciphertext_buffers.buffer_lwe_after_ks,
-        );
-
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
-        // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
-            &ciphertext_buffers.buffer_lwe_after_ks,
+        // Compute a key switch followed by the bootstrap
+        server_key.keyswitch_programmable_bootstrap_assign(
             &mut ct.ct,
+            &mut ciphertext_buffers.buffer_lwe_after_ks,
             &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         ct.degree = acc.degree;
@@ -376,6 +332,10 @@ impl ShortintEngine {
     where
         F: Fn(u64, u64) -> u64,
     {
+        if self.fold_trivial_bivariate_operand(server_key, ct_left, ct_right, &f)? {
+            return Ok(());
+        }
+
         // Generate the accumulator for the function
         let factor = MessageModulus(ct_right.degree.0 + 1);
         let acc = self.generate_accumulator_bivariate_with_factor(server_key, f, factor)?;
@@ -410,6 +370,10 @@ impl ShortintEngine {
     where
         F: Fn(u64, u64) -> u64,
     {
+        if self.fold_trivial_bivariate_operand(server_key, ct_left, ct_right, &f)? {
+            return Ok(());
+        }
+
         // Generate the accumulator for the function
         let factor = MessageModulus(ct_right.degree.0 + 1);
         let acc = self.generate_accumulator_bivariate_with_factor(server_key, f, factor)?;
@@ -418,6 +382,40 @@ impl ShortintEngine {
         Ok(())
     }
 
+    /// When one of the operands is a trivial ciphertext, evaluate the bivariate function as a
+    /// lookup table of the other operand. This saves packing the operands in a single ciphertext,
+    /// and the carry cleaning this may require. Return `false` if no operand is trivial.
+    fn fold_trivial_bivariate_operand<OpOrder: PBSOrderMarker, F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        f: &F,
+    ) -> EngineResult<bool>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        // Same reduction of the operands as the bivariate accumulators
+        let message_modulus = server_key.message_modulus.0 as u64;
+
+        if let Some(rhs) = ct_right.decrypt_trivial() {
+            let rhs = rhs % message_modulus;
+            let acc = self.generate_accumulator(server_key, |x| f(x % message_modulus, rhs))?;
+            self.apply_lookup_table_assign(server_key, ct_left, &acc)?;
+            return Ok(true);
+        }
+
+        if let Some(lhs) = ct_left.decrypt_trivial() {
+            let lhs = lhs % message_modulus;
+            let acc = self.generate_accumulator(server_key, |x| f(lhs, x % message_modulus))?;
+            *ct_left = ct_right.clone();
+            self.apply_lookup_table_assign(server_key, ct_left, &acc)?;
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
     pub(crate) fn smart_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -457,32 +455,16 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        server_key.programmable_bootstrap(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
             &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         // Compute a key switch
+        crate::metrics::record_keyswitch(1);
         keyswitch_lwe_ciphertext(
             &server_key.key_switching_key,
             &ciphertext_buffers.buffer_lwe_after_pbs,
@@ -503,32 +485,16 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        server_key.programmable_bootstrap(
             &ct.ct,
             &mut ciphertext_buffers.buffer_lwe_after_pbs,
             &ciphertext_buffers.accumulator.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         // Compute a keyswitch
+        crate::metrics::record_keyswitch(1);
         keyswitch_lwe_ciphertext(
             &server_key.key_switching_key,
             &ciphertext_buffers.buffer_lwe_after_pbs,
@@ -540,12 +506,27 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn apply_lookup_table_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct);
+        self.apply_lookup_table_assign(server_key, ct_out, acc)
+    }
+
     pub(crate) fn apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
         acc: &LookupTableOwned,
     ) -> EngineResult<()> {
+        if fold_trivial_lookup_table(server_key, ct, acc) {
+            return Ok(());
+        }
+
         // We know the OpOrder corresponds to the CiphertextBig or CiphertextSmall and the memory
         // layout is the same as the type information is just encoded in a phantom data marker
         match OpOrder::pbs_order() {
@@ -564,6 +545,70 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn apply_lookup_table_with_mode_assign<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+        mode: PbsMode,
+    ) -> EngineResult<()> {
+        let level_count =
+            mode.level_count(server_key.bootstrapping_key.decomposition_level_count());
+        if level_count == server_key.bootstrapping_key.decomposition_level_count() {
+            return self.apply_lookup_table_assign(server_key, ct, acc);
+        }
+
+        if fold_trivial_lookup_table(server_key, ct, acc) {
+            return Ok(());
+        }
+
+        let (mut ciphertext_buffers, buffers) =
+            self.get_carry_clearing_accumulator_and_buffers(server_key);
+
+        match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                // Compute a key switch
+                crate::metrics::record_keyswitch(1);
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+
+                // Compute a bootstrap
+                server_key.approximate_programmable_bootstrap(
+                    &ciphertext_buffers.buffer_lwe_after_ks,
+                    &mut ct.ct,
+                    &acc.acc,
+                    level_count,
+                    buffers,
+                );
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                // Compute a bootstrap
+                server_key.approximate_programmable_bootstrap(
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                    &acc.acc,
+                    level_count,
+                    buffers,
+                );
+
+                // Compute a key switch
+                crate::metrics::record_keyswitch(1);
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ciphertext_buffers.buffer_lwe_after_pbs,
+                    &mut ct.ct,
+                );
+            }
+        }
+
+        ct.degree = acc.degree;
+
+        Ok(())
+    }
+
     pub(crate) fn apply_lookup_table<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -582,6 +627,14 @@ impl ShortintEngine {
         server_key: &ServerKey,
         ct: &mut CiphertextBase<OpOrder>,
     ) -> EngineResult<()> {
+        if ct.is_trivial() {
+            let modulus = server_key.message_modulus.0 as u64;
+            let acc = self.generate_accumulator(server_key, |x| x % modulus)?;
+            if fold_trivial_lookup_table(server_key, ct, &acc) {
+                return Ok(());
+            }
+        }
+
         // We know the OpOrder corresponds to the CiphertextBig or CiphertextSmall and the memory
         // layout is the same as the type information is just encoded in a phantom data marker
         match OpOrder::pbs_order() {
@@ -600,6 +653,16 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn carry_extract_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct);
+        self.carry_extract_assign(server_key, ct_out)
+    }
+
     pub(crate) fn carry_extract_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -624,6 +687,16 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn message_extract_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct);
+        self.message_extract_assign(server_key, ct_out)
+    }
+
     pub(crate) fn message_extract_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
@@ -713,3 +786,36 @@ impl ShortintEngine {
         Ok(())
     }
 }
+
+/// Evaluate a lookup table in the clear when `ct` is a trivial ciphertext, the result being a
+/// trivial ciphertext. Return `false`, leaving `ct` untouched, if the PBS has to be computed.
+fn fold_trivial_lookup_table<OpOrder: PBSOrderMarker>(
+    server_key: &ServerKey,
+    ct: &mut CiphertextBase<OpOrder>,
+    acc: &LookupTableOwned,
+) -> bool {
+    let full_modulus = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    if ct.message_modulus.0 * ct.carry_modulus.0 != full_modulus {
+        return false;
+    }
+    let value = match ct.trivial_encoded_value() {
+        Some(value) => value as usize,
+        None => return false,
+    };
+
+    // The PBS rotates the accumulator by the encoded value, reading the middle of the box of the
+    // value, which is the start of the box once the accumulator is rotated. The accumulator is
+    // negacyclic, values with the padding bit set read the opposite of the lower box.
+    let box_size = acc.acc.polynomial_size().0 / full_modulus;
+    let acc_body = acc.acc.get_body();
+    let acc_body = acc_body.as_ref();
+    let output = if value < full_modulus {
+        acc_body[value * box_size]
+    } else {
+        acc_body[(value - full_modulus) * box_size].wrapping_neg()
+    };
+
+    *ct.ct.get_mut_body().data = output;
+    ct.degree = acc.degree;
+    true
+}
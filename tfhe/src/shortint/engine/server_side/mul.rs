@@ -14,6 +14,17 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn unchecked_mul_lsb_into<OpOrder: PBSOrderMarker>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) -> EngineResult<()> {
+        ct_out.clone_from(ct_left);
+        self.unchecked_mul_lsb_assign(server_key, ct_out, ct_right)
+    }
+
     pub(crate) fn unchecked_mul_lsb_assign<OpOrder: PBSOrderMarker>(
         &mut self,
         server_key: &ServerKey,
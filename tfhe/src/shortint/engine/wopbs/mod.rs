@@ -7,7 +7,8 @@ use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapK
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::engine::{EngineResult, ShortintEngine};
-use crate::shortint::server_key::MaxDegree;
+use crate::shortint::server_key::backend::ServerKeyBackend;
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
 use crate::shortint::wopbs::WopbsKey;
 use crate::shortint::{CiphertextBase, ClientKey, PBSOrderMarker, Parameters, ServerKey};
 
@@ -30,8 +31,16 @@ impl ShortintEngine {
 
         let sks_cpy = sks.clone();
 
+        // The WoPBS needs the whole bootstrapping key in the Fourier domain
+        let wopbs_server_key = ServerKey {
+            bootstrapping_key: ShortintBootstrappingKey::Classic(
+                sks.bootstrapping_key.clone().into_fourier(),
+            ),
+            ..sks.clone()
+        };
+
         let wopbs_key = WopbsKey {
-            wopbs_server_key: sks_cpy.clone(),
+            wopbs_server_key,
             cbs_pfpksk,
             ksk_pbs_to_wopbs: sks.key_switching_key.clone(),
             param: cks.parameters,
@@ -68,7 +77,7 @@ impl ShortintEngine {
                 &glwe_secret_key,
                 parameters.pbs_base_log,
                 parameters.pbs_level,
-                parameters.glwe_modular_std_dev,
+                parameters.glwe_noise_distribution(),
                 parameters.ciphertext_modulus,
                 &mut self.encryption_generator,
             );
@@ -105,7 +114,7 @@ impl ShortintEngine {
             &small_lwe_secret_key,
             parameters.ks_base_log,
             parameters.ks_level,
-            parameters.lwe_modular_std_dev,
+            parameters.lwe_noise_distribution(),
             parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -116,7 +125,7 @@ impl ShortintEngine {
             &large_lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            parameters.lwe_modular_std_dev,
+            parameters.lwe_noise_distribution(),
             parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -128,7 +137,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution(),
             parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -145,11 +154,12 @@ impl ShortintEngine {
 
         let wopbs_server_key = ServerKey {
             key_switching_key: ksk_wopbs_large_to_wopbs_small,
-            bootstrapping_key: small_bsk,
+            bootstrapping_key: ShortintBootstrappingKey::Classic(small_bsk),
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
             max_degree: MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1),
             ciphertext_modulus: parameters.ciphertext_modulus,
+            backend: ServerKeyBackend::default(),
         };
 
         let pbs_server_key = ServerKey {
@@ -161,6 +171,7 @@ impl ShortintEngine {
                 cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0 - 1,
             ),
             ciphertext_modulus: cks.parameters.ciphertext_modulus,
+            backend: sks.backend.clone(),
         };
 
         let wopbs_key = WopbsKey {
@@ -217,7 +228,10 @@ impl ShortintEngine {
     {
         let server_key = &wopbs_key.wopbs_server_key;
 
-        let bsk = &server_key.bootstrapping_key;
+        let bsk = server_key
+            .bootstrapping_key
+            .as_fourier()
+            .expect("WoPBS keys are built with a bootstrapping key in the Fourier domain");
         let ksk = &server_key.key_switching_key;
 
         let fft = Fft::new(bsk.polynomial_size());
@@ -260,7 +274,10 @@ impl ShortintEngine {
         InputCont: Container<Element = u64>,
     {
         let sks = &wopbs_key.wopbs_server_key;
-        let fourier_bsk = &sks.bootstrapping_key;
+        let fourier_bsk = sks
+            .bootstrapping_key
+            .as_fourier()
+            .expect("WoPBS keys are built with a bootstrapping key in the Fourier domain");
 
         let output_lwe_size = fourier_bsk.output_lwe_dimension().to_lwe_size();
 
@@ -296,7 +313,7 @@ impl ShortintEngine {
             extracted_bits,
             &mut output_cbs_vp_ct,
             &lut,
-            &sks.bootstrapping_key,
+            fourier_bsk,
             &wopbs_key.cbs_pfpksk,
             wopbs_key.param.cbs_base_log,
             wopbs_key.param.cbs_level,
@@ -390,6 +407,7 @@ impl ShortintEngine {
         );
 
         // Compute a key switch
+        crate::metrics::record_keyswitch(1);
         keyswitch_lwe_ciphertext(
             &wopbs_key.ksk_pbs_to_wopbs,
             &ct_clean.ct,
@@ -421,39 +439,25 @@ impl ShortintEngine {
         let (mut ciphertext_buffers, buffers) =
             self.get_carry_clearing_accumulator_and_buffers(&wopbs_key.pbs_server_key);
         // Compute a key switch
+        crate::metrics::record_keyswitch(1);
         keyswitch_lwe_ciphertext(
             &wopbs_key.pbs_server_key.key_switching_key,
             &ct_in.ct,
             &mut ciphertext_buffers.buffer_lwe_after_ks,
         );
 
-        let fourier_bsk = &wopbs_key.pbs_server_key.bootstrapping_key;
+        let bootstrapping_key = &wopbs_key.pbs_server_key.bootstrapping_key;
 
-        let out_lwe_size = fourier_bsk.output_lwe_dimension().to_lwe_size();
+        let out_lwe_size = bootstrapping_key.output_lwe_dimension().to_lwe_size();
         let mut ct_out =
             LweCiphertextOwned::new(0, out_lwe_size, wopbs_key.param.ciphertext_modulus);
 
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-        buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        wopbs_key.pbs_server_key.programmable_bootstrap(
             &ciphertext_buffers.buffer_lwe_after_ks,
             &mut ct_out,
             &acc.acc,
-            fourier_bsk,
-            fft,
-            stack,
+            buffers,
         );
 
         Ok(CiphertextBase {
@@ -0,0 +1,127 @@
+//! Module with the definition of the key used to move ciphertexts to a new client key.
+//!
+//! Rotating the secret key periodically limits the amount of data encrypted under a single key.
+//! With a [`KeyRotationKey`], generated by the client from its current and new [`ClientKey`], the
+//! server migrates the ciphertexts it stores to the new key by keyswitching them, without the
+//! client having to download, decrypt and encrypt them again.
+//!
+//! The keyswitch adds noise to the migrated ciphertexts, a PBS with the server key of the new
+//! client key (e.g. [`ServerKey::message_extract`](`crate::shortint::ServerKey::message_extract`))
+//! brings it back to the noise of a freshly bootstrapped ciphertext.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::{ClientKey, ServerKey};
+//!
+//! let old_cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let mut stored = [0, 1, 2, 3].map(|msg| old_cks.encrypt(msg));
+//!
+//! // Client side: generate the new keys
+//! let (new_cks, rotation_key) = old_cks.rotate();
+//! let new_sks = ServerKey::new(&new_cks);
+//!
+//! // Server side: migrate the stored ciphertexts
+//! rotation_key.migrate_ciphertexts(&mut stored);
+//!
+//! for (msg, ct) in stored.iter().enumerate() {
+//!     let ct = new_sks.message_extract(ct);
+//!     assert_eq!(new_cks.decrypt(&ct), msg as u64);
+//! }
+//! ```
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::{CiphertextBase, ClientKey, PBSOrder, PBSOrderMarker};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A key moving ciphertexts from a client key to another one with the same parameters.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyRotationKey {
+    /// Keyswitching key from the old large LWE key to the new one, for the ciphertexts in the
+    /// [`PBSOrder::KeyswitchBootstrap`] order
+    pub(crate) large_key_switching_key: LweKeyswitchKeyOwned<u64>,
+    /// Keyswitching key from the old small LWE key to the new one, for the ciphertexts in the
+    /// [`PBSOrder::BootstrapKeyswitch`] order
+    pub(crate) small_key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) ciphertext_modulus: CiphertextModulus,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for KeyRotationKey {
+    large_key_switching_key,
+    small_key_switching_key
+});
+
+impl ClientKey {
+    /// Generate a new client key with the same parameters, and the key to move the ciphertexts
+    /// encrypted under this client key to the new one.
+    pub fn rotate(&self) -> (ClientKey, KeyRotationKey) {
+        let new_key = ClientKey::new(self.parameters);
+        let rotation_key = self.new_key_rotation_key(&new_key);
+        (new_key, rotation_key)
+    }
+
+    /// Generate the key to move the ciphertexts encrypted under this client key to `new_key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys do not have the same parameters.
+    pub fn new_key_rotation_key(&self, new_key: &ClientKey) -> KeyRotationKey {
+        assert_eq!(
+            self.parameters, new_key.parameters,
+            "The keys of a rotation must have the same parameters"
+        );
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_rotation_key(self, new_key).unwrap()
+        })
+    }
+}
+
+impl KeyRotationKey {
+    /// Move a ciphertext encrypted under the old client key to the new client key.
+    ///
+    /// The degree of the ciphertext is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext does not have the message and carry moduli of the keys.
+    pub fn migrate_ciphertext<OpOrder: PBSOrderMarker>(&self, ct: &mut CiphertextBase<OpOrder>) {
+        assert_eq!(ct.message_modulus, self.message_modulus);
+        assert_eq!(ct.carry_modulus, self.carry_modulus);
+
+        let key_switching_key = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => &self.large_key_switching_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_key_switching_key,
+        };
+
+        let mut ct_out = LweCiphertext::new(
+            0u64,
+            key_switching_key.output_lwe_size(),
+            self.ciphertext_modulus,
+        );
+        crate::metrics::record_keyswitch(1);
+        keyswitch_lwe_ciphertext(key_switching_key, &ct.ct, &mut ct_out);
+        ct.ct = ct_out;
+    }
+
+    /// Move ciphertexts encrypted under the old client key to the new client key, in parallel.
+    ///
+    /// The degrees of the ciphertexts are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a ciphertext does not have the message and carry moduli of the keys.
+    pub fn migrate_ciphertexts<OpOrder: PBSOrderMarker>(
+        &self,
+        cts: &mut [CiphertextBase<OpOrder>],
+    ) {
+        cts.par_iter_mut()
+            .for_each(|ct| self.migrate_ciphertext(ct));
+    }
+}
@@ -257,6 +257,8 @@ impl NamedParam for Parameters {
                 PARAM_SMALL_MESSAGE_2_CARRY_2,
                 PARAM_SMALL_MESSAGE_3_CARRY_3,
                 PARAM_SMALL_MESSAGE_4_CARRY_4,
+                // Approximate PBS
+                PARAM_MESSAGE_2_CARRY_2_APPROX_PBS,
                 // Wops
                 WOPBS_PARAM_MESSAGE_1_NORM2_2,
                 WOPBS_PARAM_MESSAGE_1_NORM2_4,
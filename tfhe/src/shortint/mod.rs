@@ -48,25 +48,35 @@
 //! ```
 pub mod ciphertext;
 pub mod client_key;
+pub mod debug;
 pub mod engine;
+pub mod key_rotation;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+#[cfg(feature = "experimental-multi-key")]
+pub mod multi_key;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
+#[cfg(feature = "sanitizer")]
+pub mod sanitizer;
 pub mod server_key;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 #[cfg(not(feature = "__wasm_api"))]
 pub mod wopbs;
 
 pub use ciphertext::{
-    CiphertextBase, CiphertextBig, CiphertextSmall, CompressedCiphertextBase,
-    CompressedCiphertextBig, CompressedCiphertextSmall, PBSOrder, PBSOrderMarker,
+    CiphertextBase, CiphertextBig, CiphertextSmall, CompactCiphertextList,
+    CompressedCiphertextBase, CompressedCiphertextBatch, CompressedCiphertextBig,
+    CompressedCiphertextSmall, PBSOrder, PBSOrderMarker,
 };
 pub use client_key::ClientKey;
+pub use key_rotation::KeyRotationKey;
 pub use parameters::{CarryModulus, CiphertextModulus, MessageModulus, Parameters};
 pub use public_key::{
-    CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall, PublicKeyBase,
-    PublicKeyBig, PublicKeySmall,
+    CompactPublicKey, CompressedPublicKeyBase, CompressedPublicKeyBig, CompressedPublicKeySmall,
+    PublicKeyBase, PublicKeyBig, PublicKeySmall,
 };
 pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 
@@ -89,3 +99,41 @@ pub fn gen_keys(parameters_set: Parameters) -> (ClientKey, ServerKey) {
 
     (cks, sks)
 }
+
+/// Generate `count` couples of client and server keys in parallel, e.g. to enroll many clients at
+/// once.
+///
+/// The parameters are validated once for the whole batch (see [`Parameters::validate`]), and the
+/// FFT plan used to convert the bootstrapping keys to the Fourier domain is computed once before
+/// the generations start, so that it is shared by all of them.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys_batch;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+///
+/// let keys = gen_keys_batch(PARAM_MESSAGE_2_CARRY_2, 2).unwrap();
+/// assert_eq!(keys.len(), 2);
+///
+/// let (cks, sks) = &keys[1];
+/// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(2));
+/// assert_eq!(cks.decrypt(&ct), 3);
+/// ```
+pub fn gen_keys_batch(
+    parameters_set: Parameters,
+    count: usize,
+) -> Result<Vec<(ClientKey, ServerKey)>, parameters::ParametersError> {
+    use rayon::prelude::*;
+
+    parameters_set.validate()?;
+
+    // Plans are cached globally, computing it here avoids all the generations racing to build it
+    let _ =
+        crate::core_crypto::fft_impl::fft64::math::fft::Fft::new(parameters_set.polynomial_size);
+
+    Ok((0..count)
+        .into_par_iter()
+        .map(|_| gen_keys(parameters_set))
+        .collect())
+}
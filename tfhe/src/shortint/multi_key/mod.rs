@@ -0,0 +1,444 @@
+//! Experimental multi-key mode.
+//!
+//! In this mode, several parties each own a regular [`ClientKey`] generated with the same
+//! [`Parameters`]. Ciphertexts encrypted by different parties are first *extended* into
+//! [`MultiKeyCiphertext`]s, which are LWE ciphertexts under the concatenation of the secret keys
+//! of a set of parties, and can then be combined with linear operations.
+//!
+//! Decrypting a [`MultiKeyCiphertext`] requires every party involved in it: each party computes a
+//! [`PartialDecryption`] with its own key, and the partial decryptions are then combined into the
+//! message.
+//!
+//! This is a slow reference implementation meant for research purposes: no programmable
+//! bootstrapping is available on [`MultiKeyCiphertext`]s, so the noise and the degree grow with
+//! every operation, and the smudging noise added to partial decryptions is not chosen to provide
+//! any formal security guarantee.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::multi_key::{PartyId, PartyKey};
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//!
+//! let alice = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(0));
+//! let bob = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(1));
+//!
+//! let ct_alice = alice.encrypt(2);
+//! let ct_bob = bob.encrypt(1);
+//!
+//! // Ciphertexts under different keys are extended to the union of the parties
+//! let ct_sum = ct_alice.add(&ct_bob).unwrap();
+//! assert_eq!(ct_sum.parties(), &[PartyId(0), PartyId(1)]);
+//!
+//! // All the parties are needed to decrypt the result
+//! let partials = [
+//!     alice.partial_decrypt(&ct_sum).unwrap(),
+//!     bob.partial_decrypt(&ct_sum).unwrap(),
+//! ];
+//! assert_eq!(ct_sum.combine_decryptions(&partials).unwrap(), 3);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::{LweDimension, LweSize};
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::{CiphertextBase, ClientKey, PBSOrder, PBSOrderMarker, Parameters};
+use serde::{Deserialize, Serialize};
+
+/// Identifier of a party taking part in a multi-key computation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PartyId(pub usize);
+
+/// Error returned by the operations of the multi-key mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiKeyError {
+    /// The ciphertexts do not use the same parameters or PBS order.
+    MismatchedParameters,
+    /// The result of the operation would exceed the capacity of the ciphertext.
+    CarryFull,
+    /// The party is not involved in the ciphertext.
+    UnknownParty(PartyId),
+    /// The partial decryption of the party is missing.
+    MissingPartialDecryption(PartyId),
+    /// The parties given to extend a ciphertext do not contain all its current parties.
+    MissingParty(PartyId),
+}
+
+impl std::fmt::Display for MultiKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MismatchedParameters => {
+                write!(f, "The ciphertexts do not have the same parameters")
+            }
+            Self::CarryFull => write!(f, "The carry buffer is full"),
+            Self::UnknownParty(id) => {
+                write!(f, "Party {} is not involved in the ciphertext", id.0)
+            }
+            Self::MissingPartialDecryption(id) => {
+                write!(f, "The partial decryption of party {} is missing", id.0)
+            }
+            Self::MissingParty(id) => write!(
+                f,
+                "Party {} of the ciphertext is missing from the extension",
+                id.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MultiKeyError {}
+
+/// The key of a party taking part in a multi-key computation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartyKey {
+    pub(crate) client_key: ClientKey,
+    pub(crate) id: PartyId,
+}
+
+impl PartyKey {
+    /// Generate a new key for the party `id`.
+    pub fn new(parameters: Parameters, id: PartyId) -> Self {
+        Self::from_client_key(ClientKey::new(parameters), id)
+    }
+
+    /// Use an existing [`ClientKey`] as the key of the party `id`.
+    pub fn from_client_key(client_key: ClientKey, id: PartyId) -> Self {
+        Self { client_key, id }
+    }
+
+    pub fn id(&self) -> PartyId {
+        self.id
+    }
+
+    pub fn client_key(&self) -> &ClientKey {
+        &self.client_key
+    }
+
+    /// Encrypt a message with the key of the party and extend it to a [`MultiKeyCiphertext`].
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    pub fn encrypt(&self, message: u64) -> MultiKeyCiphertext {
+        MultiKeyCiphertext::extend(&self.client_key.encrypt(message), self.id)
+    }
+
+    /// Encrypt a message with the small key of the party and extend it to a
+    /// [`MultiKeyCiphertext`].
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    pub fn encrypt_small(&self, message: u64) -> MultiKeyCiphertext {
+        MultiKeyCiphertext::extend(&self.client_key.encrypt_small(message), self.id)
+    }
+
+    /// Compute the share of the party in the decryption of a [`MultiKeyCiphertext`].
+    ///
+    /// The inner product of the mask of the party with its secret key is hidden by a fresh noise.
+    pub fn partial_decrypt(
+        &self,
+        ct: &MultiKeyCiphertext,
+    ) -> Result<PartialDecryption, MultiKeyError> {
+        let index = ct.party_index(self.id)?;
+
+        let (lwe_secret_key, noise_parameter) = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => (
+                &self.client_key.large_lwe_secret_key,
+                self.client_key.parameters.glwe_noise_distribution(),
+            ),
+            PBSOrder::BootstrapKeyswitch => (
+                &self.client_key.small_lwe_secret_key,
+                self.client_key.parameters.lwe_noise_distribution(),
+            ),
+        };
+        if lwe_secret_key.lwe_dimension() != ct.party_lwe_dimension {
+            return Err(MultiKeyError::MismatchedParameters);
+        }
+
+        let n = ct.party_lwe_dimension.0;
+        let mask = ct.ct.get_mask();
+        let party_mask = &mask.as_ref()[index * n..(index + 1) * n];
+
+        let inner_product = party_mask
+            .iter()
+            .zip(lwe_secret_key.as_ref().iter())
+            .fold(0u64, |acc, (&mask, &key)| {
+                acc.wrapping_add(mask.wrapping_mul(key))
+            });
+
+        let smudging_noise: u64 = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.sample_noise(noise_parameter).unwrap()
+        });
+
+        Ok(PartialDecryption {
+            party: self.id,
+            value: inner_product.wrapping_add(smudging_noise),
+        })
+    }
+}
+
+/// The share of a party in the decryption of a [`MultiKeyCiphertext`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryption {
+    pub(crate) party: PartyId,
+    pub(crate) value: u64,
+}
+
+impl PartialDecryption {
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+}
+
+/// An LWE ciphertext under the concatenation of the secret keys of several parties.
+///
+/// The mask is made of one chunk per party, the parties being sorted by [`PartyId`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiKeyCiphertext {
+    pub(crate) ct: LweCiphertextOwned<u64>,
+    pub(crate) parties: Vec<PartyId>,
+    pub(crate) party_lwe_dimension: LweDimension,
+    pub(crate) pbs_order: PBSOrder,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+impl MultiKeyCiphertext {
+    /// Extend a ciphertext encrypted by the party `party` to a [`MultiKeyCiphertext`].
+    pub fn extend<OpOrder: PBSOrderMarker>(ct: &CiphertextBase<OpOrder>, party: PartyId) -> Self {
+        Self {
+            ct: ct.ct.clone(),
+            parties: vec![party],
+            party_lwe_dimension: ct.ct.lwe_size().to_lwe_dimension(),
+            pbs_order: OpOrder::pbs_order(),
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+
+    /// Return the sorted list of the parties whose keys are needed to decrypt the ciphertext.
+    pub fn parties(&self) -> &[PartyId] {
+        &self.parties
+    }
+
+    /// Extend the ciphertext to a larger set of parties.
+    ///
+    /// The chunks of the mask of the new parties are filled with zeros, `parties` must contain all
+    /// the current parties of the ciphertext.
+    pub fn extend_to(&self, parties: &[PartyId]) -> Result<Self, MultiKeyError> {
+        let mut parties = parties.to_vec();
+        parties.sort_unstable();
+        parties.dedup();
+
+        if let Some(&missing) = self.parties.iter().find(|id| !parties.contains(id)) {
+            return Err(MultiKeyError::MissingParty(missing));
+        }
+
+        let n = self.party_lwe_dimension.0;
+        let mut ct = LweCiphertextOwned::new(
+            0u64,
+            LweSize(parties.len() * n + 1),
+            self.ct.ciphertext_modulus(),
+        );
+
+        let input_mask = self.ct.get_mask();
+        let (mut output_mask, output_body) = ct.get_mut_mask_and_body();
+        for (output_chunk, id) in output_mask.as_mut().chunks_exact_mut(n).zip(parties.iter()) {
+            if let Ok(index) = self.party_index(*id) {
+                output_chunk.copy_from_slice(&input_mask.as_ref()[index * n..(index + 1) * n]);
+            }
+        }
+        *output_body.data = *self.ct.get_body().data;
+
+        Ok(Self {
+            ct,
+            parties,
+            party_lwe_dimension: self.party_lwe_dimension,
+            pbs_order: self.pbs_order,
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        })
+    }
+
+    /// Compute homomorphically an addition between two ciphertexts, extending them to the union
+    /// of their parties.
+    ///
+    /// The degree of the result is not checked.
+    pub fn unchecked_add(&self, other: &Self) -> Result<Self, MultiKeyError> {
+        let (mut lhs, rhs) = self.extend_pair(other)?;
+        lwe_ciphertext_add_assign(&mut lhs.ct, &rhs.ct);
+        lhs.degree = Degree(lhs.degree.0 + rhs.degree.0);
+        Ok(lhs)
+    }
+
+    /// Compute homomorphically an addition between two ciphertexts, extending them to the union
+    /// of their parties.
+    ///
+    /// Returns [`MultiKeyError::CarryFull`] if the result could exceed the capacity of the
+    /// ciphertext.
+    pub fn add(&self, other: &Self) -> Result<Self, MultiKeyError> {
+        self.check_degree(self.degree.0 + other.degree.0)?;
+        self.unchecked_add(other)
+    }
+
+    /// Compute homomorphically a subtraction between two ciphertexts, extending them to the union
+    /// of their parties.
+    ///
+    /// The degree of the result is not checked.
+    pub fn unchecked_sub(&self, other: &Self) -> Result<Self, MultiKeyError> {
+        let (mut lhs, mut rhs) = self.extend_pair(other)?;
+
+        // z = ceil( degree / 2^p ) * 2^p
+        let msg_mod = rhs.message_modulus.0;
+        let z = ((rhs.degree.0 + msg_mod - 1) / msg_mod) * msg_mod;
+
+        // (0, Delta * z) - rhs
+        lwe_ciphertext_opposite_assign(&mut rhs.ct);
+        lwe_ciphertext_plaintext_add_assign(&mut rhs.ct, Plaintext(z as u64 * self.delta()));
+
+        lwe_ciphertext_add_assign(&mut lhs.ct, &rhs.ct);
+        lhs.degree = Degree(lhs.degree.0 + z);
+        Ok(lhs)
+    }
+
+    /// Compute homomorphically a subtraction between two ciphertexts, extending them to the union
+    /// of their parties.
+    ///
+    /// Returns [`MultiKeyError::CarryFull`] if the result could exceed the capacity of the
+    /// ciphertext.
+    pub fn sub(&self, other: &Self) -> Result<Self, MultiKeyError> {
+        let msg_mod = other.message_modulus.0;
+        let z = ((other.degree.0 + msg_mod - 1) / msg_mod) * msg_mod;
+        self.check_degree(self.degree.0 + z)?;
+        self.unchecked_sub(other)
+    }
+
+    /// Compute homomorphically an addition between a ciphertext and a clear scalar.
+    ///
+    /// The degree of the result is not checked.
+    pub fn unchecked_scalar_add(&self, scalar: u8) -> Self {
+        let mut result = self.clone();
+        lwe_ciphertext_plaintext_add_assign(
+            &mut result.ct,
+            Plaintext(scalar as u64 * self.delta()),
+        );
+        result.degree = Degree(self.degree.0 + scalar as usize);
+        result
+    }
+
+    /// Compute homomorphically an addition between a ciphertext and a clear scalar.
+    ///
+    /// Returns [`MultiKeyError::CarryFull`] if the result could exceed the capacity of the
+    /// ciphertext.
+    pub fn scalar_add(&self, scalar: u8) -> Result<Self, MultiKeyError> {
+        self.check_degree(self.degree.0 + scalar as usize)?;
+        Ok(self.unchecked_scalar_add(scalar))
+    }
+
+    /// Compute homomorphically a multiplication between a ciphertext and a clear scalar.
+    ///
+    /// The degree of the result is not checked.
+    pub fn unchecked_scalar_mul(&self, scalar: u8) -> Self {
+        let mut result = self.clone();
+        lwe_ciphertext_cleartext_mul_assign(&mut result.ct, Cleartext(scalar as u64));
+        result.degree = Degree(self.degree.0 * scalar as usize);
+        result
+    }
+
+    /// Compute homomorphically a multiplication between a ciphertext and a clear scalar.
+    ///
+    /// Returns [`MultiKeyError::CarryFull`] if the result could exceed the capacity of the
+    /// ciphertext.
+    pub fn scalar_mul(&self, scalar: u8) -> Result<Self, MultiKeyError> {
+        self.check_degree(self.degree.0 * scalar as usize)?;
+        Ok(self.unchecked_scalar_mul(scalar))
+    }
+
+    /// Combine the partial decryptions of all the parties of the ciphertext into the message and
+    /// carry it encrypts.
+    pub fn combine_decryptions_message_and_carry(
+        &self,
+        partial_decryptions: &[PartialDecryption],
+    ) -> Result<u64, MultiKeyError> {
+        if let Some(partial) = partial_decryptions
+            .iter()
+            .find(|partial| !self.parties.contains(&partial.party))
+        {
+            return Err(MultiKeyError::UnknownParty(partial.party));
+        }
+
+        let mut decrypted = *self.ct.get_body().data;
+        for &id in self.parties.iter() {
+            let partial = partial_decryptions
+                .iter()
+                .find(|partial| partial.party == id)
+                .ok_or(MultiKeyError::MissingPartialDecryption(id))?;
+            decrypted = decrypted.wrapping_sub(partial.value);
+        }
+
+        let delta = self.delta();
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (decrypted & rounding_bit) << 1;
+
+        Ok(decrypted.wrapping_add(rounding) / delta)
+    }
+
+    /// Combine the partial decryptions of all the parties of the ciphertext into the message it
+    /// encrypts.
+    pub fn combine_decryptions(
+        &self,
+        partial_decryptions: &[PartialDecryption],
+    ) -> Result<u64, MultiKeyError> {
+        self.combine_decryptions_message_and_carry(partial_decryptions)
+            .map(|message_and_carry| message_and_carry % self.message_modulus.0 as u64)
+    }
+
+    fn party_index(&self, id: PartyId) -> Result<usize, MultiKeyError> {
+        self.parties
+            .binary_search(&id)
+            .map_err(|_| MultiKeyError::UnknownParty(id))
+    }
+
+    fn delta(&self) -> u64 {
+        (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64
+    }
+
+    fn check_degree(&self, degree: usize) -> Result<(), MultiKeyError> {
+        let max_degree = self.message_modulus.0 * self.carry_modulus.0 - 1;
+        if degree > max_degree {
+            Err(MultiKeyError::CarryFull)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Extend both ciphertexts to the union of their parties.
+    fn extend_pair(&self, other: &Self) -> Result<(Self, Self), MultiKeyError> {
+        if self.pbs_order != other.pbs_order
+            || self.party_lwe_dimension != other.party_lwe_dimension
+            || self.message_modulus != other.message_modulus
+            || self.carry_modulus != other.carry_modulus
+            || self.ct.ciphertext_modulus() != other.ct.ciphertext_modulus()
+        {
+            return Err(MultiKeyError::MismatchedParameters);
+        }
+
+        let parties = self
+            .parties
+            .iter()
+            .chain(other.parties.iter())
+            .copied()
+            .collect::<Vec<_>>();
+
+        Ok((self.extend_to(&parties)?, other.extend_to(&parties)?))
+    }
+}
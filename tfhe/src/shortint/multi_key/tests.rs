@@ -0,0 +1,143 @@
+use rand::Rng;
+
+use super::*;
+use crate::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+
+const NB_TEST: usize = 10;
+const NB_PARTIES: usize = 3;
+
+fn decrypt_with_all_parties(parties: &[PartyKey], ct: &MultiKeyCiphertext) -> u64 {
+    let partials = parties
+        .iter()
+        .filter(|party| ct.parties().contains(&party.id()))
+        .map(|party| party.partial_decrypt(ct).unwrap())
+        .collect::<Vec<_>>();
+    ct.combine_decryptions(&partials).unwrap()
+}
+
+fn multi_key_linear_operations(param: Parameters, small: bool) {
+    let parties = (0..NB_PARTIES)
+        .map(|i| PartyKey::new(param, PartyId(i)))
+        .collect::<Vec<_>>();
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clears = (0..NB_PARTIES)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let cts = parties
+            .iter()
+            .zip(clears.iter())
+            .map(|(party, &clear)| {
+                if small {
+                    party.encrypt_small(clear)
+                } else {
+                    party.encrypt(clear)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for (ct, &clear) in cts.iter().zip(clears.iter()) {
+            assert_eq!(decrypt_with_all_parties(&parties, ct), clear);
+        }
+
+        let ct_add = cts[0].add(&cts[1]).unwrap();
+        assert_eq!(ct_add.parties(), &[PartyId(0), PartyId(1)]);
+        assert_eq!(
+            decrypt_with_all_parties(&parties, &ct_add),
+            (clears[0] + clears[1]) % modulus
+        );
+
+        let ct_sub = cts[2].sub(&ct_add).unwrap();
+        assert_eq!(ct_sub.parties(), &[PartyId(0), PartyId(1), PartyId(2)]);
+        assert_eq!(
+            decrypt_with_all_parties(&parties, &ct_sub),
+            (clears[2] + 2 * modulus - clears[0] - clears[1]) % modulus
+        );
+
+        let scalar = rng.gen::<u8>() % modulus as u8;
+        let ct_scalar = cts[2].unchecked_scalar_mul(scalar).unchecked_scalar_add(1);
+        assert_eq!(
+            decrypt_with_all_parties(&parties, &ct_scalar),
+            (clears[2] * scalar as u64 + 1) % modulus
+        );
+    }
+}
+
+#[test]
+fn shortint_multi_key_linear_operations() {
+    multi_key_linear_operations(PARAM_MESSAGE_2_CARRY_2, false);
+}
+
+#[test]
+fn shortint_multi_key_linear_operations_small() {
+    multi_key_linear_operations(PARAM_SMALL_MESSAGE_2_CARRY_2, true);
+}
+
+#[test]
+fn shortint_multi_key_decryption_requires_all_parties() {
+    let alice = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(0));
+    let bob = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(1));
+    let carol = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(2));
+
+    let ct = alice.encrypt(1).add(&bob.encrypt(2)).unwrap();
+
+    let alice_partial = alice.partial_decrypt(&ct).unwrap();
+    assert_eq!(
+        ct.combine_decryptions(&[alice_partial]),
+        Err(MultiKeyError::MissingPartialDecryption(PartyId(1)))
+    );
+    assert_eq!(
+        carol.partial_decrypt(&ct),
+        Err(MultiKeyError::UnknownParty(PartyId(2)))
+    );
+
+    let bob_partial = bob.partial_decrypt(&ct).unwrap();
+    assert_eq!(ct.combine_decryptions(&[bob_partial, alice_partial]), Ok(3));
+}
+
+#[test]
+fn shortint_multi_key_extend_to() {
+    let alice = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(0));
+    let bob = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(1));
+
+    let ct = bob.encrypt(3);
+    let extended = ct.extend_to(&[PartyId(1), PartyId(0)]).unwrap();
+    assert_eq!(extended.parties(), &[PartyId(0), PartyId(1)]);
+
+    let partials = [
+        alice.partial_decrypt(&extended).unwrap(),
+        bob.partial_decrypt(&extended).unwrap(),
+    ];
+    assert_eq!(extended.combine_decryptions(&partials), Ok(3));
+
+    assert_eq!(
+        extended.extend_to(&[PartyId(1)]).unwrap_err(),
+        MultiKeyError::MissingParty(PartyId(0))
+    );
+}
+
+#[test]
+fn shortint_multi_key_carry_full() {
+    let alice = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(0));
+    let bob = PartyKey::new(PARAM_MESSAGE_2_CARRY_2, PartyId(1));
+
+    let mut ct = alice.encrypt(3);
+    // Max degree is 15, each addition adds 3
+    for _ in 0..4 {
+        ct = ct.add(&bob.encrypt(3)).unwrap();
+    }
+    assert_eq!(ct.degree, Degree(15));
+    assert_eq!(
+        ct.add(&bob.encrypt(3)).unwrap_err(),
+        MultiKeyError::CarryFull
+    );
+
+    let mismatched = alice.encrypt_small(1);
+    assert_eq!(
+        ct.unchecked_add(&mismatched).unwrap_err(),
+        MultiKeyError::MismatchedParameters
+    );
+}
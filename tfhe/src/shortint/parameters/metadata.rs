@@ -0,0 +1,272 @@
+//! Design targets of the parameter sets provided by the library.
+//!
+//! Each parameter set exported by [`crate::shortint::parameters`] was optimized for a security
+//! level, a failure probability per programmable bootstrapping and a noise budget, i.e. the
+//! largest amount of noise a ciphertext can accumulate between two bootstraps while keeping that
+//! failure probability.
+//!
+//! The noise is measured with the noise level: a freshly encrypted or bootstrapped ciphertext has
+//! a noise level of 1, the noise level of a linear combination of ciphertexts is the sum of the
+//! noise levels of the ciphertexts weighted by the square of their coefficients.
+use super::registry::ParametersError;
+use super::*;
+
+/// The design targets of a parameter set.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ParametersMetadata {
+    /// Base 2 logarithm of the failure probability of a programmable bootstrapping, for an input
+    /// within the noise budget.
+    pub log2_p_fail: f64,
+    /// Security level in bits, estimated with the lattice estimator.
+    pub security_level: u32,
+    /// Largest noise level a ciphertext can have when it is bootstrapped.
+    pub max_noise_level: u64,
+}
+
+/// Failure probability targeted by all the parameter sets of the library.
+pub const DEFAULT_LOG2_P_FAIL: f64 = -40.0;
+/// Security level targeted by all the parameter sets of the library.
+pub const DEFAULT_SECURITY_LEVEL: u32 = 128;
+
+impl ParametersMetadata {
+    /// Metadata of a parameter set whose noise budget allows to fill the carry space, i.e. to sum
+    /// `carry_modulus + 1` ciphertexts.
+    pub const fn with_carry_space_budget(params: &Parameters) -> Self {
+        Self {
+            log2_p_fail: DEFAULT_LOG2_P_FAIL,
+            security_level: DEFAULT_SECURITY_LEVEL,
+            max_noise_level: params.carry_modulus.0 as u64 + 1,
+        }
+    }
+
+    /// Metadata of a parameter set optimized for a given squared 2-norm, like the
+    /// `WOPBS_PARAM_MESSAGE_X_NORM2_Y` sets.
+    pub const fn with_norm2_budget(norm2: u64) -> Self {
+        Self {
+            log2_p_fail: DEFAULT_LOG2_P_FAIL,
+            security_level: DEFAULT_SECURITY_LEVEL,
+            max_noise_level: norm2,
+        }
+    }
+
+    /// Failure probability of a programmable bootstrapping.
+    pub fn p_fail(&self) -> f64 {
+        self.log2_p_fail.exp2()
+    }
+}
+
+macro_rules! norm2_metadata {
+    ($(($name:ident, $norm2:expr)),* $(,)?) => {
+        /// Metadata of the parameter sets whose noise budget is not tied to their carry space.
+        ///
+        /// The other parameter sets which can be looked up by name use
+        /// [`ParametersMetadata::with_carry_space_budget`].
+        pub const NORM2_PARAMETERS_METADATA: &[(&str, ParametersMetadata)] = &[
+            $((stringify!($name), ParametersMetadata::with_norm2_budget($norm2)),)*
+        ];
+    };
+}
+
+norm2_metadata!(
+    (WOPBS_PARAM_MESSAGE_1_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_1_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_1_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_1_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_2_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_2_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_2_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_2_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_3_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_3_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_3_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_3_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_4_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_4_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_4_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_4_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_5_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_5_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_5_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_5_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_6_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_6_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_6_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_6_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_7_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_7_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_7_NORM2_6, 6),
+    (WOPBS_PARAM_MESSAGE_7_NORM2_8, 8),
+    (WOPBS_PARAM_MESSAGE_8_NORM2_2, 2),
+    (WOPBS_PARAM_MESSAGE_8_NORM2_4, 4),
+    (WOPBS_PARAM_MESSAGE_8_NORM2_6, 6),
+);
+
+/// Noise accumulated by a workload between two bootstraps, see
+/// [`Parameters::assert_compatible_with`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::OpsProfile;
+///
+/// // ct_0 + ct_1 + ... + ct_8
+/// let profile = OpsProfile::sequential_unchecked_adds(8);
+/// assert_eq!(profile.noise_level(), 9);
+///
+/// // 3 * (ct_0 + ct_1)
+/// let profile = OpsProfile::fresh()
+///     .unchecked_add(OpsProfile::fresh())
+///     .unchecked_scalar_mul(3);
+/// assert_eq!(profile.noise_level(), 18);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OpsProfile {
+    noise_level: u64,
+}
+
+impl OpsProfile {
+    /// A freshly encrypted or bootstrapped ciphertext.
+    pub const fn fresh() -> Self {
+        Self { noise_level: 1 }
+    }
+
+    /// A fresh ciphertext to which `count` fresh ciphertexts are added one after the other.
+    pub const fn sequential_unchecked_adds(count: u64) -> Self {
+        Self {
+            noise_level: count.saturating_add(1),
+        }
+    }
+
+    /// The sum of the ciphertexts described by `self` and `other`.
+    pub const fn unchecked_add(self, other: Self) -> Self {
+        Self {
+            noise_level: self.noise_level.saturating_add(other.noise_level),
+        }
+    }
+
+    /// The ciphertext described by `self` multiplied by `scalar`.
+    pub const fn unchecked_scalar_mul(self, scalar: u64) -> Self {
+        Self {
+            noise_level: self
+                .noise_level
+                .saturating_mul(scalar.saturating_mul(scalar)),
+        }
+    }
+
+    /// Noise level of the ciphertext before it is bootstrapped.
+    pub const fn noise_level(&self) -> u64 {
+        self.noise_level
+    }
+}
+
+impl Parameters {
+    /// Return the design targets of the parameter set if it is one of the sets provided by the
+    /// library.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let metadata = PARAM_MESSAGE_2_CARRY_2.metadata().unwrap();
+    /// assert_eq!(metadata.security_level, 128);
+    /// assert_eq!(metadata.log2_p_fail, -40.0);
+    /// assert_eq!(metadata.max_noise_level, 5);
+    /// ```
+    pub fn metadata(&self) -> Option<ParametersMetadata> {
+        let name = self.registered_name()?;
+        let metadata = NORM2_PARAMETERS_METADATA
+            .iter()
+            .find(|(param_name, _)| *param_name == name)
+            .map_or_else(
+                || ParametersMetadata::with_carry_space_budget(self),
+                |(_, metadata)| *metadata,
+            );
+        Some(metadata)
+    }
+
+    /// Check that a workload fits in the noise budget of the parameter set, so that bootstrapping
+    /// its result has the failure probability of the parameter set.
+    ///
+    /// Parameter sets which are not provided by the library have no known budget and are always
+    /// rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{OpsProfile, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// assert!(PARAM_MESSAGE_2_CARRY_2
+    ///     .assert_compatible_with(OpsProfile::sequential_unchecked_adds(4))
+    ///     .is_ok());
+    /// assert!(PARAM_MESSAGE_2_CARRY_2
+    ///     .assert_compatible_with(OpsProfile::sequential_unchecked_adds(8))
+    ///     .is_err());
+    /// ```
+    pub fn assert_compatible_with(&self, ops_profile: OpsProfile) -> Result<(), ParametersError> {
+        let metadata = self.metadata().ok_or(ParametersError::UnknownMetadata)?;
+
+        if ops_profile.noise_level() > metadata.max_noise_level {
+            return Err(ParametersError::NoiseBudgetExceeded {
+                noise_level: ops_profile.noise_level(),
+                max_noise_level: metadata.max_noise_level,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::parameters_wopbs::WOPBS_PARAM_MESSAGE_2_NORM2_6;
+    use crate::shortint::parameters::registry::NAMED_PARAMETERS;
+
+    #[test]
+    fn test_named_parameters_have_metadata() {
+        for (name, params) in NAMED_PARAMETERS {
+            let metadata = params.metadata().unwrap();
+            assert!(metadata.max_noise_level >= 2, "{name}");
+            assert!(
+                params
+                    .assert_compatible_with(OpsProfile::sequential_unchecked_adds(1))
+                    .is_ok(),
+                "{name}"
+            );
+        }
+
+        for (name, _) in NORM2_PARAMETERS_METADATA {
+            assert!(Parameters::from_name(name).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_assert_compatible_with() {
+        let params = PARAM_MESSAGE_2_CARRY_2;
+        assert!(params
+            .assert_compatible_with(OpsProfile::sequential_unchecked_adds(4))
+            .is_ok());
+        assert!(matches!(
+            params.assert_compatible_with(OpsProfile::sequential_unchecked_adds(8)),
+            Err(ParametersError::NoiseBudgetExceeded {
+                noise_level: 9,
+                max_noise_level: 5
+            })
+        ));
+        assert!(params
+            .assert_compatible_with(OpsProfile::fresh().unchecked_scalar_mul(3))
+            .is_err());
+
+        let params = WOPBS_PARAM_MESSAGE_2_NORM2_6;
+        assert_eq!(params.metadata().unwrap().max_noise_level, 6);
+
+        let mut params = PARAM_MESSAGE_2_CARRY_2;
+        params.lwe_dimension = LweDimension(params.lwe_dimension.0 + 1);
+        assert!(params.metadata().is_none());
+        assert!(matches!(
+            params.assert_compatible_with(OpsProfile::fresh()),
+            Err(ParametersError::UnknownMetadata)
+        ));
+    }
+}
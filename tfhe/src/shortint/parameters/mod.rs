@@ -5,16 +5,24 @@
 //! homomorphic evaluation of integer circuits as well as a list of secure cryptographic parameter
 //! sets.
 
-pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+pub use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, DynamicDistribution, NoiseDistribution, StandardDev,
+};
+pub use crate::core_crypto::commons::math::random::TUniform;
 pub use crate::core_crypto::commons::parameters::{
     CiphertextModulus as CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
     GlweDimension, LweDimension, PolynomialSize,
 };
 use serde::{Deserialize, Serialize};
 
+pub mod metadata;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
+pub mod registry;
+
+pub use metadata::{OpsProfile, ParametersMetadata};
+pub use registry::{ParametersConfig, ParametersError};
 
 /// The number of bits on which the message will be encoded.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
@@ -27,6 +35,21 @@ pub struct CarryModulus(pub usize);
 /// Determines in what ring computations are made
 pub type CiphertextModulus = CoreCiphertextModulus<u64>;
 
+/// The distributions the encryption noise of the keys and ciphertexts is sampled from.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq, Eq, Default)]
+pub enum NoiseDistributionKind {
+    /// Gaussian noise, with the `lwe_modular_std_dev` and `glwe_modular_std_dev` standard
+    /// deviations.
+    #[default]
+    Gaussian,
+    /// Bounded uniform noise, in $[-2^{bound\_log2}, 2^{bound\_log2}]$ modulo the ciphertext
+    /// modulus, see [`TUniform`]. The LWE and GLWE standard deviations are not used.
+    TUniform {
+        lwe_bound_log2: u32,
+        glwe_bound_log2: u32,
+    },
+}
+
 /// A structure defining the set of cryptographic parameters for homomorphic integer circuit
 /// evaluation.
 #[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
@@ -36,6 +59,8 @@ pub struct Parameters {
     pub polynomial_size: PolynomialSize,
     pub lwe_modular_std_dev: StandardDev,
     pub glwe_modular_std_dev: StandardDev,
+    #[serde(default)]
+    pub noise_distribution: NoiseDistributionKind,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -84,6 +109,7 @@ impl Parameters {
             polynomial_size,
             lwe_modular_std_dev,
             glwe_modular_std_dev,
+            noise_distribution: NoiseDistributionKind::Gaussian,
             pbs_base_log,
             pbs_level,
             ks_level,
@@ -98,6 +124,26 @@ impl Parameters {
             ciphertext_modulus,
         }
     }
+
+    /// Returns the distribution the noise of the LWE encryptions is sampled from.
+    pub fn lwe_noise_distribution(&self) -> DynamicDistribution {
+        match self.noise_distribution {
+            NoiseDistributionKind::Gaussian => self.lwe_modular_std_dev.into(),
+            NoiseDistributionKind::TUniform { lwe_bound_log2, .. } => {
+                TUniform::new(lwe_bound_log2).into()
+            }
+        }
+    }
+
+    /// Returns the distribution the noise of the GLWE encryptions is sampled from.
+    pub fn glwe_noise_distribution(&self) -> DynamicDistribution {
+        match self.noise_distribution {
+            NoiseDistributionKind::Gaussian => self.glwe_modular_std_dev.into(),
+            NoiseDistributionKind::TUniform {
+                glwe_bound_log2, ..
+            } => TUniform::new(glwe_bound_log2).into(),
+        }
+    }
 }
 
 /// Vector containing all parameter sets
@@ -165,6 +211,7 @@ pub const PARAM_MESSAGE_1_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(256),
     lwe_modular_std_dev: StandardDev(0.000022810107419132102),
     glwe_modular_std_dev: StandardDev(0.00000000037411618952047216),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -184,6 +231,7 @@ pub const PARAM_MESSAGE_1_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00002043784477291318),
     glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -203,6 +251,7 @@ pub const PARAM_MESSAGE_2_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.000034119201269311964),
     glwe_modular_std_dev: StandardDev(0.00000004053919869756513),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(8),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -222,6 +271,7 @@ pub const PARAM_MESSAGE_1_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -241,6 +291,7 @@ pub const PARAM_MESSAGE_2_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -260,6 +311,7 @@ pub const PARAM_MESSAGE_3_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -279,6 +331,7 @@ pub const PARAM_MESSAGE_1_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.000006692125069956277),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -298,6 +351,7 @@ pub const PARAM_MESSAGE_2_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -317,6 +371,7 @@ pub const PARAM_MESSAGE_3_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -336,6 +391,7 @@ pub const PARAM_MESSAGE_4_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.000007069849454709433),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -355,6 +411,7 @@ pub const PARAM_MESSAGE_1_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(4096),
     lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -374,6 +431,7 @@ pub const PARAM_MESSAGE_2_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(4096),
     lwe_modular_std_dev: StandardDev(0.0000008775214009854235),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -393,6 +451,7 @@ pub const PARAM_MESSAGE_3_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(4096),
     lwe_modular_std_dev: StandardDev(0.0000019633637461248447),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -412,6 +471,7 @@ pub const PARAM_MESSAGE_4_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(4096),
     lwe_modular_std_dev: StandardDev(0.0000021124945159091033),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -431,6 +491,7 @@ pub const PARAM_MESSAGE_5_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(4096),
     lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -450,6 +511,7 @@ pub const PARAM_MESSAGE_1_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.000000757998020150446),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -469,6 +531,7 @@ pub const PARAM_MESSAGE_2_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.000000757998020150446),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -488,6 +551,7 @@ pub const PARAM_MESSAGE_3_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.000000757998020150446),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -507,6 +571,7 @@ pub const PARAM_MESSAGE_4_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.000000757998020150446),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -526,6 +591,7 @@ pub const PARAM_MESSAGE_5_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.0000006197725091905067),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -545,6 +611,7 @@ pub const PARAM_MESSAGE_6_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.00000029804653749339636),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -564,6 +631,7 @@ pub const PARAM_MESSAGE_1_CARRY_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(6),
@@ -583,6 +651,7 @@ pub const PARAM_MESSAGE_2_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000021050318566634375),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -602,6 +671,7 @@ pub const PARAM_MESSAGE_3_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -621,6 +691,7 @@ pub const PARAM_MESSAGE_4_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -640,6 +711,7 @@ pub const PARAM_MESSAGE_5_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -659,6 +731,7 @@ pub const PARAM_MESSAGE_6_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -678,6 +751,7 @@ pub const PARAM_MESSAGE_7_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(16384),
     lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -697,6 +771,7 @@ pub const PARAM_MESSAGE_1_CARRY_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.00000005845871624688967),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -716,6 +791,7 @@ pub const PARAM_MESSAGE_2_CARRY_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.00000007979529246348835),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -735,6 +811,7 @@ pub const PARAM_MESSAGE_3_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.00000008277032914509569),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -754,6 +831,7 @@ pub const PARAM_MESSAGE_4_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.00000006767666038309478),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -773,6 +851,7 @@ pub const PARAM_MESSAGE_5_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.000000043618425315728666),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -792,6 +871,7 @@ pub const PARAM_MESSAGE_6_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.000000045244666805696514),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -811,6 +891,7 @@ pub const PARAM_MESSAGE_7_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -830,6 +911,7 @@ pub const PARAM_MESSAGE_8_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -850,6 +932,7 @@ pub const PARAM_SMALL_MESSAGE_1_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.0000033382067621812462),
     glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -870,6 +953,7 @@ pub const PARAM_SMALL_MESSAGE_2_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.0000006791658447437413),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -890,6 +974,7 @@ pub const PARAM_SMALL_MESSAGE_3_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(8192),
     lwe_modular_std_dev: StandardDev(0.00000003980397588319241),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -910,6 +995,7 @@ pub const PARAM_SMALL_MESSAGE_4_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(32768),
     lwe_modular_std_dev: StandardDev(0.0000000012520482863081104),
     glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -924,6 +1010,64 @@ pub const PARAM_SMALL_MESSAGE_4_CARRY_4: Parameters = Parameters {
     ciphertext_modulus: CiphertextModulus::new_native(),
 };
 
+/// Variant of [`PARAM_MESSAGE_2_CARRY_2`] with a bootstrapping key decomposed over 3 levels of 8
+/// bits instead of a single level of 23 bits, the other parameters are unchanged.
+///
+/// The decomposition only changes the bootstrapping key, not the secret keys, so the security is
+/// the one of [`PARAM_MESSAGE_2_CARRY_2`]. The key can also be used with
+/// [`PbsMode::Approximate`](crate::shortint::server_key::PbsMode::Approximate), dropping the
+/// least significant level to make the bootstraps faster.
+///
+/// # Failure probability
+///
+/// The failure probability of a bootstrap depends on the noise of its input, so on the mode of
+/// the bootstraps which produced the ciphertexts it is computed on. It is derived from the
+/// variance of the noise at the input of the blind rotation, for binary secret keys:
+///
+/// - the output of a bootstrap has a variance of `n * ((k + 1) * l * N * (B^2 + 2) / 12 *
+///   glwe_var + (k * N / 2 + 1) / (24 * B^(2 * l)))` with `B = 2^pbs_base_log` and `l` the number
+///   of levels used, that is `2^-33.0` with the 3 levels of an exact bootstrap and `2^-17.0` with
+///   the 2 levels of an approximate one, where the rounding of the decomposition dominates;
+/// - the keyswitch adds `2^-18.4` and the modulus switch to `2N` adds `2^-19.0`.
+///
+/// With the noise level of 5 allowed by the carry space, the input of a bootstrap decodes
+/// correctly unless the noise exceeds `1/64`, which happens with probability:
+///
+/// - `2^-40.3` when the inputs come from exact bootstraps, like [`PARAM_MESSAGE_2_CARRY_2`];
+/// - `2^-6.0` when they come from approximate bootstraps, and still `2^-17.1` for a noise level
+///   of 1, i.e. for a lookup table applied directly to the output of an approximate bootstrap.
+///
+/// Approximate bootstraps are therefore only suited to workloads tolerating wrong blocks, and
+/// should not feed additions or scalar multiplications before the next bootstrap.
+pub const PARAM_MESSAGE_2_CARRY_2_APPROX_PBS: Parameters = Parameters {
+    pbs_base_log: DecompositionBaseLog(8),
+    pbs_level: DecompositionLevelCount(3),
+    ..PARAM_MESSAGE_2_CARRY_2
+};
+
+/// Nomenclature: PARAM_BOOLEAN_CARRY_Y: parameter sets for boolean workloads, with a 1 bit message
+/// and a carry modulus encoded over Y bits. They are the PARAM_MESSAGE_1_CARRY_Y parameter sets,
+/// exposed under a name stating their intended use.
+///
+/// A fresh ciphertext encrypting a boolean has a degree of 1, so up to
+/// `2 * 2^{Y} - 1` of them can be summed with unchecked additions before the result has to be
+/// cleaned by a PBS. This allows evaluating wide gates (parity, majority, thresholds, ...) with a
+/// single PBS, keeping mixed boolean and arithmetic circuits within a single ciphertext type.
+pub const PARAM_BOOLEAN_CARRY_3: Parameters = PARAM_MESSAGE_1_CARRY_3;
+pub const PARAM_BOOLEAN_CARRY_4: Parameters = PARAM_MESSAGE_1_CARRY_4;
+pub const PARAM_BOOLEAN_CARRY_5: Parameters = PARAM_MESSAGE_1_CARRY_5;
+pub const PARAM_BOOLEAN_CARRY_6: Parameters = PARAM_MESSAGE_1_CARRY_6;
+pub const PARAM_BOOLEAN_CARRY_7: Parameters = PARAM_MESSAGE_1_CARRY_7;
+
+/// Vector containing all parameter sets for boolean workloads
+pub const BOOLEAN_PARAMETER_SET_VEC: [Parameters; 5] = [
+    PARAM_BOOLEAN_CARRY_3,
+    PARAM_BOOLEAN_CARRY_4,
+    PARAM_BOOLEAN_CARRY_5,
+    PARAM_BOOLEAN_CARRY_6,
+    PARAM_BOOLEAN_CARRY_7,
+];
+
 /// Return a parameter set from a message and carry moduli.
 ///
 /// # Example
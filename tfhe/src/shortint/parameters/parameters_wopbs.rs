@@ -4,7 +4,9 @@ pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardD
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
-use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, MessageModulus, NoiseDistributionKind,
+};
 use crate::shortint::Parameters;
 
 pub const ALL_PARAMETER_VEC_WOPBS_NORM2: [Parameters; 31] = [
@@ -47,6 +49,7 @@ pub const WOPBS_PARAM_MESSAGE_1_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.0003472352121441949901),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(24),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -66,6 +69,7 @@ pub const WOPBS_PARAM_MESSAGE_1_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00041688866384199045524),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -85,6 +89,7 @@ pub const WOPBS_PARAM_MESSAGE_1_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.0004403915565001254653),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -104,6 +109,7 @@ pub const WOPBS_PARAM_MESSAGE_1_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00043241360644590172285),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -123,6 +129,7 @@ pub const WOPBS_PARAM_MESSAGE_2_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.0005384866525630595423),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -142,6 +149,7 @@ pub const WOPBS_PARAM_MESSAGE_2_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.0005384866525630595423),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -161,6 +169,7 @@ pub const WOPBS_PARAM_MESSAGE_2_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -180,6 +189,7 @@ pub const WOPBS_PARAM_MESSAGE_2_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -199,6 +209,7 @@ pub const WOPBS_PARAM_MESSAGE_3_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.0005384866525630595423),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -218,6 +229,7 @@ pub const WOPBS_PARAM_MESSAGE_3_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -237,6 +249,7 @@ pub const WOPBS_PARAM_MESSAGE_3_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00048254425233109359873),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -256,6 +269,7 @@ pub const WOPBS_PARAM_MESSAGE_3_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00048254425233109359873),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -275,6 +289,7 @@ pub const WOPBS_PARAM_MESSAGE_4_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -294,6 +309,7 @@ pub const WOPBS_PARAM_MESSAGE_4_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -313,6 +329,7 @@ pub const WOPBS_PARAM_MESSAGE_4_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -332,6 +349,7 @@ pub const WOPBS_PARAM_MESSAGE_4_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -351,6 +369,7 @@ pub const WOPBS_PARAM_MESSAGE_5_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -370,6 +389,7 @@ pub const WOPBS_PARAM_MESSAGE_5_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -389,6 +409,7 @@ pub const WOPBS_PARAM_MESSAGE_5_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -408,6 +429,7 @@ pub const WOPBS_PARAM_MESSAGE_5_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -427,6 +449,7 @@ pub const WOPBS_PARAM_MESSAGE_6_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -446,6 +469,7 @@ pub const WOPBS_PARAM_MESSAGE_6_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -465,6 +489,7 @@ pub const WOPBS_PARAM_MESSAGE_6_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -484,6 +509,7 @@ pub const WOPBS_PARAM_MESSAGE_6_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -503,6 +529,7 @@ pub const WOPBS_PARAM_MESSAGE_7_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -522,6 +549,7 @@ pub const WOPBS_PARAM_MESSAGE_7_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -541,6 +569,7 @@ pub const WOPBS_PARAM_MESSAGE_7_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -560,6 +589,7 @@ pub const WOPBS_PARAM_MESSAGE_7_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -579,6 +609,7 @@ pub const WOPBS_PARAM_MESSAGE_8_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -598,6 +629,7 @@ pub const WOPBS_PARAM_MESSAGE_8_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -617,6 +649,7 @@ pub const WOPBS_PARAM_MESSAGE_8_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -637,6 +670,7 @@ pub const PARAM_4_BITS_5_BLOCKS: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.0000000004168323308734758),
     glwe_modular_std_dev: StandardDev(0.00000000000000000000000000000004905643852600863),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(7),
     pbs_level: DecompositionLevelCount(6),
     ks_base_log: DecompositionBaseLog(1),
@@ -7,7 +7,9 @@ pub use crate::core_crypto::commons::parameters::{
 };
 use crate::shortint::parameters::parameters_wopbs::*;
 use crate::shortint::parameters::parameters_wopbs_prime_moduli::*;
-use crate::shortint::parameters::{CarryModulus, MessageModulus, PARAM_MESSAGE_2_CARRY_2};
+use crate::shortint::parameters::{
+    CarryModulus, MessageModulus, NoiseDistributionKind, PARAM_MESSAGE_2_CARRY_2,
+};
 use crate::shortint::Parameters;
 
 pub const ALL_PARAMETER_VEC_WOPBS: [Parameters; 116] = [
@@ -135,6 +137,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -154,6 +157,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -173,6 +177,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(8),
     pbs_level: DecompositionLevelCount(5),
     ks_level: DecompositionLevelCount(9),
@@ -192,6 +197,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -211,6 +217,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(7),
     pbs_level: DecompositionLevelCount(6),
     ks_level: DecompositionLevelCount(9),
@@ -230,6 +237,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -249,6 +257,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00044851669823869648209),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(24),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -268,6 +277,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00003604499526942373),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(2),
@@ -287,6 +297,7 @@ pub const WOPBS_PARAM_MESSAGE_1_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00054842163045222410337),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -306,6 +317,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -325,6 +337,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -344,6 +357,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -363,6 +377,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(8),
     pbs_level: DecompositionLevelCount(5),
     ks_level: DecompositionLevelCount(9),
@@ -382,6 +397,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -401,6 +417,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00043241360644590172285),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -420,6 +437,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.0000043131554647504185),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(2),
@@ -439,6 +457,7 @@ pub const WOPBS_PARAM_MESSAGE_2_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00054842163045222410337),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -458,6 +477,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -477,6 +497,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -496,6 +517,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.0000006428797112843789),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(1),
@@ -515,6 +537,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -534,6 +557,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -553,6 +577,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -572,6 +597,7 @@ pub const WOPBS_PARAM_MESSAGE_3_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00054842163045222410337),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -591,6 +617,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.0000001486733969411098),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(1),
@@ -610,6 +637,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -629,6 +657,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(9),
@@ -648,6 +677,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -667,6 +697,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -686,6 +717,7 @@ pub const WOPBS_PARAM_MESSAGE_4_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -705,6 +737,7 @@ pub const WOPBS_PARAM_MESSAGE_5_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -724,6 +757,7 @@ pub const WOPBS_PARAM_MESSAGE_5_CARRY_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -743,6 +777,7 @@ pub const WOPBS_PARAM_MESSAGE_5_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -762,6 +797,7 @@ pub const WOPBS_PARAM_MESSAGE_5_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -781,6 +817,7 @@ pub const WOPBS_PARAM_MESSAGE_5_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -800,6 +837,7 @@ pub const WOPBS_PARAM_MESSAGE_6_CARRY_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -819,6 +857,7 @@ pub const WOPBS_PARAM_MESSAGE_6_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -838,6 +877,7 @@ pub const WOPBS_PARAM_MESSAGE_6_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -857,6 +897,7 @@ pub const WOPBS_PARAM_MESSAGE_6_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(512),
     lwe_modular_std_dev: StandardDev(0.00055853990682276860028),
     glwe_modular_std_dev: StandardDev(0.000000000002573000821792597679153983627),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(4),
@@ -876,6 +917,7 @@ pub const WOPBS_PARAM_MESSAGE_7_CARRY_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00061200133780220371345),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(9),
@@ -895,6 +937,7 @@ pub const WOPBS_PARAM_MESSAGE_7_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -914,6 +957,7 @@ pub const WOPBS_PARAM_MESSAGE_7_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -933,6 +977,7 @@ pub const WOPBS_PARAM_MESSAGE_8_CARRY_1: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(5),
@@ -952,6 +997,7 @@ pub const WOPBS_PARAM_MESSAGE_8_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00045679174732062467505),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -971,6 +1017,7 @@ pub const WOPBS_PARAM_MESSAGE_9_CARRY_0: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00049144710341316649172),
     glwe_modular_std_dev: StandardDev(0.00000000000000022148688116005568513645324585951),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -2,7 +2,9 @@ pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardD
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
-use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, MessageModulus, NoiseDistributionKind,
+};
 use crate::shortint::Parameters;
 
 pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2: Parameters = Parameters {
@@ -11,6 +13,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -30,6 +33,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.0000173339182921315917918),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -49,6 +53,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000005372539047440715995675),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -68,6 +73,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -87,6 +93,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001671088050446407327190),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -106,6 +113,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001391593132168288907584),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -125,6 +133,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_2_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001269897734067647866200),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -144,6 +153,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001611023673517825963297),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(16),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -163,6 +173,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00000913465281899372298196),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -182,6 +193,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001831229863526819043776),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -201,6 +213,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001553118206991877872242),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -220,6 +233,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000017019548679502491437),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -239,6 +253,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000880632348297507352018),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(15),
@@ -258,6 +273,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_3_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001366355065014387319960),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -277,6 +293,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001470138983326210590285),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -296,6 +313,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -315,6 +333,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000016407810365194741608),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -334,6 +353,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001202050272339788291268),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -353,6 +373,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001137827730902298847640),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -372,6 +393,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000669212506995627734883),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -391,6 +413,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_4_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001765409465411734898801),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -410,6 +433,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001470138983326210590285),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -429,6 +453,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -448,6 +473,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000016407810365194741608),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -467,6 +493,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001202050272339788291268),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -486,6 +513,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001137827730902298847640),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -505,6 +533,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000669212506995627734883),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -524,6 +553,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_5_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001765409465411734898801),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -543,6 +573,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001470138983326210590285),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -562,6 +593,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -581,6 +613,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000016407810365194741608),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -600,6 +633,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001202050272339788291268),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -619,6 +653,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001137827730902298847640),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -638,6 +673,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000669212506995627734883),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -657,6 +693,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_6_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001765409465411734898801),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -676,6 +713,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001470138983326210590285),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -695,6 +733,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -714,6 +753,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000016407810365194741608),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -733,6 +773,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001202050272339788291268),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -752,6 +793,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001137827730902298847640),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -771,6 +813,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000669212506995627734883),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -790,6 +833,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_7_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001765409465411734898801),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -809,6 +853,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_2: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001470138983326210590285),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -828,6 +873,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_3: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001865054674846586206642),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -847,6 +893,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_4: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.000016407810365194741608),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -866,6 +913,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_5: Parameters = Parameters {
     polynomial_size: PolynomialSize(2048),
     lwe_modular_std_dev: StandardDev(0.00001202050272339788291268),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -885,6 +933,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_6: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001137827730902298847640),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -904,6 +953,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_7: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00000669212506995627734883),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
@@ -923,6 +973,7 @@ pub const WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_8: Parameters = Parameters {
     polynomial_size: PolynomialSize(1024),
     lwe_modular_std_dev: StandardDev(0.00001765409465411734898801),
     glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432531092229224715860),
+    noise_distribution: NoiseDistributionKind::Gaussian,
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(7),
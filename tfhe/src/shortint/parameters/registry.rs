@@ -0,0 +1,471 @@
+//! Symbolic names for the parameter sets provided by the library.
+//!
+//! Every parameter set exported by [`crate::shortint::parameters`] can be looked up by the name of
+//! its constant, so configuration files and other language bindings can refer to a parameter set
+//! by name instead of duplicating its values.
+//!
+//! With the `parameters-config` feature, parameters can also be loaded from JSON or TOML
+//! documents, either by name or as an explicit set of values which is validated after loading.
+use super::parameters_wopbs::*;
+use super::parameters_wopbs_message_carry::*;
+use super::*;
+
+macro_rules! named_parameters {
+    ($($name:ident),* $(,)?) => {
+        /// All the parameter sets that can be looked up by name, with their name.
+        pub const NAMED_PARAMETERS: &[(&str, Parameters)] = &[
+            $((stringify!($name), $name),)*
+        ];
+    };
+}
+
+named_parameters!(
+    PARAM_MESSAGE_1_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_1,
+    PARAM_MESSAGE_3_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_3,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_1,
+    PARAM_MESSAGE_4_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_3,
+    PARAM_MESSAGE_3_CARRY_2,
+    PARAM_MESSAGE_4_CARRY_1,
+    PARAM_MESSAGE_5_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_5,
+    PARAM_MESSAGE_2_CARRY_4,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_2,
+    PARAM_MESSAGE_5_CARRY_1,
+    PARAM_MESSAGE_6_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_6,
+    PARAM_MESSAGE_2_CARRY_5,
+    PARAM_MESSAGE_3_CARRY_4,
+    PARAM_MESSAGE_4_CARRY_3,
+    PARAM_MESSAGE_5_CARRY_2,
+    PARAM_MESSAGE_6_CARRY_1,
+    PARAM_MESSAGE_7_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_7,
+    PARAM_MESSAGE_2_CARRY_6,
+    PARAM_MESSAGE_3_CARRY_5,
+    PARAM_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_5_CARRY_3,
+    PARAM_MESSAGE_6_CARRY_2,
+    PARAM_MESSAGE_7_CARRY_1,
+    PARAM_MESSAGE_8_CARRY_0,
+    PARAM_SMALL_MESSAGE_1_CARRY_1,
+    PARAM_SMALL_MESSAGE_2_CARRY_2,
+    PARAM_SMALL_MESSAGE_3_CARRY_3,
+    PARAM_SMALL_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_2_APPROX_PBS,
+    PARAM_BOOLEAN_CARRY_3,
+    PARAM_BOOLEAN_CARRY_4,
+    PARAM_BOOLEAN_CARRY_5,
+    PARAM_BOOLEAN_CARRY_6,
+    PARAM_BOOLEAN_CARRY_7,
+    WOPBS_PARAM_MESSAGE_1_NORM2_2,
+    WOPBS_PARAM_MESSAGE_1_NORM2_4,
+    WOPBS_PARAM_MESSAGE_1_NORM2_6,
+    WOPBS_PARAM_MESSAGE_1_NORM2_8,
+    WOPBS_PARAM_MESSAGE_2_NORM2_2,
+    WOPBS_PARAM_MESSAGE_2_NORM2_4,
+    WOPBS_PARAM_MESSAGE_2_NORM2_6,
+    WOPBS_PARAM_MESSAGE_2_NORM2_8,
+    WOPBS_PARAM_MESSAGE_3_NORM2_2,
+    WOPBS_PARAM_MESSAGE_3_NORM2_4,
+    WOPBS_PARAM_MESSAGE_3_NORM2_6,
+    WOPBS_PARAM_MESSAGE_3_NORM2_8,
+    WOPBS_PARAM_MESSAGE_4_NORM2_2,
+    WOPBS_PARAM_MESSAGE_4_NORM2_4,
+    WOPBS_PARAM_MESSAGE_4_NORM2_6,
+    WOPBS_PARAM_MESSAGE_4_NORM2_8,
+    WOPBS_PARAM_MESSAGE_5_NORM2_2,
+    WOPBS_PARAM_MESSAGE_5_NORM2_4,
+    WOPBS_PARAM_MESSAGE_5_NORM2_6,
+    WOPBS_PARAM_MESSAGE_5_NORM2_8,
+    WOPBS_PARAM_MESSAGE_6_NORM2_2,
+    WOPBS_PARAM_MESSAGE_6_NORM2_4,
+    WOPBS_PARAM_MESSAGE_6_NORM2_6,
+    WOPBS_PARAM_MESSAGE_6_NORM2_8,
+    WOPBS_PARAM_MESSAGE_7_NORM2_2,
+    WOPBS_PARAM_MESSAGE_7_NORM2_4,
+    WOPBS_PARAM_MESSAGE_7_NORM2_6,
+    WOPBS_PARAM_MESSAGE_7_NORM2_8,
+    WOPBS_PARAM_MESSAGE_8_NORM2_2,
+    WOPBS_PARAM_MESSAGE_8_NORM2_4,
+    WOPBS_PARAM_MESSAGE_8_NORM2_6,
+    PARAM_4_BITS_5_BLOCKS,
+    WOPBS_PARAM_MESSAGE_1_CARRY_0,
+    WOPBS_PARAM_MESSAGE_1_CARRY_1,
+    WOPBS_PARAM_MESSAGE_1_CARRY_2,
+    WOPBS_PARAM_MESSAGE_1_CARRY_3,
+    WOPBS_PARAM_MESSAGE_1_CARRY_4,
+    WOPBS_PARAM_MESSAGE_1_CARRY_5,
+    WOPBS_PARAM_MESSAGE_1_CARRY_6,
+    WOPBS_PARAM_MESSAGE_1_CARRY_7,
+    WOPBS_PARAM_MESSAGE_1_CARRY_8,
+    WOPBS_PARAM_MESSAGE_2_CARRY_0,
+    WOPBS_PARAM_MESSAGE_2_CARRY_1,
+    WOPBS_PARAM_MESSAGE_2_CARRY_2,
+    WOPBS_PARAM_MESSAGE_2_CARRY_3,
+    WOPBS_PARAM_MESSAGE_2_CARRY_4,
+    WOPBS_PARAM_MESSAGE_2_CARRY_5,
+    WOPBS_PARAM_MESSAGE_2_CARRY_6,
+    WOPBS_PARAM_MESSAGE_2_CARRY_7,
+    WOPBS_PARAM_MESSAGE_3_CARRY_0,
+    WOPBS_PARAM_MESSAGE_3_CARRY_1,
+    WOPBS_PARAM_MESSAGE_3_CARRY_2,
+    WOPBS_PARAM_MESSAGE_3_CARRY_3,
+    WOPBS_PARAM_MESSAGE_3_CARRY_4,
+    WOPBS_PARAM_MESSAGE_3_CARRY_5,
+    WOPBS_PARAM_MESSAGE_3_CARRY_6,
+    WOPBS_PARAM_MESSAGE_4_CARRY_0,
+    WOPBS_PARAM_MESSAGE_4_CARRY_1,
+    WOPBS_PARAM_MESSAGE_4_CARRY_2,
+    WOPBS_PARAM_MESSAGE_4_CARRY_3,
+    WOPBS_PARAM_MESSAGE_4_CARRY_4,
+    WOPBS_PARAM_MESSAGE_4_CARRY_5,
+    WOPBS_PARAM_MESSAGE_5_CARRY_0,
+    WOPBS_PARAM_MESSAGE_5_CARRY_1,
+    WOPBS_PARAM_MESSAGE_5_CARRY_2,
+    WOPBS_PARAM_MESSAGE_5_CARRY_3,
+    WOPBS_PARAM_MESSAGE_5_CARRY_4,
+    WOPBS_PARAM_MESSAGE_6_CARRY_0,
+    WOPBS_PARAM_MESSAGE_6_CARRY_1,
+    WOPBS_PARAM_MESSAGE_6_CARRY_2,
+    WOPBS_PARAM_MESSAGE_6_CARRY_3,
+    WOPBS_PARAM_MESSAGE_7_CARRY_0,
+    WOPBS_PARAM_MESSAGE_7_CARRY_1,
+    WOPBS_PARAM_MESSAGE_7_CARRY_2,
+    WOPBS_PARAM_MESSAGE_8_CARRY_0,
+    WOPBS_PARAM_MESSAGE_8_CARRY_1,
+    WOPBS_PARAM_MESSAGE_9_CARRY_0,
+);
+
+/// Error returned when a set of parameters is rejected.
+#[derive(Debug)]
+pub enum ParametersError {
+    UnknownName(String),
+    InvalidParameter {
+        name: &'static str,
+        reason: &'static str,
+    },
+    /// The parameter set is not provided by the library, so its design targets are unknown.
+    UnknownMetadata,
+    /// A workload accumulates more noise than the parameter set was designed for.
+    NoiseBudgetExceeded {
+        noise_level: u64,
+        max_noise_level: u64,
+    },
+    /// Externally generated key material does not match the parameter set.
+    IncompatibleKey {
+        name: &'static str,
+        expected: String,
+        found: String,
+    },
+    /// A lookup table does not encode a function for the moduli of the parameter set.
+    IncompatibleLookupTable,
+    #[cfg(feature = "parameters-config")]
+    Json(serde_json::Error),
+    #[cfg(feature = "parameters-config")]
+    Toml(toml::de::Error),
+}
+
+impl std::fmt::Display for ParametersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownName(name) => write!(f, "Unknown parameter set {name}"),
+            Self::InvalidParameter { name, reason } => {
+                write!(f, "Invalid parameter {name}: {reason}")
+            }
+            Self::UnknownMetadata => write!(f, "Unknown design targets for the parameter set"),
+            Self::NoiseBudgetExceeded {
+                noise_level,
+                max_noise_level,
+            } => write!(
+                f,
+                "Noise level {noise_level} exceeds the budget of the parameter set \
+                ({max_noise_level})"
+            ),
+            Self::IncompatibleKey {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Incompatible key {name}: expected {expected}, found {found}"
+            ),
+            Self::IncompatibleLookupTable => write!(
+                f,
+                "The lookup table was not generated for the moduli of the parameter set"
+            ),
+            #[cfg(feature = "parameters-config")]
+            Self::Json(err) => write!(f, "JSON error: {err}"),
+            #[cfg(feature = "parameters-config")]
+            Self::Toml(err) => write!(f, "TOML error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParametersError {}
+
+impl ParametersError {
+    /// Check that a property of imported key material matches the one expected from the
+    /// parameter set.
+    pub(crate) fn check_key<T: PartialEq + std::fmt::Debug>(
+        name: &'static str,
+        expected: T,
+        found: T,
+    ) -> Result<(), Self> {
+        if expected == found {
+            Ok(())
+        } else {
+            Err(Self::IncompatibleKey {
+                name,
+                expected: format!("{expected:?}"),
+                found: format!("{found:?}"),
+            })
+        }
+    }
+}
+
+impl Parameters {
+    /// Return the parameter set provided by the library under the given name, the name being the
+    /// one of its constant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let params = Parameters::from_name("PARAM_MESSAGE_2_CARRY_2").unwrap();
+    /// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2);
+    /// assert_eq!(params.registered_name(), Some("PARAM_MESSAGE_2_CARRY_2"));
+    ///
+    /// assert!(Parameters::from_name("PARAM_MESSAGE_42_CARRY_42").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, ParametersError> {
+        NAMED_PARAMETERS
+            .iter()
+            .find(|(param_name, _)| *param_name == name)
+            .map(|(_, params)| *params)
+            .ok_or_else(|| ParametersError::UnknownName(name.to_owned()))
+    }
+
+    /// Return the name of the parameter set if it is one of the sets provided by the library.
+    ///
+    /// Some sets are exported under several names, in which case the first one is returned.
+    pub fn registered_name(&self) -> Option<&'static str> {
+        NAMED_PARAMETERS
+            .iter()
+            .find(|(_, params)| params == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Check that the parameters are consistent.
+    ///
+    /// This only catches structural errors (e.g. a message modulus which is not a power of two),
+    /// it does not tell anything about the security or the correctness of the parameters.
+    pub fn validate(&self) -> Result<(), ParametersError> {
+        let invalid = |name, reason| Err(ParametersError::InvalidParameter { name, reason });
+
+        if self.lwe_dimension.0 == 0 {
+            return invalid("lwe_dimension", "must be greater than 0");
+        }
+        if self.glwe_dimension.0 == 0 {
+            return invalid("glwe_dimension", "must be greater than 0");
+        }
+        if !self.polynomial_size.0.is_power_of_two() {
+            return invalid("polynomial_size", "must be a power of 2");
+        }
+        if !self.message_modulus.0.is_power_of_two() {
+            return invalid("message_modulus", "must be a power of 2");
+        }
+        if !self.carry_modulus.0.is_power_of_two() {
+            return invalid("carry_modulus", "must be a power of 2");
+        }
+        if self.message_modulus.0 * self.carry_modulus.0 > self.polynomial_size.0 {
+            return invalid(
+                "polynomial_size",
+                "must be at least message_modulus * carry_modulus",
+            );
+        }
+
+        let decompositions = [
+            ("pbs", self.pbs_base_log, self.pbs_level),
+            ("ks", self.ks_base_log, self.ks_level),
+            ("pfks", self.pfks_base_log, self.pfks_level),
+            ("cbs", self.cbs_base_log, self.cbs_level),
+        ];
+        for (name, base_log, level) in decompositions {
+            if base_log.0 * level.0 > u64::BITS as usize {
+                return invalid(name, "base_log * level must be at most 64");
+            }
+        }
+        if self.pbs_base_log.0 == 0 || self.pbs_level.0 == 0 {
+            return invalid("pbs", "base_log and level must be greater than 0");
+        }
+        if self.ks_base_log.0 == 0 || self.ks_level.0 == 0 {
+            return invalid("ks", "base_log and level must be greater than 0");
+        }
+
+        let std_devs = [
+            ("lwe_modular_std_dev", self.lwe_modular_std_dev),
+            ("glwe_modular_std_dev", self.glwe_modular_std_dev),
+            ("pfks_modular_std_dev", self.pfks_modular_std_dev),
+        ];
+        for (name, std_dev) in std_devs {
+            if !std_dev.0.is_finite() || std_dev.0 < 0.0 {
+                return invalid(name, "must be a finite non-negative number");
+            }
+        }
+
+        if !self.ciphertext_modulus.is_compatible_with_native_modulus() {
+            return invalid("ciphertext_modulus", "must be a power of 2");
+        }
+
+        Ok(())
+    }
+}
+
+/// A parameter set as written in a configuration file: either the name of a parameter set
+/// provided by the library or its explicit values.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ParametersConfig {
+    Named { name: String },
+    Explicit(Parameters),
+}
+
+impl ParametersConfig {
+    /// Return the parameters described by the configuration, after validating them.
+    pub fn resolve(&self) -> Result<Parameters, ParametersError> {
+        let params = match self {
+            Self::Named { name } => Parameters::from_name(name)?,
+            Self::Explicit(params) => *params,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+}
+
+impl From<Parameters> for ParametersConfig {
+    fn from(params: Parameters) -> Self {
+        match params.registered_name() {
+            Some(name) => Self::Named {
+                name: name.to_owned(),
+            },
+            None => Self::Explicit(params),
+        }
+    }
+}
+
+#[cfg(feature = "parameters-config")]
+impl Parameters {
+    /// Load and validate parameters from a JSON document, see [`ParametersConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let params = Parameters::from_json_str(r#"{ "name": "PARAM_MESSAGE_2_CARRY_2" }"#).unwrap();
+    /// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let json = serde_json::to_string(&PARAM_MESSAGE_2_CARRY_2).unwrap();
+    /// let params = Parameters::from_json_str(&json).unwrap();
+    /// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, ParametersError> {
+        serde_json::from_str::<ParametersConfig>(json)
+            .map_err(ParametersError::Json)?
+            .resolve()
+    }
+
+    /// Load and validate parameters from a TOML document, see [`ParametersConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let params = Parameters::from_toml_str(r#"name = "PARAM_MESSAGE_2_CARRY_2""#).unwrap();
+    /// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    pub fn from_toml_str(toml: &str) -> Result<Self, ParametersError> {
+        toml::from_str::<ParametersConfig>(toml)
+            .map_err(ParametersError::Toml)?
+            .resolve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_parameters_round_trip() {
+        for (name, params) in NAMED_PARAMETERS {
+            assert_eq!(Parameters::from_name(name).unwrap(), *params);
+            let found = params.registered_name().unwrap();
+            assert_eq!(Parameters::from_name(found).unwrap(), *params);
+        }
+    }
+
+    #[test]
+    fn test_named_parameters_are_valid() {
+        for (name, params) in NAMED_PARAMETERS {
+            assert!(params.validate().is_ok(), "{name} is not valid");
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let mut params = PARAM_MESSAGE_2_CARRY_2;
+        params.message_modulus = MessageModulus(3);
+        assert!(params.validate().is_err());
+
+        let mut params = PARAM_MESSAGE_2_CARRY_2;
+        params.carry_modulus = CarryModulus(params.polynomial_size.0);
+        assert!(params.validate().is_err());
+
+        let mut params = PARAM_MESSAGE_2_CARRY_2;
+        params.lwe_modular_std_dev = StandardDev(f64::NAN);
+        assert!(params.validate().is_err());
+
+        assert!(matches!(
+            Parameters::from_name("PARAM_MESSAGE_2_CARRY_9"),
+            Err(ParametersError::UnknownName(_))
+        ));
+    }
+
+    #[test]
+    fn test_parameters_config_serde() {
+        let config = ParametersConfig::from(PARAM_MESSAGE_2_CARRY_2);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"name":"PARAM_MESSAGE_2_CARRY_2"}"#);
+
+        let mut params = PARAM_MESSAGE_2_CARRY_2;
+        params.lwe_dimension = LweDimension(params.lwe_dimension.0 + 1);
+        let config = ParametersConfig::from(params);
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized: ParametersConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.resolve().unwrap(), params);
+    }
+
+    #[cfg(feature = "parameters-config")]
+    #[test]
+    fn test_parameters_from_toml() {
+        let params = Parameters::from_toml_str(r#"name = "PARAM_MESSAGE_3_CARRY_3""#).unwrap();
+        assert_eq!(params, PARAM_MESSAGE_3_CARRY_3);
+
+        let toml = toml::to_string(&PARAM_MESSAGE_3_CARRY_3).unwrap();
+        let params = Parameters::from_toml_str(&toml).unwrap();
+        assert_eq!(params, PARAM_MESSAGE_3_CARRY_3);
+
+        assert!(Parameters::from_toml_str(r#"name = "PARAM_UNKNOWN""#).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Module with the definition of the CompactPublicKey.
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{CiphertextBig, CompactCiphertextList};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::Parameters;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A structure containing a compact public key.
+///
+/// The key is much smaller than a [`PublicKeyBig`](crate::shortint::PublicKeyBig) and encrypts
+/// lists of messages into a [`CompactCiphertextList`], which reduces the upload size when
+/// submitting many ciphertexts to a server. The expanded ciphertexts are [`CiphertextBig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompactPublicKey {
+    pub(crate) key: LweCompactPublicKeyOwned<u64>,
+    pub parameters: Parameters,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for CompactPublicKey { key });
+
+impl CompactPublicKey {
+    /// Generate a compact public key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::client_key::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::public_key::CompactPublicKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let pk = CompactPublicKey::new(&cks);
+    /// ```
+    pub fn new(client_key: &ClientKey) -> CompactPublicKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compact_public_key(client_key).unwrap()
+        })
+    }
+
+    /// Encrypt a small integer message using the compact public key.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// // Encryption of one message that is within the encrypted message modulus:
+    /// let msg = 3;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt(&self, message: u64) -> CiphertextBig {
+        let mut expanded = self.encrypt_slice(&[message]).expand();
+        expanded.pop().unwrap()
+    }
+
+    /// Encrypt a slice of small integer messages in a [`CompactCiphertextList`].
+    ///
+    /// The input messages are reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = [0, 1, 2, 3, 4, 5];
+    /// let compact_list = pk.encrypt_slice(&msgs);
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// for (ct, msg) in compact_list.expand().iter().zip(msgs) {
+    ///     assert_eq!(cks.decrypt(ct), msg % modulus);
+    /// }
+    /// ```
+    pub fn encrypt_slice(&self, messages: &[u64]) -> CompactCiphertextList {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_slice_with_compact_public_key(self, messages)
+                .unwrap()
+        })
+    }
+
+    /// Encrypt the small integer messages yielded by an iterator in a [`CompactCiphertextList`].
+    ///
+    /// See [`CompactPublicKey::encrypt_slice`].
+    pub fn encrypt_iter(&self, messages: impl Iterator<Item = u64>) -> CompactCiphertextList {
+        let messages = messages.collect::<Vec<_>>();
+        self.encrypt_slice(&messages)
+    }
+}
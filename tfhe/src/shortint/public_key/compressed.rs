@@ -17,6 +17,8 @@ pub struct CompressedPublicKeyBase<OpOrder: PBSOrderMarker> {
     pub _order_marker: std::marker::PhantomData<OpOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[OpOrder: PBSOrderMarker] for CompressedPublicKeyBase<OpOrder> { lwe_public_key });
+
 pub type CompressedPublicKeyBig = CompressedPublicKeyBase<KeyswitchBootstrap>;
 pub type CompressedPublicKeySmall = CompressedPublicKeyBase<BootstrapKeyswitch>;
 
@@ -17,6 +17,8 @@ pub struct PublicKeyBase<OpOrder: PBSOrderMarker> {
     pub _order_marker: std::marker::PhantomData<OpOrder>,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl[OpOrder: PBSOrderMarker] for PublicKeyBase<OpOrder> { lwe_public_key });
+
 pub type PublicKeyBig = PublicKeyBase<KeyswitchBootstrap>;
 pub type PublicKeySmall = PublicKeyBase<BootstrapKeyswitch>;
 
@@ -0,0 +1,328 @@
+//! Validation of the ciphertexts received by a server.
+//!
+//! A server cannot trust the ciphertexts sent by its clients: a blob may not deserialize to a
+//! ciphertext of the server parameters, and the metadata of a well-formed ciphertext (its degree)
+//! are chosen by the sender, so an operation relying on them may overflow the carries without the
+//! server noticing. A malicious client may also craft a ciphertext with a noise chosen to learn
+//! something from the results.
+//!
+//! A [`Sanitizer`] processes the incoming ciphertexts in one pass before they enter the compute
+//! pipeline:
+//!
+//! 1. structural validation: the blob must be the bincode serialization of a single ciphertext
+//!    whose LWE size, moduli and degree match the [`ServerKey`];
+//! 2. optional re-randomization: an encryption of zero under a [`PublicKeyBase`] is added to the
+//!    ciphertext, so its mask is no longer the one chosen by the sender;
+//! 3. optional degree normalization: the carries are cleared with a PBS, which also replaces the
+//!    noise of the ciphertext by the noise of a freshly bootstrapped ciphertext and makes its
+//!    degree trustworthy again.
+//!
+//! The re-randomization adds the noise of a public key encryption, services which do not
+//! normalize the degrees must account for it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::sanitizer::{SanitizeError, Sanitizer};
+//! use tfhe::shortint::{gen_keys, CiphertextBig, PublicKeyBig};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let pk = PublicKeyBig::new(&cks);
+//!
+//! // Client side
+//! let ct = cks.encrypt(3);
+//! let serialized = bincode::serialize(&ct).unwrap();
+//!
+//! // Server side
+//! let sanitizer = Sanitizer::new(&sks)
+//!     .with_rerandomization(&pk)
+//!     .with_degree_normalization(true);
+//!
+//! let ct: CiphertextBig = sanitizer.sanitize(&serialized).unwrap();
+//! assert_eq!(cks.decrypt(&ct), 3);
+//!
+//! // Truncated blobs are rejected
+//! let res = sanitizer.sanitize(&serialized[..serialized.len() - 1]);
+//! assert!(matches!(res, Err(SanitizeError::Deserialization(_))));
+//! ```
+use crate::core_crypto::algorithms::lwe_ciphertext_add_assign;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::{CiphertextBase, PBSOrder, PBSOrderMarker, PublicKeyBase, ServerKey};
+use bincode::Options;
+use rayon::prelude::*;
+
+/// Error returned when an incoming ciphertext is rejected.
+#[derive(Debug)]
+pub enum SanitizeError {
+    /// The input is not the serialization of a single ciphertext
+    Deserialization(bincode::Error),
+    LweSizeMismatch {
+        expected: usize,
+        got: usize,
+    },
+    CiphertextModulusMismatch {
+        expected: CiphertextModulus,
+        got: CiphertextModulus,
+    },
+    MessageModulusMismatch {
+        expected: MessageModulus,
+        got: MessageModulus,
+    },
+    CarryModulusMismatch {
+        expected: CarryModulus,
+        got: CarryModulus,
+    },
+    /// The degree is larger than the maximum degree of the server key
+    DegreeTooLarge {
+        max: usize,
+        got: usize,
+    },
+}
+
+impl std::fmt::Display for SanitizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deserialization(err) => write!(f, "Invalid ciphertext serialization: {err}"),
+            Self::LweSizeMismatch { expected, got } => {
+                write!(f, "Expected a LWE size of {expected}, got {got}")
+            }
+            Self::CiphertextModulusMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected a ciphertext modulus of {expected:?}, got {got:?}"
+                )
+            }
+            Self::MessageModulusMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected a message modulus of {}, got {}",
+                    expected.0, got.0
+                )
+            }
+            Self::CarryModulusMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Expected a carry modulus of {}, got {}",
+                    expected.0, got.0
+                )
+            }
+            Self::DegreeTooLarge { max, got } => {
+                write!(f, "Degree {got} exceeds the maximum degree {max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SanitizeError {}
+
+impl From<bincode::Error> for SanitizeError {
+    fn from(err: bincode::Error) -> Self {
+        Self::Deserialization(err)
+    }
+}
+
+/// Validates, re-randomizes and normalizes incoming ciphertexts, see the
+/// [module documentation](self).
+#[derive(Clone, Copy)]
+pub struct Sanitizer<'a, OpOrder: PBSOrderMarker> {
+    server_key: &'a ServerKey,
+    public_key: Option<&'a PublicKeyBase<OpOrder>>,
+    normalize_degree: bool,
+}
+
+impl<'a, OpOrder: PBSOrderMarker> Sanitizer<'a, OpOrder> {
+    /// Create a sanitizer only validating the ciphertexts against `server_key`.
+    pub fn new(server_key: &'a ServerKey) -> Self {
+        Self {
+            server_key,
+            public_key: None,
+            normalize_degree: false,
+        }
+    }
+
+    /// Re-randomize the ciphertexts by adding an encryption of zero under `public_key`.
+    pub fn with_rerandomization(mut self, public_key: &'a PublicKeyBase<OpOrder>) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Clear the carries of the ciphertexts with a PBS, their degree then being at most
+    /// `message_modulus - 1` whatever degree the sender declared.
+    pub fn with_degree_normalization(mut self, normalize_degree: bool) -> Self {
+        self.normalize_degree = normalize_degree;
+        self
+    }
+
+    /// Deserialize a ciphertext from its bincode serialization and sanitize it.
+    ///
+    /// Trailing bytes after the ciphertext are rejected.
+    pub fn sanitize(&self, serialized: &[u8]) -> Result<CiphertextBase<OpOrder>, SanitizeError> {
+        let ct = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize(serialized)?;
+        self.sanitize_ciphertext(ct)
+    }
+
+    /// Sanitize a batch of serialized ciphertexts in parallel.
+    ///
+    /// The whole batch is rejected with the error of the first invalid ciphertext.
+    pub fn sanitize_all<B>(
+        &self,
+        serialized: &[B],
+    ) -> Result<Vec<CiphertextBase<OpOrder>>, SanitizeError>
+    where
+        B: AsRef<[u8]> + Sync,
+    {
+        serialized
+            .par_iter()
+            .map(|serialized| self.sanitize(serialized.as_ref()))
+            .collect()
+    }
+
+    /// Sanitize an already deserialized ciphertext.
+    pub fn sanitize_ciphertext(
+        &self,
+        mut ct: CiphertextBase<OpOrder>,
+    ) -> Result<CiphertextBase<OpOrder>, SanitizeError> {
+        self.validate(&ct)?;
+
+        if let Some(public_key) = self.public_key {
+            let zero = public_key.encrypt(0);
+            lwe_ciphertext_add_assign(&mut ct.ct, &zero.ct);
+        }
+
+        if self.normalize_degree {
+            self.server_key.message_extract_assign(&mut ct);
+        }
+
+        Ok(ct)
+    }
+
+    /// Check that a ciphertext can be used with the server key.
+    pub fn validate(&self, ct: &CiphertextBase<OpOrder>) -> Result<(), SanitizeError> {
+        let sks = self.server_key;
+
+        let expected_lwe_dimension = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => sks.bootstrapping_key.output_lwe_dimension(),
+            PBSOrder::BootstrapKeyswitch => sks.bootstrapping_key.input_lwe_dimension(),
+        };
+        let expected = expected_lwe_dimension.to_lwe_size().0;
+        let got = ct.ct.lwe_size().0;
+        if got != expected {
+            return Err(SanitizeError::LweSizeMismatch { expected, got });
+        }
+
+        if ct.ct.ciphertext_modulus() != sks.ciphertext_modulus {
+            return Err(SanitizeError::CiphertextModulusMismatch {
+                expected: sks.ciphertext_modulus,
+                got: ct.ct.ciphertext_modulus(),
+            });
+        }
+
+        if ct.message_modulus != sks.message_modulus {
+            return Err(SanitizeError::MessageModulusMismatch {
+                expected: sks.message_modulus,
+                got: ct.message_modulus,
+            });
+        }
+
+        if ct.carry_modulus != sks.carry_modulus {
+            return Err(SanitizeError::CarryModulusMismatch {
+                expected: sks.carry_modulus,
+                got: ct.carry_modulus,
+            });
+        }
+
+        if ct.degree.0 > sks.max_degree.0 {
+            return Err(SanitizeError::DegreeTooLarge {
+                max: sks.max_degree.0,
+                got: ct.degree.0,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::ciphertext::Degree;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    use crate::shortint::{CiphertextBig, CiphertextSmall, PublicKeyBig};
+
+    #[test]
+    fn test_sanitizer_rejects_invalid_ciphertexts() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let other_keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+
+        let sanitizer = Sanitizer::new(sks);
+
+        let ct = cks.encrypt(2);
+        let serialized = bincode::serialize(&ct).unwrap();
+        let sanitized: CiphertextBig = sanitizer.sanitize(&serialized).unwrap();
+        assert_eq!(cks.decrypt(&sanitized), 2);
+
+        let mut trailing = serialized.clone();
+        trailing.push(0);
+        assert!(matches!(
+            sanitizer.sanitize(&trailing),
+            Err(SanitizeError::Deserialization(_))
+        ));
+
+        // The small ciphertexts are serialized with their PBS order
+        let small = bincode::serialize(&cks.encrypt_small(2)).unwrap();
+        assert!(matches!(
+            sanitizer.sanitize(&small),
+            Err(SanitizeError::Deserialization(_))
+        ));
+
+        let other = other_keys.client_key().encrypt(1);
+        assert!(matches!(
+            sanitizer.sanitize_ciphertext(other),
+            Err(SanitizeError::LweSizeMismatch { .. })
+        ));
+
+        let mut forged = ct;
+        forged.degree = Degree(sks.max_degree.0 + 1);
+        assert!(matches!(
+            sanitizer.sanitize_ciphertext(forged),
+            Err(SanitizeError::DegreeTooLarge { .. })
+        ));
+
+        let small_sanitizer = Sanitizer::<crate::shortint::ciphertext::BootstrapKeyswitch>::new(sks);
+        let sanitized: CiphertextSmall = small_sanitizer.sanitize(&small).unwrap();
+        assert_eq!(cks.decrypt(&sanitized), 2);
+    }
+
+    #[test]
+    fn test_sanitizer_rerandomizes_and_normalizes() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let pk = PublicKeyBig::new(cks);
+
+        let sanitizer = Sanitizer::new(sks)
+            .with_rerandomization(&pk)
+            .with_degree_normalization(true);
+
+        let msg = 3;
+        let mut ct = cks.encrypt(msg);
+        sks.unchecked_scalar_add_assign(&mut ct, 2);
+        let serialized = [
+            bincode::serialize(&ct).unwrap(),
+            bincode::serialize(&ct).unwrap(),
+        ];
+
+        let sanitized = sanitizer.sanitize_all(&serialized).unwrap();
+        for sanitized in sanitized.iter() {
+            assert_ne!(sanitized.ct, ct.ct);
+            assert_eq!(sanitized.degree.0, sks.message_modulus.0 - 1);
+            assert_eq!(cks.decrypt(sanitized), (msg + 2) % 4);
+        }
+        // Each ciphertext gets its own randomness
+        assert_ne!(sanitized[0].ct, sanitized[1].ct);
+    }
+}
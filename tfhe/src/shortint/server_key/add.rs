@@ -136,6 +136,42 @@ impl ServerKey {
         self.message_extract_assign(ct_left);
     }
 
+    /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::add`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(2);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an addition:
+    /// sks.add_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(2 + 1, res);
+    /// ```
+    pub fn add_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.add_assign(ct_out, ct_right);
+    }
+
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -239,6 +275,44 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_add`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(2);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an addition:
+    /// sks.unchecked_add_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(2 + 1, res);
+    /// ```
+    pub fn unchecked_add_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_add_into(ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Verify if ct_left and ct_right can be added together.
     ///
     /// This checks that the sum of their degree is
@@ -284,6 +358,46 @@ impl ServerKey {
         final_operation_count <= self.max_degree.0
     }
 
+    /// Return the number of unchecked additions of ciphertexts with empty carries which can be
+    /// chained before the carries have to be cleared.
+    ///
+    /// This is computed from the [`MaxDegree`](crate::shortint::server_key::MaxDegree) of the
+    /// server key and its message modulus: adding `n` ciphertexts together is `n - 1` additions,
+    /// and the result stays correct as long as the degree of the sum, `n * (message_modulus - 1)`,
+    /// does not exceed the maximum degree.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let num_additions = sks.max_noise_free_additions();
+    /// assert_eq!(num_additions, 4);
+    ///
+    /// let mut ct_res = ct.clone();
+    /// for _ in 0..num_additions {
+    ///     sks.unchecked_add_assign(&mut ct_res, &ct);
+    /// }
+    /// assert_eq!(cks.decrypt_message_and_carry(&ct_res), msg * (num_additions as u64 + 1));
+    ///
+    /// // One more addition would exceed the carry space
+    /// assert!(!sks.is_add_possible(&ct_res, &ct));
+    /// ```
+    pub fn max_noise_free_additions(&self) -> usize {
+        let clean_degree = self.message_modulus.0 - 1;
+        if clean_degree == 0 {
+            return usize::MAX;
+        }
+
+        (self.max_degree.0 / clean_degree).saturating_sub(1)
+    }
+
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
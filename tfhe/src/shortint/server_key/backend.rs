@@ -0,0 +1,770 @@
+//! Module with the definition of the programmable bootstrapping backends.
+//!
+//! The implementation used to compute the PBS of a [`ServerKey`] is chosen at runtime with
+//! [`ServerKey::set_bootstrap_backend`], the backends compiled in the library can be listed with
+//! [`available_backends`] and their features queried with [`BootstrapBackend::capabilities`].
+//!
+//! The FFT backends compute the exact same function, ciphertexts produced with one of them are
+//! bit for bit equal to the ones produced by the other for the same inputs.
+//!
+//! The [`NttBackend`] computes the polynomial products of the bootstrap exactly, with a number
+//! theoretic transform over two primes recombined with the CRT. Its outputs do not carry the
+//! rounding errors of the floating point FFT, so they are not bit for bit equal to the outputs of
+//! the FFT backends, they decrypt to the same messages. The transform needs the bootstrapping key
+//! in the standard domain and the backend is therefore only available for
+//! [`ShortintBootstrappingKey::LazySeeded`] keys.
+
+use super::{ServerKey, ShortintBootstrappingKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::{DecompositionLevelCount, LweCiphertextCount};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::core_crypto::ntt_impl::ntt64::math::Ntt;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::{CiphertextBase, PBSOrder, PBSOrderMarker};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// Features supported by a [`BootstrapBackend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    /// The backend can bootstrap with a [`ShortintBootstrappingKey::Classic`] key, which is stored
+    /// in the Fourier domain.
+    pub fourier_key: bool,
+    /// The backend can bootstrap with a [`ShortintBootstrappingKey::LazySeeded`] key.
+    pub lazy_seeded_key: bool,
+    /// The backend spreads the bootstraps of a batch over several threads.
+    pub parallel_batches: bool,
+}
+
+/// An implementation of the programmable bootstrapping.
+pub trait BootstrapBackend: Send + Sync {
+    /// Name identifying the backend.
+    fn name(&self) -> &'static str;
+
+    fn capabilities(&self) -> BackendCapabilities;
+
+    /// Compute a programmable bootstrap of `input` with `accumulator` and write the result in
+    /// `output`.
+    ///
+    /// `buffers` is resized as needed.
+    fn programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    );
+
+    /// Compute a programmable bootstrap of `input` with `accumulator` only using the
+    /// `level_count` most significant decomposition levels of the bootstrapping key, see
+    /// [`PbsMode::Approximate`](super::PbsMode::Approximate).
+    ///
+    /// The default implementation ignores `level_count` and computes an exact bootstrap with
+    /// [`BootstrapBackend::programmable_bootstrap`].
+    fn approximate_programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        level_count: DecompositionLevelCount,
+        buffers: &mut ComputationBuffers,
+    ) {
+        let _ = level_count;
+        self.programmable_bootstrap(bootstrapping_key, input, output, accumulator, buffers);
+    }
+
+    /// Keyswitch `ct` with `key_switching_key`, then compute a programmable bootstrap of the
+    /// result with `accumulator` and write it back in `ct`.
+    ///
+    /// `buffer_lwe_after_ks` has the output LWE size of `key_switching_key`, it is reused from
+    /// one call to the next to hold the keyswitched ciphertext by backends which do not fuse the
+    /// keyswitch in the bootstrap.
+    ///
+    /// The default implementation keyswitches into `buffer_lwe_after_ks` and calls
+    /// [`BootstrapBackend::programmable_bootstrap`].
+    fn keyswitch_programmable_bootstrap_assign(
+        &self,
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        mut ct: LweCiphertextMutView<'_, u64>,
+        mut buffer_lwe_after_ks: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        keyswitch_lwe_ciphertext(key_switching_key, &ct, &mut buffer_lwe_after_ks);
+        self.programmable_bootstrap(
+            bootstrapping_key,
+            buffer_lwe_after_ks.as_view(),
+            ct.as_mut_view(),
+            accumulator,
+            buffers,
+        );
+    }
+
+    /// Compute the programmable bootstrap of each ciphertext of `inputs` with `accumulator` and
+    /// write the results in `outputs`.
+    ///
+    /// The default implementation bootstraps the ciphertexts one after the other.
+    fn programmable_bootstrap_batch(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        inputs: LweCiphertextListView<'_, u64>,
+        mut outputs: LweCiphertextListMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+    ) {
+        assert_eq!(
+            inputs.lwe_ciphertext_count(),
+            outputs.lwe_ciphertext_count(),
+            "Mismatched number of input ({:?}) and output ({:?}) ciphertexts",
+            inputs.lwe_ciphertext_count(),
+            outputs.lwe_ciphertext_count()
+        );
+
+        let mut buffers = ComputationBuffers::new();
+        for (input, output) in inputs.iter().zip(outputs.iter_mut()) {
+            self.programmable_bootstrap(
+                bootstrapping_key,
+                input,
+                output,
+                accumulator.as_view(),
+                &mut buffers,
+            );
+        }
+    }
+}
+
+/// Return the number of bytes of scratch memory needed by the FFT based programmable bootstrap
+/// with the given key.
+pub(crate) fn fft_programmable_bootstrap_scratch_bytes(
+    bootstrapping_key: &ShortintBootstrappingKey,
+) -> usize {
+    let fft = Fft::new(bootstrapping_key.polynomial_size());
+    let fft = fft.as_view();
+
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic(fourier_bsk) => {
+            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required()
+        }
+        ShortintBootstrappingKey::LazySeeded(seeded_bsk) => {
+            programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement::<u64>(
+                seeded_bsk.glwe_size(),
+                seeded_bsk.polynomial_size(),
+                seeded_bsk.decomposition_level_count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required()
+        }
+    }
+}
+
+/// Compute a programmable bootstrap with the FFT based implementation of `core_crypto`.
+fn fft_programmable_bootstrap(
+    bootstrapping_key: &ShortintBootstrappingKey,
+    input: LweCiphertextView<'_, u64>,
+    mut output: LweCiphertextMutView<'_, u64>,
+    accumulator: GlweCiphertextView<'_, u64>,
+    buffers: &mut ComputationBuffers,
+) {
+    buffers.resize(fft_programmable_bootstrap_scratch_bytes(bootstrapping_key));
+
+    let fft = Fft::new(bootstrapping_key.polynomial_size());
+    let fft = fft.as_view();
+    let stack = buffers.stack();
+
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic(fourier_bsk) => {
+            programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &input,
+                &mut output,
+                &accumulator,
+                fourier_bsk,
+                fft,
+                stack,
+            );
+        }
+        ShortintBootstrappingKey::LazySeeded(seeded_bsk) => {
+            programmable_bootstrap_lwe_ciphertext_with_seeded_bsk_mem_optimized(
+                &input,
+                &mut output,
+                &accumulator,
+                seeded_bsk,
+                fft,
+                stack,
+            );
+        }
+    }
+}
+
+/// Compute an approximate programmable bootstrap with the FFT based implementation of
+/// `core_crypto`.
+///
+/// Only [`ShortintBootstrappingKey::Classic`] keys skip the least significant levels, the GGSWs of
+/// a [`ShortintBootstrappingKey::LazySeeded`] key are decompressed whole and bootstrapped exactly.
+fn fft_approximate_programmable_bootstrap(
+    bootstrapping_key: &ShortintBootstrappingKey,
+    input: LweCiphertextView<'_, u64>,
+    mut output: LweCiphertextMutView<'_, u64>,
+    accumulator: GlweCiphertextView<'_, u64>,
+    level_count: DecompositionLevelCount,
+    buffers: &mut ComputationBuffers,
+) {
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic(fourier_bsk) => {
+            buffers.resize(fft_programmable_bootstrap_scratch_bytes(bootstrapping_key));
+
+            let fft = Fft::new(fourier_bsk.polynomial_size());
+            let fft = fft.as_view();
+            let stack = buffers.stack();
+
+            approximate_programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &input,
+                &mut output,
+                &accumulator,
+                fourier_bsk,
+                level_count,
+                fft,
+                stack,
+            );
+        }
+        ShortintBootstrappingKey::LazySeeded(_) => {
+            fft_programmable_bootstrap(bootstrapping_key, input, output, accumulator, buffers);
+        }
+    }
+}
+
+/// Compute a keyswitch followed by a programmable bootstrap with the FFT based implementation of
+/// `core_crypto`.
+///
+/// With a [`ShortintBootstrappingKey::Classic`] key the keyswitch is fused in the blind rotation
+/// with [`keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized`] and
+/// `buffer_lwe_after_ks` is left untouched, with a [`ShortintBootstrappingKey::LazySeeded`] key
+/// the keyswitched ciphertext is written in `buffer_lwe_after_ks` before being bootstrapped.
+fn fft_keyswitch_programmable_bootstrap_assign(
+    key_switching_key: &LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &ShortintBootstrappingKey,
+    mut ct: LweCiphertextMutView<'_, u64>,
+    mut buffer_lwe_after_ks: LweCiphertextMutView<'_, u64>,
+    accumulator: GlweCiphertextView<'_, u64>,
+    buffers: &mut ComputationBuffers,
+) {
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic(fourier_bsk) => {
+            let fft = Fft::new(fourier_bsk.polynomial_size());
+            let fft = fft.as_view();
+
+            buffers.resize(
+                keyswitch_programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                    key_switching_key.input_key_lwe_dimension(),
+                    key_switching_key.decomposition_level_count(),
+                    fourier_bsk.glwe_size(),
+                    fourier_bsk.polynomial_size(),
+                    fft,
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            );
+            let stack = buffers.stack();
+
+            keyswitch_programmable_bootstrap_lwe_ciphertext_assign_mem_optimized(
+                &mut ct,
+                &accumulator,
+                key_switching_key,
+                fourier_bsk,
+                fft,
+                stack,
+            );
+        }
+        ShortintBootstrappingKey::LazySeeded(_) => {
+            keyswitch_lwe_ciphertext(key_switching_key, &ct, &mut buffer_lwe_after_ks);
+            fft_programmable_bootstrap(
+                bootstrapping_key,
+                buffer_lwe_after_ks.as_view(),
+                ct,
+                accumulator,
+                buffers,
+            );
+        }
+    }
+}
+
+/// FFT based backend computing everything on the calling thread, this is the default backend.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FftSerialBackend;
+
+impl BootstrapBackend for FftSerialBackend {
+    fn name(&self) -> &'static str {
+        "fft-serial"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            fourier_key: true,
+            lazy_seeded_key: true,
+            parallel_batches: false,
+        }
+    }
+
+    fn programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_programmable_bootstrap(bootstrapping_key, input, output, accumulator, buffers);
+    }
+
+    fn approximate_programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        level_count: DecompositionLevelCount,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_approximate_programmable_bootstrap(
+            bootstrapping_key,
+            input,
+            output,
+            accumulator,
+            level_count,
+            buffers,
+        );
+    }
+
+    fn keyswitch_programmable_bootstrap_assign(
+        &self,
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        ct: LweCiphertextMutView<'_, u64>,
+        buffer_lwe_after_ks: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_keyswitch_programmable_bootstrap_assign(
+            key_switching_key,
+            bootstrapping_key,
+            ct,
+            buffer_lwe_after_ks,
+            accumulator,
+            buffers,
+        );
+    }
+}
+
+/// FFT based backend bootstrapping the ciphertexts of a batch in parallel on the rayon thread
+/// pool.
+///
+/// Single bootstraps are computed like with the [`FftSerialBackend`].
+#[cfg(not(feature = "__wasm_api"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FftMultithreadBackend;
+
+#[cfg(not(feature = "__wasm_api"))]
+impl BootstrapBackend for FftMultithreadBackend {
+    fn name(&self) -> &'static str {
+        "fft-multithread"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            fourier_key: true,
+            lazy_seeded_key: true,
+            parallel_batches: true,
+        }
+    }
+
+    fn programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_programmable_bootstrap(bootstrapping_key, input, output, accumulator, buffers);
+    }
+
+    fn approximate_programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        level_count: DecompositionLevelCount,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_approximate_programmable_bootstrap(
+            bootstrapping_key,
+            input,
+            output,
+            accumulator,
+            level_count,
+            buffers,
+        );
+    }
+
+    fn keyswitch_programmable_bootstrap_assign(
+        &self,
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        ct: LweCiphertextMutView<'_, u64>,
+        buffer_lwe_after_ks: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        fft_keyswitch_programmable_bootstrap_assign(
+            key_switching_key,
+            bootstrapping_key,
+            ct,
+            buffer_lwe_after_ks,
+            accumulator,
+            buffers,
+        );
+    }
+
+    fn programmable_bootstrap_batch(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        inputs: LweCiphertextListView<'_, u64>,
+        mut outputs: LweCiphertextListMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+    ) {
+        use rayon::prelude::*;
+
+        assert_eq!(
+            inputs.lwe_ciphertext_count(),
+            outputs.lwe_ciphertext_count(),
+            "Mismatched number of input ({:?}) and output ({:?}) ciphertexts",
+            inputs.lwe_ciphertext_count(),
+            outputs.lwe_ciphertext_count()
+        );
+
+        inputs.par_iter().zip(outputs.par_iter_mut()).for_each_init(
+            ComputationBuffers::new,
+            |buffers, (input, output)| {
+                fft_programmable_bootstrap(
+                    bootstrapping_key,
+                    input,
+                    output,
+                    accumulator.as_view(),
+                    buffers,
+                );
+            },
+        );
+    }
+}
+
+/// NTT based backend computing everything on the calling thread.
+///
+/// Only supports [`ShortintBootstrappingKey::LazySeeded`] keys, each GGSW of the key is
+/// decompressed and transformed right before being used like with the FFT backends.
+/// Approximate bootstraps are computed exactly.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NttBackend;
+
+impl BootstrapBackend for NttBackend {
+    fn name(&self) -> &'static str {
+        "ntt"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            fourier_key: false,
+            lazy_seeded_key: true,
+            parallel_batches: false,
+        }
+    }
+
+    fn programmable_bootstrap(
+        &self,
+        bootstrapping_key: &ShortintBootstrappingKey,
+        input: LweCiphertextView<'_, u64>,
+        mut output: LweCiphertextMutView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        let seeded_bsk = match bootstrapping_key {
+            ShortintBootstrappingKey::LazySeeded(seeded_bsk) => seeded_bsk,
+            ShortintBootstrappingKey::Classic(_) => panic!(
+                "The {} backend does not support Fourier bootstrapping keys",
+                self.name()
+            ),
+        };
+
+        buffers.resize(
+            programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized_requirement(
+                seeded_bsk.glwe_size(),
+                seeded_bsk.polynomial_size(),
+                seeded_bsk.decomposition_level_count(),
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        let ntt = Ntt::new(seeded_bsk.polynomial_size());
+        let ntt = ntt.as_view();
+        let stack = buffers.stack();
+
+        programmable_bootstrap_ntt64_lwe_ciphertext_with_seeded_bsk_mem_optimized(
+            &input,
+            &mut output,
+            &accumulator,
+            seeded_bsk,
+            ntt,
+            stack,
+        );
+    }
+}
+
+/// Return the backends available in this build of the library.
+pub fn available_backends() -> Vec<Arc<dyn BootstrapBackend>> {
+    vec![
+        Arc::new(FftSerialBackend),
+        #[cfg(not(feature = "__wasm_api"))]
+        Arc::new(FftMultithreadBackend),
+        Arc::new(NttBackend),
+    ]
+}
+
+/// Check that `backend` can bootstrap with `bootstrapping_key`.
+///
+/// # Panics
+///
+/// Panics if the backend does not support the kind of the key.
+pub(crate) fn assert_backend_supports_key(
+    backend: &dyn BootstrapBackend,
+    bootstrapping_key: &ShortintBootstrappingKey,
+) {
+    let capabilities = backend.capabilities();
+    match bootstrapping_key {
+        ShortintBootstrappingKey::Classic(_) => assert!(
+            capabilities.fourier_key,
+            "The {} backend does not support Fourier bootstrapping keys",
+            backend.name()
+        ),
+        ShortintBootstrappingKey::LazySeeded(_) => assert!(
+            capabilities.lazy_seeded_key,
+            "The {} backend does not support lazy seeded bootstrapping keys",
+            backend.name()
+        ),
+    }
+}
+
+/// Return the backend named `name` if it is available in this build of the library.
+pub fn backend_by_name(name: &str) -> Option<Arc<dyn BootstrapBackend>> {
+    available_backends()
+        .into_iter()
+        .find(|backend| backend.name() == name)
+}
+
+/// Backend attached to a [`ServerKey`].
+///
+/// The backend is a runtime choice and is not part of the key material: it is not serialized,
+/// deserialized keys use the [`FftSerialBackend`].
+#[derive(Clone)]
+pub struct ServerKeyBackend(pub(crate) Arc<dyn BootstrapBackend>);
+
+impl Default for ServerKeyBackend {
+    fn default() -> Self {
+        Self(Arc::new(FftSerialBackend))
+    }
+}
+
+impl Debug for ServerKeyBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ServerKeyBackend")
+            .field(&self.0.name())
+            .finish()
+    }
+}
+
+impl PartialEq for ServerKeyBackend {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.name() == other.0.name()
+    }
+}
+
+impl ServerKey {
+    /// Return the backend used to compute the programmable bootstraps.
+    pub fn bootstrap_backend(&self) -> &dyn BootstrapBackend {
+        self.backend.0.as_ref()
+    }
+
+    /// Set the backend used to compute the programmable bootstraps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend does not support the bootstrapping key of the server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::backend::backend_by_name;
+    ///
+    /// let (cks, mut sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// sks.set_bootstrap_backend(backend_by_name("fft-multithread").unwrap());
+    ///
+    /// let msg = [0, 1, 2, 3];
+    /// let cts = msg.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// let acc = sks.generate_accumulator(|x| 3 - x % 4);
+    /// let cts_res = sks.apply_lookup_table_batch(&cts, &acc);
+    ///
+    /// for (&m, ct) in msg.iter().zip(cts_res.iter()) {
+    ///     assert_eq!(cks.decrypt(ct), 3 - m);
+    /// }
+    /// ```
+    pub fn set_bootstrap_backend(&mut self, backend: Arc<dyn BootstrapBackend>) {
+        assert_backend_supports_key(backend.as_ref(), &self.bootstrapping_key);
+        self.backend = ServerKeyBackend(backend);
+    }
+
+    /// Compute a programmable bootstrap of `input` with the backend of the server key.
+    pub(crate) fn programmable_bootstrap(
+        &self,
+        input: &LweCiphertext<impl Container<Element = u64>>,
+        output: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+        accumulator: &GlweCiphertext<impl Container<Element = u64>>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        crate::metrics::record_pbs(1);
+        self.bootstrap_backend().programmable_bootstrap(
+            &self.bootstrapping_key,
+            input.as_view(),
+            output.as_mut_view(),
+            accumulator.as_view(),
+            buffers,
+        );
+    }
+
+    /// Compute an approximate programmable bootstrap of `input` with the backend of the server
+    /// key, using `level_count` decomposition levels of the bootstrapping key.
+    pub(crate) fn approximate_programmable_bootstrap(
+        &self,
+        input: &LweCiphertext<impl Container<Element = u64>>,
+        output: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+        accumulator: &GlweCiphertext<impl Container<Element = u64>>,
+        level_count: DecompositionLevelCount,
+        buffers: &mut ComputationBuffers,
+    ) {
+        crate::metrics::record_pbs(1);
+        self.bootstrap_backend().approximate_programmable_bootstrap(
+            &self.bootstrapping_key,
+            input.as_view(),
+            output.as_mut_view(),
+            accumulator.as_view(),
+            level_count,
+            buffers,
+        );
+    }
+
+    /// Keyswitch `ct` then compute a programmable bootstrap of the result with the backend of the
+    /// server key, the result is written back in `ct`.
+    ///
+    /// `buffer_lwe_after_ks` holds the keyswitched ciphertext for backends which do not fuse the
+    /// keyswitch in the bootstrap.
+    pub(crate) fn keyswitch_programmable_bootstrap_assign(
+        &self,
+        ct: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+        buffer_lwe_after_ks: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+        accumulator: &GlweCiphertext<impl Container<Element = u64>>,
+        buffers: &mut ComputationBuffers,
+    ) {
+        crate::metrics::record_keyswitch(1);
+        crate::metrics::record_pbs(1);
+        self.bootstrap_backend()
+            .keyswitch_programmable_bootstrap_assign(
+                &self.key_switching_key,
+                &self.bootstrapping_key,
+                ct.as_mut_view(),
+                buffer_lwe_after_ks.as_mut_view(),
+                accumulator.as_view(),
+                buffers,
+            );
+    }
+
+    /// Apply the same lookup table to each ciphertext of `cts`.
+    ///
+    /// The bootstraps are computed with
+    /// [`BootstrapBackend::programmable_bootstrap_batch`], backends supporting parallel batches
+    /// compute them in parallel.
+    pub fn apply_lookup_table_batch<OpOrder: PBSOrderMarker>(
+        &self,
+        cts: &[CiphertextBase<OpOrder>],
+        acc: &LookupTableOwned,
+    ) -> Vec<CiphertextBase<OpOrder>> {
+        let count = LweCiphertextCount(cts.len());
+        let small_lwe_size = self.bootstrapping_key.input_lwe_dimension().to_lwe_size();
+        let big_lwe_size = self.bootstrapping_key.output_lwe_dimension().to_lwe_size();
+
+        let mut small_cts =
+            LweCiphertextList::new(0u64, small_lwe_size, count, self.ciphertext_modulus);
+        let mut big_cts =
+            LweCiphertextList::new(0u64, big_lwe_size, count, self.ciphertext_modulus);
+
+        let mut results = cts.to_vec();
+
+        crate::metrics::record_pbs(cts.len());
+
+        match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                crate::metrics::record_keyswitch(cts.len());
+                for (ct, mut small_ct) in cts.iter().zip(small_cts.iter_mut()) {
+                    keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut small_ct);
+                }
+
+                self.bootstrap_backend().programmable_bootstrap_batch(
+                    &self.bootstrapping_key,
+                    small_cts.as_view(),
+                    big_cts.as_mut_view(),
+                    acc.acc.as_view(),
+                );
+
+                for (result, big_ct) in results.iter_mut().zip(big_cts.iter()) {
+                    result.ct.as_mut().copy_from_slice(big_ct.as_ref());
+                }
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                for (ct, mut small_ct) in cts.iter().zip(small_cts.iter_mut()) {
+                    small_ct.as_mut().copy_from_slice(ct.ct.as_ref());
+                }
+
+                self.bootstrap_backend().programmable_bootstrap_batch(
+                    &self.bootstrapping_key,
+                    small_cts.as_view(),
+                    big_cts.as_mut_view(),
+                    acc.acc.as_view(),
+                );
+
+                crate::metrics::record_keyswitch(cts.len());
+                for (result, big_ct) in results.iter_mut().zip(big_cts.iter()) {
+                    keyswitch_lwe_ciphertext(&self.key_switching_key, &big_ct, &mut result.ct);
+                }
+            }
+        }
+
+        for result in results.iter_mut() {
+            result.degree = acc.degree;
+        }
+
+        results
+    }
+}
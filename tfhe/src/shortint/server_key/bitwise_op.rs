@@ -131,6 +131,42 @@ impl ServerKey {
         self.unchecked_bitand_assign(ct_left, rhs);
     }
 
+    /// Compute homomorphically an AND between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::bitand`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an AND:
+    /// sks.bitand_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 & 1, res);
+    /// ```
+    pub fn bitand_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.bitand_assign(ct_out, ct_right);
+    }
+
     /// Compute bitwise AND between two ciphertexts without checks.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -219,6 +255,44 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically an AND between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_bitand`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an AND:
+    /// sks.unchecked_bitand_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 & 1, res);
+    /// ```
+    pub fn unchecked_bitand_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_bitand_into(self, ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Compute bitwise AND between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
@@ -563,6 +637,42 @@ impl ServerKey {
         self.unchecked_bitxor_assign(ct_left, rhs);
     }
 
+    /// Compute homomorphically a XOR between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::bitxor`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// sks.bitxor_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 ^ 1, res);
+    /// ```
+    pub fn bitxor_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.bitxor_assign(ct_out, ct_right);
+    }
+
     /// Compute bitwise XOR between two ciphertexts without checks.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -655,6 +765,44 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically a XOR between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_bitxor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// sks.unchecked_bitxor_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 ^ 1, res);
+    /// ```
+    pub fn unchecked_bitxor_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_bitxor_into(self, ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Compute bitwise XOR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
@@ -999,6 +1147,42 @@ impl ServerKey {
         self.unchecked_bitor_assign(ct_left, rhs);
     }
 
+    /// Compute homomorphically an OR between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::bitor`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(2);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an OR:
+    /// sks.bitor_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(2 | 1, res);
+    /// ```
+    pub fn bitor_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.bitor_assign(ct_out, ct_right);
+    }
+
     /// Compute bitwise OR between two ciphertexts.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -1093,6 +1277,44 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically an OR between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_bitor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(2);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically an OR:
+    /// sks.unchecked_bitor_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(2 | 1, res);
+    /// ```
+    pub fn unchecked_bitor_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_bitor_into(self, ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Compute bitwise OR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
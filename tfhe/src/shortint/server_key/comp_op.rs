@@ -1,4 +1,4 @@
-use super::ServerKey;
+use super::{BivariateLookupTableOwned, ServerKey};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::server_key::CheckError;
 use crate::shortint::server_key::CheckError::CarryFull;
@@ -10,7 +10,146 @@ use crate::shortint::{CiphertextBase, PBSOrderMarker};
 // however, comparisons like equality do not have that, "==" does not have and "===",
 // ">=" is greater of equal, not greater_assign.
 
+/// The comparisons that can be computed between two ciphertexts in a single bivariate PBS.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOp {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl ComparisonOp {
+    /// Evaluate the comparison on clear values, returning 1 if it holds and 0 otherwise.
+    pub fn evaluate(self, lhs: u64, rhs: u64) -> u64 {
+        let result = match self {
+            Self::Greater => lhs > rhs,
+            Self::GreaterOrEqual => lhs >= rhs,
+            Self::Less => lhs < rhs,
+            Self::LessOrEqual => lhs <= rhs,
+            Self::Equal => lhs == rhs,
+            Self::NotEqual => lhs != rhs,
+        };
+        u64::from(result)
+    }
+}
+
 impl ServerKey {
+    /// Generate the bivariate lookup table of a comparison.
+    ///
+    /// The table can be generated once and applied with
+    /// [`ServerKey::unchecked_apply_lookup_table_bivariate`] to any pair of ciphertexts with empty
+    /// carries, the result encrypting 1 if the comparison holds and 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::ComparisonOp;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_comparison_accumulator(ComparisonOp::Greater);
+    ///
+    /// for (msg_1, msg_2) in [(3, 1), (1, 3), (2, 2)] {
+    ///     let ct_left = cks.encrypt(msg_1);
+    ///     let ct_right = cks.encrypt(msg_2);
+    ///
+    ///     let ct_res = sks.unchecked_apply_lookup_table_bivariate(&ct_left, &ct_right, &acc);
+    ///
+    ///     let res = cks.decrypt(&ct_res);
+    ///     assert_eq!((msg_1 > msg_2) as u64, res);
+    /// }
+    /// ```
+    pub fn generate_comparison_accumulator(&self, op: ComparisonOp) -> BivariateLookupTableOwned {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_comparison_accumulator(self, op).unwrap()
+        })
+    }
+
+    /// Compute homomorphically a comparison between two ciphertexts without checks.
+    ///
+    /// The comparison is done in a single bivariate PBS and the result encrypts 1 if it holds
+    /// and 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::ComparisonOp;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 1;
+    /// let msg_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.unchecked_comparison(&ct_left, &ct_right, ComparisonOp::LessOrEqual);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!((msg_1 <= msg_2) as u64, res);
+    /// ```
+    pub fn unchecked_comparison<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        op: ComparisonOp,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_comparison(self, ct_left, ct_right, op)
+                .unwrap()
+        })
+    }
+
+    /// Compute homomorphically a comparison between two ciphertexts.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::server_key::ComparisonOp;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 3;
+    /// let msg_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let mut ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.smart_comparison(&mut ct_left, &mut ct_right, ComparisonOp::NotEqual);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!((msg_1 != msg_2) as u64, res);
+    /// ```
+    pub fn smart_comparison<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+        op: ComparisonOp,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_comparison(self, ct_left, ct_right, op)
+                .unwrap()
+        })
+    }
+
     /// Compute homomorphically a `>` between two ciphertexts encrypting integer values.
     ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
@@ -24,6 +24,8 @@ pub struct CompressedServerKey {
     pub ciphertext_modulus: CiphertextModulus,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for CompressedServerKey { key_switching_key, bootstrapping_key });
+
 impl CompressedServerKey {
     /// Generate a compressed server key.
     ///
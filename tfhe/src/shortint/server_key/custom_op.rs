@@ -0,0 +1,278 @@
+//! Module with the definition of custom operations.
+//!
+//! Crates depending on this library can add operations to the [`ServerKey`] (lookup table
+//! pipelines, offloads to dedicated hardware, ...) by implementing [`CustomOp`] and registering
+//! the implementation with [`register_custom_op`]. A registered operation is then called by name
+//! with [`ServerKey::custom_op`], or with `custom_op` on the short integer types of the high level
+//! API.
+//!
+//! The operations only see the [`CustomOp`] trait and the [`CustomOpContext`] given to them, new
+//! capabilities are added to the context as methods so existing operations keep compiling.
+
+use super::ServerKey;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{BootstrapKeyswitch, KeyswitchBootstrap};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextBase, CiphertextBig, CiphertextSmall, PBSOrder, PBSOrderMarker};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, RwLock};
+
+/// Error returned when a custom operation cannot be evaluated.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CustomOpError {
+    /// No operation is registered with this name.
+    UnknownOp(String),
+    /// The operation was given a wrong number of ciphertexts.
+    WrongNumberOfInputs {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// The operation does not support ciphertexts with this PBS order.
+    UnsupportedPbsOrder { name: String, pbs_order: PBSOrder },
+    /// The operation failed, with a message given by its implementation.
+    Failed(String),
+}
+
+impl Display for CustomOpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownOp(name) => write!(f, "No custom operation is registered as '{name}'"),
+            Self::WrongNumberOfInputs {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The custom operation '{name}' expects {expected} inputs, got {found}"
+            ),
+            Self::UnsupportedPbsOrder { name, pbs_order } => write!(
+                f,
+                "The custom operation '{name}' does not support ciphertexts with the \
+                 {pbs_order:?} PBS order"
+            ),
+            Self::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for CustomOpError {}
+
+/// An operation computed with a [`ServerKey`], defined outside of this crate.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::shortint::server_key::custom_op::{
+///     register_custom_op, CustomOp, CustomOpContext, CustomOpError,
+/// };
+/// use tfhe::shortint::CiphertextBig;
+///
+/// struct Clamp {
+///     max: u64,
+/// }
+///
+/// impl CustomOp for Clamp {
+///     fn name(&self) -> &str {
+///         "clamp"
+///     }
+///
+///     fn num_inputs(&self) -> usize {
+///         1
+///     }
+///
+///     fn evaluate(
+///         &self,
+///         context: &CustomOpContext<'_>,
+///         inputs: &[&CiphertextBig],
+///     ) -> Result<CiphertextBig, CustomOpError> {
+///         let sks = context.server_key();
+///         let acc = sks.generate_accumulator(|x| x.min(self.max));
+///         Ok(sks.apply_lookup_table(inputs[0], &acc))
+///     }
+/// }
+///
+/// register_custom_op(Arc::new(Clamp { max: 2 }));
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+///
+/// let ct = cks.encrypt(3);
+/// let ct_res = sks.custom_op("clamp", &[&ct]).unwrap();
+/// assert_eq!(cks.decrypt(&ct_res), 2);
+/// ```
+pub trait CustomOp: Send + Sync {
+    /// Name under which the operation is registered.
+    fn name(&self) -> &str;
+
+    /// Number of ciphertexts the operation takes.
+    fn num_inputs(&self) -> usize;
+
+    /// Compute the operation on ciphertexts using the [`KeyswitchBootstrap`] order.
+    ///
+    /// `inputs` has [`CustomOp::num_inputs`] elements.
+    fn evaluate(
+        &self,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextBig],
+    ) -> Result<CiphertextBig, CustomOpError>;
+
+    /// Compute the operation on ciphertexts using the [`BootstrapKeyswitch`] order.
+    ///
+    /// `inputs` has [`CustomOp::num_inputs`] elements.
+    ///
+    /// The default implementation returns [`CustomOpError::UnsupportedPbsOrder`].
+    fn evaluate_small(
+        &self,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextSmall],
+    ) -> Result<CiphertextSmall, CustomOpError> {
+        let _ = (context, inputs);
+        Err(CustomOpError::UnsupportedPbsOrder {
+            name: self.name().to_owned(),
+            pbs_order: PBSOrder::BootstrapKeyswitch,
+        })
+    }
+}
+
+/// What a [`CustomOp`] has access to when it is evaluated.
+///
+/// The scratch memory used by the bootstraps is the one of the thread local
+/// [`ShortintEngine`], shared with the operations of the [`ServerKey`].
+pub struct CustomOpContext<'a> {
+    server_key: &'a ServerKey,
+}
+
+impl<'a> CustomOpContext<'a> {
+    /// The server key the operation is computed with.
+    pub fn server_key(&self) -> &'a ServerKey {
+        self.server_key
+    }
+
+    /// Keyswitch `input` with the key switching key of the server key.
+    pub fn keyswitch(
+        &self,
+        input: &LweCiphertext<impl Container<Element = u64>>,
+        output: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+    ) {
+        keyswitch_lwe_ciphertext(&self.server_key.key_switching_key, input, output);
+    }
+
+    /// Compute a programmable bootstrap of `input` with the backend of the server key, without
+    /// keyswitching before or after.
+    pub fn programmable_bootstrap(
+        &self,
+        input: &LweCiphertext<impl Container<Element = u64>>,
+        output: &mut LweCiphertext<impl ContainerMut<Element = u64>>,
+        accumulator: &GlweCiphertext<impl Container<Element = u64>>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            self.server_key.programmable_bootstrap(
+                input,
+                output,
+                accumulator,
+                engine.computation_buffers(),
+            );
+        });
+    }
+
+    /// Give `func` the scratch memory of the thread local engine, e.g. for an operation
+    /// computing its bootstraps with its own implementation.
+    ///
+    /// `func` must not call operations of the [`ServerKey`], which use the same engine.
+    pub fn with_computation_buffers<R>(
+        &self,
+        func: impl FnOnce(&mut ComputationBuffers) -> R,
+    ) -> R {
+        ShortintEngine::with_thread_local_mut(|engine| func(engine.computation_buffers()))
+    }
+}
+
+/// PBS orders of the ciphertexts a [`CustomOp`] can be computed on.
+///
+/// This trait is implemented for [`KeyswitchBootstrap`] and [`BootstrapKeyswitch`].
+pub trait CustomOpOrder: PBSOrderMarker {
+    #[doc(hidden)]
+    fn evaluate_custom_op(
+        op: &dyn CustomOp,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextBase<Self>],
+    ) -> Result<CiphertextBase<Self>, CustomOpError>;
+}
+
+impl CustomOpOrder for KeyswitchBootstrap {
+    fn evaluate_custom_op(
+        op: &dyn CustomOp,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextBig],
+    ) -> Result<CiphertextBig, CustomOpError> {
+        op.evaluate(context, inputs)
+    }
+}
+
+impl CustomOpOrder for BootstrapKeyswitch {
+    fn evaluate_custom_op(
+        op: &dyn CustomOp,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextSmall],
+    ) -> Result<CiphertextSmall, CustomOpError> {
+        op.evaluate_small(context, inputs)
+    }
+}
+
+static REGISTERED_OPS: RwLock<BTreeMap<String, Arc<dyn CustomOp>>> = RwLock::new(BTreeMap::new());
+
+/// Register a [`CustomOp`] under its [name](CustomOp::name), for all the server keys.
+///
+/// The operation previously registered with the same name is returned.
+pub fn register_custom_op(op: Arc<dyn CustomOp>) -> Option<Arc<dyn CustomOp>> {
+    let name = op.name().to_owned();
+    REGISTERED_OPS.write().unwrap().insert(name, op)
+}
+
+/// Remove the [`CustomOp`] registered as `name` and return it.
+pub fn unregister_custom_op(name: &str) -> Option<Arc<dyn CustomOp>> {
+    REGISTERED_OPS.write().unwrap().remove(name)
+}
+
+/// Return the [`CustomOp`] registered as `name`.
+pub fn custom_op_by_name(name: &str) -> Option<Arc<dyn CustomOp>> {
+    REGISTERED_OPS.read().unwrap().get(name).cloned()
+}
+
+/// Return the names of the registered [`CustomOp`]s, in alphabetical order.
+pub fn registered_custom_ops() -> Vec<String> {
+    REGISTERED_OPS.read().unwrap().keys().cloned().collect()
+}
+
+impl ServerKey {
+    /// Compute the [`CustomOp`] registered as `name` on `inputs`.
+    ///
+    /// See [`CustomOp`] for an example.
+    pub fn custom_op<OpOrder: CustomOpOrder>(
+        &self,
+        name: &str,
+        inputs: &[&CiphertextBase<OpOrder>],
+    ) -> Result<CiphertextBase<OpOrder>, CustomOpError> {
+        let op =
+            custom_op_by_name(name).ok_or_else(|| CustomOpError::UnknownOp(name.to_owned()))?;
+
+        if inputs.len() != op.num_inputs() {
+            return Err(CustomOpError::WrongNumberOfInputs {
+                name: name.to_owned(),
+                expected: op.num_inputs(),
+                found: inputs.len(),
+            });
+        }
+
+        let context = CustomOpContext { server_key: self };
+        OpOrder::evaluate_custom_op(op.as_ref(), &context, inputs)
+    }
+}
@@ -356,6 +356,409 @@ impl ServerKey {
         })
     }
 
+    /// Compute a modulo between two ciphertexts.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext carries are empty and clears them if it's not the case and
+    /// the operation requires it. It outputs a ciphertext whose carry is always empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.mod_(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt_small(clear_1);
+    /// let ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.mod_(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn mod_<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct_left.clone();
+        self.mod_assign(&mut ct_res, ct_right);
+        ct_res
+    }
+
+    /// Compute a modulo between two ciphertexts.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext carries are empty and clears them if it's not the case and
+    /// the operation requires it. It outputs a ciphertext whose carry is always empty.
+    ///
+    /// This means that when using only "default" operations, a given operation (like add for
+    /// example) has always the same performance characteristics from one call to another and
+    /// guarantees correctness by pre-emptively clearing carries of output ciphertexts.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.mod_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt_small(clear_1);
+    /// let ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.mod_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn mod_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) {
+        let tmp_rhs: CiphertextBase<OpOrder>;
+
+        if !ct_left.carry_is_empty() {
+            self.clear_carry_assign(ct_left);
+        }
+
+        let rhs = if ct_right.carry_is_empty() {
+            ct_right
+        } else {
+            tmp_rhs = self.clear_carry(ct_right);
+            &tmp_rhs
+        };
+
+        self.unchecked_mod_assign(ct_left, rhs);
+    }
+
+    /// Compute a modulo between two ciphertexts without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.unchecked_mod(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt_small(clear_1);
+    /// let ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.unchecked_mod(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn unchecked_mod<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_mod(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute a modulo between two ciphertexts without checks.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.unchecked_mod_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt_small(clear_1);
+    /// let ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.unchecked_mod_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn unchecked_mod_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_mod_assign(self, ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Compute a modulo between two ciphertexts.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.smart_mod(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt_small(clear_1);
+    /// let mut ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// let ct_res = sks.smart_mod(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn smart_mod<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) -> CiphertextBase<OpOrder> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_mod(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute a modulo between two ciphertexts.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A modulo by zero returns the numerator, i.e. `x % 0 == x`, so that
+    /// `x == (x / y) * y + x % y` holds for all inputs given that a division by zero returns 0.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.smart_mod_assign(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_SMALL_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt_small(clear_1);
+    /// let mut ct_2 = cks.encrypt_small(clear_2);
+    ///
+    /// // Compute homomorphically a modulo
+    /// sks.smart_mod_assign(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn smart_mod_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_mod_assign(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute a division between two ciphertexts, along with an encrypted flag telling whether
+    /// the divisor was zero.
+    ///
+    /// The quotient follows the convention of [`ServerKey::div`] (a division by zero returns 0),
+    /// the flag encrypts 1 if `ct_right` encrypts 0 and encrypts 0 otherwise. Both are returned in
+    /// _new_ ciphertexts whose carries are empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_1 = cks.encrypt(3);
+    /// let ct_2 = cks.encrypt(0);
+    ///
+    /// let (ct_quotient, ct_is_zero) = sks.div_with_zero_flag(&ct_1, &ct_2);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_quotient), 0);
+    /// assert_eq!(cks.decrypt(&ct_is_zero), 1);
+    /// ```
+    pub fn div_with_zero_flag<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+    ) -> (CiphertextBase<OpOrder>, CiphertextBase<OpOrder>) {
+        let tmp_rhs: CiphertextBase<OpOrder>;
+
+        let rhs = if ct_right.carry_is_empty() {
+            ct_right
+        } else {
+            tmp_rhs = self.clear_carry(ct_right);
+            &tmp_rhs
+        };
+
+        let (quotient, is_zero) =
+            rayon::join(|| self.div(ct_left, rhs), || self.scalar_equal(rhs, 0));
+
+        (quotient, is_zero)
+    }
+
     /// Alias to [`unchecked_scalar_div`](`Self::unchecked_scalar_div`) provided for convenience
     ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
@@ -0,0 +1,55 @@
+use super::ServerKey;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextBase, PBSOrderMarker};
+
+impl ServerKey {
+    /// Add fresh Gaussian noise of standard deviation `std_dev` to the ciphertext body.
+    ///
+    /// The noise of a ciphertext depends on the circuit which computed it, so decrypting it may
+    /// leak information on the circuit to the owner of the client key. Flooding the ciphertext
+    /// with a noise much larger than the circuit noise before sending it back hides this
+    /// fingerprint, the result being statistically close to a ciphertext with only the flooding
+    /// noise.
+    ///
+    /// `std_dev` is given on the torus, like the noise parameters of [`Parameters`]. It must be
+    /// chosen so that:
+    ///
+    /// - it is larger than the circuit noise by a factor of at least `2^k` to get `k` bits of
+    ///   statistical hiding;
+    /// - the total noise stays well below `1 / (4 * message_modulus * carry_modulus)` (half the
+    ///   distance between two encoded values, one bit of padding included), otherwise the
+    ///   decryption fails. Keeping it below a tenth of this bound makes failures negligible.
+    ///
+    /// The degree of the ciphertext is not modified.
+    ///
+    /// [`Parameters`]: crate::shortint::Parameters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Flood the ciphertext before sending it back to the client
+    /// sks.add_flooding_noise(&mut ct, StandardDev(2.0f64.powi(-20)));
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn add_flooding_noise<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        std_dev: impl DispersionParameter,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.add_flooding_noise_assign(ct, std_dev).unwrap()
+        })
+    }
+}
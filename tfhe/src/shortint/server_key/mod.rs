@@ -6,29 +6,48 @@ mod add;
 mod bitwise_op;
 mod comp_op;
 mod div_mod;
+mod flooding;
 mod mul;
 mod neg;
+mod pbs_order;
+#[cfg(not(feature = "__wasm_api"))]
+mod pipeline;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
 mod sub;
+mod vector;
 
+pub mod backend;
 pub mod compressed;
+pub mod custom_op;
+pub mod split;
+pub mod trace;
+pub use comp_op::ComparisonOp;
 pub use compressed::CompressedServerKey;
+pub use split::{BootstrapServerKey, KeyswitchServerKey};
 
 #[cfg(test)]
 mod tests;
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
 use crate::shortint::ciphertext::{CiphertextBase, Degree};
 use crate::shortint::client_key::ClientKey;
-use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::debug::{diff_field, field_path, DataHash, Mismatch, StructuralDiff};
+use crate::shortint::engine::{fill_accumulator_with_moduli, ShortintEngine};
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, MessageModulus, Parameters, ParametersError,
+};
+use crate::shortint::server_key::backend::ServerKeyBackend;
 use crate::shortint::PBSOrderMarker;
+use crate::size::FheSize;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display, Formatter};
 
@@ -36,6 +55,47 @@ use std::fmt::{Debug, Display, Formatter};
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct MaxDegree(pub usize);
 
+/// Precision of the programmable bootstraps of an operation.
+///
+/// The approximate mode is opt-in and selected per operation: it is meant for workloads that can
+/// tolerate rare errors on some blocks in exchange for faster bootstraps, like the inference of
+/// quantized machine learning models.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PbsMode {
+    /// Use all the decomposition levels of the bootstrapping key, the failure probability is the
+    /// one of the parameter set.
+    #[default]
+    Exact,
+    /// Drop the least significant decomposition level of the bootstrapping key.
+    ///
+    /// The blind rotation is faster, the external products computing one level less, but the
+    /// rounding error of the coarser decomposition makes the bootstrap fail much more often. The
+    /// mode is only meaningful with keys having more than one level: it is a no-op with keys
+    /// having a single level, and for a [`ShortintBootstrappingKey::LazySeeded`] key.
+    ///
+    /// The failure probability of this mode is not part of the guarantees of the parameter sets:
+    /// the targets returned by [`Parameters::metadata`](crate::shortint::Parameters::metadata)
+    /// only hold for exact bootstraps. [`PARAM_MESSAGE_2_CARRY_2_APPROX_PBS`] is a parameter set
+    /// with enough levels for this mode to be meaningful, its documentation gives the failure
+    /// probabilities of both modes.
+    ///
+    /// [`PARAM_MESSAGE_2_CARRY_2_APPROX_PBS`]: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_APPROX_PBS
+    Approximate,
+}
+
+impl PbsMode {
+    /// Return the number of decomposition levels used by a bootstrap in this mode, for a key with
+    /// `key_level_count` levels.
+    pub fn level_count(self, key_level_count: DecompositionLevelCount) -> DecompositionLevelCount {
+        match self {
+            Self::Exact => key_level_count,
+            Self::Approximate => {
+                DecompositionLevelCount(key_level_count.0.saturating_sub(1).max(1))
+            }
+        }
+    }
+}
+
 /// Error returned when the carry buffer is full.
 #[derive(Debug)]
 pub enum CheckError {
@@ -54,14 +114,131 @@ impl Display for CheckError {
 
 impl std::error::Error for CheckError {}
 
+/// The bootstrapping key of a [`ServerKey`].
+///
+/// Server keys serialized before this enum was introduced stored the Fourier key directly, they
+/// are read with the functions of the `migration` module (behind the `migration` feature), which
+/// wrap it in a [`ShortintBootstrappingKey::Classic`] key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ShortintBootstrappingKey {
+    /// Key fully converted to the Fourier domain, this is the fastest option.
+    Classic(FourierLweBootstrapKeyOwned),
+    /// Seeded key kept compressed in memory, each GGSW of the key is decompressed and converted
+    /// to the Fourier domain on the fly during the blind rotation.
+    ///
+    /// This divides the memory used by the key by `k + 1` (`k` being the GLWE
+    /// dimension) compared to [`ShortintBootstrappingKey::Classic`], at the cost of a slower PBS.
+    LazySeeded(SeededLweBootstrapKeyOwned<u64>),
+}
+
+impl FheSize for ShortintBootstrappingKey {
+    fn element_count(&self) -> usize {
+        match self {
+            Self::Classic(bsk) => bsk.element_count(),
+            Self::LazySeeded(bsk) => bsk.element_count(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Classic(bsk) => bsk.heap_size(),
+            Self::LazySeeded(bsk) => bsk.heap_size(),
+        }
+    }
+}
+
+impl ShortintBootstrappingKey {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Classic(bsk) => bsk.input_lwe_dimension(),
+            Self::LazySeeded(bsk) => bsk.input_lwe_dimension(),
+        }
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Classic(bsk) => bsk.output_lwe_dimension(),
+            Self::LazySeeded(bsk) => bsk.output_lwe_dimension(),
+        }
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        match self {
+            Self::Classic(bsk) => bsk.glwe_size(),
+            Self::LazySeeded(bsk) => bsk.glwe_size(),
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            Self::Classic(bsk) => bsk.polynomial_size(),
+            Self::LazySeeded(bsk) => bsk.polynomial_size(),
+        }
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match self {
+            Self::Classic(bsk) => bsk.decomposition_base_log(),
+            Self::LazySeeded(bsk) => bsk.decomposition_base_log(),
+        }
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match self {
+            Self::Classic(bsk) => bsk.decomposition_level_count(),
+            Self::LazySeeded(bsk) => bsk.decomposition_level_count(),
+        }
+    }
+
+    /// Return the key in the Fourier domain, or `None` for a
+    /// [`ShortintBootstrappingKey::LazySeeded`] key.
+    ///
+    /// The operations needing the whole key in the Fourier domain (like the WoPBS) convert lazy
+    /// seeded keys with [`ShortintBootstrappingKey::into_fourier`] first.
+    pub fn as_fourier(&self) -> Option<&FourierLweBootstrapKeyOwned> {
+        match self {
+            Self::Classic(bsk) => Some(bsk),
+            Self::LazySeeded(_) => None,
+        }
+    }
+
+    /// Convert the key to the Fourier domain, decompressing it first if needed.
+    pub fn into_fourier(self) -> FourierLweBootstrapKeyOwned {
+        match self {
+            Self::Classic(bsk) => bsk,
+            Self::LazySeeded(seeded_bsk) => {
+                let standard_bsk = seeded_bsk.decompress_into_lwe_bootstrap_key();
+
+                let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+                    standard_bsk.input_lwe_dimension(),
+                    standard_bsk.glwe_size(),
+                    standard_bsk.polynomial_size(),
+                    standard_bsk.decomposition_base_log(),
+                    standard_bsk.decomposition_level_count(),
+                );
+
+                convert_standard_lwe_bootstrap_key_to_fourier(&standard_bsk, &mut fourier_bsk);
+
+                fourier_bsk
+            }
+        }
+    }
+}
+
+impl From<FourierLweBootstrapKeyOwned> for ShortintBootstrappingKey {
+    fn from(bsk: FourierLweBootstrapKeyOwned) -> Self {
+        Self::Classic(bsk)
+    }
+}
+
 /// A structure containing the server public key.
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerKey {
     pub key_switching_key: LweKeyswitchKeyOwned<u64>,
-    pub bootstrapping_key: FourierLweBootstrapKeyOwned,
+    pub bootstrapping_key: ShortintBootstrappingKey,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
     // Size of the carry buffer
@@ -70,6 +247,172 @@ pub struct ServerKey {
     pub max_degree: MaxDegree,
     // Modulus use for computations on the ciphertext
     pub ciphertext_modulus: CiphertextModulus,
+    // Implementation used to compute the PBS, not part of the key material
+    #[serde(skip)]
+    pub(crate) backend: ServerKeyBackend,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for ServerKey { key_switching_key, bootstrapping_key });
+
+// The keys are summarized, their raw data would be megabytes of output
+impl Debug for ServerKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ksk = &self.key_switching_key;
+        let bsk = &self.bootstrapping_key;
+        let bsk_kind = match bsk {
+            ShortintBootstrappingKey::Classic(_) => "Classic",
+            ShortintBootstrappingKey::LazySeeded(_) => "LazySeeded",
+        };
+
+        f.debug_struct("ServerKey")
+            .field("message_modulus", &self.message_modulus)
+            .field("carry_modulus", &self.carry_modulus)
+            .field("max_degree", &self.max_degree)
+            .field("ciphertext_modulus", &self.ciphertext_modulus)
+            .field(
+                "key_switching_key",
+                &format_args!(
+                    "{{ input_lwe_dimension: {:?}, output_lwe_dimension: {:?}, \
+                    base_log: {:?}, level_count: {:?}, data: {:?} }}",
+                    ksk.input_key_lwe_dimension(),
+                    ksk.output_key_lwe_dimension(),
+                    ksk.decomposition_base_log(),
+                    ksk.decomposition_level_count(),
+                    DataHash::of(ksk.as_ref()),
+                ),
+            )
+            .field(
+                "bootstrapping_key",
+                &format_args!(
+                    "{bsk_kind} {{ input_lwe_dimension: {:?}, glwe_size: {:?}, \
+                    polynomial_size: {:?}, base_log: {:?}, level_count: {:?} }}",
+                    bsk.input_lwe_dimension(),
+                    bsk.glwe_size(),
+                    bsk.polynomial_size(),
+                    bsk.decomposition_base_log(),
+                    bsk.decomposition_level_count(),
+                ),
+            )
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl StructuralDiff for ServerKey {
+    fn diff_into(&self, other: &Self, path: &str, mismatches: &mut Vec<Mismatch>) {
+        diff_field(
+            path,
+            "message_modulus",
+            &self.message_modulus,
+            &other.message_modulus,
+            mismatches,
+        );
+        diff_field(
+            path,
+            "carry_modulus",
+            &self.carry_modulus,
+            &other.carry_modulus,
+            mismatches,
+        );
+        diff_field(
+            path,
+            "max_degree",
+            &self.max_degree,
+            &other.max_degree,
+            mismatches,
+        );
+        diff_field(
+            path,
+            "ciphertext_modulus",
+            &self.ciphertext_modulus,
+            &other.ciphertext_modulus,
+            mismatches,
+        );
+
+        let ksk_path = field_path(path, "key_switching_key");
+        let (ksk, other_ksk) = (&self.key_switching_key, &other.key_switching_key);
+        diff_field(
+            &ksk_path,
+            "input_lwe_dimension",
+            &ksk.input_key_lwe_dimension(),
+            &other_ksk.input_key_lwe_dimension(),
+            mismatches,
+        );
+        diff_field(
+            &ksk_path,
+            "output_lwe_dimension",
+            &ksk.output_key_lwe_dimension(),
+            &other_ksk.output_key_lwe_dimension(),
+            mismatches,
+        );
+        diff_field(
+            &ksk_path,
+            "base_log",
+            &ksk.decomposition_base_log(),
+            &other_ksk.decomposition_base_log(),
+            mismatches,
+        );
+        diff_field(
+            &ksk_path,
+            "level_count",
+            &ksk.decomposition_level_count(),
+            &other_ksk.decomposition_level_count(),
+            mismatches,
+        );
+        diff_field(
+            &ksk_path,
+            "data",
+            &DataHash::of(ksk.as_ref()),
+            &DataHash::of(other_ksk.as_ref()),
+            mismatches,
+        );
+
+        let bsk_path = field_path(path, "bootstrapping_key");
+        let (bsk, other_bsk) = (&self.bootstrapping_key, &other.bootstrapping_key);
+        diff_field(
+            &bsk_path,
+            "input_lwe_dimension",
+            &bsk.input_lwe_dimension(),
+            &other_bsk.input_lwe_dimension(),
+            mismatches,
+        );
+        diff_field(
+            &bsk_path,
+            "glwe_size",
+            &bsk.glwe_size(),
+            &other_bsk.glwe_size(),
+            mismatches,
+        );
+        diff_field(
+            &bsk_path,
+            "polynomial_size",
+            &bsk.polynomial_size(),
+            &other_bsk.polynomial_size(),
+            mismatches,
+        );
+        diff_field(
+            &bsk_path,
+            "base_log",
+            &bsk.decomposition_base_log(),
+            &other_bsk.decomposition_base_log(),
+            mismatches,
+        );
+        diff_field(
+            &bsk_path,
+            "level_count",
+            &bsk.decomposition_level_count(),
+            &other_bsk.decomposition_level_count(),
+            mismatches,
+        );
+        // The Fourier data is made of floats, the keys are only compared for equality
+        if bsk != other_bsk {
+            mismatches.push(Mismatch {
+                path: field_path(&bsk_path, "data"),
+                left: "..".to_owned(),
+                right: "..".to_owned(),
+            });
+        }
+    }
 }
 
 /// Returns whether it is possible to pack lhs and rhs into a unique
@@ -84,7 +427,7 @@ fn ciphertexts_can_be_packed_without_exceeding_space<OpOrder: PBSOrderMarker>(
     final_degree < lhs.carry_modulus.0 * lhs.message_modulus.0
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub struct LookupTable<C: Container<Element = u64>> {
     pub acc: GlweCiphertext<C>,
@@ -95,6 +438,7 @@ pub type LookupTableOwned = LookupTable<Vec<u64>>;
 pub type LookupTableMutView<'a> = LookupTable<&'a mut [u64]>;
 pub type LookupTableView<'a> = LookupTable<&'a [u64]>;
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub struct BivariateLookupTable<C: Container<Element = u64>> {
     // A bivariate accumulator is an univariate accumulator
@@ -117,118 +461,894 @@ impl<C: Container<Element = u64>> BivariateLookupTable<C> {
     ) -> bool {
         ciphertexts_can_be_packed_without_exceeding_space(lhs, rhs, self.ct_right_modulus.0)
     }
-}
+}
+
+impl<C: Container<Element = u64>> LookupTable<C> {
+    /// Override the degree given to the ciphertexts the lookup table is applied to.
+    ///
+    /// The degree of a generated lookup table is the maximum of the function over all the values
+    /// a ciphertext can hold. When the inputs are known to be smaller, a tighter degree leaves
+    /// more room for the leveled operations done on the output. The degree must still be an upper
+    /// bound of the outputs for the inputs the table is applied to, otherwise the operations
+    /// relying on it may overflow the carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // x -> 2x, only applied to clean ciphertexts, so the output is at most 6
+    /// let acc = sks.generate_accumulator(|x| (2 * x) % 16).with_degree(Degree(6));
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(ct_res.degree, Degree(6));
+    /// assert_eq!(cks.decrypt_message_and_carry(&ct_res), 6);
+    /// ```
+    pub fn with_degree(mut self, degree: Degree) -> Self {
+        self.degree = degree;
+        self
+    }
+}
+
+impl ServerKey {
+    /// Generate a server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::{gen_keys, ServerKey};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Generate the server key:
+    /// let sks = ServerKey::new(&cks);
+    /// ```
+    pub fn new(cks: &ClientKey) -> ServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| engine.new_server_key(cks).unwrap())
+    }
+
+    /// Generate a server key with a chosen maximum degree
+    pub fn new_with_max_degree(cks: &ClientKey, max_degree: MaxDegree) -> ServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .new_server_key_with_max_degree(cks, max_degree)
+                .unwrap()
+        })
+    }
+
+    /// Build a server key from its parts.
+    ///
+    /// The Fourier conversion of the bootstrapping key is the most expensive step of the
+    /// construction of a server key from a [`CompressedServerKey`]. A bootstrapping key already
+    /// in the Fourier domain can be serialized once (see [`ServerKey::into_raw_parts`]) and the
+    /// server key rebuilt from it with this function, skipping the conversion at process start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions of the keys are not compatible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::FourierLweBootstrapKeyOwned;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{gen_keys, ServerKey};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let (ksk, bsk, message_modulus, carry_modulus, max_degree, ciphertext_modulus) =
+    ///     sks.into_raw_parts();
+    ///
+    /// // The Fourier bootstrapping key can be stored as is
+    /// let serialized_bsk = bincode::serialize(&bsk.into_fourier()).unwrap();
+    ///
+    /// let fourier_bsk: FourierLweBootstrapKeyOwned = bincode::deserialize(&serialized_bsk).unwrap();
+    /// let sks = ServerKey::from_raw_parts(
+    ///     ksk,
+    ///     fourier_bsk.into(),
+    ///     message_modulus,
+    ///     carry_modulus,
+    ///     max_degree,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.scalar_mul(&ct, 2);
+    /// assert_eq!(cks.decrypt(&ct_res), 2);
+    /// ```
+    pub fn from_raw_parts(
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        bootstrapping_key: ShortintBootstrappingKey,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        max_degree: MaxDegree,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        assert_eq!(
+            key_switching_key.input_key_lwe_dimension(),
+            bootstrapping_key.output_lwe_dimension(),
+            "Mismatch between the input LweKeyswitchKey LweDimension ({:?}) \
+            and the bootstrapping key output LweDimension ({:?})",
+            key_switching_key.input_key_lwe_dimension(),
+            bootstrapping_key.output_lwe_dimension()
+        );
+        assert_eq!(
+            key_switching_key.output_key_lwe_dimension(),
+            bootstrapping_key.input_lwe_dimension(),
+            "Mismatch between the output LweKeyswitchKey LweDimension ({:?}) \
+            and the bootstrapping key input LweDimension ({:?})",
+            key_switching_key.output_key_lwe_dimension(),
+            bootstrapping_key.input_lwe_dimension()
+        );
+        assert_eq!(
+            key_switching_key.ciphertext_modulus(),
+            ciphertext_modulus,
+            "Mismatch between the LweKeyswitchKey CiphertextModulus ({:?}) \
+            and the provided CiphertextModulus ({:?})",
+            key_switching_key.ciphertext_modulus(),
+            ciphertext_modulus
+        );
+
+        Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+            backend: ServerKeyBackend::default(),
+        }
+    }
+
+    /// Deconstruct a [`ServerKey`] into its constituents, see [`ServerKey::from_raw_parts`].
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        LweKeyswitchKeyOwned<u64>,
+        ShortintBootstrappingKey,
+        MessageModulus,
+        CarryModulus,
+        MaxDegree,
+        CiphertextModulus,
+    ) {
+        let Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+            backend: _,
+        } = self;
+
+        (
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+        )
+    }
+
+    /// Build a server key from a keyswitching key and a standard domain bootstrapping key
+    /// generated outside of this library, e.g. by another TFHE implementation sharing the same
+    /// key formats.
+    ///
+    /// Contrary to [`ServerKey::from_raw_parts`], the dimensions, decomposition parameters and
+    /// ciphertext moduli of the keys are checked against `parameters` and an error is returned if
+    /// they do not match. The bootstrapping key is converted to the Fourier domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, ServerKey};
+    ///
+    /// let parameters = PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Keys generated with the core_crypto primitives, as another toolchain would
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    /// let mut secret_generator =
+    ///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+    ///
+    /// let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+    ///     parameters.lwe_dimension,
+    ///     &mut secret_generator,
+    /// );
+    /// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+    ///     parameters.glwe_dimension,
+    ///     parameters.polynomial_size,
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+    ///     &small_lwe_secret_key,
+    ///     &glwe_secret_key,
+    ///     parameters.pbs_base_log,
+    ///     parameters.pbs_level,
+    ///     parameters.glwe_modular_std_dev,
+    ///     parameters.ciphertext_modulus,
+    ///     &mut encryption_generator,
+    /// );
+    /// let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+    ///     &glwe_secret_key.clone().into_lwe_secret_key(),
+    ///     &small_lwe_secret_key,
+    ///     parameters.ks_base_log,
+    ///     parameters.ks_level,
+    ///     parameters.lwe_modular_std_dev,
+    ///     parameters.ciphertext_modulus,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let cks = ClientKey::try_from_raw_parts(glwe_secret_key, small_lwe_secret_key, parameters)
+    ///     .unwrap();
+    /// let sks = ServerKey::try_from_raw_keys(ksk, bsk, parameters).unwrap();
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.scalar_mul(&ct, 2);
+    /// assert_eq!(cks.decrypt(&ct_res), 2);
+    /// ```
+    pub fn try_from_raw_keys(
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        bootstrap_key: LweBootstrapKeyOwned<u64>,
+        parameters: Parameters,
+    ) -> Result<Self, ParametersError> {
+        let large_lwe_dimension =
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0);
+
+        ParametersError::check_key(
+            "LweKeyswitchKey input LweDimension",
+            large_lwe_dimension,
+            key_switching_key.input_key_lwe_dimension(),
+        )?;
+        ParametersError::check_key(
+            "LweKeyswitchKey output LweDimension",
+            parameters.lwe_dimension,
+            key_switching_key.output_key_lwe_dimension(),
+        )?;
+        ParametersError::check_key(
+            "LweKeyswitchKey DecompositionBaseLog",
+            parameters.ks_base_log,
+            key_switching_key.decomposition_base_log(),
+        )?;
+        ParametersError::check_key(
+            "LweKeyswitchKey DecompositionLevelCount",
+            parameters.ks_level,
+            key_switching_key.decomposition_level_count(),
+        )?;
+        ParametersError::check_key(
+            "LweKeyswitchKey CiphertextModulus",
+            parameters.ciphertext_modulus,
+            key_switching_key.ciphertext_modulus(),
+        )?;
+
+        ParametersError::check_key(
+            "LweBootstrapKey input LweDimension",
+            parameters.lwe_dimension,
+            bootstrap_key.input_lwe_dimension(),
+        )?;
+        ParametersError::check_key(
+            "LweBootstrapKey GlweSize",
+            parameters.glwe_dimension.to_glwe_size(),
+            bootstrap_key.glwe_size(),
+        )?;
+        ParametersError::check_key(
+            "LweBootstrapKey PolynomialSize",
+            parameters.polynomial_size,
+            bootstrap_key.polynomial_size(),
+        )?;
+        ParametersError::check_key(
+            "LweBootstrapKey DecompositionBaseLog",
+            parameters.pbs_base_log,
+            bootstrap_key.decomposition_base_log(),
+        )?;
+        ParametersError::check_key(
+            "LweBootstrapKey DecompositionLevelCount",
+            parameters.pbs_level,
+            bootstrap_key.decomposition_level_count(),
+        )?;
+        ParametersError::check_key(
+            "LweBootstrapKey CiphertextModulus",
+            parameters.ciphertext_modulus,
+            bootstrap_key.ciphertext_modulus(),
+        )?;
+
+        let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+
+        let max_degree = MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1);
+
+        Ok(Self::from_raw_parts(
+            key_switching_key,
+            ShortintBootstrappingKey::Classic(fourier_bsk),
+            parameters.message_modulus,
+            parameters.carry_modulus,
+            max_degree,
+            parameters.ciphertext_modulus,
+        ))
+    }
+
+    /// Convert the bootstrapping key to the Fourier domain if it is a
+    /// [`ShortintBootstrappingKey::LazySeeded`] key, making the PBS faster at the cost of memory.
+    ///
+    /// Does nothing if the key is already in the Fourier domain.
+    pub fn precompute_fourier_bootstrapping_key(&mut self) {
+        if let ShortintBootstrappingKey::LazySeeded(seeded_bsk) = &self.bootstrapping_key {
+            self.bootstrapping_key = ShortintBootstrappingKey::Classic(
+                ShortintBootstrappingKey::LazySeeded(seeded_bsk.clone()).into_fourier(),
+            );
+        }
+    }
+
+    /// Build a server key from a [`CompressedServerKey`], keeping the bootstrapping key seeded.
+    ///
+    /// The bootstrapping key is stored as a [`ShortintBootstrappingKey::LazySeeded`] key: it is
+    /// never fully decompressed and each of its GGSW ciphertexts is expanded to the Fourier domain
+    /// during the PBS, trading computation time for memory. The key switching key is
+    /// decompressed as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{ClientKey, CompressedServerKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// let sks = ServerKey::from_compressed_lazy(compressed_sks);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.unchecked_scalar_mul(&ct, 2);
+    /// let ct_res = sks.message_extract(&ct_res);
+    /// assert_eq!(cks.decrypt(&ct_res), 2);
+    /// ```
+    pub fn from_compressed_lazy(compressed_server_key: CompressedServerKey) -> Self {
+        let CompressedServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+        } = compressed_server_key;
+
+        Self {
+            key_switching_key: key_switching_key.decompress_into_lwe_keyswitch_key(),
+            bootstrapping_key: ShortintBootstrappingKey::LazySeeded(bootstrapping_key),
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            ciphertext_modulus,
+            backend: ServerKeyBackend::default(),
+        }
+    }
+
+    /// Constructs the accumulator given a function as input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Generate the accumulator for the function f: x -> x^2 mod 2^2
+    /// let f = |x| x ^ 2 % 4;
+    ///
+    /// let acc = sks.generate_accumulator(f);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// // 3^2 mod 4 = 1
+    /// assert_eq!(dec, f(msg));
+    /// ```
+    pub fn generate_accumulator<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_accumulator(self, f).unwrap()
+        })
+    }
+
+    /// Check that a lookup table, e.g. loaded from a library of precomputed tables, can be
+    /// applied with this server key.
+    ///
+    /// The sizes of the table must match the ones of the bootstrapping key, and its content must
+    /// be the encoding of a function for the message and carry moduli of the key, i.e. the output
+    /// of [`ServerKey::generate_accumulator`]. The degree of the table is not checked as it may
+    /// have been tightened with [`LookupTable::with_degree`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::server_key::LookupTableOwned;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_accumulator(|x| (x * x) % 4);
+    /// let serialized = bincode::serialize(&acc).unwrap();
+    ///
+    /// // In another run
+    /// let acc: LookupTableOwned = bincode::deserialize(&serialized).unwrap();
+    /// sks.check_lookup_table(&acc).unwrap();
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    ///
+    /// // Tables generated for other parameters are rejected
+    /// let (_, other_sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    /// let other_acc = other_sks.generate_accumulator(|x| x % 2);
+    /// assert!(sks.check_lookup_table(&other_acc).is_err());
+    /// ```
+    pub fn check_lookup_table(&self, acc: &LookupTableOwned) -> Result<(), ParametersError> {
+        ParametersError::check_key(
+            "LookupTable GlweSize",
+            self.bootstrapping_key.glwe_size(),
+            acc.acc.glwe_size(),
+        )?;
+        ParametersError::check_key(
+            "LookupTable PolynomialSize",
+            self.bootstrapping_key.polynomial_size(),
+            acc.acc.polynomial_size(),
+        )?;
+        ParametersError::check_key(
+            "LookupTable CiphertextModulus",
+            self.ciphertext_modulus,
+            acc.acc.ciphertext_modulus(),
+        )?;
+
+        // The middle of the box of each input is at the start of the box once the table is
+        // rotated, and holds the encoding of the output
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let box_size = acc.acc.polynomial_size().0 / modulus_sup;
+        let delta = (1_u64 << 63) / modulus_sup as u64;
+
+        let body = acc.acc.get_body();
+        let body = body.as_ref();
+        if (0..modulus_sup).any(|i| body[i * box_size] % delta != 0) {
+            return Err(ParametersError::IncompatibleLookupTable);
+        }
+
+        let mut expected = GlweCiphertext::new(
+            0,
+            acc.acc.glwe_size(),
+            acc.acc.polynomial_size(),
+            acc.acc.ciphertext_modulus(),
+        );
+        fill_accumulator_with_moduli(
+            &mut expected,
+            self.message_modulus,
+            self.carry_modulus,
+            |x| body[x as usize * box_size] / delta,
+        );
+
+        if expected == acc.acc {
+            Ok(())
+        } else {
+            Err(ParametersError::IncompatibleLookupTable)
+        }
+    }
+
+    /// Check that a bivariate lookup table can be applied with this server key, see
+    /// [`ServerKey::check_lookup_table`].
+    pub fn check_bivariate_lookup_table(
+        &self,
+        acc: &BivariateLookupTableOwned,
+    ) -> Result<(), ParametersError> {
+        self.check_lookup_table(&acc.acc)?;
+
+        if acc.ct_right_modulus.0 == 0
+            || acc.ct_right_modulus.0 > self.message_modulus.0 * self.carry_modulus.0
+        {
+            return Err(ParametersError::IncompatibleLookupTable);
+        }
+
+        Ok(())
+    }
+
+    pub fn generate_accumulator_bivariate_with_factor<F>(
+        &self,
+        f: F,
+        left_message_scaling: MessageModulus,
+    ) -> BivariateLookupTableOwned
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .generate_accumulator_bivariate_with_factor(self, f, left_message_scaling)
+                .unwrap()
+        })
+    }
+
+    /// Constructs the accumulator for a given bivariate function as input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 3;
+    /// let msg_2 = 2;
+    ///
+    /// let ct1 = cks.encrypt(msg_1);
+    /// let mut ct2 = cks.encrypt(msg_2);
+    ///
+    /// let f = |x, y| (x + y) % 4;
+    ///
+    /// let acc = sks.generate_accumulator_bivariate(f);
+    /// assert!(acc.is_bivariate_pbs_possible(&ct1, &ct2));
+    /// let ct_res = sks.smart_apply_lookup_table_bivariate(&ct1, &mut ct2, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, f(msg_1, msg_2));
+    /// ```
+    pub fn generate_accumulator_bivariate<F>(&self, f: F) -> BivariateLookupTableOwned
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_accumulator_bivariate(self, f).unwrap()
+        })
+    }
+
+    /// Constructs the accumulator from a table of values, `table[x]` being the output for the
+    /// input `x`.
+    ///
+    /// The table must have one entry per value the ciphertext can hold, i.e.
+    /// `message_modulus * carry_modulus` entries, and its entries must be smaller than this
+    /// modulus. The degree of the accumulator is the maximum of the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table does not have the expected length or if one of its entries is too big.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    ///
+    /// // x -> 3 - x
+    /// let table = [3, 2, 1, 0];
+    /// let acc = sks.generate_accumulator_from_slice(&table);
+    /// assert_eq!(acc.degree.0, 3);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn generate_accumulator_from_slice(&self, table: &[u64]) -> LookupTableOwned {
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        assert_eq!(
+            table.len(),
+            modulus_sup,
+            "The table must have message_modulus * carry_modulus entries"
+        );
+        assert!(
+            table.iter().all(|&value| value < modulus_sup as u64),
+            "The entries of the table must be smaller than message_modulus * carry_modulus"
+        );
+
+        self.generate_accumulator(|x| table[x as usize])
+    }
+
+    /// Constructs the accumulator of a function over signed values.
+    ///
+    /// The message and carry bits of the input are interpreted as a two's complement value,
+    /// i.e. inputs in `[0, message_modulus * carry_modulus / 2)` are non-negative and the
+    /// other ones are negative. The output of `f` is encoded the same way, modulo
+    /// `message_modulus * carry_modulus`, so that the padding bit of the result stays clean.
+    ///
+    /// The degree of the accumulator is the biggest encoded output, so functions with
+    /// negative outputs fill the carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let modulus_sup = 16;
+    ///
+    /// // x -> -x on 4 bits
+    /// let acc = sks.generate_accumulator_signed(|x| -x);
+    ///
+    /// // -3 in two's complement on 4 bits
+    /// let ct = cks.unchecked_encrypt(modulus_sup - 3);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(dec, 3);
+    ///
+    /// let ct = cks.encrypt(2);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(dec, modulus_sup - 2);
+    /// ```
+    pub fn generate_accumulator_signed<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(i64) -> i64,
+    {
+        let modulus_sup = (self.message_modulus.0 * self.carry_modulus.0) as i64;
+        let half_modulus_sup = modulus_sup / 2;
+
+        self.generate_accumulator(|x| {
+            let x = x as i64;
+            let signed_x = if x < half_modulus_sup {
+                x
+            } else {
+                x - modulus_sup
+            };
+            f(signed_x).rem_euclid(modulus_sup) as u64
+        })
+    }
+
+    /// Constructs the bivariate accumulator from a table of values, `table[lhs * message_modulus
+    /// + rhs]` being the output for the inputs `lhs` and `rhs`.
+    ///
+    /// The table must have `message_modulus * message_modulus` entries, and its entries must be
+    /// smaller than `message_modulus * carry_modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table does not have the expected length or if one of its entries is too big.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // (x, y) -> x * y, as a 4x4 table
+    /// let table = (0..4)
+    ///     .flat_map(|x| (0..4).map(move |y| x * y))
+    ///     .collect::<Vec<u64>>();
+    /// let acc = sks.generate_accumulator_bivariate_from_slice(&table);
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let mut ct2 = cks.encrypt(2);
+    /// let ct_res = sks.smart_apply_lookup_table_bivariate(&ct1, &mut ct2, &acc);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(dec, 6);
+    /// ```
+    pub fn generate_accumulator_bivariate_from_slice(
+        &self,
+        table: &[u64],
+    ) -> BivariateLookupTableOwned {
+        let message_modulus = self.message_modulus.0;
+        let modulus_sup = message_modulus * self.carry_modulus.0;
+        assert_eq!(
+            table.len(),
+            message_modulus * message_modulus,
+            "The table must have message_modulus * message_modulus entries"
+        );
+        assert!(
+            table.iter().all(|&value| value < modulus_sup as u64),
+            "The entries of the table must be smaller than message_modulus * carry_modulus"
+        );
+
+        self.generate_accumulator_bivariate(|lhs, rhs| {
+            table[lhs as usize * message_modulus + rhs as usize]
+        })
+    }
+
+    /// Constructs the accumulator for a function that is only applied to ciphertexts whose degree
+    /// is at most `input_degree`.
+    ///
+    /// The accumulator is the same as the one returned by
+    /// [`generate_accumulator`](Self::generate_accumulator), but its degree is the maximum of `f`
+    /// over `0..=input_degree` instead of over all the values a ciphertext can hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let f = |x| (x + 3) % 16;
+    /// let acc = sks.generate_accumulator(f);
+    /// assert_eq!(acc.degree, Degree(15));
+    ///
+    /// let ct = cks.encrypt(2);
+    /// let acc = sks.generate_accumulator_for_input_degree(f, ct.degree);
+    /// assert_eq!(acc.degree, Degree(6));
+    ///
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(ct_res.degree, Degree(6));
+    /// assert_eq!(cks.decrypt_message_and_carry(&ct_res), 5);
+    /// ```
+    pub fn generate_accumulator_for_input_degree<F>(
+        &self,
+        f: F,
+        input_degree: Degree,
+    ) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus_sup = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let max_input = (input_degree.0 as u64).min(modulus_sup - 1);
+        let max_value = (0..=max_input).map(&f).max().unwrap_or(0);
+
+        self.generate_accumulator(f)
+            .with_degree(Degree(max_value as usize))
+    }
 
-impl ServerKey {
-    /// Generate a server key.
+    /// Recover the table of values encoded in an accumulator, the entry `x` being the output of
+    /// the accumulator for the input `x`.
+    ///
+    /// The table has `message_modulus * carry_modulus` entries, and the outputs are recovered
+    /// modulo `2 * message_modulus * carry_modulus`, i.e. including the padding bit.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
-    /// use tfhe::shortint::{gen_keys, ServerKey};
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1;
     ///
-    /// // Generate the client key and the server key:
-    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
     ///
-    /// // Generate the server key:
-    /// let sks = ServerKey::new(&cks);
+    /// let acc = sks.generate_accumulator(|x| 3 - x);
+    /// assert_eq!(sks.lookup_table_values(&acc), vec![3, 2, 1, 0]);
     /// ```
-    pub fn new(cks: &ClientKey) -> ServerKey {
-        ShortintEngine::with_thread_local_mut(|engine| engine.new_server_key(cks).unwrap())
-    }
+    pub fn lookup_table_values(&self, acc: &LookupTableOwned) -> Vec<u64> {
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let box_size = acc.acc.polynomial_size().0 / modulus_sup;
+        let delta = (1_u64 << 63) / modulus_sup as u64;
 
-    /// Generate a server key with a chosen maximum degree
-    pub fn new_with_max_degree(cks: &ClientKey, max_degree: MaxDegree) -> ServerKey {
-        ShortintEngine::with_thread_local_mut(|engine| {
-            engine
-                .new_server_key_with_max_degree(cks, max_degree)
-                .unwrap()
-        })
+        // The accumulator is rotated by half a box, so the first coefficient of each box is in
+        // the middle of the box of the input
+        let body = acc.acc.get_body();
+        let body = body.as_ref();
+        (0..modulus_sup)
+            .map(|x| body[x * box_size].wrapping_add(delta / 2) / delta)
+            .collect()
     }
 
-    /// Constructs the accumulator given a function as input.
+    /// Constructs the accumulator of `g ∘ f` from the accumulator of `f`.
+    ///
+    /// The composition is computed on the tables, so applying the result costs a single
+    /// programmable bootstrapping instead of two.
+    ///
+    /// The degree follows the one of `acc`: only the outputs of `f` that are at most `acc.degree`
+    /// are considered as inputs of `g`, so a degree tightened with
+    /// [`LookupTable::with_degree`] or
+    /// [`generate_accumulator_for_input_degree`](Self::generate_accumulator_for_input_degree) is
+    /// propagated to the composition.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tfhe::shortint::gen_keys;
-    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
     ///
-    /// // Generate the client key and the server key:
     /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
     ///
-    /// let msg = 3;
-    ///
-    /// let ct = cks.encrypt(msg);
+    /// let f = |x| (x + 1) % 4;
+    /// let g = |x| (2 * x) % 4;
     ///
-    /// // Generate the accumulator for the function f: x -> x^2 mod 2^2
-    /// let f = |x| x ^ 2 % 4;
+    /// let acc_f = sks.generate_accumulator(f);
+    /// let acc = sks.compose_lookup_table(&acc_f, g);
     ///
-    /// let acc = sks.generate_accumulator(f);
+    /// let ct = cks.encrypt(2);
     /// let ct_res = sks.apply_lookup_table(&ct, &acc);
-    ///
-    /// let dec = cks.decrypt(&ct_res);
-    /// // 3^2 mod 4 = 1
-    /// assert_eq!(dec, f(msg));
+    /// assert_eq!(cks.decrypt(&ct_res), g(f(2)));
     /// ```
-    pub fn generate_accumulator<F>(&self, f: F) -> LookupTableOwned
+    pub fn compose_lookup_table<G>(&self, acc: &LookupTableOwned, g: G) -> LookupTableOwned
     where
-        F: Fn(u64) -> u64,
+        G: Fn(u64) -> u64,
     {
-        ShortintEngine::with_thread_local_mut(|engine| {
-            engine.generate_accumulator(self, f).unwrap()
-        })
-    }
+        let values = self.lookup_table_values(acc);
+        let max_value = values
+            .iter()
+            .filter(|&&value| value <= acc.degree.0 as u64)
+            .map(|&value| g(value))
+            .max()
+            .unwrap_or(0);
 
-    pub fn generate_accumulator_bivariate_with_factor<F>(
-        &self,
-        f: F,
-        left_message_scaling: MessageModulus,
-    ) -> BivariateLookupTableOwned
-    where
-        F: Fn(u64, u64) -> u64,
-    {
-        ShortintEngine::with_thread_local_mut(|engine| {
-            engine
-                .generate_accumulator_bivariate_with_factor(self, f, left_message_scaling)
-                .unwrap()
-        })
+        self.generate_accumulator(|x| g(values[x as usize]))
+            .with_degree(Degree(max_value as usize))
     }
 
-    /// Constructs the accumulator for a given bivariate function as input.
+    /// Constructs the accumulator of `x -> -f(x) mod message_modulus` from the accumulator of `f`.
+    ///
+    /// The result has clean carries, its degree is the maximum of the negated outputs, following
+    /// the degree propagation of [`compose_lookup_table`](Self::compose_lookup_table).
     ///
     /// # Example
     ///
     /// ```rust
     /// use tfhe::shortint::gen_keys;
-    /// use tfhe::shortint::parameters::{PARAM_MESSAGE_2_CARRY_2, PARAM_SMALL_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
     ///
-    /// // Generate the client key and the server key:
     /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
     ///
-    /// let msg_1 = 3;
-    /// let msg_2 = 2;
+    /// let acc = sks.generate_accumulator(|x| x % 4);
+    /// let acc = sks.negate_lookup_table(&acc);
     ///
-    /// let ct1 = cks.encrypt(msg_1);
-    /// let mut ct2 = cks.encrypt(msg_2);
+    /// let ct = cks.encrypt(1);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn negate_lookup_table(&self, acc: &LookupTableOwned) -> LookupTableOwned {
+        let message_modulus = self.message_modulus.0 as u64;
+        self.compose_lookup_table(acc, |value| {
+            (message_modulus - value % message_modulus) % message_modulus
+        })
+    }
+
+    /// Constructs the accumulator of `x -> message_modulus - 1 - (f(x) mod message_modulus)`, i.e.
+    /// the bitwise complement of the message of `f(x)`, from the accumulator of `f`.
     ///
-    /// let f = |x, y| (x + y) % 4;
+    /// The result has clean carries, its degree is the maximum of the complemented outputs,
+    /// following the degree propagation of [`compose_lookup_table`](Self::compose_lookup_table).
     ///
-    /// let acc = sks.generate_accumulator_bivariate(f);
-    /// assert!(acc.is_bivariate_pbs_possible(&ct1, &ct2));
-    /// let ct_res = sks.smart_apply_lookup_table_bivariate(&ct1, &mut ct2, &acc);
+    /// # Example
     ///
-    /// let dec = cks.decrypt(&ct_res);
-    /// assert_eq!(dec, f(msg_1, msg_2));
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let acc = sks.generate_accumulator(|x| x % 4);
+    /// let acc = sks.complement_lookup_table(&acc);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res), 2);
     /// ```
-    pub fn generate_accumulator_bivariate<F>(&self, f: F) -> BivariateLookupTableOwned
-    where
-        F: Fn(u64, u64) -> u64,
-    {
-        ShortintEngine::with_thread_local_mut(|engine| {
-            engine.generate_accumulator_bivariate(self, f).unwrap()
-        })
+    pub fn complement_lookup_table(&self, acc: &LookupTableOwned) -> LookupTableOwned {
+        let message_modulus = self.message_modulus.0 as u64;
+        self.compose_lookup_table(acc, |value| message_modulus - 1 - value % message_modulus)
     }
 
     /// Compute a keyswitch and a bootstrap, returning a new ciphertext with empty
@@ -422,6 +1542,102 @@ impl ServerKey {
         })
     }
 
+    /// Compute a keyswitch and programmable bootstrap evaluating a bivariate lookup table.
+    ///
+    /// The inputs are not modified: if they cannot be packed in the same ciphertext, their carries
+    /// are cleared on copies first. The degree of the result is the degree of the lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg: u64 = 3;
+    /// let ct1 = cks.encrypt(msg);
+    /// let ct2 = cks.encrypt(msg);
+    /// // ct2 now has carries
+    /// let ct2 = sks.unchecked_add(&ct2, &ct1);
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    ///
+    /// let acc = sks.generate_accumulator_bivariate(|x, y| x * y % modulus);
+    /// let ct_res = sks.apply_lookup_table_bivariate(&ct1, &ct2, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg * ((msg + msg) % modulus)) % modulus);
+    /// ```
+    pub fn apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        acc: &BivariateLookupTableOwned,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct_left.clone();
+        self.apply_lookup_table_bivariate_assign(&mut ct_res, ct_right, acc);
+        ct_res
+    }
+
+    pub fn apply_lookup_table_bivariate_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &mut CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        acc: &BivariateLookupTableOwned,
+    ) {
+        if acc.is_bivariate_pbs_possible(ct_left, ct_right) {
+            self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
+            return;
+        }
+
+        self.message_extract_assign(ct_left);
+        if acc.is_bivariate_pbs_possible(ct_left, ct_right) {
+            self.unchecked_apply_lookup_table_bivariate_assign(ct_left, ct_right, acc);
+        } else {
+            let ct_right = self.message_extract(ct_right);
+            self.unchecked_apply_lookup_table_bivariate_assign(ct_left, &ct_right, acc);
+        }
+    }
+
+    /// Compute a keyswitch and programmable bootstrap evaluating a bivariate lookup table, if the
+    /// inputs can be packed in the same ciphertext.
+    ///
+    /// Otherwise [CheckError::CarryFull] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let ct2 = cks.encrypt(2);
+    ///
+    /// let acc = sks.generate_accumulator_bivariate(|x, y| x.max(y));
+    /// let ct_res = sks.checked_apply_lookup_table_bivariate(&ct1, &ct2, &acc);
+    /// assert_eq!(cks.decrypt(&ct_res.unwrap()), 3);
+    ///
+    /// let ct3 = sks.unchecked_add(&ct1, &ct2);
+    /// let ct_res = sks.checked_apply_lookup_table_bivariate(&ct3, &ct2, &acc);
+    /// assert!(ct_res.is_err());
+    /// ```
+    pub fn checked_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        acc: &BivariateLookupTableOwned,
+    ) -> Result<CiphertextBase<OpOrder>, CheckError> {
+        if acc.is_bivariate_pbs_possible(ct_left, ct_right) {
+            Ok(self.unchecked_apply_lookup_table_bivariate(ct_left, ct_right, acc))
+        } else {
+            Err(CheckError::CarryFull)
+        }
+    }
+
     /// Compute a keyswitch and programmable bootstrap.
     ///
     /// # Example
@@ -465,6 +1681,95 @@ impl ServerKey {
         })
     }
 
+    /// Compute a keyswitch and programmable bootstrap, writing the result in `ct_out`.
+    ///
+    /// The allocation of `ct_out` is reused, which avoids allocating a new ciphertext for each
+    /// lookup table evaluated in loops.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// let acc = sks.generate_accumulator(|x| x * x * x % modulus);
+    ///
+    /// let mut ct_res = sks.create_trivial(0);
+    /// for msg in 0..modulus {
+    ///     let ct = cks.encrypt(msg);
+    ///     sks.apply_lookup_table_into(&ct, &acc, &mut ct_res);
+    ///     assert_eq!(cks.decrypt(&ct_res), (msg * msg * msg) % modulus);
+    /// }
+    /// ```
+    pub fn apply_lookup_table_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .apply_lookup_table_into(self, ct_in, acc, ct_out)
+                .unwrap()
+        })
+    }
+
+    /// Compute a keyswitch and programmable bootstrap with the given [`PbsMode`].
+    ///
+    /// With [`PbsMode::Exact`] this is the same as [`ServerKey::apply_lookup_table`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_APPROX_PBS;
+    /// use tfhe::shortint::server_key::PbsMode;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_APPROX_PBS);
+    ///
+    /// let msg: u64 = 3;
+    /// let ct = cks.encrypt(msg);
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    ///
+    /// // Generate the accumulator for the function f: x -> x^3 mod 2^2
+    /// let acc = sks.generate_accumulator(|x| x * x * x % modulus);
+    /// let ct_res = sks.apply_lookup_table_with_mode(&ct, &acc, PbsMode::Approximate);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// // 3^3 mod 4 = 3
+    /// assert_eq!(dec, (msg * msg * msg) % modulus);
+    /// ```
+    pub fn apply_lookup_table_with_mode<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+        mode: PbsMode,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct_in.clone();
+        self.apply_lookup_table_with_mode_assign(&mut ct_res, acc, mode);
+        ct_res
+    }
+
+    /// Compute a keyswitch and programmable bootstrap with the given [`PbsMode`], the result is
+    /// written back in `ct_in`.
+    pub fn apply_lookup_table_with_mode_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_in: &mut CiphertextBase<OpOrder>,
+        acc: &LookupTableOwned,
+        mode: PbsMode,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .apply_lookup_table_with_mode_assign(self, ct_in, acc, mode)
+                .unwrap()
+        })
+    }
+
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
     /// multiplications, division).
@@ -617,6 +1922,38 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.carry_extract(self, ct).unwrap())
     }
 
+    /// Extract the carry buffer of `ct` in `ct_out`, reusing the allocation of `ct_out`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 9 = 0b10_01, the carry is 2
+    /// let ct = cks.unchecked_encrypt(9);
+    /// let mut ct_res = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically carry extraction
+    /// sks.carry_extract_into(&ct, &mut ct_res);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(2, res);
+    /// ```
+    pub fn carry_extract_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.carry_extract_into(self, ct, ct_out).unwrap()
+        })
+    }
+
     /// Clears the carry buffer of the input ciphertext.
     ///
     /// # Example
@@ -741,6 +2078,38 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct).unwrap())
     }
 
+    /// Extract the message buffer of `ct` in `ct_out`, reusing the allocation of `ct_out`.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // 9 = 0b10_01, the message is 1
+    /// let ct = cks.unchecked_encrypt(9);
+    /// let mut ct_res = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically the message extraction
+    /// sks.message_extract_into(&ct, &mut ct_res);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(1, res);
+    /// ```
+    pub fn message_extract_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.message_extract_into(self, ct, ct_out).unwrap()
+        })
+    }
+
     /// Compute a trivial shortint ciphertext with the dimension of the big LWE secret key from a
     /// given value.
     ///
@@ -780,11 +2149,18 @@ impl ServerKey {
     }
 
     pub fn bootstrapping_key_size_elements(&self) -> usize {
-        self.bootstrapping_key.as_view().data().as_ref().len()
+        match &self.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(bsk) => bsk.as_view().data().as_ref().len(),
+            ShortintBootstrappingKey::LazySeeded(bsk) => bsk.as_ref().len(),
+        }
     }
 
     pub fn bootstrapping_key_size_bytes(&self) -> usize {
-        self.bootstrapping_key_size_elements() * std::mem::size_of::<concrete_fft::c64>()
+        let element_size = match &self.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(_) => std::mem::size_of::<concrete_fft::c64>(),
+            ShortintBootstrappingKey::LazySeeded(_) => std::mem::size_of::<u64>(),
+        };
+        self.bootstrapping_key_size_elements() * element_size
     }
 
     pub fn key_switching_key_size_elements(&self) -> usize {
@@ -808,28 +2184,17 @@ impl From<CompressedServerKey> for ServerKey {
         } = compressed_server_key;
 
         let key_switching_key = key_switching_key.decompress_into_lwe_keyswitch_key();
-        let standard_bootstrapping_key = bootstrapping_key.decompress_into_lwe_bootstrap_key();
-
-        let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
-            standard_bootstrapping_key.input_lwe_dimension(),
-            standard_bootstrapping_key.glwe_size(),
-            standard_bootstrapping_key.polynomial_size(),
-            standard_bootstrapping_key.decomposition_base_log(),
-            standard_bootstrapping_key.decomposition_level_count(),
-        );
-
-        convert_standard_lwe_bootstrap_key_to_fourier(
-            &standard_bootstrapping_key,
-            &mut bootstrapping_key,
-        );
+        let bootstrapping_key =
+            ShortintBootstrappingKey::LazySeeded(bootstrapping_key).into_fourier();
 
         Self {
             key_switching_key,
-            bootstrapping_key,
+            bootstrapping_key: ShortintBootstrappingKey::Classic(bootstrapping_key),
             message_modulus,
             carry_modulus,
             max_degree,
             ciphertext_modulus,
+            backend: ServerKeyBackend::default(),
         }
     }
 }
@@ -130,6 +130,45 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// keeping the LSB of the result.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_mul_lsb`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(2);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a multiplication:
+    /// sks.unchecked_mul_lsb_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!((3 * 2) % 4, res);
+    /// ```
+    pub fn unchecked_mul_lsb_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_mul_lsb_into(self, ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Multiply two ciphertexts together without checks.
     ///
     /// Return the "most significant bits" of the multiplication, i.e., the part in the carry
@@ -890,6 +929,43 @@ impl ServerKey {
         }
     }
 
+    /// Compute homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// keeping the LSB of the result.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::mul_lsb`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(2);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a multiplication:
+    /// sks.mul_lsb_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!((3 * 2) % 4, res);
+    /// ```
+    pub fn mul_lsb_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.mul_lsb_assign(ct_out, ct_right);
+    }
+
     /// Multiply two ciphertexts.
     ///
     /// Return the "least significant bits" of the multiplication, i.e., the result modulus the
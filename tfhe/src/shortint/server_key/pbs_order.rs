@@ -0,0 +1,90 @@
+use super::ServerKey;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{CiphertextBase, PBSOrder, PBSOrderMarker};
+
+impl ServerKey {
+    /// Convert a ciphertext to another [`PBSOrder`].
+    ///
+    /// A [`CiphertextBig`](crate::shortint::CiphertextBig) is keyswitched to the small LWE key,
+    /// and a [`CiphertextSmall`](crate::shortint::CiphertextSmall) is bootstrapped with the
+    /// identity function to the large LWE key. The degree of the ciphertext is kept, the
+    /// conversion to the same order is a copy.
+    ///
+    /// This allows, for instance, to receive ciphertexts encrypted under the small LWE key, which
+    /// are smaller to upload, and to compute on them with the
+    /// [`KeyswitchBootstrap`](crate::shortint::ciphertext::KeyswitchBootstrap) order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::{CiphertextBig, CiphertextSmall};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let ct_small = cks.encrypt_small(msg);
+    ///
+    /// let ct_big: CiphertextBig = sks.switch_pbs_order(&ct_small);
+    /// let ct_res = sks.unchecked_add(&ct_big, &cks.encrypt(1));
+    /// assert_eq!(cks.decrypt(&ct_res), msg + 1);
+    ///
+    /// let ct_small: CiphertextSmall = sks.switch_pbs_order(&ct_res);
+    /// assert_eq!(cks.decrypt(&ct_small), msg + 1);
+    /// ```
+    pub fn switch_pbs_order<InputOrder, OutputOrder>(
+        &self,
+        ct: &CiphertextBase<InputOrder>,
+    ) -> CiphertextBase<OutputOrder>
+    where
+        InputOrder: PBSOrderMarker,
+        OutputOrder: PBSOrderMarker,
+    {
+        let ct_out = if InputOrder::pbs_order() == OutputOrder::pbs_order() {
+            ct.ct.clone()
+        } else {
+            match InputOrder::pbs_order() {
+                PBSOrder::KeyswitchBootstrap => {
+                    let mut ct_out = LweCiphertext::new(
+                        0u64,
+                        self.key_switching_key.output_lwe_size(),
+                        self.ciphertext_modulus,
+                    );
+
+                    crate::metrics::record_keyswitch(1);
+                    keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_out);
+                    ct_out
+                }
+                PBSOrder::BootstrapKeyswitch => {
+                    let mut ct_out = LweCiphertext::new(
+                        0u64,
+                        self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+                        self.ciphertext_modulus,
+                    );
+
+                    let acc = self.generate_accumulator(|x| x);
+                    ShortintEngine::with_thread_local_mut(|engine| {
+                        self.programmable_bootstrap(
+                            &ct.ct,
+                            &mut ct_out,
+                            &acc.acc,
+                            engine.computation_buffers(),
+                        );
+                    });
+                    ct_out
+                }
+            }
+        };
+
+        CiphertextBase {
+            ct: ct_out,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            _order_marker: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::{LookupTableOwned, ServerKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::pipeline::{run_pipeline, PipelineWorkers};
+use crate::shortint::{CiphertextBase, PBSOrder, PBSOrderMarker};
+
+/// Number of keyswitched ciphertexts which may wait for a thread to bootstrap them, per thread.
+const PIPELINE_DEPTH_PER_WORKER: usize = 2;
+
+impl ServerKey {
+    /// Apply a lookup table to each ciphertext, the keyswitches and the bootstraps of the batch
+    /// being pipelined.
+    ///
+    /// Rather than computing each operation on its own thread, one thread keyswitches the
+    /// ciphertexts while the other threads of the rayon pool blind rotate the ones already
+    /// keyswitched. The keyswitch of an operation then overlaps the blind rotation of the previous
+    /// ones, which improves the throughput of large batches of independent operations on
+    /// multicore machines.
+    ///
+    /// The results are the same as the ones of [`ServerKey::apply_lookup_table`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let double = sks.generate_accumulator(|x| (2 * x) % 4);
+    /// let negate = sks.generate_accumulator(|x| (4 - x) % 4);
+    ///
+    /// let msg = [0, 1, 2, 3];
+    /// let cts = msg.map(|m| cks.encrypt(m));
+    /// let ops = [
+    ///     (&cts[0], &double),
+    ///     (&cts[1], &negate),
+    ///     (&cts[2], &double),
+    ///     (&cts[3], &negate),
+    /// ];
+    ///
+    /// let res = sks.apply_lookup_tables_pipelined(&ops);
+    ///
+    /// assert_eq!(cks.decrypt(&res[0]), 0);
+    /// assert_eq!(cks.decrypt(&res[1]), 3);
+    /// assert_eq!(cks.decrypt(&res[2]), 0);
+    /// assert_eq!(cks.decrypt(&res[3]), 1);
+    /// ```
+    pub fn apply_lookup_tables_pipelined<OpOrder: PBSOrderMarker>(
+        &self,
+        ops: &[(&CiphertextBase<OpOrder>, &LookupTableOwned)],
+    ) -> Vec<CiphertextBase<OpOrder>> {
+        let threads = rayon::current_num_threads();
+        // The keyswitch is much cheaper than the blind rotation, a single thread keeps up with
+        // the others
+        let keyswitch_workers = 1;
+        let bootstrap_workers = threads.saturating_sub(keyswitch_workers).max(1);
+        let depth = bootstrap_workers * PIPELINE_DEPTH_PER_WORKER;
+
+        let small_lwe_size = self.bootstrapping_key.input_lwe_dimension().to_lwe_size();
+        let big_lwe_size = self.bootstrapping_key.output_lwe_dimension().to_lwe_size();
+
+        crate::metrics::record_keyswitch(ops.len());
+        crate::metrics::record_pbs(ops.len());
+
+        let keyswitch = |ct: &LweCiphertextOwned<u64>| {
+            let mut ct_out = LweCiphertext::new(0u64, small_lwe_size, self.ciphertext_modulus);
+            keyswitch_lwe_ciphertext(&self.key_switching_key, ct, &mut ct_out);
+            ct_out
+        };
+        let bootstrap = |buffers: &mut ComputationBuffers,
+                         ct: &LweCiphertextOwned<u64>,
+                         acc: &LookupTableOwned| {
+            let mut ct_out = LweCiphertext::new(0u64, big_lwe_size, self.ciphertext_modulus);
+            self.bootstrap_backend().programmable_bootstrap(
+                &self.bootstrapping_key,
+                ct.as_view(),
+                ct_out.as_mut_view(),
+                acc.acc.as_view(),
+                buffers,
+            );
+            ct_out
+        };
+
+        let cts = match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => run_pipeline(
+                ops,
+                PipelineWorkers {
+                    first_stage: keyswitch_workers,
+                    second_stage: bootstrap_workers,
+                },
+                depth,
+                || (),
+                |_, _, (ct, _)| keyswitch(&ct.ct),
+                ComputationBuffers::new,
+                |buffers, index, ct| bootstrap(buffers, &ct, ops[index].1),
+            ),
+            PBSOrder::BootstrapKeyswitch => run_pipeline(
+                ops,
+                PipelineWorkers {
+                    first_stage: bootstrap_workers,
+                    second_stage: keyswitch_workers,
+                },
+                depth,
+                ComputationBuffers::new,
+                |buffers, _, (ct, acc)| bootstrap(buffers, &ct.ct, acc),
+                || (),
+                |_, _, ct| keyswitch(&ct),
+            ),
+        };
+
+        ops.iter()
+            .zip(cts)
+            .map(|((ct, acc), ct_out)| CiphertextBase {
+                ct: ct_out,
+                degree: acc.degree,
+                message_modulus: ct.message_modulus,
+                carry_modulus: ct.carry_modulus,
+                _order_marker: Default::default(),
+            })
+            .collect()
+    }
+}
@@ -131,6 +131,51 @@ impl ServerKey {
         self.apply_lookup_table_assign(ct, &acc);
     }
 
+    /// Compute homomorphically an addition between a ciphertext and a signed scalar.
+    ///
+    /// The scalar is reduced modulo the message modulus, so adding a negative scalar subtracts
+    /// its magnitude. The result is returned in a _new_ ciphertext with an empty carry, like
+    /// [`ServerKey::scalar_add`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(1);
+    ///
+    /// let ct_res = sks.scalar_add_signed(&ct, -2);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn scalar_add_signed<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct.clone();
+        self.scalar_add_signed_assign(&mut ct_res, scalar);
+        ct_res
+    }
+
+    /// Compute homomorphically an addition of a ciphertext by a signed scalar.
+    ///
+    /// The result is _stored_ in the `ct` ciphertext.
+    pub fn scalar_add_signed_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) {
+        self.scalar_add_assign(ct, self.reduce_signed_scalar(scalar));
+    }
+
+    /// Return the representative of `scalar` modulo the message modulus.
+    pub(crate) fn reduce_signed_scalar(&self, scalar: i8) -> u8 {
+        (scalar as i64).rem_euclid(self.message_modulus.0 as i64) as u8
+    }
+
     /// Compute homomorphically an addition between a ciphertext and a scalar.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -121,6 +121,46 @@ impl ServerKey {
         self.apply_lookup_table_assign(ct, &acc);
     }
 
+    /// Compute homomorphically a multiplication of a ciphertext by a signed scalar.
+    ///
+    /// The scalar is reduced modulo the message modulus, so multiplying by a negative scalar
+    /// negates the product by its magnitude. The result is returned in a _new_ ciphertext with an
+    /// empty carry, like [`ServerKey::scalar_mul`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(1);
+    ///
+    /// let ct_res = sks.scalar_mul_signed(&ct, -1);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn scalar_mul_signed<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct.clone();
+        self.scalar_mul_signed_assign(&mut ct_res, scalar);
+        ct_res
+    }
+
+    /// Compute homomorphically a multiplication of a ciphertext by a signed scalar.
+    ///
+    /// The result is _stored_ in the `ct` ciphertext.
+    pub fn scalar_mul_signed_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) {
+        self.scalar_mul_assign(ct, self.reduce_signed_scalar(scalar));
+    }
+
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -255,6 +295,51 @@ impl ServerKey {
         final_degree <= self.max_degree.0
     }
 
+    /// Return the number of unchecked multiplications by `scalar` which can be chained on a
+    /// ciphertext with an empty carry before its carry has to be cleared.
+    ///
+    /// This is computed from the [`MaxDegree`](crate::shortint::server_key::MaxDegree) of the
+    /// server key and its message modulus. Multiplying by `0` or `1` does not increase the
+    /// degree, `usize::MAX` is returned for these scalars.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let scalar = 2;
+    /// let num_muls = sks.max_scalar_mul(scalar);
+    /// assert_eq!(num_muls, 2);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    /// for _ in 0..num_muls {
+    ///     sks.unchecked_scalar_mul_assign(&mut ct, scalar);
+    /// }
+    /// assert_eq!(cks.decrypt_message_and_carry(&ct), msg * 4);
+    ///
+    /// // One more multiplication would exceed the carry space
+    /// assert!(!sks.is_scalar_mul_possible(&ct, scalar));
+    /// ```
+    pub fn max_scalar_mul(&self, scalar: u8) -> usize {
+        let clean_degree = self.message_modulus.0 - 1;
+        if scalar <= 1 || clean_degree == 0 {
+            return usize::MAX;
+        }
+
+        let scalar = scalar as usize;
+        let mut degree = clean_degree * scalar;
+        let mut num_muls = 0;
+        while degree <= self.max_degree.0 {
+            num_muls += 1;
+            degree *= scalar;
+        }
+        num_muls
+    }
+
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
     ///
     /// If the operation is possible, the result is returned in a _new_ ciphertext.
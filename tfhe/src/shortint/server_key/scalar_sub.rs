@@ -125,6 +125,46 @@ impl ServerKey {
         self.apply_lookup_table_assign(ct, &acc);
     }
 
+    /// Compute homomorphically a subtraction of a signed scalar to a ciphertext.
+    ///
+    /// The scalar is reduced modulo the message modulus, so subtracting a negative scalar adds
+    /// its magnitude. The result is returned in a _new_ ciphertext with an empty carry, like
+    /// [`ServerKey::scalar_sub`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(1);
+    ///
+    /// let ct_res = sks.scalar_sub_signed(&ct, -2);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn scalar_sub_signed<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) -> CiphertextBase<OpOrder> {
+        let mut ct_res = ct.clone();
+        self.scalar_sub_signed_assign(&mut ct_res, scalar);
+        ct_res
+    }
+
+    /// Compute homomorphically a subtraction of a signed scalar to a ciphertext.
+    ///
+    /// The result is _stored_ in the `ct` ciphertext.
+    pub fn scalar_sub_signed_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &mut CiphertextBase<OpOrder>,
+        scalar: i8,
+    ) {
+        self.scalar_sub_assign(ct, self.reduce_signed_scalar(scalar));
+    }
+
     /// Compute homomorphically a subtraction of a ciphertext by a scalar.
     ///
     /// The result is returned in a _new_ ciphertext.
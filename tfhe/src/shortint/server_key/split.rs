@@ -0,0 +1,207 @@
+//! Module with the definition of the halves of a [`ServerKey`] used for distributed evaluation.
+//!
+//! Every programmable bootstrap of the library is the composition of two stages: a keyswitch,
+//! taking a [`CiphertextBig`] encrypted under the large LWE key to a [`CiphertextSmall`]
+//! encrypted under the small LWE key, and a bootstrap taking it back to a [`CiphertextBig`].
+//! [`ServerKey::split`] separates the key material of the two stages, so that each stage can be
+//! executed by a different process holding only the key it needs.
+//!
+//! Both ciphertext types are serializable and are the intermediate values handed over from one
+//! stage to the other.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::{CiphertextBig, CiphertextSmall};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let (ks_key, bs_key) = sks.split();
+//!
+//! let msg = 3;
+//! let ct = cks.encrypt(msg);
+//!
+//! // Keyswitching stage
+//! let ct_small = ks_key.keyswitch(&ct);
+//! let handoff = bincode::serialize(&ct_small).unwrap();
+//!
+//! // Bootstrap stage
+//! let ct_small: CiphertextSmall = bincode::deserialize(&handoff).unwrap();
+//! let acc = bs_key.generate_accumulator(|x| (x + 1) % 4);
+//! let ct_res: CiphertextBig = bs_key.bootstrap(&ct_small, &acc);
+//!
+//! assert_eq!(cks.decrypt(&ct_res), (msg + 1) % 4);
+//! ```
+
+use super::backend::{assert_backend_supports_key, BootstrapBackend, ServerKeyBackend};
+use super::{LookupTableOwned, MaxDegree, ServerKey, ShortintBootstrappingKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::{fill_accumulator_with_moduli, ShortintEngine};
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::{CiphertextBig, CiphertextSmall};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The keyswitching half of a [`ServerKey`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyswitchServerKey {
+    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for KeyswitchServerKey { key_switching_key });
+
+/// The bootstrapping half of a [`ServerKey`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BootstrapServerKey {
+    pub bootstrapping_key: ShortintBootstrappingKey,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub max_degree: MaxDegree,
+    pub ciphertext_modulus: CiphertextModulus,
+    // Implementation used to compute the PBS, not part of the key material
+    #[serde(skip)]
+    pub(crate) backend: ServerKeyBackend,
+}
+
+crate::size::impl_fhe_size_from_fields!(impl for BootstrapServerKey { bootstrapping_key });
+
+impl ServerKey {
+    /// Split the server key into its keyswitching and bootstrapping halves.
+    ///
+    /// The bootstrapping half keeps the backend of the server key.
+    pub fn split(self) -> (KeyswitchServerKey, BootstrapServerKey) {
+        let ks_key = KeyswitchServerKey {
+            key_switching_key: self.key_switching_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+        };
+        let bs_key = BootstrapServerKey {
+            bootstrapping_key: self.bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            ciphertext_modulus: self.ciphertext_modulus,
+            backend: self.backend,
+        };
+        (ks_key, bs_key)
+    }
+
+    /// Reassemble a server key from the halves returned by [`ServerKey::split`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two halves do not belong to the same parameters.
+    pub fn from_split(ks_key: KeyswitchServerKey, bs_key: BootstrapServerKey) -> Self {
+        assert_eq!(ks_key.message_modulus, bs_key.message_modulus);
+        assert_eq!(ks_key.carry_modulus, bs_key.carry_modulus);
+
+        let mut server_key = Self::from_raw_parts(
+            ks_key.key_switching_key,
+            bs_key.bootstrapping_key,
+            bs_key.message_modulus,
+            bs_key.carry_modulus,
+            bs_key.max_degree,
+            bs_key.ciphertext_modulus,
+        );
+        server_key.backend = bs_key.backend;
+        server_key
+    }
+}
+
+impl KeyswitchServerKey {
+    /// Keyswitch a ciphertext from the large LWE key to the small LWE key.
+    ///
+    /// The degree of the ciphertext is kept.
+    pub fn keyswitch(&self, ct: &CiphertextBig) -> CiphertextSmall {
+        let mut ct_out = LweCiphertext::new(
+            0u64,
+            self.key_switching_key.output_lwe_size(),
+            self.ciphertext_modulus,
+        );
+
+        crate::metrics::record_keyswitch(1);
+        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_out);
+
+        CiphertextSmall {
+            ct: ct_out,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            _order_marker: Default::default(),
+        }
+    }
+}
+
+impl BootstrapServerKey {
+    /// Return the backend used to compute the programmable bootstraps.
+    pub fn bootstrap_backend(&self) -> &dyn BootstrapBackend {
+        self.backend.0.as_ref()
+    }
+
+    /// Set the backend used to compute the programmable bootstraps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend does not support the bootstrapping key.
+    pub fn set_bootstrap_backend(&mut self, backend: Arc<dyn BootstrapBackend>) {
+        assert_backend_supports_key(backend.as_ref(), &self.bootstrapping_key);
+        self.backend = ServerKeyBackend(backend);
+    }
+
+    /// Constructs the accumulator given a function as input, the same way as
+    /// [`ServerKey::generate_accumulator`].
+    pub fn generate_accumulator<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        let mut acc = GlweCiphertext::new(
+            0,
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+        );
+        let max_value =
+            fill_accumulator_with_moduli(&mut acc, self.message_modulus, self.carry_modulus, f);
+
+        LookupTableOwned {
+            acc,
+            degree: Degree(max_value as usize),
+        }
+    }
+
+    /// Compute a programmable bootstrap of a keyswitched ciphertext, going back to the large LWE
+    /// key.
+    pub fn bootstrap(&self, ct: &CiphertextSmall, acc: &LookupTableOwned) -> CiphertextBig {
+        let mut ct_out = LweCiphertext::new(
+            0u64,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            self.ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            crate::metrics::record_pbs(1);
+            self.bootstrap_backend().programmable_bootstrap(
+                &self.bootstrapping_key,
+                ct.ct.as_view(),
+                ct_out.as_mut_view(),
+                acc.acc.as_view(),
+                engine.computation_buffers(),
+            );
+        });
+
+        CiphertextBig {
+            ct: ct_out,
+            degree: acc.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            _order_marker: Default::default(),
+        }
+    }
+}
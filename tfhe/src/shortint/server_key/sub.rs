@@ -99,6 +99,42 @@ impl ServerKey {
         self.clear_carry_assign(ct_left);
     }
 
+    /// Compute homomorphically a subtraction between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. Like [`Self::sub`], the carries
+    /// of the inputs are cleared if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a subtraction:
+    /// sks.sub_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 - 1, res);
+    /// ```
+    pub fn sub_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ct_out.clone_from(ct_left);
+        self.sub_assign(ct_out, ct_right);
+    }
+
     /// Homomorphically subtracts ct_right to ct_left.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -175,6 +211,44 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically a subtraction between two ciphertexts encrypting integer values.
+    ///
+    /// The result is written in `ct_out`, reusing its allocation: this avoids allocating a new
+    /// ciphertext for each operation in loops. See [`Self::unchecked_sub`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct_left = cks.encrypt(3);
+    /// let ct_right = cks.encrypt(1);
+    /// let mut ct_out = sks.create_trivial(0);
+    ///
+    /// // Compute homomorphically a subtraction:
+    /// sks.unchecked_sub_into(&ct_left, &ct_right, &mut ct_out);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_out);
+    /// assert_eq!(3 - 1, res);
+    /// ```
+    pub fn unchecked_sub_into<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &CiphertextBase<OpOrder>,
+        ct_right: &CiphertextBase<OpOrder>,
+        ct_out: &mut CiphertextBase<OpOrder>,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_sub_into(self, ct_left, ct_right, ct_out)
+                .unwrap()
+        })
+    }
+
     /// Verify if ct_right can be subtracted to ct_left.
     ///
     /// # Example
@@ -1,6 +1,13 @@
+use crate::shortint::ciphertext::vector::ShortintVector;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::client_key::DegreeAuditError;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
-use crate::shortint::CiphertextBig;
+use crate::shortint::server_key::custom_op::{
+    register_custom_op, unregister_custom_op, CustomOp, CustomOpContext, CustomOpError,
+};
+use crate::shortint::server_key::{ComparisonOp, PbsMode};
+use crate::shortint::{CiphertextBig, CiphertextSmall, ClientKey, PBSOrder, PBSOrderMarker};
 use paste::paste;
 use rand::Rng;
 
@@ -98,6 +105,7 @@ create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_accumulator);
+create_parametrized_test!(shortint_generate_accumulator_signed);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -115,6 +123,8 @@ create_parametrized_test!(shortint_default_scalar_sub);
 create_parametrized_test!(shortint_unchecked_scalar_mul);
 create_parametrized_test!(shortint_smart_scalar_mul);
 create_parametrized_test!(shortint_default_scalar_mul);
+create_parametrized_test!(shortint_default_signed_scalar_ops);
+create_parametrized_test!(shortint_trivial_operand_folding);
 create_parametrized_test!(shortint_unchecked_right_shift);
 create_parametrized_test!(shortint_default_right_shift);
 create_parametrized_test!(shortint_unchecked_left_shift);
@@ -124,6 +134,19 @@ create_parametrized_test!(shortint_smart_sub);
 create_parametrized_test!(shortint_default_sub);
 create_parametrized_test!(shortint_mul_small_carry);
 create_parametrized_test!(shortint_mux);
+create_parametrized_test!(shortint_add_flooding_noise);
+create_parametrized_test!(shortint_vector_ops);
+create_parametrized_test!(shortint_apply_lookup_table_with_mode {
+    PARAM_MESSAGE_2_CARRY_2_APPROX_PBS
+});
+create_parametrized_test!(shortint_custom_op {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_compact_public_key_smart_add);
+create_parametrized_test!(shortint_compressed_ciphertext_batch_smart_add);
+create_parametrized_test!(shortint_switch_pbs_order);
+create_parametrized_test!(shortint_degree_audit);
+create_parametrized_test!(shortint_max_chaining);
 
 // Public key tests are limited to small parameter sets to avoid blowing up memory and large testing
 // times. Compressed keygen takes 20 minutes for params 2_2 and for encryption as well.
@@ -143,12 +166,98 @@ fn test_shortint_public_key_smart_add_param_message_2_carry_2() {
     shortint_public_key_smart_add(PARAM_MESSAGE_2_CARRY_2)
 }
 
+#[test]
+fn test_shortint_lazy_seeded_bootstrapping_key_param_message_1_carry_1() {
+    shortint_lazy_seeded_bootstrapping_key(PARAM_MESSAGE_1_CARRY_1)
+}
+
+#[test]
+fn test_shortint_lazy_seeded_bootstrapping_key_param_message_2_carry_2() {
+    shortint_lazy_seeded_bootstrapping_key(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_server_key_from_raw_parts_param_message_2_carry_2() {
+    shortint_server_key_from_raw_parts(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_keys_from_raw_keys_param_message_2_carry_2() {
+    shortint_keys_from_raw_keys(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_t_uniform_noise_param_message_2_carry_2() {
+    shortint_t_uniform_noise(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_rng_backends_param_message_2_carry_2() {
+    shortint_rng_backends(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_ops_into_param_message_2_carry_2() {
+    shortint_ops_into(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_split_server_key_param_message_2_carry_2() {
+    shortint_split_server_key(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_apply_lookup_tables_pipelined_param_message_2_carry_2() {
+    shortint_apply_lookup_tables_pipelined(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_lookup_table_serialization_param_message_2_carry_2() {
+    shortint_lookup_table_serialization(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_key_rotation_param_message_2_carry_2() {
+    shortint_key_rotation(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_bootstrap_backends_param_message_2_carry_2() {
+    shortint_bootstrap_backends(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_ntt_bootstrap_backend_param_message_2_carry_2() {
+    shortint_ntt_bootstrap_backend(PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_boolean_gate_depth_param_boolean_carry_3() {
+    shortint_boolean_gate_depth(PARAM_BOOLEAN_CARRY_3)
+}
+
+#[test]
+fn test_shortint_boolean_gate_depth_param_boolean_carry_4() {
+    shortint_boolean_gate_depth(PARAM_BOOLEAN_CARRY_4)
+}
+
+#[test]
+fn test_shortint_engine_buffers_cache() {
+    shortint_engine_buffers_cache(PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2)
+}
+
+#[test]
+fn test_shortint_gen_keys_batch_param_message_1_carry_1() {
+    shortint_gen_keys_batch(PARAM_MESSAGE_1_CARRY_1)
+}
+
 //These functions are compatible with some parameter sets where the carry modulus is larger than
 // the message modulus.
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitand);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitor);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitxor);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_greater);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_comparison);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_greater_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less_or_equal);
@@ -175,8 +284,10 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_less_or_
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div);
-create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_div_mod_by_zero);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_mod);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_lsb);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_msb);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_mul_msb);
@@ -188,6 +299,9 @@ create_parametrized_test_bivariate_pbs_compliant!(
     shortint_encrypt_with_message_modulus_smart_add_and_mul
 );
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_less_or_equal_trivial);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_generate_accumulator_from_slice);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_apply_lookup_table_degree_control);
+create_parametrized_test!(shortint_compose_lookup_table);
 
 /// test encryption and decryption with the LWE client key
 fn shortint_encrypt_decrypt(param: Parameters) {
@@ -435,6 +549,76 @@ fn shortint_generate_accumulator(param: Parameters) {
     }
 }
 
+fn shortint_generate_accumulator_signed(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as i64;
+    let half_modulus_sup = modulus_sup / 2;
+
+    let f = |x: i64| 3 * x - 1;
+    let acc = sks.generate_accumulator_signed(f);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen_range(-half_modulus_sup..half_modulus_sup);
+
+        // encryption of the two's complement encoding of the value
+        let ct = cks.unchecked_encrypt(clear.rem_euclid(modulus_sup) as u64);
+
+        let ct_res = sks.apply_lookup_table(&ct, &acc);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt_message_and_carry(&ct_res);
+
+        // assert
+        assert_eq!(f(clear).rem_euclid(modulus_sup) as u64, dec_res);
+    }
+}
+
+fn shortint_generate_accumulator_from_slice(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus.0 as u64;
+
+    let table = (0..modulus_sup)
+        .map(|_| rng.gen::<u64>() % modulus_sup)
+        .collect::<Vec<_>>();
+    let acc = sks.generate_accumulator_from_slice(&table);
+    assert_eq!(acc.degree.0 as u64, *table.iter().max().unwrap());
+
+    let bivariate_table = (0..modulus * modulus)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let bivariate_acc = sks.generate_accumulator_bivariate_from_slice(&bivariate_table);
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let mut ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.apply_lookup_table(&ct_0, &acc);
+        let dec_res = cks.decrypt_message_and_carry(&ct_res);
+        assert_eq!(table[clear_0 as usize], dec_res);
+
+        let ct_res = sks.smart_apply_lookup_table_bivariate(&ct_0, &mut ct_1, &bivariate_acc);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!(
+            bivariate_table[(clear_0 * modulus + clear_1) as usize],
+            dec_res
+        );
+    }
+}
+
 /// test addition with the LWE server key
 fn shortint_unchecked_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -587,6 +771,649 @@ fn shortint_compressed_public_key_smart_add(param: Parameters) {
     }
 }
 
+/// test the PBS with a bootstrapping key decompressed on the fly against the classic PBS
+fn shortint_lazy_seeded_bootstrapping_key(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let lazy_sks = crate::shortint::ServerKey::from_compressed_lazy(
+        crate::shortint::CompressedServerKey::new(cks),
+    );
+
+    assert!(lazy_sks.bootstrapping_key_size_bytes() < sks.bootstrapping_key_size_bytes());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let f = |x: u64| (x * x + 1) % modulus;
+    let acc = lazy_sks.generate_accumulator(f);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Keyswitch then bootstrap
+        let ctxt = cks.encrypt(clear);
+        let ct_res = lazy_sks.apply_lookup_table(&ctxt, &acc);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+        assert_eq!(
+            cks.decrypt(&ct_res),
+            cks.decrypt(&sks.apply_lookup_table(&ctxt, &acc))
+        );
+
+        // Bootstrap then keyswitch
+        let ctxt = cks.encrypt_small(clear);
+        let ct_res = lazy_sks.apply_lookup_table(&ctxt, &acc);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+    }
+}
+
+/// test rebuilding a server key from a serialized Fourier bootstrapping key
+fn shortint_server_key_from_raw_parts(param: Parameters) {
+    use crate::core_crypto::prelude::FourierLweBootstrapKeyOwned;
+    use crate::shortint::server_key::ShortintBootstrappingKey;
+    use crate::shortint::{CompressedServerKey, ServerKey};
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    // Lazy key converted to the Fourier domain after the fact
+    let mut sks = ServerKey::from_compressed_lazy(CompressedServerKey::new(cks));
+    sks.precompute_fourier_bootstrapping_key();
+    assert!(matches!(
+        sks.bootstrapping_key,
+        ShortintBootstrappingKey::Classic(_)
+    ));
+
+    let (ksk, bsk, message_modulus, carry_modulus, max_degree, ciphertext_modulus) =
+        sks.into_raw_parts();
+
+    let serialized_bsk = bincode::serialize(&bsk.into_fourier()).unwrap();
+    let fourier_bsk: FourierLweBootstrapKeyOwned = bincode::deserialize(&serialized_bsk).unwrap();
+
+    let sks = ServerKey::from_raw_parts(
+        ksk,
+        fourier_bsk.into(),
+        message_modulus,
+        carry_modulus,
+        max_degree,
+        ciphertext_modulus,
+    );
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_mul_lsb(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+    }
+}
+
+/// test importing keys generated with the core_crypto primitives, as another toolchain would
+fn shortint_keys_from_raw_keys(param: Parameters) {
+    use crate::core_crypto::prelude::*;
+    use crate::shortint::ServerKey;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (glwe_secret_key, small_lwe_secret_key, parameters) =
+        keys.client_key().clone().into_raw_parts();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &small_lwe_secret_key,
+        &glwe_secret_key,
+        param.pbs_base_log,
+        param.pbs_level,
+        param.glwe_noise_distribution(),
+        param.ciphertext_modulus,
+        &mut encryption_generator,
+    );
+    let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+        &glwe_secret_key.clone().into_lwe_secret_key(),
+        &small_lwe_secret_key,
+        param.ks_base_log,
+        param.ks_level,
+        param.lwe_noise_distribution(),
+        param.ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    // Keys which do not match the parameters are rejected
+    let mut wrong_param = param;
+    wrong_param.ks_level = DecompositionLevelCount(param.ks_level.0 + 1);
+    assert!(ServerKey::try_from_raw_keys(ksk.clone(), bsk.clone(), wrong_param).is_err());
+    let mut wrong_param = param;
+    wrong_param.lwe_dimension = LweDimension(param.lwe_dimension.0 + 1);
+    assert!(ClientKey::try_from_raw_parts(
+        glwe_secret_key.clone(),
+        small_lwe_secret_key.clone(),
+        wrong_param
+    )
+    .is_err());
+
+    let cks =
+        ClientKey::try_from_raw_parts(glwe_secret_key, small_lwe_secret_key, parameters).unwrap();
+    assert_eq!(&cks, keys.client_key());
+    let sks = ServerKey::try_from_raw_keys(ksk, bsk, parameters).unwrap();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_mul_lsb(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+    }
+}
+
+/// test keys and ciphertexts encrypted with a bounded uniform noise, with bounds lower than the
+/// standard deviations of the gaussian noise of the parameters
+fn shortint_t_uniform_noise(param: Parameters) {
+    use crate::shortint::gen_keys;
+
+    let mut param = param;
+    param.noise_distribution = NoiseDistributionKind::TUniform {
+        lwe_bound_log2: 46,
+        glwe_bound_log2: 24,
+    };
+    let (cks, sks) = gen_keys(param);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        assert_eq!(cks.decrypt(&ctxt_0), clear_0);
+
+        let ct_res = sks.unchecked_mul_lsb(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+    }
+}
+
+fn shortint_rng_backends(param: Parameters) {
+    use crate::core_crypto::commons::math::random::RandomGeneratorBackend;
+    use crate::shortint::engine::ShortintEngine;
+    use crate::shortint::gen_keys;
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    for backend in RandomGeneratorBackend::available() {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            *engine = ShortintEngine::new_with_backend(backend);
+        });
+        let (cks, sks) = gen_keys(param);
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // The snapshot keeps the backend
+        let state = ShortintEngine::with_thread_local_mut(|engine| engine.snapshot_rng_state());
+        let state: crate::shortint::engine::EngineRngState =
+            bincode::deserialize(&bincode::serialize(&state).unwrap()).unwrap();
+        let ctxt_0 = cks.encrypt(clear_0);
+        let engine = ShortintEngine::new_from_rng_state(&state);
+        assert_eq!(engine.rng_backend(), backend);
+        ShortintEngine::with_thread_local_mut(|local_engine| *local_engine = engine);
+        assert_eq!(cks.encrypt(clear_0).ct, ctxt_0.ct);
+
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 + clear_1) % modulus);
+    }
+
+    ShortintEngine::with_thread_local_mut(|engine| *engine = ShortintEngine::new());
+}
+
+fn shortint_ops_into(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+    let acc = sks.generate_accumulator(|x| (x + 1) % modulus);
+
+    let mut ct_out = sks.create_trivial(0);
+    let out_ptr = ct_out.ct.as_ref().as_ptr();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        sks.unchecked_add_into(&ctxt_0, &ctxt_1, &mut ct_out);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_out), clear_0 + clear_1);
+
+        // The carry of the output of the unchecked add is cleared
+        let dirty = ct_out.clone();
+        sks.bitand_into(&dirty, &ctxt_1, &mut ct_out);
+        assert_eq!(
+            cks.decrypt(&ct_out),
+            ((clear_0 + clear_1) % modulus) & clear_1
+        );
+
+        sks.sub_into(&ctxt_0, &ctxt_1, &mut ct_out);
+        assert_eq!(
+            cks.decrypt(&ct_out),
+            (clear_0 + modulus - clear_1) % modulus
+        );
+
+        sks.mul_lsb_into(&ctxt_0, &ctxt_1, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), (clear_0 * clear_1) % modulus);
+
+        sks.unchecked_bitxor_into(&ctxt_0, &ctxt_1, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), clear_0 ^ clear_1);
+
+        sks.bitor_into(&ctxt_0, &ctxt_1, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), clear_0 | clear_1);
+
+        sks.apply_lookup_table_into(&ctxt_0, &acc, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), (clear_0 + 1) % modulus);
+
+        let sum = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        sks.carry_extract_into(&sum, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), (clear_0 + clear_1) / modulus);
+        sks.message_extract_into(&sum, &mut ct_out);
+        assert_eq!(cks.decrypt(&ct_out), (clear_0 + clear_1) % modulus);
+    }
+
+    // The output ciphertext was never reallocated
+    assert_eq!(ct_out.ct.as_ref().as_ptr(), out_ptr);
+}
+
+fn shortint_split_server_key(param: Parameters) {
+    use crate::shortint::server_key::{BootstrapServerKey, KeyswitchServerKey};
+    use crate::shortint::{CiphertextSmall, ServerKey};
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let (ks_key, bs_key) = sks.clone().split();
+
+    // Each half travels to its own process
+    let ks_key: KeyswitchServerKey =
+        bincode::deserialize(&bincode::serialize(&ks_key).unwrap()).unwrap();
+    let bs_key: BootstrapServerKey =
+        bincode::deserialize(&bincode::serialize(&bs_key).unwrap()).unwrap();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_small = ks_key.keyswitch(&ctxt);
+        let ct_small: CiphertextSmall =
+            bincode::deserialize(&bincode::serialize(&ct_small).unwrap()).unwrap();
+
+        let acc = bs_key.generate_accumulator(|x| (x * x) % modulus);
+        assert_eq!(acc, sks.generate_accumulator(|x| (x * x) % modulus));
+
+        let ct_res: CiphertextBig = bs_key.bootstrap(&ct_small, &acc);
+        assert_eq!(ct_res.degree, acc.degree);
+        assert_eq!(cks.decrypt(&ct_res), (clear * clear) % modulus);
+    }
+
+    let sks_rebuilt = ServerKey::from_split(ks_key, bs_key);
+    assert_eq!(&sks_rebuilt, sks);
+}
+
+fn shortint_apply_lookup_tables_pipelined(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    let accs = (0..modulus)
+        .map(|i| sks.generate_accumulator(|x| (x + i) % modulus))
+        .collect::<Vec<_>>();
+
+    let clears = (0..NB_TEST)
+        .map(|_| (rng.gen::<u64>() % modulus, rng.gen::<usize>() % accs.len()))
+        .collect::<Vec<_>>();
+
+    let cts = clears
+        .iter()
+        .map(|&(clear, _)| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+    let ops = cts
+        .iter()
+        .zip(clears.iter())
+        .map(|(ct, &(_, i))| (ct, &accs[i]))
+        .collect::<Vec<_>>();
+    let res = sks.apply_lookup_tables_pipelined(&ops);
+
+    let cts_small = clears
+        .iter()
+        .map(|&(clear, _)| cks.encrypt_small(clear))
+        .collect::<Vec<_>>();
+    let ops_small = cts_small
+        .iter()
+        .zip(clears.iter())
+        .map(|(ct, &(_, i))| (ct, &accs[i]))
+        .collect::<Vec<_>>();
+    let res_small = sks.apply_lookup_tables_pipelined(&ops_small);
+
+    for (&(clear, i), (ct, ct_small)) in clears.iter().zip(res.iter().zip(res_small.iter())) {
+        let expected = (clear + i as u64) % modulus;
+        assert_eq!(cks.decrypt(ct), expected);
+        assert_eq!(cks.decrypt(ct_small), expected);
+        assert_eq!(ct.degree, accs[i].degree);
+    }
+
+    // The pipeline computes the same PBS as the sequential path
+    let expected = sks.apply_lookup_table(&cts[0], ops[0].1);
+    assert_eq!(res[0].ct, expected.ct);
+}
+
+fn shortint_lookup_table_serialization(param: Parameters) {
+    use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned};
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    let acc = sks.generate_accumulator(|x| (3 * x + 1) % modulus);
+    let acc: LookupTableOwned = bincode::deserialize(&bincode::serialize(&acc).unwrap()).unwrap();
+    sks.check_lookup_table(&acc).unwrap();
+
+    let bivariate_acc = sks.generate_accumulator_bivariate(|x, y| (x + 2 * y) % modulus);
+    let bivariate_acc: BivariateLookupTableOwned =
+        bincode::deserialize(&bincode::serialize(&bivariate_acc).unwrap()).unwrap();
+    sks.check_bivariate_lookup_table(&bivariate_acc).unwrap();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.apply_lookup_table(&ctxt_0, &acc);
+        assert_eq!(cks.decrypt(&ct_res), (3 * clear_0 + 1) % modulus);
+
+        let ct_res = sks.unchecked_apply_lookup_table_bivariate(&ctxt_0, &ctxt_1, &bivariate_acc);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 + 2 * clear_1) % modulus);
+    }
+
+    // A table whose degree was tightened is still valid
+    sks.check_lookup_table(&acc.clone().with_degree(Degree(1)))
+        .unwrap();
+
+    // A corrupted table is rejected
+    let mut corrupted_acc = acc;
+    corrupted_acc.acc.as_mut()[0] = corrupted_acc.acc.as_mut()[0].wrapping_add(1);
+    assert!(sks.check_lookup_table(&corrupted_acc).is_err());
+}
+
+fn shortint_key_rotation(param: Parameters) {
+    use crate::shortint::{KeyRotationKey, ServerKey};
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let (new_cks, rotation_key) = cks.rotate();
+    let new_sks = ServerKey::new(&new_cks);
+
+    // The rotation key is sent to the server
+    let rotation_key: KeyRotationKey =
+        bincode::deserialize(&bincode::serialize(&rotation_key).unwrap()).unwrap();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let clears = (0..NB_TEST)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let mut cts = clears.iter().map(|m| cks.encrypt(*m)).collect::<Vec<_>>();
+    rotation_key.migrate_ciphertexts(&mut cts);
+
+    let mut cts_small = clears
+        .iter()
+        .map(|m| cks.encrypt_small(*m))
+        .collect::<Vec<_>>();
+    rotation_key.migrate_ciphertexts(&mut cts_small);
+
+    for ((clear, ct), ct_small) in clears.iter().zip(cts.iter()).zip(cts_small.iter()) {
+        assert_eq!(new_cks.decrypt(ct), *clear);
+        assert_eq!(new_cks.decrypt(ct_small), *clear);
+
+        // The migrated ciphertexts are usable with the new server key
+        let ct_res = new_sks.scalar_add(ct, 1);
+        assert_eq!(new_cks.decrypt(&ct_res), (clear + 1) % modulus);
+        let ct_res = new_sks.scalar_add(ct_small, 1);
+        assert_eq!(new_cks.decrypt(&ct_res), (clear + 1) % modulus);
+    }
+}
+
+/// test that as many booleans as the carry space allows can be summed before a single PBS
+/// evaluating a wide gate
+fn shortint_boolean_gate_depth(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let max_inputs = sks.max_degree.0;
+
+    let parity = sks.generate_accumulator(|x| x % 2);
+    let majority = sks.generate_accumulator(|x| u64::from(2 * x > max_inputs as u64));
+
+    for _ in 0..NB_TEST {
+        let clears = (0..max_inputs)
+            .map(|_| rng.gen::<u64>() % 2)
+            .collect::<Vec<_>>();
+        let cts = clears.iter().map(|&b| cks.encrypt(b)).collect::<Vec<_>>();
+
+        let mut ct_sum = cts[0].clone();
+        for ct in cts[1..].iter() {
+            assert!(sks.is_add_possible(&ct_sum, ct));
+            sks.unchecked_add_assign(&mut ct_sum, ct);
+        }
+        assert_eq!(ct_sum.degree.0, max_inputs);
+
+        let sum = clears.iter().sum::<u64>();
+
+        let ct_res = sks.apply_lookup_table(&ct_sum, &parity);
+        assert_eq!(cks.decrypt(&ct_res), sum % 2);
+
+        let ct_res = sks.apply_lookup_table(&ct_sum, &majority);
+        assert_eq!(cks.decrypt(&ct_res), u64::from(2 * sum > max_inputs as u64));
+    }
+}
+
+/// test that alternating between keys of different sizes with a small buffers cache is correct
+fn shortint_gen_keys_batch(param: Parameters) {
+    let keys = crate::shortint::gen_keys_batch(param, 3).unwrap();
+    assert_eq!(keys.len(), 3);
+
+    let modulus = param.message_modulus.0 as u64;
+    for (i, (cks, sks)) in keys.iter().enumerate() {
+        let clear = i as u64 % modulus;
+        let ct = sks.unchecked_scalar_add(&cks.encrypt(clear), 1);
+        let ct = sks.message_extract(&ct);
+        assert_eq!(cks.decrypt(&ct), (clear + 1) % modulus);
+    }
+
+    // Each client gets its own keys
+    assert_ne!(keys[0].0, keys[1].0);
+
+    let mut invalid_param = param;
+    invalid_param.message_modulus = MessageModulus(3);
+    assert!(crate::shortint::gen_keys_batch(invalid_param, 3).is_err());
+}
+
+fn shortint_engine_buffers_cache(param_1: Parameters, param_2: Parameters) {
+    use crate::shortint::engine::ShortintEngine;
+
+    let keys_1 = KEY_CACHE.get_from_param(param_1);
+    let keys_2 = KEY_CACHE.get_from_param(param_2);
+
+    let mut rng = rand::thread_rng();
+
+    for capacity in [1, 2] {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.set_buffers_cache_capacity(capacity);
+            engine.preallocate_for(keys_1.server_key());
+            engine.preallocate_for(keys_2.server_key());
+        });
+
+        for _ in 0..NB_TEST {
+            for keys in [&keys_1, &keys_2] {
+                let (cks, sks) = (keys.client_key(), keys.server_key());
+                let modulus = cks.parameters.message_modulus.0 as u64;
+
+                let clear = rng.gen::<u64>() % modulus;
+                let ct = cks.encrypt(clear);
+                let ct_res = sks.message_extract(&ct);
+
+                assert_eq!(cks.decrypt(&ct_res), clear);
+            }
+        }
+    }
+
+    ShortintEngine::with_thread_local_mut(|engine| {
+        engine.set_buffers_cache_capacity(crate::shortint::engine::DEFAULT_BUFFERS_CACHE_CAPACITY);
+    });
+}
+
+/// test that all the available backends compute the same ciphertexts
+fn shortint_bootstrap_backends(param: Parameters) {
+    use crate::shortint::server_key::backend::available_backends;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let cts = (0..8)
+        .map(|_| cks.encrypt(rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+    let small_cts = (0..8)
+        .map(|_| cks.encrypt_small(rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+
+    let mut reference = None;
+    // The backends working on Fourier keys are bit for bit equal, the others are tested on their
+    // own
+    for backend in available_backends()
+        .into_iter()
+        .filter(|backend| backend.capabilities().fourier_key)
+    {
+        let mut sks = keys.server_key().clone();
+        sks.set_bootstrap_backend(backend);
+
+        let acc = sks.generate_accumulator(|x| (x + 1) % modulus);
+
+        let single = cts
+            .iter()
+            .map(|ct| sks.apply_lookup_table(ct, &acc).ct)
+            .collect::<Vec<_>>();
+        let batch = sks
+            .apply_lookup_table_batch(&cts, &acc)
+            .into_iter()
+            .map(|ct| ct.ct)
+            .collect::<Vec<_>>();
+        let small_batch = sks.apply_lookup_table_batch(&small_cts, &acc);
+
+        assert_eq!(single, batch);
+        for (ct, ct_res) in small_cts.iter().zip(small_batch.iter()) {
+            assert_eq!(cks.decrypt(ct_res), (cks.decrypt(ct) + 1) % modulus);
+        }
+        let small_batch = small_batch.into_iter().map(|ct| ct.ct).collect::<Vec<_>>();
+
+        match &reference {
+            None => reference = Some((batch, small_batch)),
+            Some((ref_batch, ref_small_batch)) => {
+                assert_eq!(ref_batch, &batch);
+                assert_eq!(ref_small_batch, &small_batch);
+            }
+        }
+    }
+}
+
+/// test the NTT backend against the FFT backend on a lazy seeded key
+fn shortint_ntt_bootstrap_backend(param: Parameters) {
+    use crate::shortint::server_key::backend::backend_by_name;
+    use crate::shortint::{CompressedServerKey, ServerKey};
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let fft_sks = ServerKey::from_compressed_lazy(CompressedServerKey::new(cks));
+    let mut ntt_sks = fft_sks.clone();
+    ntt_sks.set_bootstrap_backend(backend_by_name("ntt").unwrap());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let f = |x: u64| (x * x + 1) % modulus;
+    let acc = ntt_sks.generate_accumulator(f);
+
+    let clears = (0..NB_TEST)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    let small_cts = clears
+        .iter()
+        .map(|&m| cks.encrypt_small(m))
+        .collect::<Vec<_>>();
+
+    let batch = ntt_sks.apply_lookup_table_batch(&cts, &acc);
+    for ((&clear, ct), ct_batch) in clears.iter().zip(cts.iter()).zip(batch.iter()) {
+        // Keyswitch then bootstrap
+        let ct_res = ntt_sks.apply_lookup_table(ct, &acc);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+        assert_eq!(
+            cks.decrypt(&ct_res),
+            cks.decrypt(&fft_sks.apply_lookup_table(ct, &acc))
+        );
+        assert_eq!(ct_res.ct, ct_batch.ct);
+    }
+
+    // Bootstrap then keyswitch
+    for (&clear, ct) in clears.iter().zip(small_cts.iter()) {
+        let ct_res = ntt_sks.apply_lookup_table(ct, &acc);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+    }
+}
+
 /// test addition with the LWE server key using the a public key for encryption
 fn shortint_public_key_smart_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -613,21 +1440,158 @@ fn shortint_public_key_smart_add(param: Parameters) {
         let mut ct_res = sks.smart_add(&mut ctxt_0, &mut ctxt_1);
         let mut clear = clear_0 + clear_1;
 
-        //add multiple times to raise the degree and test the smart operation
-        for _ in 0..40 {
-            ct_res = sks.smart_add(&mut ct_res, &mut ctxt_0);
-            clear += clear_0;
+        //add multiple times to raise the degree and test the smart operation
+        for _ in 0..40 {
+            ct_res = sks.smart_add(&mut ct_res, &mut ctxt_0);
+            clear += clear_0;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear % modulus, dec_res);
+        }
+    }
+}
+
+/// test addition with the LWE server key using the a compact public key for encryption
+fn shortint_compact_public_key_smart_add(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clears = (0..3)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        // encryption of the integers in a single compact list, sent to the server and expanded
+        let compact_list = pk.encrypt_slice(&clears);
+        let compact_list: crate::shortint::CompactCiphertextList =
+            bincode::deserialize(&bincode::serialize(&compact_list).unwrap()).unwrap();
+        assert_eq!(compact_list.len(), clears.len());
+
+        let mut cts = compact_list.expand();
+        for (ct, clear) in cts.iter().zip(clears.iter()) {
+            assert_eq!(cks.decrypt(ct), *clear);
+        }
+
+        // add the ciphertexts
+        let (ctxt_0, rest) = cts.split_first_mut().unwrap();
+        let mut ct_res = sks.smart_add(ctxt_0, &mut rest[0]);
+        ct_res = sks.smart_add(&mut ct_res, &mut rest[1]);
+        let mut clear = clears.iter().sum::<u64>();
+
+        //add multiple times to raise the degree and test the smart operation
+        for _ in 0..10 {
+            ct_res = sks.smart_add(&mut ct_res, ctxt_0);
+            clear += clears[0];
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear % modulus, dec_res);
+        }
+    }
+}
+
+/// test bitwise 'and' with the LWE server key
+fn shortint_apply_lookup_table_degree_control(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus.0 as u64;
+
+    let f = |x| (x + 1) % modulus_sup;
+    let acc = sks.generate_accumulator(f);
+    assert_eq!(acc.degree.0 as u64, modulus_sup - 1);
+    let acc = sks.generate_accumulator_for_input_degree(f, Degree(modulus as usize - 1));
+    assert_eq!(acc.degree.0 as u64, modulus);
+
+    let bivariate_acc = sks.generate_accumulator_bivariate(|x, y| (x + y) % modulus);
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.apply_lookup_table(&ct_0, &acc);
+        assert_eq!(ct_res.degree, acc.degree);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), clear_0 + 1);
+
+        let ct_res = sks
+            .checked_apply_lookup_table_bivariate(&ct_0, &ct_1, &bivariate_acc)
+            .unwrap();
+        assert_eq!(ct_res.degree, bivariate_acc.acc.degree);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 + clear_1) % modulus);
+
+        // ct_2 has carries, the inputs are cleaned on copies
+        let ct_2 = sks.unchecked_add(&ct_1, &ct_1);
+        let clear_2 = (2 * clear_1) % modulus;
+        let ct_res = sks.apply_lookup_table_bivariate(&ct_2, &ct_2, &bivariate_acc);
+        assert_eq!(ct_res.degree, bivariate_acc.acc.degree);
+        assert_eq!(cks.decrypt(&ct_res), (2 * clear_2) % modulus);
+        assert_eq!(cks.decrypt_message_and_carry(&ct_2), 2 * clear_1);
+    }
+}
+
+fn shortint_compose_lookup_table(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus.0 as u64;
+
+    let table = (0..modulus_sup)
+        .map(|_| rng.gen::<u64>() % modulus_sup)
+        .collect::<Vec<_>>();
+    let acc = sks.generate_accumulator_from_slice(&table);
+    assert_eq!(sks.lookup_table_values(&acc), table);
+
+    let g = |x: u64| (x * 3 + 1) % modulus_sup;
+    let composed = sks.compose_lookup_table(&acc, g);
+    let expected = table.iter().map(|&x| g(x)).collect::<Vec<_>>();
+    assert_eq!(sks.lookup_table_values(&composed), expected);
+    assert_eq!(composed.degree.0 as u64, *expected.iter().max().unwrap());
+
+    let negated = sks.negate_lookup_table(&acc);
+    let complemented = sks.complement_lookup_table(&acc);
+    assert!(negated.degree.0 < modulus as usize);
+    assert!(complemented.degree.0 < modulus as usize);
+
+    // The degree of the inner accumulator restricts the inputs of the outer function
+    let identity = sks.generate_accumulator_for_input_degree(|x| x, Degree(modulus as usize - 1));
+    let composed = sks.compose_lookup_table(&identity, |x| x + 1);
+    assert_eq!(composed.degree.0 as u64, modulus);
 
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt(clear);
+        let value = table[clear as usize];
 
-            // assert
-            assert_eq!(clear % modulus, dec_res);
-        }
+        let ct_res = sks.apply_lookup_table(&ct, &negated);
+        assert_eq!(cks.decrypt(&ct_res), (modulus - value % modulus) % modulus);
+
+        let ct_res = sks.apply_lookup_table(&ct, &complemented);
+        assert_eq!(cks.decrypt(&ct_res), modulus - 1 - value % modulus);
     }
 }
 
-/// test bitwise 'and' with the LWE server key
 fn shortint_unchecked_bitand(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
@@ -975,6 +1939,47 @@ fn shortint_unchecked_greater(param: Parameters) {
     }
 }
 
+/// test the generic comparisons and their cached accumulators with the LWE server key
+fn shortint_comparison(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for op in [
+        ComparisonOp::Greater,
+        ComparisonOp::GreaterOrEqual,
+        ComparisonOp::Less,
+        ComparisonOp::LessOrEqual,
+        ComparisonOp::Equal,
+        ComparisonOp::NotEqual,
+    ] {
+        let acc = sks.generate_comparison_accumulator(op);
+
+        for _ in 0..NB_TEST {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = rng.gen::<u64>() % modulus;
+
+            let mut ctxt_0 = cks.encrypt(clear_0);
+            let mut ctxt_1 = cks.encrypt(clear_1);
+
+            let expected = op.evaluate(clear_0, clear_1);
+
+            let ct_res = sks.unchecked_apply_lookup_table_bivariate(&ctxt_0, &ctxt_1, &acc);
+            assert_eq!(cks.decrypt(&ct_res), expected);
+
+            let ct_res = sks.unchecked_comparison(&ctxt_0, &ctxt_1, op);
+            assert_eq!(cks.decrypt(&ct_res), expected);
+            assert_eq!(ct_res.degree, Degree(1));
+
+            let ct_res = sks.smart_comparison(&mut ctxt_0, &mut ctxt_1, op);
+            assert_eq!(cks.decrypt(&ct_res), expected);
+        }
+    }
+}
+
 /// test '>' with the LWE server key
 fn shortint_smart_greater(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -1663,6 +2668,74 @@ fn shortint_unchecked_div(param: Parameters) {
     }
 }
 
+/// test modulo with the LWE server key
+fn shortint_unchecked_mod(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // modulo of the two ciphertexts
+        let ct_res = sks.unchecked_mod(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 % clear_1, dec_res);
+    }
+}
+
+/// test the division and modulo by zero conventions with the LWE server key
+fn shortint_div_mod_by_zero(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % 2;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_quotient, ct_is_zero) = sks.div_with_zero_flag(&ctxt_0, &ctxt_1);
+        let ct_remainder = sks.mod_(&ctxt_0, &ctxt_1);
+
+        let quotient = cks.decrypt(&ct_quotient);
+        let remainder = cks.decrypt(&ct_remainder);
+        let is_zero = cks.decrypt(&ct_is_zero);
+
+        match (clear_0.checked_div(clear_1), clear_0.checked_rem(clear_1)) {
+            (Some(expected_quotient), Some(expected_remainder)) => {
+                assert_eq!(quotient, expected_quotient);
+                assert_eq!(remainder, expected_remainder);
+            }
+            _ => {
+                assert_eq!(quotient, 0);
+                assert_eq!(remainder, clear_0);
+            }
+        }
+        assert_eq!(is_zero, u64::from(clear_1 == 0));
+        assert_eq!(quotient * clear_1 + remainder, clear_0);
+    }
+}
+
 /// test scalar division with the LWE server key
 fn shortint_unchecked_scalar_div(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -1690,8 +2763,8 @@ fn shortint_unchecked_scalar_div(param: Parameters) {
     }
 }
 
-/// test modulus with the LWE server key
-fn shortint_unchecked_mod(param: Parameters) {
+/// test scalar modulus with the LWE server key
+fn shortint_unchecked_scalar_mod(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -2343,6 +3416,83 @@ fn shortint_default_scalar_mul(param: Parameters) {
     }
 }
 
+fn shortint_trivial_operand_folding(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let trivial_0: CiphertextBig = sks.create_trivial(clear_0);
+        let trivial_1: CiphertextBig = sks.create_trivial(clear_1);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // Lookup tables are evaluated in the clear on trivial ciphertexts
+        let acc = sks.generate_accumulator(|x| (x * x + 1) % modulus);
+        let ct_res = sks.apply_lookup_table(&trivial_0, &acc);
+        assert!(ct_res.is_trivial());
+        assert_eq!(ct_res.degree, acc.degree);
+        assert_eq!(
+            ct_res.decrypt_trivial(),
+            Some((clear_0 * clear_0 + 1) % modulus)
+        );
+
+        // Including the carry cleaning of a trivial ciphertext with carries
+        let sum = sks.unchecked_add(&trivial_0, &trivial_1);
+        let ct_res = sks.message_extract(&sum);
+        assert!(ct_res.is_trivial());
+        assert_eq!(
+            ct_res.decrypt_trivial(),
+            Some((clear_0 + clear_1) % modulus)
+        );
+
+        let ct_res = sks.unchecked_mul_lsb(&trivial_0, &trivial_1);
+        assert!(ct_res.is_trivial());
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+
+        // With a single trivial operand, the bivariate function is a lookup table of the other
+        let ct_res = sks.unchecked_mul_lsb(&trivial_0, &ctxt_1);
+        assert!(!ct_res.is_trivial());
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 * clear_1) % modulus);
+
+        let ct_res = sks.unchecked_greater(&ctxt_1, &trivial_0);
+        assert_eq!(cks.decrypt(&ct_res), (clear_1 > clear_0) as u64);
+    }
+}
+
+fn shortint_default_signed_scalar_ops(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as i64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus as u64;
+        let scalar = rng.gen::<i8>();
+
+        let ct = cks.encrypt(clear);
+
+        let ct_res = sks.scalar_add_signed(&ct, scalar);
+        let expected = (clear as i64 + scalar as i64).rem_euclid(modulus) as u64;
+        assert_eq!(cks.decrypt(&ct_res), expected);
+
+        let ct_res = sks.scalar_sub_signed(&ct, scalar);
+        let expected = (clear as i64 - scalar as i64).rem_euclid(modulus) as u64;
+        assert_eq!(cks.decrypt(&ct_res), expected);
+
+        let ct_res = sks.scalar_mul_signed(&ct, scalar);
+        let expected = (clear as i64 * scalar as i64).rem_euclid(modulus) as u64;
+        assert_eq!(cks.decrypt(&ct_res), expected);
+    }
+}
+
 /// test unchecked '>>' operation
 fn shortint_unchecked_right_shift(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -2632,3 +3782,382 @@ fn shortint_mux(param: Parameters) {
     println!("(msg_true - msg_false) * control_bit  + msg_false = {clear_mux}, res = {dec_res}");
     assert_eq!(clear_mux, dec_res);
 }
+
+fn shortint_add_flooding_noise(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    // A tenth of the decryption failure bound, see ServerKey::add_flooding_noise
+    let p = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as f64;
+    let std_dev = StandardDev(1.0 / (40.0 * p));
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+
+        let mut ct_flooded = ct.clone();
+        sks.add_flooding_noise(&mut ct_flooded, std_dev);
+        assert_ne!(ct_flooded.ct, ct.ct);
+        assert_eq!(ct_flooded.degree, ct.degree);
+        assert_eq!(cks.decrypt(&ct_flooded), clear);
+
+        assert_eq!(cks.decrypt_with_flooding(&ct, std_dev), clear);
+    }
+}
+
+fn shortint_vector_ops(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let len = 16;
+    let clear_0 = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let clear_1 = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let scalar = rng.gen::<u8>() % modulus as u8;
+
+    fn decrypt_vector<OpOrder: PBSOrderMarker>(
+        cks: &ClientKey,
+        vector: &ShortintVector<OpOrder>,
+    ) -> Vec<u64> {
+        vector
+            .to_ciphertexts()
+            .iter()
+            .map(|ct| cks.decrypt(ct))
+            .collect()
+    }
+
+    // Big ciphertexts
+    let cts_0 = clear_0.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    let cts_1 = clear_1.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    let vector_0 = ShortintVector::from_ciphertexts(&cts_0);
+    let vector_1 = ShortintVector::from_ciphertexts(&cts_1);
+    assert_eq!(vector_0.len(), len);
+    assert_eq!(decrypt_vector(cks, &vector_0), clear_0);
+
+    let sum = sks.vector_add(&vector_0, &vector_1);
+    assert!(sum.carry_is_empty());
+    let expected = clear_0
+        .iter()
+        .zip(clear_1.iter())
+        .map(|(a, b)| (a + b) % modulus)
+        .collect::<Vec<_>>();
+    assert_eq!(decrypt_vector(cks, &sum), expected);
+
+    let product = sks.vector_scalar_mul(&vector_0, scalar);
+    let expected = clear_0
+        .iter()
+        .map(|a| (a * scalar as u64) % modulus)
+        .collect::<Vec<_>>();
+    assert_eq!(decrypt_vector(cks, &product), expected);
+
+    // Small ciphertexts
+    let cts_0 = clear_0
+        .iter()
+        .map(|&m| cks.encrypt_small(m))
+        .collect::<Vec<_>>();
+    let cts_1 = clear_1
+        .iter()
+        .map(|&m| cks.encrypt_small(m))
+        .collect::<Vec<_>>();
+    let vector_0 = ShortintVector::from_ciphertexts(&cts_0);
+    let vector_1 = ShortintVector::from_ciphertexts(&cts_1);
+
+    let mut sum = sks.unchecked_vector_add(&vector_0, &vector_1);
+    assert_eq!(sum.degree(), Degree(2 * (modulus as usize - 1)));
+    sks.vector_message_extract_assign(&mut sum);
+    let expected = clear_0
+        .iter()
+        .zip(clear_1.iter())
+        .map(|(a, b)| (a + b) % modulus)
+        .collect::<Vec<_>>();
+    assert_eq!(decrypt_vector(cks, &sum), expected);
+}
+
+fn shortint_apply_lookup_table_with_mode(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let f = |x: u64| (x * x + 1) % modulus;
+    let acc = sks.generate_accumulator(f);
+
+    // The approximate mode has a higher failure probability, which is still far from being
+    // observable in NB_TEST bootstraps
+    for mode in [PbsMode::Exact, PbsMode::Approximate] {
+        for _ in 0..NB_TEST {
+            let clear = rng.gen::<u64>() % modulus;
+
+            let ct = cks.encrypt(clear);
+            let ct_res = sks.apply_lookup_table_with_mode(&ct, &acc, mode);
+            assert_eq!(ct_res.degree, acc.degree);
+            assert_eq!(cks.decrypt(&ct_res), f(clear));
+
+            let ct = cks.encrypt_small(clear);
+            let ct_res = sks.apply_lookup_table_with_mode(&ct, &acc, mode);
+            assert_eq!(ct_res.degree, acc.degree);
+            assert_eq!(cks.decrypt(&ct_res), f(clear));
+        }
+    }
+}
+
+/// Computes `(x + 1) % message_modulus` with a keyswitch and a bootstrap done through the context
+struct KeyswitchThenIncrement;
+
+impl CustomOp for KeyswitchThenIncrement {
+    fn name(&self) -> &str {
+        "test_keyswitch_then_increment"
+    }
+
+    fn num_inputs(&self) -> usize {
+        1
+    }
+
+    fn evaluate(
+        &self,
+        context: &CustomOpContext<'_>,
+        inputs: &[&CiphertextBig],
+    ) -> Result<CiphertextBig, CustomOpError> {
+        use crate::core_crypto::prelude::*;
+
+        let sks = context.server_key();
+        let modulus = sks.message_modulus.0 as u64;
+        let acc = sks.generate_accumulator(|x| (x + 1) % modulus);
+
+        let mut keyswitched = LweCiphertext::new(
+            0u64,
+            sks.key_switching_key.output_lwe_size(),
+            sks.ciphertext_modulus,
+        );
+        context.keyswitch(&inputs[0].ct, &mut keyswitched);
+
+        let mut result = inputs[0].clone();
+        context.programmable_bootstrap(&keyswitched, &mut result.ct, &acc.acc);
+        result.degree = acc.degree;
+        Ok(result)
+    }
+}
+
+fn shortint_custom_op(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let name = KeyswitchThenIncrement.name().to_owned();
+    register_custom_op(std::sync::Arc::new(KeyswitchThenIncrement));
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        let ct_res = sks.custom_op(&name, &[&ct]).unwrap();
+        assert_eq!(cks.decrypt(&ct_res), (clear + 1) % modulus);
+    }
+
+    let ct = cks.encrypt(0);
+    assert_eq!(
+        sks.custom_op(&name, &[&ct, &ct]).unwrap_err(),
+        CustomOpError::WrongNumberOfInputs {
+            name: name.clone(),
+            expected: 1,
+            found: 2,
+        }
+    );
+
+    let ct_small = cks.encrypt_small(0);
+    let result: Result<CiphertextSmall, _> = sks.custom_op(&name, &[&ct_small]);
+    assert_eq!(
+        result.unwrap_err(),
+        CustomOpError::UnsupportedPbsOrder {
+            name: name.clone(),
+            pbs_order: PBSOrder::BootstrapKeyswitch,
+        }
+    );
+
+    assert!(unregister_custom_op(&name).is_some());
+    assert_eq!(
+        sks.custom_op(&name, &[&ct]).unwrap_err(),
+        CustomOpError::UnknownOp(name)
+    );
+}
+
+fn shortint_compressed_ciphertext_batch_smart_add(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clears = (0..3)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        // encryption of the integers in a single batch, sent to the server and expanded
+        let batch = cks.encrypt_compressed_batch(&clears);
+        let serialized_batch = bincode::serialize(&batch).unwrap();
+        let batch: crate::shortint::CompressedCiphertextBatch =
+            bincode::deserialize(&serialized_batch).unwrap();
+        assert_eq!(batch.len(), clears.len());
+
+        // the batch is smaller than the ciphertexts compressed one by one
+        let individual_size: usize = clears
+            .iter()
+            .map(|clear| {
+                bincode::serialized_size(&cks.encrypt_compressed(*clear)).unwrap() as usize
+            })
+            .sum();
+        assert!(serialized_batch.len() < individual_size);
+
+        let mut cts = batch.expand();
+        for (ct, clear) in cts.iter().zip(clears.iter()) {
+            assert_eq!(cks.decrypt(ct), *clear);
+        }
+
+        // add the ciphertexts
+        let (ctxt_0, rest) = cts.split_first_mut().unwrap();
+        let mut ct_res = sks.smart_add(ctxt_0, &mut rest[0]);
+        ct_res = sks.smart_add(&mut ct_res, &mut rest[1]);
+        let clear = clears.iter().sum::<u64>();
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear % modulus, dec_res);
+    }
+}
+
+fn shortint_switch_pbs_order(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // small to big, with a non empty carry
+        let mut ctxt_small = cks.encrypt_small(clear_0);
+        sks.unchecked_scalar_add_assign(&mut ctxt_small, clear_1 as u8);
+        let ctxt_big: CiphertextBig = sks.switch_pbs_order(&ctxt_small);
+        assert_eq!(ctxt_big.degree, ctxt_small.degree);
+        assert_eq!(cks.decrypt_message_and_carry(&ctxt_big), clear_0 + clear_1);
+
+        // big to small
+        let ctxt_small: CiphertextSmall = sks.switch_pbs_order(&ctxt_big);
+        assert_eq!(
+            cks.decrypt_message_and_carry(&ctxt_small),
+            clear_0 + clear_1
+        );
+
+        // same order
+        let ctxt_copy: CiphertextBig = sks.switch_pbs_order(&ctxt_big);
+        assert_eq!(ctxt_copy.ct, ctxt_big.ct);
+    }
+}
+
+fn shortint_degree_audit(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let full_modulus = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        assert_eq!(cks.audit_degree(&ctxt_0), Ok(()));
+
+        let mut ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        assert_eq!(cks.audit_degree(&ct_res), Ok(()));
+
+        // The exact value is a valid degree
+        let sum = clear_0 + clear_1;
+        ct_res.assume_degree(Degree(sum as usize));
+        assert_eq!(cks.audit_degree(&ct_res), Ok(()));
+
+        if sum > 0 {
+            ct_res.assume_degree(Degree(sum as usize - 1));
+            assert_eq!(
+                cks.audit_degree(&ct_res),
+                Err(DegreeAuditError {
+                    degree: Degree(sum as usize - 1),
+                    value: sum,
+                })
+            );
+        }
+
+        ct_res.set_degree(Degree(full_modulus as usize - 1));
+        assert_eq!(cks.audit_degree(&ct_res), Ok(()));
+        assert_eq!(cks.decrypt(&ct_res), sum % modulus);
+
+        let acc = sks.generate_accumulator(|x| x % modulus);
+        let ct_res = sks.apply_lookup_table(&ct_res, &acc);
+        assert_eq!(cks.audit_degree(&ct_res), Ok(()));
+    }
+}
+
+fn shortint_max_chaining(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let clear = modulus - 1;
+    let ctxt = cks.encrypt(clear);
+
+    // Additions
+    let num_additions = sks.max_noise_free_additions();
+    let mut ct_res = ctxt.clone();
+    for _ in 0..num_additions {
+        assert!(sks.is_add_possible(&ct_res, &ctxt));
+        sks.unchecked_add_assign(&mut ct_res, &ctxt);
+    }
+    assert!(!sks.is_add_possible(&ct_res, &ctxt));
+    assert_eq!(
+        cks.decrypt_message_and_carry(&ct_res),
+        clear * (num_additions as u64 + 1)
+    );
+
+    // Scalar multiplications
+    for scalar in 2..=modulus as u8 {
+        let num_muls = sks.max_scalar_mul(scalar);
+        let mut ct_res = ctxt.clone();
+        let mut expected = clear;
+        for _ in 0..num_muls {
+            assert!(sks.is_scalar_mul_possible(&ct_res, scalar));
+            sks.unchecked_scalar_mul_assign(&mut ct_res, scalar);
+            expected *= scalar as u64;
+        }
+        assert!(!sks.is_scalar_mul_possible(&ct_res, scalar));
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+    }
+
+    assert_eq!(sks.max_scalar_mul(0), usize::MAX);
+    assert_eq!(sks.max_scalar_mul(1), usize::MAX);
+}
@@ -0,0 +1,476 @@
+//! Recording and replay of the operations of a circuit.
+//!
+//! A [`TracingServerKey`] wraps a [`ServerKey`] and records every operation it computes in a
+//! [`Trace`]: the operation with its clear parameters, and the ids of its operands and of its
+//! result. The trace is serializable and can be replayed deterministically, on real ciphertexts
+//! with a [`ServerKey`] or on clear values with a
+//! [`SimulatedServerKey`](crate::simulator::SimulatedServerKey), to inspect the intermediate
+//! values of a circuit or find the operation where its degree or noise goes wrong.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::server_key::trace::TracingServerKey;
+//! use tfhe::simulator::SimulatedServerKey;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let tracing_sks = TracingServerKey::new(&sks);
+//!
+//! let ct_1 = tracing_sks.input(cks.encrypt(3));
+//! let ct_2 = tracing_sks.input(cks.encrypt(2));
+//! let ct_3 = tracing_sks.unchecked_add(&ct_1, &ct_2);
+//! let ct_res = tracing_sks.message_extract(&ct_3);
+//! assert_eq!(cks.decrypt(&ct_res.ct), 1);
+//!
+//! let trace = tracing_sks.into_trace();
+//! assert_eq!(trace.entries().len(), 4);
+//!
+//! // Replay the circuit on clear values to look at the intermediate results
+//! let sim = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+//! let values = trace.replay(&sim, [sim.encrypt(3), sim.encrypt(2)]);
+//! assert_eq!(values[ct_3.id()].value, 5);
+//! assert_eq!(values[ct_res.id()].value, 1);
+//! ```
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::MessageModulus;
+use crate::shortint::server_key::{BivariateLookupTable, LookupTableOwned};
+use crate::shortint::{CiphertextBase, PBSOrderMarker, ServerKey};
+use crate::simulator::{SimulatedCiphertext, SimulatedServerKey};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// An operation recorded in a [`Trace`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TracedOp {
+    /// A ciphertext given to the circuit, it has no operands.
+    Input,
+    UncheckedAdd,
+    UncheckedSub,
+    UncheckedNeg,
+    UncheckedScalarAdd {
+        scalar: u8,
+    },
+    UncheckedScalarMul {
+        scalar: u8,
+    },
+    MessageExtract,
+    CarryExtract,
+    /// A programmable bootstrapping, `table[x]` being the output for the input `x`.
+    LookupTable {
+        table: Vec<u64>,
+        degree: usize,
+    },
+    /// A programmable bootstrapping of `lhs * ct_right_modulus + rhs`, `table[x]` being the output
+    /// for the packed input `x`.
+    BivariateLookupTable {
+        table: Vec<u64>,
+        degree: usize,
+        ct_right_modulus: usize,
+    },
+}
+
+impl TracedOp {
+    /// Number of operands of the operation.
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::Input => 0,
+            Self::UncheckedNeg
+            | Self::UncheckedScalarAdd { .. }
+            | Self::UncheckedScalarMul { .. }
+            | Self::MessageExtract
+            | Self::CarryExtract
+            | Self::LookupTable { .. } => 1,
+            Self::UncheckedAdd | Self::UncheckedSub | Self::BivariateLookupTable { .. } => 2,
+        }
+    }
+}
+
+/// An operation of a [`Trace`] with the ids of its operands and of its result.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    pub op: TracedOp,
+    pub operands: Vec<usize>,
+    pub output: usize,
+}
+
+/// The operations of a circuit, in the order they were computed.
+///
+/// The ids of the values are their indices in the order they were created, so the result of the
+/// `i`-th entry has the id `i`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trace {
+    entries: Vec<TraceEntry>,
+}
+
+impl Trace {
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Number of inputs of the circuit.
+    pub fn num_inputs(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.op == TracedOp::Input)
+            .count()
+    }
+
+    fn push(&mut self, op: TracedOp, operands: Vec<usize>) -> usize {
+        let output = self.entries.len();
+        self.entries.push(TraceEntry {
+            op,
+            operands,
+            output,
+        });
+        output
+    }
+
+    /// Execute the operations of the trace with `backend`, the inputs of the circuit being taken
+    /// from `inputs` in the order they were given.
+    ///
+    /// Return all the values computed, indexed by id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` does not have [`num_inputs`](Self::num_inputs) items, or if the trace
+    /// is malformed.
+    pub fn replay<Ciphertext, Backend>(
+        &self,
+        backend: &Backend,
+        inputs: impl IntoIterator<Item = Ciphertext>,
+    ) -> Vec<Ciphertext>
+    where
+        Backend: TraceBackend<Ciphertext>,
+    {
+        let mut inputs = inputs.into_iter();
+        let mut values = Vec::with_capacity(self.entries.len());
+
+        for entry in &self.entries {
+            assert_eq!(entry.output, values.len(), "Malformed trace");
+            assert_eq!(entry.operands.len(), entry.op.arity(), "Malformed trace");
+
+            let value = if entry.op == TracedOp::Input {
+                inputs
+                    .next()
+                    .expect("Not enough inputs to replay the trace")
+            } else {
+                let operands = entry
+                    .operands
+                    .iter()
+                    .map(|&id| &values[id])
+                    .collect::<Vec<_>>();
+                backend.execute(&entry.op, &operands)
+            };
+            values.push(value);
+        }
+
+        assert!(
+            inputs.next().is_none(),
+            "Too many inputs to replay the trace"
+        );
+
+        values
+    }
+}
+
+/// Something that can execute the operations of a [`Trace`].
+pub trait TraceBackend<Ciphertext> {
+    /// Execute `op` on `operands`, which has [`TracedOp::arity`] items. `op` is never
+    /// [`TracedOp::Input`].
+    fn execute(&self, op: &TracedOp, operands: &[&Ciphertext]) -> Ciphertext;
+}
+
+impl<OpOrder: PBSOrderMarker> TraceBackend<CiphertextBase<OpOrder>> for ServerKey {
+    fn execute(
+        &self,
+        op: &TracedOp,
+        operands: &[&CiphertextBase<OpOrder>],
+    ) -> CiphertextBase<OpOrder> {
+        match op {
+            TracedOp::Input => unreachable!("Inputs are not executed"),
+            TracedOp::UncheckedAdd => self.unchecked_add(operands[0], operands[1]),
+            TracedOp::UncheckedSub => self.unchecked_sub(operands[0], operands[1]),
+            TracedOp::UncheckedNeg => self.unchecked_neg(operands[0]),
+            TracedOp::UncheckedScalarAdd { scalar } => {
+                self.unchecked_scalar_add(operands[0], *scalar)
+            }
+            TracedOp::UncheckedScalarMul { scalar } => {
+                self.unchecked_scalar_mul(operands[0], *scalar)
+            }
+            TracedOp::MessageExtract => self.message_extract(operands[0]),
+            TracedOp::CarryExtract => self.carry_extract(operands[0]),
+            TracedOp::LookupTable { table, degree } => {
+                let acc = self.lookup_table_from_trace(table, *degree);
+                self.apply_lookup_table(operands[0], &acc)
+            }
+            TracedOp::BivariateLookupTable {
+                table,
+                degree,
+                ct_right_modulus,
+            } => {
+                let acc = BivariateLookupTable {
+                    acc: self.lookup_table_from_trace(table, *degree),
+                    ct_right_modulus: MessageModulus(*ct_right_modulus),
+                };
+                self.unchecked_apply_lookup_table_bivariate(operands[0], operands[1], &acc)
+            }
+        }
+    }
+}
+
+impl ServerKey {
+    fn lookup_table_from_trace(&self, table: &[u64], degree: usize) -> LookupTableOwned {
+        self.generate_accumulator(|x| table[x as usize])
+            .with_degree(Degree(degree))
+    }
+}
+
+impl TraceBackend<SimulatedCiphertext> for SimulatedServerKey {
+    fn execute(&self, op: &TracedOp, operands: &[&SimulatedCiphertext]) -> SimulatedCiphertext {
+        match op {
+            TracedOp::Input => unreachable!("Inputs are not executed"),
+            TracedOp::UncheckedAdd => self.unchecked_add(operands[0], operands[1]),
+            TracedOp::UncheckedSub => self.unchecked_sub(operands[0], operands[1]),
+            TracedOp::UncheckedNeg => self.unchecked_neg(operands[0]),
+            TracedOp::UncheckedScalarAdd { scalar } => {
+                self.unchecked_scalar_add(operands[0], *scalar)
+            }
+            TracedOp::UncheckedScalarMul { scalar } => {
+                self.unchecked_scalar_mul(operands[0], *scalar)
+            }
+            TracedOp::MessageExtract => self.message_extract(operands[0]),
+            TracedOp::CarryExtract => self.carry_extract(operands[0]),
+            TracedOp::LookupTable { table, degree } => {
+                let mut result = self.apply_lookup_table(operands[0], |x| table[x as usize]);
+                result.degree = Degree(*degree);
+                result
+            }
+            TracedOp::BivariateLookupTable {
+                table,
+                degree,
+                ct_right_modulus,
+            } => {
+                let shifted = self.unchecked_scalar_mul(operands[0], *ct_right_modulus as u8);
+                let packed = self.unchecked_add(&shifted, operands[1]);
+                let mut result = self.apply_lookup_table(&packed, |x| table[x as usize]);
+                result.degree = Degree(*degree);
+                result
+            }
+        }
+    }
+}
+
+/// A ciphertext computed by a [`TracingServerKey`], with its id in the [`Trace`].
+#[derive(Clone)]
+pub struct TracedCiphertext<OpOrder: PBSOrderMarker> {
+    id: usize,
+    pub ct: CiphertextBase<OpOrder>,
+}
+
+impl<OpOrder: PBSOrderMarker> TracedCiphertext<OpOrder> {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn into_inner(self) -> CiphertextBase<OpOrder> {
+        self.ct
+    }
+}
+
+/// A [`ServerKey`] recording the operations it computes, see the [module documentation](self).
+pub struct TracingServerKey<'key> {
+    server_key: &'key ServerKey,
+    trace: Mutex<Trace>,
+}
+
+impl<'key> TracingServerKey<'key> {
+    pub fn new(server_key: &'key ServerKey) -> Self {
+        Self {
+            server_key,
+            trace: Mutex::new(Trace::default()),
+        }
+    }
+
+    pub fn server_key(&self) -> &'key ServerKey {
+        self.server_key
+    }
+
+    /// Return a copy of the operations recorded so far.
+    pub fn trace(&self) -> Trace {
+        self.trace.lock().unwrap().clone()
+    }
+
+    pub fn into_trace(self) -> Trace {
+        self.trace.into_inner().unwrap()
+    }
+
+    fn record<OpOrder: PBSOrderMarker>(
+        &self,
+        op: TracedOp,
+        operands: &[&TracedCiphertext<OpOrder>],
+        ct: CiphertextBase<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let operands = operands.iter().map(|operand| operand.id).collect();
+        let id = self.trace.lock().unwrap().push(op, operands);
+        TracedCiphertext { id, ct }
+    }
+
+    /// Register a ciphertext given to the circuit.
+    pub fn input<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: CiphertextBase<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        self.record(TracedOp::Input, &[], ct)
+    }
+
+    pub fn unchecked_add<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &TracedCiphertext<OpOrder>,
+        ct_right: &TracedCiphertext<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let ct = self.server_key.unchecked_add(&ct_left.ct, &ct_right.ct);
+        self.record(TracedOp::UncheckedAdd, &[ct_left, ct_right], ct)
+    }
+
+    pub fn unchecked_sub<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &TracedCiphertext<OpOrder>,
+        ct_right: &TracedCiphertext<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let ct = self.server_key.unchecked_sub(&ct_left.ct, &ct_right.ct);
+        self.record(TracedOp::UncheckedSub, &[ct_left, ct_right], ct)
+    }
+
+    pub fn unchecked_neg<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.unchecked_neg(&ct.ct);
+        self.record(TracedOp::UncheckedNeg, &[ct], result)
+    }
+
+    pub fn unchecked_scalar_add<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+        scalar: u8,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.unchecked_scalar_add(&ct.ct, scalar);
+        self.record(TracedOp::UncheckedScalarAdd { scalar }, &[ct], result)
+    }
+
+    pub fn unchecked_scalar_mul<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+        scalar: u8,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.unchecked_scalar_mul(&ct.ct, scalar);
+        self.record(TracedOp::UncheckedScalarMul { scalar }, &[ct], result)
+    }
+
+    pub fn message_extract<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.message_extract(&ct.ct);
+        self.record(TracedOp::MessageExtract, &[ct], result)
+    }
+
+    pub fn carry_extract<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.carry_extract(&ct.ct);
+        self.record(TracedOp::CarryExtract, &[ct], result)
+    }
+
+    /// Apply a lookup table, the table of values of the accumulator being recorded so that the
+    /// trace does not depend on the key.
+    pub fn apply_lookup_table<OpOrder: PBSOrderMarker>(
+        &self,
+        ct: &TracedCiphertext<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> TracedCiphertext<OpOrder> {
+        let result = self.server_key.apply_lookup_table(&ct.ct, acc);
+        let op = TracedOp::LookupTable {
+            table: self.server_key.lookup_table_values(acc),
+            degree: acc.degree.0,
+        };
+        self.record(op, &[ct], result)
+    }
+
+    pub fn unchecked_apply_lookup_table_bivariate<OpOrder: PBSOrderMarker>(
+        &self,
+        ct_left: &TracedCiphertext<OpOrder>,
+        ct_right: &TracedCiphertext<OpOrder>,
+        acc: &BivariateLookupTable<Vec<u64>>,
+    ) -> TracedCiphertext<OpOrder> {
+        let result =
+            self.server_key
+                .unchecked_apply_lookup_table_bivariate(&ct_left.ct, &ct_right.ct, acc);
+        let op = TracedOp::BivariateLookupTable {
+            table: self.server_key.lookup_table_values(&acc.acc),
+            degree: acc.acc.degree.0,
+            ct_right_modulus: acc.ct_right_modulus.0,
+        };
+        self.record(op, &[ct_left, ct_right], result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_trace_replay() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let tracing_sks = TracingServerKey::new(sks);
+
+        let clear_inputs = [3, 1, 2];
+        let inputs = clear_inputs.map(|clear| tracing_sks.input(cks.encrypt(clear)));
+
+        let ct_sub = tracing_sks.unchecked_sub(&inputs[0], &inputs[1]);
+        let ct_mul = tracing_sks.unchecked_scalar_mul(&inputs[2], 2);
+        let ct_add = tracing_sks.unchecked_add(&ct_sub, &inputs[1]);
+        let ct_add = tracing_sks.unchecked_scalar_add(&ct_add, 1);
+        let ct_carry = tracing_sks.carry_extract(&ct_add);
+        let ct_msg = tracing_sks.message_extract(&ct_mul);
+        let ct_neg = tracing_sks.unchecked_neg(&ct_msg);
+        let acc = sks.generate_accumulator(|x| (x * x) % 4);
+        let ct_square = tracing_sks.apply_lookup_table(&ct_neg, &acc);
+        let acc = sks.generate_accumulator_bivariate(|x, y| x.max(y));
+        let ct_max =
+            tracing_sks.unchecked_apply_lookup_table_bivariate(&ct_square, &ct_carry, &acc);
+
+        let trace = tracing_sks.into_trace();
+        assert_eq!(trace.num_inputs(), 3);
+        assert_eq!(trace.entries().len(), ct_max.id() + 1);
+
+        let serialized = bincode::serialize(&trace).unwrap();
+        let trace: Trace = bincode::deserialize(&serialized).unwrap();
+
+        let replayed = trace.replay(sks, clear_inputs.map(|clear| cks.encrypt(clear)));
+        assert_eq!(replayed.len(), trace.entries().len());
+        assert_eq!(cks.decrypt(&replayed[ct_max.id()]), cks.decrypt(&ct_max.ct));
+        assert_eq!(replayed[ct_max.id()].degree, ct_max.ct.degree);
+
+        let sim = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+        let simulated = trace.replay(&sim, clear_inputs.map(|clear| sim.encrypt(clear)));
+        for ct in [
+            &ct_sub, &ct_mul, &ct_add, &ct_carry, &ct_msg, &ct_neg, &ct_square, &ct_max,
+        ] {
+            let value = &simulated[ct.id()];
+            assert_eq!(value.degree, ct.ct.degree);
+            assert_eq!(
+                sim.decrypt_message_and_carry(value),
+                cks.decrypt_message_and_carry(&ct.ct)
+            );
+        }
+        assert_eq!(sim.report().pbs_count, 4);
+        assert_eq!(sim.report().carry_overflows, 0);
+    }
+}
@@ -0,0 +1,273 @@
+use super::{LookupTableOwned, ServerKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::vector::ShortintVector;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::{PBSOrder, PBSOrderMarker};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Apply the same lookup table to all the ciphertexts of a vector.
+    ///
+    /// The keyswitches are computed in parallel and the bootstraps are computed in a single
+    /// batch with [`BootstrapBackend::programmable_bootstrap_batch`], backends supporting
+    /// parallel batches compute them in parallel.
+    ///
+    /// [`BootstrapBackend::programmable_bootstrap_batch`]: super::backend::BootstrapBackend::programmable_bootstrap_batch
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::vector::ShortintVector;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = [0, 1, 2, 3];
+    /// let mut vector = ShortintVector::from_ciphertexts(&msg.map(|m| cks.encrypt(m)));
+    ///
+    /// let acc = sks.generate_accumulator(|x| 3 - x % 4);
+    /// sks.vector_apply_lookup_table_assign(&mut vector, &acc);
+    ///
+    /// for (m, ct) in msg.iter().zip(vector.to_ciphertexts()) {
+    ///     assert_eq!(cks.decrypt(&ct), 3 - m);
+    /// }
+    /// ```
+    pub fn vector_apply_lookup_table_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        vector: &mut ShortintVector<OpOrder>,
+        acc: &LookupTableOwned,
+    ) {
+        let count = vector.cts.lwe_ciphertext_count();
+        let small_lwe_size = self.bootstrapping_key.input_lwe_dimension().to_lwe_size();
+        let big_lwe_size = self.bootstrapping_key.output_lwe_dimension().to_lwe_size();
+
+        crate::metrics::record_keyswitch(count.0);
+        crate::metrics::record_pbs(count.0);
+
+        match OpOrder::pbs_order() {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut small_cts =
+                    LweCiphertextList::new(0u64, small_lwe_size, count, self.ciphertext_modulus);
+
+                vector
+                    .cts
+                    .par_iter()
+                    .zip(small_cts.par_iter_mut())
+                    .for_each(|(ct, mut small_ct)| {
+                        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct, &mut small_ct);
+                    });
+
+                self.bootstrap_backend().programmable_bootstrap_batch(
+                    &self.bootstrapping_key,
+                    small_cts.as_view(),
+                    vector.cts.as_mut_view(),
+                    acc.acc.as_view(),
+                );
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                let mut big_cts =
+                    LweCiphertextList::new(0u64, big_lwe_size, count, self.ciphertext_modulus);
+
+                self.bootstrap_backend().programmable_bootstrap_batch(
+                    &self.bootstrapping_key,
+                    vector.cts.as_view(),
+                    big_cts.as_mut_view(),
+                    acc.acc.as_view(),
+                );
+
+                big_cts
+                    .par_iter()
+                    .zip(vector.cts.par_iter_mut())
+                    .for_each(|(big_ct, mut ct)| {
+                        keyswitch_lwe_ciphertext(&self.key_switching_key, &big_ct, &mut ct);
+                    });
+            }
+        }
+
+        vector.degree = acc.degree;
+    }
+
+    /// Apply the same lookup table to all the ciphertexts of a vector.
+    ///
+    /// The result is returned in a _new_ vector, see
+    /// [`ServerKey::vector_apply_lookup_table_assign`].
+    pub fn vector_apply_lookup_table<OpOrder: PBSOrderMarker>(
+        &self,
+        vector: &ShortintVector<OpOrder>,
+        acc: &LookupTableOwned,
+    ) -> ShortintVector<OpOrder> {
+        let mut result = vector.clone();
+        self.vector_apply_lookup_table_assign(&mut result, acc);
+        result
+    }
+
+    /// Clear the carries of all the ciphertexts of a vector.
+    pub fn vector_message_extract_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        vector: &mut ShortintVector<OpOrder>,
+    ) {
+        let modulus = self.message_modulus.0 as u64;
+        let acc = self.generate_accumulator(|x| x % modulus);
+        self.vector_apply_lookup_table_assign(vector, &acc);
+    }
+
+    /// Compute homomorphically the element-wise addition of two vectors.
+    ///
+    /// The result is assigned to `lhs`.
+    ///
+    /// This function computes the additions without checking if they exceed the capacity of the
+    /// ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length or parameters.
+    pub fn unchecked_vector_add_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut ShortintVector<OpOrder>,
+        rhs: &ShortintVector<OpOrder>,
+    ) {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "The vectors must have the same length"
+        );
+        assert!(
+            lhs.message_modulus == rhs.message_modulus
+                && lhs.carry_modulus == rhs.carry_modulus
+                && lhs.lwe_size() == rhs.lwe_size(),
+            "The vectors must have the same parameters"
+        );
+
+        lhs.cts
+            .par_iter_mut()
+            .zip(rhs.cts.par_iter())
+            .for_each(|(mut lhs_ct, rhs_ct)| lwe_ciphertext_add_assign(&mut lhs_ct, &rhs_ct));
+
+        lhs.degree = Degree(lhs.degree.0 + rhs.degree.0);
+    }
+
+    /// Compute homomorphically the element-wise addition of two vectors.
+    ///
+    /// The result is returned in a _new_ vector.
+    ///
+    /// This function computes the additions without checking if they exceed the capacity of the
+    /// ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length or parameters.
+    pub fn unchecked_vector_add<OpOrder: PBSOrderMarker>(
+        &self,
+        lhs: &ShortintVector<OpOrder>,
+        rhs: &ShortintVector<OpOrder>,
+    ) -> ShortintVector<OpOrder> {
+        let mut result = lhs.clone();
+        self.unchecked_vector_add_assign(&mut result, rhs);
+        result
+    }
+
+    /// Compute homomorphically the element-wise addition of two vectors.
+    ///
+    /// The result is assigned to `lhs`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input vectors carries are empty and clears them if it's not the case. It
+    /// outputs a vector whose carries are always empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length or parameters.
+    pub fn vector_add_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        lhs: &mut ShortintVector<OpOrder>,
+        rhs: &ShortintVector<OpOrder>,
+    ) {
+        let mut tmp_rhs: ShortintVector<OpOrder>;
+
+        if !lhs.carry_is_empty() {
+            self.vector_message_extract_assign(lhs);
+        }
+
+        let rhs = if rhs.carry_is_empty() {
+            rhs
+        } else {
+            tmp_rhs = rhs.clone();
+            self.vector_message_extract_assign(&mut tmp_rhs);
+            &tmp_rhs
+        };
+
+        self.unchecked_vector_add_assign(lhs, rhs);
+        self.vector_message_extract_assign(lhs);
+    }
+
+    /// Compute homomorphically the element-wise addition of two vectors.
+    ///
+    /// The result is returned in a _new_ vector, see [`ServerKey::vector_add_assign`].
+    pub fn vector_add<OpOrder: PBSOrderMarker>(
+        &self,
+        lhs: &ShortintVector<OpOrder>,
+        rhs: &ShortintVector<OpOrder>,
+    ) -> ShortintVector<OpOrder> {
+        let mut result = lhs.clone();
+        self.vector_add_assign(&mut result, rhs);
+        result
+    }
+
+    /// Compute homomorphically the multiplication of all the ciphertexts of a vector by a
+    /// scalar.
+    ///
+    /// This function computes the multiplications without checking if they exceed the capacity
+    /// of the ciphertexts.
+    pub fn unchecked_vector_scalar_mul_assign<OpOrder: PBSOrderMarker>(
+        &self,
+        vector: &mut ShortintVector<OpOrder>,
+        scalar: u8,
+    ) {
+        let cleartext = Cleartext(u64::from(scalar));
+        vector
+            .cts
+            .par_iter_mut()
+            .for_each(|mut ct| lwe_ciphertext_cleartext_mul_assign(&mut ct, cleartext));
+
+        vector.degree = Degree(vector.degree.0 * scalar as usize);
+    }
+
+    /// Compute homomorphically the multiplication of all the ciphertexts of a vector by a
+    /// scalar.
+    ///
+    /// The result is returned in a _new_ vector, computed with a single lookup table applied to
+    /// the whole vector. Its carries are always empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::vector::ShortintVector;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = [0, 1, 2, 3];
+    /// let vector = ShortintVector::from_ciphertexts(&msg.map(|m| cks.encrypt(m)));
+    ///
+    /// let res = sks.vector_scalar_mul(&vector, 3);
+    ///
+    /// for (m, ct) in msg.iter().zip(res.to_ciphertexts()) {
+    ///     assert_eq!(cks.decrypt(&ct), (m * 3) % 4);
+    /// }
+    /// ```
+    pub fn vector_scalar_mul<OpOrder: PBSOrderMarker>(
+        &self,
+        vector: &ShortintVector<OpOrder>,
+        scalar: u8,
+    ) -> ShortintVector<OpOrder> {
+        let modulus = self.message_modulus.0 as u64;
+        let acc = self.generate_accumulator(|x| (scalar as u64 * x) % modulus);
+        self.vector_apply_lookup_table(vector, &acc)
+    }
+}
@@ -0,0 +1,140 @@
+//! Module with helpers to write property based tests on top of shortint.
+//!
+//! This module is only available when the `test-utils` feature is enabled, it exposes the random
+//! generators used internally by the shortint test suite as [`proptest`] strategies, so that crates
+//! building on top of shortint can check their own homomorphic properties without duplicating them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::shortint::test_utils::{clear_value_strategy, gen_keys_for_tests};
+//!
+//! let param = PARAM_MESSAGE_2_CARRY_2;
+//! let (cks, sks) = gen_keys_for_tests(param);
+//! let modulus = param.message_modulus.0 as u64;
+//!
+//! proptest!(ProptestConfig::with_cases(4), |(
+//!     clear_0 in clear_value_strategy(param),
+//!     clear_1 in clear_value_strategy(param),
+//! )| {
+//!     let ct_0 = cks.encrypt(clear_0);
+//!     let ct_1 = cks.encrypt(clear_1);
+//!
+//!     let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+//!
+//!     prop_assert_eq!(cks.decrypt(&ct_res), (clear_0 + clear_1) % modulus);
+//! });
+//! ```
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{
+    Parameters, ALL_PARAMETER_VEC, BIVARIATE_PBS_COMPLIANT_PARAMETER_SET_VEC,
+};
+use crate::shortint::{CiphertextBig, ClientKey, ServerKey};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// Strategy yielding one of the parameter sets of
+/// [`ALL_PARAMETER_VEC`](crate::shortint::parameters::ALL_PARAMETER_VEC).
+pub fn parameters_strategy() -> impl Strategy<Value = Parameters> {
+    select(ALL_PARAMETER_VEC.to_vec())
+}
+
+/// Strategy yielding one of the parameter sets compatible with the bivariate PBS.
+pub fn bivariate_pbs_compliant_parameters_strategy() -> impl Strategy<Value = Parameters> {
+    select(BIVARIATE_PBS_COMPLIANT_PARAMETER_SET_VEC.to_vec())
+}
+
+/// Strategy yielding a clear value fitting in the message space of the given parameters.
+pub fn clear_value_strategy(parameters: Parameters) -> impl Strategy<Value = u64> {
+    0..parameters.message_modulus.0 as u64
+}
+
+/// Strategy yielding a clear value fitting in the message and carry spaces of the given
+/// parameters.
+pub fn clear_value_with_carry_strategy(parameters: Parameters) -> impl Strategy<Value = u64> {
+    0..(parameters.message_modulus.0 * parameters.carry_modulus.0) as u64
+}
+
+/// Strategy yielding a [`Degree`] that can be reached by a ciphertext encrypted under the given
+/// parameters.
+pub fn degree_strategy(parameters: Parameters) -> impl Strategy<Value = Degree> {
+    (0..parameters.message_modulus.0 * parameters.carry_modulus.0).prop_map(Degree)
+}
+
+/// Strategy yielding a clear value together with a [`Degree`] greater or equal to it, i.e. a pair
+/// that can be used with [`encrypt_with_degree`].
+pub fn clear_value_and_degree_strategy(
+    parameters: Parameters,
+) -> impl Strategy<Value = (u64, Degree)> {
+    let modulus_sup = parameters.message_modulus.0 * parameters.carry_modulus.0;
+    (0..modulus_sup).prop_flat_map(move |degree| (0..=degree as u64, Just(Degree(degree))))
+}
+
+/// Generate a couple of client and server keys for the given parameters.
+///
+/// When the `internal-keycache` feature is enabled keys are fetched from the key cache, otherwise
+/// they are generated on each call.
+pub fn gen_keys_for_tests(parameters: Parameters) -> (ClientKey, ServerKey) {
+    #[cfg(feature = "internal-keycache")]
+    {
+        let keys = crate::shortint::keycache::KEY_CACHE.get_from_param(parameters);
+        (keys.client_key().clone(), keys.server_key().clone())
+    }
+    #[cfg(not(feature = "internal-keycache"))]
+    {
+        crate::shortint::gen_keys(parameters)
+    }
+}
+
+/// Encrypt `message` without reducing it and set the degree of the resulting ciphertext.
+///
+/// This allows to build ciphertexts in the states reached after some unchecked operations.
+///
+/// # Panics
+///
+/// Panics if the `degree` is smaller than the `message` or does not fit in the message and carry
+/// spaces.
+pub fn encrypt_with_degree(cks: &ClientKey, message: u64, degree: Degree) -> CiphertextBig {
+    let modulus_sup = cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0;
+    assert!(
+        message as usize <= degree.0,
+        "message {message} does not fit in degree {}",
+        degree.0
+    );
+    assert!(
+        degree.0 < modulus_sup,
+        "degree {} does not fit in the message and carry spaces ({modulus_sup})",
+        degree.0
+    );
+
+    let mut ct = cks.unchecked_encrypt(message);
+    ct.degree = degree;
+    ct
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_clear_value_and_degree_are_consistent(
+            (param, (clear, degree)) in parameters_strategy()
+                .prop_flat_map(|param| (Just(param), clear_value_and_degree_strategy(param)))
+        ) {
+            let modulus_sup = param.message_modulus.0 * param.carry_modulus.0;
+            prop_assert!(clear as usize <= degree.0);
+            prop_assert!(degree.0 < modulus_sup);
+        }
+
+        #[test]
+        fn test_clear_value_fits_in_message_space(
+            (param, clear) in parameters_strategy()
+                .prop_flat_map(|param| (Just(param), clear_value_strategy(param)))
+        ) {
+            prop_assert!((clear as usize) < param.message_modulus.0);
+        }
+    }
+}
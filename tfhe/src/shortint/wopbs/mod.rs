@@ -28,6 +28,13 @@ pub struct WopbsKey {
     pub param: Parameters,
 }
 
+crate::size::impl_fhe_size_from_fields!(impl for WopbsKey {
+    wopbs_server_key,
+    pbs_server_key,
+    cbs_pfpksk,
+    ksk_pbs_to_wopbs,
+});
+
 impl WopbsKey {
     /// Generate the server key required to compute a WoPBS from the client and the server keys.
     ///
@@ -0,0 +1,425 @@
+//! Simulation of shortint and integer circuits on clear values.
+//!
+//! The [`SimulatedServerKey`] mirrors the API of the shortint
+//! [`ServerKey`](crate::shortint::ServerKey) but operates on clear values, tracking for each
+//! ciphertext the degree the real ciphertext would have, and its noise level as defined in
+//! [`metadata`](crate::shortint::parameters::metadata). Running a circuit on the simulator is
+//! instantaneous and tells whether it would overflow the carry space or exceed the noise budget of
+//! the parameters, and what its failure probability would be.
+//!
+//! The simulator does not model the failures themselves: once a problem is reported, the values
+//! it computes are those the circuit would compute if the problem had not occurred.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::simulator::SimulatedServerKey;
+//!
+//! let sks = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+//!
+//! let ct_1 = sks.encrypt(3);
+//! let ct_2 = sks.encrypt(2);
+//!
+//! // 3 * 3 + 2 fits in the carry space, but its noise level is 3^2 + 1 = 10 which exceeds the
+//! // noise budget of the parameters
+//! let ct_3 = sks.unchecked_scalar_mul(&ct_1, 3);
+//! let ct_4 = sks.unchecked_add(&ct_3, &ct_2);
+//! assert_eq!(ct_4.noise_level, 10);
+//!
+//! let ct_res = sks.message_extract(&ct_4);
+//! assert_eq!(sks.decrypt(&ct_res), (3 * 3 + 2) % 4);
+//!
+//! let report = sks.report();
+//! assert_eq!(report.pbs_count, 1);
+//! assert_eq!(report.noise_budget_violations, 1);
+//! assert!(report.failure_probability().is_none());
+//! ```
+#[cfg(feature = "integer")]
+mod radix;
+
+#[cfg(feature = "integer")]
+pub use radix::SimulatedRadixCiphertext;
+
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::registry::ParametersError;
+use crate::shortint::parameters::{Parameters, ParametersMetadata};
+use crate::shortint::server_key::MaxDegree;
+use std::sync::Mutex;
+
+/// A clear value standing for a shortint ciphertext.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimulatedCiphertext {
+    /// The value the ciphertext would decrypt to, message and carry included.
+    pub value: u64,
+    /// The degree the ciphertext would have.
+    pub degree: Degree,
+    /// The noise level of the ciphertext, 1 for a fresh or bootstrapped ciphertext, 0 for a
+    /// trivial one.
+    pub noise_level: u64,
+}
+
+/// Summary of the operations run on a [`SimulatedServerKey`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SimulationReport {
+    /// Number of programmable bootstrappings.
+    pub pbs_count: u64,
+    /// Largest noise level of a bootstrapped ciphertext.
+    pub max_noise_level: u64,
+    /// Number of bootstrapped ciphertexts whose noise level exceeded the noise budget.
+    pub noise_budget_violations: u64,
+    /// Number of operations whose result exceeded the carry space.
+    pub carry_overflows: u64,
+    // Sum of the log(1 - p_fail) of the bootstraps within the noise budget
+    log_success_probability: f64,
+}
+
+impl SimulationReport {
+    /// Probability that at least one bootstrap fails, assuming independent failures.
+    ///
+    /// `None` if the noise budget was exceeded, as the failure probability of a bootstrap is only
+    /// known within the budget.
+    pub fn failure_probability(&self) -> Option<f64> {
+        if self.noise_budget_violations > 0 {
+            None
+        } else {
+            Some(-self.log_success_probability.exp_m1())
+        }
+    }
+}
+
+/// A server key computing on [`SimulatedCiphertext`]s, see the [module documentation](self).
+pub struct SimulatedServerKey {
+    parameters: Parameters,
+    metadata: ParametersMetadata,
+    max_degree: MaxDegree,
+    report: Mutex<SimulationReport>,
+}
+
+impl SimulatedServerKey {
+    /// Create a simulator for one of the parameter sets provided by the library.
+    ///
+    /// Other parameter sets have no known noise budget, use
+    /// [`with_metadata`](Self::with_metadata) for them.
+    pub fn new(parameters: Parameters) -> Result<Self, ParametersError> {
+        let metadata = parameters
+            .metadata()
+            .ok_or(ParametersError::UnknownMetadata)?;
+        Ok(Self::with_metadata(parameters, metadata))
+    }
+
+    /// Create a simulator for parameters with the given design targets.
+    pub fn with_metadata(parameters: Parameters, metadata: ParametersMetadata) -> Self {
+        let max_degree = MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1);
+        Self {
+            parameters,
+            metadata,
+            max_degree,
+            report: Mutex::new(SimulationReport::default()),
+        }
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+
+    pub fn metadata(&self) -> ParametersMetadata {
+        self.metadata
+    }
+
+    /// Return the summary of the operations run since the creation of the key or the last call to
+    /// [`reset_report`](Self::reset_report).
+    pub fn report(&self) -> SimulationReport {
+        *self.report.lock().unwrap()
+    }
+
+    pub fn reset_report(&self) {
+        *self.report.lock().unwrap() = SimulationReport::default();
+    }
+
+    fn message_modulus(&self) -> u64 {
+        self.parameters.message_modulus.0 as u64
+    }
+
+    fn modulus_sup(&self) -> u64 {
+        self.message_modulus() * self.parameters.carry_modulus.0 as u64
+    }
+
+    fn check_degree(&self, ct: SimulatedCiphertext) -> SimulatedCiphertext {
+        if ct.degree.0 > self.max_degree.0 {
+            self.report.lock().unwrap().carry_overflows += 1;
+        }
+        ct
+    }
+
+    fn record_pbs(&self, noise_level: u64) {
+        let mut report = self.report.lock().unwrap();
+        report.pbs_count += 1;
+        report.max_noise_level = report.max_noise_level.max(noise_level);
+        if noise_level > self.metadata.max_noise_level {
+            report.noise_budget_violations += 1;
+        } else {
+            report.log_success_probability += (-self.metadata.p_fail()).ln_1p();
+        }
+    }
+
+    /// Simulate the encryption of `message`, which is reduced modulo the message modulus.
+    pub fn encrypt(&self, message: u64) -> SimulatedCiphertext {
+        SimulatedCiphertext {
+            value: message % self.message_modulus(),
+            degree: Degree(self.parameters.message_modulus.0 - 1),
+            noise_level: 1,
+        }
+    }
+
+    pub fn create_trivial(&self, value: u64) -> SimulatedCiphertext {
+        let value = value % self.modulus_sup();
+        SimulatedCiphertext {
+            value,
+            degree: Degree(value as usize),
+            noise_level: 0,
+        }
+    }
+
+    pub fn decrypt(&self, ct: &SimulatedCiphertext) -> u64 {
+        ct.value % self.message_modulus()
+    }
+
+    pub fn decrypt_message_and_carry(&self, ct: &SimulatedCiphertext) -> u64 {
+        ct.value % self.modulus_sup()
+    }
+
+    pub fn is_add_possible(
+        &self,
+        ct_left: &SimulatedCiphertext,
+        ct_right: &SimulatedCiphertext,
+    ) -> bool {
+        ct_left.degree.0 + ct_right.degree.0 <= self.max_degree.0
+    }
+
+    pub fn unchecked_add(
+        &self,
+        ct_left: &SimulatedCiphertext,
+        ct_right: &SimulatedCiphertext,
+    ) -> SimulatedCiphertext {
+        self.check_degree(SimulatedCiphertext {
+            value: ct_left.value + ct_right.value,
+            degree: Degree(ct_left.degree.0 + ct_right.degree.0),
+            noise_level: ct_left.noise_level + ct_right.noise_level,
+        })
+    }
+
+    /// Add the ciphertexts, clearing their carries first if needed as the shortint `smart_add`
+    /// does.
+    pub fn smart_add(
+        &self,
+        ct_left: &mut SimulatedCiphertext,
+        ct_right: &mut SimulatedCiphertext,
+    ) -> SimulatedCiphertext {
+        let mut result = *ct_left;
+        if !self.is_add_possible(&result, ct_right) {
+            let message_max = self.parameters.message_modulus.0 - 1;
+            if message_max + ct_right.degree.0 <= self.max_degree.0 {
+                result = self.message_extract(&result);
+            } else if message_max + result.degree.0 <= self.max_degree.0 {
+                *ct_right = self.message_extract(ct_right);
+            } else {
+                result = self.message_extract(&result);
+                *ct_right = self.message_extract(ct_right);
+            }
+        }
+        self.unchecked_add(&result, ct_right)
+    }
+
+    pub fn unchecked_scalar_add(
+        &self,
+        ct: &SimulatedCiphertext,
+        scalar: u8,
+    ) -> SimulatedCiphertext {
+        let scalar = scalar as u64;
+        self.check_degree(SimulatedCiphertext {
+            value: ct.value + scalar,
+            degree: Degree(ct.degree.0 + scalar as usize),
+            noise_level: ct.noise_level,
+        })
+    }
+
+    pub fn unchecked_scalar_mul(
+        &self,
+        ct: &SimulatedCiphertext,
+        scalar: u8,
+    ) -> SimulatedCiphertext {
+        let scalar = scalar as u64;
+        self.check_degree(SimulatedCiphertext {
+            value: ct.value * scalar,
+            degree: Degree(ct.degree.0 * scalar as usize),
+            noise_level: ct.noise_level * scalar * scalar,
+        })
+    }
+
+    /// Negate the ciphertext, adding the smallest multiple of the message modulus that keeps the
+    /// result positive, like the shortint `unchecked_neg`.
+    pub fn unchecked_neg(&self, ct: &SimulatedCiphertext) -> SimulatedCiphertext {
+        let z = self.neg_correcting_term(ct);
+        self.check_degree(SimulatedCiphertext {
+            value: z - ct.value,
+            degree: Degree(z as usize),
+            noise_level: ct.noise_level,
+        })
+    }
+
+    pub fn unchecked_sub(
+        &self,
+        ct_left: &SimulatedCiphertext,
+        ct_right: &SimulatedCiphertext,
+    ) -> SimulatedCiphertext {
+        let z = self.neg_correcting_term(ct_right);
+        self.check_degree(SimulatedCiphertext {
+            value: ct_left.value + z - ct_right.value,
+            degree: Degree(ct_left.degree.0 + z as usize),
+            noise_level: ct_left.noise_level + ct_right.noise_level,
+        })
+    }
+
+    // z = ceil(degree / message_modulus) * message_modulus
+    fn neg_correcting_term(&self, ct: &SimulatedCiphertext) -> u64 {
+        let message_modulus = self.message_modulus();
+        let z = (ct.degree.0 as u64 + message_modulus - 1) / message_modulus;
+        z * message_modulus
+    }
+
+    /// Simulate a programmable bootstrapping evaluating `f` on the message and carry of `ct`.
+    ///
+    /// The degree of the result is the maximum of `f` over all the values a ciphertext can hold,
+    /// as for a lookup table generated with
+    /// [`ServerKey::generate_accumulator`](crate::shortint::ServerKey::generate_accumulator).
+    pub fn apply_lookup_table<F>(&self, ct: &SimulatedCiphertext, f: F) -> SimulatedCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        self.record_pbs(ct.noise_level);
+
+        let modulus_sup = self.modulus_sup();
+        let max_value = (0..modulus_sup).map(&f).max().unwrap_or(0);
+        SimulatedCiphertext {
+            value: f(ct.value % modulus_sup),
+            degree: Degree(max_value as usize),
+            noise_level: 1,
+        }
+    }
+
+    /// Simulate a programmable bootstrapping evaluating the bivariate function `f` on the messages
+    /// of the ciphertexts.
+    pub fn unchecked_evaluate_bivariate_function<F>(
+        &self,
+        ct_left: &SimulatedCiphertext,
+        ct_right: &SimulatedCiphertext,
+        f: F,
+    ) -> SimulatedCiphertext
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let message_modulus = self.message_modulus();
+        // The ciphertexts are packed as ct_left * message_modulus + ct_right
+        let packed = self.check_degree(SimulatedCiphertext {
+            value: ct_left.value * message_modulus + ct_right.value,
+            degree: Degree(ct_left.degree.0 * message_modulus as usize + ct_right.degree.0),
+            noise_level: ct_left.noise_level * message_modulus * message_modulus
+                + ct_right.noise_level,
+        });
+
+        self.apply_lookup_table(&packed, |x| {
+            f((x / message_modulus) % message_modulus, x % message_modulus)
+        })
+    }
+
+    pub fn message_extract(&self, ct: &SimulatedCiphertext) -> SimulatedCiphertext {
+        let message_modulus = self.message_modulus();
+        self.apply_lookup_table(ct, |x| x % message_modulus)
+    }
+
+    pub fn carry_extract(&self, ct: &SimulatedCiphertext) -> SimulatedCiphertext {
+        let message_modulus = self.message_modulus();
+        self.apply_lookup_table(ct, |x| x / message_modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_simulator_matches_shortint() {
+        let sim = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        for msg_1 in 0..4 {
+            for msg_2 in 0..4 {
+                let (mut sim_1, mut sim_2) = (sim.encrypt(msg_1), sim.encrypt(msg_2));
+                let (mut ct_1, mut ct_2) = (cks.encrypt(msg_1), cks.encrypt(msg_2));
+
+                let sim_res = sim.unchecked_sub(&sim_1, &sim_2);
+                let ct_res = sks.unchecked_sub(&ct_1, &ct_2);
+                assert_eq!(sim_res.degree, ct_res.degree);
+                assert_eq!(sim.decrypt(&sim_res), cks.decrypt(&ct_res));
+
+                let sim_res = sim.unchecked_neg(&sim_1);
+                let ct_res = sks.unchecked_neg(&ct_1);
+                assert_eq!(sim_res.degree, ct_res.degree);
+                assert_eq!(sim.decrypt(&sim_res), cks.decrypt(&ct_res));
+
+                let sim_res = sim.unchecked_scalar_mul(&sim_1, 3);
+                let ct_res = sks.unchecked_scalar_mul(&ct_1, 3);
+                assert_eq!(sim_res.degree, ct_res.degree);
+                assert_eq!(
+                    sim.decrypt_message_and_carry(&sim_res),
+                    cks.decrypt_message_and_carry(&ct_res)
+                );
+
+                let sim_res =
+                    sim.unchecked_evaluate_bivariate_function(&sim_1, &sim_2, |x, y| x * y);
+                let ct_res = sks.unchecked_evaluate_bivariate_function(&ct_1, &ct_2, |x, y| x * y);
+                assert_eq!(sim_res.degree, ct_res.degree);
+                assert_eq!(
+                    sim.decrypt_message_and_carry(&sim_res),
+                    cks.decrypt_message_and_carry(&ct_res)
+                );
+
+                let sim_res = sim.smart_add(&mut sim_1, &mut sim_2);
+                let ct_res = sks.smart_add(&mut ct_1, &mut ct_2);
+                assert_eq!(sim_res.degree, ct_res.degree);
+                assert_eq!(sim.decrypt(&sim_res), cks.decrypt(&ct_res));
+            }
+        }
+
+        let report = sim.report();
+        assert_eq!(report.pbs_count, 16);
+        assert_eq!(report.carry_overflows, 0);
+        assert_eq!(report.noise_budget_violations, 0);
+        let p_fail = report.failure_probability().unwrap();
+        assert!(p_fail > 15.0 * sim.metadata().p_fail());
+        assert!(p_fail <= 16.0 * sim.metadata().p_fail());
+    }
+
+    #[test]
+    fn test_simulator_reports_problems() {
+        let sim = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+
+        let ct = sim.encrypt(3);
+        let ct = sim.unchecked_scalar_mul(&ct, 6);
+        assert_eq!(sim.report().carry_overflows, 1);
+        assert_eq!(ct.noise_level, 36);
+
+        let _ = sim.message_extract(&ct);
+        let report = sim.report();
+        assert_eq!(report.noise_budget_violations, 1);
+        assert_eq!(report.max_noise_level, 36);
+        assert!(report.failure_probability().is_none());
+
+        sim.reset_report();
+        assert_eq!(sim.report(), SimulationReport::default());
+        assert_eq!(sim.report().failure_probability(), Some(0.0));
+    }
+}
@@ -0,0 +1,182 @@
+use super::{SimulatedCiphertext, SimulatedServerKey};
+use crate::integer::ciphertext::BaseRadixCiphertext;
+
+/// A radix decomposition of [`SimulatedCiphertext`]s, standing for a
+/// [`RadixCiphertextBig`](crate::integer::RadixCiphertextBig).
+pub type SimulatedRadixCiphertext = BaseRadixCiphertext<SimulatedCiphertext>;
+
+impl SimulatedRadixCiphertext {
+    /// The blocks are stored from LSB to MSB
+    pub fn blocks(&self) -> &[SimulatedCiphertext] {
+        &self.blocks
+    }
+}
+
+impl SimulatedServerKey {
+    /// Simulate the encryption of `message` in radix decomposition, the message being reduced
+    /// modulo the capacity of the blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::simulator::SimulatedServerKey;
+    ///
+    /// let sks = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+    ///
+    /// let ct_1 = sks.encrypt_radix(200, 4);
+    /// let ct_2 = sks.encrypt_radix(100, 4);
+    ///
+    /// let ct_res = sks.add_radix(&ct_1, &ct_2);
+    /// assert_eq!(sks.decrypt_radix(&ct_res), (200 + 100) % 256);
+    /// // The carries of each block are propagated to the next one
+    /// assert_eq!(sks.report().pbs_count, 2 * 4);
+    /// ```
+    pub fn encrypt_radix(&self, message: u64, num_blocks: usize) -> SimulatedRadixCiphertext {
+        let message_modulus = self.message_modulus();
+        let mut message = message;
+        let blocks = (0..num_blocks)
+            .map(|_| {
+                let block = self.encrypt(message % message_modulus);
+                message /= message_modulus;
+                block
+            })
+            .collect::<Vec<_>>();
+        SimulatedRadixCiphertext::from(blocks)
+    }
+
+    pub fn create_trivial_radix(&self, value: u64, num_blocks: usize) -> SimulatedRadixCiphertext {
+        let message_modulus = self.message_modulus();
+        let mut value = value;
+        let blocks = (0..num_blocks)
+            .map(|_| {
+                let block = self.create_trivial(value % message_modulus);
+                value /= message_modulus;
+                block
+            })
+            .collect::<Vec<_>>();
+        SimulatedRadixCiphertext::from(blocks)
+    }
+
+    /// Simulate the decryption of a radix ciphertext, the carries of the blocks being added to the
+    /// next block as the integer client key does.
+    pub fn decrypt_radix(&self, ct: &SimulatedRadixCiphertext) -> u64 {
+        let message_modulus = self.message_modulus();
+        let mut result = 0u64;
+        let mut shift = 1u64;
+        for block in &ct.blocks {
+            result = result.wrapping_add(self.decrypt_message_and_carry(block).wrapping_mul(shift));
+            shift = shift.wrapping_mul(message_modulus);
+        }
+        let modulus = message_modulus.checked_pow(ct.blocks.len() as u32);
+        modulus.map_or(result, |modulus| result % modulus)
+    }
+
+    pub fn unchecked_add_radix(
+        &self,
+        ct_left: &SimulatedRadixCiphertext,
+        ct_right: &SimulatedRadixCiphertext,
+    ) -> SimulatedRadixCiphertext {
+        assert_eq!(
+            ct_left.blocks.len(),
+            ct_right.blocks.len(),
+            "The ciphertexts must have the same number of blocks"
+        );
+        let blocks = ct_left
+            .blocks
+            .iter()
+            .zip(ct_right.blocks.iter())
+            .map(|(left, right)| self.unchecked_add(left, right))
+            .collect::<Vec<_>>();
+        SimulatedRadixCiphertext::from(blocks)
+    }
+
+    /// Propagate the carries of all the blocks, from the LSB to the MSB, as the integer
+    /// `full_propagate` does.
+    pub fn full_propagate_radix(&self, ct: &mut SimulatedRadixCiphertext) {
+        let num_blocks = ct.blocks.len();
+        for index in 0..num_blocks {
+            let carry = self.carry_extract(&ct.blocks[index]);
+            ct.blocks[index] = self.message_extract(&ct.blocks[index]);
+            if index < num_blocks - 1 {
+                ct.blocks[index + 1] = self.unchecked_add(&ct.blocks[index + 1], &carry);
+            }
+        }
+    }
+
+    /// Add the radix ciphertexts, propagating the carries of the inputs if they are not empty and
+    /// the ones of the result, as the integer `add_parallelized` does.
+    pub fn add_radix(
+        &self,
+        ct_left: &SimulatedRadixCiphertext,
+        ct_right: &SimulatedRadixCiphertext,
+    ) -> SimulatedRadixCiphertext {
+        let mut ct_left = ct_left.clone();
+        let mut ct_right = ct_right.clone();
+        if !self.radix_carries_are_empty(&ct_left) {
+            self.full_propagate_radix(&mut ct_left);
+        }
+        if !self.radix_carries_are_empty(&ct_right) {
+            self.full_propagate_radix(&mut ct_right);
+        }
+
+        let mut result = self.unchecked_add_radix(&ct_left, &ct_right);
+        self.full_propagate_radix(&mut result);
+        result
+    }
+
+    fn radix_carries_are_empty(&self, ct: &SimulatedRadixCiphertext) -> bool {
+        let message_modulus = self.parameters.message_modulus.0;
+        ct.blocks
+            .iter()
+            .all(|block| block.degree.0 < message_modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::gen_keys_radix;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use rand::Rng;
+
+    #[test]
+    fn test_simulator_radix_matches_integer() {
+        let num_blocks = 4;
+        let modulus = 1u64 << 8;
+
+        let sim = SimulatedServerKey::new(PARAM_MESSAGE_2_CARRY_2).unwrap();
+        let (cks, sks) = gen_keys_radix(&PARAM_MESSAGE_2_CARRY_2, num_blocks);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..4 {
+            let clear_1 = rng.gen::<u64>() % modulus;
+            let clear_2 = rng.gen::<u64>() % modulus;
+
+            let sim_1 = sim.encrypt_radix(clear_1, num_blocks);
+            let sim_2 = sim.encrypt_radix(clear_2, num_blocks);
+            let ct_1 = cks.encrypt(clear_1);
+            let ct_2 = cks.encrypt(clear_2);
+
+            let sim_res = sim.unchecked_add_radix(&sim_1, &sim_2);
+            let mut ct_res = sks.unchecked_add(&ct_1, &ct_2);
+            for (sim_block, block) in sim_res.blocks().iter().zip(ct_res.blocks.iter()) {
+                assert_eq!(sim_block.degree, block.degree);
+            }
+
+            let mut sim_res = sim_res;
+            sim.full_propagate_radix(&mut sim_res);
+            sks.full_propagate(&mut ct_res);
+            for (sim_block, block) in sim_res.blocks().iter().zip(ct_res.blocks.iter()) {
+                assert_eq!(sim_block.degree, block.degree);
+            }
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(sim.decrypt_radix(&sim_res), dec);
+            assert_eq!(dec, (clear_1 + clear_2) % modulus);
+        }
+
+        assert_eq!(sim.report().carry_overflows, 0);
+        assert_eq!(sim.report().noise_budget_violations, 0);
+    }
+}
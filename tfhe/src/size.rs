@@ -0,0 +1,575 @@
+//! Size introspection for ciphertexts and keys.
+//!
+//! The [`FheSize`] trait gives the number of scalar elements stored by a value, the number of
+//! bytes it uses in memory and the number of bytes of its bincode serialization. The serialized
+//! size is computed by walking the value with a counting serializer: nothing is allocated and no
+//! bytes are produced, which makes it cheap to use for bandwidth or storage budgeting.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::size::FheSize;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//! let ct = cks.encrypt(3);
+//!
+//! // A ciphertext is made of lwe_dimension + 1 u64
+//! let lwe_size = PARAM_MESSAGE_2_CARRY_2.glwe_dimension.0
+//!     * PARAM_MESSAGE_2_CARRY_2.polynomial_size.0
+//!     + 1;
+//! assert_eq!(ct.element_count(), lwe_size);
+//! assert!(ct.in_memory_size() >= lwe_size * std::mem::size_of::<u64>());
+//! assert_eq!(
+//!     ct.serialized_size(),
+//!     bincode::serialized_size(&ct).unwrap()
+//! );
+//!
+//! assert_eq!(
+//!     sks.element_count(),
+//!     sks.bootstrapping_key_size_elements() + sks.key_switching_key_size_elements()
+//! );
+//! ```
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::c64;
+use serde::ser::{self, Serialize};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Size introspection for ciphertexts and keys.
+pub trait FheSize {
+    /// Returns the number of scalar elements (torus values, secret key coefficients, Fourier
+    /// coefficients) stored by the value.
+    fn element_count(&self) -> usize;
+
+    /// Returns the number of bytes allocated on the heap by the value.
+    fn heap_size(&self) -> usize;
+
+    /// Returns the number of bytes used in memory by the value, heap allocations included.
+    fn in_memory_size(&self) -> usize {
+        std::mem::size_of_val(self) + self.heap_size()
+    }
+
+    /// Returns the number of bytes of the bincode serialization of the value, without
+    /// serializing it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value fails to serialize, in which case serializing it with bincode would
+    /// fail too.
+    fn serialized_size(&self) -> u64
+    where
+        Self: Serialize,
+    {
+        let mut counter = SizeCounter { total: 0 };
+        self.serialize(&mut counter)
+            .unwrap_or_else(|err| panic!("Could not compute the serialized size: {err}"));
+        counter.total
+    }
+}
+
+/// Implements [`FheSize`] for a type by summing the sizes of some of its fields, the other fields
+/// being plain values stored inline.
+macro_rules! impl_fhe_size_from_fields {
+    (
+        impl$([$($generics:tt)*])? for $ty:ty $([where $($bounds:tt)*])? {
+            $($field:tt),* $(,)?
+        }
+    ) => {
+        impl$(<$($generics)*>)? $crate::size::FheSize for $ty $(where $($bounds)*)? {
+            fn element_count(&self) -> usize {
+                0 $(+ $crate::size::FheSize::element_count(&self.$field))*
+            }
+
+            fn heap_size(&self) -> usize {
+                0 $(+ $crate::size::FheSize::heap_size(&self.$field))*
+            }
+        }
+    };
+}
+
+pub(crate) use impl_fhe_size_from_fields;
+
+impl<T: FheSize> FheSize for Vec<T> {
+    fn element_count(&self) -> usize {
+        self.iter().map(FheSize::element_count).sum()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.len() * std::mem::size_of::<T>() + self.iter().map(FheSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: FheSize> FheSize for Option<T> {
+    fn element_count(&self) -> usize {
+        self.as_ref().map_or(0, FheSize::element_count)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, FheSize::heap_size)
+    }
+}
+
+impl<T: FheSize> FheSize for RefCell<T> {
+    fn element_count(&self) -> usize {
+        self.borrow().element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.borrow().heap_size()
+    }
+}
+
+impl<T: FheSize> FheSize for Arc<T> {
+    fn element_count(&self) -> usize {
+        self.as_ref().element_count()
+    }
+
+    fn heap_size(&self) -> usize {
+        std::mem::size_of::<T>() + self.as_ref().heap_size()
+    }
+}
+
+macro_rules! impl_fhe_size_for_owned_entities {
+    ($($entity:ident),* $(,)?) => {
+        $(
+            impl<Scalar: UnsignedInteger> FheSize for $entity<Vec<Scalar>> {
+                fn element_count(&self) -> usize {
+                    self.as_ref().len()
+                }
+
+                fn heap_size(&self) -> usize {
+                    self.as_ref().len() * std::mem::size_of::<Scalar>()
+                }
+            }
+        )*
+    };
+}
+
+impl_fhe_size_for_owned_entities!(
+    GgswCiphertext,
+    GgswCiphertextList,
+    GlweCiphertext,
+    GlweCiphertextList,
+    GlweSecretKey,
+    LweBootstrapKey,
+    LweCiphertext,
+    LweCiphertextList,
+    LweCompactCiphertextList,
+    LweCompactPublicKey,
+    LweKeyswitchKey,
+    LwePrivateFunctionalPackingKeyswitchKeyList,
+    LwePublicKey,
+    LweSecretKey,
+    SeededGgswCiphertext,
+    SeededGlweCiphertext,
+    SeededLweBootstrapKey,
+    SeededLweCiphertextList,
+    SeededLweKeyswitchKey,
+    SeededLwePublicKey,
+);
+
+impl<Scalar: UnsignedInteger> FheSize for SeededLweCiphertext<Scalar> {
+    fn element_count(&self) -> usize {
+        1
+    }
+
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl FheSize for FourierLweBootstrapKeyOwned {
+    fn element_count(&self) -> usize {
+        self.as_view().data().len()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.element_count() * std::mem::size_of::<c64>()
+    }
+}
+
+#[derive(Debug)]
+struct SizeError(String);
+
+impl Display for SizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SizeError {}
+
+impl ser::Error for SizeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Serializer counting the bytes bincode would output with its default configuration: fixed size
+/// little endian integers, u64 lengths and u32 enum variant indices.
+struct SizeCounter {
+    total: u64,
+}
+
+impl SizeCounter {
+    fn add(&mut self, size: usize) -> Result<(), SizeError> {
+        self.total += size as u64;
+        Ok(())
+    }
+}
+
+const LENGTH_SIZE: usize = std::mem::size_of::<u64>();
+const VARIANT_SIZE: usize = std::mem::size_of::<u32>();
+
+impl ser::Serializer for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, _v: bool) -> Result<(), SizeError> {
+        self.add(1)
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<(), SizeError> {
+        self.add(1)
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<(), SizeError> {
+        self.add(2)
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<(), SizeError> {
+        self.add(4)
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<(), SizeError> {
+        self.add(8)
+    }
+
+    fn serialize_i128(self, _v: i128) -> Result<(), SizeError> {
+        self.add(16)
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<(), SizeError> {
+        self.add(1)
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<(), SizeError> {
+        self.add(2)
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<(), SizeError> {
+        self.add(4)
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<(), SizeError> {
+        self.add(8)
+    }
+
+    fn serialize_u128(self, _v: u128) -> Result<(), SizeError> {
+        self.add(16)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<(), SizeError> {
+        self.add(4)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<(), SizeError> {
+        self.add(8)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SizeError> {
+        self.add(v.len_utf8())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SizeError> {
+        self.add(LENGTH_SIZE + v.len())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SizeError> {
+        self.add(LENGTH_SIZE + v.len())
+    }
+
+    fn serialize_none(self) -> Result<(), SizeError> {
+        self.add(1)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), SizeError> {
+        self.add(1)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), SizeError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), SizeError> {
+        self.add(VARIANT_SIZE)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), SizeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<(), SizeError> {
+        self.add(VARIANT_SIZE)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self, SizeError> {
+        if len.is_none() {
+            return Err(SizeError(
+                "Sequences must have a known length to be serialized".to_string(),
+            ));
+        }
+        self.add(LENGTH_SIZE)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, SizeError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, SizeError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, SizeError> {
+        self.add(VARIANT_SIZE)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self, SizeError> {
+        if len.is_none() {
+            return Err(SizeError(
+                "Maps must have a known length to be serialized".to_string(),
+            ));
+        }
+        self.add(LENGTH_SIZE)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, SizeError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, SizeError> {
+        self.add(VARIANT_SIZE)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl ser::SerializeSeq for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SizeError> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut SizeCounter {
+    type Ok = ();
+    type Error = SizeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), SizeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SizeError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::parameters::{CiphertextModulus, LweSize};
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    enum Value {
+        Unit,
+        Newtype(u16),
+        Tuple(u8, char),
+        Struct { name: String, data: Vec<u64> },
+    }
+
+    #[derive(Serialize)]
+    struct Container {
+        values: Vec<Value>,
+        maybe: Option<(i32, f64)>,
+        nothing: Option<u128>,
+        bytes: std::collections::BTreeMap<u8, bool>,
+    }
+
+    fn counted_size<T: Serialize>(value: &T) -> u64 {
+        let mut counter = SizeCounter { total: 0 };
+        value.serialize(&mut counter).unwrap();
+        counter.total
+    }
+
+    #[test]
+    fn test_size_counter_matches_bincode() {
+        let value = Container {
+            values: vec![
+                Value::Unit,
+                Value::Newtype(3),
+                Value::Tuple(1, 'é'),
+                Value::Struct {
+                    name: "tfhe".to_string(),
+                    data: vec![1, 2, 3],
+                },
+            ],
+            maybe: Some((-1, 0.5)),
+            nothing: None,
+            bytes: [(1, true), (2, false)].into_iter().collect(),
+        };
+
+        assert_eq!(
+            counted_size(&value),
+            bincode::serialized_size(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_entity_size() {
+        let ct = LweCiphertext::new(0u64, LweSize(10), CiphertextModulus::new_native());
+        assert_eq!(ct.element_count(), 10);
+        assert_eq!(ct.heap_size(), 10 * std::mem::size_of::<u64>());
+        assert_eq!(ct.serialized_size(), bincode::serialized_size(&ct).unwrap());
+
+        let cts = vec![ct.clone(), ct];
+        assert_eq!(cts.element_count(), 20);
+        assert_eq!(
+            cts.heap_size(),
+            2 * (std::mem::size_of::<LweCiphertextOwned<u64>>() + 10 * std::mem::size_of::<u64>())
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Empirical measurement of the noise and of the error rate of shortint ciphertexts.
+//!
+//! [`measure_noise`] runs a number of trials, each producing a ciphertext together with the value
+//! it is expected to decrypt to, and decrypts them with the [`ClientKey`]. The noise of each
+//! ciphertext is the difference between its decrypted phase and the encoding of the expected
+//! value, as a fraction of the torus. The returned [`NoiseStats`] give the error rate and the
+//! distribution of the noise, with confidence intervals.
+//!
+//! This is what the statistical tests of the library do, parameter tuners and auditors can use it
+//! to check a parameter set or a circuit against its target failure probability.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::stats::measure_noise;
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! // Noise after a PBS
+//! let stats = measure_noise(&cks, 100, |i| {
+//!     let msg = i as u64 % 4;
+//!     let ct = cks.encrypt(msg);
+//!     (sks.message_extract(&ct), msg)
+//! });
+//!
+//! assert_eq!(stats.trials(), 100);
+//! assert_eq!(stats.failures(), 0);
+//!
+//! // With no failure out of 100 trials, the error rate is below 4% with 95% confidence
+//! let (_, upper_bound) = stats.error_rate_interval(1.96);
+//! assert!(upper_bound < 0.04);
+//!
+//! println!("noise std dev: {:e}", stats.std_dev());
+//! ```
+use crate::core_crypto::algorithms::decrypt_lwe_ciphertext;
+use crate::shortint::ciphertext::{CiphertextBase, PBSOrder, PBSOrderMarker};
+use crate::shortint::ClientKey;
+use rayon::prelude::*;
+
+/// Empirical noise and error rate measured by [`measure_noise`].
+#[derive(Clone, Debug)]
+pub struct NoiseStats {
+    /// Noise of each trial, as a fraction of the torus in `[-0.5, 0.5)`
+    samples: Vec<f64>,
+    failures: usize,
+}
+
+impl NoiseStats {
+    pub fn trials(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Number of trials which did not decrypt to the expected value.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    pub fn error_rate(&self) -> f64 {
+        self.failures as f64 / self.trials() as f64
+    }
+
+    /// Wilson score interval of the error rate for the given z-score (e.g. 1.96 for 95%
+    /// confidence).
+    ///
+    /// Contrary to the normal approximation, the interval is meaningful when no failure was
+    /// observed, which is the expected outcome of a test.
+    pub fn error_rate_interval(&self, z: f64) -> (f64, f64) {
+        let n = self.trials() as f64;
+        let p = self.error_rate();
+        let z2 = z * z;
+
+        let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let half_width = z / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+
+        (
+            (center - half_width).max(0.0),
+            (center + half_width).min(1.0),
+        )
+    }
+
+    /// Noise of each trial, as a fraction of the torus.
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.samples.iter().sum::<f64>() / self.trials() as f64
+    }
+
+    /// Unbiased sample variance of the noise.
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        let sum_of_squares: f64 = self.samples.iter().map(|x| (x - mean) * (x - mean)).sum();
+        sum_of_squares / (self.trials() as f64 - 1.0)
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Confidence interval of the mean for the given z-score.
+    pub fn mean_interval(&self, z: f64) -> (f64, f64) {
+        let half_width = z * self.std_dev() / (self.trials() as f64).sqrt();
+        let mean = self.mean();
+        (mean - half_width, mean + half_width)
+    }
+
+    /// Confidence interval of the standard deviation for the given z-score, assuming a Gaussian
+    /// noise (large sample approximation).
+    pub fn std_dev_interval(&self, z: f64) -> (f64, f64) {
+        let std_dev = self.std_dev();
+        let half_width = z * std_dev / (2.0 * (self.trials() as f64 - 1.0)).sqrt();
+        ((std_dev - half_width).max(0.0), std_dev + half_width)
+    }
+
+    /// Largest absolute noise among the trials.
+    pub fn max_abs(&self) -> f64 {
+        self.samples
+            .iter()
+            .fold(0.0, |acc: f64, x| acc.max(x.abs()))
+    }
+
+    /// Number of samples in each of `bins` intervals of the same width spanning
+    /// `[-max_abs, max_abs]`.
+    pub fn histogram(&self, bins: usize) -> Vec<usize> {
+        assert!(bins > 0, "A histogram needs at least one bin");
+
+        let mut histogram = vec![0; bins];
+        let max_abs = self.max_abs();
+        if max_abs == 0.0 {
+            histogram[bins / 2] = self.trials();
+            return histogram;
+        }
+
+        for x in &self.samples {
+            let bin = ((x + max_abs) / (2.0 * max_abs) * bins as f64) as usize;
+            histogram[bin.min(bins - 1)] += 1;
+        }
+        histogram
+    }
+}
+
+/// Run `trials` trials and measure the noise and the error rate of the ciphertexts they produce.
+///
+/// `trial` is called with the index of the trial and returns a ciphertext and the value (message
+/// and carry) it should decrypt to. Trials are run in parallel.
+///
+/// # Panics
+///
+/// Panics if `trials` is lower than 2.
+pub fn measure_noise<OpOrder, F>(cks: &ClientKey, trials: usize, trial: F) -> NoiseStats
+where
+    OpOrder: PBSOrderMarker,
+    F: Fn(usize) -> (CiphertextBase<OpOrder>, u64) + Sync,
+{
+    assert!(
+        trials >= 2,
+        "At least two trials are needed to estimate a variance"
+    );
+
+    let full_modulus = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as u64;
+    // One bit of padding
+    let delta = (1_u64 << 63) / full_modulus;
+
+    let results: Vec<(f64, bool)> = (0..trials)
+        .into_par_iter()
+        .map(|i| {
+            let (ct, expected) = trial(i);
+            let expected = expected % full_modulus;
+
+            let lwe_secret_key = match OpOrder::pbs_order() {
+                PBSOrder::KeyswitchBootstrap => &cks.large_lwe_secret_key,
+                PBSOrder::BootstrapKeyswitch => &cks.small_lwe_secret_key,
+            };
+            let phase = decrypt_lwe_ciphertext(lwe_secret_key, &ct.ct).0;
+
+            let noise = phase.wrapping_sub(expected * delta) as i64;
+            let noise = noise as f64 / 2.0f64.powi(64);
+
+            (noise, cks.decrypt_message_and_carry(&ct) != expected)
+        })
+        .collect();
+
+    let failures = results.iter().filter(|(_, failed)| *failed).count();
+    let samples = results.into_iter().map(|(noise, _)| noise).collect();
+
+    NoiseStats { samples, failures }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_measure_fresh_noise() {
+        let param = PARAM_MESSAGE_2_CARRY_2;
+        let (cks, _sks) = gen_keys(param);
+
+        let stats = measure_noise(&cks, 1000, |i| {
+            let msg = i as u64 % 4;
+            (cks.encrypt(msg), msg)
+        });
+
+        assert_eq!(stats.failures(), 0);
+        assert_eq!(stats.histogram(10).iter().sum::<usize>(), 1000);
+
+        // Fresh ciphertexts (KeyswitchBootstrap order) are encrypted under the GLWE noise
+        let expected_std_dev = param.glwe_modular_std_dev.0;
+        let (lower_bound, upper_bound) = stats.std_dev_interval(4.0);
+        assert!(lower_bound < expected_std_dev && expected_std_dev < upper_bound);
+        let (lower_bound, upper_bound) = stats.mean_interval(4.0);
+        assert!(lower_bound < 0.0 && 0.0 < upper_bound);
+    }
+
+    #[test]
+    fn test_measure_failures() {
+        let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        // Half of the trials expect a wrong value
+        let stats = measure_noise(&cks, 100, |i| (cks.encrypt(1), 1 + (i % 2) as u64));
+
+        assert_eq!(stats.failures(), 50);
+        let (lower_bound, upper_bound) = stats.error_rate_interval(1.96);
+        assert!(lower_bound < 0.5 && 0.5 < upper_bound);
+    }
+}
@@ -0,0 +1,379 @@
+//! Measurement of the data-dependent timing of the public operations.
+//!
+//! The duration of some operations depends on the state of their operands: the default
+//! operations clean the carries of their inputs before computing, so they are slower on inputs
+//! with non-empty carries, and operations on trivial ciphertexts may be computed in the clear.
+//! The degree of a ciphertext is public, these differences do not leak the encrypted values, but
+//! they leak how the inputs were computed to anyone able to time the server.
+//!
+//! [`TimingHarness::measure`] times an operation on inputs prepared in several states and
+//! returns a [`TimingReport`] giving the median duration for each state and the divergence
+//! between them. [`audit_shortint_operations`] and [`audit_radix_operations`] run it on the main
+//! entry points of the shortint and integer server keys, which makes it possible to check which
+//! of them depend on the carries of their inputs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+//! use tfhe::timing_audit::{audit_shortint_operations, TimingHarness};
+//!
+//! let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let harness = TimingHarness::new(5);
+//! for report in audit_shortint_operations(&cks, &sks, &harness) {
+//!     println!("{report}");
+//!     if report.is_data_dependent(0.2) {
+//!         println!("{} depends on the state of its inputs", report.entry_point());
+//!     }
+//! }
+//! ```
+use crate::shortint::CiphertextBig;
+use rand::Rng;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Times operations on inputs prepared in several states.
+#[derive(Clone, Copy, Debug)]
+pub struct TimingHarness {
+    samples: usize,
+    warmup: usize,
+}
+
+impl TimingHarness {
+    /// Creates a harness timing `samples` runs of an operation for each state of its inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is zero.
+    pub fn new(samples: usize) -> Self {
+        assert!(samples > 0, "At least one sample per state is required");
+        Self { samples, warmup: 1 }
+    }
+
+    /// Sets the number of untimed runs done for each state before the timed ones.
+    pub fn with_warmup(self, warmup: usize) -> Self {
+        Self { warmup, ..self }
+    }
+
+    /// Times `operation` on inputs produced by each of the `states`.
+    ///
+    /// The inputs are prepared before starting the timer, a new input being prepared for each
+    /// run. The runs of the different states are interleaved, so that a change of the load of
+    /// the machine during the measurement affects all the states alike.
+    pub fn measure<I, R>(
+        &self,
+        entry_point: &str,
+        states: &[(&str, &dyn Fn() -> I)],
+        operation: impl Fn(&I) -> R,
+    ) -> TimingReport {
+        for (_, prepare) in states {
+            for _ in 0..self.warmup {
+                operation(&prepare());
+            }
+        }
+
+        let mut durations = vec![Vec::with_capacity(self.samples); states.len()];
+        for _ in 0..self.samples {
+            for ((_, prepare), durations) in states.iter().zip(durations.iter_mut()) {
+                let input = prepare();
+                let start = Instant::now();
+                let output = operation(&input);
+                durations.push(start.elapsed());
+                drop(output);
+            }
+        }
+
+        let states = states
+            .iter()
+            .zip(durations)
+            .map(|((name, _), mut durations)| {
+                durations.sort_unstable();
+                StateTiming {
+                    name: name.to_string(),
+                    durations,
+                }
+            })
+            .collect();
+
+        TimingReport {
+            entry_point: entry_point.to_string(),
+            states,
+        }
+    }
+}
+
+/// Durations of the runs of an operation for one state of its inputs.
+#[derive(Clone, Debug)]
+pub struct StateTiming {
+    name: String,
+    // Sorted
+    durations: Vec<Duration>,
+}
+
+impl StateTiming {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn median(&self) -> Duration {
+        self.durations[self.durations.len() / 2]
+    }
+
+    pub fn min(&self) -> Duration {
+        self.durations[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.durations[self.durations.len() - 1]
+    }
+}
+
+/// Timing of an operation for each state of its inputs, returned by [`TimingHarness::measure`].
+#[derive(Clone, Debug)]
+pub struct TimingReport {
+    entry_point: String,
+    states: Vec<StateTiming>,
+}
+
+impl TimingReport {
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    pub fn states(&self) -> &[StateTiming] {
+        &self.states
+    }
+
+    /// Returns the relative difference between the slowest and the fastest median durations of
+    /// the states, e.g. `0.5` if the slowest state takes 50% longer than the fastest one.
+    pub fn divergence(&self) -> f64 {
+        let medians = self.states.iter().map(|state| state.median().as_secs_f64());
+        let fastest = medians.clone().fold(f64::INFINITY, f64::min);
+        let slowest = medians.fold(0.0, f64::max);
+        if fastest > 0.0 {
+            (slowest - fastest) / fastest
+        } else if slowest > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns whether the divergence between the states is above `threshold`.
+    ///
+    /// Timings are noisy, a threshold of a few tens of percents avoids reporting the noise of the
+    /// measurement as a dependency on the inputs.
+    pub fn is_data_dependent(&self, threshold: f64) -> bool {
+        self.divergence() > threshold
+    }
+}
+
+impl fmt::Display for TimingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: divergence {:.1}%",
+            self.entry_point,
+            self.divergence() * 100.0
+        )?;
+        for state in &self.states {
+            write!(
+                f,
+                "\n  {}: median {:?} (min {:?}, max {:?})",
+                state.name,
+                state.median(),
+                state.min(),
+                state.max()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Names of the input states used by the audits
+const EMPTY_CARRIES: &str = "empty carries";
+const NON_EMPTY_CARRIES: &str = "non-empty carries";
+const TRIVIAL: &str = "trivial";
+
+/// Times the default operations of the shortint server key on fresh inputs, inputs with
+/// non-empty carries and trivial inputs.
+pub fn audit_shortint_operations(
+    cks: &crate::shortint::ClientKey,
+    sks: &crate::shortint::ServerKey,
+    harness: &TimingHarness,
+) -> Vec<TimingReport> {
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let random_message = || rand::thread_rng().gen::<u64>() % modulus;
+
+    let empty_carries = || (cks.encrypt(random_message()), cks.encrypt(random_message()));
+    // The sum of two messages fills the carries of the left hand side
+    let non_empty_carries = || {
+        let (lhs, rhs) = empty_carries();
+        (sks.unchecked_add(&lhs, &rhs), cks.encrypt(random_message()))
+    };
+    let trivial = || {
+        (
+            sks.create_trivial(random_message()),
+            sks.create_trivial(random_message()),
+        )
+    };
+    let states: [(&str, &dyn Fn() -> (CiphertextBig, CiphertextBig)); 3] = [
+        (EMPTY_CARRIES, &empty_carries),
+        (NON_EMPTY_CARRIES, &non_empty_carries),
+        (TRIVIAL, &trivial),
+    ];
+
+    type Operation<'a> = &'a dyn Fn(&(CiphertextBig, CiphertextBig)) -> CiphertextBig;
+    let operations: [(&str, Operation); 7] = [
+        ("shortint::ServerKey::add", &|(lhs, rhs)| sks.add(lhs, rhs)),
+        ("shortint::ServerKey::sub", &|(lhs, rhs)| sks.sub(lhs, rhs)),
+        ("shortint::ServerKey::mul", &|(lhs, rhs)| sks.mul(lhs, rhs)),
+        ("shortint::ServerKey::bitand", &|(lhs, rhs)| {
+            sks.bitand(lhs, rhs)
+        }),
+        ("shortint::ServerKey::greater", &|(lhs, rhs)| {
+            sks.greater(lhs, rhs)
+        }),
+        ("shortint::ServerKey::scalar_add", &|(lhs, _)| {
+            sks.scalar_add(lhs, 1)
+        }),
+        ("shortint::ServerKey::scalar_mul", &|(lhs, _)| {
+            sks.scalar_mul(lhs, 2)
+        }),
+    ];
+
+    operations
+        .iter()
+        .map(|(entry_point, operation)| harness.measure(entry_point, &states, operation))
+        .collect()
+}
+
+/// Times the default parallelized operations of the integer server key on fresh inputs, inputs
+/// with non-empty carries and trivial inputs.
+#[cfg(feature = "integer")]
+pub fn audit_radix_operations(
+    cks: &crate::integer::RadixClientKey,
+    sks: &crate::integer::ServerKey,
+    harness: &TimingHarness,
+) -> Vec<TimingReport> {
+    use crate::integer::RadixCiphertextBig;
+
+    let num_blocks = cks.num_blocks();
+    let bits = cks.parameters().message_modulus.0.trailing_zeros() as usize * num_blocks;
+    let random_message = || {
+        let message = rand::thread_rng().gen::<u64>();
+        if bits < 64 {
+            message % (1 << bits)
+        } else {
+            message
+        }
+    };
+
+    let empty_carries = || (cks.encrypt(random_message()), cks.encrypt(random_message()));
+    // The sum of two messages fills the carries of the blocks of the left hand side
+    let non_empty_carries = || {
+        let (lhs, rhs) = empty_carries();
+        (sks.unchecked_add(&lhs, &rhs), cks.encrypt(random_message()))
+    };
+    let trivial = || {
+        (
+            sks.create_trivial_radix(random_message(), num_blocks),
+            sks.create_trivial_radix(random_message(), num_blocks),
+        )
+    };
+    let states: [(&str, &dyn Fn() -> (RadixCiphertextBig, RadixCiphertextBig)); 3] = [
+        (EMPTY_CARRIES, &empty_carries),
+        (NON_EMPTY_CARRIES, &non_empty_carries),
+        (TRIVIAL, &trivial),
+    ];
+
+    type Operation<'a> =
+        &'a dyn Fn(&(RadixCiphertextBig, RadixCiphertextBig)) -> RadixCiphertextBig;
+    let operations: [(&str, Operation); 9] =
+        [
+            ("integer::ServerKey::add_parallelized", &|(lhs, rhs)| {
+                sks.add_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::sub_parallelized", &|(lhs, rhs)| {
+                sks.sub_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::mul_parallelized", &|(lhs, rhs)| {
+                sks.mul_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::bitand_parallelized", &|(lhs, rhs)| {
+                sks.bitand_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::eq_parallelized", &|(lhs, rhs)| {
+                sks.eq_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::gt_parallelized", &|(lhs, rhs)| {
+                sks.gt_parallelized(lhs, rhs)
+            }),
+            ("integer::ServerKey::neg_parallelized", &|(lhs, _)| {
+                sks.neg_parallelized(lhs)
+            }),
+            (
+                "integer::ServerKey::scalar_add_parallelized",
+                &|(lhs, _)| sks.scalar_add_parallelized(lhs, 1),
+            ),
+            (
+                "integer::ServerKey::scalar_mul_parallelized",
+                &|(lhs, _)| sks.scalar_mul_parallelized(lhs, 3),
+            ),
+        ];
+
+    operations
+        .iter()
+        .map(|(entry_point, operation)| harness.measure(entry_point, &states, operation))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+
+    #[test]
+    fn test_measure_detects_divergence() {
+        let harness = TimingHarness::new(5).with_warmup(0);
+
+        let fast = || Duration::ZERO;
+        let slow = || Duration::from_millis(10);
+        let states: [(&str, &dyn Fn() -> Duration); 2] = [("fast", &fast), ("slow", &slow)];
+
+        let report = harness.measure("sleep", &states, |duration| std::thread::sleep(*duration));
+
+        assert_eq!(report.entry_point(), "sleep");
+        assert_eq!(report.states().len(), 2);
+        assert_eq!(report.states()[0].name(), "fast");
+        assert!(report.states()[1].min() >= Duration::from_millis(10));
+        assert!(report.is_data_dependent(1.0));
+
+        let report = harness.measure("constant", &states, |_| ());
+        assert!(report
+            .states()
+            .iter()
+            .all(|state| state.min() <= state.max()));
+    }
+
+    #[test]
+    fn test_audit_shortint_operations() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+
+        let reports = audit_shortint_operations(&cks, &sks, &TimingHarness::new(2));
+
+        assert_eq!(reports.len(), 7);
+        for report in &reports {
+            let names = report
+                .states()
+                .iter()
+                .map(StateTiming::name)
+                .collect::<Vec<_>>();
+            assert_eq!(names, [EMPTY_CARRIES, NON_EMPTY_CARRIES, TRIVIAL]);
+            assert!(report.divergence() >= 0.0);
+        }
+    }
+}
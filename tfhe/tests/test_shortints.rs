@@ -100,7 +100,7 @@ fn test_product_uint_4_vec() -> Result<(), Box<dyn std::error::Error>> {
     set_server_key(server_keys);
 
     let clear_vec = vec![2, 5];
-    let expected = clear_vec.iter().copied().product();
+    let expected = clear_vec.iter().copied().product::<u8>();
 
     let fhe_vec: Vec<FheUint4> = clear_vec
         .iter()